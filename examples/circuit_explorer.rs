@@ -0,0 +1,14 @@
+use intrico::QuantumCircuit;
+
+fn main() -> std::io::Result<()> {
+    // Three-qubit GHZ state with a phase kick, to step through in the terminal
+    let mut qc = QuantumCircuit::new(3);
+
+    qc.h(0);
+    qc.cnot(0, 1);
+    qc.cnot(1, 2);
+    qc.t(2);
+    qc.h(0);
+
+    qc.explore()
+}
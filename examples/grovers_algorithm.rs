@@ -1,37 +1,20 @@
-use intrico::{simulator::Simulator, QuantumCircuit};
+use intrico::{algorithms::{grover, optimal_iterations, phase_oracle}, simulator::Simulator};
 
 fn main() {
-    let mut circuit = QuantumCircuit::new(2);
+    let num_qubits = 3;
+    let marked = 0b101;
 
-    // Step 1: Superposition
-    circuit.h(0);
-    circuit.h(1);
-
-    // Step 2: Oracle for |11⟩
-    circuit.cz(0, 1);
-
-    // Step 3: Diffusion operator
-    circuit.h(0);
-    circuit.h(1);
-    circuit.x(0);
-    circuit.x(1);
-    circuit.cz(0, 1);
-    circuit.x(0);
-    circuit.x(1);
-    circuit.h(0);
-    circuit.h(1);
+    // Superposition, oracle and diffuser are assembled by the builder
+    let iterations = optimal_iterations(num_qubits, 1);
+    let circuit = grover(num_qubits, phase_oracle(&[marked]), iterations);
 
     circuit.display();
 
-    let states = circuit.execute();
-
-    println!("Final states: {:?}", states);
-
     let sim = Simulator::new()
         .with_circuit(circuit);
 
     let result = sim.run(1024);
 
-    println!("Result: {:?}", result);
-
-}
\ No newline at end of file
+    println!("{}", result);
+    println!("Most frequent: {:?}", result.most_frequent());
+}
@@ -9,13 +9,13 @@ fn main() {
 
     let states = qc.execute();
 
-    println!("Final states: {:?}", states);
+    println!("{}", states);
 
     let sim = Simulator::new()
         .with_circuit(qc);
 
     let result = sim.run(1024);
 
-    println!("Result: {:?}", result);
+    println!("{}", result);
 
 }
\ No newline at end of file
@@ -0,0 +1,200 @@
+use std::f64::consts::PI;
+
+use crate::QuantumCircuit;
+use crate::simulator::Simulator;
+use super::{phase_estimation, PhaseEstimation};
+
+/// An amplitude estimation problem: the probability of measuring a good state
+/// 
+/// A preparation circuit `A` produces `A|0⟩ = √(1-a)|bad⟩ + √a|good⟩`, and the oracle
+/// flips the phase of the good states. Good states are those where every objective
+/// qubit reads 1, which is also what the default oracle, a multi-controlled Z on the
+/// objective qubits, marks. The amplitude `a` is estimated through the Grover operator
+/// `Q = -A·S₀·A†·Sχ`, whose eigenphases `±θ/π` satisfy `a = sin²(θ)`.
+/// 
+/// # Examples
+/// ```
+/// use intrico::QuantumCircuit;
+/// use intrico::algorithms::AmplitudeEstimation;
+/// 
+/// // Ry(2θ) prepares a = sin²(θ) on qubit 0
+/// let mut preparation = QuantumCircuit::new(1);
+/// preparation.ry(0, 2.0 * 0.6);
+/// 
+/// let problem = AmplitudeEstimation::new(preparation, &[0]);
+/// let estimate = problem.maximum_likelihood(&[0, 1, 2, 4, 8], 1000);
+/// assert!((estimate - 0.6_f64.sin().powi(2)).abs() < 0.02);
+/// ```
+#[derive(Debug, Clone)]
+pub struct AmplitudeEstimation {
+    preparation: QuantumCircuit,
+    oracle: QuantumCircuit,
+    objective_qubits: Vec<usize>,
+    simulator: Simulator,
+}
+
+impl AmplitudeEstimation {
+    /// Creates a problem whose good states have all objective qubits set
+    /// 
+    /// # Arguments
+    /// * `preparation` - The circuit `A` preparing the state
+    /// * `objective_qubits` - The qubits that read 1 exactly in the good states
+    /// 
+    /// # Panics
+    /// Panics if no objective qubit is given or one is out of bounds
+    pub fn new(preparation: QuantumCircuit, objective_qubits: &[usize]) -> Self {
+        if objective_qubits.is_empty() {
+            panic!("Amplitude estimation requires at least one objective qubit");
+        }
+
+        let mut oracle = QuantumCircuit::new(preparation.num_qubits());
+        oracle.mcz(objective_qubits);
+
+        AmplitudeEstimation {
+            preparation,
+            oracle,
+            objective_qubits: objective_qubits.to_vec(),
+            simulator: Simulator::new(),
+        }
+    }
+
+    /// Replaces the default oracle by a circuit flipping the phase of the good states
+    /// 
+    /// # Panics
+    /// Panics if the oracle does not act on the qubits of the preparation
+    pub fn with_oracle(mut self, oracle: QuantumCircuit) -> Self {
+        if oracle.num_qubits() != self.preparation.num_qubits() {
+            panic!("Oracle acts on {} qubits but the preparation on {}",
+                   oracle.num_qubits(), self.preparation.num_qubits());
+        }
+        self.oracle = oracle;
+        self
+    }
+
+    /// Sets the simulator used to run the estimation circuits
+    pub fn with_simulator(mut self, simulator: Simulator) -> Self {
+        self.simulator = simulator;
+        self
+    }
+
+    /// Builds the Grover operator `Q = -A·S₀·A†·Sχ`
+    pub fn grover_operator(&self) -> QuantumCircuit {
+        let num_qubits = self.preparation.num_qubits();
+        let all: Vec<usize> = (0..num_qubits).collect();
+        let mut circuit = QuantumCircuit::new(num_qubits);
+
+        circuit.compose(&self.oracle, &all);
+        circuit.compose(&self.preparation.inverse(), &all);
+
+        // X·MCZ·X = I - 2|0⟩⟨0| = S₀, and Z·X·Z·X = -I supplies the sign of Q
+        for &qubit in &all {
+            circuit.x(qubit);
+        }
+        circuit.mcz(&all);
+        for &qubit in &all {
+            circuit.x(qubit);
+        }
+        for _ in 0..2 {
+            circuit.z(0);
+            circuit.x(0);
+        }
+
+        circuit.compose(&self.preparation, &all);
+        circuit
+    }
+
+    /// Builds the canonical amplitude estimation circuit: phase estimation of `Q` on `A|0⟩`
+    pub fn canonical_circuit(&self, precision_qubits: usize) -> PhaseEstimation {
+        phase_estimation(&self.grover_operator(), precision_qubits).with_preparation(&self.preparation)
+    }
+
+    /// Estimates the amplitude with canonical (phase estimation based) amplitude estimation
+    /// 
+    /// The estimate is restricted to the grid `sin²(πk / 2^m)` of `m` precision qubits.
+    /// 
+    /// # Arguments
+    /// * `precision_qubits` - The number of qubits of the counting register
+    /// * `shots` - The number of measurements of the circuit
+    pub fn canonical(&self, precision_qubits: usize, shots: usize) -> f64 {
+        let qpe = self.canonical_circuit(precision_qubits);
+        let result = self.simulator.clone().with_circuit(qpe.circuit.clone()).run(shots);
+        amplitude_from_phase(qpe.estimate(&result))
+    }
+
+    /// Builds the circuit `Q^k·A` measured by maximum-likelihood amplitude estimation
+    pub fn power_circuit(&self, power: usize) -> QuantumCircuit {
+        let all: Vec<usize> = (0..self.preparation.num_qubits()).collect();
+        let grover = self.grover_operator();
+        let mut circuit = QuantumCircuit::new(all.len());
+
+        circuit.compose(&self.preparation, &all);
+        for _ in 0..power {
+            circuit.compose(&grover, &all);
+        }
+        circuit
+    }
+
+    /// Estimates the amplitude with iterative maximum-likelihood amplitude estimation
+    /// 
+    /// Each circuit `Q^k·A` finds a good state with probability `sin²((2k+1)θ)`. The
+    /// estimate maximizes the likelihood of the observed good counts over `θ ∈ [0, π/2]`.
+    /// 
+    /// # Arguments
+    /// * `powers` - The powers `k` of the Grover operator to measure, e.g. `[0, 1, 2, 4, 8]`
+    /// * `shots` - The number of measurements of each circuit
+    /// 
+    /// # Panics
+    /// Panics if no power is given
+    pub fn maximum_likelihood(&self, powers: &[usize], shots: usize) -> f64 {
+        if powers.is_empty() {
+            panic!("Maximum-likelihood amplitude estimation requires at least one power");
+        }
+
+        let circuits: Vec<QuantumCircuit> = powers.iter().map(|&power| self.power_circuit(power)).collect();
+        let good = "1".repeat(self.objective_qubits.len());
+        let hits: Vec<usize> = self.simulator.run_batch(&circuits, shots)
+            .iter()
+            .map(|result| result.marginal_counts(&self.objective_qubits).get(&good).copied().unwrap_or(0))
+            .collect();
+
+        let log_likelihood = |theta: f64| -> f64 {
+            powers.iter().zip(&hits).map(|(&power, &good)| {
+                let p = ((2 * power + 1) as f64 * theta).sin().powi(2).clamp(1e-12, 1.0 - 1e-12);
+                good as f64 * p.ln() + (shots - good) as f64 * (1.0 - p).ln()
+            }).sum()
+        };
+
+        // Grid search, resolved finely enough for the highest power, then refined
+        let max_power = powers.iter().copied().max().unwrap_or(0);
+        let points = 100 * (2 * max_power + 1);
+        let step = PI / 2.0 / points as f64;
+        let mut best = (0..=points)
+            .map(|i| i as f64 * step)
+            .max_by(|&a, &b| log_likelihood(a).total_cmp(&log_likelihood(b)))
+            .unwrap_or(0.0);
+
+        let mut width = step;
+        for _ in 0..40 {
+            width /= 2.0;
+            best = [best - width, best, best + width]
+                .into_iter()
+                .filter(|theta| (0.0..=PI / 2.0).contains(theta))
+                .max_by(|&a, &b| log_likelihood(a).total_cmp(&log_likelihood(b)))
+                .unwrap_or(best);
+        }
+
+        best.sin().powi(2)
+    }
+}
+
+/// Converts a phase of the Grover operator into the amplitude `sin²(πφ)`
+/// 
+/// # Examples
+/// ```
+/// use intrico::algorithms::amplitude_from_phase;
+/// 
+/// assert!((amplitude_from_phase(0.25) - 0.5).abs() < 1e-12);
+/// ```
+pub fn amplitude_from_phase(phase: f64) -> f64 {
+    (PI * phase).sin().powi(2)
+}
@@ -0,0 +1,269 @@
+//! Reversible arithmetic circuits
+//! 
+//! Registers are little-endian: the first qubit of a slice holds the least significant
+//! bit. Every builder appends its gates to an existing circuit, so the registers can be
+//! prepared and reused freely.
+
+use std::f64::consts::PI;
+
+use crate::QuantumCircuit;
+use super::{inverse_qft, qft};
+
+/// Appends a Cuccaro ripple-carry adder computing `b ← a + b mod 2^n`
+/// 
+/// The adder only uses Toffoli and CNOT gates and one ancilla, which must start and
+/// end in |0⟩. If a carry qubit is given, the overflow bit is XORed into it.
+/// 
+/// # Arguments
+/// * `circuit` - The circuit to append the adder to
+/// * `a` - The first operand, left unchanged
+/// * `b` - The second operand, overwritten with the sum
+/// * `ancilla` - A qubit in |0⟩
+/// * `carry` - An optional qubit receiving the carry out
+/// 
+/// # Panics
+/// Panics if the operands are empty or of different lengths
+/// 
+/// # Examples
+/// ```
+/// use intrico::QuantumCircuit;
+/// use intrico::algorithms::arithmetic::ripple_carry_adder;
+/// use intrico::simulator::Simulator;
+/// 
+/// // 3 + 1 on two bits: a = qubits 0-1, b = qubits 2-3, ancilla 4, carry 5
+/// let mut qc = QuantumCircuit::new(6);
+/// qc.x(0);
+/// qc.x(1);
+/// qc.x(2);
+/// ripple_carry_adder(&mut qc, &[0, 1], &[2, 3], 4, Some(5));
+/// 
+/// let result = Simulator::new().with_circuit(qc).run(10);
+/// assert_eq!(result.counts["100011"], 10);  // b = 0 with a carry
+/// ```
+pub fn ripple_carry_adder(circuit: &mut QuantumCircuit, a: &[usize], b: &[usize], ancilla: usize, carry: Option<usize>) {
+    check_operands(a, b);
+    let n = a.len();
+
+    majority(circuit, ancilla, b[0], a[0]);
+    for i in 1..n {
+        majority(circuit, a[i - 1], b[i], a[i]);
+    }
+
+    if let Some(carry) = carry {
+        circuit.cnot(a[n - 1], carry);
+    }
+
+    for i in (1..n).rev() {
+        unmajority(circuit, a[i - 1], b[i], a[i]);
+    }
+    unmajority(circuit, ancilla, b[0], a[0]);
+}
+
+/// Appends a comparator flipping `result` when `a < b`
+/// 
+/// The carry out of `¬a + b` is set exactly when `b > a`. It is computed with the
+/// majority chain of the ripple-carry adder and uncomputed afterwards, so both
+/// operands and the ancilla are left unchanged.
+/// 
+/// # Arguments
+/// * `circuit` - The circuit to append the comparator to
+/// * `a` - The first operand
+/// * `b` - The second operand
+/// * `ancilla` - A qubit in |0⟩
+/// * `result` - The qubit XORed with `a < b`
+/// 
+/// # Panics
+/// Panics if the operands are empty or of different lengths
+pub fn comparator(circuit: &mut QuantumCircuit, a: &[usize], b: &[usize], ancilla: usize, result: usize) {
+    check_operands(a, b);
+    let n = a.len();
+
+    for &qubit in a {
+        circuit.x(qubit);
+    }
+
+    majority(circuit, ancilla, b[0], a[0]);
+    for i in 1..n {
+        majority(circuit, a[i - 1], b[i], a[i]);
+    }
+
+    circuit.cnot(a[n - 1], result);
+
+    for i in (1..n).rev() {
+        majority_inverse(circuit, a[i - 1], b[i], a[i]);
+    }
+    majority_inverse(circuit, ancilla, b[0], a[0]);
+
+    for &qubit in a {
+        circuit.x(qubit);
+    }
+}
+
+/// Appends a Draper adder computing `b ← a + b mod 2^m` in the Fourier basis
+/// 
+/// No ancilla is needed; the operand `a` may be shorter than `b`.
+/// 
+/// # Arguments
+/// * `circuit` - The circuit to append the adder to
+/// * `a` - The first operand, left unchanged
+/// * `b` - The second operand of `m` qubits, overwritten with the sum
+/// 
+/// # Panics
+/// Panics if `a` is longer than `b`
+/// 
+/// # Examples
+/// ```
+/// use intrico::QuantumCircuit;
+/// use intrico::algorithms::arithmetic::qft_adder;
+/// use intrico::simulator::Simulator;
+/// 
+/// // 2 + 3 on three bits: a = qubits 0-1, b = qubits 2-4
+/// let mut qc = QuantumCircuit::new(5);
+/// qc.x(1);
+/// qc.x(2);
+/// qc.x(3);
+/// qft_adder(&mut qc, &[0, 1], &[2, 3, 4]);
+/// 
+/// let result = Simulator::new().with_circuit(qc).run(10);
+/// assert_eq!(result.counts["10110"], 10);  // b = 5
+/// ```
+pub fn qft_adder(circuit: &mut QuantumCircuit, a: &[usize], b: &[usize]) {
+    if a.len() > b.len() {
+        panic!("The first operand has {} qubits but the second only {}", a.len(), b.len());
+    }
+
+    qft(circuit, b);
+    phase_add_register(circuit, a, b, 1.0);
+    inverse_qft(circuit, b);
+}
+
+/// Appends a Draper adder computing `register ← register + value mod 2^m`
+/// 
+/// # Arguments
+/// * `circuit` - The circuit to append the adder to
+/// * `register` - The register of `m` qubits to add to
+/// * `value` - The classical constant to add
+pub fn add_constant(circuit: &mut QuantumCircuit, register: &[usize], value: u64) {
+    qft(circuit, register);
+    phase_add_constant(circuit, register, value as f64, None);
+    inverse_qft(circuit, register);
+}
+
+/// Appends a modular adder computing `b ← (a + b) mod N` for `a, b < N`
+/// 
+/// The register `b` needs one more qubit than `a`, used as a sign bit and left in |0⟩.
+/// Following Beauregard, the sum is reduced by subtracting `N`, a flag records whether
+/// the result went negative, `N` is added back under that flag, and the flag is
+/// uncomputed by comparing the result with `a`.
+/// 
+/// # Arguments
+/// * `circuit` - The circuit to append the adder to
+/// * `a` - The first operand of `n` qubits, left unchanged
+/// * `b` - The second operand of `n + 1` qubits whose top qubit is |0⟩
+/// * `modulus` - The modulus `N`, at most `2^n`
+/// * `ancilla` - A qubit in |0⟩ holding the flag
+/// 
+/// # Panics
+/// Panics if `b` is not one qubit longer than `a`, or if the modulus does not fit
+/// 
+/// # Examples
+/// ```
+/// use intrico::QuantumCircuit;
+/// use intrico::algorithms::arithmetic::modular_adder;
+/// use intrico::simulator::Simulator;
+/// 
+/// // (4 + 3) mod 5 = 2: a = qubits 0-2, b = qubits 3-6, ancilla 7
+/// let mut qc = QuantumCircuit::new(8);
+/// qc.x(2);
+/// qc.x(3);
+/// qc.x(4);
+/// modular_adder(&mut qc, &[0, 1, 2], &[3, 4, 5, 6], 5, 7);
+/// 
+/// let result = Simulator::new().with_circuit(qc).run(10);
+/// assert_eq!(result.counts["00010100"], 10);
+/// ```
+pub fn modular_adder(circuit: &mut QuantumCircuit, a: &[usize], b: &[usize], modulus: u64, ancilla: usize) {
+    if b.len() != a.len() + 1 {
+        panic!("The second operand needs {} qubits, got {}", a.len() + 1, b.len());
+    }
+    if modulus == 0 || modulus > 1 << a.len() {
+        panic!("Modulus {} does not fit in {} qubits", modulus, a.len());
+    }
+
+    let sign = b[b.len() - 1];
+    let modulus = modulus as f64;
+
+    // b ← a + b - N, flagging a negative result
+    qft(circuit, b);
+    phase_add_register(circuit, a, b, 1.0);
+    phase_add_constant(circuit, b, -modulus, None);
+    inverse_qft(circuit, b);
+    circuit.cnot(sign, ancilla);
+
+    // Add N back if the flag is set, then compare the result with a
+    qft(circuit, b);
+    phase_add_constant(circuit, b, modulus, Some(ancilla));
+    phase_add_register(circuit, a, b, -1.0);
+    inverse_qft(circuit, b);
+
+    // The flag was set exactly when no reduction happened, i.e. when the result is ≥ a
+    circuit.x(sign);
+    circuit.cnot(sign, ancilla);
+    circuit.x(sign);
+
+    qft(circuit, b);
+    phase_add_register(circuit, a, b, 1.0);
+    inverse_qft(circuit, b);
+}
+
+/// Adds `sign · a` to a register in the Fourier basis
+fn phase_add_register(circuit: &mut QuantumCircuit, a: &[usize], b: &[usize], sign: f64) {
+    let m = b.len();
+    for (i, &control) in a.iter().enumerate() {
+        // Terms with i + j ≥ m are multiples of 2π
+        for (j, &target) in b.iter().enumerate().take(m.saturating_sub(i)) {
+            let angle = sign * 2.0 * PI * (1u64 << (i + j)) as f64 / (1u64 << m) as f64;
+            circuit.cp(control, target, angle);
+        }
+    }
+}
+
+/// Adds a constant to a register in the Fourier basis, optionally controlled
+fn phase_add_constant(circuit: &mut QuantumCircuit, register: &[usize], value: f64, control: Option<usize>) {
+    let m = register.len();
+    for (j, &target) in register.iter().enumerate() {
+        let angle = 2.0 * PI * value * (1u64 << j) as f64 / (1u64 << m) as f64;
+        match control {
+            Some(control) => circuit.cp(control, target, angle),
+            None => circuit.p(target, angle),
+        }
+    }
+}
+
+/// Computes the carry into `a` from `c`, `b` and `a`
+fn majority(circuit: &mut QuantumCircuit, c: usize, b: usize, a: usize) {
+    circuit.cnot(a, b);
+    circuit.cnot(a, c);
+    circuit.ccx(c, b, a);
+}
+
+/// Uncomputes the carry and writes the sum bit into `b`
+fn unmajority(circuit: &mut QuantumCircuit, c: usize, b: usize, a: usize) {
+    circuit.ccx(c, b, a);
+    circuit.cnot(a, c);
+    circuit.cnot(c, b);
+}
+
+/// Undoes [`majority`], restoring all three qubits
+fn majority_inverse(circuit: &mut QuantumCircuit, c: usize, b: usize, a: usize) {
+    circuit.ccx(c, b, a);
+    circuit.cnot(a, c);
+    circuit.cnot(a, b);
+}
+
+/// Panics unless both operands are non-empty and of equal length
+fn check_operands(a: &[usize], b: &[usize]) {
+    if a.is_empty() || a.len() != b.len() {
+        panic!("Operands must be non-empty and of equal length, got {} and {} qubits", a.len(), b.len());
+    }
+}
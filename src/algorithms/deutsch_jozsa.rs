@@ -0,0 +1,53 @@
+use crate::QuantumCircuit;
+use crate::simulator::SimulationResult;
+use super::BitOracle;
+
+/// Builds the Deutsch–Jozsa circuit deciding whether an oracle is constant or balanced
+/// 
+/// Qubits `0..n` hold the input register and qubit `n` the output qubit prepared in |−⟩.
+/// After running the circuit, the input register reads all zeros exactly when the
+/// function is constant; see [`is_constant`].
+/// 
+/// # Examples
+/// ```
+/// use intrico::algorithms::{deutsch_jozsa, is_constant, BitOracle};
+/// use intrico::simulator::Simulator;
+/// 
+/// let oracle = BitOracle::balanced(3, 0b101);
+/// let result = Simulator::new().with_circuit(deutsch_jozsa(&oracle)).run(100);
+/// assert!(!is_constant(&result, oracle.num_inputs()));
+/// ```
+pub fn deutsch_jozsa(oracle: &BitOracle) -> QuantumCircuit {
+    let num_inputs = oracle.num_inputs();
+    let inputs: Vec<usize> = (0..num_inputs).collect();
+    let output = num_inputs;
+    let mut circuit = QuantumCircuit::new(num_inputs + 1);
+
+    circuit.x(output);
+    circuit.h(output);
+    for &qubit in &inputs {
+        circuit.h(qubit);
+    }
+
+    oracle.apply(&mut circuit, &inputs, output);
+
+    for &qubit in &inputs {
+        circuit.h(qubit);
+    }
+
+    circuit
+}
+
+/// Returns true if the input register of a Deutsch–Jozsa run read all zeros in most shots
+/// 
+/// # Arguments
+/// * `result` - The result of running [`deutsch_jozsa`]
+/// * `num_inputs` - The number of input bits of the oracle
+pub fn is_constant(result: &SimulationResult, num_inputs: usize) -> bool {
+    let inputs: Vec<usize> = (0..num_inputs).collect();
+    let zeros = result.marginal_counts(&inputs)
+        .get(&"0".repeat(num_inputs))
+        .copied()
+        .unwrap_or(0);
+    2 * zeros > result.shots
+}
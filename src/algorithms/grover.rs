@@ -0,0 +1,97 @@
+use std::f64::consts::PI;
+
+use crate::QuantumCircuit;
+
+/// Builds a Grover search circuit over `num_qubits` qubits
+/// 
+/// The circuit prepares the uniform superposition and then applies `iterations`
+/// rounds of the oracle followed by the diffuser, which reflects the state about
+/// the uniform superposition. The oracle must flip the phase of the marked states.
+/// 
+/// # Arguments
+/// * `num_qubits` - The number of qubits of the search space
+/// * `oracle` - Appends the phase oracle to the circuit
+/// * `iterations` - The number of Grover iterations, see [`optimal_iterations`]
+/// 
+/// # Examples
+/// ```
+/// use intrico::algorithms::{grover, optimal_iterations, phase_oracle};
+/// use intrico::simulator::Simulator;
+/// 
+/// let circuit = grover(3, phase_oracle(&[0b101]), optimal_iterations(3, 1));
+/// let result = Simulator::new().with_circuit(circuit).run(1000);
+/// 
+/// assert_eq!(result.most_frequent().unwrap().0, "101");
+/// ```
+pub fn grover<F: Fn(&mut QuantumCircuit)>(num_qubits: usize, oracle: F, iterations: usize) -> QuantumCircuit {
+    let qubits: Vec<usize> = (0..num_qubits).collect();
+    let mut circuit = QuantumCircuit::new(num_qubits);
+
+    // Uniform superposition
+    for &qubit in &qubits {
+        circuit.h(qubit);
+    }
+
+    for _ in 0..iterations {
+        oracle(&mut circuit);
+
+        // Diffuser: reflection about the uniform superposition
+        for &qubit in &qubits {
+            circuit.h(qubit);
+            circuit.x(qubit);
+        }
+        circuit.mcz(&qubits);
+        for &qubit in &qubits {
+            circuit.x(qubit);
+            circuit.h(qubit);
+        }
+    }
+
+    circuit
+}
+
+/// Returns an oracle flipping the phase of the given basis states
+/// 
+/// Basis states are given as integers whose bit `i` is the value of qubit `i`.
+/// 
+/// # Examples
+/// ```
+/// use intrico::QuantumCircuit;
+/// use intrico::algorithms::phase_oracle;
+/// 
+/// let mut qc = QuantumCircuit::new(2);
+/// phase_oracle(&[0b11])(&mut qc);
+/// ```
+pub fn phase_oracle(marked: &[usize]) -> impl Fn(&mut QuantumCircuit) {
+    let marked = marked.to_vec();
+    move |circuit: &mut QuantumCircuit| {
+        let qubits: Vec<usize> = (0..circuit.num_qubits()).collect();
+        for &state in &marked {
+            // Map the marked state onto |1...1⟩, flip its phase and map it back
+            let zeros: Vec<usize> = qubits.iter().copied().filter(|&q| state >> q & 1 == 0).collect();
+            for &qubit in &zeros {
+                circuit.x(qubit);
+            }
+            circuit.mcz(&qubits);
+            for &qubit in &zeros {
+                circuit.x(qubit);
+            }
+        }
+    }
+}
+
+/// Returns the number of Grover iterations maximizing the success probability
+/// 
+/// # Arguments
+/// * `num_qubits` - The number of qubits of the search space
+/// * `num_marked` - The number of marked states
+/// 
+/// # Panics
+/// Panics if there are no marked states
+pub fn optimal_iterations(num_qubits: usize, num_marked: usize) -> usize {
+    if num_marked == 0 {
+        panic!("Grover search needs at least one marked state");
+    }
+    let ratio = (num_marked as f64 / (1u64 << num_qubits) as f64).sqrt();
+    (PI / (4.0 * ratio.asin()) - 0.5).round().max(0.0) as usize
+}
@@ -0,0 +1,234 @@
+use std::f64::consts::PI;
+
+use rusticle::complex::Complex;
+use rusticle::linalg::Matrix;
+
+use crate::{QuantumCircuit, QuantumGate};
+use crate::simulator::Simulator;
+use crate::utility::state_fidelity;
+use crate::utility::linalg::{complete_unitary, exponentiate};
+use super::phase_estimation;
+
+/// The HHL algorithm solving a small Hermitian linear system `A·x = b`
+/// 
+/// Qubits `0..m` form the phase estimation register, the next `log₂(dim)` qubits hold
+/// `|b⟩` and finally `|x⟩`, and the last qubit is the ancilla flagging success. Phase
+/// estimation of `e^(iAt)` writes the eigenvalues of `A` into the counting register, a
+/// rotation of the ancilla by `C/λ` inverts them, and the phase estimation is then
+/// uncomputed. Eigenvalues are only exact when `λt/2π` is a multiple of `2^-m`.
+/// 
+/// # Examples
+/// ```
+/// use rusticle::complex::Complex;
+/// use rusticle::linalg::Matrix;
+/// use intrico::algorithms::HHL;
+/// 
+/// // Eigenvalues 2/3 and 4/3 give the exact phases 1/8 and 1/4 for t = 3π/8
+/// let third = Complex::new(-1.0 / 3.0, 0.0);
+/// let a = Matrix::new(2, 2, vec![Complex::new(1.0, 0.0), third, third, Complex::new(1.0, 0.0)]);
+/// let b = [Complex::new(0.0, 0.0), Complex::new(1.0, 0.0)];
+/// 
+/// let result = HHL::new(a, &b).with_precision(3).with_time(3.0 * std::f64::consts::PI / 8.0).run();
+/// assert!((result.fidelity - 1.0).abs() < 1e-6);
+/// ```
+#[derive(Debug, Clone)]
+pub struct HHL {
+    matrix: Matrix<Complex>,
+    b: Vec<Complex>,
+    precision_qubits: usize,
+    time: f64,
+}
+
+/// Outcome of an [`HHL`] run compared with the classical solution
+#[derive(Debug, Clone)]
+pub struct HHLResult {
+    /// The full HHL circuit
+    pub circuit: QuantumCircuit,
+    /// The normalized solution read from the system register when the ancilla is |1⟩
+    pub solution: Vec<Complex>,
+    /// The normalized classical solution of `A·x = b`
+    pub classical_solution: Vec<Complex>,
+    /// The fidelity between the quantum and classical solutions
+    pub fidelity: f64,
+    /// The probability of measuring the ancilla in |1⟩
+    pub success_probability: f64,
+}
+
+impl HHL {
+    /// Creates an HHL instance for a 2x2 or 4x4 Hermitian matrix
+    /// 
+    /// The evolution time defaults to `π/(2‖A‖∞)`, which keeps every `|λt/2π|` within `1/4`,
+    /// away from the wrap-around between positive and negative eigenvalues at `1/2`. The
+    /// counting register defaults to 4 qubits.
+    /// 
+    /// # Panics
+    /// Panics if the matrix is not a 2x2 or 4x4 Hermitian matrix, or if `b` does not
+    /// match its dimension or is zero
+    pub fn new(matrix: Matrix<Complex>, b: &[Complex]) -> Self {
+        let dim = matrix.rows();
+        if matrix.cols() != dim || !(dim == 2 || dim == 4) {
+            panic!("HHL supports 2x2 and 4x4 matrices, got {}x{}", dim, matrix.cols());
+        }
+        if b.len() != dim {
+            panic!("Right-hand side of length {} does not match a {}x{} matrix", b.len(), dim, dim);
+        }
+        let hermitian = (0..dim).all(|r| (0..dim).all(|c| {
+            let diff = *matrix.get(r, c) - matrix.get(c, r).conjugate();
+            diff.norm() < 1e-10
+        }));
+        if !hermitian {
+            panic!("HHL requires a Hermitian matrix");
+        }
+
+        let norm = b.iter().map(|amp| amp.norm_squared()).sum::<f64>().sqrt();
+        if norm < 1e-12 {
+            panic!("Right-hand side must be non-zero");
+        }
+
+        let bound = (0..dim)
+            .map(|r| (0..dim).map(|c| matrix.get(r, c).norm()).sum::<f64>())
+            .fold(0.0, f64::max);
+
+        HHL {
+            matrix,
+            b: b.iter().map(|&amp| amp / norm).collect(),
+            precision_qubits: 4,
+            time: PI / (2.0 * bound.max(1e-12)),
+        }
+    }
+
+    /// Sets the number of qubits of the phase estimation register
+    pub fn with_precision(mut self, precision_qubits: usize) -> Self {
+        self.precision_qubits = precision_qubits;
+        self
+    }
+
+    /// Sets the evolution time `t` of `e^(iAt)`
+    pub fn with_time(mut self, time: f64) -> Self {
+        self.time = time;
+        self
+    }
+
+    /// Returns the number of qubits holding `|b⟩` and `|x⟩`
+    pub fn system_qubits(&self) -> usize {
+        self.b.len().trailing_zeros() as usize
+    }
+
+    /// Returns the eigenvalue encoded by a value of the counting register
+    /// 
+    /// Values of `2^(m-1)` and above are read as negative eigenvalues.
+    pub fn eigenvalue(&self, value: usize) -> f64 {
+        let grid = (1usize << self.precision_qubits) as f64;
+        let mut phase = value as f64 / grid;
+        if phase >= 0.5 {
+            phase -= 1.0;
+        }
+        2.0 * PI * phase / self.time
+    }
+
+    /// Builds the HHL circuit
+    pub fn circuit(&self) -> QuantumCircuit {
+        let m = self.precision_qubits;
+        let n = self.system_qubits();
+        let system: Vec<usize> = (m..m + n).collect();
+        let ancilla = m + n;
+        let mut circuit = QuantumCircuit::new(m + n + 1);
+
+        // Load |b⟩, the first qubit of a custom gate being its most significant bit
+        let mut reversed = system.clone();
+        reversed.reverse();
+        let load = QuantumGate::Custom(complete_unitary(std::slice::from_ref(&self.b)), "Prep(b)".to_string(), "B".to_string());
+        circuit.add_multi_qubit_gate(load, &reversed);
+
+        let mut evolution = QuantumCircuit::new(n);
+        let mut targets: Vec<usize> = (0..n).collect();
+        targets.reverse();
+        let gate = QuantumGate::Custom(exponentiate(&self.matrix, self.time), "exp(iAt)".to_string(), "U".to_string());
+        evolution.add_multi_qubit_gate(gate, &targets);
+
+        let qpe = phase_estimation(&evolution, m).circuit;
+        let register: Vec<usize> = (0..m + n).collect();
+        circuit.compose(&qpe, &register);
+
+        let mut rotation_qubits = vec![ancilla];
+        rotation_qubits.extend((0..m).rev());
+        let rotation = QuantumGate::Custom(self.inversion(), "CRy(C/λ)".to_string(), "R".to_string());
+        circuit.add_multi_qubit_gate(rotation, &rotation_qubits);
+
+        circuit.compose(&qpe.inverse(), &register);
+        circuit
+    }
+
+    /// Runs the circuit and compares the extracted solution with the classical one
+    pub fn run(&self) -> HHLResult {
+        let circuit = self.circuit();
+        let state = Simulator::new()
+            .run_batch(std::slice::from_ref(&circuit), 0)
+            .remove(0)
+            .final_state;
+
+        // Amplitudes with the ancilla set and the counting register back in |0⟩
+        let m = self.precision_qubits;
+        let ancilla = 1 << (m + self.system_qubits());
+        let amplitudes: Vec<Complex> = (0..self.b.len()).map(|x| state[ancilla | (x << m)]).collect();
+        let success_probability = amplitudes.iter().map(|amp| amp.norm_squared()).sum::<f64>();
+        let solution = normalized(amplitudes);
+
+        let classical_solution = normalized(solve(&self.matrix, &self.b));
+        let fidelity = state_fidelity(&solution, &classical_solution);
+
+        HHLResult { circuit, solution, classical_solution, fidelity, success_probability }
+    }
+
+    /// Returns the block-diagonal ancilla rotations `Ry(2·asin(C/λ))` for every counting value
+    fn inversion(&self) -> Matrix<Complex> {
+        let values = 1 << self.precision_qubits;
+        // The smallest eigenvalue the register can represent
+        let c = 2.0 * PI / (values as f64 * self.time);
+        let mut matrix = Matrix::identity(2 * values);
+
+        for value in 1..values {
+            let ratio = (c / self.eigenvalue(value)).clamp(-1.0, 1.0);
+            let (sin, cos) = (ratio, (1.0 - ratio * ratio).sqrt());
+            matrix.set(value, value, Complex::new(cos, 0.0));
+            matrix.set(value, values + value, Complex::new(-sin, 0.0));
+            matrix.set(values + value, value, Complex::new(sin, 0.0));
+            matrix.set(values + value, values + value, Complex::new(cos, 0.0));
+        }
+        matrix
+    }
+}
+
+/// Solves `A·x = b` by Gaussian elimination with partial pivoting
+fn solve(matrix: &Matrix<Complex>, b: &[Complex]) -> Vec<Complex> {
+    let dim = b.len();
+    let mut rows: Vec<Vec<Complex>> = (0..dim)
+        .map(|r| (0..dim).map(|c| *matrix.get(r, c)).chain([b[r]]).collect())
+        .collect();
+
+    for col in 0..dim {
+        let pivot = (col..dim)
+            .max_by(|&x, &y| rows[x][col].norm().total_cmp(&rows[y][col].norm()))
+            .unwrap_or(col);
+        if rows[pivot][col].norm() < 1e-12 {
+            panic!("HHL requires a non-singular matrix");
+        }
+        rows.swap(col, pivot);
+
+        let pivot_row = rows[col].clone();
+        for (_, row) in rows.iter_mut().enumerate().filter(|&(r, _)| r != col) {
+            let factor = row[col] / pivot_row[col];
+            for (entry, &p) in row.iter_mut().zip(&pivot_row).skip(col) {
+                *entry -= p * factor;
+            }
+        }
+    }
+
+    (0..dim).map(|r| rows[r][dim] / rows[r][r]).collect()
+}
+
+/// Scales a vector to unit norm
+fn normalized(vector: Vec<Complex>) -> Vec<Complex> {
+    let norm = vector.iter().map(|amp| amp.norm_squared()).sum::<f64>().sqrt();
+    vector.into_iter().map(|amp| amp / norm).collect()
+}
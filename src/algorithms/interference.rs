@@ -0,0 +1,136 @@
+use crate::{QuantumCircuit, QuantumGate};
+use crate::simulator::SimulationResult;
+use super::phase_estimation::controlled;
+
+/// The part of `⟨ψ|U|ψ⟩` measured by a [`hadamard_test`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExpectationPart {
+    /// The real part `Re⟨ψ|U|ψ⟩`
+    Real,
+    /// The imaginary part `Im⟨ψ|U|ψ⟩`
+    Imaginary,
+}
+
+/// Builds the SWAP test estimating the overlap `|⟨a|b⟩|²` of two prepared states
+/// 
+/// Qubit 0 is the ancilla, qubits `1..=n` hold `|a⟩` and qubits `n+1..=2n` hold `|b⟩`.
+/// The ancilla controls a swap of the registers between two Hadamard gates, so it
+/// reads 0 with probability `(1 + |⟨a|b⟩|²) / 2`; see [`swap_test_overlap`].
+/// 
+/// # Arguments
+/// * `state_a` - The circuit preparing `|a⟩` from |0...0⟩
+/// * `state_b` - The circuit preparing `|b⟩` from |0...0⟩
+/// 
+/// # Panics
+/// Panics if the preparations act on different numbers of qubits
+/// 
+/// # Examples
+/// ```
+/// use intrico::QuantumCircuit;
+/// use intrico::algorithms::{swap_test, swap_test_overlap};
+/// use intrico::simulator::Simulator;
+/// 
+/// let zero = QuantumCircuit::new(1);
+/// let mut plus = QuantumCircuit::new(1);
+/// plus.h(0);
+/// 
+/// let result = Simulator::new().with_circuit(swap_test(&zero, &plus)).run(4000);
+/// assert!((swap_test_overlap(&result) - 0.5).abs() < 0.1);
+/// ```
+pub fn swap_test(state_a: &QuantumCircuit, state_b: &QuantumCircuit) -> QuantumCircuit {
+    let n = state_a.num_qubits();
+    if state_b.num_qubits() != n {
+        panic!("Cannot compare states of {} and {} qubits", n, state_b.num_qubits());
+    }
+
+    let register_a: Vec<usize> = (1..=n).collect();
+    let register_b: Vec<usize> = (n + 1..=2 * n).collect();
+    let mut circuit = QuantumCircuit::new(2 * n + 1);
+
+    circuit.compose(state_a, &register_a);
+    circuit.compose(state_b, &register_b);
+
+    circuit.h(0);
+    for (&a, &b) in register_a.iter().zip(&register_b) {
+        circuit.cswap(0, a, b);
+    }
+    circuit.h(0);
+
+    circuit
+}
+
+/// Returns the overlap `|⟨a|b⟩|² = 2·P(0) - 1` estimated from a [`swap_test`] run
+/// 
+/// Statistical fluctuations can make the estimate slightly negative, so it is clamped
+/// to `[0, 1]`.
+pub fn swap_test_overlap(result: &SimulationResult) -> f64 {
+    (2.0 * ancilla_zero_probability(result) - 1.0).clamp(0.0, 1.0)
+}
+
+/// Builds the Hadamard test measuring the real or imaginary part of `⟨ψ|U|ψ⟩`
+/// 
+/// Qubit 0 is the ancilla and qubits `1..=n` hold `|ψ⟩`. The ancilla controls `U`
+/// between two Hadamard gates, preceded by an S† for the imaginary part, so that
+/// `P(0) - P(1)` equals the requested part; see [`hadamard_test_expectation`].
+/// 
+/// # Arguments
+/// * `unitary` - The circuit implementing `U`
+/// * `preparation` - The circuit preparing `|ψ⟩` from |0...0⟩
+/// * `part` - Whether to measure the real or the imaginary part
+/// 
+/// # Panics
+/// Panics if the circuits act on different numbers of qubits
+/// 
+/// # Examples
+/// ```
+/// use intrico::QuantumCircuit;
+/// use intrico::algorithms::{hadamard_test, hadamard_test_expectation, ExpectationPart};
+/// use intrico::simulator::Simulator;
+/// 
+/// // ⟨1|S|1⟩ = i
+/// let mut s = QuantumCircuit::new(1);
+/// s.s(0);
+/// let mut one = QuantumCircuit::new(1);
+/// one.x(0);
+/// 
+/// let result = Simulator::new().with_circuit(hadamard_test(&s, &one, ExpectationPart::Imaginary)).run(100);
+/// assert_eq!(hadamard_test_expectation(&result), 1.0);
+/// ```
+pub fn hadamard_test(unitary: &QuantumCircuit, preparation: &QuantumCircuit, part: ExpectationPart) -> QuantumCircuit {
+    let n = unitary.num_qubits();
+    if preparation.num_qubits() != n {
+        panic!("Unitary acts on {} qubits but the preparation on {}", n, preparation.num_qubits());
+    }
+
+    let register: Vec<usize> = (1..=n).collect();
+    let mut circuit = QuantumCircuit::new(n + 1);
+    circuit.compose(preparation, &register);
+
+    circuit.h(0);
+    if part == ExpectationPart::Imaginary {
+        circuit.sdg(0);
+    }
+
+    // The first qubit of a custom gate is the most significant bit of its matrix index
+    let mut qubits = vec![0];
+    qubits.extend(register.iter().rev());
+    let gate = QuantumGate::Custom(controlled(&unitary.unitary()), "C-U".to_string(), "U".to_string());
+    circuit.add_multi_qubit_gate(gate, &qubits);
+
+    circuit.h(0);
+    circuit
+}
+
+/// Returns `P(0) - P(1)` of the ancilla, the part of `⟨ψ|U|ψ⟩` estimated by a [`hadamard_test`]
+pub fn hadamard_test_expectation(result: &SimulationResult) -> f64 {
+    2.0 * ancilla_zero_probability(result) - 1.0
+}
+
+/// Returns the fraction of shots in which qubit 0 read 0
+fn ancilla_zero_probability(result: &SimulationResult) -> f64 {
+    if result.shots == 0 {
+        return 0.0;
+    }
+    let zeros = result.marginal_counts(&[0]).get("0").copied().unwrap_or(0);
+    zeros as f64 / result.shots as f64
+}
@@ -0,0 +1,36 @@
+//! Quantum algorithms module
+//! 
+//! This module provides builders that assemble the circuits of well-known quantum
+//! algorithms, ready to be executed with the [`Simulator`](crate::simulator::Simulator).
+
+pub mod arithmetic;
+
+mod grover;
+mod qft;
+mod phase_estimation;
+mod oracle;
+mod deutsch_jozsa;
+mod simon;
+mod shor;
+mod teleportation;
+mod superdense;
+mod amplitude_estimation;
+mod hhl;
+mod quantum_walk;
+mod trotter;
+mod interference;
+
+pub use grover::{grover, optimal_iterations, phase_oracle};
+pub use qft::{qft, inverse_qft};
+pub use phase_estimation::{phase_estimation, PhaseEstimation};
+pub use oracle::BitOracle;
+pub use deutsch_jozsa::{deutsch_jozsa, is_constant};
+pub use simon::{find_period, simon, simon_oracle, solve_simon};
+pub use shor::{order_finding, phase_to_order, shor, shor_with, SHOR_MAX_N};
+pub use teleportation::{received_state, teleport, teleportation};
+pub use superdense::{decoded_bits, superdense_coding};
+pub use amplitude_estimation::{amplitude_from_phase, AmplitudeEstimation};
+pub use hhl::{HHL, HHLResult};
+pub use quantum_walk::{position_distribution, quantum_walk};
+pub use trotter::trotter_evolution;
+pub use interference::{hadamard_test, hadamard_test_expectation, swap_test, swap_test_overlap, ExpectationPart};
@@ -0,0 +1,118 @@
+use crate::QuantumCircuit;
+
+/// A classical boolean function `f: {0,1}^n → {0,1}` given as a quantum oracle
+/// 
+/// Applying the oracle maps `|x⟩|y⟩` to `|x⟩|y ⊕ f(x)⟩`. Inputs are integers whose
+/// bit `i` is the value of input qubit `i`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BitOracle {
+    /// The same value for every input
+    Constant {
+        /// Number of input bits
+        num_inputs: usize,
+        /// The constant output
+        value: bool,
+    },
+    /// The parity of the input bits selected by a mask, balanced for any non-zero mask
+    Parity {
+        /// Number of input bits
+        num_inputs: usize,
+        /// Input bits contributing to the parity
+        mask: usize,
+    },
+    /// An arbitrary function given by its output for every input, in input order
+    TruthTable(Vec<bool>),
+}
+
+impl BitOracle {
+    /// Creates an oracle returning `value` for every input
+    pub fn constant(num_inputs: usize, value: bool) -> Self {
+        BitOracle::Constant { num_inputs, value }
+    }
+
+    /// Creates a balanced oracle computing the parity of the masked input bits
+    /// 
+    /// # Panics
+    /// Panics if the mask is zero or selects bits beyond `num_inputs`
+    pub fn balanced(num_inputs: usize, mask: usize) -> Self {
+        if mask == 0 || mask >> num_inputs != 0 {
+            panic!("Mask {:#b} does not select a non-empty subset of {} input bits", mask, num_inputs);
+        }
+        BitOracle::Parity { num_inputs, mask }
+    }
+
+    /// Creates an oracle from the output of every input
+    /// 
+    /// # Panics
+    /// Panics if the table length is not a power of two
+    pub fn truth_table(table: Vec<bool>) -> Self {
+        if !table.len().is_power_of_two() {
+            panic!("A truth table needs 2^n entries, got {}", table.len());
+        }
+        BitOracle::TruthTable(table)
+    }
+
+    /// Returns the number of input bits
+    pub fn num_inputs(&self) -> usize {
+        match self {
+            BitOracle::Constant { num_inputs, .. } | BitOracle::Parity { num_inputs, .. } => *num_inputs,
+            BitOracle::TruthTable(table) => table.len().trailing_zeros() as usize,
+        }
+    }
+
+    /// Evaluates the function classically
+    pub fn evaluate(&self, input: usize) -> bool {
+        match self {
+            BitOracle::Constant { value, .. } => *value,
+            BitOracle::Parity { mask, .. } => (input & mask).count_ones() % 2 == 1,
+            BitOracle::TruthTable(table) => table[input],
+        }
+    }
+
+    /// Appends the oracle to a circuit
+    /// 
+    /// # Arguments
+    /// * `circuit` - The circuit to append to
+    /// * `inputs` - The input qubits, `inputs[i]` holding bit `i`
+    /// * `output` - The qubit receiving `f(x)`
+    /// 
+    /// # Panics
+    /// Panics if the number of input qubits does not match the oracle
+    pub fn apply(&self, circuit: &mut QuantumCircuit, inputs: &[usize], output: usize) {
+        if inputs.len() != self.num_inputs() {
+            panic!("Oracle expects {} input qubits, got {}", self.num_inputs(), inputs.len());
+        }
+
+        match self {
+            BitOracle::Constant { value, .. } => {
+                if *value {
+                    circuit.x(output);
+                }
+            },
+            BitOracle::Parity { mask, .. } => {
+                for (bit, &qubit) in inputs.iter().enumerate() {
+                    if mask >> bit & 1 == 1 {
+                        circuit.cnot(qubit, output);
+                    }
+                }
+            },
+            BitOracle::TruthTable(table) => {
+                // One multi-controlled X per input mapping to 1
+                for (input, _) in table.iter().enumerate().filter(|&(_, &value)| value) {
+                    let zeros: Vec<usize> = inputs.iter()
+                        .enumerate()
+                        .filter(|&(bit, _)| input >> bit & 1 == 0)
+                        .map(|(_, &qubit)| qubit)
+                        .collect();
+                    for &qubit in &zeros {
+                        circuit.x(qubit);
+                    }
+                    circuit.mcx(inputs, output);
+                    for &qubit in &zeros {
+                        circuit.x(qubit);
+                    }
+                }
+            },
+        }
+    }
+}
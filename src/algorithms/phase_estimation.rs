@@ -0,0 +1,151 @@
+use std::collections::HashMap;
+use std::ops::Range;
+
+use rusticle::complex::Complex;
+use rusticle::linalg::Matrix;
+
+use crate::{QuantumCircuit, QuantumGate};
+use crate::simulator::SimulationResult;
+use crate::utility::{bitstring_to_index, Endianness};
+use super::inverse_qft;
+
+/// A quantum phase estimation circuit together with its register layout
+/// 
+/// Qubits `0..precision_qubits` form the counting register and the remaining qubits
+/// hold the target register the unitary acts on.
+#[derive(Debug, Clone)]
+pub struct PhaseEstimation {
+    /// The full phase estimation circuit
+    pub circuit: QuantumCircuit,
+    /// Number of qubits in the counting register
+    pub precision_qubits: usize,
+}
+
+/// Builds a quantum phase estimation circuit for the unitary implemented by a circuit
+/// 
+/// The counting register is put into superposition, controls the powers
+/// `U^(2^k)` and is finally transformed with the inverse QFT, so that measuring it
+/// yields the phase `φ` of `U|ψ⟩ = e^(2πiφ)|ψ⟩` with `precision_qubits` bits. The target
+/// register starts in |0...0⟩; use [`PhaseEstimation::with_preparation`] to prepare an
+/// eigenstate first.
+/// 
+/// # Arguments
+/// * `unitary` - The circuit implementing `U`
+/// * `precision_qubits` - The number of bits of the estimated phase
+/// 
+/// # Examples
+/// ```
+/// use intrico::QuantumCircuit;
+/// use intrico::algorithms::phase_estimation;
+/// use intrico::simulator::Simulator;
+/// 
+/// // T has eigenvalue e^(iπ/4) on |1⟩, i.e. φ = 1/8
+/// let mut t = QuantumCircuit::new(1);
+/// t.t(0);
+/// let mut prepare = QuantumCircuit::new(1);
+/// prepare.x(0);
+/// 
+/// let qpe = phase_estimation(&t, 3).with_preparation(&prepare);
+/// let result = Simulator::new().with_circuit(qpe.circuit.clone()).run(100);
+/// assert_eq!(qpe.estimate(&result), 0.125);
+/// ```
+pub fn phase_estimation(unitary: &QuantumCircuit, precision_qubits: usize) -> PhaseEstimation {
+    let target_qubits = unitary.num_qubits();
+    let mut circuit = QuantumCircuit::new(precision_qubits + target_qubits);
+    let counting: Vec<usize> = (0..precision_qubits).collect();
+
+    for &qubit in &counting {
+        circuit.h(qubit);
+    }
+
+    // The first qubit of a custom gate is the most significant bit of its matrix index
+    let mut targets: Vec<usize> = (precision_qubits..precision_qubits + target_qubits).collect();
+    targets.reverse();
+
+    let mut power = unitary.unitary();
+    for &control in &counting {
+        let mut qubits = vec![control];
+        qubits.extend(&targets);
+
+        let gate = QuantumGate::Custom(controlled(&power), format!("C-U^{}", 1u64 << control), "U".to_string());
+        circuit.add_multi_qubit_gate(gate, &qubits);
+        power = &power * &power;
+    }
+
+    inverse_qft(&mut circuit, &counting);
+
+    PhaseEstimation { circuit, precision_qubits }
+}
+
+impl PhaseEstimation {
+    /// Prepends a circuit preparing the target register, e.g. in an eigenstate of `U`
+    /// 
+    /// # Panics
+    /// Panics if the preparation does not act on as many qubits as the target register
+    pub fn with_preparation(mut self, preparation: &QuantumCircuit) -> Self {
+        let mut circuit = QuantumCircuit::new(self.circuit.num_qubits());
+        let targets: Vec<usize> = self.target_qubits().collect();
+        circuit.compose(preparation, &targets);
+        for op in self.circuit.operations() {
+            circuit.add_operation(op.clone());
+        }
+        self.circuit = circuit;
+        self
+    }
+
+    /// Returns the qubits of the target register
+    pub fn target_qubits(&self) -> Range<usize> {
+        self.precision_qubits..self.circuit.num_qubits()
+    }
+
+    /// Converts a measured bitstring of the full register into a phase in `[0, 1)`
+    /// 
+    /// # Panics
+    /// Panics if the bitstring is not made of `0` and `1` characters
+    pub fn decode_phase(&self, bitstring: &str) -> f64 {
+        let counting = &bitstring[bitstring.len() - self.precision_qubits..];
+        let value = bitstring_to_index(counting, Endianness::Little)
+            .unwrap_or_else(|| panic!("Invalid bitstring '{}'", bitstring));
+        value as f64 / (1u64 << self.precision_qubits) as f64
+    }
+
+    /// Returns the probability of every measured phase
+    pub fn phase_distribution(&self, result: &SimulationResult) -> Vec<(f64, f64)> {
+        let mut totals: HashMap<String, usize> = HashMap::new();
+        for (bitstring, &count) in &result.counts {
+            let counting = &bitstring[bitstring.len() - self.precision_qubits..];
+            *totals.entry(counting.to_string()).or_insert(0) += count;
+        }
+
+        let shots: usize = totals.values().sum();
+        let mut phases: Vec<(f64, f64)> = totals.into_iter()
+            .map(|(bits, count)| (self.decode_phase(&bits), count as f64 / shots as f64))
+            .collect();
+        phases.sort_by(|a, b| a.0.total_cmp(&b.0));
+        phases
+    }
+
+    /// Returns the most frequently measured phase
+    /// 
+    /// # Panics
+    /// Panics if the result contains no shots
+    pub fn estimate(&self, result: &SimulationResult) -> f64 {
+        self.phase_distribution(result)
+            .into_iter()
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(phase, _)| phase)
+            .expect("Cannot estimate a phase from a result without shots")
+    }
+}
+
+/// Returns the matrix of `U` controlled by an additional most significant qubit
+pub(super) fn controlled(unitary: &Matrix<Complex>) -> Matrix<Complex> {
+    let dim = unitary.rows();
+    let mut matrix = Matrix::identity(2 * dim);
+    for row in 0..dim {
+        for col in 0..dim {
+            matrix.set(dim + row, dim + col, *unitary.get(row, col));
+        }
+    }
+    matrix
+}
@@ -0,0 +1,51 @@
+use std::f64::consts::PI;
+
+use crate::QuantumCircuit;
+
+/// Appends the quantum Fourier transform on the given qubits
+/// 
+/// The register is read little-endian: `qubits[0]` holds the least significant bit,
+/// so `|x⟩` is mapped to `Σ_y e^(2πixy/2^n) |y⟩ / √2^n`.
+/// 
+/// # Examples
+/// ```
+/// use intrico::QuantumCircuit;
+/// use intrico::algorithms::{qft, inverse_qft};
+/// use intrico::simulator::Simulator;
+/// 
+/// let mut qc = QuantumCircuit::new(3);
+/// qc.x(1);
+/// qft(&mut qc, &[0, 1, 2]);
+/// inverse_qft(&mut qc, &[0, 1, 2]);
+/// 
+/// let result = Simulator::new().with_circuit(qc).run(10);
+/// assert_eq!(result.counts["010"], 10);
+/// ```
+pub fn qft(circuit: &mut QuantumCircuit, qubits: &[usize]) {
+    let n = qubits.len();
+    for j in (0..n).rev() {
+        circuit.h(qubits[j]);
+        for k in (0..j).rev() {
+            circuit.cp(qubits[k], qubits[j], PI / (1u64 << (j - k)) as f64);
+        }
+    }
+    for i in 0..n / 2 {
+        circuit.swap(qubits[i], qubits[n - 1 - i]);
+    }
+}
+
+/// Appends the inverse quantum Fourier transform on the given qubits
+/// 
+/// Undoes [`qft`] on the same qubits.
+pub fn inverse_qft(circuit: &mut QuantumCircuit, qubits: &[usize]) {
+    let n = qubits.len();
+    for i in (0..n / 2).rev() {
+        circuit.swap(qubits[i], qubits[n - 1 - i]);
+    }
+    for j in 0..n {
+        for k in 0..j {
+            circuit.cp(qubits[k], qubits[j], -PI / (1u64 << (j - k)) as f64);
+        }
+        circuit.h(qubits[j]);
+    }
+}
@@ -0,0 +1,86 @@
+use rusticle::complex::Complex;
+
+use crate::{QuantumCircuit, QuantumGate};
+
+/// Builds a coined discrete-time quantum walk on a cycle of `2^n` positions
+/// 
+/// Qubit 0 is the coin and qubits `1..=n` hold the position, starting at 0 with the
+/// coin in |0⟩. Every step applies the coin gate, then moves the walker one position
+/// up when the coin is |1⟩ and one position down when it is |0⟩, modulo `2^n`. As long
+/// as `steps < 2^(n-1)` the walker never wraps around, so position `2^n - p` stands
+/// for `-p` on an infinite line.
+/// 
+/// # Arguments
+/// * `num_position_qubits` - The number of qubits of the position register
+/// * `steps` - The number of coin flips and shifts
+/// * `coin` - The single-qubit gate flipping the coin, e.g. [`QuantumGate::H`]
+/// 
+/// # Panics
+/// Panics if the position register is empty or the coin is not a single-qubit gate
+/// 
+/// # Examples
+/// ```
+/// use intrico::QuantumGate;
+/// use intrico::algorithms::{position_distribution, quantum_walk};
+/// 
+/// let walk = quantum_walk(3, 1, QuantumGate::H);
+/// let distribution = position_distribution(&walk.execute());
+/// // One step reaches positions +1 and -1 (i.e. 7) with equal probability
+/// assert!((distribution[1] - 0.5).abs() < 1e-6);
+/// assert!((distribution[7] - 0.5).abs() < 1e-6);
+/// ```
+pub fn quantum_walk(num_position_qubits: usize, steps: usize, coin: QuantumGate) -> QuantumCircuit {
+    if num_position_qubits == 0 {
+        panic!("A quantum walk requires at least one position qubit");
+    }
+    if coin.arity() != 1 {
+        panic!("The coin must be a single-qubit gate, got {}", coin.name());
+    }
+
+    let coin_qubit = 0;
+    let position: Vec<usize> = (1..=num_position_qubits).collect();
+    let mut circuit = QuantumCircuit::new(num_position_qubits + 1);
+
+    for _ in 0..steps {
+        circuit.add_gate(coin.clone(), coin_qubit);
+
+        increment(&mut circuit, coin_qubit, &position);
+
+        // Decrementing is incrementing the complement of the position
+        circuit.x(coin_qubit);
+        for &qubit in &position {
+            circuit.x(qubit);
+        }
+        increment(&mut circuit, coin_qubit, &position);
+        for &qubit in &position {
+            circuit.x(qubit);
+        }
+        circuit.x(coin_qubit);
+    }
+
+    circuit
+}
+
+/// Returns the probability of every walker position, summed over the coin
+/// 
+/// # Arguments
+/// * `state` - The statevector of a [`quantum_walk`] circuit
+pub fn position_distribution(state: &[Complex]) -> Vec<f64> {
+    let mut distribution = vec![0.0; state.len() / 2];
+    for (idx, amp) in state.iter().enumerate() {
+        distribution[idx >> 1] += amp.norm_squared();
+    }
+    distribution
+}
+
+/// Adds one to the little-endian register when the control qubit is |1⟩
+/// 
+/// Bit `k` flips when the control and all lower bits are set, starting from the top
+/// bit so that the lower bits still hold their original values.
+fn increment(circuit: &mut QuantumCircuit, control: usize, register: &[usize]) {
+    for k in (0..register.len()).rev() {
+        let mut controls = vec![control];
+        controls.extend(&register[..k]);
+        circuit.mcx(&controls, register[k]);
+    }
+}
@@ -0,0 +1,182 @@
+use rusticle::complex::Complex;
+use rusticle::linalg::Matrix;
+
+use crate::{QuantumCircuit, QuantumGate};
+use crate::simulator::Simulator;
+use super::{phase_estimation, PhaseEstimation};
+
+/// Largest number [`shor`] accepts, keeping the simulation below 20 qubits
+pub const SHOR_MAX_N: u64 = 63;
+
+/// Shots drawn from every order finding circuit
+const ORDER_FINDING_SHOTS: usize = 8;
+
+/// Factors a small composite number with Shor's algorithm on the default simulator
+/// 
+/// Returns the two factors in ascending order, or `None` if `n` is prime or below 4.
+/// Even numbers are split classically.
+/// 
+/// # Panics
+/// Panics if `n` exceeds [`SHOR_MAX_N`]
+/// 
+/// # Examples
+/// ```
+/// use intrico::algorithms::shor;
+/// 
+/// assert_eq!(shor(15), Some((3, 5)));
+/// ```
+pub fn shor(n: u64) -> Option<(u64, u64)> {
+    shor_with(n, &Simulator::new())
+}
+
+/// Factors a small composite number with Shor's algorithm on the given simulator
+/// 
+/// Bases `a = 2, 3, ...` are tried in order. A base sharing a factor with `n` splits it
+/// classically; otherwise the order of `a` modulo `n` is found by phase estimation and
+/// continued fractions, and `gcd(a^(r/2) ± 1, n)` yields the factors.
+/// 
+/// # Panics
+/// Panics if `n` exceeds [`SHOR_MAX_N`]
+pub fn shor_with(n: u64, simulator: &Simulator) -> Option<(u64, u64)> {
+    if n > SHOR_MAX_N {
+        panic!("Shor's algorithm is limited to n <= {} in simulation, got {}", SHOR_MAX_N, n);
+    }
+    if n < 4 || is_prime(n) {
+        return None;
+    }
+    if n.is_multiple_of(2) {
+        return Some((2, n / 2));
+    }
+
+    for a in 2..n {
+        let shared = gcd(a, n);
+        if shared > 1 {
+            return Some(ordered(shared, n / shared));
+        }
+
+        let qpe = order_finding(a, n);
+        let result = simulator.clone().with_circuit(qpe.circuit.clone()).run(ORDER_FINDING_SHOTS);
+        let denominators = qpe.phase_distribution(&result)
+            .into_iter()
+            .filter_map(|(phase, _)| phase_to_order(phase, a, n));
+
+        // Each phase reveals a divisor of the order, their least common multiple the order
+        let order = denominators.fold(1, lcm);
+        if mod_pow(a, order, n) != 1 || !order.is_multiple_of(2) {
+            continue;
+        }
+
+        let half = mod_pow(a, order / 2, n);
+        if half == n - 1 {
+            continue;
+        }
+        for candidate in [gcd(half + n - 1, n), gcd(half + 1, n)] {
+            if candidate > 1 && candidate < n {
+                return Some(ordered(candidate, n / candidate));
+            }
+        }
+    }
+
+    None
+}
+
+/// Builds the order finding circuit for `a` modulo `n`
+/// 
+/// The target register holds `⌈log2(n)⌉` qubits prepared in |1⟩ and the counting register
+/// twice as many, so that the measured phases `s/r` determine the order `r` of `a`.
+/// 
+/// # Panics
+/// Panics if `a` and `n` are not coprime
+pub fn order_finding(a: u64, n: u64) -> PhaseEstimation {
+    if gcd(a, n) != 1 {
+        panic!("{} and {} must be coprime for order finding", a, n);
+    }
+
+    let work_qubits = (u64::BITS - n.leading_zeros()) as usize;
+    let dim = 1usize << work_qubits;
+
+    // Permutation |y⟩ → |a·y mod n⟩, leaving the states y >= n untouched
+    let mut matrix = Matrix::zeros(dim, dim);
+    for y in 0..dim {
+        let image = if (y as u64) < n { (a * y as u64 % n) as usize } else { y };
+        matrix.set(image, y, Complex::new(1.0, 0.0));
+    }
+
+    // The first qubit of a custom gate is the most significant bit of its matrix index
+    let mut multiply = QuantumCircuit::new(work_qubits);
+    let qubits: Vec<usize> = (0..work_qubits).rev().collect();
+    multiply.add_multi_qubit_gate(QuantumGate::Custom(matrix, format!("×{} mod {}", a, n), "U".to_string()), &qubits);
+
+    let mut prepare = QuantumCircuit::new(work_qubits);
+    prepare.x(0);
+
+    phase_estimation(&multiply, 2 * work_qubits).with_preparation(&prepare)
+}
+
+/// Returns the denominator of the best approximation of `phase` with denominator below `n`
+/// 
+/// The continued fraction expansion of the phase is walked until the denominator
+/// reaches `n`. Among the convergents, the smallest denominator `r` with `a^r ≡ 1 (mod n)`
+/// is preferred, otherwise the largest denominator found is returned. Returns `None`
+/// for a zero phase, which carries no information about the order.
+pub fn phase_to_order(phase: f64, a: u64, n: u64) -> Option<u64> {
+    let (mut h_prev, mut h) = (0u64, 1u64);
+    let (mut k_prev, mut k) = (1u64, 0u64);
+    let mut x = phase;
+    let mut best = None;
+
+    for _ in 0..64 {
+        let term = x.floor();
+        let digit = term as u64;
+        (h_prev, h) = (h, digit * h + h_prev);
+        (k_prev, k) = (k, digit * k + k_prev);
+
+        if k >= n {
+            break;
+        }
+        if k > 1 && h > 0 {
+            if mod_pow(a, k, n) == 1 {
+                return Some(k);
+            }
+            best = Some(k);
+        }
+
+        let fraction = x - term;
+        if fraction < 1e-9 {
+            break;
+        }
+        x = 1.0 / fraction;
+    }
+
+    best
+}
+
+fn ordered(a: u64, b: u64) -> (u64, u64) {
+    (a.min(b), a.max(b))
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+fn lcm(a: u64, b: u64) -> u64 {
+    a / gcd(a, b) * b
+}
+
+fn mod_pow(base: u64, exponent: u64, modulus: u64) -> u64 {
+    let mut result = 1 % modulus;
+    let mut base = base % modulus;
+    let mut exponent = exponent;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = result * base % modulus;
+        }
+        base = base * base % modulus;
+        exponent >>= 1;
+    }
+    result
+}
+
+fn is_prime(n: u64) -> bool {
+    n >= 2 && (2..).take_while(|d| d * d <= n).all(|d| !n.is_multiple_of(d))
+}
@@ -0,0 +1,136 @@
+use crate::QuantumCircuit;
+use crate::simulator::SimulationResult;
+use crate::utility::{bitstring_to_index, Endianness};
+
+/// Builds the quantum part of Simon's algorithm for a two-to-one function
+/// 
+/// Qubits `0..n` hold the input register and qubits `n..2n` the output register the
+/// oracle writes `f(x)` into. Every measurement of the input register yields a `y`
+/// with `y · s = 0 (mod 2)` for the hidden period `s`, which [`solve_simon`] recovers.
+/// 
+/// # Arguments
+/// * `num_bits` - The number of input bits `n`
+/// * `oracle` - Appends `|x⟩|0⟩ → |x⟩|f(x)⟩` to the circuit, see [`simon_oracle`]
+/// 
+/// # Examples
+/// ```
+/// use intrico::algorithms::{find_period, simon, simon_oracle};
+/// use intrico::simulator::Simulator;
+/// 
+/// let circuit = simon(3, simon_oracle(3, 0b110));
+/// let result = Simulator::new().with_circuit(circuit).run(100);
+/// assert_eq!(find_period(&result, 3), Some(0b110));
+/// ```
+pub fn simon<F: Fn(&mut QuantumCircuit)>(num_bits: usize, oracle: F) -> QuantumCircuit {
+    let mut circuit = QuantumCircuit::new(2 * num_bits);
+
+    for qubit in 0..num_bits {
+        circuit.h(qubit);
+    }
+    oracle(&mut circuit);
+    for qubit in 0..num_bits {
+        circuit.h(qubit);
+    }
+
+    circuit
+}
+
+/// Returns an oracle for a function with `f(x) = f(x ⊕ secret)`
+/// 
+/// The oracle copies the input into the output register and, for a non-zero secret,
+/// XORs the secret into the output whenever the lowest set bit of the secret is 1.
+/// 
+/// # Panics
+/// The returned oracle panics if the secret does not fit into `num_bits` bits
+pub fn simon_oracle(num_bits: usize, secret: usize) -> impl Fn(&mut QuantumCircuit) {
+    move |circuit: &mut QuantumCircuit| {
+        if secret >> num_bits != 0 {
+            panic!("Secret {:#b} does not fit into {} bits", secret, num_bits);
+        }
+
+        for bit in 0..num_bits {
+            circuit.cnot(bit, num_bits + bit);
+        }
+        if secret != 0 {
+            let pivot = secret.trailing_zeros() as usize;
+            for bit in (0..num_bits).filter(|&bit| secret >> bit & 1 == 1) {
+                circuit.cnot(pivot, num_bits + bit);
+            }
+        }
+    }
+}
+
+/// Recovers the hidden period from samples `y` satisfying `y · s = 0 (mod 2)`
+/// 
+/// Solves the linear system over GF(2) by Gaussian elimination. Returns `Some(0)` if the
+/// samples only admit the trivial solution, i.e. the function is one-to-one, and `None`
+/// if the samples do not determine the period uniquely yet.
+/// 
+/// # Arguments
+/// * `samples` - Measured values of the input register, bit `i` being qubit `i`
+/// * `num_bits` - The number of input bits
+/// 
+/// # Examples
+/// ```
+/// use intrico::algorithms::solve_simon;
+/// 
+/// // Both samples are orthogonal to 0b011 only
+/// assert_eq!(solve_simon(&[0b011, 0b100], 3), Some(0b011));
+/// assert_eq!(solve_simon(&[0b100], 3), None);
+/// ```
+pub fn solve_simon(samples: &[usize], num_bits: usize) -> Option<usize> {
+    // Reduced row echelon form, one row per pivot column
+    let mut rows: Vec<usize> = Vec::new();
+    let mut pivots: Vec<usize> = Vec::new();
+
+    for &sample in samples {
+        let mut row = sample;
+        for (&pivot_row, &pivot) in rows.iter().zip(&pivots) {
+            if row >> pivot & 1 == 1 {
+                row ^= pivot_row;
+            }
+        }
+        if row == 0 {
+            continue;
+        }
+
+        let pivot = row.trailing_zeros() as usize;
+        for existing in rows.iter_mut() {
+            if *existing >> pivot & 1 == 1 {
+                *existing ^= row;
+            }
+        }
+        rows.push(row);
+        pivots.push(pivot);
+    }
+
+    let free: Vec<usize> = (0..num_bits).filter(|bit| !pivots.contains(bit)).collect();
+    match free.as_slice() {
+        [] => Some(0),
+        [free_bit] => {
+            // Set the free variable and solve every pivot variable from its row
+            let mut period = 1 << free_bit;
+            for (&row, &pivot) in rows.iter().zip(&pivots) {
+                if row >> free_bit & 1 == 1 {
+                    period |= 1 << pivot;
+                }
+            }
+            Some(period)
+        },
+        _ => None,
+    }
+}
+
+/// Extracts the measured input values of a Simon run and solves for the period
+/// 
+/// # Arguments
+/// * `result` - The result of running [`simon`]
+/// * `num_bits` - The number of input bits
+pub fn find_period(result: &SimulationResult, num_bits: usize) -> Option<usize> {
+    let inputs: Vec<usize> = (0..num_bits).collect();
+    let samples: Vec<usize> = result.marginal_counts(&inputs)
+        .keys()
+        .map(|bits| bitstring_to_index(bits, Endianness::Little).expect("Counts must be binary bitstrings"))
+        .collect();
+    solve_simon(&samples, num_bits)
+}
@@ -0,0 +1,65 @@
+use crate::QuantumCircuit;
+use crate::simulator::SimulationResult;
+use crate::utility::{bitstring_to_index, Endianness};
+
+/// Builds the superdense coding protocol sending two classical bits with one qubit
+/// 
+/// Qubits 0 (sender) and 1 (receiver) share a Bell pair. The sender encodes the pair
+/// `(b0, b1)` by applying Z if `b0` is set and X if `b1` is set to qubit 0, then sends
+/// it to the receiver, who decodes with a CNOT and a Hadamard. Qubit 0 then reads `b0`
+/// and qubit 1 reads `b1`; see [`decoded_bits`].
+/// 
+/// # Arguments
+/// * `bits` - The two classical bits to send, each 0 or 1
+/// 
+/// # Panics
+/// Panics if either bit is greater than 1
+/// 
+/// # Examples
+/// ```
+/// use intrico::algorithms::{decoded_bits, superdense_coding};
+/// use intrico::simulator::Simulator;
+/// 
+/// let result = Simulator::new().with_circuit(superdense_coding((1, 0))).run(100);
+/// assert_eq!(decoded_bits(&result), (1, 0));
+/// ```
+pub fn superdense_coding(bits: (u8, u8)) -> QuantumCircuit {
+    let (b0, b1) = bits;
+    if b0 > 1 || b1 > 1 {
+        panic!("Superdense coding sends two bits, got ({}, {})", b0, b1);
+    }
+
+    let mut circuit = QuantumCircuit::new(2);
+
+    // Shared Bell pair
+    circuit.h(0);
+    circuit.cnot(0, 1);
+
+    // Encoding on the sender's qubit
+    if b1 == 1 {
+        circuit.x(0);
+    }
+    if b0 == 1 {
+        circuit.z(0);
+    }
+
+    // Decoding by the receiver
+    circuit.cnot(0, 1);
+    circuit.h(0);
+
+    circuit
+}
+
+/// Returns the two bits decoded by the receiver in most shots of a superdense coding run
+/// 
+/// # Arguments
+/// * `result` - The result of running [`superdense_coding`]
+/// 
+/// # Panics
+/// Panics if the result holds no counts
+pub fn decoded_bits(result: &SimulationResult) -> (u8, u8) {
+    let (bitstring, _) = result.most_frequent()
+        .expect("Cannot decode bits from a result without counts");
+    let value = bitstring_to_index(&bitstring, Endianness::Little).unwrap_or_default();
+    ((value & 1) as u8, ((value >> 1) & 1) as u8)
+}
@@ -0,0 +1,92 @@
+use rusticle::complex::Complex;
+
+use crate::QuantumCircuit;
+
+/// Builds the quantum teleportation protocol over three qubits
+/// 
+/// Qubit 0 holds the state to send, and qubits 1 and 2 share a Bell pair. The sender
+/// measures qubits 0 and 1 into classical bits 0 and 1, and the receiver applies the
+/// X and Z corrections conditioned on them, leaving the original state on qubit 2.
+/// 
+/// # Examples
+/// ```
+/// use intrico::algorithms::teleportation;
+/// 
+/// let qc = teleportation();
+/// assert_eq!(qc.num_qubits(), 3);
+/// assert_eq!(qc.num_classical_bits(), 2);
+/// ```
+pub fn teleportation() -> QuantumCircuit {
+    let mut circuit = QuantumCircuit::new(3);
+
+    // Shared Bell pair between the sender (qubit 1) and the receiver (qubit 2)
+    circuit.h(1);
+    circuit.cnot(1, 2);
+
+    // Bell measurement of the sender's qubits
+    circuit.cnot(0, 1);
+    circuit.h(0);
+    circuit.measure(0, 0);
+    circuit.measure(1, 1);
+
+    // Corrections on the receiver's qubit
+    circuit.x(2);
+    circuit.c_if(1, true);
+    circuit.z(2);
+    circuit.c_if(0, true);
+
+    circuit
+}
+
+/// Builds the teleportation protocol for the state prepared by a single-qubit circuit
+/// 
+/// The preparation is applied to qubit 0 before [`teleportation`].
+/// 
+/// # Panics
+/// Panics if the preparation circuit does not act on exactly one qubit
+/// 
+/// # Examples
+/// ```
+/// use intrico::QuantumCircuit;
+/// use intrico::algorithms::{received_state, teleport};
+/// use intrico::simulator::Simulator;
+/// use intrico::utility::state_fidelity;
+/// 
+/// let mut preparation = QuantumCircuit::new(1);
+/// preparation.ry(0, 1.2);
+/// preparation.rz(0, 0.4);
+/// 
+/// let result = Simulator::new().with_circuit(teleport(&preparation)).run(100);
+/// let expected = preparation.execute();
+/// assert!((state_fidelity(&received_state(&result.final_state), &expected) - 1.0).abs() < 1e-6);
+/// ```
+pub fn teleport(preparation: &QuantumCircuit) -> QuantumCircuit {
+    if preparation.num_qubits() != 1 {
+        panic!("Teleportation sends a single qubit, but the preparation acts on {} qubits",
+               preparation.num_qubits());
+    }
+
+    let mut circuit = QuantumCircuit::new(3);
+    circuit.compose(preparation, &[0]);
+    circuit.compose(&teleportation(), &[0, 1, 2]);
+    circuit
+}
+
+/// Extracts the single-qubit state received on qubit 2 after teleportation
+/// 
+/// The measured qubits 0 and 1 are in a basis state, so the amplitudes of qubit 2 are
+/// read off the only populated pair of entries.
+/// 
+/// # Panics
+/// Panics if the statevector does not describe three qubits
+pub fn received_state(final_state: &[Complex]) -> Vec<Complex> {
+    if final_state.len() != 8 {
+        panic!("Expected a 3-qubit statevector, found {} amplitudes", final_state.len());
+    }
+
+    (0..2)
+        .map(|value| final_state[value << 2..(value + 1) << 2]
+            .iter()
+            .fold(Complex::new(0.0, 0.0), |sum, &amp| sum + amp))
+        .collect()
+}
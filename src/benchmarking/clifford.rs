@@ -0,0 +1,121 @@
+use std::collections::{HashMap, VecDeque};
+
+use rusticle::complex::Complex;
+use rusticle::linalg::Matrix;
+
+use crate::QuantumCircuit;
+
+/// The Clifford group on one or two qubits, with a lookup of elements by unitary
+#[derive(Debug, Clone)]
+pub(crate) struct CliffordGroup {
+    /// Every element as a circuit of H, S and CNOT gates, together with its unitary
+    elements: Vec<(QuantumCircuit, Matrix<Complex>)>,
+    /// Element index of every unitary, keyed up to a global phase
+    index: HashMap<Vec<(i64, i64)>, usize>,
+}
+
+impl CliffordGroup {
+    /// Enumerates the group by breadth-first search over the H, S and CNOT generators
+    /// 
+    /// Every element is reached through a shortest sequence of generators.
+    /// 
+    /// # Panics
+    /// Panics if the number of qubits is not 1 or 2
+    pub(crate) fn new(num_qubits: usize) -> Self {
+        let generators: Vec<fn(&mut QuantumCircuit)> = match num_qubits {
+            1 => vec![|c| c.h(0), |c| c.s(0)],
+            2 => vec![|c| c.h(0), |c| c.h(1), |c| c.s(0), |c| c.s(1), |c| c.cnot(0, 1)],
+            _ => panic!("Clifford groups are only available on 1 or 2 qubits, got {}", num_qubits),
+        };
+        let generator_unitaries: Vec<Matrix<Complex>> = generators.iter()
+            .map(|apply| {
+                let mut circuit = QuantumCircuit::new(num_qubits);
+                apply(&mut circuit);
+                circuit.unitary()
+            })
+            .collect();
+
+        let identity = QuantumCircuit::new(num_qubits);
+        let unitary = identity.unitary();
+        let mut group = CliffordGroup {
+            index: HashMap::from([(phase_key(&unitary), 0)]),
+            elements: vec![(identity, unitary)],
+        };
+
+        let mut queue = VecDeque::from([0]);
+        while let Some(current) = queue.pop_front() {
+            for (apply, generator) in generators.iter().zip(&generator_unitaries) {
+                let unitary = generator * &group.elements[current].1;
+                let key = phase_key(&unitary);
+                if group.index.contains_key(&key) {
+                    continue;
+                }
+
+                let mut circuit = group.elements[current].0.clone();
+                apply(&mut circuit);
+                group.index.insert(key, group.elements.len());
+                queue.push_back(group.elements.len());
+                group.elements.push((circuit, unitary));
+            }
+        }
+        group
+    }
+
+    /// Returns the number of elements
+    pub(crate) fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    /// Returns the circuit and unitary of an element
+    pub(crate) fn element(&self, index: usize) -> &(QuantumCircuit, Matrix<Complex>) {
+        &self.elements[index]
+    }
+
+    /// Returns the index of the element equal to the unitary up to a global phase
+    pub(crate) fn find(&self, unitary: &Matrix<Complex>) -> Option<usize> {
+        self.index.get(&phase_key(unitary)).copied()
+    }
+}
+
+/// Returns the circuits of every element of the Clifford group on one or two qubits
+/// 
+/// The group has 24 elements on one qubit and 11520 on two, up to a global phase. Each
+/// element is given as a shortest circuit of H, S and CNOT gates.
+/// 
+/// # Panics
+/// Panics if the number of qubits is not 1 or 2
+/// 
+/// # Examples
+/// ```
+/// use intrico::benchmarking::clifford_group;
+/// 
+/// let group = clifford_group(1);
+/// assert_eq!(group.len(), 24);
+/// assert_eq!(group[0].num_operations(), 0);
+/// ```
+pub fn clifford_group(num_qubits: usize) -> Vec<QuantumCircuit> {
+    CliffordGroup::new(num_qubits).elements.into_iter()
+        .map(|(circuit, _)| circuit)
+        .collect()
+}
+
+/// Rounds the entries of a unitary after removing the phase of its first non-zero entry
+fn phase_key(unitary: &Matrix<Complex>) -> Vec<(i64, i64)> {
+    let entries: Vec<Complex> = (0..unitary.rows())
+        .flat_map(|r| (0..unitary.cols()).map(move |c| (r, c)))
+        .map(|(r, c)| *unitary.get(r, c))
+        .collect();
+
+    let pivot = entries.iter()
+        .find(|entry| entry.norm() > 1e-6)
+        .copied()
+        .unwrap_or(Complex::new(1.0, 0.0));
+    let phase = pivot.conjugate() / pivot.norm();
+
+    entries.into_iter()
+        .map(|entry| {
+            let rotated = entry * phase;
+            ((rotated.real * 1e6).round() as i64, (rotated.imag * 1e6).round() as i64)
+        })
+        .collect()
+}
@@ -0,0 +1,13 @@
+//! Benchmarking module
+//! 
+//! This module provides the experiments used to characterize quantum processors, such
+//! as randomized benchmarking and quantum volume. They run on a [`Simulator`](crate::simulator::Simulator)
+//! so that simulated noise models can be characterized the same way hardware is.
+
+mod clifford;
+mod quantum_volume;
+mod randomized_benchmarking;
+
+pub use clifford::clifford_group;
+pub use quantum_volume::{quantum_volume, QuantumVolume, QVResult};
+pub use randomized_benchmarking::{fit_decay, try_fit_decay, DecayFit, RandomizedBenchmarking, RBResult};
@@ -0,0 +1,157 @@
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use rand::seq::SliceRandom;
+
+use crate::{QuantumCircuit, QuantumGate};
+use crate::simulator::{fresh_seed, Simulator};
+use crate::utility::random_unitary;
+use crate::utility::{index_to_bitstring, Endianness};
+
+/// A quantum volume experiment over the widths `2..=n`
+/// 
+/// The model circuit of width `m` has `m` layers, each of which applies Haar-random
+/// SU(4) gates to the qubit pairs of a random permutation. The heavy outputs of a model
+/// circuit are the bitstrings whose ideal probability exceeds the median. A width
+/// passes when the measured heavy output probability stays above 2/3 by two standard
+/// deviations, and the quantum volume is `2^m` for the largest width `m` such that
+/// every width up to `m` passes.
+#[derive(Debug, Clone)]
+pub struct QuantumVolume {
+    max_width: usize,
+    trials: usize,
+    shots: usize,
+    simulator: Simulator,
+    seed: Option<u64>,
+}
+
+/// Outcome of a [`QuantumVolume`] experiment
+#[derive(Debug, Clone, PartialEq)]
+pub struct QVResult {
+    /// The tested widths, starting at 2
+    pub widths: Vec<usize>,
+    /// The measured heavy output probability of every width, averaged over the trials
+    pub heavy_output_probabilities: Vec<f64>,
+    /// Whether every width passed the two-sigma test
+    pub passed: Vec<bool>,
+    /// The achieved quantum volume, 1 if no width passed
+    pub quantum_volume: usize,
+}
+
+/// Creates a quantum volume experiment on up to `n` qubits with `trials` circuits per width
+/// 
+/// # Panics
+/// Panics if `n` is smaller than 2 or no trial is requested
+/// 
+/// # Examples
+/// ```
+/// use intrico::benchmarking::quantum_volume;
+/// use intrico::simulator::Simulator;
+/// 
+/// let result = quantum_volume(3, 100)
+///     .with_simulator(Simulator::new().with_seed(1))
+///     .with_seed(1)
+///     .run();
+/// assert_eq!(result.quantum_volume, 8);
+/// ```
+pub fn quantum_volume(n: usize, trials: usize) -> QuantumVolume {
+    if n < 2 {
+        panic!("Quantum volume requires at least 2 qubits, got {}", n);
+    }
+    if trials == 0 {
+        panic!("Quantum volume requires at least one trial");
+    }
+
+    QuantumVolume {
+        max_width: n,
+        trials,
+        shots: 100,
+        simulator: Simulator::new(),
+        seed: None,
+    }
+}
+
+impl QuantumVolume {
+    /// Sets the number of shots per model circuit, 100 by default
+    pub fn with_shots(mut self, shots: usize) -> Self {
+        self.shots = shots;
+        self
+    }
+
+    /// Sets the simulator, and with it the noise model, used to run the model circuits
+    pub fn with_simulator(mut self, simulator: Simulator) -> Self {
+        self.simulator = simulator;
+        self
+    }
+
+    /// Seeds the random choice of the model circuits
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Builds a random model circuit of the given width
+    pub fn model_circuit<R: Rng + ?Sized>(&self, width: usize, rng: &mut R) -> QuantumCircuit {
+        let mut circuit = QuantumCircuit::new(width);
+        let mut qubits: Vec<usize> = (0..width).collect();
+
+        for _ in 0..width {
+            qubits.shuffle(rng);
+            for pair in qubits.chunks_exact(2) {
+                let gate = QuantumGate::Custom(random_unitary(4, rng), "SU(4)".to_string(), "SU4".to_string());
+                circuit.add_multi_qubit_gate(gate, pair);
+            }
+        }
+        circuit
+    }
+
+    /// Runs the model circuits of every width and determines the quantum volume
+    pub fn run(&self) -> QVResult {
+        let mut rng = StdRng::seed_from_u64(self.seed.unwrap_or_else(fresh_seed));
+        let widths: Vec<usize> = (2..=self.max_width).collect();
+
+        let heavy_output_probabilities: Vec<f64> = widths.iter()
+            .map(|&width| {
+                let circuits: Vec<QuantumCircuit> = (0..self.trials)
+                    .map(|_| self.model_circuit(width, &mut rng))
+                    .collect();
+                let heavy: usize = self.simulator.run_batch(&circuits, self.shots)
+                    .iter()
+                    .zip(&circuits)
+                    .map(|(result, circuit)| {
+                        let heavy_outputs = heavy_outputs(circuit);
+                        result.counts.iter()
+                            .filter(|(bitstring, _)| heavy_outputs.contains(bitstring))
+                            .map(|(_, count)| count)
+                            .sum::<usize>()
+                    })
+                    .sum();
+                heavy as f64 / (self.trials * self.shots) as f64
+            })
+            .collect();
+
+        let passed: Vec<bool> = heavy_output_probabilities.iter()
+            .map(|&h| h - 2.0 * (h * (1.0 - h) / self.trials as f64).sqrt() > 2.0 / 3.0)
+            .collect();
+        let quantum_volume = widths.iter()
+            .zip(&passed)
+            .take_while(|&(_, &pass)| pass)
+            .last()
+            .map_or(1, |(&width, _)| 1 << width);
+
+        QVResult { widths, heavy_output_probabilities, passed, quantum_volume }
+    }
+}
+
+/// Returns the bitstrings whose ideal probability exceeds the median
+fn heavy_outputs(circuit: &QuantumCircuit) -> Vec<String> {
+    let probabilities: Vec<f64> = circuit.execute().iter().map(|amp| amp.norm_squared()).collect();
+    let mut sorted = probabilities.clone();
+    sorted.sort_by(f64::total_cmp);
+    let middle = sorted.len() / 2;
+    let median = (sorted[middle - 1] + sorted[middle]) / 2.0;
+
+    probabilities.iter()
+        .enumerate()
+        .filter(|&(_, &p)| p > median)
+        .map(|(index, _)| index_to_bitstring(index, circuit.num_qubits(), Endianness::Little))
+        .collect()
+}
@@ -0,0 +1,265 @@
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+use crate::QuantumCircuit;
+use crate::simulator::{fresh_seed, Simulator};
+use super::clifford::CliffordGroup;
+
+/// A standard randomized benchmarking experiment on one or two qubits
+/// 
+/// Each sequence applies `m` Clifford gates drawn uniformly at random, followed by the
+/// single Clifford inverting their product, so that a noiseless sequence returns to
+/// |0…0⟩. Averaging the survival probability over random sequences turns any noise into
+/// a depolarizing channel, and fitting `F(m) = A·pᵐ + B` yields the error per Clifford
+/// `r = (1 - p)(d - 1)/d` with `d = 2ⁿ`.
+/// 
+/// # Examples
+/// ```
+/// use intrico::benchmarking::RandomizedBenchmarking;
+/// use intrico::simulator::{NoiseModel, Simulator};
+/// 
+/// let simulator = Simulator::new()
+///     .with_noise(NoiseModel::depolarizing(0.01, 0.0))
+///     .with_seed(3);
+/// let rb = RandomizedBenchmarking::new(1)
+///     .with_lengths(&[1, 10, 20, 50, 100])
+///     .with_simulator(simulator)
+///     .with_seed(3);
+/// 
+/// let result = rb.run();
+/// let error = result.error_per_clifford.unwrap();
+/// assert!(error > 0.002 && error < 0.05);
+/// ```
+#[derive(Debug, Clone)]
+pub struct RandomizedBenchmarking {
+    num_qubits: usize,
+    lengths: Vec<usize>,
+    num_sequences: usize,
+    shots: usize,
+    simulator: Simulator,
+    seed: Option<u64>,
+    group: CliffordGroup,
+}
+
+/// Outcome of a [`RandomizedBenchmarking`] run
+#[derive(Debug, Clone, PartialEq)]
+pub struct RBResult {
+    /// The sequence lengths
+    pub lengths: Vec<usize>,
+    /// The survival probability averaged over the sequences of every length
+    pub survival: Vec<f64>,
+    /// The exponential decay fitted to the survival probabilities, or `None` if fewer
+    /// than two of them lie above the fully mixed value `1/2ⁿ`
+    pub fit: Option<DecayFit>,
+    /// The average error per Clifford gate, or `None` if no decay could be fitted
+    pub error_per_clifford: Option<f64>,
+}
+
+/// An exponential decay `F(m) = A·pᵐ + B`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DecayFit {
+    /// The amplitude `A`, absorbing state preparation and measurement errors
+    pub amplitude: f64,
+    /// The decay parameter `p`
+    pub decay: f64,
+    /// The asymptote `B`
+    pub offset: f64,
+}
+
+impl RandomizedBenchmarking {
+    /// Creates an experiment on one or two qubits
+    /// 
+    /// Defaults to the lengths 1, 2, 4, …, 64 with 10 sequences of 100 shots each, run
+    /// on a noiseless simulator.
+    /// 
+    /// # Panics
+    /// Panics if the number of qubits is not 1 or 2
+    pub fn new(num_qubits: usize) -> Self {
+        RandomizedBenchmarking {
+            num_qubits,
+            lengths: vec![1, 2, 4, 8, 16, 32, 64],
+            num_sequences: 10,
+            shots: 100,
+            simulator: Simulator::new(),
+            seed: None,
+            group: CliffordGroup::new(num_qubits),
+        }
+    }
+
+    /// Sets the numbers of random Clifford gates per sequence
+    /// 
+    /// # Panics
+    /// Panics if fewer than two lengths are given
+    pub fn with_lengths(mut self, lengths: &[usize]) -> Self {
+        if lengths.len() < 2 {
+            panic!("Randomized benchmarking requires at least two sequence lengths");
+        }
+        self.lengths = lengths.to_vec();
+        self
+    }
+
+    /// Sets the number of random sequences drawn for every length
+    pub fn with_sequences(mut self, num_sequences: usize) -> Self {
+        self.num_sequences = num_sequences;
+        self
+    }
+
+    /// Sets the number of shots per sequence
+    pub fn with_shots(mut self, shots: usize) -> Self {
+        self.shots = shots;
+        self
+    }
+
+    /// Sets the simulator, and with it the noise model, used to run the sequences
+    pub fn with_simulator(mut self, simulator: Simulator) -> Self {
+        self.simulator = simulator;
+        self
+    }
+
+    /// Seeds the random choice of the sequences
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Returns the number of elements of the Clifford group the gates are drawn from
+    pub fn num_cliffords(&self) -> usize {
+        self.group.len()
+    }
+
+    /// Builds a random sequence of `length` Clifford gates followed by their inverse
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::benchmarking::RandomizedBenchmarking;
+    /// use intrico::simulator::Simulator;
+    /// 
+    /// let rb = RandomizedBenchmarking::new(2);
+    /// let sequence = rb.sequence(5, &mut rand::rng());
+    /// 
+    /// let result = Simulator::new().with_circuit(sequence).run(10);
+    /// assert_eq!(result.counts["00"], 10);
+    /// ```
+    pub fn sequence<R: Rng + ?Sized>(&self, length: usize, rng: &mut R) -> QuantumCircuit {
+        let qubits: Vec<usize> = (0..self.num_qubits).collect();
+        let mut circuit = QuantumCircuit::new(self.num_qubits);
+        let mut product = circuit.unitary();
+
+        for _ in 0..length {
+            let (clifford, unitary) = self.group.element(rng.random_range(0..self.group.len()));
+            circuit.compose(clifford, &qubits);
+            product = unitary * &product;
+        }
+
+        let inverse = self.group.find(&product.conjugate_transpose())
+            .expect("The Clifford group is closed under inversion");
+        circuit.compose(&self.group.element(inverse).0, &qubits);
+        circuit
+    }
+
+    /// Runs every sequence and fits the decay of the survival probability
+    /// 
+    /// With heavy noise or long sequences most survival probabilities may reach the fully
+    /// mixed value, leaving too few points to fit. The survival probabilities are then
+    /// still returned, with the fit and the error per Clifford left out.
+    pub fn run(&self) -> RBResult {
+        let mut rng = StdRng::seed_from_u64(self.seed.unwrap_or_else(fresh_seed));
+        let ground = "0".repeat(self.num_qubits);
+
+        let survival: Vec<f64> = self.lengths.iter()
+            .map(|&length| {
+                let circuits: Vec<QuantumCircuit> = (0..self.num_sequences)
+                    .map(|_| self.sequence(length, &mut rng))
+                    .collect();
+                let survived: usize = self.simulator.run_batch(&circuits, self.shots)
+                    .iter()
+                    .map(|result| result.counts.get(&ground).copied().unwrap_or(0))
+                    .sum();
+                survived as f64 / (self.num_sequences * self.shots) as f64
+            })
+            .collect();
+
+        let dim = (1 << self.num_qubits) as f64;
+        let fit = try_fit_decay(&self.lengths, &survival, 1.0 / dim);
+        RBResult {
+            lengths: self.lengths.clone(),
+            survival,
+            fit,
+            error_per_clifford: fit.map(|fit| (1.0 - fit.decay) * (dim - 1.0) / dim),
+        }
+    }
+}
+
+impl DecayFit {
+    /// Evaluates the decay at sequence length `m`
+    pub fn value(&self, m: usize) -> f64 {
+        self.amplitude * self.decay.powi(m as i32) + self.offset
+    }
+}
+
+/// Fits `F(m) = A·pᵐ + B` to survival probabilities for a known asymptote `B`
+/// 
+/// The fit is a least-squares line through `ln(F(m) - B)`. With the asymptote fixed to
+/// the fully mixed value `1/2ⁿ`, this is the standard fit for randomized benchmarking.
+/// Points at or below the asymptote carry no information on the decay and are skipped.
+/// 
+/// # Panics
+/// Panics if the inputs differ in length or fewer than two distinct lengths have a
+/// survival probability above the asymptote; see [`try_fit_decay`]
+/// 
+/// # Examples
+/// ```
+/// use intrico::benchmarking::fit_decay;
+/// 
+/// let lengths = [1, 5, 10, 20];
+/// let survival: Vec<f64> = lengths.iter().map(|&m| 0.45 * 0.98_f64.powi(m as i32) + 0.5).collect();
+/// 
+/// let fit = fit_decay(&lengths, &survival, 0.5);
+/// assert!((fit.decay - 0.98).abs() < 1e-9);
+/// assert!((fit.amplitude - 0.45).abs() < 1e-9);
+/// ```
+pub fn fit_decay(lengths: &[usize], survival: &[f64], offset: f64) -> DecayFit {
+    try_fit_decay(lengths, survival, offset).unwrap_or_else(|| {
+        panic!("Fitting a decay requires survival probabilities above {} at two distinct sequence lengths", offset)
+    })
+}
+
+/// Fits `F(m) = A·pᵐ + B` like [`fit_decay`], returning `None` instead of panicking when
+/// fewer than two distinct lengths have a survival probability above the asymptote
+/// 
+/// # Panics
+/// Panics if the inputs differ in length
+/// 
+/// # Examples
+/// ```
+/// use intrico::benchmarking::try_fit_decay;
+/// 
+/// // Survival has decayed to the fully mixed value after the first length
+/// assert!(try_fit_decay(&[1, 50, 100], &[0.9, 0.5, 0.49], 0.5).is_none());
+/// ```
+pub fn try_fit_decay(lengths: &[usize], survival: &[f64], offset: f64) -> Option<DecayFit> {
+    if lengths.len() != survival.len() {
+        panic!("Got {} lengths but {} survival probabilities", lengths.len(), survival.len());
+    }
+    let points: Vec<(f64, f64)> = lengths.iter()
+        .zip(survival)
+        .filter(|&(_, &f)| f - offset > 1e-12)
+        .map(|(&m, &f)| (m as f64, (f - offset).ln()))
+        .collect();
+
+    let count = points.len() as f64;
+    let mean_m = points.iter().map(|(m, _)| m).sum::<f64>() / count;
+    let mean_y = points.iter().map(|(_, y)| y).sum::<f64>() / count;
+    let covariance: f64 = points.iter().map(|(m, y)| (m - mean_m) * (y - mean_y)).sum();
+    let variance: f64 = points.iter().map(|(m, _)| (m - mean_m).powi(2)).sum();
+    // A single point, or points at one length only, do not determine a slope
+    if points.len() < 2 || variance == 0.0 {
+        return None;
+    }
+
+    let slope = covariance / variance;
+    Some(DecayFit {
+        amplitude: (mean_y - slope * mean_m).exp(),
+        decay: slope.exp(),
+        offset,
+    })
+}
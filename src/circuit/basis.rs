@@ -0,0 +1,56 @@
+/// A single-qubit Pauli measurement basis
+/// 
+/// Measuring in a basis rotates its eigenstates onto |0⟩ and |1⟩ before a computational
+/// measurement, so a `0` outcome stands for the +1 eigenstate (|0⟩, |+⟩ or |+i⟩) and a
+/// `1` outcome for the -1 eigenstate (|1⟩, |-⟩ or |-i⟩).
+/// 
+/// # Examples
+/// ```
+/// use intrico::circuit::Basis;
+/// 
+/// assert_eq!(Basis::from_pauli('Y'), Some(Basis::Y));
+/// assert_eq!(Basis::X.pauli(), 'X');
+/// assert_eq!(Basis::X.eigenstate(true), "-");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Basis {
+    /// Eigenbasis of Pauli-X: |+⟩ and |-⟩
+    X,
+    /// Eigenbasis of Pauli-Y: |+i⟩ and |-i⟩
+    Y,
+    /// Computational basis, the eigenbasis of Pauli-Z: |0⟩ and |1⟩
+    Z,
+}
+
+impl Basis {
+    /// Returns the basis of a Pauli operator character, `None` for other characters
+    pub fn from_pauli(pauli: char) -> Option<Basis> {
+        match pauli {
+            'X' => Some(Basis::X),
+            'Y' => Some(Basis::Y),
+            'Z' => Some(Basis::Z),
+            _ => None,
+        }
+    }
+
+    /// Returns the Pauli operator whose eigenbasis this is
+    pub fn pauli(&self) -> char {
+        match self {
+            Basis::X => 'X',
+            Basis::Y => 'Y',
+            Basis::Z => 'Z',
+        }
+    }
+
+    /// Returns the label of the eigenstate behind an outcome: `0`/`1`, `+`/`-` or `+i`/`-i`
+    pub fn eigenstate(&self, outcome: bool) -> &'static str {
+        match (self, outcome) {
+            (Basis::X, false) => "+",
+            (Basis::X, true) => "-",
+            (Basis::Y, false) => "+i",
+            (Basis::Y, true) => "-i",
+            (Basis::Z, false) => "0",
+            (Basis::Z, true) => "1",
+        }
+    }
+}
@@ -1,13 +1,16 @@
-use std::{cmp, fmt};
-use rusticle::complex::{Complex, ComplexVector};
+use std::{cmp, collections::HashMap, fmt, ops::Index};
+use rusticle::complex::Complex;
+use rusticle::linalg::Matrix;
 
-use crate::{core::gate::{GateOp, QuantumGate}, utility::round_if_close};
+use crate::{core::{gate::{GateOp, QuantumGate}, Amplitude, ClassicalOp, NoiseChannel}, trace::span, utility::Tolerance, IntricoError};
+use super::{Basis, CircuitError, StateVector};
 
 /// Represents a quantum circuit that can be built and executed
 /// 
 /// A quantum circuit is a sequence of quantum gates applied to one or more qubits.
 /// This implementation allows for building circuits incrementally and executing them
 /// on a set of qubits.
+#[derive(Clone)]
 pub struct QuantumCircuit {
     /// The number of qubits in the circuit
     num_qubits: usize,
@@ -17,6 +20,12 @@ pub struct QuantumCircuit {
     classical_bits: Vec<u8>,
     /// Last step of the qubit (for step calculation)
     last_step: Vec<usize>,
+    /// Composite gates defined with [`QuantumCircuit::define_gate`], by name
+    definitions: HashMap<String, QuantumGate>,
+    /// Labels set with [`QuantumCircuit::label_qubit`], by qubit
+    qubit_labels: HashMap<usize, String>,
+    /// Labels set with [`QuantumCircuit::label_clbit`], by classical bit
+    clbit_labels: HashMap<usize, String>,
 }
 
 impl QuantumCircuit {
@@ -37,9 +46,44 @@ impl QuantumCircuit {
             operations: Vec::new(),
             classical_bits: Vec::with_capacity(num_qubits),
             last_step: vec![0; num_qubits],
+            definitions: HashMap::new(),
+            qubit_labels: HashMap::new(),
+            clbit_labels: HashMap::new(),
         }
     }
 
+    /// Creates a circuit starting from a computational basis state instead of |0...0⟩
+    /// 
+    /// The circuit has one qubit per character and begins with an X gate on every qubit
+    /// set to 1. Qubit 0 is the rightmost character, as in the keys of the counts, so
+    /// an oracle can be tested on many basis inputs by looping over bitstrings.
+    /// 
+    /// # Arguments
+    /// * `bitstring` - The initial basis state, e.g. `"0110"`
+    /// 
+    /// # Panics
+    /// Panics if the bitstring contains characters other than `0` and `1`
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// 
+    /// let qc = QuantumCircuit::with_initial_bitstring("0110");
+    /// assert_eq!(qc.num_qubits(), 4);
+    /// assert_eq!(qc.execute().probabilities()[0b0110], 1.0);
+    /// ```
+    pub fn with_initial_bitstring(bitstring: &str) -> Self {
+        let mut circuit = QuantumCircuit::new(bitstring.chars().count());
+        for (qubit, bit) in bitstring.chars().rev().enumerate() {
+            match bit {
+                '0' => {},
+                '1' => circuit.x(qubit),
+                _ => panic!("Initial bitstrings can only contain 0 and 1, got {:?}", bitstring),
+            }
+        }
+        circuit
+    }
+
     /// Applies a Hadamard gate to the specified qubit
     /// 
     /// # Arguments
@@ -136,6 +180,38 @@ impl QuantumCircuit {
         self.add_gate(QuantumGate::T, target);
     }
 
+    /// Applies an S† gate to the specified qubit
+    /// 
+    /// # Arguments
+    /// * `target` - The index of the qubit to apply the gate to
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// 
+    /// let mut qc = QuantumCircuit::new(1);
+    /// qc.sdg(0);  // Apply S† gate to the first qubit
+    /// ```
+    pub fn sdg(&mut self, target: usize) {
+        self.add_gate(QuantumGate::Sdg, target);
+    }
+
+    /// Applies a T† gate to the specified qubit
+    /// 
+    /// # Arguments
+    /// * `target` - The index of the qubit to apply the gate to
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// 
+    /// let mut qc = QuantumCircuit::new(1);
+    /// qc.tdg(0);  // Apply T† gate to the first qubit
+    /// ```
+    pub fn tdg(&mut self, target: usize) {
+        self.add_gate(QuantumGate::Tdg, target);
+    }
+
     /// Applies a CNOT gate with the specified control and target qubits
     /// 
     /// # Arguments
@@ -166,7 +242,6 @@ impl QuantumCircuit {
     /// let mut qc = QuantumCircuit::new(2);
     /// qc.cx(0, 1);  // Apply CNOT gate with control qubit 0 and target qubit 1
     /// ```
-
     pub fn cx(&mut self, control: usize, target: usize) {
         self.cnot(control, target);
     }
@@ -190,251 +265,1482 @@ impl QuantumCircuit {
         self.add_controlled_gate(QuantumGate::CZ, control, target);
     }
 
-    /// Applies a Rx gate to the specified qubit
+    /// Applies a controlled Rx rotation to the target when the control is |1⟩
     /// 
     /// # Arguments
-    /// * `target` - The index of the qubit to apply the gate to
-    /// * `angle` - The angle of the Rx gate
+    /// * `control` - The index of the control qubit
+    /// * `target` - The index of the target qubit
+    /// * `angle` - The rotation angle θ
     /// 
     /// # Examples
     /// ```
     /// use intrico::QuantumCircuit;
     /// 
-    /// let mut qc = QuantumCircuit::new(1);
-    /// qc.rx(0, std::f64::consts::PI / 2.0);  // Apply Rx gate to the first qubit with angle π/2
+    /// let mut qc = QuantumCircuit::new(2);
+    /// qc.x(0);
+    /// qc.crx(0, 1, std::f64::consts::PI);  // Flips the target
+    /// 
+    /// assert!((qc.execute().probabilities()[3] - 1.0).abs() < 1e-6);
     /// ```
-    pub fn rx(&mut self, target: usize, angle: f64) {
-        self.add_gate(QuantumGate::Rx(angle), target);
+    pub fn crx(&mut self, control: usize, target: usize, angle: f64) {
+        self.add_controlled_gate(QuantumGate::CRx(angle), control, target);
     }
 
-    /// Applies a Ry gate to the specified qubit
+    /// Applies a controlled Ry rotation to the target when the control is |1⟩
     /// 
     /// # Arguments
-    /// * `target` - The index of the qubit to apply the gate to
-    /// * `angle` - The angle of the Ry gate
+    /// * `control` - The index of the control qubit
+    /// * `target` - The index of the target qubit
+    /// * `angle` - The rotation angle θ
     /// 
     /// # Examples
     /// ```
     /// use intrico::QuantumCircuit;
     /// 
-    /// let mut qc = QuantumCircuit::new(1);
-    /// qc.ry(0, std::f64::consts::PI / 2.0);  // Apply Ry gate to the first qubit with angle π/2
+    /// let mut qc = QuantumCircuit::new(2);
+    /// qc.cry(0, 1, std::f64::consts::FRAC_PI_2);  // Control is |0⟩, nothing happens
+    /// 
+    /// assert!((qc.execute().probabilities()[0] - 1.0).abs() < 1e-6);
     /// ```
-    pub fn ry(&mut self, target: usize, angle: f64) {
-        self.add_gate(QuantumGate::Ry(angle), target);
+    pub fn cry(&mut self, control: usize, target: usize, angle: f64) {
+        self.add_controlled_gate(QuantumGate::CRy(angle), control, target);
     }
 
-    /// Applies a Rz gate to the specified qubit
+    /// Applies a controlled Rz rotation to the target when the control is |1⟩
+    /// 
+    /// Unlike [`QuantumCircuit::cp`], the rotation also changes the phase of the target's
+    /// |0⟩ state, by `e^(-iθ/2)`.
     /// 
     /// # Arguments
-    /// * `target` - The index of the qubit to apply the gate to
-    /// * `angle` - The angle of the Rz gate
+    /// * `control` - The index of the control qubit
+    /// * `target` - The index of the target qubit
+    /// * `angle` - The rotation angle θ
     /// 
     /// # Examples
     /// ```
     /// use intrico::QuantumCircuit;
     /// 
-    /// let mut qc = QuantumCircuit::new(1);
-    /// qc.rz(0, std::f64::consts::PI / 2.0);  // Apply Rz gate to the first qubit with angle π/2
+    /// let mut qc = QuantumCircuit::new(2);
+    /// qc.h(0);
+    /// qc.crz(0, 1, std::f64::consts::FRAC_PI_2);
     /// ```
-    pub fn rz(&mut self, target: usize, angle: f64) {
-        self.add_gate(QuantumGate::Rz(angle), target);
+    pub fn crz(&mut self, control: usize, target: usize, angle: f64) {
+        self.add_controlled_gate(QuantumGate::CRz(angle), control, target);
     }
 
-    /// Applies a Measurement
+    /// Applies a uniformly controlled (multiplexed) Ry rotation
+    /// 
+    /// The target is rotated by `angles[j]` when the controls hold the value `j`, read
+    /// with the first control as the most significant bit. The operation is decomposed
+    /// into `2^k` Ry rotations and `2^k` CNOTs following a Gray code over the controls,
+    /// which makes it the building block of arbitrary state preparation.
     /// 
     /// # Arguments
-    /// * `qubit` - The index of the qubit to measure
-    /// * `classical_bit` - The index of the classical bit to store the result
+    /// * `controls` - The indices of the control qubits
+    /// * `target` - The index of the target qubit
+    /// * `angles` - One rotation angle per control value, `2^k` in total
+    /// 
+    /// # Panics
+    /// Panics if the number of angles is not `2^k` for the `k` controls given, or if the
+    /// qubits are out of bounds or not distinct
     /// 
     /// # Examples
-    /// ``` 
+    /// ```
     /// use intrico::QuantumCircuit;
+    /// use std::f64::consts::PI;
     /// 
-    /// let mut qc = QuantumCircuit::new(1);
-    /// qc.measure(0, 0);  // Measure the first qubit and store the result in the first classical bit
-    /// ``` 
-    pub fn measure(&mut self, qubit: usize, classical_bit: usize) {
-        if qubit >= self.num_qubits {
-            panic!("Qubit index {} is out of bounds for circuit with {} qubits", 
-                   qubit, self.num_qubits);
-        }
+    /// let mut qc = QuantumCircuit::new(2);
+    /// qc.x(0);
+    /// qc.ucry(&[0], 1, &[0.0, PI]);  // Flips qubit 1 only when qubit 0 is |1⟩
+    /// 
+    /// assert!((qc.execute().probabilities()[3] - 1.0).abs() < 1e-6);
+    /// ```
+    pub fn ucry(&mut self, controls: &[usize], target: usize, angles: &[f64]) {
+        self.multiplexed_rotation(QuantumGate::Ry, controls, target, angles);
+    }
 
-        // Ensure classical bits vector has enough space
-        while classical_bit >= self.classical_bits.len() {
-            self.classical_bits.push(0);
+    /// Applies a uniformly controlled (multiplexed) Rz rotation
+    /// 
+    /// The target is rotated by `angles[j]` when the controls hold the value `j`, read
+    /// with the first control as the most significant bit. See [`QuantumCircuit::ucry`]
+    /// for the decomposition.
+    /// 
+    /// # Panics
+    /// Panics if the number of angles is not `2^k` for the `k` controls given, or if the
+    /// qubits are out of bounds or not distinct
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// 
+    /// let mut qc = QuantumCircuit::new(3);
+    /// qc.ucrz(&[0, 1], 2, &[0.1, 0.2, 0.3, 0.4]);
+    /// assert_eq!(qc.num_operations(), 8);
+    /// ```
+    pub fn ucrz(&mut self, controls: &[usize], target: usize, angles: &[f64]) {
+        self.multiplexed_rotation(QuantumGate::Rz, controls, target, angles);
+    }
+
+    /// Emits a multiplexed rotation as rotations and CNOTs
+    /// 
+    /// With Gray codes `g_i`, the circuit alternates a rotation by `θ_i` with a CNOT from
+    /// the control whose bit changes between `g_i` and `g_(i+1)`. Conjugating by X
+    /// negates the angle, so control value `j` sees `Σ_i (-1)^(j·g_i) θ_i`, and solving
+    /// for the requested angles gives `θ_i = 2^(-k) Σ_j (-1)^(j·g_i) angles[j]`.
+    fn multiplexed_rotation(&mut self, rotation: fn(f64) -> QuantumGate, controls: &[usize], target: usize, angles: &[f64]) {
+        let k = controls.len();
+        let count = 1usize << k;
+        if angles.len() != count {
+            panic!("A multiplexed rotation with {} controls needs {} angles, got {}", k, count, angles.len());
+        }
+        let mut qubits = controls.to_vec();
+        qubits.push(target);
+        if let Err(error) = self.check_qubits(&qubits) {
+            panic!("{}", error);
         }
 
-        self.last_step[qubit]+=1;
-        let step = self.last_step[qubit];
+        if k == 0 {
+            self.add_gate(rotation(angles[0]), target);
+            return;
+        }
 
+        let gray = |i: usize| i ^ (i >> 1);
+        for i in 0..count {
+            let theta = angles.iter()
+                .enumerate()
+                .map(|(j, angle)| if (j & gray(i)).count_ones() % 2 == 0 { *angle } else { -angle })
+                .sum::<f64>() / count as f64;
+            self.add_gate(rotation(theta), target);
 
-        let mut op = GateOp::new(QuantumGate::Measure, qubit, step);
-        op.classical_bit = Some(classical_bit);
-        self.operations.push(op);
+            let changed = (gray(i) ^ gray((i + 1) % count)).trailing_zeros() as usize;
+            self.cnot(controls[k - 1 - changed], target);
+        }
     }
 
-    /// Adds a gate operation to the circuit
+    /// Applies a multi-controlled X gate, flipping the target when all controls are |1⟩
     /// 
     /// # Arguments
-    /// * `gate` - The quantum gate to apply
-    /// * `target` - The index of the qubit to apply the gate to
+    /// * `controls` - The indices of the control qubits
+    /// * `target` - The index of the target qubit
+    /// 
+    /// # Panics
+    /// Panics if the qubits are out of bounds or not distinct
     /// 
     /// # Examples
     /// ```
-    /// use intrico::{QuantumCircuit, QuantumGate};
+    /// use intrico::QuantumCircuit;
     /// 
-    /// let mut qc = QuantumCircuit::new(1);
-    /// qc.add_gate(QuantumGate::H, 0);  // Add a Hadamard gate to the first qubit
+    /// let mut qc = QuantumCircuit::new(4);
+    /// qc.mcx(&[0, 1, 2], 3);
     /// ```
+    pub fn mcx(&mut self, controls: &[usize], target: usize) {
+        match controls {
+            [] => self.x(target),
+            [control] => self.cnot(*control, target),
+            [control1, control2] => self.ccx(*control1, *control2, target),
+            _ => {
+                let mut qubits = controls.to_vec();
+                qubits.push(target);
 
-    pub fn add_gate(&mut self, gate: QuantumGate, target: usize) {
-        if target >= self.num_qubits {
-            panic!("Qubit index {} is out of bounds for circuit with {} qubits", 
-                   target, self.num_qubits);
+                self.h(target);
+                self.mcz(&qubits);
+                self.h(target);
+            },
         }
-        self.last_step[target] += 1;
-        let step = self.last_step[target];
-        self.operations.push(GateOp::new(gate, target, step));
     }
 
-    /// Adds a controlled gate operation to the circuit
-    pub fn add_controlled_gate(&mut self, gate: QuantumGate, control: usize, target: usize) {
-        if control >= self.num_qubits || target >= self.num_qubits {
-            panic!("Qubit index out of bounds for circuit with {} qubits", self.num_qubits);
-        }
-        let max_step = cmp::max(self.last_step[control], self.last_step[target]) + 1;
-        self.last_step[control] = max_step;
-        self.last_step[target] = max_step;
-
-        let step = self.last_step[target];
-        self.operations.push(GateOp::controlled(gate, control, target, step));
+    /// Applies a SWAP gate exchanging the states of two qubits
+    /// 
+    /// The swap is built from three CNOT gates.
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// 
+    /// let mut qc = QuantumCircuit::new(2);
+    /// qc.swap(0, 1);
+    /// ```
+    pub fn swap(&mut self, a: usize, b: usize) {
+        self.cnot(a, b);
+        self.cnot(b, a);
+        self.cnot(a, b);
     }
 
-    fn apply_single_qubit_gate(&self, state_vector: &mut Vec<Complex>, gate: QuantumGate, target: usize) {
-        let n = state_vector.len();
-        let mask = 1 << target;
-
-        for i in 0..n {
-            if i & mask == 0 {
-                let j = i | mask;  // Flip the target qubit
-                let a = state_vector[i];      // Amplitude of the state |i⟩
-                let b = state_vector[j];      // Amplitude of the state |j⟩
-
-                let ampl_vec = ComplexVector::new(vec![a, b]);
-                let ampl_vec = ampl_vec.mul_matrix(&gate.matrix());
-
-                state_vector[i] = ampl_vec.components[0];
-                state_vector[j] = ampl_vec.components[1];
-            }
-        }
+    /// Applies an Rzz rotation `e^(-iθ Z⊗Z / 2)` to two qubits
+    /// 
+    /// The rotation is built from two CNOT gates around an Rz on the second qubit.
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// 
+    /// let mut qc = QuantumCircuit::new(2);
+    /// qc.rzz(0, 1, std::f64::consts::PI / 2.0);
+    /// ```
+    pub fn rzz(&mut self, a: usize, b: usize, angle: f64) {
+        self.cnot(a, b);
+        self.rz(b, angle);
+        self.cnot(a, b);
     }
 
-    fn apply_two_qubit_gate(&self, state_vector: &mut Vec<Complex>, gate: QuantumGate, control: usize, target: usize) {
-        let n = self.num_qubits;
-        let dim = 1 << n;
-
-        let (low, high) = if control < target { (control, target) } else { (target, control) };
-
-        let mut visited = vec![false; dim];  
+    /// Applies an Rxx rotation `e^(-iθ X⊗X / 2)` to two qubits
+    /// 
+    /// The rotation is an [`QuantumCircuit::rzz`] conjugated by Hadamard gates.
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// 
+    /// let mut qc = QuantumCircuit::new(2);
+    /// qc.rxx(0, 1, std::f64::consts::PI / 2.0);
+    /// ```
+    pub fn rxx(&mut self, a: usize, b: usize, angle: f64) {
+        self.h(a);
+        self.h(b);
+        self.rzz(a, b, angle);
+        self.h(a);
+        self.h(b);
+    }
 
-        for i in 0..dim {
-            if visited[i] {
-                continue;
-            }
+    /// Applies a Givens rotation by θ in the {|01⟩, |10⟩} subspace of two qubits
+    /// 
+    /// With `a` as the first qubit, |01⟩ means `a` in |0⟩ and `b` in |1⟩, so the rotation
+    /// takes |01⟩ to cos θ|01⟩ + sin θ|10⟩. The number of qubits in |1⟩ is preserved.
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// 
+    /// let mut qc = QuantumCircuit::new(2);
+    /// qc.x(0);
+    /// qc.givens(0, 1, std::f64::consts::FRAC_PI_2);  // Moves the excitation to qubit 1
+    /// 
+    /// assert!((qc.execute().probabilities()[2] - 1.0).abs() < 1e-6);
+    /// ```
+    pub fn givens(&mut self, a: usize, b: usize, angle: f64) {
+        self.add_multi_qubit_gate(QuantumGate::Givens(angle), &[a, b]);
+    }
 
-            // Compute 4 indices for this 2-qubit subspace
-            let base = i & !(1 << low) & !(1 << high); 
-            let mut indices = [0usize; 4];
-            for k in 0..4 {
-                let b0 = k & 1;
-                let b1 = (k >> 1) & 1;
-                indices[k] = base | (b0 << low) | (b1 << high);
-            }
+    /// Applies a phase gate, multiplying |1⟩ by `e^(iθ)`
+    /// 
+    /// # Arguments
+    /// * `target` - The index of the qubit to apply the gate to
+    /// * `angle` - The phase angle θ
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// 
+    /// let mut qc = QuantumCircuit::new(1);
+    /// qc.p(0, std::f64::consts::PI / 4.0);  // Same as a T gate
+    /// ```
+    pub fn p(&mut self, target: usize, angle: f64) {
+        let mut matrix = Matrix::identity(2);
+        matrix.set(1, 1, Complex::new(0.0, angle).exp());
 
-            if indices.iter().any(|&idx| visited[idx]) {
-                continue;
-            }
+        let gate = QuantumGate::Custom(matrix, format!("P({})", angle), format!("P({:.2})", angle));
+        self.add_gate(gate, target);
+    }
 
-            // Extract amplitudes
-            let original: [Complex; 4] = indices.map(|idx| state_vector[idx]);
+    /// Applies a controlled SWAP (Fredkin) gate, exchanging `a` and `b` when the control is |1⟩
+    /// 
+    /// The gate is built from a Toffoli between two CNOT gates.
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// 
+    /// let mut qc = QuantumCircuit::new(3);
+    /// qc.cswap(0, 1, 2);
+    /// ```
+    pub fn cswap(&mut self, control: usize, a: usize, b: usize) {
+        self.cnot(b, a);
+        self.ccx(control, a, b);
+        self.cnot(b, a);
+    }
 
-            // Apply gate
-            let mut new_values = [Complex::new(0.0, 0.0); 4];
-            for r in 0..4 {
-                for c in 0..4 {
-                    new_values[r] += *gate.matrix().get(r, c) * original[c];
-                }
-            }
+    /// Applies a controlled phase gate, multiplying |11⟩ by `e^(iθ)`
+    /// 
+    /// # Arguments
+    /// * `control` - The index of the control qubit
+    /// * `target` - The index of the target qubit
+    /// * `angle` - The phase angle θ
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// 
+    /// let mut qc = QuantumCircuit::new(2);
+    /// qc.cp(0, 1, std::f64::consts::PI / 2.0);
+    /// ```
+    pub fn cp(&mut self, control: usize, target: usize, angle: f64) {
+        let mut matrix = Matrix::identity(4);
+        matrix.set(3, 3, Complex::new(0.0, angle).exp());
 
-            for (k, &val) in indices.iter().zip(&new_values) {
-                state_vector[*k] = val;
-                visited[*k] = true;
-            }
-        }
+        let gate = QuantumGate::Custom(matrix, format!("CP({})", angle), format!("P({:.2})", angle));
+        self.add_multi_qubit_gate(gate, &[control, target]);
     }
 
-    fn apply_cnot(&self, state_vector: &mut Vec<Complex>, control: usize, target: usize) {
-        let dim = state_vector.len();
-        let mut new_state = state_vector.clone();
-    
-        for i in 0..dim {
-            let control_bit = (i >> control) & 1;
-            if control_bit == 1 {
-                let flipped = i ^ (1 << target);  // Flip target bit
-                new_state[flipped] = state_vector[i];
-                new_state[i] = state_vector[flipped];
-            }
+    /// Applies a multi-controlled Z gate, flipping the phase when all qubits are |1⟩
+    /// 
+    /// The gate is symmetric in its qubits, so no qubit is singled out as the target.
+    /// 
+    /// # Arguments
+    /// * `qubits` - The indices of the qubits the gate acts on
+    /// 
+    /// # Panics
+    /// Panics if no qubits are given, or if the qubits are out of bounds or not distinct
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// 
+    /// let mut qc = QuantumCircuit::new(3);
+    /// qc.mcz(&[0, 1, 2]);  // Flip the phase of |111⟩
+    /// ```
+    pub fn mcz(&mut self, qubits: &[usize]) {
+        match qubits {
+            [] => panic!("A multi-controlled Z gate needs at least one qubit"),
+            [target] => self.z(*target),
+            [control, target] => self.cz(*control, *target),
+            _ => {
+                let dim = 1 << qubits.len();
+                let mut matrix = Matrix::identity(dim);
+                matrix.set(dim - 1, dim - 1, Complex::new(-1.0, 0.0));
+
+                let symbol = format!("C{}Z", qubits.len() - 1);
+                let gate = QuantumGate::Custom(matrix, format!("MCZ({})", qubits.len()), symbol);
+                self.add_multi_qubit_gate(gate, qubits);
+            },
         }
-    
-        *state_vector = new_state;
     }
 
-    /// Executes the circuit on a set of qubits
+    /// Applies a diagonal gate multiplying each basis state of the qubits by `e^(i·phase)`
+    /// 
+    /// The phases are indexed with the first qubit as the most significant bit, so for
+    /// `qubits = [a, b]` the phases apply to |a b⟩ = |00⟩, |01⟩, |10⟩ and |11⟩ in order.
+    /// The gate is applied pointwise, which makes it cheap for oracles and QAOA phase
+    /// separators on many qubits.
     /// 
     /// # Arguments
-    /// * `qubits` - A slice of qubits to apply the circuit to
+    /// * `qubits` - The indices of the qubits the gate acts on
+    /// * `phases` - One phase per basis state of the qubits, `2^k` in total
+    /// 
+    /// # Panics
+    /// Panics if the number of phases is not `2^k` for the `k` qubits given, or if the
+    /// qubits are out of bounds or not distinct
     /// 
     /// # Examples
     /// ```
     /// use intrico::QuantumCircuit;
+    /// use std::f64::consts::PI;
     /// 
-    /// let mut qc = QuantumCircuit::new(1);
+    /// let mut qc = QuantumCircuit::new(2);
     /// qc.h(0);
+    /// qc.h(1);
+    /// qc.diagonal(&[0, 1], &[0.0, 0.0, 0.0, PI]);  // Same as CZ
     /// 
-    /// qc.execute();
+    /// assert!((qc.execute()[3].real + 0.5).abs() < 1e-6);
     /// ```
-    pub fn execute(&self) -> Vec<Complex> {
-        let dim = 1 << self.num_qubits;
-        let mut state_vector = vec![Complex::new(0.0, 0.0); dim];
-
-        // Selecting first state as active state
-        state_vector[0] = Complex::new(1.0, 0.0);
-
-        for op in &self.operations {
-            match op.gate.arity() {
-                // single qubit gates
-                1 => {
-                    self.apply_single_qubit_gate(&mut state_vector, op.gate.clone(), op.target());
-                },
-                2 => {
-                    if op.gate == QuantumGate::CNOT {
-                        self.apply_cnot(&mut state_vector, op.controls()[0], op.target());
-                    } else {
-                        self.apply_two_qubit_gate(&mut state_vector, op.gate.clone(), op.controls()[0], op.target());
-                    }
-                },
-                _ => {}
-            }
-        }
-
-        state_vector
-            .into_iter()
-            .map(|c| Complex {
-                real: round_if_close(c.real, 1e-10),
-                imag: round_if_close(c.imag, 1e-10),
-            })
-            .collect()
+    pub fn diagonal(&mut self, qubits: &[usize], phases: &[f64]) {
+        self.add_multi_qubit_gate(QuantumGate::Diagonal(phases.to_vec()), qubits);
+    }
+
+    /// Applies a Toffoli (CCX) gate with the specified control and target qubits
+    /// 
+    /// # Arguments
+    /// * `control1` - The index of the first control qubit
+    /// * `control2` - The index of the second control qubit
+    /// * `target` - The index of the target qubit
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// 
+    /// let mut qc = QuantumCircuit::new(3);
+    /// qc.ccx(0, 1, 2);  // Flip qubit 2 when qubits 0 and 1 are both |1⟩
+    /// ```
+    pub fn ccx(&mut self, control1: usize, control2: usize, target: usize) {
+        self.add_multi_qubit_gate(QuantumGate::Toffoli, &[control1, control2, target]);
+    }
+
+    /// Applies a Toffoli (CCX) gate with the specified control and target qubits
+    /// 
+    /// Alias of [`QuantumCircuit::ccx`].
+    pub fn toffoli(&mut self, control1: usize, control2: usize, target: usize) {
+        self.ccx(control1, control2, target);
+    }
+
+    /// Applies a Rx gate to the specified qubit
+    /// 
+    /// # Arguments
+    /// * `target` - The index of the qubit to apply the gate to
+    /// * `angle` - The angle of the Rx gate
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// 
+    /// let mut qc = QuantumCircuit::new(1);
+    /// qc.rx(0, std::f64::consts::PI / 2.0);  // Apply Rx gate to the first qubit with angle π/2
+    /// ```
+    pub fn rx(&mut self, target: usize, angle: f64) {
+        self.add_gate(QuantumGate::Rx(angle), target);
+    }
+
+    /// Applies a Ry gate to the specified qubit
+    /// 
+    /// # Arguments
+    /// * `target` - The index of the qubit to apply the gate to
+    /// * `angle` - The angle of the Ry gate
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// 
+    /// let mut qc = QuantumCircuit::new(1);
+    /// qc.ry(0, std::f64::consts::PI / 2.0);  // Apply Ry gate to the first qubit with angle π/2
+    /// ```
+    pub fn ry(&mut self, target: usize, angle: f64) {
+        self.add_gate(QuantumGate::Ry(angle), target);
+    }
+
+    /// Applies a Rz gate to the specified qubit
+    /// 
+    /// # Arguments
+    /// * `target` - The index of the qubit to apply the gate to
+    /// * `angle` - The angle of the Rz gate
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// 
+    /// let mut qc = QuantumCircuit::new(1);
+    /// qc.rz(0, std::f64::consts::PI / 2.0);  // Apply Rz gate to the first qubit with angle π/2
+    /// ```
+    pub fn rz(&mut self, target: usize, angle: f64) {
+        self.add_gate(QuantumGate::Rz(angle), target);
+    }
+
+    /// Prepares a qubit in the state `α|0⟩ + β|1⟩`
+    /// 
+    /// The instruction is only valid before any other operation on the wire, where the
+    /// qubit is still in |0⟩; snapshots do not count as operations. Circuits have no reset
+    /// instruction, so a wire cannot be prepared again once used. It is added as the custom gate `[[α, -β*], [β, α*]]`, which
+    /// maps |0⟩ onto the requested state, and drawn as a single `Init` box.
+    /// 
+    /// # Arguments
+    /// * `target` - The index of the qubit to prepare
+    /// * `alpha` - The amplitude of |0⟩
+    /// * `beta` - The amplitude of |1⟩
+    /// 
+    /// # Panics
+    /// Panics if the qubit is out of bounds, if an operation was already applied to it,
+    /// or if `|α|² + |β|²` differs from 1
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// use rusticle::complex::Complex;
+    /// 
+    /// // Start qubit 1 in |+i⟩ instead of |0⟩
+    /// let h = 1.0 / 2.0_f64.sqrt();
+    /// let mut qc = QuantumCircuit::new(2);
+    /// qc.set_qubit_state(1, Complex::new(h, 0.0), Complex::new(0.0, h));
+    /// 
+    /// let state = qc.execute();
+    /// assert!((state[2].imag - h).abs() < 1e-6);
+    /// ```
+    pub fn set_qubit_state(&mut self, target: usize, alpha: Complex, beta: Complex) {
+        if target >= self.num_qubits {
+            panic!("Qubit index {} is out of bounds for circuit with {} qubits",
+                   target, self.num_qubits);
+        }
+        let used = self.operations.iter()
+            .any(|op| op.qubit.contains(&target) && !matches!(op.gate, QuantumGate::Snapshot(_)));
+        if used {
+            panic!("The state of qubit {} can only be set before any other operation on it", target);
+        }
+        let norm = alpha.norm_squared() + beta.norm_squared();
+        if (norm - 1.0).abs() > 1e-6 {
+            panic!("State amplitudes must be normalized, got |α|² + |β|² = {}", norm);
+        }
+
+        let preparation = Matrix::new(2, 2, vec![
+            alpha, -beta.conjugate(),
+            beta, alpha.conjugate(),
+        ]);
+        self.add_gate(QuantumGate::Custom(preparation, "Initialize".to_string(), "Init".to_string()), target);
+    }
+
+    /// Applies a Measurement
+    /// 
+    /// # Arguments
+    /// * `qubit` - The index of the qubit to measure
+    /// * `classical_bit` - The index of the classical bit to store the result
+    /// 
+    /// # Examples
+    /// ``` 
+    /// use intrico::QuantumCircuit;
+    /// 
+    /// let mut qc = QuantumCircuit::new(1);
+    /// qc.measure(0, 0);  // Measure the first qubit and store the result in the first classical bit
+    /// ``` 
+    pub fn measure(&mut self, qubit: usize, classical_bit: usize) {
+        self.add_measurement(QuantumGate::Measure, qubit, classical_bit);
+    }
+
+    /// Measures a qubit in the eigenbasis of Pauli-X
+    /// 
+    /// Unlike [`QuantumCircuit::measure_in_basis`], the basis change is part of the
+    /// measurement instruction, so it is drawn as a single `[MX]` box. Simulators rotate
+    /// the qubit by H before measuring it, leaving it in |0⟩ for |+⟩ and in |1⟩ for |-⟩.
+    /// 
+    /// # Arguments
+    /// * `qubit` - The index of the qubit to measure
+    /// * `classical_bit` - The index of the classical bit to store the result
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// use intrico::simulator::Simulator;
+    /// 
+    /// let mut qc = QuantumCircuit::new(1);
+    /// qc.h(0);
+    /// qc.measure_x(0, 0);
+    /// 
+    /// let result = Simulator::new().with_circuit(qc).run(100);
+    /// assert_eq!(result.counts["0"], 100);
+    /// ```
+    pub fn measure_x(&mut self, qubit: usize, classical_bit: usize) {
+        self.add_measurement(QuantumGate::MeasureX, qubit, classical_bit);
+    }
+
+    /// Measures a qubit in the eigenbasis of Pauli-Y
+    /// 
+    /// The basis change S† followed by H is part of the measurement instruction, so it is
+    /// drawn as a single `[MY]` box. The qubit is left in |0⟩ for |+i⟩ and in |1⟩ for |-i⟩.
+    /// 
+    /// # Arguments
+    /// * `qubit` - The index of the qubit to measure
+    /// * `classical_bit` - The index of the classical bit to store the result
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// use intrico::simulator::Simulator;
+    /// 
+    /// // S·H|0⟩ is |+i⟩
+    /// let mut qc = QuantumCircuit::new(1);
+    /// qc.h(0);
+    /// qc.s(0);
+    /// qc.measure_y(0, 0);
+    /// 
+    /// let result = Simulator::new().with_circuit(qc).run(100);
+    /// assert_eq!(result.counts["0"], 100);
+    /// ```
+    pub fn measure_y(&mut self, qubit: usize, classical_bit: usize) {
+        self.add_measurement(QuantumGate::MeasureY, qubit, classical_bit);
+    }
+
+    /// Measures every qubit into the classical bit of the same index
+    /// 
+    /// The classical register grows to at least one bit per qubit.
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// use intrico::simulator::Simulator;
+    /// 
+    /// let mut qc = QuantumCircuit::new(2);
+    /// qc.x(1);
+    /// qc.measure_all();
+    /// 
+    /// assert_eq!(qc.num_classical_bits(), 2);
+    /// let result = Simulator::new().with_circuit(qc).run(10);
+    /// assert_eq!(result.counts["10"], 10);
+    /// ```
+    pub fn measure_all(&mut self) {
+        for qubit in 0..self.num_qubits {
+            self.measure(qubit, qubit);
+        }
+    }
+
+    /// Removes the measurements that no later operation depends on
+    /// 
+    /// A measurement is final when no later operation other than a snapshot acts on its
+    /// qubit or reads its classical bit. Removing them leaves the circuit whose
+    /// [`QuantumCircuit::execute`] statevector is the raw state before readout. The
+    /// classical register shrinks to the bits still used.
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// 
+    /// let mut qc = QuantumCircuit::new(2);
+    /// qc.h(0);
+    /// qc.measure(0, 0);
+    /// qc.x(1);
+    /// qc.c_if(0, true);
+    /// qc.measure_all();
+    /// 
+    /// qc.remove_final_measurements();
+    /// // The measurement feeding the condition stays
+    /// assert_eq!(qc.num_operations(), 3);
+    /// assert_eq!(qc.num_classical_bits(), 1);
+    /// ```
+    pub fn remove_final_measurements(&mut self) {
+        let operations = std::mem::take(&mut self.operations);
+        let is_final = |i: usize, op: &GateOp| {
+            let qubit = op.target();
+            let bit = op.classical_bit.unwrap_or_default();
+            op.gate.is_measurement() && !operations[i + 1..].iter().any(|later| {
+                !matches!(later.gate, QuantumGate::Snapshot(_))
+                    && (later.qubit.contains(&qubit) || Self::reads_bit(later, bit))
+            })
+        };
+        let kept: Vec<GateOp> = operations.iter().enumerate()
+            .filter(|&(i, op)| !is_final(i, op))
+            .map(|(_, op)| op.clone())
+            .collect();
+
+        self.last_step = vec![0; self.num_qubits];
+        for op in &kept {
+            for &qubit in &op.qubit {
+                self.last_step[qubit] = self.last_step[qubit].max(op.step);
+            }
+        }
+        let used = kept.iter().map(Self::classical_bits_used).max().unwrap_or(0);
+        self.classical_bits.truncate(used);
+        self.operations = kept;
+    }
+
+    /// Returns whether an operation reads a classical bit, through a condition, a
+    /// classical operation or a loop
+    fn reads_bit(op: &GateOp, bit: usize) -> bool {
+        let reads = match &op.gate {
+            QuantumGate::Classical(classical) => classical.inputs().contains(&bit),
+            QuantumGate::RepeatUntil { body, condition: (tested, _), .. } => {
+                *tested == bit || body.iter().any(|inner| Self::reads_bit(inner, bit))
+            },
+            _ => false,
+        };
+        reads || op.condition.is_some_and(|(read, _)| read == bit)
+    }
+
+    /// Appends a measurement of the given kind, growing the classical register as needed
+    fn add_measurement(&mut self, gate: QuantumGate, qubit: usize, classical_bit: usize) {
+        if qubit >= self.num_qubits {
+            panic!("Qubit index {} is out of bounds for circuit with {} qubits", 
+                   qubit, self.num_qubits);
+        }
+
+        // Ensure classical bits vector has enough space
+        while classical_bit >= self.classical_bits.len() {
+            self.classical_bits.push(0);
+        }
+
+        self.last_step[qubit]+=1;
+        let step = self.last_step[qubit];
+
+
+        let mut op = GateOp::new(gate, qubit, step);
+        op.classical_bit = Some(classical_bit);
+        self.operations.push(op);
+    }
+
+    /// Applies the rotation mapping the eigenstates of a basis onto |0⟩ and |1⟩
+    /// 
+    /// The rotation is H for X, S† followed by H for Y, and nothing for Z.
+    /// 
+    /// # Arguments
+    /// * `qubit` - The index of the qubit to rotate
+    /// * `basis` - The basis whose +1 eigenstate is mapped to |0⟩
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// use intrico::circuit::Basis;
+    /// 
+    /// let mut qc = QuantumCircuit::new(1);
+    /// qc.h(0);
+    /// qc.rotate_to_basis(0, Basis::X);
+    /// assert!((qc.execute()[0].norm() - 1.0).abs() < 1e-6);
+    /// ```
+    pub fn rotate_to_basis(&mut self, qubit: usize, basis: Basis) {
+        match basis {
+            Basis::X => self.h(qubit),
+            Basis::Y => {
+                self.sdg(qubit);
+                self.h(qubit);
+            },
+            Basis::Z => {},
+        }
+    }
+
+    /// Measures a qubit in a Pauli basis
+    /// 
+    /// The qubit is rotated with [`QuantumCircuit::rotate_to_basis`] and then measured,
+    /// so it is left in |0⟩ for the +1 eigenstate and in |1⟩ for the -1 eigenstate, and
+    /// its bit in the counts reads the outcome in the given basis.
+    /// 
+    /// # Arguments
+    /// * `qubit` - The index of the qubit to measure
+    /// * `basis` - The basis to measure in
+    /// * `classical_bit` - The index of the classical bit to store the result
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// use intrico::circuit::Basis;
+    /// use intrico::simulator::Simulator;
+    /// 
+    /// // |+i⟩ is the +1 eigenstate of Y
+    /// let mut qc = QuantumCircuit::new(1);
+    /// qc.h(0);
+    /// qc.s(0);
+    /// qc.measure_in_basis(0, Basis::Y, 0);
+    /// 
+    /// let result = Simulator::new().with_circuit(qc).run(100);
+    /// assert_eq!(result.counts["0"], 100);
+    /// ```
+    pub fn measure_in_basis(&mut self, qubit: usize, basis: Basis, classical_bit: usize) {
+        self.rotate_to_basis(qubit, basis);
+        self.measure(qubit, classical_bit);
+    }
+
+    /// Conditions the most recently added operation on the value of a classical bit
+    /// 
+    /// The operation is only applied in shots where the classical bit, written by an
+    /// earlier [`QuantumCircuit::measure`], holds `value`. Classical bits start out as 0.
+    /// 
+    /// # Arguments
+    /// * `classical_bit` - The index of the classical bit to test
+    /// * `value` - The value the classical bit must hold
+    /// 
+    /// # Panics
+    /// Panics if the circuit has no operations, or if the last operation is a
+    /// measurement, a snapshot or a loop
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// 
+    /// let mut qc = QuantumCircuit::new(2);
+    /// qc.h(0);
+    /// qc.measure(0, 0);
+    /// qc.x(1);
+    /// qc.c_if(0, true);  // Flip qubit 1 only if qubit 0 was measured as 1
+    /// ```
+    pub fn c_if(&mut self, classical_bit: usize, value: bool) {
+        let Some(op) = self.operations.last_mut() else {
+            panic!("c_if requires a preceding operation to condition");
+        };
+        if op.gate.is_measurement() || matches!(op.gate, QuantumGate::Snapshot(_) | QuantumGate::RepeatUntil { .. }) {
+            panic!("Measurements, snapshots and loops cannot be conditioned");
+        }
+        op.condition = Some((classical_bit, value));
+
+        while classical_bit >= self.classical_bits.len() {
+            self.classical_bits.push(0);
+        }
+        self.schedule_after_write(self.operations.len() - 1);
+    }
+
+    /// Moves a conditioned operation to a step after the last operation writing the bit
+    /// it reads, so that diagrams show the condition after the measurement
+    fn schedule_after_write(&mut self, index: usize) {
+        let Some((bit, _)) = self.operations[index].condition else {
+            return;
+        };
+        let written = self.operations[..index].iter()
+            .filter(|op| op.classical_bit == Some(bit))
+            .filter(|op| op.gate.is_measurement() || matches!(op.gate, QuantumGate::Classical(_) | QuantumGate::RepeatUntil { .. }))
+            .map(|op| op.step)
+            .max()
+            .unwrap_or(0);
+
+        let op = &mut self.operations[index];
+        if op.step <= written {
+            op.step = written + 1;
+            for &qubit in &op.qubit {
+                self.last_step[qubit] = op.step;
+            }
+        }
+    }
+
+    /// Writes the exclusive or of two classical bits into a third one
+    /// 
+    /// Together with [`QuantumCircuit::c_if`] this feeds measurement outcomes forward,
+    /// e.g. to apply a correction depending on the parity of two outcomes.
+    /// 
+    /// # Arguments
+    /// * `a` - The index of the first classical bit
+    /// * `b` - The index of the second classical bit
+    /// * `target` - The index of the classical bit receiving the result
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// use intrico::simulator::Simulator;
+    /// 
+    /// let mut qc = QuantumCircuit::new(3);
+    /// qc.h(0);
+    /// qc.cnot(0, 1);
+    /// qc.measure(0, 0);
+    /// qc.measure(1, 1);
+    /// qc.c_xor(0, 1, 2);
+    /// qc.x(2);
+    /// qc.c_if(2, false);  // Bell outcomes always agree, so qubit 2 is always flipped
+    /// 
+    /// let result = Simulator::new().with_circuit(qc).run(100);
+    /// assert!(result.counts.keys().all(|bits| bits.starts_with('1')));
+    /// ```
+    pub fn c_xor(&mut self, a: usize, b: usize, target: usize) {
+        self.add_classical(ClassicalOp::Xor(a, b), target);
+    }
+
+    /// Writes the conjunction of two classical bits into a third one
+    /// 
+    /// # Arguments
+    /// * `a` - The index of the first classical bit
+    /// * `b` - The index of the second classical bit
+    /// * `target` - The index of the classical bit receiving the result
+    pub fn c_and(&mut self, a: usize, b: usize, target: usize) {
+        self.add_classical(ClassicalOp::And(a, b), target);
+    }
+
+    /// Writes 1 into a classical bit when a group of classical bits holds a constant
+    /// 
+    /// The bits are read with the first one as the least significant, so conditioning
+    /// on `target` afterwards conditions on the value of a whole register.
+    /// 
+    /// # Arguments
+    /// * `bits` - The indices of the classical bits to compare, least significant first
+    /// * `value` - The constant to compare against
+    /// * `target` - The index of the classical bit receiving the result
+    /// 
+    /// # Panics
+    /// Panics if more than 64 bits are compared
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// 
+    /// let mut qc = QuantumCircuit::new(3);
+    /// qc.measure(0, 0);
+    /// qc.measure(1, 1);
+    /// qc.c_eq(&[0, 1], 0b10, 2);
+    /// qc.x(2);
+    /// qc.c_if(2, true);  // Flip qubit 2 only when qubit 1 read 1 and qubit 0 read 0
+    /// ```
+    pub fn c_eq(&mut self, bits: &[usize], value: u64, target: usize) {
+        if bits.len() > 64 {
+            panic!("Cannot compare {} classical bits against a 64-bit constant", bits.len());
+        }
+        self.add_classical(ClassicalOp::Equals(bits.to_vec(), value), target);
+    }
+
+    /// Appends a classical operation, growing the classical register as needed
+    /// 
+    /// The operation acts on no qubit, so its step follows the latest operation of the
+    /// circuit without delaying any wire.
+    fn add_classical(&mut self, op: ClassicalOp, target: usize) {
+        let highest = op.inputs().into_iter().chain([target]).max().unwrap_or(target);
+        while highest >= self.classical_bits.len() {
+            self.classical_bits.push(0);
+        }
+
+        let step = self.last_step.iter().max().unwrap_or(&0) + 1;
+        let mut op = GateOp::multi_qubit(QuantumGate::Classical(op), Vec::new(), step);
+        op.classical_bit = Some(target);
+        self.operations.push(op);
+    }
+
+    /// Appends a bounded repeat-until-success loop
+    /// 
+    /// The body is applied, then applied again as long as the classical bit does not hold
+    /// `value`, up to `max_iterations` applications in total. The body usually measures
+    /// into the classical bit to decide whether it succeeded. Simulators evaluate the
+    /// condition for every shot, so different shots may run a different number of
+    /// iterations. The body shares the classical register of this circuit.
+    /// 
+    /// # Arguments
+    /// * `body` - The circuit applied in every iteration
+    /// * `qubits` - The qubits of this circuit the qubits of the body are mapped to
+    /// * `classical_bit` - The index of the classical bit tested after every iteration
+    /// * `value` - The value of the classical bit that ends the loop
+    /// * `max_iterations` - The maximum number of iterations
+    /// 
+    /// # Panics
+    /// Panics if `qubits` does not provide a qubit for every qubit of the body, if a
+    /// mapped qubit is out of bounds or listed twice, or if `max_iterations` is 0
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// use intrico::simulator::Simulator;
+    /// 
+    /// // Flip a coin until it lands on 1
+    /// let mut body = QuantumCircuit::new(1);
+    /// body.h(0);
+    /// body.measure(0, 0);
+    /// 
+    /// let mut qc = QuantumCircuit::new(1);
+    /// qc.repeat_until(&body, &[0], 0, true, 30);
+    /// 
+    /// let result = Simulator::new().with_circuit(qc).with_seed(3).run(100);
+    /// assert_eq!(result.counts["1"], 100);
+    /// ```
+    pub fn repeat_until(&mut self, body: &QuantumCircuit, qubits: &[usize], classical_bit: usize, value: bool, max_iterations: usize) {
+        if qubits.len() != body.num_qubits {
+            panic!("Expected {} qubits to map the loop body onto, got {}", body.num_qubits, qubits.len());
+        }
+        if max_iterations == 0 {
+            panic!("A repeat-until-success loop needs at least one iteration");
+        }
+        self.validate_qubits(qubits);
+
+        let body: Vec<GateOp> = body.operations.iter().map(|op| Self::map_qubits(op, qubits)).collect();
+        let mut touched: Vec<usize> = body.iter().flat_map(|op| op.qubit.iter().copied()).collect();
+        touched.sort_unstable();
+        touched.dedup();
+
+        let gate = QuantumGate::RepeatUntil { body, condition: (classical_bit, value), max_iterations };
+        let mut op = GateOp::multi_qubit(gate, touched, 0);
+        op.classical_bit = Some(classical_bit);
+        self.add_operation(op);
+    }
+
+    /// Returns a copy of the operation with its qubit `i` replaced by `qubits[i]`,
+    /// including the operations of loop bodies
+    fn map_qubits(op: &GateOp, qubits: &[usize]) -> GateOp {
+        let mut mapped = op.clone();
+        mapped.qubit = op.qubit.iter().map(|&q| qubits[q]).collect();
+        if let QuantumGate::RepeatUntil { body, .. } = &mut mapped.gate {
+            for inner in body.iter_mut() {
+                *inner = Self::map_qubits(inner, qubits);
+            }
+        }
+        mapped
+    }
+
+    /// Returns the number of classical bits an operation reads or writes, including the
+    /// operations of loop bodies
+    fn classical_bits_used(op: &GateOp) -> usize {
+        let mut used = op.classical_bit.into_iter()
+            .chain(op.condition.map(|(bit, _)| bit))
+            .map(|bit| bit + 1)
+            .max()
+            .unwrap_or(0);
+        match &op.gate {
+            QuantumGate::Classical(classical) => {
+                used = classical.inputs().into_iter().map(|bit| bit + 1).fold(used, usize::max);
+            },
+            QuantumGate::RepeatUntil { body, condition: (bit, _), .. } => {
+                used = body.iter().map(Self::classical_bits_used).fold(used.max(bit + 1), usize::max);
+            },
+            _ => {},
+        }
+        used
+    }
+
+    /// Attaches a noise channel to the most recently added operation
+    /// 
+    /// The channel acts right after the operation in simulations, in addition to any
+    /// global [`NoiseModel`](crate::simulator::NoiseModel), so a single faulty gate can
+    /// be studied in an otherwise ideal circuit. Attaching a second channel replaces
+    /// the first one.
+    /// 
+    /// # Arguments
+    /// * `channel` - The noise channel to apply after the operation
+    /// 
+    /// # Panics
+    /// Panics if the circuit has no operations, or if the last operation is a
+    /// measurement, a snapshot, a classical operation or a loop
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// use intrico::core::NoiseChannel;
+    /// use intrico::simulator::Simulator;
+    /// 
+    /// let mut qc = QuantumCircuit::new(2);
+    /// qc.x(0);
+    /// qc.add_noise(NoiseChannel::BitFlip(1.0));  // The X gate always fails
+    /// qc.x(1);
+    /// 
+    /// let result = Simulator::new().with_circuit(qc).run(100);
+    /// assert_eq!(result.counts["10"], 100);
+    /// ```
+    pub fn add_noise(&mut self, channel: NoiseChannel) {
+        let Some(op) = self.operations.last_mut() else {
+            panic!("add_noise requires a preceding operation to attach the noise to");
+        };
+        if op.gate.is_measurement() || matches!(op.gate, QuantumGate::Snapshot(_) | QuantumGate::Classical(_) | QuantumGate::RepeatUntil { .. }) {
+            panic!("Noise cannot be attached to measurements, snapshots, classical operations and loops");
+        }
+        op.noise = Some(channel);
+    }
+
+    /// Sets the duration of the most recently added operation
+    /// 
+    /// The duration takes precedence over the calibration of a
+    /// [`DeviceModel`](crate::device::DeviceModel) when scheduling the circuit with
+    /// [`QuantumCircuit::timeline`] and when simulating thermal relaxation.
+    /// 
+    /// # Arguments
+    /// * `duration` - The duration of the operation in nanoseconds
+    /// 
+    /// # Panics
+    /// Panics if the circuit has no operations, if the last operation is a snapshot, or
+    /// if the duration is negative, infinite or NaN
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// 
+    /// let mut qc = QuantumCircuit::new(2);
+    /// qc.h(0);
+    /// qc.set_duration(35.0);
+    /// qc.cnot(0, 1);
+    /// qc.set_duration(300.0);
+    /// 
+    /// assert_eq!(qc.duration(), 335.0);
+    /// ```
+    pub fn set_duration(&mut self, duration: f64) {
+        let Some(op) = self.operations.last_mut() else {
+            panic!("set_duration requires a preceding operation");
+        };
+        if let QuantumGate::Snapshot(_) = op.gate {
+            panic!("Snapshots are instantaneous and cannot have a duration");
+        }
+        if !duration.is_finite() || duration < 0.0 {
+            panic!("Operation durations must be finite and cannot be negative, got {}", duration);
+        }
+        op.duration = Some(duration);
+    }
+
+    /// Records the statevector at this point of the circuit under the given label
+    /// 
+    /// The snapshot spans all qubits, so it is placed after every preceding operation.
+    /// The recorded states are available in `SimulationResult::snapshots` after a simulation.
+    /// 
+    /// # Arguments
+    /// * `label` - The label to store the statevector under
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// use intrico::simulator::Simulator;
+    /// 
+    /// let mut qc = QuantumCircuit::new(2);
+    /// qc.h(0);
+    /// qc.snapshot("superposition");
+    /// qc.cnot(0, 1);
+    /// 
+    /// let result = Simulator::new().with_circuit(qc).run(10);
+    /// assert_eq!(result.snapshots["superposition"].len(), 4);
+    /// ```
+    pub fn snapshot<S: Into<String>>(&mut self, label: S) {
+        let step = self.last_step.iter().max().unwrap_or(&0) + 1;
+        self.last_step.iter_mut().for_each(|last| *last = step);
+
+        let op = GateOp {
+            gate: QuantumGate::Snapshot(label.into()),
+            qubit: (0..self.num_qubits).collect(),
+            step,
+            classical_bit: None,
+            condition: None,
+            noise: None,
+            duration: None,
+        };
+        self.operations.push(op);
+    }
+
+    /// Adds a gate operation to the circuit
+    /// 
+    /// # Arguments
+    /// * `gate` - The quantum gate to apply
+    /// * `target` - The index of the qubit to apply the gate to
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::{QuantumCircuit, QuantumGate};
+    /// 
+    /// let mut qc = QuantumCircuit::new(1);
+    /// qc.add_gate(QuantumGate::H, 0);  // Add a Hadamard gate to the first qubit
+    /// ```
+    pub fn add_gate(&mut self, gate: QuantumGate, target: usize) {
+        if target >= self.num_qubits {
+            panic!("Qubit index {} is out of bounds for circuit with {} qubits", 
+                   target, self.num_qubits);
+        }
+        if let Err(error) = Self::check_phases(&gate, 1) {
+            panic!("{}", error);
+        }
+        self.last_step[target] += 1;
+        let step = self.last_step[target];
+        self.operations.push(GateOp::new(gate, target, step));
+    }
+
+    /// Adds a controlled gate operation to the circuit
+    pub fn add_controlled_gate(&mut self, gate: QuantumGate, control: usize, target: usize) {
+        if control >= self.num_qubits || target >= self.num_qubits {
+            panic!("Qubit index out of bounds for circuit with {} qubits", self.num_qubits);
+        }
+        let max_step = cmp::max(self.last_step[control], self.last_step[target]) + 1;
+        self.last_step[control] = max_step;
+        self.last_step[target] = max_step;
+
+        let step = self.last_step[target];
+        self.operations.push(GateOp::controlled(gate, control, target, step));
+    }
+
+    /// Adds a gate operation acting on several qubits to the circuit
+    /// 
+    /// For controlled gates the controls come first and the target last. For custom
+    /// gates the first qubit corresponds to the most significant bit of the matrix index.
+    /// 
+    /// # Arguments
+    /// * `gate` - The quantum gate to apply
+    /// * `qubits` - The indices of the qubits the gate acts on
+    /// 
+    /// # Panics
+    /// Panics if the number of qubits does not match the gate arity, or if the
+    /// qubits are out of bounds or not distinct
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::{QuantumCircuit, QuantumGate};
+    /// 
+    /// let mut qc = QuantumCircuit::new(3);
+    /// qc.add_multi_qubit_gate(QuantumGate::Toffoli, &[0, 1, 2]);
+    /// ```
+    pub fn add_multi_qubit_gate(&mut self, gate: QuantumGate, qubits: &[usize]) {
+        if let Err(error) = self.try_add_multi_qubit_gate(gate, qubits) {
+            panic!("{}", error);
+        }
+    }
+
+    /// Adds a gate operation acting on several qubits, returning an error instead of
+    /// panicking when the qubits do not fit the gate or the circuit
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::{IntricoError, QuantumCircuit, QuantumGate};
+    /// use intrico::circuit::CircuitError;
+    /// 
+    /// let mut qc = QuantumCircuit::new(2);
+    /// assert!(qc.try_add_multi_qubit_gate(QuantumGate::CZ, &[0, 1]).is_ok());
+    /// assert!(matches!(
+    ///     qc.try_add_multi_qubit_gate(QuantumGate::CZ, &[1, 1]),
+    ///     Err(IntricoError::Circuit(CircuitError::DuplicateQubit { qubit: 1 }))
+    /// ));
+    /// assert_eq!(qc.num_operations(), 1);
+    /// ```
+    pub fn try_add_multi_qubit_gate(&mut self, gate: QuantumGate, qubits: &[usize]) -> Result<(), IntricoError> {
+        Self::check_phases(&gate, qubits.len())?;
+        if qubits.len() != gate.arity() {
+            return Err(CircuitError::ArityMismatch { gate: gate.name(), expected: gate.arity(), found: qubits.len() }.into());
+        }
+        self.check_qubits(qubits)?;
+
+        let step = self.next_step(qubits);
+        self.operations.push(GateOp::multi_qubit(gate, qubits.to_vec(), step));
+        Ok(())
+    }
+
+    /// Appends an existing operation to the circuit, recomputing its step
+    /// 
+    /// This is useful for copying operations between circuits, e.g. when rewriting them.
+    /// 
+    /// # Panics
+    /// Panics if the operation refers to qubits outside the circuit
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// 
+    /// let mut original = QuantumCircuit::new(2);
+    /// original.h(0);
+    /// original.cnot(0, 1);
+    /// 
+    /// let mut copy = QuantumCircuit::new(2);
+    /// for op in original.operations() {
+    ///     copy.add_operation(op.clone());
+    /// }
+    /// assert_eq!(copy.num_operations(), 2);
+    /// ```
+    pub fn add_operation(&mut self, mut op: GateOp) {
+        if let QuantumGate::Snapshot(label) = op.gate {
+            self.snapshot(label);
+            return;
+        }
+        if let (QuantumGate::Classical(classical), Some(target)) = (&op.gate, op.classical_bit) {
+            let condition = op.condition;
+            self.add_classical(classical.clone(), target);
+            if let Some((bit, value)) = condition {
+                self.c_if(bit, value);
+            }
+            return;
+        }
+        if let (true, Some(classical_bit)) = (op.gate.is_measurement(), op.classical_bit) {
+            let qubit = op.target();
+            self.add_measurement(op.gate, qubit, classical_bit);
+            return;
+        }
+
+        self.validate_qubits(&op.qubit);
+        if let Err(error) = Self::check_phases(&op.gate, op.qubit.len()) {
+            panic!("{}", error);
+        }
+        op.step = if op.qubit.is_empty() {
+            self.last_step.iter().max().unwrap_or(&0) + 1
+        } else {
+            self.next_step(&op.qubit)
+        };
+        while Self::classical_bits_used(&op) > self.classical_bits.len() {
+            self.classical_bits.push(0);
+        }
+        self.operations.push(op);
+        self.schedule_after_write(self.operations.len() - 1);
+    }
+
+    /// Returns an error if a diagonal gate does not have `2^k` phases for its `k` qubits
+    fn check_phases(gate: &QuantumGate, num_qubits: usize) -> Result<(), CircuitError> {
+        match gate {
+            QuantumGate::Diagonal(phases) if num_qubits == 0 || 1usize.checked_shl(num_qubits as u32) != Some(phases.len()) => {
+                Err(CircuitError::PhaseCountMismatch { num_qubits, found: phases.len() })
+            },
+            _ => Ok(()),
+        }
+    }
+
+    /// Panics if any qubit is out of bounds or listed twice
+    fn validate_qubits(&self, qubits: &[usize]) {
+        if let Err(error) = self.check_qubits(qubits) {
+            panic!("{}", error);
+        }
+    }
+
+    /// Returns an error if any qubit is out of bounds or listed twice
+    fn check_qubits(&self, qubits: &[usize]) -> Result<(), CircuitError> {
+        for (i, &qubit) in qubits.iter().enumerate() {
+            if qubit >= self.num_qubits {
+                return Err(CircuitError::QubitOutOfRange { qubit, num_qubits: self.num_qubits });
+            }
+            if qubits[..i].contains(&qubit) {
+                return Err(CircuitError::DuplicateQubit { qubit });
+            }
+        }
+        Ok(())
+    }
+
+    /// Advances the steps of the given qubits past their latest operation
+    fn next_step(&mut self, qubits: &[usize]) -> usize {
+        let step = qubits.iter().map(|&q| self.last_step[q]).max().unwrap_or(0) + 1;
+        for &qubit in qubits {
+            self.last_step[qubit] = step;
+        }
+        step
+    }
+
+    /// Applies a single-qubit gate by a direct 2x2 update of every pair of amplitudes
+    /// that differ in the target qubit
+    fn apply_single_qubit_gate<A: Amplitude>(&self, state_vector: &mut [A], gate: &QuantumGate, target: usize) {
+        let mask = 1 << target;
+        let [m00, m01, m10, m11] = gate.entries::<A>()[..] else {
+            panic!("Gate {} does not act on a single qubit", gate.name());
+        };
+
+        // Every block of 2·mask amplitudes holds the states with the target qubit unset
+        // in its first half, each paired with the state at the same offset in the second
+        for block in state_vector.chunks_exact_mut(2 * mask) {
+            let (zeros, ones) = block.split_at_mut(mask);
+            for (zero, one) in zeros.iter_mut().zip(ones) {
+                let (a, b) = (*zero, *one);
+                *zero = m00 * a + m01 * b;
+                *one = m10 * a + m11 * b;
+            }
+        }
+    }
+
+    /// Multiplies every amplitude by the phase of its basis state on the given qubits
+    /// 
+    /// The first qubit in `qubits` is the most significant bit of the phase index.
+    fn apply_diagonal_gate<A: Amplitude>(&self, state_vector: &mut [A], phases: &[f64], qubits: &[usize]) {
+        let factors: Vec<A> = phases.iter().map(|&phase| A::from_complex(Complex::new(0.0, phase).exp())).collect();
+        let k = qubits.len();
+
+        for (index, amplitude) in state_vector.iter_mut().enumerate() {
+            let sub_index = qubits.iter()
+                .enumerate()
+                .fold(0, |acc, (j, &q)| acc | (((index >> q) & 1) << (k - 1 - j)));
+            *amplitude = factors[sub_index] * *amplitude;
+        }
+    }
+
+    /// Applies a gate acting on several qubits
+    /// 
+    /// The first qubit in `qubits` corresponds to the most significant bit of the
+    /// matrix index, so `[control, target]` matches the usual CNOT/CZ matrices.
+    fn apply_multi_qubit_gate<A: Amplitude>(&self, state_vector: &mut [A], gate: &QuantumGate, qubits: &[usize]) {
+        let k = qubits.len();
+        let sub_dim = 1 << k;
+        let entries: Vec<A> = gate.entries();
+
+        // Offset of every basis state of the subspace relative to its base index
+        let offsets: Vec<usize> = (0..sub_dim)
+            .map(|m| {
+                qubits.iter()
+                    .enumerate()
+                    .filter(|&(j, _)| (m >> (k - 1 - j)) & 1 == 1)
+                    .map(|(_, &q)| 1 << q)
+                    .sum()
+            })
+            .collect();
+        let mask: usize = qubits.iter().map(|&q| 1 << q).sum();
+
+        let mut amplitudes = vec![A::ZERO; sub_dim];
+        for base in 0..state_vector.len() {
+            if base & mask != 0 {
+                continue;
+            }
+
+            for (amplitude, &offset) in amplitudes.iter_mut().zip(&offsets) {
+                *amplitude = state_vector[base | offset];
+            }
+
+            for (row, &offset) in entries.chunks_exact(sub_dim).zip(&offsets) {
+                state_vector[base | offset] = row.iter()
+                    .zip(&amplitudes)
+                    .fold(A::ZERO, |acc, (m, a)| acc + *m * *a);
+            }
+        }
+    }
+
+    /// Applies a CNOT in place by swapping the amplitudes of every pair of basis states
+    /// that differ in the target qubit and have the control qubit set
+    fn apply_cnot<A: Amplitude>(&self, state_vector: &mut [A], control: usize, target: usize) {
+        let control_mask = 1 << control;
+        let target_mask = 1 << target;
+
+        // Visit each pair once, from the member whose target bit is 0
+        for i in 0..state_vector.len() {
+            if i & control_mask != 0 && i & target_mask == 0 {
+                state_vector.swap(i, i | target_mask);
+            }
+        }
+    }
+
+    /// Executes the circuit on a set of qubits
+    /// 
+    /// Measurements are ignored, so classical bits read as 0 in conditions. Amplitudes
+    /// are cleaned up according to the process-wide
+    /// [`Tolerance`].
+    /// 
+    /// # Arguments
+    /// * `qubits` - A slice of qubits to apply the circuit to
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// 
+    /// let mut qc = QuantumCircuit::new(1);
+    /// qc.h(0);
+    /// 
+    /// let state = qc.execute();
+    /// assert!((state.probabilities()[1] - 0.5).abs() < 1e-6);
+    /// ```
+    pub fn execute(&self) -> StateVector {
+        let _span = span!(INFO, "execute", qubits = self.num_qubits, operations = self.operations.len());
+        let mut state_vector: Vec<Complex> = self.initial_state();
+
+        for op in self.unmeasured_operations() {
+            self.apply_operation(&mut state_vector, op);
+        }
+
+        StateVector::new(Tolerance::global().round_state(&state_vector))
+    }
+
+    /// Returns the operations applied when measurements are ignored
+    /// 
+    /// Measurements, including their basis changes, and classical operations are left
+    /// out. Without them every classical bit stays 0, so operations conditioned on a
+    /// classical bit being 1 are skipped, and the body of a loop is applied once.
+    pub(crate) fn unmeasured_operations(&self) -> impl Iterator<Item = &GateOp> {
+        self.operations.iter().filter(|op| Self::applies_unmeasured(op))
+    }
+
+    /// Returns whether an operation is applied when measurements are ignored
+    fn applies_unmeasured(op: &GateOp) -> bool {
+        !op.gate.is_measurement()
+            && !matches!(op.gate, QuantumGate::Classical(_))
+            && op.condition.is_none_or(|(_, value)| !value)
+    }
+
+    /// Returns the |0...0⟩ statevector the circuit starts from
+    pub(crate) fn initial_state<A: Amplitude>(&self) -> Vec<A> {
+        let dim = 1 << self.num_qubits;
+        let mut state_vector = vec![A::ZERO; dim];
+
+        // Selecting first state as active state
+        state_vector[0] = A::ONE;
+        state_vector
+    }
+
+    /// Applies a single operation to the statevector
+    /// 
+    /// Snapshots, classical operations and computational-basis measurements leave the
+    /// state untouched. X- and Y-basis measurements apply their rotation onto the
+    /// computational basis; collapsing the state is left to the simulator. Loops apply
+    /// their body once, ignoring its measurements, and composite gates their definition.
+    pub(crate) fn apply_operation<A: Amplitude>(&self, state_vector: &mut [A], op: &GateOp) {
+        match op.gate {
+            QuantumGate::Measure | QuantumGate::Snapshot(_) | QuantumGate::Classical(_) => return,
+            QuantumGate::RepeatUntil { ref body, .. } => {
+                for inner in body.iter().filter(|inner| Self::applies_unmeasured(inner)) {
+                    self.apply_operation(state_vector, inner);
+                }
+                return;
+            },
+            QuantumGate::Composite { ref body, .. } => {
+                for inner in body {
+                    self.apply_operation(state_vector, &Self::map_qubits(inner, &op.qubit));
+                }
+                return;
+            },
+            QuantumGate::MeasureX => {
+                self.apply_single_qubit_gate(state_vector, &QuantumGate::H, op.target());
+                return;
+            },
+            QuantumGate::MeasureY => {
+                self.apply_single_qubit_gate(state_vector, &QuantumGate::Sdg, op.target());
+                self.apply_single_qubit_gate(state_vector, &QuantumGate::H, op.target());
+                return;
+            },
+            _ => {},
+        }
+
+        if let QuantumGate::Diagonal(phases) = &op.gate {
+            self.apply_diagonal_gate(state_vector, phases, &op.qubit);
+            return;
+        }
+
+        match op.gate.arity() {
+            // single qubit gates
+            1 => {
+                self.apply_single_qubit_gate(state_vector, &op.gate, op.target());
+            },
+            _ => {
+                if op.gate == QuantumGate::CNOT {
+                    self.apply_cnot(state_vector, op.controls()[0], op.target());
+                } else {
+                    self.apply_multi_qubit_gate(state_vector, &op.gate, &op.qubit);
+                }
+            },
+        }
     }
 
     /// Returns the number of qubits in the circuit
@@ -447,85 +1753,481 @@ impl QuantumCircuit {
         self.operations.len()
     }
 
+    /// Returns the number of classical bits used by measurements and conditions
+    pub fn num_classical_bits(&self) -> usize {
+        self.classical_bits.len()
+    }
+
+    /// Sets the label of a qubit, shown instead of `q0`, `q1`, … in diagrams
+    /// 
+    /// # Panics
+    /// Panics if the qubit is out of bounds
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// 
+    /// let mut qc = QuantumCircuit::new(2);
+    /// qc.label_qubit(1, "anc");
+    /// qc.cnot(0, 1);
+    /// 
+    /// assert_eq!(qc.qubit_label(1), "anc");
+    /// assert!(qc.draw().starts_with("q0 : ─●─"));
+    /// assert!(qc.draw().contains("anc: ─X─"));
+    /// ```
+    pub fn label_qubit<S: Into<String>>(&mut self, qubit: usize, label: S) {
+        if qubit >= self.num_qubits {
+            panic!("Qubit index {} out of bounds for circuit of {} qubits", qubit, self.num_qubits);
+        }
+        self.qubit_labels.insert(qubit, label.into());
+    }
+
+    /// Sets the label of a classical bit, shown instead of `c0`, `c1`, … in diagrams
+    /// 
+    /// The bit does not need to be used yet, as the classical register grows with the
+    /// measurements and conditions added to the circuit.
+    pub fn label_clbit<S: Into<String>>(&mut self, bit: usize, label: S) {
+        self.clbit_labels.insert(bit, label.into());
+    }
+
+    /// Returns the label of a qubit, `q` followed by its index unless set with
+    /// [`QuantumCircuit::label_qubit`]
+    pub fn qubit_label(&self, qubit: usize) -> String {
+        self.qubit_labels.get(&qubit).cloned().unwrap_or_else(|| format!("q{}", qubit))
+    }
+
+    /// Returns the label of a classical bit, `c` followed by its index unless set with
+    /// [`QuantumCircuit::label_clbit`]
+    pub fn clbit_label(&self, bit: usize) -> String {
+        self.clbit_labels.get(&bit).cloned().unwrap_or_else(|| format!("c{}", bit))
+    }
+
+    /// Returns the labels of all qubits followed by a colon, padded to the same width
+    pub(crate) fn wire_labels(&self) -> Vec<String> {
+        pad_labels((0..self.num_qubits).map(|qubit| self.qubit_label(qubit)).collect())
+    }
+
+    /// Returns the operations of the circuit in the order they were added
+    pub fn operations(&self) -> &[GateOp] {
+        &self.operations
+    }
+
+    /// Appends the operations of another circuit, mapping its qubit `i` to `qubits[i]`
+    /// 
+    /// # Panics
+    /// Panics if `qubits` does not provide a qubit for every qubit of `other`, or if
+    /// a mapped qubit is out of bounds
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// 
+    /// let mut bell = QuantumCircuit::new(2);
+    /// bell.h(0);
+    /// bell.cnot(0, 1);
+    /// 
+    /// let mut qc = QuantumCircuit::new(4);
+    /// qc.compose(&bell, &[2, 3]);
+    /// assert_eq!(qc.operations()[1].qubit, vec![2, 3]);
+    /// ```
+    pub fn compose(&mut self, other: &QuantumCircuit, qubits: &[usize]) {
+        if qubits.len() != other.num_qubits {
+            panic!("Expected {} qubits to compose a circuit onto, got {}", other.num_qubits, qubits.len());
+        }
+
+        for op in &other.operations {
+            self.add_operation(Self::map_qubits(op, qubits));
+        }
+    }
+
+    /// Defines a named gate from a sub-circuit, to be applied with [`QuantumCircuit::call_gate`]
+    /// 
+    /// Every call shows up as a single box in diagrams and is only expanded into the
+    /// operations of the definition when the circuit is executed, simulated or transpiled.
+    /// 
+    /// # Arguments
+    /// * `name` - The name of the gate
+    /// * `definition` - The circuit implementing the gate
+    /// 
+    /// # Panics
+    /// Panics if a gate with the same name is already defined, or if the definition
+    /// contains measurements, snapshots, classical operations, loops, conditioned
+    /// operations or attached noise
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// 
+    /// let mut bell = QuantumCircuit::new(2);
+    /// bell.h(0);
+    /// bell.cnot(0, 1);
+    /// 
+    /// let mut qc = QuantumCircuit::new(4);
+    /// qc.define_gate("bell", bell);
+    /// qc.call_gate("bell", &[0, 1]);
+    /// qc.call_gate("bell", &[2, 3]);
+    /// 
+    /// assert_eq!(qc.num_operations(), 2);
+    /// assert!((qc.execute().probabilities()[0b1111] - 0.25).abs() < 1e-6);
+    /// ```
+    pub fn define_gate<S: Into<String>>(&mut self, name: S, definition: QuantumCircuit) {
+        let name = name.into();
+        if self.definitions.contains_key(&name) {
+            panic!("A gate named \"{}\" is already defined", name);
+        }
+        let unitary = definition.operations.iter()
+            .all(|op| op.gate.inverse().is_some() && op.condition.is_none() && op.noise.is_none());
+        if !unitary {
+            panic!("Gate definitions can only contain unconditioned unitary gates without noise");
+        }
+
+        let gate = QuantumGate::Composite {
+            name: name.clone(),
+            matrix: definition.unitary(),
+            body: definition.operations,
+        };
+        self.definitions.insert(name, gate);
+    }
+
+    /// Applies a gate defined with [`QuantumCircuit::define_gate`]
+    /// 
+    /// # Arguments
+    /// * `name` - The name of the gate
+    /// * `qubits` - The qubits the qubits of the definition are mapped to, in order
+    /// 
+    /// # Panics
+    /// Panics if no gate with this name is defined, if the number of qubits does not
+    /// match the definition, or if a qubit is out of bounds or listed twice
+    pub fn call_gate(&mut self, name: &str, qubits: &[usize]) {
+        let Some(gate) = self.definitions.get(name) else {
+            panic!("No gate named \"{}\" is defined", name);
+        };
+        if gate.arity() != qubits.len() {
+            panic!("Gate \"{}\" acts on {} qubits, got {}", name, gate.arity(), qubits.len());
+        }
+        self.add_multi_qubit_gate(gate.clone(), qubits);
+    }
+
+    /// Returns the circuit undoing this one, with the inverse gates in reverse order
+    /// 
+    /// # Panics
+    /// Panics if the circuit contains measurements, snapshots or conditioned operations
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// 
+    /// let mut qc = QuantumCircuit::new(1);
+    /// qc.h(0);
+    /// qc.t(0);
+    /// 
+    /// let inverse = qc.inverse();
+    /// assert_eq!(inverse.operations()[0].gate.name(), "T†");
+    /// ```
+    pub fn inverse(&self) -> QuantumCircuit {
+        let mut inverse = QuantumCircuit::new(self.num_qubits);
+        inverse.qubit_labels = self.qubit_labels.clone();
+        inverse.clbit_labels = self.clbit_labels.clone();
+        for op in self.operations.iter().rev() {
+            let gate = op.gate.inverse()
+                .filter(|_| op.condition.is_none())
+                .unwrap_or_else(|| panic!("Cannot invert the non-unitary operation {}", op.gate));
+
+            inverse.add_operation(GateOp { gate, step: 0, ..op.clone() });
+        }
+        inverse
+    }
+
+    /// Returns the unitary matrix implemented by the circuit
+    /// 
+    /// Column `j` holds the final state of the circuit applied to basis state `j`.
+    /// Measurements and snapshots are ignored, so classical bits read as 0 in conditions.
+    /// The matrix has `4^n` entries, so this is only practical for small circuits.
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::{QuantumCircuit, QuantumGate};
+    /// 
+    /// let mut qc = QuantumCircuit::new(1);
+    /// qc.h(0);
+    /// assert!(qc.unitary().is_unitary());
+    /// ```
+    pub fn unitary(&self) -> Matrix<Complex> {
+        let dim = 1 << self.num_qubits;
+        let mut unitary = Matrix::zeros(dim, dim);
+
+        for col in 0..dim {
+            let mut state = vec![Complex::new(0.0, 0.0); dim];
+            state[col] = Complex::new(1.0, 0.0);
+            for op in self.unmeasured_operations() {
+                self.apply_operation(&mut state, op);
+            }
+            for (row, amplitude) in state.into_iter().enumerate() {
+                unitary.set(row, col, amplitude);
+            }
+        }
+        unitary
+    }
+
+    /// Returns mutable access to the operations, e.g. to rebind rotation angles
+    pub(crate) fn operations_mut(&mut self) -> &mut [GateOp] {
+        &mut self.operations
+    }
+
     /// Displays the quantum circuit in ASCII format to stdout
+    /// 
+    /// See [`QuantumCircuit::draw`] for environments without a standard output.
     pub fn display(&self) {
+        print!("{}", self.draw());
+    }
+
+    /// Returns the ASCII diagram of the circuit, one line per wire and gap
+    /// 
+    /// Wires are labelled with [`QuantumCircuit::qubit_label`], padded to the same width,
+    /// and every column is as wide as its widest cell, so that connectors run straight.
+    /// Classical bits are drawn as double rails below the qubits, labelled with
+    /// [`QuantumCircuit::clbit_label`]. Every measurement is connected to the rail of its
+    /// bit by an arrow `▼`, every conditioned operation to the rail it reads by a marker
+    /// showing the tested value, `●` for 1 and `○` for 0, and classical operations are
+    /// written on the rail of the bit they set.
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// 
+    /// let mut qc = QuantumCircuit::new(2);
+    /// qc.h(0);
+    /// qc.cnot(0, 1);
+    /// 
+    /// let diagram = qc.draw();
+    /// assert_eq!(diagram.lines().count(), 3);
+    /// assert!(diagram.starts_with("q0: ─H─"));
+    /// 
+    /// // Feed-forward: flip qubit 1 when qubit 0 was measured as 1
+    /// let mut qc = QuantumCircuit::new(2);
+    /// qc.h(0);
+    /// qc.measure(0, 0);
+    /// qc.x(1);
+    /// qc.c_if(0, true);
+    /// 
+    /// let diagram = qc.draw();
+    /// let lines: Vec<&str> = diagram.lines().map(str::trim_end).collect();
+    /// assert_eq!(lines, [
+    ///     "q0: ─H──[M]────",
+    ///     "         ║",
+    ///     "q1: ─────╫───X─",
+    ///     "         ║   ║",
+    ///     "c0: ═════▼═══●═",
+    /// ]);
+    /// ```
+    pub fn draw(&self) -> String {
+        let labels = pad_labels((0..self.num_qubits).map(|qubit| self.qubit_label(qubit))
+            .chain((0..self.classical_bits.len()).map(|bit| self.clbit_label(bit)))
+            .collect());
+
         // Handle empty circuit case
         if self.operations.is_empty() {
-            for i in 0..self.num_qubits {
-                println!("q{}: ───", i);
+            return labels.iter().map(|label| format!("{}───\n", label)).collect();
+        }
+
+        let connectors = [" │ ", " ░ ", " ║ "];
+        let mut grid = self.diagram_grid();
+        let quantum_rows = grid.len();
+        self.add_classical_rails(&mut grid);
+        let gap = " ".repeat(labels.first().map_or(0, |label| label.chars().count()));
+
+        // Every cell of a column is centred on its widest cell, so connectors stay straight
+        let columns = grid.first().map_or(0, Vec::len);
+        let widths: Vec<usize> = (0..columns)
+            .map(|col| grid.iter().map(|row| row[col].chars().count()).max().unwrap_or(0))
+            .collect();
+
+        let mut diagram = String::new();
+        for (i, grid_row) in grid.iter().enumerate() {
+            if i % 2 == 0 {
+                diagram.push_str(&labels[i / 2]);
+            } else {
+                diagram.push_str(&gap);
+            }
+            
+            // Write the row contents
+            for (cell, &width) in grid_row.iter().zip(&widths).skip(1) {
+                let padded = match (i % 2, i < quantum_rows) {
+                    (1, _) if connectors.contains(&cell.as_str()) => pad_cell(cell, width, ' '),
+                    (1, _) => " ".repeat(width),
+                    (_, true) => pad_cell(cell, width, '─'),
+                    (_, false) => pad_cell(cell, width, '═'),
+                };
+                diagram.push_str(&padded);
+            }
+            diagram.push('\n');
+        }
+        diagram
+    }
+
+    /// Appends a gap and a rail row for every classical bit to the cells of the diagram
+    /// 
+    /// Vertical double lines run from measurements and conditioned operations down to
+    /// the rail of their bit, crossing the wires and rails in between.
+    fn add_classical_rails(&self, grid: &mut Vec<Vec<String>>) {
+        let quantum_rows = grid.len();
+        let columns = grid.first().map_or(0, Vec::len);
+        for _ in 0..self.classical_bits.len() {
+            grid.push(vec!["   ".to_string(); columns]);
+            grid.push(vec!["═══".to_string(); columns]);
+        }
+        let rail = |bit: usize| quantum_rows + 1 + 2 * bit;
+
+        // Connects two rows of a column, leaving both ends untouched
+        let connect = |grid: &mut Vec<Vec<String>>, col: usize, from: usize, to: usize| {
+            for (row, cells) in grid.iter_mut().enumerate().take(from.max(to)).skip(from.min(to) + 1) {
+                let cell = &mut cells[col];
+                let crossing = match cell.as_str() {
+                    " ░ " | " │ " => continue,
+                    _ if row % 2 == 1 => " ║ ",
+                    "───" => "─╫─",
+                    "═══" => "═╬═",
+                    _ => continue,
+                };
+                *cell = crossing.to_string();
+            }
+        };
+
+        for op in &self.operations {
+            let col = op.step;
+            if col >= columns {
+                continue;
+            }
+            let bottom = op.qubit.iter().map(|&qubit| 2 * qubit).max();
+
+            match (&op.gate, op.classical_bit, bottom) {
+                (QuantumGate::Classical(_), Some(bit), _) => grid[rail(bit)][col] = op.gate.display_symbol(),
+                (gate, Some(bit), Some(row)) if gate.is_measurement() => {
+                    connect(grid, col, row, rail(bit));
+                    grid[rail(bit)][col] = "═▼═".to_string();
+                },
+                _ => {},
+            }
+
+            if let Some((bit, value)) = op.condition {
+                let from = bottom.or(op.classical_bit.map(rail)).unwrap_or(quantum_rows);
+                connect(grid, col, from, rail(bit));
+                grid[rail(bit)][col] = if value { "═●═" } else { "═○═" }.to_string();
             }
-            return;
         }
+    }
 
-        let max_step = *self.last_step.iter()
+    /// Returns the cells of the diagram, one row per wire and gap and one column per step
+    /// 
+    /// Column 0 is left empty since steps start at 1. Wire rows hold gate symbols, control
+    /// dots or plain wire, gap rows hold vertical connectors, barriers or plain wire.
+    pub(crate) fn diagram_grid(&self) -> Vec<Vec<String>> {
+        // Classical operations take a step of their own without occupying a qubit
+        let max_step = self.last_step.iter()
+            .chain(self.operations.iter().map(|op| &op.step))
             .max()
-            .unwrap_or(&0);
+            .copied()
+            .unwrap_or(0);
         
-        let height = 2 * self.num_qubits - 1;
+        let height = (2 * self.num_qubits).saturating_sub(1);
         
         // Misc symbols
         let wire = "───".to_string();
         let vert_line = " │ ".to_string();
         let ctrl_dot = "─●─".to_string();
+        let barrier = " ░ ".to_string();
         
         let mut grid = vec![vec![wire; max_step + 1]; height];
         
         for op in &self.operations {
             let row = 2 * op.target();
             let col = op.step;
+
+            // Snapshots are drawn as a barrier across every wire
+            if let QuantumGate::Snapshot(_) = op.gate {
+                for (r, grid_row) in grid.iter_mut().enumerate() {
+                    grid_row[col] = if r % 2 == 0 { op.gate.display_symbol() } else { barrier.clone() };
+                }
+                continue;
+            }
             
+            // Classical operations do not touch any wire
+            if op.qubit.is_empty() {
+                continue;
+            }
+
             // Skip if the operation is out of bounds (safety check)
             if row >= height || col > max_step {
                 continue;
             }
             
-            match op.gate.arity() {
-                1 => {
-                    grid[row][col] = op.gate.display_symbol();
-                },
-                2 => {
-                    let control = op.controls()[0];
-                    let ctrl_row = 2 * control;
-                    
-                    // Skip if control is out of bounds
-                    if ctrl_row >= height {
-                        continue;
-                    }
-                    
-                    grid[ctrl_row][col] = ctrl_dot.clone();  
-                    grid[row][col] = op.gate.display_symbol();
-                    
-                    let (start, end) = if ctrl_row < row {
-                        (ctrl_row + 1, row)
-                    } else {
-                        (row + 1, ctrl_row)
-                    };
-                    
-                    for r in start..end {
-                        grid[r][col] = vert_line.clone(); 
-                    }
-                },
-                _ => {}
+            if op.qubit.len() == 1 {
+                grid[row][col] = op.gate.display_symbol();
+                continue;
             }
-        }
-        
-        for i in 0..height {
-            if i % 2 == 0 {
-                print!("q{}: ", i/2);
-            } else {
-                print!("    ");  
+
+            let rows: Vec<usize> = op.qubit.iter().map(|&q| 2 * q).collect();
+
+            // Skip if any involved qubit is out of bounds
+            if rows.iter().any(|&r| r >= height) {
+                continue;
             }
-            
-            // Print the row contents
-            for j in 1..=max_step {
-                if i % 2 == 1 && grid[i][j] != vert_line {
-                    print!("   ");  
-                } else {
-                    print!("{}", grid[i][j]);
+
+            // Controls are drawn as dots, every other involved qubit gets the gate symbol
+            let num_controls = op.gate.num_controls();
+            for (j, &r) in rows.iter().enumerate() {
+                grid[r][col] = if j < num_controls { ctrl_dot.clone() } else { op.gate.display_symbol() };
+            }
+
+            let start = rows.iter().min().unwrap() + 1;
+            let end = *rows.iter().max().unwrap();
+            for (r, grid_row) in grid.iter_mut().enumerate().take(end).skip(start) {
+                if !rows.contains(&r) {
+                    grid_row[col] = vert_line.clone();
                 }
             }
-            println!();
         }
+        grid
+    }
+}
+
+/// Centres a diagram cell in a column of the given width, extending it with `fill`
+pub(crate) fn pad_cell(cell: &str, width: usize, fill: char) -> String {
+    let missing = width.saturating_sub(cell.chars().count());
+    let left = missing / 2;
+    let mut padded: String = std::iter::repeat_n(fill, left).collect();
+    padded.push_str(cell);
+    padded.extend(std::iter::repeat_n(fill, missing - left));
+    padded
+}
+
+/// Appends a colon to every label and pads them to the same width
+fn pad_labels(labels: Vec<String>) -> Vec<String> {
+    let width = labels.iter().map(|label| label.chars().count()).max().unwrap_or(0);
+    labels.into_iter().map(|label| format!("{:<width$}: ", label)).collect()
+}
+
+impl Index<usize> for QuantumCircuit {
+    type Output = GateOp;
+
+    /// Returns the operation at the given position
+    /// 
+    /// # Panics
+    /// Panics if the index is out of bounds
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::{QuantumCircuit, QuantumGate};
+    /// 
+    /// let mut qc = QuantumCircuit::new(2);
+    /// qc.h(0);
+    /// qc.cnot(0, 1);
+    /// 
+    /// assert_eq!(qc[0].gate, QuantumGate::H);
+    /// assert_eq!(qc[1].qubit, vec![0, 1]);
+    /// ```
+    fn index(&self, index: usize) -> &GateOp {
+        &self.operations[index]
     }
 }
 
@@ -534,14 +2236,27 @@ impl fmt::Display for QuantumCircuit {
         writeln!(f, "Quantum Circuit ({} qubits, {} operations):", 
                  self.num_qubits, self.num_operations())?;
         for (i, op) in self.operations.iter().enumerate() {
-            if op.gate == QuantumGate::CNOT {
-                
-                writeln!(f, "  {}. {} on qubit {} by {} (Step: {})", 
-                        i + 1, op.gate, op.target(), op.controls()[0], op.step)?;
+            let condition = op.condition
+                .map_or(String::new(), |(bit, value)| format!(" if c{} == {}", bit, value as u8));
+
+            if let QuantumGate::Snapshot(label) = &op.gate {
+                writeln!(f, "  {}. Snapshot \"{}\" (Step: {})", 
+                         i + 1, label, op.step)?;
+            } else if let (QuantumGate::Classical(classical), Some(target)) = (&op.gate, op.classical_bit) {
+                writeln!(f, "  {}. c{} = {}{} (Step: {})",
+                         i + 1, target, classical, condition, op.step)?;
+            } else if op.gate.num_controls() > 0 {
+                let controls: Vec<String> = op.controls().iter().map(|c| c.to_string()).collect();
+                writeln!(f, "  {}. {} on qubit {} by {}{} (Step: {})", 
+                        i + 1, op.gate, op.target(), controls.join(", "), condition, op.step)?;
                 
+            } else if op.qubit.len() > 1 {
+                let qubits: Vec<String> = op.qubit.iter().map(|q| q.to_string()).collect();
+                writeln!(f, "  {}. {} on qubits {}{} (Step: {})", 
+                         i + 1, op.gate, qubits.join(", "), condition, op.step)?;
             } else {
-                writeln!(f, "  {}. {} on qubit {} (Step: {})", 
-                         i + 1, op.gate, op.target(), op.step)?;
+                writeln!(f, "  {}. {} on qubit {}{} (Step: {})", 
+                         i + 1, op.gate, op.target(), condition, op.step)?;
             }
         }
         Ok(())
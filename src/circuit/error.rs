@@ -0,0 +1,63 @@
+use std::fmt;
+
+/// Errors that can occur while adding operations to a [`QuantumCircuit`](super::QuantumCircuit)
+#[derive(Debug, Clone, PartialEq)]
+pub enum CircuitError {
+    /// A qubit index is not smaller than the number of qubits of the circuit
+    QubitOutOfRange {
+        /// The offending qubit index
+        qubit: usize,
+        /// Number of qubits of the circuit
+        num_qubits: usize,
+    },
+    /// The same qubit is listed twice in one operation
+    DuplicateQubit {
+        /// The repeated qubit index
+        qubit: usize,
+    },
+    /// The number of qubits does not match the arity of the gate
+    ArityMismatch {
+        /// Name of the gate
+        gate: String,
+        /// Number of qubits the gate acts on
+        expected: usize,
+        /// Number of qubits given
+        found: usize,
+    },
+    /// A diagonal gate does not have one phase per basis state of its qubits
+    PhaseCountMismatch {
+        /// Number of qubits the gate is applied to
+        num_qubits: usize,
+        /// Number of phases of the gate
+        found: usize,
+    },
+}
+
+impl fmt::Display for CircuitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CircuitError::QubitOutOfRange { qubit, num_qubits } => write!(
+                f,
+                "Qubit index {} is out of bounds for circuit with {} qubits",
+                qubit, num_qubits
+            ),
+            CircuitError::DuplicateQubit { qubit } => write!(
+                f,
+                "Qubit {} is used more than once in the same operation",
+                qubit
+            ),
+            CircuitError::ArityMismatch { gate, expected, found } => write!(
+                f,
+                "Gate {} acts on {} qubits, but {} were given",
+                gate, expected, found
+            ),
+            CircuitError::PhaseCountMismatch { num_qubits, found } => write!(
+                f,
+                "A diagonal gate on {} qubits needs {} phases, got {}",
+                num_qubits, 1usize << num_qubits, found
+            ),
+        }
+    }
+}
+
+impl std::error::Error for CircuitError {}
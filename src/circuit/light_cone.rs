@@ -0,0 +1,89 @@
+use crate::core::gate::{GateOp, QuantumGate};
+use super::QuantumCircuit;
+
+impl QuantumCircuit {
+    /// Returns the part of the circuit that can influence the given qubits
+    /// 
+    /// Walking the circuit backwards from the end, an operation belongs to the causal
+    /// light cone if it acts on a qubit of the cone, and then adds all of its qubits to
+    /// it. The remaining operations act after, or apart from, everything the qubits
+    /// depend on, so they cannot change the reduced state of the qubits nor the
+    /// expectation value of an observable supported on them. The light cone is returned
+    /// as a circuit over its qubits only, together with the original index of each of
+    /// its qubits in ascending order.
+    /// 
+    /// Snapshots are dropped. Circuits with measurements, classical operations, loops or
+    /// conditioned operations are kept whole, since classical bits can carry influence
+    /// across the light cone.
+    /// 
+    /// # Arguments
+    /// * `qubits` - The qubits whose light cone is computed
+    /// 
+    /// # Panics
+    /// Panics if a qubit is out of bounds
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// 
+    /// let mut qc = QuantumCircuit::new(6);
+    /// for qubit in 0..6 {
+    ///     qc.h(qubit);
+    /// }
+    /// qc.cnot(0, 1);
+    /// qc.cnot(2, 3);
+    /// qc.cnot(1, 2);
+    /// qc.cnot(4, 5);
+    /// 
+    /// // Qubit 0 only interacts with qubit 1, before qubit 1 meets qubit 2
+    /// let (cone, qubits) = qc.light_cone(&[0]);
+    /// assert_eq!(qubits, vec![0, 1]);
+    /// assert_eq!(cone.num_operations(), 3);
+    /// 
+    /// // Qubit 2 depends on qubits 0 to 3
+    /// let (cone, qubits) = qc.light_cone(&[2]);
+    /// assert_eq!(qubits, vec![0, 1, 2, 3]);
+    /// assert_eq!(cone.num_operations(), 7);
+    /// ```
+    pub fn light_cone(&self, qubits: &[usize]) -> (QuantumCircuit, Vec<usize>) {
+        if let Some(&qubit) = qubits.iter().find(|&&qubit| qubit >= self.num_qubits()) {
+            panic!("Qubit {} is out of bounds for a circuit of {} qubits", qubit, self.num_qubits());
+        }
+
+        let classical = self.operations().iter().any(|op| {
+            op.gate.is_measurement()
+                || op.condition.is_some()
+                || matches!(op.gate, QuantumGate::Classical(_) | QuantumGate::RepeatUntil { .. })
+        });
+        if classical {
+            return (self.clone(), (0..self.num_qubits()).collect());
+        }
+
+        let mut in_cone = vec![false; self.num_qubits()];
+        for &qubit in qubits {
+            in_cone[qubit] = true;
+        }
+        let mut kept: Vec<&GateOp> = Vec::new();
+        for op in self.operations().iter().rev() {
+            if matches!(op.gate, QuantumGate::Snapshot(_)) || !op.qubit.iter().any(|&q| in_cone[q]) {
+                continue;
+            }
+            for &qubit in &op.qubit {
+                in_cone[qubit] = true;
+            }
+            kept.push(op);
+        }
+
+        let cone_qubits: Vec<usize> = (0..self.num_qubits()).filter(|&q| in_cone[q]).collect();
+        let mut index = vec![0; self.num_qubits()];
+        for (i, &qubit) in cone_qubits.iter().enumerate() {
+            index[qubit] = i;
+        }
+
+        let mut cone = QuantumCircuit::new(cone_qubits.len());
+        for op in kept.into_iter().rev() {
+            cone.add_operation(GateOp { qubit: op.qubit.iter().map(|&q| index[q]).collect(), ..op.clone() });
+        }
+        (cone, cone_qubits)
+    }
+}
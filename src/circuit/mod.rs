@@ -4,6 +4,22 @@
 //! and execute operations on them with visualisation features
 
 
+pub mod basis;
+#[allow(clippy::module_inception)]
 pub mod circuit;
+mod error;
+mod light_cone;
+pub mod parameterized;
+#[cfg(feature = "plotters")]
+pub mod render;
+pub mod state_vector;
+pub mod timeline;
 
-pub use circuit::QuantumCircuit;
\ No newline at end of file
+pub use basis::Basis;
+pub use circuit::QuantumCircuit;
+pub use error::CircuitError;
+pub use parameterized::ParameterizedCircuit;
+#[cfg(feature = "plotters")]
+pub use render::Bitmap;
+pub use state_vector::StateVector;
+pub use timeline::Timeline;
\ No newline at end of file
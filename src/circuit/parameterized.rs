@@ -0,0 +1,139 @@
+use crate::core::gate::QuantumGate;
+use super::QuantumCircuit;
+
+/// A quantum circuit whose rotation angles are bound to parameters at run time
+/// 
+/// Fixed gates are added through [`ParameterizedCircuit::circuit_mut`], parameterized
+/// rotations through [`ParameterizedCircuit::rx`], [`ParameterizedCircuit::ry`] and
+/// [`ParameterizedCircuit::rz`]. A parameter may be used by several rotations.
+/// 
+/// # Examples
+/// ```
+/// use intrico::circuit::ParameterizedCircuit;
+/// 
+/// let mut ansatz = ParameterizedCircuit::new(2);
+/// ansatz.ry(0, 0);
+/// ansatz.circuit_mut().cnot(0, 1);
+/// ansatz.rz(1, 1);
+/// 
+/// assert_eq!(ansatz.num_parameters(), 2);
+/// let circuit = ansatz.bind(&[0.5, 1.0]);
+/// assert_eq!(circuit.num_operations(), 3);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ParameterizedCircuit {
+    /// Template circuit holding placeholder angles for the parameterized rotations
+    circuit: QuantumCircuit,
+    /// Pairs of (operation index, parameter index) for every parameterized rotation
+    bindings: Vec<(usize, usize)>,
+    /// Number of parameters the circuit expects
+    num_parameters: usize,
+}
+
+impl ParameterizedCircuit {
+    /// Creates an empty parameterized circuit with the specified number of qubits
+    pub fn new(num_qubits: usize) -> Self {
+        ParameterizedCircuit {
+            circuit: QuantumCircuit::new(num_qubits),
+            bindings: Vec::new(),
+            num_parameters: 0,
+        }
+    }
+
+    /// Returns the template circuit for adding fixed gates
+    pub fn circuit_mut(&mut self) -> &mut QuantumCircuit {
+        &mut self.circuit
+    }
+
+    /// Applies a Rx gate whose angle is the given parameter
+    /// 
+    /// # Arguments
+    /// * `target` - The index of the qubit to apply the gate to
+    /// * `parameter` - The index of the parameter controlling the angle
+    pub fn rx(&mut self, target: usize, parameter: usize) {
+        self.add_rotation(QuantumGate::Rx(0.0), target, parameter);
+    }
+
+    /// Applies a Ry gate whose angle is the given parameter
+    /// 
+    /// # Arguments
+    /// * `target` - The index of the qubit to apply the gate to
+    /// * `parameter` - The index of the parameter controlling the angle
+    pub fn ry(&mut self, target: usize, parameter: usize) {
+        self.add_rotation(QuantumGate::Ry(0.0), target, parameter);
+    }
+
+    /// Applies a Rz gate whose angle is the given parameter
+    /// 
+    /// # Arguments
+    /// * `target` - The index of the qubit to apply the gate to
+    /// * `parameter` - The index of the parameter controlling the angle
+    pub fn rz(&mut self, target: usize, parameter: usize) {
+        self.add_rotation(QuantumGate::Rz(0.0), target, parameter);
+    }
+
+    fn add_rotation(&mut self, gate: QuantumGate, target: usize, parameter: usize) {
+        self.circuit.add_gate(gate, target);
+        self.bindings.push((self.circuit.num_operations() - 1, parameter));
+        self.num_parameters = self.num_parameters.max(parameter + 1);
+    }
+
+    /// Returns the number of qubits in the circuit
+    pub fn num_qubits(&self) -> usize {
+        self.circuit.num_qubits()
+    }
+
+    /// Returns the number of parameters the circuit expects
+    pub fn num_parameters(&self) -> usize {
+        self.num_parameters
+    }
+
+    /// Returns the number of parameterized rotations in the circuit
+    pub fn num_rotations(&self) -> usize {
+        self.bindings.len()
+    }
+
+    /// Returns the parameter index controlling each parameterized rotation, in circuit order
+    pub fn rotation_parameters(&self) -> impl Iterator<Item = usize> + '_ {
+        self.bindings.iter().map(|&(_, parameter)| parameter)
+    }
+
+    /// Returns a concrete circuit with every parameter replaced by its value
+    /// 
+    /// # Panics
+    /// Panics if the number of values does not match the number of parameters
+    pub fn bind(&self, values: &[f64]) -> QuantumCircuit {
+        self.bind_shifted(values, None)
+    }
+
+    /// Binds the parameters and additionally shifts the angle of a single rotation
+    /// 
+    /// # Arguments
+    /// * `values` - The parameter values
+    /// * `shift` - The index of the rotation (in circuit order) and the amount to shift it by
+    /// 
+    /// # Panics
+    /// Panics if the number of values does not match the number of parameters
+    pub fn bind_shifted(&self, values: &[f64], shift: Option<(usize, f64)>) -> QuantumCircuit {
+        if values.len() != self.num_parameters {
+            panic!("Expected {} parameter values, got {}", self.num_parameters, values.len());
+        }
+
+        let mut circuit = self.circuit.clone();
+        let operations = circuit.operations_mut();
+        for (rotation, &(op_index, parameter)) in self.bindings.iter().enumerate() {
+            let offset = shift
+                .filter(|&(shifted, _)| shifted == rotation)
+                .map_or(0.0, |(_, amount)| amount);
+            let angle = values[parameter] + offset;
+
+            let gate = &mut operations[op_index].gate;
+            *gate = match gate {
+                QuantumGate::Rx(_) => QuantumGate::Rx(angle),
+                QuantumGate::Ry(_) => QuantumGate::Ry(angle),
+                _ => QuantumGate::Rz(angle),
+            };
+        }
+        circuit
+    }
+}
@@ -0,0 +1,172 @@
+use std::{io, path::Path};
+use plotters::coord::Shift;
+use plotters::prelude::*;
+use plotters::style::text_anchor::{HPos, Pos, VPos};
+
+use crate::core::gate::QuantumGate;
+use super::QuantumCircuit;
+
+/// Horizontal space taken by one step of the circuit, in pixels
+const STEP_WIDTH: i32 = 64;
+/// Vertical distance between two wires, in pixels
+const WIRE_SPACING: i32 = 56;
+/// Minimum space left of the first step for the wire labels, in pixels
+const LABEL_WIDTH: i32 = 48;
+/// Margin around the diagram, in pixels
+const MARGIN: i32 = 16;
+/// Side of the box drawn around a gate symbol, in pixels
+const BOX_SIZE: i32 = 36;
+/// Radius of control dots, in pixels
+const DOT_RADIUS: i32 = 5;
+/// Radius of the ⊕ drawn on CNOT targets, in pixels
+const TARGET_RADIUS: i32 = 12;
+/// Font size of gate symbols and wire labels, in points
+const FONT_SIZE: u32 = 16;
+
+/// An RGB image of a circuit diagram
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bitmap {
+    /// Width in pixels
+    pub width: u32,
+    /// Height in pixels
+    pub height: u32,
+    /// Pixels row by row, three bytes (red, green, blue) each
+    pub pixels: Vec<u8>,
+}
+
+impl QuantumCircuit {
+    /// Returns the size in pixels of the rendered diagram
+    pub fn render_size(&self) -> (u32, u32) {
+        let steps = self.operations().iter().map(|op| op.step).max().unwrap_or(0).max(1) as i32;
+        let width = 2 * MARGIN + self.label_width() + steps * STEP_WIDTH;
+        let height = 2 * MARGIN + self.num_qubits().max(1) as i32 * WIRE_SPACING;
+        (width as u32, height as u32)
+    }
+
+    /// Renders the circuit diagram to a PNG file
+    ///
+    /// The layout follows [`QuantumCircuit::draw`]: one column per step, controls as dots,
+    /// CNOT targets as ⊕ and snapshots as dashed barriers. Gate symbols are written with
+    /// the system's sans-serif font.
+    ///
+    /// # Errors
+    /// Returns an error if no font is available or the file cannot be written
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use intrico::QuantumCircuit;
+    ///
+    /// let mut qc = QuantumCircuit::new(2);
+    /// qc.h(0);
+    /// qc.cnot(0, 1);
+    /// qc.render_png("bell.png").unwrap();
+    /// ```
+    pub fn render_png<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let area = BitMapBackend::new(path.as_ref(), self.render_size()).into_drawing_area();
+        self.paint(&area).map_err(io::Error::other)?;
+        area.present().map_err(io::Error::other)
+    }
+
+    /// Renders the circuit diagram to an in-memory RGB bitmap, e.g. for display in a GUI
+    ///
+    /// # Errors
+    /// Returns an error if no font is available
+    pub fn render_bitmap(&self) -> io::Result<Bitmap> {
+        let (width, height) = self.render_size();
+        let mut pixels = vec![0; (width * height * 3) as usize];
+        {
+            let area = BitMapBackend::with_buffer(&mut pixels, (width, height)).into_drawing_area();
+            self.paint(&area).map_err(io::Error::other)?;
+            area.present().map_err(io::Error::other)?;
+        }
+        Ok(Bitmap { width, height, pixels })
+    }
+
+    /// Returns the space left of the first step for the wire labels, in pixels
+    fn label_width(&self) -> i32 {
+        let longest = (0..self.num_qubits()).map(|qubit| self.qubit_label(qubit).chars().count()).max().unwrap_or(0);
+        LABEL_WIDTH.max(longest as i32 * FONT_SIZE as i32 * 6 / 10 + 16)
+    }
+
+    /// Draws the wires and operations onto a drawing area
+    fn paint<DB: DrawingBackend>(&self, area: &DrawingArea<DB, Shift>) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let (width, _) = self.render_size();
+        let label_width = self.label_width();
+        let font = ("sans-serif", FONT_SIZE).into_font().color(&BLACK).pos(Pos::new(HPos::Center, VPos::Center));
+        let wire_y = |qubit: usize| MARGIN + WIRE_SPACING / 2 + qubit as i32 * WIRE_SPACING;
+        let step_x = |step: usize| MARGIN + label_width + (step.max(1) as i32 - 1) * STEP_WIDTH + STEP_WIDTH / 2;
+
+        area.fill(&WHITE)?;
+        for qubit in 0..self.num_qubits() {
+            let y = wire_y(qubit);
+            area.draw(&Text::new(self.qubit_label(qubit), (MARGIN + label_width / 2, y), font.clone()))?;
+            area.draw(&PathElement::new(vec![(MARGIN + label_width, y), (width as i32 - MARGIN, y)], BLACK))?;
+        }
+
+        for op in self.operations() {
+            let x = step_x(op.step);
+
+            if let QuantumGate::Snapshot(_) = op.gate {
+                let bottom = wire_y(self.num_qubits().saturating_sub(1)) + WIRE_SPACING / 2;
+                for y in (MARGIN..bottom).step_by(8) {
+                    area.draw(&PathElement::new(vec![(x, y), (x, (y + 4).min(bottom))], BLACK.stroke_width(2)))?;
+                }
+                continue;
+            }
+
+            // Classical operations do not touch any wire
+            if op.qubit.is_empty() {
+                continue;
+            }
+
+            let ys: Vec<i32> = op.qubit.iter().map(|&qubit| wire_y(qubit)).collect();
+            let (top, bottom) = (*ys.iter().min().unwrap_or(&0), *ys.iter().max().unwrap_or(&0));
+            if ys.len() > 1 {
+                area.draw(&PathElement::new(vec![(x, top), (x, bottom)], BLACK.stroke_width(2)))?;
+            }
+
+            let num_controls = op.gate.num_controls();
+            for &y in &ys[..num_controls] {
+                area.draw(&Circle::new((x, y), DOT_RADIUS, BLACK.filled()))?;
+            }
+
+            let targets = &ys[num_controls..];
+            match &op.gate {
+                QuantumGate::CNOT | QuantumGate::Toffoli => {
+                    for &y in targets {
+                        area.draw(&Circle::new((x, y), TARGET_RADIUS, WHITE.filled()))?;
+                        area.draw(&Circle::new((x, y), TARGET_RADIUS, BLACK.stroke_width(2)))?;
+                        area.draw(&PathElement::new(vec![(x - TARGET_RADIUS, y), (x + TARGET_RADIUS, y)], BLACK.stroke_width(2)))?;
+                        area.draw(&PathElement::new(vec![(x, y - TARGET_RADIUS), (x, y + TARGET_RADIUS)], BLACK.stroke_width(2)))?;
+                    }
+                },
+                QuantumGate::CZ => {
+                    for &y in targets {
+                        area.draw(&Circle::new((x, y), DOT_RADIUS, BLACK.filled()))?;
+                    }
+                },
+                gate => {
+                    let symbol = match gate {
+                        gate if gate.is_measurement() => gate.symbol(),
+                        _ => gate.display_symbol().trim_matches('─').to_string(),
+                    };
+                    // Gates without controls acting on several qubits get one tall box
+                    let spans: Vec<(i32, i32)> = if num_controls == 0 { vec![(top, bottom)] } else { targets.iter().map(|&y| (y, y)).collect() };
+                    for (top, bottom) in spans {
+                        let half_width = (BOX_SIZE / 2).max(symbol.chars().count() as i32 * FONT_SIZE as i32 * 3 / 10 + 6).min(STEP_WIDTH / 2 - 2);
+                        let corners = [(x - half_width, top - BOX_SIZE / 2), (x + half_width, bottom + BOX_SIZE / 2)];
+                        area.draw(&Rectangle::new(corners, WHITE.filled()))?;
+                        area.draw(&Rectangle::new(corners, BLACK.stroke_width(2)))?;
+                        area.draw(&Text::new(symbol.clone(), (x, (top + bottom) / 2), font.clone()))?;
+                    }
+                },
+            }
+
+            if let Some((bit, value)) = op.condition {
+                let condition = ("sans-serif", FONT_SIZE - 4).into_font().color(&BLACK).pos(Pos::new(HPos::Center, VPos::Top));
+                area.draw(&Text::new(format!("{}={}", self.clbit_label(bit), value as u8), (x, bottom + BOX_SIZE / 2 + 2), condition))?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,151 @@
+use std::{fmt, ops::Deref, slice, vec};
+use rusticle::complex::Complex;
+
+use crate::utility::{index_to_bitstring, ComplexFormat, Endianness};
+
+/// Probability below which basis states are left out of the table and plots by default
+const DEFAULT_THRESHOLD: f64 = 1e-10;
+
+/// The statevector returned by [`QuantumCircuit::execute`](super::QuantumCircuit::execute)
+///
+/// Dereferences to the slice of amplitudes, indexed by basis state with qubit 0 as the
+/// least significant bit. Displaying it prints an aligned table of basis states,
+/// amplitudes and probabilities, leaving out states whose probability is below the
+/// [threshold](StateVector::with_threshold). The formatter precision sets the number of
+/// decimals, four by default.
+///
+/// # Examples
+/// ```
+/// use intrico::QuantumCircuit;
+///
+/// let mut qc = QuantumCircuit::new(2);
+/// qc.h(0);
+/// qc.cnot(0, 1);
+///
+/// let state = qc.execute();
+/// assert!((state[3].real - 0.5_f64.sqrt()).abs() < 1e-6);
+/// assert_eq!(state.to_string(), "\
+/// State  Amplitude  Probability
+/// |00⟩   0.7071     0.5000
+/// |11⟩   0.7071     0.5000
+/// ");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct StateVector {
+    /// Amplitude of every basis state
+    amplitudes: Vec<Complex>,
+    /// Probability below which basis states are hidden in the table and plots
+    threshold: f64,
+}
+
+impl StateVector {
+    /// Wraps a list of amplitudes
+    pub fn new(amplitudes: Vec<Complex>) -> Self {
+        StateVector { amplitudes, threshold: DEFAULT_THRESHOLD }
+    }
+
+    /// Sets the probability below which basis states are hidden in the table and plots, `0.0` to show all
+    ///
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    ///
+    /// let state = QuantumCircuit::new(1).execute().with_threshold(0.0);
+    /// assert_eq!(state.to_string().lines().count(), 3);
+    /// ```
+    pub fn with_threshold(mut self, threshold: f64) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Returns the probability below which basis states are hidden
+    pub fn threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    /// Returns the amplitudes
+    pub fn amplitudes(&self) -> &[Complex] {
+        &self.amplitudes
+    }
+
+    /// Returns the amplitudes, consuming the statevector
+    pub fn into_vec(self) -> Vec<Complex> {
+        self.amplitudes
+    }
+
+    /// Returns the number of qubits
+    pub fn num_qubits(&self) -> usize {
+        self.amplitudes.len().max(1).ilog2() as usize
+    }
+
+    /// Returns the probability of every basis state
+    pub fn probabilities(&self) -> Vec<f64> {
+        self.amplitudes.iter().map(|amp| amp.norm_squared()).collect()
+    }
+}
+
+impl Deref for StateVector {
+    type Target = [Complex];
+
+    fn deref(&self) -> &[Complex] {
+        &self.amplitudes
+    }
+}
+
+impl From<Vec<Complex>> for StateVector {
+    fn from(amplitudes: Vec<Complex>) -> Self {
+        StateVector::new(amplitudes)
+    }
+}
+
+impl From<StateVector> for Vec<Complex> {
+    fn from(state: StateVector) -> Self {
+        state.amplitudes
+    }
+}
+
+impl IntoIterator for StateVector {
+    type Item = Complex;
+    type IntoIter = vec::IntoIter<Complex>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.amplitudes.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a StateVector {
+    type Item = &'a Complex;
+    type IntoIter = slice::Iter<'a, Complex>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.amplitudes.iter()
+    }
+}
+
+impl fmt::Display for StateVector {
+    /// Writes one row per basis state above the threshold, qubit 0 rightmost in the labels
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let precision = f.precision().unwrap_or(ComplexFormat::DEFAULT.precision());
+        let format = ComplexFormat::DEFAULT.with_precision(precision);
+        let num_qubits = self.num_qubits();
+
+        let rows: Vec<(String, String, String)> = self.amplitudes.iter()
+            .enumerate()
+            .filter(|(_, amp)| amp.norm_squared() >= self.threshold)
+            .map(|(index, &amp)| (
+                format!("|{}⟩", index_to_bitstring(index, num_qubits, Endianness::Little)),
+                format.format(amp),
+                format!("{:.*}", precision, amp.norm_squared()),
+            ))
+            .collect();
+
+        let state_width = rows.iter().map(|row| row.0.chars().count()).chain([5]).max().unwrap_or(0);
+        let amplitude_width = rows.iter().map(|row| row.1.chars().count()).chain([9]).max().unwrap_or(0);
+
+        writeln!(f, "{:<state_width$}  {:<amplitude_width$}  Probability", "State", "Amplitude")?;
+        for (state, amplitude, probability) in rows {
+            writeln!(f, "{:<state_width$}  {:<amplitude_width$}  {}", state, amplitude, probability)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,238 @@
+use crate::core::gate::{GateOp, QuantumGate};
+use super::QuantumCircuit;
+
+/// Number of columns between two labels of the time axis drawn by [`Timeline::draw`]
+const AXIS_LABEL_SPACING: usize = 10;
+
+/// The as-soon-as-possible schedule of the operations of a circuit
+///
+/// Every operation starts as soon as all of its qubits are free and the classical bits
+/// it reads, through a condition or a classical operation, have been written. Snapshots
+/// act as barriers across all qubits. Loops take the duration set on them, since their
+/// number of iterations is only known per shot. All times are given in nanoseconds.
+///
+/// # Examples
+/// ```
+/// use intrico::QuantumCircuit;
+///
+/// let mut qc = QuantumCircuit::new(2);
+/// qc.h(0);
+/// qc.set_duration(50.0);
+/// qc.x(1);
+/// qc.set_duration(20.0);
+/// qc.cnot(0, 1);
+/// qc.set_duration(200.0);
+///
+/// let timeline = qc.timeline();
+/// assert_eq!(timeline.start_times, vec![0.0, 0.0, 50.0]);
+/// assert_eq!(timeline.duration(), 250.0);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Timeline {
+    /// Start time of every operation, in the order of the circuit
+    pub start_times: Vec<f64>,
+    /// Duration of every operation, in the order of the circuit
+    pub durations: Vec<f64>,
+    /// Symbol, qubits and number of controls of every operation, for rendering
+    operations: Vec<(String, Vec<usize>, usize)>,
+    /// Padded wire labels of the scheduled circuit, one per qubit
+    labels: Vec<String>,
+}
+
+impl Timeline {
+    /// Returns the time at which the last operation finishes
+    pub fn duration(&self) -> f64 {
+        self.start_times.iter()
+            .zip(&self.durations)
+            .map(|(start, duration)| start + duration)
+            .fold(0.0, f64::max)
+    }
+
+    /// Returns the time at which the given operation finishes
+    ///
+    /// # Panics
+    /// Panics if the index is out of bounds
+    pub fn end_time(&self, index: usize) -> f64 {
+        self.start_times[index] + self.durations[index]
+    }
+
+    /// Returns the diagram of the schedule with time running from left to right
+    ///
+    /// Every column stands for `resolution` nanoseconds, so the width of a gate box is
+    /// proportional to its duration and idle periods show as plain wire. Instantaneous
+    /// operations take a single column each; consecutive ones on the same qubit are drawn
+    /// side by side, shifting the operations after them to the right of their start time.
+    /// The bottom line labels the time axis.
+    ///
+    /// # Panics
+    /// Panics if the resolution is not positive
+    ///
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    ///
+    /// let mut qc = QuantumCircuit::new(2);
+    /// qc.h(0);
+    /// qc.set_duration(40.0);
+    /// qc.cnot(0, 1);
+    /// qc.set_duration(60.0);
+    ///
+    /// assert_eq!(qc.timeline().draw(10.0), "\
+    /// q0: [H─]●─────
+    /// q1: ────[X───]
+    ///     0         100
+    /// ");
+    /// ```
+    pub fn draw(&self, resolution: f64) -> String {
+        if resolution <= 0.0 {
+            panic!("The time resolution must be positive, got {}", resolution);
+        }
+        let column = |time: f64| (time / resolution).round() as usize;
+
+        // Operations start at the column of their start time, or further right when an
+        // earlier operation on one of their qubits, e.g. an instantaneous one, still
+        // occupies it
+        let mut next_free = vec![0; self.labels.len()];
+        let mut placed = Vec::with_capacity(self.operations.len());
+        for (i, (_, qubits, _)) in self.operations.iter().enumerate() {
+            let span = column(self.end_time(i)).saturating_sub(column(self.start_times[i])).max(1);
+            let start = qubits.iter().map(|&qubit| next_free[qubit]).fold(column(self.start_times[i]), usize::max);
+            for &qubit in qubits {
+                next_free[qubit] = start + span;
+            }
+            placed.push((start, span));
+        }
+        let width = placed.iter().map(|(start, span)| start + span).max().unwrap_or(0).max(1);
+
+        let mut rows = vec![vec!['─'; width]; self.labels.len()];
+        for ((symbol, qubits, num_controls), &(start, span)) in self.operations.iter().zip(&placed) {
+            for (j, &qubit) in qubits.iter().enumerate() {
+                let cells = if j < *num_controls { control_cells(span) } else { gate_cells(symbol, span) };
+                rows[qubit][start..start + span].copy_from_slice(&cells);
+            }
+        }
+
+        let mut diagram = String::new();
+        for (label, row) in self.labels.iter().zip(&rows) {
+            diagram.push_str(label);
+            diagram.extend(row);
+            diagram.push('\n');
+        }
+
+        let mut axis = String::new();
+        for col in (0..=width).step_by(AXIS_LABEL_SPACING) {
+            let label = format!("{}", col as f64 * resolution);
+            if axis.len() <= col {
+                axis.push_str(&" ".repeat(col - axis.len()));
+                axis.push_str(&label);
+            }
+        }
+        let indent = self.labels.first().map_or(0, |label| label.chars().count());
+        diagram.push_str(&format!("{}{}\n", " ".repeat(indent), axis));
+        diagram
+    }
+}
+
+/// Returns the cells of a gate box spanning `span` columns, e.g. `[H──]`
+fn gate_cells(symbol: &str, span: usize) -> Vec<char> {
+    let symbol: Vec<char> = symbol.chars().collect();
+    if span < 3 {
+        return symbol.iter().copied().chain(std::iter::repeat('─')).take(span).collect();
+    }
+
+    let mut cells = vec!['─'; span];
+    cells[0] = '[';
+    cells[span - 1] = ']';
+    for (cell, &c) in cells[1..span - 1].iter_mut().zip(&symbol) {
+        *cell = c;
+    }
+    cells
+}
+
+/// Returns the cells of a control spanning `span` columns, a dot followed by wire
+fn control_cells(span: usize) -> Vec<char> {
+    std::iter::once('●').chain(std::iter::repeat('─')).take(span).collect()
+}
+
+impl QuantumCircuit {
+    /// Schedules the circuit using the durations set on its operations
+    ///
+    /// Operations without a duration are instantaneous. See
+    /// [`DeviceModel::timeline`](crate::device::DeviceModel::timeline) to take calibrated
+    /// gate durations into account.
+    pub fn timeline(&self) -> Timeline {
+        self.timeline_with(|_| None)
+    }
+
+    /// Schedules the circuit, looking up the duration of operations that have none set
+    ///
+    /// # Arguments
+    /// * `durations` - Returns the duration of an operation in nanoseconds, or `None`
+    ///   for an instantaneous operation
+    ///
+    /// # Examples
+    /// ```
+    /// use intrico::{QuantumCircuit, QuantumGate};
+    ///
+    /// let mut qc = QuantumCircuit::new(1);
+    /// qc.h(0);
+    /// qc.x(0);
+    /// qc.set_duration(10.0);
+    ///
+    /// let timeline = qc.timeline_with(|op| (op.gate == QuantumGate::H).then_some(25.0));
+    /// assert_eq!(timeline.duration(), 35.0);
+    /// ```
+    pub fn timeline_with<F: Fn(&GateOp) -> Option<f64>>(&self, durations: F) -> Timeline {
+        let mut qubit_free = vec![0.0_f64; self.num_qubits()];
+        let mut bit_ready = vec![0.0_f64; self.num_classical_bits()];
+        let mut timeline = Timeline {
+            start_times: Vec::with_capacity(self.num_operations()),
+            durations: Vec::with_capacity(self.num_operations()),
+            operations: Vec::with_capacity(self.num_operations()),
+            labels: self.wire_labels(),
+        };
+
+        for op in self.operations() {
+            let duration = match op.gate {
+                QuantumGate::Snapshot(_) => 0.0,
+                _ => op.duration.or_else(|| durations(op)).unwrap_or(0.0),
+            };
+            let mut inputs = match &op.gate {
+                QuantumGate::Classical(classical) => classical.inputs(),
+                QuantumGate::RepeatUntil { body, .. } => body.iter()
+                    .filter_map(|inner| inner.condition.map(|(bit, _)| bit))
+                    .collect(),
+                _ => Vec::new(),
+            };
+            inputs.extend(op.condition.map(|(bit, _)| bit));
+            let start = op.qubit.iter()
+                .map(|&qubit| qubit_free[qubit])
+                .chain(inputs.iter().map(|&bit| bit_ready[bit]))
+                .fold(0.0, f64::max);
+
+            for &qubit in &op.qubit {
+                qubit_free[qubit] = start + duration;
+            }
+            if let (true, Some(bit)) = (op.gate.is_measurement() || matches!(op.gate, QuantumGate::Classical(_) | QuantumGate::RepeatUntil { .. }), op.classical_bit) {
+                bit_ready[bit] = start + duration;
+            }
+
+            timeline.start_times.push(start);
+            timeline.durations.push(duration);
+            let symbol = match op.gate {
+                QuantumGate::Snapshot(_) => op.gate.symbol(),
+                _ => op.gate.display_symbol().trim_matches('─').trim_matches(|c| c == '[' || c == ']').to_string(),
+            };
+            timeline.operations.push((symbol, op.qubit.clone(), op.gate.num_controls()));
+        }
+        timeline
+    }
+
+    /// Returns the total duration of the circuit in nanoseconds
+    ///
+    /// Only durations set with [`QuantumCircuit::set_duration`] are taken into account;
+    /// see [`QuantumCircuit::timeline_with`] for other sources.
+    pub fn duration(&self) -> f64 {
+        self.timeline().duration()
+    }
+}
@@ -0,0 +1,65 @@
+/// An operation on classical bits, written into a destination bit of the circuit
+/// 
+/// Classical operations run between measurements and feed their result forward: a
+/// later quantum operation can be conditioned on the destination bit with
+/// [`QuantumCircuit::c_if`](crate::QuantumCircuit::c_if).
+/// 
+/// # Examples
+/// ```
+/// use intrico::core::ClassicalOp;
+/// 
+/// let bits = [true, false, true];
+/// assert!(ClassicalOp::Xor(0, 1).evaluate(&bits));
+/// assert!(!ClassicalOp::And(0, 1).evaluate(&bits));
+/// assert!(ClassicalOp::Equals(vec![0, 1, 2], 0b101).evaluate(&bits));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClassicalOp {
+    /// Exclusive or of two bits
+    Xor(usize, usize),
+    /// Conjunction of two bits
+    And(usize, usize),
+    /// Comparison of several bits against a constant, read with the first bit as the
+    /// least significant
+    Equals(Vec<usize>, u64),
+}
+
+impl ClassicalOp {
+    /// Returns the classical bits the operation reads
+    pub fn inputs(&self) -> Vec<usize> {
+        match self {
+            ClassicalOp::Xor(a, b) | ClassicalOp::And(a, b) => vec![*a, *b],
+            ClassicalOp::Equals(bits, _) => bits.clone(),
+        }
+    }
+
+    /// Computes the result of the operation on the given classical register
+    /// 
+    /// # Panics
+    /// Panics if an input bit is outside the register
+    pub fn evaluate(&self, bits: &[bool]) -> bool {
+        match self {
+            ClassicalOp::Xor(a, b) => bits[*a] ^ bits[*b],
+            ClassicalOp::And(a, b) => bits[*a] && bits[*b],
+            ClassicalOp::Equals(inputs, value) => {
+                let register = inputs.iter()
+                    .enumerate()
+                    .fold(0u64, |acc, (j, &bit)| acc | ((bits[bit] as u64) << j));
+                register == *value
+            },
+        }
+    }
+}
+
+impl std::fmt::Display for ClassicalOp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClassicalOp::Xor(a, b) => write!(f, "c{} ^ c{}", a, b),
+            ClassicalOp::And(a, b) => write!(f, "c{} & c{}", a, b),
+            ClassicalOp::Equals(bits, value) => {
+                let bits: Vec<String> = bits.iter().map(|bit| format!("c{}", bit)).collect();
+                write!(f, "[{}] == {}", bits.join(", "), value)
+            },
+        }
+    }
+}
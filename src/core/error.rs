@@ -0,0 +1,42 @@
+use std::fmt;
+
+/// Errors that can occur while constructing a [`QuantumGate`](super::QuantumGate)
+#[derive(Debug, Clone, PartialEq)]
+pub enum GateError {
+    /// The matrix is not square with a power-of-two dimension of at least 2
+    InvalidDimension {
+        /// Number of rows of the matrix
+        rows: usize,
+        /// Number of columns of the matrix
+        cols: usize,
+    },
+    /// The matrix of a custom gate is not unitary
+    NotUnitary,
+    /// The generator of an evolution gate is not Hermitian
+    NotHermitian {
+        /// Row of the first offending entry
+        row: usize,
+        /// Column of the first offending entry
+        col: usize,
+    },
+}
+
+impl fmt::Display for GateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GateError::InvalidDimension { rows, cols } => write!(
+                f,
+                "Gate matrix must be a 2^k x 2^k matrix, got {}x{}",
+                rows, cols
+            ),
+            GateError::NotUnitary => write!(f, "Gate matrix is not unitary"),
+            GateError::NotHermitian { row, col } => write!(
+                f,
+                "Hamiltonian is not Hermitian at entry ({}, {})",
+                row, col
+            ),
+        }
+    }
+}
+
+impl std::error::Error for GateError {}
@@ -0,0 +1,195 @@
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+use rusticle::complex::Complex;
+
+/// A floating-point type amplitudes can be stored in
+///
+/// Implemented for `f64`, whose amplitudes are plain [`Complex`] numbers, and `f32`,
+/// whose amplitudes are [`Complex32`]. Single precision halves the memory of a
+/// statevector at the cost of roughly seven significant digits, which is usually
+/// enough for sampling but accumulates error over deep circuits.
+///
+/// # Examples
+/// ```
+/// use intrico::core::{Amplitude, Float};
+///
+/// let amplitude = <f32 as Float>::Complex::new(0.6, 0.8);
+/// assert!((amplitude.norm_squared() - 1.0).abs() < 1e-6);
+/// assert_eq!(f32::from_f64(0.5).to_f64(), 0.5);
+/// ```
+pub trait Float:
+    Copy
+    + Default
+    + PartialOrd
+    + fmt::Debug
+    + Send
+    + Sync
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + 'static
+{
+    /// The complex amplitude type with this precision
+    type Complex: Amplitude<Real = Self>;
+
+    /// Converts from double precision, rounding if needed
+    fn from_f64(value: f64) -> Self;
+
+    /// Converts to double precision
+    fn to_f64(self) -> f64;
+}
+
+impl Float for f64 {
+    type Complex = Complex;
+
+    fn from_f64(value: f64) -> Self {
+        value
+    }
+
+    fn to_f64(self) -> f64 {
+        self
+    }
+}
+
+impl Float for f32 {
+    type Complex = Complex32;
+
+    fn from_f64(value: f64) -> Self {
+        value as f32
+    }
+
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+}
+
+/// A complex amplitude stored with the precision of a [`Float`]
+pub trait Amplitude:
+    Copy
+    + PartialEq
+    + fmt::Debug
+    + Send
+    + Sync
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + 'static
+{
+    /// The type of the real and imaginary parts
+    type Real: Float<Complex = Self>;
+
+    /// The amplitude 0
+    const ZERO: Self;
+
+    /// The amplitude 1
+    const ONE: Self;
+
+    /// Creates an amplitude from its real and imaginary parts
+    fn new(real: Self::Real, imag: Self::Real) -> Self;
+
+    /// Converts from a double precision complex number
+    fn from_complex(value: Complex) -> Self;
+
+    /// Converts to a double precision complex number
+    fn to_complex(self) -> Complex;
+
+    /// Returns the squared magnitude, accumulated in double precision
+    fn norm_squared(self) -> f64;
+
+    /// Multiplies both parts by a real factor
+    fn scale(self, factor: f64) -> Self;
+}
+
+impl Amplitude for Complex {
+    type Real = f64;
+
+    const ZERO: Self = Complex { real: 0.0, imag: 0.0 };
+    const ONE: Self = Complex { real: 1.0, imag: 0.0 };
+
+    fn new(real: f64, imag: f64) -> Self {
+        Complex::new(real, imag)
+    }
+
+    fn from_complex(value: Complex) -> Self {
+        value
+    }
+
+    fn to_complex(self) -> Complex {
+        self
+    }
+
+    fn norm_squared(self) -> f64 {
+        Complex::norm_squared(&self)
+    }
+
+    fn scale(self, factor: f64) -> Self {
+        Complex::new(self.real * factor, self.imag * factor)
+    }
+}
+
+/// A complex number in single precision
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Complex32 {
+    /// The real part
+    pub real: f32,
+    /// The imaginary part
+    pub imag: f32,
+}
+
+impl Add for Complex32 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Complex32 { real: self.real + other.real, imag: self.imag + other.imag }
+    }
+}
+
+impl Sub for Complex32 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Complex32 { real: self.real - other.real, imag: self.imag - other.imag }
+    }
+}
+
+impl Mul for Complex32 {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Complex32 {
+            real: self.real * other.real - self.imag * other.imag,
+            imag: self.real * other.imag + self.imag * other.real,
+        }
+    }
+}
+
+impl Amplitude for Complex32 {
+    type Real = f32;
+
+    const ZERO: Self = Complex32 { real: 0.0, imag: 0.0 };
+    const ONE: Self = Complex32 { real: 1.0, imag: 0.0 };
+
+    fn new(real: f32, imag: f32) -> Self {
+        Complex32 { real, imag }
+    }
+
+    fn from_complex(value: Complex) -> Self {
+        Complex32 { real: value.real as f32, imag: value.imag as f32 }
+    }
+
+    fn to_complex(self) -> Complex {
+        Complex::new(self.real as f64, self.imag as f64)
+    }
+
+    fn norm_squared(self) -> f64 {
+        let (real, imag) = (self.real as f64, self.imag as f64);
+        real * real + imag * imag
+    }
+
+    fn scale(self, factor: f64) -> Self {
+        let factor = factor as f32;
+        Complex32 { real: self.real * factor, imag: self.imag * factor }
+    }
+}
@@ -1,6 +1,10 @@
 use rusticle::complex::Complex;
 use rusticle::linalg::Matrix;
 
+use crate::IntricoError;
+use crate::utility::linalg::{exponentiate, matrix_log};
+use super::{Amplitude, ClassicalOp, GateError, NoiseChannel};
+
 /// Represents a basic quantum gate that can be applied to a qubit.
 /// 
 /// Each variant represents a different quantum gate with its corresponding
@@ -61,6 +65,24 @@ pub enum QuantumGate {
     /// ```
     T,
 
+    /// The S† gate (inverse of the S gate)
+    /// 
+    /// Matrix representation:
+    /// ```text
+    /// [1  0]
+    /// [0 -i]
+    /// ```
+    Sdg,
+
+    /// The T† gate (inverse of the T gate)
+    /// 
+    /// Matrix representation:
+    /// ```text 
+    /// [1 0]
+    /// [0 e^(-iπ/4)]
+    /// ```
+    Tdg,
+
     /// The Rx gate (rotation around X axis)
     /// 
     /// Matrix representation:
@@ -110,11 +132,124 @@ pub enum QuantumGate {
     /// ```
     CZ,
 
+    /// The controlled Rx gate, rotating the target around the X axis when the control is |1⟩
+    /// 
+    /// Matrix representation, with the control as the most significant bit:
+    /// ```text
+    /// [1 0 0              0             ]
+    /// [0 1 0              0             ]
+    /// [0 0 cos(theta/2)   -isin(theta/2)]
+    /// [0 0 -isin(theta/2) cos(theta/2)  ]
+    /// ```
+    CRx(f64),
+
+    /// The controlled Ry gate, rotating the target around the Y axis when the control is |1⟩
+    /// 
+    /// Matrix representation, with the control as the most significant bit:
+    /// ```text
+    /// [1 0 0            0            ]
+    /// [0 1 0            0            ]
+    /// [0 0 cos(theta/2) -sin(theta/2)]
+    /// [0 0 sin(theta/2) cos(theta/2) ]
+    /// ```
+    CRy(f64),
+
+    /// The controlled Rz gate, rotating the target around the Z axis when the control is |1⟩
+    /// 
+    /// Matrix representation, with the control as the most significant bit:
+    /// ```text
+    /// [1 0 0             0           ]
+    /// [0 1 0             0           ]
+    /// [0 0 e^(-itheta/2) 0           ]
+    /// [0 0 0             e^(itheta/2)]
+    /// ```
+    CRz(f64),
+
+    /// The Givens rotation, rotating by theta within the {|01⟩, |10⟩} subspace of two qubits
+    /// 
+    /// Preserves the number of excitations, which makes it the native two-qubit gate of
+    /// fermionic and chemistry ansätze. The first qubit is the most significant bit.
+    /// 
+    /// Matrix representation:
+    /// ```text
+    /// [1 0           0          0]
+    /// [0 cos(theta) -sin(theta) 0]
+    /// [0 sin(theta)  cos(theta) 0]
+    /// [0 0           0          1]
+    /// ```
+    Givens(f64),
+
+    /// A diagonal gate multiplying each basis state of k qubits by `e^(i·phase)`
+    /// 
+    /// Holds one phase per basis state, `2^k` in total, indexed with the first qubit as
+    /// the most significant bit. Simulators apply it pointwise without building the matrix.
+    /// 
+    /// Matrix representation:
+    /// ```text
+    /// [e^(i·phase0) 0            ...]
+    /// [0            e^(i·phase1) ...]
+    /// [...                          ]
+    /// ```
+    Diagonal(Vec<f64>),
+
+    /// The Toffoli gate (controlled-controlled-NOT)
+    /// 
+    /// Flips the target qubit when both control qubits are |1⟩, i.e. the
+    /// 8x8 identity with the last two rows swapped.
+    Toffoli,
+
     /// Measurement gate
     Measure,
 
+    /// Measurement in the eigenbasis of Pauli-X
+    /// 
+    /// The qubit is rotated by H onto the computational basis and then measured, so a
+    /// `0` outcome stands for |+⟩ and a `1` outcome for |-⟩.
+    MeasureX,
+
+    /// Measurement in the eigenbasis of Pauli-Y
+    /// 
+    /// The qubit is rotated by S† followed by H onto the computational basis and then
+    /// measured, so a `0` outcome stands for |+i⟩ and a `1` outcome for |-i⟩.
+    MeasureY,
+
+    /// Snapshot instruction that records the statevector under the given label
+    Snapshot(String),
+
+    /// Classical operation writing its result into the classical bit of the operation
+    /// 
+    /// Acts on no qubits; see [`ClassicalOp`].
+    Classical(ClassicalOp),
+
+    /// Bounded repeat-until-success loop
+    /// 
+    /// The body operations are applied, then repeated while the classical bit of the
+    /// condition does not hold the given value, at most `max_iterations` times in total.
+    /// Simulators evaluate the condition separately for every shot.
+    RepeatUntil {
+        /// Operations applied in every iteration, on the qubits of the enclosing circuit
+        body: Vec<GateOp>,
+        /// Classical bit and value that end the loop
+        condition: (usize, bool),
+        /// Maximum number of iterations, at least 1
+        max_iterations: usize,
+    },
+
     /// Custom Gate (Matrix, Name, Symbol)
     Custom(Matrix<Complex>, String, String),
+
+    /// User-defined gate built from a sub-circuit with `QuantumCircuit::define_gate`
+    /// 
+    /// Diagrams show it as a single box; simulation and transpilation expand it into
+    /// its body.
+    Composite {
+        /// Name of the definition
+        name: String,
+        /// Unitary of the body
+        matrix: Matrix<Complex>,
+        /// Operations of the body, whose qubit `i` stands for the qubit `i` of the operation
+        body: Vec<GateOp>,
+    },
 }
 
 /// Represents a quantum gate operation in a circuit
@@ -131,6 +266,12 @@ pub struct GateOp {
     pub step: usize,
     /// The classical bit index (for storing measurement results)
     pub classical_bit: Option<usize>,
+    /// Classical bit and value the operation is conditioned on
+    pub condition: Option<(usize, bool)>,
+    /// Noise channel acting right after the operation
+    pub noise: Option<NoiseChannel>,
+    /// Duration of the operation in nanoseconds, overriding device calibrations
+    pub duration: Option<f64>,
 }
 
 impl GateOp {
@@ -141,6 +282,9 @@ impl GateOp {
             qubit: vec![target],
             step,
             classical_bit: None,
+            condition: None,
+            noise: None,
+            duration: None,
         }
     }
 
@@ -151,6 +295,24 @@ impl GateOp {
             qubit: vec![control, target],
             step,
             classical_bit: None,
+            condition: None,
+            noise: None,
+            duration: None,
+        }
+    }
+
+    /// Creates a new gate operation acting on several qubits
+    /// 
+    /// For controlled gates the controls come first and the target last.
+    pub fn multi_qubit(gate: QuantumGate, qubits: Vec<usize>, step: usize) -> Self {
+        GateOp {
+            gate,
+            qubit: qubits,
+            step,
+            classical_bit: None,
+            condition: None,
+            noise: None,
+            duration: None,
         }
     }
     
@@ -169,6 +331,119 @@ impl GateOp {
 }
 
 impl QuantumGate {
+    /// Creates the custom gate `e^(-iHt)` evolving under a Hermitian operator for a time `t`
+    /// 
+    /// Like every custom gate, the first qubit the gate is applied to is the most
+    /// significant bit of the matrix index.
+    /// 
+    /// # Arguments
+    /// * `hamiltonian` - A Hermitian `2^k × 2^k` matrix
+    /// * `time` - The evolution time
+    /// 
+    /// # Panics
+    /// Panics if the matrix is not Hermitian or its dimension is not a power of two of at
+    /// least 2
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::{QuantumCircuit, QuantumGate};
+    /// 
+    /// // e^(-iπ/2·X) = -iX flips the qubit
+    /// let gate = QuantumGate::from_hamiltonian(&QuantumGate::X.matrix(), std::f64::consts::FRAC_PI_2);
+    /// 
+    /// let mut qc = QuantumCircuit::new(1);
+    /// qc.add_multi_qubit_gate(gate, &[0]);
+    /// assert!((qc.execute()[1].norm() - 1.0).abs() < 1e-6);
+    /// ```
+    pub fn from_hamiltonian(hamiltonian: &Matrix<Complex>, time: f64) -> QuantumGate {
+        Self::try_from_hamiltonian(hamiltonian, time).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Creates the custom gate `e^(-iHt)`, returning an error instead of panicking when
+    /// the matrix is not a Hermitian `2^k × 2^k` matrix
+    pub fn try_from_hamiltonian(hamiltonian: &Matrix<Complex>, time: f64) -> Result<QuantumGate, IntricoError> {
+        check_dimension(hamiltonian)?;
+        let dim = hamiltonian.rows();
+        for r in 0..dim {
+            for c in r..dim {
+                if (*hamiltonian.get(r, c) - hamiltonian.get(c, r).conjugate()).norm() > 1e-9 {
+                    return Err(GateError::NotHermitian { row: r, col: c }.into());
+                }
+            }
+        }
+
+        Ok(QuantumGate::Custom(exponentiate(hamiltonian, -time), "exp(-iHt)".to_string(), "U".to_string()))
+    }
+
+    /// Creates a custom gate after checking that its matrix is a `2^k × 2^k` unitary
+    /// 
+    /// Constructing [`QuantumGate::Custom`] directly skips these checks.
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::{IntricoError, QuantumGate};
+    /// use intrico::core::GateError;
+    /// use rusticle::linalg::Matrix;
+    /// 
+    /// let cnot = QuantumGate::try_custom(QuantumGate::CNOT.matrix(), "MyCNOT", "CX");
+    /// assert!(cnot.is_ok());
+    /// 
+    /// let error = QuantumGate::try_custom(Matrix::zeros(2, 2), "Zero", "0").unwrap_err();
+    /// assert_eq!(error, IntricoError::Gate(GateError::NotUnitary));
+    /// ```
+    pub fn try_custom(matrix: Matrix<Complex>, name: &str, symbol: &str) -> Result<QuantumGate, IntricoError> {
+        check_dimension(&matrix)?;
+        let dim = matrix.rows();
+        let product = &matrix.conjugate_transpose() * &matrix;
+        for r in 0..dim {
+            for c in 0..dim {
+                let expected = if r == c { 1.0 } else { 0.0 };
+                if (*product.get(r, c) - Complex::new(expected, 0.0)).norm() > 1e-9 {
+                    return Err(GateError::NotUnitary.into());
+                }
+            }
+        }
+
+        Ok(QuantumGate::Custom(matrix, name.to_string(), symbol.to_string()))
+    }
+
+    /// Returns the Hamiltonian `H` with `U = e^(-iH)`, or `None` for non-unitary instructions
+    /// and custom gates whose matrix is not unitary
+    /// 
+    /// `H = i·log(U)` is taken from the principal logarithm, so its eigenvalues lie in
+    /// `[-π, π)`. Evolving under `H` for a fraction of unit time interpolates between
+    /// the identity and the gate.
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumGate;
+    /// 
+    /// // Half of the evolution generating T is an eighth of a turn about Z
+    /// let hamiltonian = QuantumGate::T.hamiltonian().unwrap();
+    /// let half = QuantumGate::from_hamiltonian(&hamiltonian, 0.5).matrix();
+    /// let phase = half.get(1, 1).imag.atan2(half.get(1, 1).real);
+    /// assert!((phase - std::f64::consts::PI / 8.0).abs() < 1e-8);
+    /// ```
+    pub fn hamiltonian(&self) -> Option<Matrix<Complex>> {
+        if self.is_measurement() || matches!(self, QuantumGate::Snapshot(_) | QuantumGate::Classical(_) | QuantumGate::RepeatUntil { .. }) {
+            return None;
+        }
+        // Custom matrices are not checked on construction and may not be unitary
+        let matrix = self.matrix();
+        if !matrix.is_unitary() {
+            return None;
+        }
+        let log = matrix_log(&matrix);
+        let dim = log.rows();
+        let mut hamiltonian = Matrix::zeros(dim, dim);
+        for r in 0..dim {
+            for c in 0..dim {
+                hamiltonian.set(r, c, *log.get(r, c) * Complex::new(0.0, 1.0));
+            }
+        }
+        Some(hamiltonian)
+    }
+
     /// Returns the matrix representation of the quantum gate.
     pub fn matrix(&self) -> Matrix<Complex> {
         match self {
@@ -202,6 +477,25 @@ impl QuantumGate {
                             Complex::new(0.0, 0.0), phase,
                         ])
                     },
+            QuantumGate::Sdg => Matrix::new(2, 2, vec![
+                        Complex::new(1.0, 0.0), Complex::new(0.0, 0.0),
+                        Complex::new(0.0, 0.0), Complex::new(0.0, -1.0),
+                    ]),
+            QuantumGate::Tdg => {
+                        let phase = Complex::new(0.0, -std::f64::consts::PI/4.0).exp();
+                        Matrix::new(2, 2, vec![
+                            Complex::new(1.0, 0.0), Complex::new(0.0, 0.0),
+                            Complex::new(0.0, 0.0), phase,
+                        ])
+                    },
+            QuantumGate::Toffoli => {
+                        let mut matrix = Matrix::identity(8);
+                        matrix.set(6, 6, Complex::new(0.0, 0.0));
+                        matrix.set(7, 7, Complex::new(0.0, 0.0));
+                        matrix.set(6, 7, Complex::new(1.0, 0.0));
+                        matrix.set(7, 6, Complex::new(1.0, 0.0));
+                        matrix
+                    },
             QuantumGate::CNOT => Matrix::new(4, 4, vec![
                         Complex::new(1.0, 0.0), Complex::new(0.0, 0.0), Complex::new(0.0, 0.0), Complex::new(0.0, 0.0),
                         Complex::new(0.0, 0.0), Complex::new(1.0, 0.0), Complex::new(0.0, 0.0), Complex::new(0.0, 0.0),
@@ -214,8 +508,9 @@ impl QuantumGate {
                         Complex::new(0.0, 0.0), Complex::new(0.0, 0.0), Complex::new(1.0, 0.0), Complex::new(0.0, 0.0),
                         Complex::new(0.0, 0.0), Complex::new(0.0, 0.0), Complex::new(0.0, 0.0), Complex::new(-1.0, 0.0),
                     ]),
-            QuantumGate::Measure => {
-                        // Return zero for measurement matrix
+            QuantumGate::Measure | QuantumGate::MeasureX | QuantumGate::MeasureY | QuantumGate::Snapshot(_)
+                | QuantumGate::Classical(_) | QuantumGate::RepeatUntil { .. } => {
+                        // Return zero for non-unitary instructions
                         Matrix::zeros(1, 1)
                     }
             QuantumGate::Rx(angle) => {
@@ -244,10 +539,48 @@ impl QuantumGate {
                     Complex::new(0.0, 0.0), plus_i,
                 ])
             },
+            QuantumGate::CRx(angle) => controlled(&QuantumGate::Rx(*angle).matrix()),
+            QuantumGate::CRy(angle) => controlled(&QuantumGate::Ry(*angle).matrix()),
+            QuantumGate::CRz(angle) => controlled(&QuantumGate::Rz(*angle).matrix()),
+            QuantumGate::Givens(angle) => {
+                let mut matrix = Matrix::identity(4);
+                let (sin, cos) = angle.sin_cos();
+                matrix.set(1, 1, Complex::new(cos, 0.0));
+                matrix.set(1, 2, Complex::new(-sin, 0.0));
+                matrix.set(2, 1, Complex::new(sin, 0.0));
+                matrix.set(2, 2, Complex::new(cos, 0.0));
+                matrix
+            },
+            QuantumGate::Diagonal(phases) => {
+                let mut matrix = Matrix::zeros(phases.len(), phases.len());
+                for (i, &phase) in phases.iter().enumerate() {
+                    matrix.set(i, i, Complex::new(0.0, phase).exp());
+                }
+                matrix
+            },
             QuantumGate::Custom(matrix, _, _) => matrix.clone(),
+            QuantumGate::Composite { matrix, .. } => matrix.clone(),
         }
     }
 
+    /// Returns the matrix entries in row-major order at the precision of `A`
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumGate;
+    /// use intrico::core::{Amplitude, Complex32};
+    /// 
+    /// let entries: Vec<Complex32> = QuantumGate::X.entries();
+    /// assert_eq!(entries, vec![Complex32::ZERO, Complex32::ONE, Complex32::ONE, Complex32::ZERO]);
+    /// ```
+    pub fn entries<A: Amplitude>(&self) -> Vec<A> {
+        let matrix = self.matrix();
+        let dim = matrix.rows();
+        (0..dim * dim)
+            .map(|idx| A::from_complex(*matrix.get(idx / dim, idx % dim)))
+            .collect()
+    }
+
     /// Returns the name of the quantum gate.
     pub fn name(&self) -> String {
         match self {
@@ -258,12 +591,28 @@ impl QuantumGate {
             QuantumGate::S => "S".to_string(),
             QuantumGate::T => "T".to_string(),
             QuantumGate::CNOT => "CNOT".to_string(),
+            QuantumGate::Sdg => "S†".to_string(),
+            QuantumGate::Tdg => "T†".to_string(),
             QuantumGate::CZ => "CZ".to_string(),
+            QuantumGate::Toffoli => "Toffoli".to_string(),
             QuantumGate::Measure => "Measurement".to_string(),
+            QuantumGate::MeasureX => "Measurement-X".to_string(),
+            QuantumGate::MeasureY => "Measurement-Y".to_string(),
+            QuantumGate::Snapshot(label) => format!("Snapshot({})", label),
+            QuantumGate::Classical(op) => format!("Classical({})", op),
+            QuantumGate::RepeatUntil { condition: (bit, value), max_iterations, .. } => {
+                format!("RepeatUntil(c{} == {}, max {})", bit, *value as u8, max_iterations)
+            },
             QuantumGate::Rx(angle) => format!("Rx({})", angle),
             QuantumGate::Ry(angle) => format!("Ry({})", angle),
             QuantumGate::Rz(angle) => format!("Rz({})", angle),
-            QuantumGate::Custom(_, name, _) => format!("{}", name),
+            QuantumGate::CRx(angle) => format!("CRx({})", angle),
+            QuantumGate::CRy(angle) => format!("CRy({})", angle),
+            QuantumGate::CRz(angle) => format!("CRz({})", angle),
+            QuantumGate::Givens(angle) => format!("Givens({})", angle),
+            QuantumGate::Diagonal(_) => "Diagonal".to_string(),
+            QuantumGate::Custom(_, name, _) => name.clone(),
+            QuantumGate::Composite { name, .. } => name.clone(),
         }
     }
 
@@ -277,12 +626,26 @@ impl QuantumGate {
             QuantumGate::S => "S".to_string(),
             QuantumGate::T => "T".to_string(),
             QuantumGate::CNOT => "CX".to_string(),
+            QuantumGate::Sdg => "S†".to_string(),
+            QuantumGate::Tdg => "T†".to_string(),
             QuantumGate::CZ => "CZ".to_string(),
+            QuantumGate::Toffoli => "CCX".to_string(),
             QuantumGate::Measure => "M".to_string(),
+            QuantumGate::MeasureX => "MX".to_string(),
+            QuantumGate::MeasureY => "MY".to_string(),
+            QuantumGate::Snapshot(_) => "░".to_string(),
+            QuantumGate::Classical(op) => op.to_string(),
+            QuantumGate::RepeatUntil { .. } => "RUS".to_string(),
             QuantumGate::Rx(angle) => format!("Rx({})", angle),
             QuantumGate::Ry(angle) => format!("Ry({})", angle),
             QuantumGate::Rz(angle) => format!("Rz({})", angle),
-            QuantumGate::Custom(_, _, symbol) => format!("{}", symbol),
+            QuantumGate::CRx(angle) => format!("CRx({})", angle),
+            QuantumGate::CRy(angle) => format!("CRy({})", angle),
+            QuantumGate::CRz(angle) => format!("CRz({})", angle),
+            QuantumGate::Givens(angle) => format!("G({})", angle),
+            QuantumGate::Diagonal(_) => "Diag".to_string(),
+            QuantumGate::Custom(_, _, symbol) => symbol.clone(),
+            QuantumGate::Composite { name, .. } => name.clone(),
         }
     }
     
@@ -296,22 +659,117 @@ impl QuantumGate {
             QuantumGate::S => "─S─".to_string(),
             QuantumGate::T => "─T─".to_string(),
             QuantumGate::CNOT => "─X─".to_string(),
+            QuantumGate::Sdg => "─S†".to_string(),
+            QuantumGate::Tdg => "─T†".to_string(),
             QuantumGate::CZ => "─Z─".to_string(),
+            QuantumGate::Toffoli => "─X─".to_string(),
             QuantumGate::Measure => "─[M]─".to_string(),
+            QuantumGate::MeasureX => "─[MX]─".to_string(),
+            QuantumGate::MeasureY => "─[MY]─".to_string(),
+            QuantumGate::Snapshot(_) => "─░─".to_string(),
+            QuantumGate::Classical(op) => format!("═{}═", op),
+            QuantumGate::RepeatUntil { .. } => "─RUS─".to_string(),
             QuantumGate::Rx(angle) => format!("─Rx({:.2})─", angle),
             QuantumGate::Ry(angle) => format!("─Ry({:.2})─", angle),
             QuantumGate::Rz(angle) => format!("─Rz({:.2})─", angle),
+            // Controls are drawn as dots, so the target box only shows the rotation
+            QuantumGate::CRx(angle) => format!("─Rx({:.2})─", angle),
+            QuantumGate::CRy(angle) => format!("─Ry({:.2})─", angle),
+            QuantumGate::CRz(angle) => format!("─Rz({:.2})─", angle),
+            QuantumGate::Givens(angle) => format!("─G({:.2})─", angle),
+            QuantumGate::Diagonal(_) => "─Diag─".to_string(),
             QuantumGate::Custom(_, _, symbol) => format!("─{}─", symbol),
+            QuantumGate::Composite { name, .. } => format!("[{}]", name),
         }
     }
 
-    /// Returns the number of qubits that the gate operates on.
+    /// Returns the number of qubits that the gate operates on, 0 for classical operations.
     pub fn arity(&self) -> usize {
         match self {
-            QuantumGate::CNOT | QuantumGate::CZ => 2,
+            QuantumGate::CNOT | QuantumGate::CZ | QuantumGate::CRx(_) | QuantumGate::CRy(_) | QuantumGate::CRz(_)
+                | QuantumGate::Givens(_) => 2,
+            QuantumGate::Toffoli => 3,
+            QuantumGate::Diagonal(phases) => phases.len().max(2).trailing_zeros() as usize,
+            QuantumGate::Custom(matrix, _, _) | QuantumGate::Composite { matrix, .. } => {
+                matrix.rows().max(2).trailing_zeros() as usize
+            },
+            QuantumGate::Classical(_) => 0,
+            QuantumGate::RepeatUntil { body, .. } => {
+                let mut qubits: Vec<usize> = body.iter().flat_map(|op| op.qubit.iter().copied()).collect();
+                qubits.sort_unstable();
+                qubits.dedup();
+                qubits.len()
+            },
             _ => 1,
         }
     }
+
+    /// Returns the number of control qubits of a controlled gate, 0 for other gates
+    pub fn num_controls(&self) -> usize {
+        match self {
+            QuantumGate::CNOT | QuantumGate::CZ | QuantumGate::CRx(_) | QuantumGate::CRy(_) | QuantumGate::CRz(_) => 1,
+            QuantumGate::Toffoli => 2,
+            _ => 0,
+        }
+    }
+
+    /// Returns whether the gate is a measurement, in any basis
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumGate;
+    /// 
+    /// assert!(QuantumGate::MeasureX.is_measurement());
+    /// assert!(!QuantumGate::H.is_measurement());
+    /// ```
+    pub fn is_measurement(&self) -> bool {
+        matches!(self, QuantumGate::Measure | QuantumGate::MeasureX | QuantumGate::MeasureY)
+    }
+
+    /// Returns the inverse of the gate, or `None` for non-unitary instructions
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumGate;
+    /// 
+    /// assert_eq!(QuantumGate::S.inverse(), Some(QuantumGate::Sdg));
+    /// assert_eq!(QuantumGate::Rx(0.5).inverse(), Some(QuantumGate::Rx(-0.5)));
+    /// assert_eq!(QuantumGate::Measure.inverse(), None);
+    /// ```
+    pub fn inverse(&self) -> Option<QuantumGate> {
+        let inverse = match self {
+            QuantumGate::S => QuantumGate::Sdg,
+            QuantumGate::Sdg => QuantumGate::S,
+            QuantumGate::T => QuantumGate::Tdg,
+            QuantumGate::Tdg => QuantumGate::T,
+            QuantumGate::Rx(angle) => QuantumGate::Rx(-angle),
+            QuantumGate::Ry(angle) => QuantumGate::Ry(-angle),
+            QuantumGate::Rz(angle) => QuantumGate::Rz(-angle),
+            QuantumGate::CRx(angle) => QuantumGate::CRx(-angle),
+            QuantumGate::CRy(angle) => QuantumGate::CRy(-angle),
+            QuantumGate::CRz(angle) => QuantumGate::CRz(-angle),
+            QuantumGate::Givens(angle) => QuantumGate::Givens(-angle),
+            QuantumGate::Diagonal(phases) => QuantumGate::Diagonal(phases.iter().map(|phase| -phase).collect()),
+            QuantumGate::Custom(matrix, name, symbol) => QuantumGate::Custom(
+                matrix.conjugate_transpose(),
+                format!("{}†", name),
+                format!("{}†", symbol),
+            ),
+            QuantumGate::Composite { name, matrix, body } => QuantumGate::Composite {
+                name: format!("{}†", name),
+                matrix: matrix.conjugate_transpose(),
+                body: body.iter()
+                    .rev()
+                    .map(|op| op.gate.inverse().map(|gate| GateOp { gate, ..op.clone() }))
+                    .collect::<Option<Vec<_>>>()?,
+            },
+            QuantumGate::Measure | QuantumGate::MeasureX | QuantumGate::MeasureY | QuantumGate::Snapshot(_)
+                | QuantumGate::Classical(_) | QuantumGate::RepeatUntil { .. } => return None,
+            // The remaining gates are self-inverse
+            gate => gate.clone(),
+        };
+        Some(inverse)
+    }
 }
 
 impl std::fmt::Display for QuantumGate {
@@ -320,6 +778,10 @@ impl std::fmt::Display for QuantumGate {
             QuantumGate::Rx(angle) => write!(f, "Rx({:.2})", angle),
             QuantumGate::Ry(angle) => write!(f, "Ry({:.2})", angle),
             QuantumGate::Rz(angle) => write!(f, "Rz({:.2})", angle),
+            QuantumGate::CRx(angle) => write!(f, "CRx({:.2})", angle),
+            QuantumGate::CRy(angle) => write!(f, "CRy({:.2})", angle),
+            QuantumGate::CRz(angle) => write!(f, "CRz({:.2})", angle),
+            QuantumGate::Givens(angle) => write!(f, "G({:.2})", angle),
             _ => write!(f, "{}", self.symbol()),
         }
     }
@@ -331,7 +793,32 @@ impl std::fmt::Debug for QuantumGate {
             QuantumGate::Rx(angle) => write!(f, "Rx({:.2})", angle),
             QuantumGate::Ry(angle) => write!(f, "Ry({:.2})", angle),
             QuantumGate::Rz(angle) => write!(f, "Rz({:.2})", angle),
+            QuantumGate::CRx(angle) => write!(f, "CRx({:.2})", angle),
+            QuantumGate::CRy(angle) => write!(f, "CRy({:.2})", angle),
+            QuantumGate::CRz(angle) => write!(f, "CRz({:.2})", angle),
+            QuantumGate::Givens(angle) => write!(f, "G({:.2})", angle),
             _ => write!(f, "{}", self.symbol()),
         }
     }
 }
+
+/// Returns the 4x4 matrix applying a single-qubit matrix to the target when the control
+/// (most significant bit) is |1⟩
+fn controlled(matrix: &Matrix<Complex>) -> Matrix<Complex> {
+    let mut result = Matrix::identity(4);
+    for r in 0..2 {
+        for c in 0..2 {
+            result.set(2 + r, 2 + c, *matrix.get(r, c));
+        }
+    }
+    result
+}
+
+/// Returns an error unless the matrix is square with a power-of-two dimension of at least 2
+fn check_dimension(matrix: &Matrix<Complex>) -> Result<(), GateError> {
+    let (rows, cols) = (matrix.rows(), matrix.cols());
+    if rows != cols || rows < 2 || !rows.is_power_of_two() {
+        return Err(GateError::InvalidDimension { rows, cols });
+    }
+    Ok(())
+}
@@ -4,7 +4,15 @@
 //! including qubit state representation, quantum gates, and their operations.
 
 pub mod qubit;
+pub mod classical;
 pub mod gate;
+pub mod float;
+pub mod noise;
+mod error;
 
 pub use qubit::Qubit;
-pub use gate::{QuantumGate, GateOp};
\ No newline at end of file
+pub use classical::ClassicalOp;
+pub use gate::{QuantumGate, GateOp};
+pub use float::{Amplitude, Complex32, Float};
+pub use noise::NoiseChannel;
+pub use error::GateError;
\ No newline at end of file
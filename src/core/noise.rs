@@ -0,0 +1,47 @@
+/// A noise channel attached to a single operation of a circuit
+/// 
+/// The channel acts right after the operation, on every qubit the operation touches.
+/// Unlike a global [`NoiseModel`](crate::simulator::NoiseModel) it only affects one
+/// location, which makes it possible to study the effect of a single faulty gate.
+/// 
+/// # Examples
+/// ```
+/// use intrico::QuantumCircuit;
+/// use intrico::core::NoiseChannel;
+/// 
+/// let mut qc = QuantumCircuit::new(2);
+/// qc.h(0);
+/// qc.cnot(0, 1);
+/// qc.add_noise(NoiseChannel::Depolarizing(0.01));
+/// 
+/// assert_eq!(qc.operations()[1].noise, Some(NoiseChannel::Depolarizing(0.01)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NoiseChannel {
+    /// Applies a uniformly random non-identity Pauli string to all qubits of the
+    /// operation with the given probability
+    Depolarizing(f64),
+    /// Flips every qubit of the operation independently with the given probability
+    BitFlip(f64),
+    /// Applies Z to every qubit of the operation independently with the given probability
+    PhaseFlip(f64),
+}
+
+impl NoiseChannel {
+    /// Returns the error probability of the channel
+    pub fn probability(&self) -> f64 {
+        match self {
+            NoiseChannel::Depolarizing(p) | NoiseChannel::BitFlip(p) | NoiseChannel::PhaseFlip(p) => *p,
+        }
+    }
+}
+
+impl std::fmt::Display for NoiseChannel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NoiseChannel::Depolarizing(p) => write!(f, "depolarizing({})", p),
+            NoiseChannel::BitFlip(p) => write!(f, "bit_flip({})", p),
+            NoiseChannel::PhaseFlip(p) => write!(f, "phase_flip({})", p),
+        }
+    }
+}
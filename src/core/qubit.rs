@@ -1,13 +1,26 @@
 use std::fmt;
 use rusticle::complex::{Complex, ComplexVector};
 use crate::core::gate::QuantumGate;
+use crate::core::{Amplitude, Float};
+use crate::utility::ComplexFormat;
 
 /// Represents a quantum bit (qubit) with its state vector
+/// 
+/// The amplitudes are stored with the precision of `F`, double by default. Qubits are
+/// created in double precision and converted with [`Qubit::to_precision`].
+/// 
+/// # Examples
+/// ```
+/// use intrico::{Qubit, QuantumGate};
+/// 
+/// let mut qubit = Qubit::zero().to_precision::<f32>();
+/// qubit.apply(QuantumGate::H);
+/// assert!((qubit.probability_one() - 0.5).abs() < 1e-6);
+/// ```
 #[derive(Clone)]
-pub struct Qubit {
-    /// The state vector of the qubit represented as a complex vector
-    /// [alpha, beta] where |ψ⟩ = α|0⟩ + β|1⟩
-    state: ComplexVector,
+pub struct Qubit<F: Float = f64> {
+    /// The state vector of the qubit [alpha, beta] where |ψ⟩ = α|0⟩ + β|1⟩
+    state: [F::Complex; 2],
 }
 
 impl Qubit {
@@ -35,7 +48,7 @@ impl Qubit {
             panic!("State vector must be normalized");
         }
         Qubit {
-            state: ComplexVector::new(vec![alpha, beta]),
+            state: [alpha, beta],
         }
     }
 
@@ -52,7 +65,7 @@ impl Qubit {
     /// ```
     pub fn zero() -> Self {
         Qubit {
-            state: ComplexVector::new(vec![Complex::new(1.0, 0.0), Complex::new(0.0, 0.0)]),
+            state: [Complex::new(1.0, 0.0), Complex::new(0.0, 0.0)],
         }
     }
 
@@ -69,10 +82,12 @@ impl Qubit {
     /// ```
     pub fn one() -> Self {
         Qubit {
-            state: ComplexVector::new(vec![Complex::new(0.0, 0.0), Complex::new(1.0, 0.0)]),
+            state: [Complex::new(0.0, 0.0), Complex::new(1.0, 0.0)],
         }
     }
+}
 
+impl<F: Float> Qubit<F> {
     /// Returns the probability of measuring the qubit in the |0⟩ state
     /// 
     /// # Examples
@@ -87,7 +102,7 @@ impl Qubit {
     /// assert_eq!(qubit.probability_zero(), 0.0);
     /// ```
     pub fn probability_zero(&self) -> f64 {
-        self.state.components[0].norm_squared()
+        self.state[0].norm_squared()
     }
 
     /// Returns the probability of measuring the qubit in the |1⟩ state
@@ -104,7 +119,7 @@ impl Qubit {
     /// assert_eq!(qubit.probability_one(), 1.0);
     /// ```
     pub fn probability_one(&self) -> f64 {
-        self.state.components[1].norm_squared()
+        self.state[1].norm_squared()
     }
 
     /// Returns the state vector of the qubit
@@ -120,7 +135,14 @@ impl Qubit {
     /// assert_eq!(state.components[1], Complex::new(0.0, 0.0));
     /// ```
     pub fn state_vector(&self) -> ComplexVector {
-        self.state.clone()
+        ComplexVector::new(self.state.iter().map(|amp| amp.to_complex()).collect())
+    }
+
+    /// Returns the qubit with its amplitudes converted to the precision of `G`
+    pub fn to_precision<G: Float>(&self) -> Qubit<G> {
+        Qubit {
+            state: self.state.map(|amp| G::Complex::from_complex(amp.to_complex())),
+        }
     }
 
     /// Applies a quantum gate to the qubit, modifying its state.
@@ -139,8 +161,11 @@ impl Qubit {
     /// assert_eq!(qubit.state_vector().components[1], Complex::new(1.0, 0.0));
     /// ```
     pub fn apply(&mut self, gate: QuantumGate) {
-        let gate_matrix = gate.matrix();
-        self.state = gate_matrix.mul_vector(&self.state);
+        let [m00, m01, m10, m11] = gate.entries::<F::Complex>()[..] else {
+            panic!("Cannot apply a {}-qubit gate to a single qubit", gate.arity());
+        };
+        let [alpha, beta] = self.state;
+        self.state = [m00 * alpha + m01 * beta, m10 * alpha + m11 * beta];
     }
 
     /// Returns true if the qubit is in a basis state (|0⟩ or |1⟩)
@@ -154,13 +179,12 @@ impl Qubit {
     /// assert!(qubit.is_basis_state());
     /// ```
     pub fn is_basis_state(&self) -> bool {
-        (self.state.components[0] == Complex::new(1.0, 0.0) && self.state.components[1] == Complex::new(0.0, 0.0)) || 
-        (self.state.components[0] == Complex::new(0.0, 0.0) && self.state.components[1] == Complex::new(1.0, 0.0))
+        self.state == [F::Complex::ONE, F::Complex::ZERO] || self.state == [F::Complex::ZERO, F::Complex::ONE]
     }
 
     /// Returns true if the qubit is in the |1⟩ state
     pub fn is_one(&self) -> bool {
-        self.state.components[0] == Complex::new(0.0, 0.0) && self.state.components[1] == Complex::new(1.0, 0.0)
+        self.state == [F::Complex::ZERO, F::Complex::ONE]
     }
 }
 
@@ -181,32 +205,19 @@ impl Default for Qubit {
     }
 }
 
-impl fmt::Display for Qubit {
-    /// Formats the qubit state in Dirac notation
+impl<F: Float> fmt::Display for Qubit<F> {
+    /// Formats the qubit state in Dirac notation, such as `|ψ⟩ = 0.7071|0⟩ + 0.7071i|1⟩`
+    /// 
+    /// The formatter precision sets the number of decimals, four by default.
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let alpha = self.state.components[0];
-        let beta = self.state.components[1];
-        if alpha.norm_squared() == 1.0 {
-            write!(f, "|ψ⟩ = |0⟩")
-        } else if beta.norm_squared() == 1.0 {
-            write!(f, "|ψ⟩ = |1⟩")
-        } else {
-            write!(f, "|ψ⟩ = {:?}|0⟩ + {:?}|1⟩", alpha, beta)
-        }
+        let format = ComplexFormat::DEFAULT.with_precision(f.precision().unwrap_or(ComplexFormat::DEFAULT.precision()));
+        write!(f, "|ψ⟩ = {}", format.format_state(&self.state_vector().components))
     }
 }
 
-impl fmt::Debug for Qubit {
+impl<F: Float> fmt::Debug for Qubit<F> {
     /// Formats the qubit state in Dirac notation for debugging
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let alpha = self.state.components[0];
-        let beta = self.state.components[1];
-        if alpha.norm_squared() == 1.0 {
-            write!(f, "|ψ⟩ = |0⟩")
-        } else if beta.norm_squared() == 1.0 {
-            write!(f, "|ψ⟩ = |1⟩")
-        } else {
-            write!(f, "|ψ⟩ = {:?}|0⟩ + {:?}|1⟩", alpha, beta)
-        }
+        fmt::Display::fmt(self, f)
     }
 }
@@ -0,0 +1,12 @@
+//! Circuit cutting module
+//! 
+//! This module cuts circuits into fragments that are simulated independently and knits
+//! the fragment results back together. Every wire cut replaces the identity channel on
+//! the wire by a measurement of a Pauli operator on one side and the preparation of one
+//! of its eigenstates on the other, so a circuit wider than the simulator can handle is
+//! evaluated through narrower fragments, at a cost growing exponentially with the number
+//! of cuts.
+
+mod wire_cut;
+
+pub use wire_cut::CutCircuit;
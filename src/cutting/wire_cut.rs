@@ -0,0 +1,325 @@
+use std::collections::HashMap;
+use rusticle::complex::Complex;
+
+use crate::QuantumCircuit;
+use crate::core::{GateOp, QuantumGate};
+use crate::observables::PauliSum;
+use crate::simulator::{Observable, Simulator};
+
+/// Pauli operators a cut wire is decomposed into
+const PAULIS: [char; 4] = ['I', 'X', 'Y', 'Z'];
+
+/// A circuit with cut wires, evaluated through independently simulated fragments
+/// 
+/// Cutting the wire of a qubit after an operation splits the wire into an upstream and a
+/// downstream segment. Segments joined by multi-qubit gates form a fragment, which is
+/// simulated as a circuit of its own over one qubit per segment. The state crossing a
+/// cut is expanded as `ρ = Σ_P Tr(Pρ) P / 2` over the Pauli operators: the upstream
+/// fragment contributes the expectation value of `P`, and the downstream fragment is run
+/// from the eigenstates of `P`. Every cut multiplies the number of terms by four.
+/// 
+/// # Examples
+/// ```
+/// use intrico::QuantumCircuit;
+/// use intrico::cutting::CutCircuit;
+/// use intrico::observables::PauliSum;
+/// use intrico::simulator::Simulator;
+/// 
+/// // A 4-qubit GHZ state, evaluated on a simulator limited to 2 qubits
+/// let mut qc = QuantumCircuit::new(4);
+/// qc.h(0);
+/// qc.cnot(0, 1);
+/// qc.cnot(1, 2);
+/// qc.cnot(2, 3);
+/// 
+/// let mut cut = CutCircuit::new(&qc);
+/// cut.cut_wire(1, 1);
+/// cut.cut_wire(2, 2);
+/// assert_eq!(cut.num_fragments(), 3);
+/// 
+/// let observable: PauliSum = "ZZII + XXXX".parse().unwrap();
+/// let value = cut.expectation(&observable, &Simulator::new().with_max_qubits(2));
+/// assert!((value - 2.0).abs() < 1e-6);
+/// ```
+#[derive(Debug, Clone)]
+pub struct CutCircuit {
+    /// The circuit being cut
+    circuit: QuantumCircuit,
+    /// Cut positions of every qubit, as indices of the operations the wire is cut after
+    cuts: Vec<Vec<usize>>,
+}
+
+/// A part of the circuit simulated on its own
+#[derive(Debug, Clone)]
+struct Fragment {
+    /// The operations of the fragment, on one qubit per wire segment
+    circuit: QuantumCircuit,
+    /// Original qubit and segment index of every qubit of the fragment
+    segments: Vec<(usize, usize)>,
+}
+
+impl CutCircuit {
+    /// Prepares a circuit for cutting, initially without any cut
+    /// 
+    /// # Panics
+    /// Panics if the circuit contains operations other than unconditioned unitary gates,
+    /// such as measurements, snapshots or classical operations
+    pub fn new(circuit: &QuantumCircuit) -> Self {
+        let unitary = circuit.operations().iter()
+            .all(|op| op.gate.inverse().is_some() && op.condition.is_none());
+        if !unitary {
+            panic!("Only circuits of unconditioned unitary gates can be cut");
+        }
+
+        CutCircuit { circuit: circuit.clone(), cuts: vec![Vec::new(); circuit.num_qubits()] }
+    }
+
+    /// Cuts the wire of a qubit right after an operation acting on it
+    /// 
+    /// # Arguments
+    /// * `qubit` - The qubit whose wire is cut
+    /// * `operation` - The index of the operation after which the wire is cut
+    /// 
+    /// # Panics
+    /// Panics if the operation does not exist or does not act on the qubit, or if the
+    /// wire is already cut there
+    pub fn cut_wire(&mut self, qubit: usize, operation: usize) {
+        let Some(op) = self.circuit.operations().get(operation) else {
+            panic!("Operation {} does not exist in a circuit of {} operations", operation, self.circuit.num_operations());
+        };
+        if !op.qubit.contains(&qubit) {
+            panic!("Operation {} does not act on qubit {}", operation, qubit);
+        }
+        if self.cuts[qubit].contains(&operation) {
+            panic!("The wire of qubit {} is already cut after operation {}", qubit, operation);
+        }
+
+        self.cuts[qubit].push(operation);
+        self.cuts[qubit].sort_unstable();
+    }
+
+    /// Returns the number of wire cuts
+    pub fn num_cuts(&self) -> usize {
+        self.cuts.iter().map(Vec::len).sum()
+    }
+
+    /// Returns the number of fragments the cuts split the circuit into
+    pub fn num_fragments(&self) -> usize {
+        self.fragments().len()
+    }
+
+    /// Returns the circuits of the fragments, without the preparations of cut wires
+    /// 
+    /// Every qubit of a fragment stands for a segment of a wire of the original circuit.
+    pub fn fragment_circuits(&self) -> Vec<QuantumCircuit> {
+        self.fragments().into_iter().map(|fragment| fragment.circuit).collect()
+    }
+
+    /// Knits the fragment results into the expectation value of an observable on the
+    /// output of the full circuit
+    /// 
+    /// Every fragment with `m` incoming cuts is run from the `6^m` combinations of Pauli
+    /// eigenstates on its cut wires, taking the final statevectors of the simulator.
+    /// 
+    /// # Arguments
+    /// * `observable` - The observable on the qubits of the full circuit
+    /// * `simulator` - The simulator running the fragments
+    /// 
+    /// # Panics
+    /// Panics if the observable does not act on the qubits of the circuit
+    pub fn expectation(&self, observable: &PauliSum, simulator: &Simulator) -> f64 {
+        if observable.num_qubits() != self.circuit.num_qubits() {
+            panic!("Circuit acts on {} qubits but the observable on {}", self.circuit.num_qubits(), observable.num_qubits());
+        }
+
+        let fragments = self.fragments();
+        let locate = |segment: (usize, usize)| -> (usize, usize) {
+            fragments.iter()
+                .enumerate()
+                .find_map(|(f, fragment)| fragment.segments.iter().position(|&s| s == segment).map(|local| (f, local)))
+                .expect("every segment belongs to a fragment")
+        };
+
+        // Fragment qubits on both sides of every cut, as (cut, local qubit) per fragment
+        let mut outgoing = vec![Vec::new(); fragments.len()];
+        let mut incoming = vec![Vec::new(); fragments.len()];
+        let cuts = self.cuts.iter()
+            .enumerate()
+            .flat_map(|(qubit, positions)| (0..positions.len()).map(move |segment| (qubit, segment)));
+        for (c, (qubit, segment)) in cuts.enumerate() {
+            let (f, local) = locate((qubit, segment));
+            outgoing[f].push((c, local));
+            let (f, local) = locate((qubit, segment + 1));
+            incoming[f].push((c, local));
+        }
+
+        // Simulate every fragment from every combination of eigenstates on its cut inputs
+        let mut circuits = Vec::new();
+        for (fragment, inputs) in fragments.iter().zip(&incoming) {
+            let width = fragment.circuit.num_qubits();
+            for preparations in product(EIGENSTATES.len(), inputs.len()) {
+                let mut prepared = QuantumCircuit::new(width);
+                for (&(_, local), &state) in inputs.iter().zip(&preparations) {
+                    for gate in EIGENSTATES[state] {
+                        prepared.add_gate(gate.clone(), local);
+                    }
+                }
+                prepared.compose(&fragment.circuit, &(0..width).collect::<Vec<_>>());
+                circuits.push(prepared);
+            }
+        }
+        let mut results = simulator.run_batch(&circuits, 0).into_iter().map(|result| result.final_state);
+        let states: Vec<Vec<Vec<Complex>>> = incoming.iter()
+            .map(|inputs| results.by_ref().take(EIGENSTATES.len().pow(inputs.len() as u32)).collect())
+            .collect();
+
+        let num_cuts = self.num_cuts();
+        let mut total = 0.0;
+        for (coefficient, pauli) in observable.terms() {
+            // The rightmost character acts on qubit 0
+            let target: Vec<char> = pauli.chars().rev().collect();
+            let mut cache: Vec<HashMap<Vec<usize>, f64>> = vec![HashMap::new(); fragments.len()];
+
+            for assignment in product(PAULIS.len(), num_cuts) {
+                let mut value = *coefficient;
+                for (f, fragment) in fragments.iter().enumerate() {
+                    // A fragment only depends on the operators of its own cuts
+                    let key: Vec<usize> = outgoing[f].iter().chain(&incoming[f]).map(|&(c, _)| assignment[c]).collect();
+                    let contribution = *cache[f].entry(key).or_insert_with(|| {
+                        // Final wire segments carry the observable, outgoing cuts their operator
+                        let mut label = vec!['I'; fragment.circuit.num_qubits()];
+                        for (local, &(qubit, segment)) in fragment.segments.iter().enumerate() {
+                            if segment == self.cuts[qubit].len() {
+                                label[local] = target[qubit];
+                            }
+                        }
+                        for &(c, local) in &outgoing[f] {
+                            label[local] = PAULIS[assignment[c]];
+                        }
+                        let inputs: Vec<char> = incoming[f].iter().map(|&(c, _)| PAULIS[assignment[c]]).collect();
+                        fragment_value(&label, &inputs, &states[f])
+                    });
+
+                    value *= contribution;
+                    if value == 0.0 {
+                        break;
+                    }
+                }
+                total += value;
+            }
+        }
+        total
+    }
+
+    /// Splits the wires at the cuts and groups the segments connected by gates
+    fn fragments(&self) -> Vec<Fragment> {
+        let segment_of = |qubit: usize, operation: usize| {
+            self.cuts[qubit].iter().filter(|&&cut| cut < operation).count()
+        };
+
+        // Union-find over the segments, numbered wire by wire
+        let offsets: Vec<usize> = self.cuts.iter()
+            .scan(0, |next, positions| {
+                let offset = *next;
+                *next += positions.len() + 1;
+                Some(offset)
+            })
+            .collect();
+        let mut parent: Vec<usize> = (0..self.num_cuts() + self.circuit.num_qubits()).collect();
+        fn root(parent: &mut [usize], mut node: usize) -> usize {
+            while parent[node] != node {
+                parent[node] = parent[parent[node]];
+                node = parent[node];
+            }
+            node
+        }
+        for (i, op) in self.circuit.operations().iter().enumerate() {
+            let ids: Vec<usize> = op.qubit.iter().map(|&q| offsets[q] + segment_of(q, i)).collect();
+            for pair in ids.windows(2) {
+                let (a, b) = (root(&mut parent, pair[0]), root(&mut parent, pair[1]));
+                parent[a] = b;
+            }
+        }
+
+        // Number the fragments in the order of their first segment
+        let mut fragment_of_root: HashMap<usize, usize> = HashMap::new();
+        let mut fragments: Vec<Fragment> = Vec::new();
+        let mut location: HashMap<(usize, usize), (usize, usize)> = HashMap::new();
+        for (qubit, &offset) in offsets.iter().enumerate() {
+            for segment in 0..=self.cuts[qubit].len() {
+                let r = root(&mut parent, offset + segment);
+                let f = *fragment_of_root.entry(r).or_insert_with(|| {
+                    fragments.push(Fragment { circuit: QuantumCircuit::new(0), segments: Vec::new() });
+                    fragments.len() - 1
+                });
+                location.insert((qubit, segment), (f, fragments[f].segments.len()));
+                fragments[f].segments.push((qubit, segment));
+            }
+        }
+        for fragment in &mut fragments {
+            fragment.circuit = QuantumCircuit::new(fragment.segments.len());
+        }
+
+        for (i, op) in self.circuit.operations().iter().enumerate() {
+            let located: Vec<(usize, usize)> = op.qubit.iter().map(|&q| location[&(q, segment_of(q, i))]).collect();
+            let mapped = GateOp { qubit: located.iter().map(|&(_, local)| local).collect(), ..op.clone() };
+            fragments[located[0].0].circuit.add_operation(mapped);
+        }
+        fragments
+    }
+}
+
+/// Gates preparing the eigenstates |0⟩, |1⟩, |+⟩, |−⟩, |+i⟩ and |−i⟩ from |0⟩
+const EIGENSTATES: [&[QuantumGate]; 6] = [
+    &[],
+    &[QuantumGate::X],
+    &[QuantumGate::H],
+    &[QuantumGate::X, QuantumGate::H],
+    &[QuantumGate::H, QuantumGate::S],
+    &[QuantumGate::X, QuantumGate::H, QuantumGate::S],
+];
+
+/// Returns the contribution of a fragment for one assignment of operators to its cuts
+/// 
+/// An incoming cut carrying `P` prepares `P / 2 = (|+p⟩⟨+p| − |−p⟩⟨−p|) / 2`, or
+/// `(|0⟩⟨0| + |1⟩⟨1|) / 2` for the identity, so the expectation value of the label is
+/// averaged over both eigenstates with weights ±½.
+/// 
+/// # Arguments
+/// * `label` - The Pauli operator measured on every qubit of the fragment
+/// * `inputs` - The operator carried by every incoming cut
+/// * `states` - The final states for every combination of prepared eigenstates
+fn fragment_value(label: &[char], inputs: &[char], states: &[Vec<Complex>]) -> f64 {
+    let string: String = label.iter().rev().collect();
+    let observable = PauliSum::new(label.len()).with_term(1.0, &string);
+
+    product(2, inputs.len())
+        .map(|choices| {
+            let mut weight = 1.0;
+            let mut index = 0;
+            for (&pauli, &choice) in inputs.iter().zip(&choices) {
+                let (state, sign) = match (pauli, choice) {
+                    ('I', c) => (c, 1.0),
+                    ('Z', c) => (c, if c == 0 { 1.0 } else { -1.0 }),
+                    ('X', c) => (2 + c, if c == 0 { 1.0 } else { -1.0 }),
+                    (_, c) => (4 + c, if c == 0 { 1.0 } else { -1.0 }),
+                };
+                weight *= 0.5 * sign;
+                index = index * EIGENSTATES.len() + state;
+            }
+            weight * observable.expectation(&states[index])
+        })
+        .sum()
+}
+
+/// Returns all tuples of `length` digits below `base`, the first digit most significant
+fn product(base: usize, length: usize) -> impl Iterator<Item = Vec<usize>> {
+    (0..base.pow(length as u32)).map(move |mut index| {
+        let mut digits = vec![0; length];
+        for digit in digits.iter_mut().rev() {
+            *digit = index % base;
+            index /= base;
+        }
+        digits
+    })
+}
@@ -0,0 +1,9 @@
+//! Device description module
+//! 
+//! This module describes the physical properties of quantum hardware, such as coherence
+//! times, gate errors, gate durations and qubit connectivity. Device models can be loaded
+//! from JSON calibration files and consumed by noise simulation and routing.
+
+mod model;
+
+pub use model::{DeviceModel, GateProperties, QubitProperties};
@@ -0,0 +1,291 @@
+use std::io::{self, ErrorKind};
+
+use crate::QuantumCircuit;
+use crate::circuit::Timeline;
+use crate::core::GateOp;
+
+/// Calibration data of a single physical qubit
+/// 
+/// All times are given in nanoseconds.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QubitProperties {
+    /// Energy relaxation time T1
+    pub t1: f64,
+    /// Dephasing time T2, at most `2 * t1`
+    pub t2: f64,
+    /// Probability of reading out the wrong value
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub readout_error: f64,
+}
+
+/// Calibration data of a gate, either for specific qubits or for any qubits
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GateProperties {
+    /// Symbol of the gate, as returned by [`QuantumGate::symbol`](crate::QuantumGate::symbol) without parameters
+    pub gate: String,
+    /// Qubits the calibration applies to, or empty if it applies to any qubits
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub qubits: Vec<usize>,
+    /// Probability that the gate fails
+    pub error: f64,
+    /// Duration of the gate in nanoseconds
+    pub duration: f64,
+}
+
+/// Description of a quantum device's noise and connectivity
+/// 
+/// # Examples
+/// ```
+/// use intrico::device::{DeviceModel, QubitProperties};
+/// 
+/// let qubit = QubitProperties { t1: 100_000.0, t2: 80_000.0, readout_error: 0.02 };
+/// let device = DeviceModel::new("line", vec![qubit; 3])
+///     .with_coupling(0, 1)
+///     .with_coupling(1, 2)
+///     .with_gate("CX", &[], 0.01, 300.0);
+/// 
+/// assert!(device.are_connected(1, 0));
+/// assert!(!device.are_connected(0, 2));
+/// assert_eq!(device.gate_properties("CX", &[2, 1]).unwrap().duration, 300.0);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DeviceModel {
+    /// Name of the device
+    pub name: String,
+    /// Calibration data of every qubit, indexed by qubit
+    pub qubits: Vec<QubitProperties>,
+    /// Calibration data of the supported gates
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub gates: Vec<GateProperties>,
+    /// Pairs of qubits that two-qubit gates can act on, in either direction
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub connectivity: Vec<(usize, usize)>,
+}
+
+impl DeviceModel {
+    /// Creates a device with the given qubits, no gate calibrations and no couplings
+    /// 
+    /// # Arguments
+    /// * `name` - The name of the device
+    /// * `qubits` - The calibration data of every qubit
+    pub fn new<S: Into<String>>(name: S, qubits: Vec<QubitProperties>) -> Self {
+        DeviceModel {
+            name: name.into(),
+            qubits,
+            gates: Vec::new(),
+            connectivity: Vec::new(),
+        }
+    }
+
+    /// Adds a gate calibration to the device
+    /// 
+    /// # Arguments
+    /// * `gate` - The symbol of the gate, e.g. `"CX"` or `"Rx"`
+    /// * `qubits` - The qubits the calibration applies to, or empty for any qubits
+    /// * `error` - The error probability of the gate
+    /// * `duration` - The duration of the gate in nanoseconds
+    pub fn with_gate<S: Into<String>>(mut self, gate: S, qubits: &[usize], error: f64, duration: f64) -> Self {
+        self.gates.push(GateProperties { gate: gate.into(), qubits: qubits.to_vec(), error, duration });
+        self
+    }
+
+    /// Adds a coupling between two qubits
+    pub fn with_coupling(mut self, a: usize, b: usize) -> Self {
+        self.connectivity.push((a, b));
+        self
+    }
+
+    /// Returns the number of qubits of the device
+    pub fn num_qubits(&self) -> usize {
+        self.qubits.len()
+    }
+
+    /// Returns true if a two-qubit gate can act on the given qubits
+    pub fn are_connected(&self, a: usize, b: usize) -> bool {
+        self.connectivity.iter().any(|&(x, y)| (x, y) == (a, b) || (x, y) == (b, a))
+    }
+
+    /// Returns the qubits coupled to the given qubit, in ascending order
+    pub fn neighbours(&self, qubit: usize) -> Vec<usize> {
+        let mut neighbours: Vec<usize> = self.connectivity.iter()
+            .filter_map(|&(a, b)| match (a == qubit, b == qubit) {
+                (true, false) => Some(b),
+                (false, true) => Some(a),
+                _ => None,
+            })
+            .collect();
+        neighbours.sort_unstable();
+        neighbours.dedup();
+        neighbours
+    }
+
+    /// Returns the calibration of a gate acting on the given qubits
+    /// 
+    /// A calibration for exactly these qubits takes precedence over a generic one.
+    /// For two-qubit gates a calibration of the reversed pair is accepted as well.
+    pub fn gate_properties(&self, gate: &str, qubits: &[usize]) -> Option<&GateProperties> {
+        let reversed: Vec<usize> = qubits.iter().rev().copied().collect();
+        let candidates = || self.gates.iter().filter(move |props| props.gate == gate);
+
+        candidates().find(|props| props.qubits == qubits)
+            .or_else(|| candidates().find(|props| qubits.len() == 2 && props.qubits == reversed))
+            .or_else(|| candidates().find(|props| props.qubits.is_empty()))
+    }
+
+    /// Returns the calibration of the gate applied by an operation
+    pub fn operation_properties(&self, op: &GateOp) -> Option<&GateProperties> {
+        let symbol = op.gate.symbol();
+        let kind = symbol.split('(').next().unwrap_or(&symbol);
+        self.gate_properties(kind, &op.qubit)
+    }
+
+    /// Schedules a circuit with the calibrated gate durations of the device
+    /// 
+    /// Durations set on individual operations take precedence; operations without a
+    /// calibration are instantaneous.
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// use intrico::device::{DeviceModel, QubitProperties};
+    /// 
+    /// let qubit = QubitProperties { t1: 100_000.0, t2: 80_000.0, readout_error: 0.0 };
+    /// let device = DeviceModel::new("pair", vec![qubit; 2])
+    ///     .with_gate("H", &[], 0.0, 35.0)
+    ///     .with_gate("CX", &[], 0.0, 300.0);
+    /// 
+    /// let mut qc = QuantumCircuit::new(2);
+    /// qc.h(0);
+    /// qc.cnot(0, 1);
+    /// assert_eq!(device.timeline(&qc).duration(), 335.0);
+    /// ```
+    pub fn timeline(&self, circuit: &QuantumCircuit) -> Timeline {
+        circuit.timeline_with(|op| self.operation_properties(op).map(|props| props.duration))
+    }
+
+    /// Estimates the probability that a circuit runs without any gate or readout error
+    /// 
+    /// Every operation succeeds with one minus its calibrated error and every measurement
+    /// with one minus the readout error of its qubit; operations without a calibration
+    /// are error-free. The estimate ignores decoherence while qubits idle.
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// use intrico::device::{DeviceModel, QubitProperties};
+    /// 
+    /// let qubit = QubitProperties { t1: 100_000.0, t2: 80_000.0, readout_error: 0.02 };
+    /// let device = DeviceModel::new("pair", vec![qubit; 2])
+    ///     .with_gate("CX", &[], 0.01, 300.0);
+    /// 
+    /// let mut qc = QuantumCircuit::new(2);
+    /// qc.cnot(0, 1);
+    /// qc.measure(1, 0);
+    /// assert!((device.estimated_fidelity(&qc) - 0.99 * 0.98).abs() < 1e-12);
+    /// ```
+    pub fn estimated_fidelity(&self, circuit: &QuantumCircuit) -> f64 {
+        circuit.operations().iter()
+            .map(|op| if op.gate.is_measurement() {
+                1.0 - self.qubits.get(op.target()).map_or(0.0, |props| props.readout_error)
+            } else {
+                1.0 - self.operation_properties(op).map_or(0.0, |props| props.error)
+            })
+            .product()
+    }
+
+    /// Checks that the calibration data is physically consistent
+    /// 
+    /// # Errors
+    /// Returns an [`ErrorKind::InvalidData`] error describing the first problem found
+    pub fn validate(&self) -> io::Result<()> {
+        let num_qubits = self.num_qubits();
+
+        for (i, qubit) in self.qubits.iter().enumerate() {
+            if qubit.t1 <= 0.0 || qubit.t2 <= 0.0 {
+                return Err(invalid_data(format!("Qubit {} has non-positive coherence times", i)));
+            }
+            if qubit.t2 > 2.0 * qubit.t1 {
+                return Err(invalid_data(format!("Qubit {} has T2 = {} exceeding 2 * T1 = {}", i, qubit.t2, 2.0 * qubit.t1)));
+            }
+            if !(0.0..=1.0).contains(&qubit.readout_error) {
+                return Err(invalid_data(format!("Qubit {} has readout error {} outside [0, 1]", i, qubit.readout_error)));
+            }
+        }
+
+        for props in &self.gates {
+            if let Some(&qubit) = props.qubits.iter().find(|&&q| q >= num_qubits) {
+                return Err(invalid_data(format!("Gate {} refers to qubit {} of a {}-qubit device", props.gate, qubit, num_qubits)));
+            }
+            if !(0.0..=1.0).contains(&props.error) {
+                return Err(invalid_data(format!("Gate {} has error {} outside [0, 1]", props.gate, props.error)));
+            }
+            if props.duration < 0.0 {
+                return Err(invalid_data(format!("Gate {} has negative duration", props.gate)));
+            }
+        }
+
+        for &(a, b) in &self.connectivity {
+            if a >= num_qubits || b >= num_qubits || a == b {
+                return Err(invalid_data(format!("Invalid coupling ({}, {}) on a {}-qubit device", a, b, num_qubits)));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Parses and validates a device model from a JSON calibration document
+    /// 
+    /// # Errors
+    /// Returns an [`ErrorKind::InvalidData`] error if the document is malformed or inconsistent
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::device::DeviceModel;
+    /// 
+    /// let device = DeviceModel::from_json(r#"{
+    ///     "name": "pair",
+    ///     "qubits": [{"t1": 50000, "t2": 40000}, {"t1": 60000, "t2": 30000}],
+    ///     "gates": [{"gate": "H", "error": 0.001, "duration": 35}],
+    ///     "connectivity": [[0, 1]]
+    /// }"#).unwrap();
+    /// 
+    /// assert_eq!(device.num_qubits(), 2);
+    /// assert!(device.are_connected(0, 1));
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn from_json(json: &str) -> io::Result<Self> {
+        let device: DeviceModel = serde_json::from_str(json).map_err(|e| invalid_data(e.to_string()))?;
+        device.validate()?;
+        Ok(device)
+    }
+
+    /// Serializes the device model to a JSON calibration document
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Loads and validates a device model from a JSON calibration file
+    /// 
+    /// # Errors
+    /// Returns an error if the file cannot be read, or an [`ErrorKind::InvalidData`]
+    /// error if its contents are malformed or inconsistent
+    #[cfg(feature = "serde")]
+    pub fn load<P: AsRef<std::path::Path>>(path: P) -> io::Result<Self> {
+        Self::from_json(&std::fs::read_to_string(path)?)
+    }
+
+    /// Writes the device model to a JSON calibration file
+    #[cfg(feature = "serde")]
+    pub fn save<P: AsRef<std::path::Path>>(&self, path: P) -> io::Result<()> {
+        let json = self.to_json().map_err(|e| invalid_data(e.to_string()))?;
+        std::fs::write(path, json)
+    }
+}
+
+fn invalid_data<S: Into<String>>(message: S) -> io::Error {
+    io::Error::new(ErrorKind::InvalidData, message.into())
+}
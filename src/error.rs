@@ -0,0 +1,96 @@
+//! Error module
+//! 
+//! This module provides [`IntricoError`], the error type of the crate's fallible APIs.
+//! It wraps the error of each module, so applications can propagate any of them with
+//! `?` and still match on the cause.
+
+use std::fmt;
+
+#[cfg(feature = "circuit")]
+use crate::circuit::CircuitError;
+use crate::core::GateError;
+#[cfg(feature = "simulator")]
+use crate::observables::PauliSumError;
+#[cfg(feature = "simulator")]
+use crate::simulator::SimulationError;
+
+/// Errors returned by the fallible APIs of the crate
+/// 
+/// # Examples
+/// ```
+/// use intrico::{IntricoError, QuantumCircuit, QuantumGate};
+/// use intrico::circuit::CircuitError;
+/// 
+/// let mut qc = QuantumCircuit::new(2);
+/// let error = qc.try_add_multi_qubit_gate(QuantumGate::CNOT, &[0, 2]).unwrap_err();
+/// assert_eq!(error, IntricoError::Circuit(CircuitError::QubitOutOfRange { qubit: 2, num_qubits: 2 }));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum IntricoError {
+    /// A gate could not be constructed
+    Gate(GateError),
+    /// An operation could not be added to a circuit
+    #[cfg(feature = "circuit")]
+    Circuit(CircuitError),
+    /// A simulation failed
+    #[cfg(feature = "simulator")]
+    Simulation(SimulationError),
+    /// An operator could not be parsed from a string
+    #[cfg(feature = "simulator")]
+    Parse(PauliSumError),
+}
+
+impl fmt::Display for IntricoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IntricoError::Gate(error) => write!(f, "Gate error: {}", error),
+            #[cfg(feature = "circuit")]
+            IntricoError::Circuit(error) => write!(f, "Circuit error: {}", error),
+            #[cfg(feature = "simulator")]
+            IntricoError::Simulation(error) => write!(f, "Simulation error: {}", error),
+            #[cfg(feature = "simulator")]
+            IntricoError::Parse(error) => write!(f, "Parse error: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for IntricoError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            IntricoError::Gate(error) => Some(error),
+            #[cfg(feature = "circuit")]
+            IntricoError::Circuit(error) => Some(error),
+            #[cfg(feature = "simulator")]
+            IntricoError::Simulation(error) => Some(error),
+            #[cfg(feature = "simulator")]
+            IntricoError::Parse(error) => Some(error),
+        }
+    }
+}
+
+impl From<GateError> for IntricoError {
+    fn from(error: GateError) -> Self {
+        IntricoError::Gate(error)
+    }
+}
+
+#[cfg(feature = "circuit")]
+impl From<CircuitError> for IntricoError {
+    fn from(error: CircuitError) -> Self {
+        IntricoError::Circuit(error)
+    }
+}
+
+#[cfg(feature = "simulator")]
+impl From<SimulationError> for IntricoError {
+    fn from(error: SimulationError) -> Self {
+        IntricoError::Simulation(error)
+    }
+}
+
+#[cfg(feature = "simulator")]
+impl From<PauliSumError> for IntricoError {
+    fn from(error: PauliSumError) -> Self {
+        IntricoError::Parse(error)
+    }
+}
@@ -0,0 +1,15 @@
+//! Quantum error correction module
+//! 
+//! This module provides encoders, syndrome extraction and correction circuits for
+//! small quantum codes, including general stabilizer codes with lookup-table decoders
+//! and surface-code patches sampled by Pauli-frame propagation and decoded by matching,
+//! together with memory experiments that can be run under a
+//! [`NoiseModel`](crate::simulator::NoiseModel) to observe logical error suppression.
+
+mod repetition;
+mod stabilizer;
+mod surface;
+
+pub use repetition::{logical_error_rate, RepetitionCode};
+pub use stabilizer::StabilizerCode;
+pub use surface::SurfaceCode;
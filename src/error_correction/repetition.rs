@@ -0,0 +1,210 @@
+use rusticle::linalg::Matrix;
+
+use crate::{QuantumCircuit, QuantumGate};
+use crate::simulator::SimulationResult;
+
+/// The three-qubit repetition codes
+/// 
+/// Qubits 0, 1 and 2 hold the code block, with qubit 0 carrying the logical state
+/// before encoding and after decoding. Qubits 3 and 4 are ancillas receiving the
+/// parities `q0 ⊕ q1` and `q1 ⊕ q2`, which identify the single qubit that was flipped.
+/// The correction is applied coherently with Toffoli gates controlled by the ancillas,
+/// so the syndrome can be read from the ancillas in the final counts.
+/// 
+/// # Examples
+/// ```
+/// use intrico::QuantumCircuit;
+/// use intrico::error_correction::RepetitionCode;
+/// use intrico::simulator::Simulator;
+/// 
+/// let code = RepetitionCode::BitFlip;
+/// let mut qc = QuantumCircuit::new(5);
+/// qc.x(0);                      // Logical |1⟩
+/// code.encode(&mut qc);
+/// qc.x(2);                      // A bit flip on the block
+/// code.extract_syndrome(&mut qc);
+/// code.correct(&mut qc);
+/// code.decode(&mut qc);
+/// 
+/// let result = Simulator::new().with_circuit(qc).run(10);
+/// assert_eq!(result.counts["10001"], 10);  // Only ancilla 4 flags the error, qubit 0 is 1
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RepetitionCode {
+    /// Protects against X errors by encoding |0⟩ → |000⟩ and |1⟩ → |111⟩
+    BitFlip,
+    /// Protects against Z errors by encoding |0⟩ → |+++⟩ and |1⟩ → |−−−⟩
+    PhaseFlip,
+}
+
+impl RepetitionCode {
+    /// The qubits of the code block
+    pub const DATA_QUBITS: [usize; 3] = [0, 1, 2];
+    /// The ancillas receiving the syndrome
+    pub const ANCILLA_QUBITS: [usize; 2] = [3, 4];
+    /// The total number of qubits used by the code
+    pub const NUM_QUBITS: usize = 5;
+
+    /// Spreads the state of qubit 0 over the code block
+    pub fn encode(&self, circuit: &mut QuantumCircuit) {
+        circuit.cnot(0, 1);
+        circuit.cnot(0, 2);
+        if *self == RepetitionCode::PhaseFlip {
+            Self::hadamard_layer(circuit);
+        }
+    }
+
+    /// Gathers the state of the code block back onto qubit 0
+    pub fn decode(&self, circuit: &mut QuantumCircuit) {
+        if *self == RepetitionCode::PhaseFlip {
+            Self::hadamard_layer(circuit);
+        }
+        circuit.cnot(0, 2);
+        circuit.cnot(0, 1);
+    }
+
+    /// Writes the parities `q0 ⊕ q1` and `q1 ⊕ q2` onto ancillas 3 and 4
+    /// 
+    /// For the phase-flip code the parities are taken in the Hadamard basis.
+    pub fn extract_syndrome(&self, circuit: &mut QuantumCircuit) {
+        if *self == RepetitionCode::PhaseFlip {
+            Self::hadamard_layer(circuit);
+        }
+        Self::parity_checks(circuit);
+        if *self == RepetitionCode::PhaseFlip {
+            Self::hadamard_layer(circuit);
+        }
+    }
+
+    /// Flips the qubit identified by the syndrome held on the ancillas
+    /// 
+    /// Syndrome `(1, 0)` points at qubit 0, `(1, 1)` at qubit 1 and `(0, 1)` at qubit 2.
+    pub fn correct(&self, circuit: &mut QuantumCircuit) {
+        if *self == RepetitionCode::PhaseFlip {
+            Self::hadamard_layer(circuit);
+        }
+        Self::flip_correction(circuit);
+        if *self == RepetitionCode::PhaseFlip {
+            Self::hadamard_layer(circuit);
+        }
+    }
+
+    /// Builds a memory experiment storing one logical bit in the code
+    /// 
+    /// The logical bit is prepared on qubit 0 and encoded, the block idles for
+    /// `idle_steps` steps, and one round of syndrome extraction and correction is
+    /// applied before decoding. Qubit 0 finally reads the logical bit. Every idle step
+    /// applies an identity gate to each data qubit, which attracts the single-qubit
+    /// errors of a noise model.
+    /// 
+    /// Hadamard layers of consecutive steps cancel and are left out, so the phase-flip
+    /// block only leaves the computational basis while it idles.
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::error_correction::{logical_error_rate, RepetitionCode};
+    /// use intrico::simulator::{NoiseModel, Simulator};
+    /// 
+    /// let code = RepetitionCode::BitFlip;
+    /// let noise = NoiseModel::depolarizing(0.05, 0.0);
+    /// 
+    /// let protected = Simulator::new().with_noise(noise.clone()).with_seed(1)
+    ///     .with_circuit(code.memory_circuit(true, 1)).run(20_000);
+    /// let unprotected = Simulator::new().with_noise(noise).with_seed(1)
+    ///     .with_circuit(code.unprotected_circuit(true, 1)).run(20_000);
+    /// assert!(logical_error_rate(&protected, true) < logical_error_rate(&unprotected, true));
+    /// ```
+    pub fn memory_circuit(&self, logical_one: bool, idle_steps: usize) -> QuantumCircuit {
+        let mut circuit = QuantumCircuit::new(Self::NUM_QUBITS);
+        if logical_one {
+            circuit.x(0);
+        }
+        self.encode(&mut circuit);
+
+        for _ in 0..idle_steps {
+            for qubit in Self::DATA_QUBITS {
+                Self::idle(&mut circuit, qubit);
+            }
+        }
+
+        if *self == RepetitionCode::PhaseFlip {
+            Self::hadamard_layer(&mut circuit);
+        }
+        Self::parity_checks(&mut circuit);
+        Self::flip_correction(&mut circuit);
+        circuit.cnot(0, 2);
+        circuit.cnot(0, 1);
+        circuit
+    }
+
+    /// Builds the same experiment on a single unencoded qubit, as a baseline
+    /// 
+    /// The bit is stored as |0⟩/|1⟩ against the bit-flip code and as |+⟩/|−⟩ against the
+    /// phase-flip code, matching the basis each code stores it in.
+    pub fn unprotected_circuit(&self, logical_one: bool, idle_steps: usize) -> QuantumCircuit {
+        let mut circuit = QuantumCircuit::new(1);
+        if logical_one {
+            circuit.x(0);
+        }
+        if *self == RepetitionCode::PhaseFlip {
+            circuit.h(0);
+        }
+        for _ in 0..idle_steps {
+            Self::idle(&mut circuit, 0);
+        }
+        if *self == RepetitionCode::PhaseFlip {
+            circuit.h(0);
+        }
+        circuit
+    }
+
+    /// Writes the bit-flip parities of the block onto the ancillas
+    fn parity_checks(circuit: &mut QuantumCircuit) {
+        circuit.cnot(0, 3);
+        circuit.cnot(1, 3);
+        circuit.cnot(1, 4);
+        circuit.cnot(2, 4);
+    }
+
+    /// Flips the data qubit selected by the ancillas with Toffoli gates
+    fn flip_correction(circuit: &mut QuantumCircuit) {
+        let [s0, s1] = Self::ANCILLA_QUBITS;
+
+        circuit.x(s1);
+        circuit.ccx(s0, s1, 0);
+        circuit.x(s1);
+
+        circuit.ccx(s0, s1, 1);
+
+        circuit.x(s0);
+        circuit.ccx(s0, s1, 2);
+        circuit.x(s0);
+    }
+
+    /// Applies a Hadamard gate to every data qubit
+    fn hadamard_layer(circuit: &mut QuantumCircuit) {
+        for qubit in Self::DATA_QUBITS {
+            circuit.h(qubit);
+        }
+    }
+
+    /// Applies an identity gate, a slot for the noise model to act on
+    fn idle(circuit: &mut QuantumCircuit, qubit: usize) {
+        let gate = QuantumGate::Custom(Matrix::identity(2), "Idle".to_string(), "I".to_string());
+        circuit.add_gate(gate, qubit);
+    }
+}
+
+/// Returns the fraction of shots in which qubit 0 did not read the logical bit
+/// 
+/// # Arguments
+/// * `result` - The result of a memory experiment
+/// * `logical_one` - The logical bit that was stored
+pub fn logical_error_rate(result: &SimulationResult, logical_one: bool) -> f64 {
+    if result.shots == 0 {
+        return 0.0;
+    }
+    let wrong = if logical_one { "0" } else { "1" };
+    let errors = result.marginal_counts(&[0]).get(wrong).copied().unwrap_or(0);
+    errors as f64 / result.shots as f64
+}
@@ -0,0 +1,383 @@
+use std::collections::HashMap;
+
+use rusticle::complex::Complex;
+use rusticle::linalg::Matrix;
+
+use crate::{QuantumCircuit, QuantumGate};
+use crate::utility::complete_unitary;
+
+/// A stabilizer code encoding one logical qubit, decoded with a lookup table
+/// 
+/// The code is defined by `n - 1` independent, commuting stabilizer generators on `n`
+/// qubits together with a pair of anticommuting logical operators. Pauli strings are
+/// written like bitstrings: the rightmost character acts on qubit 0.
+/// 
+/// Qubits `0..n` hold the code block, with qubit 0 carrying the logical state before
+/// encoding and after decoding. Qubits `n..2n-1` are ancillas, the `i`-th of which
+/// receives the outcome of the `i`-th stabilizer. The decoder maps every syndrome to a
+/// minimum-weight Pauli correction, which is applied coherently with gates controlled
+/// by the ancillas, so the syndrome can be read from the ancillas in the final counts.
+/// 
+/// # Examples
+/// ```
+/// use intrico::QuantumCircuit;
+/// use intrico::error_correction::StabilizerCode;
+/// use intrico::simulator::Simulator;
+/// 
+/// let code = StabilizerCode::five_qubit();
+/// let mut qc = QuantumCircuit::new(code.num_qubits() + code.num_stabilizers());
+/// qc.x(0);                      // Logical |1⟩
+/// code.encode(&mut qc);
+/// qc.y(2);                      // An error on the block
+/// code.extract_syndrome(&mut qc);
+/// code.correct(&mut qc);
+/// code.decode(&mut qc);
+/// 
+/// let syndrome = code.syndrome("IIYII");
+/// assert_eq!(code.correction(&syndrome), "IIYII");
+/// 
+/// let result = Simulator::new().with_circuit(qc).run(10);
+/// assert_eq!(result.marginal_counts(&[0])["1"], 10);
+/// ```
+#[derive(Debug, Clone)]
+pub struct StabilizerCode {
+    num_qubits: usize,
+    stabilizers: Vec<String>,
+    logical_x: String,
+    logical_z: String,
+    /// Minimum-weight correction for every syndrome
+    decoder: HashMap<Vec<bool>, String>,
+}
+
+impl StabilizerCode {
+    /// Creates a code from its stabilizer generators and logical operators
+    /// 
+    /// # Arguments
+    /// * `stabilizers` - The `n - 1` stabilizer generators
+    /// * `logical_x` - The logical X operator
+    /// * `logical_z` - The logical Z operator
+    /// 
+    /// # Panics
+    /// Panics if a string is not a Pauli string on `n` qubits, if the generators are not
+    /// `n - 1` independent commuting operators, or if the logical operators do not
+    /// commute with the generators and anticommute with each other
+    pub fn new(stabilizers: &[&str], logical_x: &str, logical_z: &str) -> Self {
+        let num_qubits = logical_z.chars().count();
+        if num_qubits == 0 || num_qubits > 16 {
+            panic!("Stabilizer codes must act on 1 to 16 qubits, got {}", num_qubits);
+        }
+        let parse = |pauli: &str| -> (usize, usize) {
+            if pauli.chars().count() != num_qubits {
+                panic!("Pauli string '{}' does not act on {} qubits", pauli, num_qubits);
+            }
+            symplectic(pauli)
+        };
+
+        let generators: Vec<(usize, usize)> = stabilizers.iter().map(|s| parse(s)).collect();
+        let (x, z) = (parse(logical_x), parse(logical_z));
+
+        if generators.len() != num_qubits - 1 {
+            panic!("A code on {} qubits needs {} stabilizer generators, got {}",
+                num_qubits, num_qubits - 1, generators.len());
+        }
+        if rank(&generators, num_qubits) != generators.len() {
+            panic!("Stabilizer generators are not independent");
+        }
+        for (i, &a) in generators.iter().enumerate() {
+            if let Some(j) = (0..i).find(|&j| !commutes(a, generators[j])) {
+                panic!("Stabilizers '{}' and '{}' do not commute", stabilizers[j], stabilizers[i]);
+            }
+            for (name, logical) in [(logical_x, x), (logical_z, z)] {
+                if !commutes(a, logical) {
+                    panic!("Logical operator '{}' does not commute with stabilizer '{}'", name, stabilizers[i]);
+                }
+            }
+        }
+        if commutes(x, z) {
+            panic!("Logical operators '{}' and '{}' must anticommute", logical_x, logical_z);
+        }
+
+        let mut code = StabilizerCode {
+            num_qubits,
+            stabilizers: stabilizers.iter().map(|s| s.to_string()).collect(),
+            logical_x: logical_x.to_string(),
+            logical_z: logical_z.to_string(),
+            decoder: HashMap::new(),
+        };
+        code.build_decoder();
+        code
+    }
+
+    /// Creates the `[[7,1,3]]` Steane code
+    pub fn steane() -> Self {
+        Self::new(
+            &["IIIXXXX", "IXXIIXX", "XIXIXIX", "IIIZZZZ", "IZZIIZZ", "ZIZIZIZ"],
+            "XXXXXXX",
+            "ZZZZZZZ",
+        )
+    }
+
+    /// Creates the `[[5,1,3]]` perfect code
+    pub fn five_qubit() -> Self {
+        Self::new(
+            &["XZZXI", "IXZZX", "XIXZZ", "ZXIXZ"],
+            "XXXXX",
+            "ZZZZZ",
+        )
+    }
+
+    /// Returns the number of qubits in the code block
+    pub fn num_qubits(&self) -> usize {
+        self.num_qubits
+    }
+
+    /// Returns the number of stabilizer generators, which is also the number of ancillas
+    pub fn num_stabilizers(&self) -> usize {
+        self.stabilizers.len()
+    }
+
+    /// Returns the stabilizer generators
+    pub fn stabilizers(&self) -> &[String] {
+        &self.stabilizers
+    }
+
+    /// Returns the logical X operator
+    pub fn logical_x(&self) -> &str {
+        &self.logical_x
+    }
+
+    /// Returns the logical Z operator
+    pub fn logical_z(&self) -> &str {
+        &self.logical_z
+    }
+
+    /// Returns the syndrome of a Pauli error, `true` where it anticommutes with a generator
+    /// 
+    /// # Panics
+    /// Panics if the error is not a Pauli string on the qubits of the code
+    pub fn syndrome(&self, error: &str) -> Vec<bool> {
+        if error.chars().count() != self.num_qubits {
+            panic!("Pauli string '{}' does not act on {} qubits", error, self.num_qubits);
+        }
+        let error = symplectic(error);
+        self.stabilizers.iter()
+            .map(|stabilizer| !commutes(symplectic(stabilizer), error))
+            .collect()
+    }
+
+    /// Returns the minimum-weight Pauli correction for a syndrome
+    /// 
+    /// # Panics
+    /// Panics if the syndrome length differs from the number of generators
+    pub fn correction(&self, syndrome: &[bool]) -> &str {
+        self.decoder.get(syndrome)
+            .unwrap_or_else(|| panic!("Syndrome of length {} does not match {} stabilizers",
+                syndrome.len(), self.stabilizers.len()))
+    }
+
+    /// Maps the state of qubit 0 to the corresponding logical state of the code block
+    /// 
+    /// The encoder is a single custom gate on the block, computed by projecting onto
+    /// the code space.
+    pub fn encode(&self, circuit: &mut QuantumCircuit) {
+        let gate = QuantumGate::Custom(self.encoder(), "Encode".to_string(), "Enc".to_string());
+        circuit.add_multi_qubit_gate(gate, &self.block());
+    }
+
+    /// Maps the logical state of the code block back onto qubit 0
+    pub fn decode(&self, circuit: &mut QuantumCircuit) {
+        let matrix = self.encoder().conjugate_transpose();
+        let gate = QuantumGate::Custom(matrix, "Decode".to_string(), "Dec".to_string());
+        circuit.add_multi_qubit_gate(gate, &self.block());
+    }
+
+    /// Writes the outcome of every stabilizer onto its ancilla
+    /// 
+    /// Each ancilla is put in superposition, controls the Pauli operators of its
+    /// generator and is rotated back, so it reads 1 where the generator has eigenvalue -1.
+    pub fn extract_syndrome(&self, circuit: &mut QuantumCircuit) {
+        for (i, stabilizer) in self.stabilizers.iter().enumerate() {
+            let ancilla = self.num_qubits + i;
+            circuit.h(ancilla);
+            for (qubit, pauli) in stabilizer.chars().rev().enumerate() {
+                match pauli {
+                    'X' => circuit.cnot(ancilla, qubit),
+                    'Z' => circuit.cz(ancilla, qubit),
+                    'Y' => {
+                        circuit.sdg(qubit);
+                        circuit.cnot(ancilla, qubit);
+                        circuit.s(qubit);
+                    },
+                    _ => {},
+                }
+            }
+            circuit.h(ancilla);
+        }
+    }
+
+    /// Applies the correction of the syndrome held on the ancillas
+    /// 
+    /// Every non-trivial syndrome adds its correction controlled on the ancillas matching
+    /// it, so the circuit grows with the size of the lookup table.
+    pub fn correct(&self, circuit: &mut QuantumCircuit) {
+        let ancillas: Vec<usize> = (self.num_qubits..self.num_qubits + self.stabilizers.len()).collect();
+
+        let mut entries: Vec<_> = self.decoder.iter()
+            .filter(|(syndrome, _)| syndrome.iter().any(|&bit| bit))
+            .collect();
+        entries.sort();
+
+        for (syndrome, correction) in entries {
+            let flipped: Vec<usize> = ancillas.iter()
+                .zip(syndrome)
+                .filter(|&(_, &bit)| !bit)
+                .map(|(&ancilla, _)| ancilla)
+                .collect();
+
+            for &ancilla in &flipped {
+                circuit.x(ancilla);
+            }
+            for (qubit, pauli) in correction.chars().rev().enumerate() {
+                match pauli {
+                    'X' => circuit.mcx(&ancillas, qubit),
+                    'Z' => circuit.mcz(&[ancillas.as_slice(), &[qubit]].concat()),
+                    'Y' => {
+                        circuit.sdg(qubit);
+                        circuit.mcx(&ancillas, qubit);
+                        circuit.s(qubit);
+                    },
+                    _ => {},
+                }
+            }
+            for &ancilla in &flipped {
+                circuit.x(ancilla);
+            }
+        }
+    }
+
+    /// Fills the lookup table with the lowest-weight error of every syndrome
+    fn build_decoder(&mut self) {
+        let n = self.num_qubits;
+        let table_size = 1usize << self.stabilizers.len();
+        let paulis = ['X', 'Y', 'Z'];
+
+        for weight in 0..=n {
+            for support in (0..1usize << n).filter(|s| s.count_ones() as usize == weight) {
+                let qubits: Vec<usize> = (0..n).filter(|q| support >> q & 1 == 1).collect();
+                for choice in 0..3usize.pow(weight as u32) {
+                    let mut error = vec!['I'; n];
+                    let mut digits = choice;
+                    for &qubit in &qubits {
+                        error[n - 1 - qubit] = paulis[digits % 3];
+                        digits /= 3;
+                    }
+                    let error: String = error.into_iter().collect();
+                    self.decoder.entry(self.syndrome(&error)).or_insert(error);
+                }
+            }
+            if self.decoder.len() == table_size {
+                break;
+            }
+        }
+    }
+
+    /// Returns the unitary mapping |0⟩ and |1⟩ on qubit 0 to the logical basis states
+    fn encoder(&self) -> Matrix<Complex> {
+        let dim = 1 << self.num_qubits;
+        let projectors: Vec<(usize, usize)> = self.stabilizers.iter()
+            .chain(std::iter::once(&self.logical_z))
+            .map(|pauli| symplectic(pauli))
+            .collect();
+
+        // Project basis states onto the code space with logical Z = +1
+        let zero = (0..dim)
+            .map(|basis| {
+                let mut state = vec![Complex::new(0.0, 0.0); dim];
+                state[basis] = Complex::new(1.0, 0.0);
+                for &pauli in &projectors {
+                    let flipped = apply_pauli(pauli, &state);
+                    for (amp, other) in state.iter_mut().zip(flipped) {
+                        *amp = (*amp + other) * 0.5;
+                    }
+                }
+                state
+            })
+            .find(|state| state.iter().map(|amp| amp.norm_squared()).sum::<f64>() > 1e-6)
+            .expect("The code space is never empty for valid generators");
+
+        let norm = zero.iter().map(|amp| amp.norm_squared()).sum::<f64>().sqrt();
+        let zero: Vec<Complex> = zero.into_iter().map(|amp| amp / norm).collect();
+        let one = apply_pauli(symplectic(&self.logical_x), &zero);
+
+        complete_unitary(&[zero, one])
+    }
+
+    /// Returns the block qubits ordered for a custom gate whose matrix bit `j` is qubit `j`
+    fn block(&self) -> Vec<usize> {
+        (0..self.num_qubits).rev().collect()
+    }
+}
+
+/// Returns the X and Z bitmasks of a Pauli string
+/// 
+/// # Panics
+/// Panics if the string contains characters other than `I`, `X`, `Y` and `Z`
+fn symplectic(pauli: &str) -> (usize, usize) {
+    let (mut x, mut z) = (0, 0);
+    for (qubit, c) in pauli.chars().rev().enumerate() {
+        match c {
+            'I' => {},
+            'X' => x |= 1 << qubit,
+            'Y' => {
+                x |= 1 << qubit;
+                z |= 1 << qubit;
+            },
+            'Z' => z |= 1 << qubit,
+            _ => panic!("Invalid Pauli operator '{}' in '{}'", c, pauli),
+        }
+    }
+    (x, z)
+}
+
+/// Returns whether two Pauli strings commute
+fn commutes((ax, az): (usize, usize), (bx, bz): (usize, usize)) -> bool {
+    ((ax & bz) ^ (az & bx)).count_ones().is_multiple_of(2)
+}
+
+/// Returns the rank over GF(2) of a set of Pauli strings
+fn rank(paulis: &[(usize, usize)], num_qubits: usize) -> usize {
+    let mut rows: Vec<usize> = paulis.iter().map(|&(x, z)| x | z << num_qubits).collect();
+    let mut rank = 0;
+    for bit in 0..2 * num_qubits {
+        if let Some(pivot) = (rank..rows.len()).find(|&r| rows[r] >> bit & 1 == 1) {
+            rows.swap(rank, pivot);
+            let pivot_row = rows[rank];
+            for (r, row) in rows.iter_mut().enumerate() {
+                if r != rank && *row >> bit & 1 == 1 {
+                    *row ^= pivot_row;
+                }
+            }
+            rank += 1;
+        }
+    }
+    rank
+}
+
+/// Applies a Pauli string to a statevector
+fn apply_pauli((x, z): (usize, usize), state: &[Complex]) -> Vec<Complex> {
+    let num_y = (x & z).count_ones();
+    let phase = match num_y % 4 {
+        0 => Complex::new(1.0, 0.0),
+        1 => Complex::new(0.0, 1.0),
+        2 => Complex::new(-1.0, 0.0),
+        _ => Complex::new(0.0, -1.0),
+    };
+
+    // P|k⟩ = i^num_y · (-1)^popcount(k & z) |k ^ x⟩
+    let mut result = vec![Complex::new(0.0, 0.0); state.len()];
+    for (k, &amp) in state.iter().enumerate() {
+        let sign = if (k & z).count_ones().is_multiple_of(2) { 1.0 } else { -1.0 };
+        result[k ^ x] = phase * amp * sign;
+    }
+    result
+}
@@ -0,0 +1,388 @@
+use std::collections::VecDeque;
+
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+use crate::QuantumGate;
+use crate::core::GateOp;
+use crate::simulator::{apply_errors, propagate, NoiseModel, PauliChannel};
+
+/// Largest number of detection events matched exactly, beyond which matching is greedy
+const MAX_EXACT_DEFECTS: usize = 16;
+
+/// A rotated surface-code patch of odd distance `d` running a memory experiment
+/// 
+/// The `d × d` data qubits are numbered row by row, followed by one ancilla per Z-type
+/// and then per X-type plaquette. Z-type plaquettes close the left and right edges and
+/// X-type plaquettes the top and bottom edges, so the logical Z operator runs along the
+/// top row and the logical X operator down the left column.
+/// 
+/// Every round measures all plaquettes with CNOT circuits, and the memory experiment
+/// stores logical |0⟩ for `rounds` rounds before reading out the data qubits. As the
+/// circuits only contain Clifford gates and the noise model only applies Pauli errors,
+/// shots are sampled by propagating the Pauli frame of the errors instead of a
+/// statevector, so patches of tens of qubits remain cheap to simulate. Z-type detection
+/// events are decoded by minimum-weight matching on the space-time decoding graph.
+/// 
+/// # Examples
+/// ```
+/// use intrico::error_correction::SurfaceCode;
+/// use intrico::simulator::NoiseModel;
+/// 
+/// let code = SurfaceCode::new(3);
+/// let noise = NoiseModel::depolarizing(0.001, 0.001).with_readout_error(0.001);
+/// 
+/// let rate = code.logical_error_rate(3, &noise, 2000, 7);
+/// assert!(rate < 0.01);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SurfaceCode {
+    distance: usize,
+    /// Data qubits at the NW, NE, SW and SE corners of every Z-type plaquette
+    z_plaquettes: Vec<Plaquette>,
+    /// Data qubits at the NW, NE, SW and SE corners of every X-type plaquette
+    x_plaquettes: Vec<Plaquette>,
+}
+
+/// Corners of a plaquette, with `None` outside the patch
+type Plaquette = [Option<usize>; 4];
+
+/// Edge of the decoding graph between two Z-type plaquettes, or a plaquette and the boundary
+#[derive(Debug, Clone, Copy)]
+struct Edge {
+    to: Option<usize>,
+    /// Whether an error on this edge flips the logical Z operator
+    logical: bool,
+}
+
+impl SurfaceCode {
+    /// Creates a rotated surface-code patch
+    /// 
+    /// # Panics
+    /// Panics if the distance is not an odd number of at least 3
+    pub fn new(distance: usize) -> Self {
+        if distance < 3 || distance.is_multiple_of(2) {
+            panic!("Surface code distance must be odd and at least 3, got {}", distance);
+        }
+        let d = distance;
+
+        let mut z_plaquettes = Vec::new();
+        let mut x_plaquettes = Vec::new();
+        for i in 0..=d {
+            for j in 0..=d {
+                let x_type = (i + j).is_multiple_of(2);
+                let top_bottom = i == 0 || i == d;
+                let left_right = j == 0 || j == d;
+                // Only X-type plaquettes close the top and bottom, Z-type the left and right
+                if (top_bottom && !x_type) || (left_right && x_type) {
+                    continue;
+                }
+
+                let (north, west) = (i.wrapping_sub(1), j.wrapping_sub(1));
+                let data = [(north, west), (north, j), (i, west), (i, j)]
+                    .map(|(r, c)| (r < d && c < d).then(|| r * d + c));
+                if x_type {
+                    x_plaquettes.push(data);
+                } else {
+                    z_plaquettes.push(data);
+                }
+            }
+        }
+
+        SurfaceCode { distance, z_plaquettes, x_plaquettes }
+    }
+
+    /// Returns the code distance
+    pub fn distance(&self) -> usize {
+        self.distance
+    }
+
+    /// Returns the number of data qubits
+    pub fn num_data_qubits(&self) -> usize {
+        self.distance * self.distance
+    }
+
+    /// Returns the number of qubits including one ancilla per plaquette
+    pub fn num_qubits(&self) -> usize {
+        self.num_data_qubits() + self.z_plaquettes.len() + self.x_plaquettes.len()
+    }
+
+    /// Returns the plaquette operators on the data qubits, Z-type first
+    /// 
+    /// The strings are written like bitstrings: the rightmost character acts on qubit 0.
+    pub fn stabilizers(&self) -> Vec<String> {
+        let support = |plaquette: &Plaquette| plaquette.iter().flatten().copied().collect::<Vec<_>>();
+        let z = self.z_plaquettes.iter().map(|p| self.pauli_string(&support(p), 'Z'));
+        let x = self.x_plaquettes.iter().map(|p| self.pauli_string(&support(p), 'X'));
+        z.chain(x).collect()
+    }
+
+    /// Returns the logical X operator, along the left column
+    pub fn logical_x(&self) -> String {
+        let column: Vec<usize> = (0..self.distance).map(|r| r * self.distance).collect();
+        self.pauli_string(&column, 'X')
+    }
+
+    /// Returns the logical Z operator, along the top row
+    pub fn logical_z(&self) -> String {
+        let row: Vec<usize> = (0..self.distance).collect();
+        self.pauli_string(&row, 'Z')
+    }
+
+    /// Returns the number of detectors of a memory experiment
+    /// 
+    /// Detector `t · m + s` compares Z-type plaquette `s` of round `t` with the previous
+    /// round, where `m` is the number of Z-type plaquettes. The last layer `t = rounds`
+    /// compares the final round with the parities of the data readout.
+    pub fn num_detectors(&self, rounds: usize) -> usize {
+        (rounds + 1) * self.z_plaquettes.len()
+    }
+
+    /// Predicts whether the logical readout was flipped from the detection events
+    /// 
+    /// Detection events are paired up, or with the boundary, by minimum-weight matching
+    /// on the space-time decoding graph with unit edge weights. Matching is exact for up
+    /// to 16 events and greedy beyond.
+    /// 
+    /// # Panics
+    /// Panics if the number of detectors does not match the number of rounds
+    pub fn decode(&self, rounds: usize, detectors: &[bool]) -> bool {
+        if detectors.len() != self.num_detectors(rounds) {
+            panic!("Expected {} detectors for {} rounds, got {}", self.num_detectors(rounds), rounds, detectors.len());
+        }
+        let defects: Vec<usize> = (0..detectors.len()).filter(|&k| detectors[k]).collect();
+        if defects.is_empty() {
+            return false;
+        }
+
+        let graph = self.decoding_graph();
+        let paths: Vec<_> = defects.iter().map(|&k| self.shortest_paths(&graph, rounds, k)).collect();
+
+        // Distance and logical parity from defect `a` to defect `b`, or to the boundary for `None`
+        let boundary = self.num_detectors(rounds);
+        let pair = |a: usize, b: Option<usize>| paths[a][b.map_or(boundary, |b| defects[b])];
+
+        if defects.len() <= MAX_EXACT_DEFECTS {
+            exact_matching(defects.len(), pair)
+        } else {
+            greedy_matching(defects.len(), pair)
+        }
+    }
+
+    /// Samples a memory experiment and returns the fraction of shots with a logical error
+    /// 
+    /// # Arguments
+    /// * `rounds` - The number of syndrome-measurement rounds
+    /// * `noise` - The gate and readout errors, applied after every H and CNOT gate and to
+    ///   every measurement
+    /// * `shots` - The number of shots to sample
+    /// * `seed` - The seed of the random number generator
+    pub fn logical_error_rate(&self, rounds: usize, noise: &NoiseModel, shots: usize, seed: u64) -> f64 {
+        if shots == 0 {
+            return 0.0;
+        }
+        let mut rng = StdRng::seed_from_u64(seed);
+        let round = self.round_operations();
+        let channels: Vec<Vec<PauliChannel>> = round.iter().map(|op| noise.channels(op)).collect();
+
+        let errors = (0..shots)
+            .filter(|_| {
+                let (detectors, flipped) = self.sample(rounds, &round, &channels, noise, &mut rng);
+                self.decode(rounds, &detectors) != flipped
+            })
+            .count();
+        errors as f64 / shots as f64
+    }
+
+    /// Returns `(p, logical error rate)` pairs for uniform physical error rates `p`
+    /// 
+    /// Each point runs `d` rounds with depolarizing gate errors and readout errors of
+    /// probability `p`.
+    pub fn error_curve(&self, physical_error_rates: &[f64], shots: usize, seed: u64) -> Vec<(f64, f64)> {
+        physical_error_rates.iter()
+            .map(|&p| {
+                let noise = NoiseModel::depolarizing(p, p).with_readout_error(p);
+                (p, self.logical_error_rate(self.distance, &noise, shots, seed))
+            })
+            .collect()
+    }
+
+    /// Returns the gates of one round of plaquette measurements
+    fn round_operations(&self) -> Vec<GateOp> {
+        let n = self.num_data_qubits();
+        let mut ops = Vec::new();
+
+        for (s, plaquette) in self.z_plaquettes.iter().enumerate() {
+            // N-shaped order NW, SW, NE, SE leaves hook errors vertical, across the logical Z operator
+            for qubit in [0, 2, 1, 3].into_iter().filter_map(|k| plaquette[k]) {
+                ops.push(GateOp::controlled(QuantumGate::CNOT, qubit, n + s, 0));
+            }
+        }
+        let offset = n + self.z_plaquettes.len();
+        for (s, plaquette) in self.x_plaquettes.iter().enumerate() {
+            ops.push(GateOp::new(QuantumGate::H, offset + s, 0));
+            // Z-shaped order NW, NE, SW, SE leaves hook errors horizontal, across the logical X operator
+            for &qubit in plaquette.iter().flatten() {
+                ops.push(GateOp::controlled(QuantumGate::CNOT, offset + s, qubit, 0));
+            }
+            ops.push(GateOp::new(QuantumGate::H, offset + s, 0));
+        }
+        ops
+    }
+
+    /// Samples the detectors of one shot and whether the logical readout was flipped
+    fn sample<R: Rng + ?Sized>(
+        &self,
+        rounds: usize,
+        round: &[GateOp],
+        channels: &[Vec<PauliChannel>],
+        noise: &NoiseModel,
+        rng: &mut R,
+    ) -> (Vec<bool>, bool) {
+        let n = self.num_data_qubits();
+        let m = self.z_plaquettes.len();
+        let mut x_frame = vec![false; self.num_qubits()];
+        let mut z_frame = vec![false; self.num_qubits()];
+        let mut previous = vec![false; m];
+        let mut detectors = Vec::with_capacity(self.num_detectors(rounds));
+
+        for _ in 0..rounds {
+            for (op, op_channels) in round.iter().zip(channels) {
+                propagate(op, &mut x_frame, &mut z_frame);
+                apply_errors(op_channels, rng, &mut x_frame, &mut z_frame);
+            }
+
+            // Measure and reset every ancilla, keeping only the Z-type outcomes
+            for ancilla in n..self.num_qubits() {
+                let flipped = x_frame[ancilla] ^ (rng.random::<f64>() < noise.readout_error(ancilla));
+                if ancilla < n + m {
+                    detectors.push(flipped ^ previous[ancilla - n]);
+                    previous[ancilla - n] = flipped;
+                }
+                x_frame[ancilla] = false;
+                z_frame[ancilla] = false;
+            }
+        }
+
+        let readout: Vec<bool> = (0..n)
+            .map(|q| x_frame[q] ^ (rng.random::<f64>() < noise.readout_error(q)))
+            .collect();
+        for (s, plaquette) in self.z_plaquettes.iter().enumerate() {
+            let parity = plaquette.iter().flatten().fold(false, |acc, &q| acc ^ readout[q]);
+            detectors.push(parity ^ previous[s]);
+        }
+        let flipped = readout[..self.distance].iter().fold(false, |acc, &bit| acc ^ bit);
+
+        (detectors, flipped)
+    }
+
+    /// Returns the edges of every Z-type plaquette caused by X errors on the data qubits
+    fn decoding_graph(&self) -> Vec<Vec<Edge>> {
+        let mut graph = vec![Vec::new(); self.z_plaquettes.len()];
+        for qubit in 0..self.num_data_qubits() {
+            let touched: Vec<usize> = (0..self.z_plaquettes.len())
+                .filter(|&s| self.z_plaquettes[s].contains(&Some(qubit)))
+                .collect();
+            let logical = qubit < self.distance;
+            match touched[..] {
+                [a] => graph[a].push(Edge { to: None, logical }),
+                [a, b] => {
+                    graph[a].push(Edge { to: Some(b), logical });
+                    graph[b].push(Edge { to: Some(a), logical });
+                },
+                _ => {},
+            }
+        }
+        graph
+    }
+
+    /// Breadth-first search from a detector over the space-time decoding graph
+    /// 
+    /// Returns the distance and logical parity to every detector, followed by the boundary.
+    fn shortest_paths(&self, graph: &[Vec<Edge>], rounds: usize, source: usize) -> Vec<(usize, bool)> {
+        let m = self.z_plaquettes.len();
+        let boundary = self.num_detectors(rounds);
+        let mut paths = vec![(usize::MAX, false); boundary + 1];
+        let mut queue = VecDeque::from([source]);
+        paths[source] = (0, false);
+
+        while let Some(node) = queue.pop_front() {
+            let (distance, parity) = paths[node];
+            let (t, s) = (node / m, node % m);
+
+            let space = graph[s].iter().map(|edge| (edge.to.map_or(boundary, |b| t * m + b), edge.logical));
+            let time = [t.checked_sub(1), Some(t + 1).filter(|&next| next <= rounds)]
+                .into_iter()
+                .flatten()
+                .map(|next| (next * m + s, false));
+
+            for (next, logical) in space.chain(time) {
+                if paths[next].0 == usize::MAX {
+                    paths[next] = (distance + 1, parity ^ logical);
+                    // The boundary absorbs paths instead of connecting them
+                    if next != boundary {
+                        queue.push_back(next);
+                    }
+                }
+            }
+        }
+        paths
+    }
+
+    /// Returns the Pauli string acting with `pauli` on the given data qubits
+    fn pauli_string(&self, qubits: &[usize], pauli: char) -> String {
+        (0..self.num_data_qubits()).rev()
+            .map(|q| if qubits.contains(&q) { pauli } else { 'I' })
+            .collect()
+    }
+}
+
+/// Finds the minimum-weight matching by dynamic programming over subsets of defects
+/// 
+/// Returns the logical parity of the matching.
+fn exact_matching(count: usize, pair: impl Fn(usize, Option<usize>) -> (usize, bool)) -> bool {
+    let table: Vec<Vec<(usize, bool)>> = (0..count)
+        .map(|a| (0..count).map(|b| pair(a, Some(b))).chain(std::iter::once(pair(a, None))).collect())
+        .collect();
+
+    let full = (1usize << count) - 1;
+    let mut best = vec![(usize::MAX, false); full + 1];
+    best[0] = (0, false);
+
+    for mask in 1..=full {
+        // The lowest unmatched defect pairs with the boundary or another defect
+        let first = mask.trailing_zeros() as usize;
+        let rest = mask & !(1 << first);
+
+        let mut update = |(distance, logical): (usize, bool), remaining: usize| {
+            let (cost, parity) = best[remaining];
+            if cost + distance < best[mask].0 {
+                best[mask] = (cost + distance, parity ^ logical);
+            }
+        };
+        update(table[first][count], rest);
+        for other in (first + 1..count).filter(|&other| rest >> other & 1 == 1) {
+            update(table[first][other], rest & !(1 << other));
+        }
+    }
+    best[full].1
+}
+
+/// Repeatedly matches the closest pair of defects, or a defect with the boundary
+fn greedy_matching(count: usize, pair: impl Fn(usize, Option<usize>) -> (usize, bool)) -> bool {
+    let mut unmatched: Vec<usize> = (0..count).collect();
+    let mut parity = false;
+
+    while !unmatched.is_empty() {
+        let candidates = unmatched.iter().flat_map(|&a| {
+            std::iter::once(None)
+                .chain(unmatched.iter().filter(move |&&b| b > a).map(|&b| Some(b)))
+                .map(move |b| (a, b))
+        });
+        let (a, b) = candidates.min_by_key(|&(a, b)| pair(a, b).0)
+            .expect("Unmatched defects always have candidates");
+
+        parity ^= pair(a, b).1;
+        unmatched.retain(|&k| k != a && Some(k) != b);
+    }
+    parity
+}
@@ -20,9 +20,40 @@
 //! 
 //! | Feature | Description |
 //! |---------|-------------|
-//! | `core` | Core Quantum definitions like qubits, quantum gates, gate operations, etc |
-//! | `circuit` | Quantum Circuit functionality including visualisations |
-//! | `simulator` | Quantum Simulation functionality |
+//! | `core` | Core Quantum definitions like qubits, quantum gates, gate operations, etc, with no dependency beyond `rusticle` |
+//! | `circuit` | Quantum Circuit functionality including visualisations (enables `core`) |
+//! | `simulator` | Quantum Simulation functionality, pulling in `rand` (enables `circuit`, enabled by default) |
+//! | `serde` | Serialization of simulation results (JSON export) via serde |
+//! | `os-rng` | Seeds unseeded simulations from the operating system's entropy (enabled by default) |
+//! | `wasm` | `wasm-bindgen` bindings for circuit building, execution and rendering in the browser |
+//! | `python` | `pyo3` bindings for circuits, simulators and results, built as the `intrico` Python module |
+//! | `tracing` | `tracing` spans around simulations, gate batches, transpiler passes and shot sampling |
+//! | `plotters` | PNG and in-memory bitmap rendering of circuit diagrams, and histograms of simulation counts, via `plotters` |
+//! | `tui` | Interactive `ratatui` terminal explorer stepping through circuits layer by layer |
+//! 
+//! The following modules are not features of their own; they are compiled whenever the
+//! feature they build on is enabled:
+//! 
+//! | Module | Compiled with | Description |
+//! |--------|---------------|-------------|
+//! | `algorithms` | `simulator` | Builders for well-known quantum algorithms such as Grover's search |
+//! | `benchmarking` | `simulator` | Randomized benchmarking, quantum volume and other device characterization experiments |
+//! | `cutting` | `simulator` | Wire cutting of circuits into independently simulated fragments, knitted back into expectation values |
+//! | `device` | `simulator` | Device models with calibration data and connectivity |
+//! | `error_correction` | `simulator` | Repetition, stabilizer and surface codes with syndrome extraction and decoding |
+//! | `observables` | `simulator` | Hamiltonians and other observables built from Pauli strings |
+//! | `primitives` | `simulator` | Estimator and Sampler primitives for expectation values and output distributions of parameterized circuits |
+//! | `tomography` | `simulator` | State and process reconstruction from Pauli-basis measurements |
+//! | `transpiler` | `simulator` | Circuit decomposition and optimization before execution |
+//! | `variational` | `simulator` | Variational drivers such as VQE with classical optimizers |
+//! | `verify` | `circuit` | Assertions and macros checking unitarity, circuit equivalence and statevector closeness |
+//! 
+//! Builds that only need gate math can disable the default features:
+//! 
+//! ```toml
+//! [dependencies]
+//! intrico = { version = "1.0.0", default-features = false, features = ["core"] }
+//! ```
 //! 
 //! ## Quick Start
 //! 
@@ -47,14 +78,55 @@
 //! |---------|-------------|
 //! | [`quantum-circuit`](./examples/quantum_circuit.rs) | A bell state quantum circuit visualisation |
 //! | [`statevector-simulator`](./examples/statevector_simulator.rs) | A bell state simulation using statevector simulator |
-//! | [`grovers-algorithm`](./examples/grovers_algorithm.rs) | A two-qubit grover's algorithm simulation |
+//! | [`grovers-algorithm`](./examples/grovers_algorithm.rs) | An n-qubit Grover search built with the `algorithms` module |
 
 
+#[cfg(feature = "simulator")]
+pub mod algorithms;
+#[cfg(feature = "simulator")]
+pub mod benchmarking;
+#[cfg(feature = "core")]
 pub mod core;
+#[cfg(feature = "circuit")]
 pub mod circuit;
+#[cfg(feature = "simulator")]
+pub mod cutting;
+#[cfg(feature = "simulator")]
+pub mod device;
+#[cfg(feature = "core")]
+pub mod error;
+#[cfg(feature = "simulator")]
+pub mod error_correction;
+#[cfg(feature = "simulator")]
+pub mod observables;
+#[cfg(feature = "simulator")]
+pub mod primitives;
+#[cfg(feature = "simulator")]
 pub mod simulator;
+#[cfg(feature = "simulator")]
+pub mod tomography;
+#[cfg(feature = "circuit")]
+mod trace;
+#[cfg(feature = "simulator")]
+pub mod transpiler;
 pub mod utility;
+#[cfg(feature = "simulator")]
+pub mod variational;
+#[cfg(feature = "circuit")]
+pub mod verify;
+#[cfg(feature = "core")]
+pub mod visualization;
+#[cfg(feature = "python")]
+pub mod python;
+#[cfg(feature = "tui")]
+pub mod tui;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
 // Expose types from modules
+#[cfg(feature = "core")]
 pub use core::{Qubit, QuantumGate};
+#[cfg(feature = "circuit")]
 pub use circuit::QuantumCircuit;
+#[cfg(feature = "core")]
+pub use error::IntricoError;
@@ -0,0 +1,40 @@
+use std::fmt;
+
+/// Errors that can occur while parsing a [`PauliSum`](super::PauliSum) from a string
+#[derive(Debug, Clone, PartialEq)]
+pub enum PauliSumError {
+    /// The string does not contain any term
+    Empty,
+    /// A term is not of the form `coefficient*PAULI`, `coefficient PAULI` or `PAULI`
+    InvalidTerm {
+        /// The offending term
+        term: String,
+    },
+    /// The Pauli strings of two terms act on different numbers of qubits
+    QubitMismatch {
+        /// Number of qubits of the first term
+        expected: usize,
+        /// Number of qubits of the offending term
+        found: usize,
+    },
+}
+
+impl fmt::Display for PauliSumError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PauliSumError::Empty => write!(f, "Pauli sum does not contain any term"),
+            PauliSumError::InvalidTerm { term } => write!(
+                f,
+                "Invalid term '{}', expected a coefficient and a Pauli string such as 0.5*ZZ",
+                term
+            ),
+            PauliSumError::QubitMismatch { expected, found } => write!(
+                f,
+                "Pauli string acts on {} qubits but the first term acts on {}",
+                found, expected
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PauliSumError {}
@@ -0,0 +1,16 @@
+//! Observables module
+//! 
+//! This module provides Hermitian operators, such as Hamiltonians written as weighted
+//! sums of Pauli strings, whose expectation values can be evaluated on simulated states.
+//! Pauli sums can be parsed from strings such as `"0.5*ZZ + 0.3*XI"`, combined
+//! arithmetically and converted to dense matrices. Products, commutators and
+//! anticommutators are evaluated on Pauli strings in symplectic form, without dense
+//! matrices.
+
+mod error;
+mod pauli_string;
+mod pauli_sum;
+
+pub use error::PauliSumError;
+pub use pauli_string::PauliString;
+pub use pauli_sum::PauliSum;
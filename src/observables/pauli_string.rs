@@ -0,0 +1,184 @@
+use std::fmt;
+use std::ops::Mul;
+use std::str::FromStr;
+
+use super::PauliSumError;
+
+/// A Pauli string with a phase, stored in symplectic form
+/// 
+/// The operator is `i^phase · P₁ ⊗ … ⊗ P₀`, where every single-qubit factor is encoded
+/// by an X bit and a Z bit packed into 64-bit words. Products and commutation checks
+/// only touch these words, so their cost grows with `n / 64` rather than with `4^n`.
+/// Strings are written like bitstrings: the rightmost character acts on qubit 0, and
+/// an optional `-`, `i` or `-i` prefix gives the phase.
+/// 
+/// # Examples
+/// ```
+/// use intrico::observables::PauliString;
+/// 
+/// let x: PauliString = "XI".parse().unwrap();
+/// let y: PauliString = "YZ".parse().unwrap();
+/// 
+/// // X·Y = iZ on qubit 1
+/// assert_eq!((&x * &y).to_string(), "iZZ");
+/// assert!(!x.commutes_with(&y));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PauliString {
+    num_qubits: usize,
+    x: Vec<u64>,
+    z: Vec<u64>,
+    phase: u8,
+}
+
+impl PauliString {
+    /// Creates the identity on the given number of qubits
+    pub fn identity(num_qubits: usize) -> Self {
+        let words = num_qubits.div_ceil(64);
+        PauliString { num_qubits, x: vec![0; words], z: vec![0; words], phase: 0 }
+    }
+
+    /// Returns the number of qubits the string acts on
+    pub fn num_qubits(&self) -> usize {
+        self.num_qubits
+    }
+
+    /// Returns the exponent `k` of the phase `i^k`
+    pub fn phase(&self) -> u8 {
+        self.phase
+    }
+
+    /// Returns the Pauli operator on a qubit as `I`, `X`, `Y` or `Z`
+    /// 
+    /// # Panics
+    /// Panics if the qubit index is out of range
+    pub fn get(&self, qubit: usize) -> char {
+        if qubit >= self.num_qubits {
+            panic!("Qubit index {} out of range for {} qubits", qubit, self.num_qubits);
+        }
+        let (word, bit) = (qubit / 64, qubit % 64);
+        match (self.x[word] >> bit & 1, self.z[word] >> bit & 1) {
+            (0, 0) => 'I',
+            (1, 0) => 'X',
+            (1, 1) => 'Y',
+            _ => 'Z',
+        }
+    }
+
+    /// Sets the Pauli operator on a qubit, keeping the phase
+    /// 
+    /// # Panics
+    /// Panics if the qubit index is out of range or the operator is not `I`, `X`, `Y` or `Z`
+    pub fn set(&mut self, qubit: usize, pauli: char) {
+        if qubit >= self.num_qubits {
+            panic!("Qubit index {} out of range for {} qubits", qubit, self.num_qubits);
+        }
+        let (x, z) = match pauli {
+            'I' => (0, 0),
+            'X' => (1, 0),
+            'Y' => (1, 1),
+            'Z' => (0, 1),
+            _ => panic!("Invalid Pauli operator '{}'", pauli),
+        };
+        let (word, bit) = (qubit / 64, qubit % 64);
+        self.x[word] = self.x[word] & !(1 << bit) | x << bit;
+        self.z[word] = self.z[word] & !(1 << bit) | z << bit;
+    }
+
+    /// Returns the number of qubits with a non-identity factor
+    pub fn weight(&self) -> usize {
+        self.x.iter().zip(&self.z).map(|(x, z)| (x | z).count_ones() as usize).sum()
+    }
+
+    /// Returns the string without its phase, such as `XIZ`
+    pub fn label(&self) -> String {
+        (0..self.num_qubits).rev().map(|qubit| self.get(qubit)).collect()
+    }
+
+    /// Returns whether two strings commute, which holds iff they anticommute on an even
+    /// number of qubits
+    /// 
+    /// # Panics
+    /// Panics if the strings act on different numbers of qubits
+    pub fn commutes_with(&self, other: &PauliString) -> bool {
+        self.check_qubits(other);
+        let overlaps: u32 = (0..self.x.len())
+            .map(|w| (self.x[w] & other.z[w]).count_ones() + (self.z[w] & other.x[w]).count_ones())
+            .sum();
+        overlaps.is_multiple_of(2)
+    }
+
+    /// Returns the product `self · other`, including the phase it picks up
+    /// 
+    /// # Panics
+    /// Panics if the strings act on different numbers of qubits
+    pub fn multiply(&self, other: &PauliString) -> PauliString {
+        self.check_qubits(other);
+
+        // With P = i^(x·z) X^x Z^z and Z^z X^x' = (-1)^(z·x') X^x' Z^z, the product is
+        // i^(p + p' + x·z + x'·z' + 2 z·x' - x''·z'') times the Pauli of x'' = x ^ x', z'' = z ^ z'
+        let x: Vec<u64> = self.x.iter().zip(&other.x).map(|(a, b)| a ^ b).collect();
+        let z: Vec<u64> = self.z.iter().zip(&other.z).map(|(a, b)| a ^ b).collect();
+        let count = |a: &[u64], b: &[u64]| -> u32 {
+            a.iter().zip(b).map(|(a, b)| (a & b).count_ones()).sum()
+        };
+        let exponent = self.phase as u32 + other.phase as u32
+            + count(&self.x, &self.z) + count(&other.x, &other.z)
+            + 2 * count(&self.z, &other.x)
+            + 3 * count(&x, &z);
+
+        PauliString { num_qubits: self.num_qubits, x, z, phase: (exponent % 4) as u8 }
+    }
+
+    /// Panics unless both strings act on the same number of qubits
+    fn check_qubits(&self, other: &PauliString) {
+        if self.num_qubits != other.num_qubits {
+            panic!("Pauli strings act on {} and {} qubits", self.num_qubits, other.num_qubits);
+        }
+    }
+}
+
+impl Mul for &PauliString {
+    type Output = PauliString;
+
+    /// # Panics
+    /// Panics if the strings act on different numbers of qubits
+    fn mul(self, other: &PauliString) -> PauliString {
+        self.multiply(other)
+    }
+}
+
+impl FromStr for PauliString {
+    type Err = PauliSumError;
+
+    /// Parses a string such as `XIZ`, `-YY` or `-iZ`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || PauliSumError::InvalidTerm { term: s.to_string() };
+        let (phase, label) = if let Some(rest) = s.strip_prefix("-i") {
+            (3, rest)
+        } else if let Some(rest) = s.strip_prefix('-') {
+            (2, rest)
+        } else if let Some(rest) = s.strip_prefix('i') {
+            (1, rest)
+        } else {
+            (0, s)
+        };
+        if label.is_empty() || label.chars().any(|c| !matches!(c, 'I' | 'X' | 'Y' | 'Z')) {
+            return Err(invalid());
+        }
+
+        let mut pauli = PauliString::identity(label.chars().count());
+        for (qubit, c) in label.chars().rev().enumerate() {
+            pauli.set(qubit, c);
+        }
+        pauli.phase = phase;
+        Ok(pauli)
+    }
+}
+
+impl fmt::Display for PauliString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let prefix = ["", "i", "-", "-i"][self.phase as usize];
+        write!(f, "{}{}", prefix, self.label())
+    }
+}
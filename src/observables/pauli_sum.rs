@@ -0,0 +1,641 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::{Add, Mul, Neg, Sub};
+use std::str::FromStr;
+
+use rusticle::complex::Complex;
+use rusticle::linalg::Matrix;
+
+use crate::{QuantumCircuit, QuantumGate};
+use crate::circuit::Basis;
+use crate::simulator::{Observable, Simulator};
+use super::{PauliString, PauliSumError};
+
+/// A weighted sum of Pauli strings, such as a qubit Hamiltonian
+/// 
+/// Pauli strings are written like bitstrings: the rightmost character acts on qubit 0.
+/// 
+/// # Examples
+/// ```
+/// use intrico::observables::PauliSum;
+/// 
+/// // H = 0.5·Z₁Z₀ + 0.3·X₁
+/// let hamiltonian = PauliSum::new(2)
+///     .with_term(0.5, "ZZ")
+///     .with_term(0.3, "XI");
+/// assert_eq!(hamiltonian.terms().len(), 2);
+/// 
+/// // The same Hamiltonian, parsed from a string
+/// let parsed: PauliSum = "0.5*ZZ + 0.3*XI".parse().unwrap();
+/// assert_eq!(parsed, hamiltonian);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct PauliSum {
+    num_qubits: usize,
+    terms: Vec<(f64, String)>,
+}
+
+impl PauliSum {
+    /// Creates an empty sum over the given number of qubits
+    pub fn new(num_qubits: usize) -> Self {
+        PauliSum { num_qubits, terms: Vec::new() }
+    }
+
+    /// Adds a weighted Pauli string to the sum
+    /// 
+    /// # Arguments
+    /// * `coefficient` - The real weight of the term
+    /// * `pauli` - The Pauli string, made of `I`, `X`, `Y` and `Z` characters
+    /// 
+    /// # Panics
+    /// Panics if the string length differs from the number of qubits or if it contains
+    /// other characters
+    pub fn with_term(mut self, coefficient: f64, pauli: &str) -> Self {
+        if pauli.chars().count() != self.num_qubits {
+            panic!("Pauli string '{}' does not act on {} qubits", pauli, self.num_qubits);
+        }
+        if let Some(c) = pauli.chars().find(|c| !matches!(c, 'I' | 'X' | 'Y' | 'Z')) {
+            panic!("Invalid Pauli operator '{}' in '{}'", c, pauli);
+        }
+
+        self.terms.push((coefficient, pauli.to_string()));
+        self
+    }
+
+    /// Returns the number of qubits the sum acts on
+    pub fn num_qubits(&self) -> usize {
+        self.num_qubits
+    }
+
+    /// Returns the qubits on which at least one term acts nontrivially, in ascending order
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::observables::PauliSum;
+    /// 
+    /// let sum: PauliSum = "ZIIZ + 0.5*IIXI".parse().unwrap();
+    /// assert_eq!(sum.support(), vec![0, 1, 3]);
+    /// ```
+    pub fn support(&self) -> Vec<usize> {
+        (0..self.num_qubits)
+            .filter(|&qubit| self.terms.iter().any(|(_, pauli)| pauli.as_bytes()[self.num_qubits - 1 - qubit] != b'I'))
+            .collect()
+    }
+
+    /// Returns the sum acting on the given qubits only, which must cover its support
+    /// 
+    /// Qubit `i` of the result stands for `qubits[i]`.
+    fn restrict(&self, qubits: &[usize]) -> PauliSum {
+        let terms = self.terms.iter()
+            .map(|(coefficient, pauli)| {
+                let chars: Vec<char> = pauli.chars().rev().collect();
+                (*coefficient, qubits.iter().rev().map(|&qubit| chars[qubit]).collect())
+            })
+            .collect();
+        PauliSum { num_qubits: qubits.len(), terms }
+    }
+
+    /// Reduces a circuit to the light cone of the support of the sum, see
+    /// [`QuantumCircuit::light_cone`], and restricts the sum to the qubits of the cone
+    /// 
+    /// The circuit is kept whole when the simulator has a noise model or an initial
+    /// state, as both refer to the qubits of the full circuit, and when the sum is a
+    /// multiple of the identity.
+    pub(crate) fn reduce(&self, circuit: &QuantumCircuit, simulator: &Simulator) -> (QuantumCircuit, PauliSum) {
+        let support = self.support();
+        if support.is_empty() || simulator.config.noise.is_some() || simulator.config.initial_state.is_some() {
+            return (circuit.clone(), self.clone());
+        }
+        let (cone, qubits) = circuit.light_cone(&support);
+        let restricted = self.restrict(&qubits);
+        (cone, restricted)
+    }
+
+    /// Returns the weighted Pauli strings of the sum
+    pub fn terms(&self) -> &[(f64, String)] {
+        &self.terms
+    }
+
+    /// Returns the sum with equal Pauli strings merged and vanishing terms removed
+    /// 
+    /// Terms keep the order in which their Pauli string first appears.
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::observables::PauliSum;
+    /// 
+    /// let sum: PauliSum = "ZZ + 0.5*XI - ZZ + 0.5*XI".parse().unwrap();
+    /// assert_eq!(sum.simplify(), PauliSum::new(2).with_term(1.0, "XI"));
+    /// ```
+    pub fn simplify(&self) -> PauliSum {
+        Self::merged(self.num_qubits, self.terms.iter().cloned())
+    }
+
+    /// Returns the Hermitian operators `H` and `K` such that `self · other = H + i·K`
+    /// 
+    /// Products are evaluated term by term on [`PauliString`]s, so the cost grows with
+    /// the number of terms and qubits rather than with the matrix dimension.
+    /// 
+    /// # Panics
+    /// Panics if the sums act on different numbers of qubits
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::observables::PauliSum;
+    /// 
+    /// let x: PauliSum = "X".parse().unwrap();
+    /// let y: PauliSum = "Y".parse().unwrap();
+    /// 
+    /// // X·Y = iZ
+    /// let (real, imaginary) = x.multiply(&y);
+    /// assert!(real.terms().is_empty());
+    /// assert_eq!(imaginary, "Z".parse().unwrap());
+    /// ```
+    pub fn multiply(&self, other: &PauliSum) -> (PauliSum, PauliSum) {
+        if self.num_qubits != other.num_qubits {
+            panic!("Cannot multiply Pauli sums on {} and {} qubits", self.num_qubits, other.num_qubits);
+        }
+
+        let left: Vec<PauliString> = self.terms.iter().map(|(_, pauli)| Self::string(pauli)).collect();
+        let right: Vec<PauliString> = other.terms.iter().map(|(_, pauli)| Self::string(pauli)).collect();
+        let (mut real, mut imaginary) = (Vec::new(), Vec::new());
+        for ((a, _), p) in self.terms.iter().zip(&left) {
+            for ((b, _), q) in other.terms.iter().zip(&right) {
+                let product = p * q;
+                let weight = if product.phase() < 2 { a * b } else { -a * b };
+                match product.phase() % 2 {
+                    0 => real.push((weight, product.label())),
+                    _ => imaginary.push((weight, product.label())),
+                }
+            }
+        }
+        (Self::merged(self.num_qubits, real), Self::merged(self.num_qubits, imaginary))
+    }
+
+    /// Returns the Hermitian operator `C` such that `[self, other] = i·C`
+    /// 
+    /// Only anticommuting pairs of terms contribute.
+    /// 
+    /// # Panics
+    /// Panics if the sums act on different numbers of qubits
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::observables::PauliSum;
+    /// 
+    /// let x: PauliSum = "X".parse().unwrap();
+    /// let y: PauliSum = "Y".parse().unwrap();
+    /// 
+    /// // [X, Y] = 2iZ
+    /// assert_eq!(x.commutator(&y), "2*Z".parse().unwrap());
+    /// ```
+    pub fn commutator(&self, other: &PauliSum) -> PauliSum {
+        // [A, B] = AB - (AB)† = 2i·K for AB = H + i·K
+        self.multiply(other).1 * 2.0
+    }
+
+    /// Returns the anticommutator `{self, other} = self · other + other · self`
+    /// 
+    /// Only commuting pairs of terms contribute.
+    /// 
+    /// # Panics
+    /// Panics if the sums act on different numbers of qubits
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::observables::PauliSum;
+    /// 
+    /// let zz: PauliSum = "ZZ + XI".parse().unwrap();
+    /// assert_eq!(zz.anticommutator(&zz), "4*II".parse().unwrap());
+    /// ```
+    pub fn anticommutator(&self, other: &PauliSum) -> PauliSum {
+        // {A, B} = AB + (AB)† = 2·H for AB = H + i·K
+        self.multiply(other).0 * 2.0
+    }
+
+    /// Returns whether every term of the sum commutes with every term of another
+    /// 
+    /// # Panics
+    /// Panics if the sums act on different numbers of qubits
+    pub fn commutes_with(&self, other: &PauliSum) -> bool {
+        self.commutator(other).terms.is_empty()
+    }
+
+    /// Returns the custom gate `e^(-iHt)` of the sum
+    /// 
+    /// The matrix follows [`PauliSum::to_matrix`], whose index has qubit 0 as its least
+    /// significant bit, while the first qubit a custom gate is applied to is its most
+    /// significant bit: apply the gate to the qubits in descending order.
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// use intrico::observables::PauliSum;
+    /// 
+    /// // e^(-iπ/2·XI) = -i·XI flips qubit 1
+    /// let hamiltonian: PauliSum = "XI".parse().unwrap();
+    /// let gate = hamiltonian.evolution_gate(std::f64::consts::FRAC_PI_2);
+    /// 
+    /// let mut qc = QuantumCircuit::new(2);
+    /// qc.add_multi_qubit_gate(gate, &[1, 0]);
+    /// assert!((qc.execute()[2].norm() - 1.0).abs() < 1e-6);
+    /// ```
+    pub fn evolution_gate(&self, time: f64) -> QuantumGate {
+        QuantumGate::from_hamiltonian(&self.to_matrix(), time)
+    }
+
+    /// Partitions the terms into groups of qubit-wise commuting Pauli strings
+    /// 
+    /// Strings commute qubit-wise when they agree on every qubit where neither is the
+    /// identity. All terms of a group are then diagonal in one product basis, given by
+    /// [`PauliSum::measurement_basis`], and can be estimated from the same measurement
+    /// circuit. Terms are placed greedily, highest weight first, into the first group
+    /// they fit, and keep their relative order within a group.
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::observables::PauliSum;
+    /// 
+    /// let hamiltonian: PauliSum = "ZZ + ZI + IZ + XX + XI".parse().unwrap();
+    /// let groups = hamiltonian.group_commuting();
+    /// 
+    /// assert_eq!(groups.len(), 2);
+    /// assert_eq!(groups[0], "ZZ + ZI + IZ".parse().unwrap());
+    /// assert_eq!(groups[1].measurement_basis(), Some("XX".to_string()));
+    /// ```
+    pub fn group_commuting(&self) -> Vec<PauliSum> {
+        let mut order: Vec<usize> = (0..self.terms.len()).collect();
+        order.sort_by_key(|&index| std::cmp::Reverse(self.terms[index].1.chars().filter(|&c| c != 'I').count()));
+
+        let mut groups: Vec<(Vec<char>, Vec<usize>)> = Vec::new();
+        for index in order {
+            let pauli: Vec<char> = self.terms[index].1.chars().collect();
+            let fits = |basis: &[char]| basis.iter().zip(&pauli).all(|(&b, &p)| b == 'I' || p == 'I' || b == p);
+            match groups.iter_mut().find(|(basis, _)| fits(basis)) {
+                Some((basis, members)) => {
+                    for (b, &p) in basis.iter_mut().zip(&pauli) {
+                        if p != 'I' {
+                            *b = p;
+                        }
+                    }
+                    members.push(index);
+                },
+                None => groups.push((pauli, vec![index])),
+            }
+        }
+
+        groups.into_iter()
+            .map(|(_, mut members)| {
+                members.sort_unstable();
+                let terms = members.into_iter().map(|index| self.terms[index].clone()).collect();
+                PauliSum { num_qubits: self.num_qubits, terms }
+            })
+            .collect()
+    }
+
+    /// Returns the product basis in which every term is diagonal, if the terms commute
+    /// qubit-wise
+    /// 
+    /// Qubits on which every term acts as the identity are marked `I`.
+    pub fn measurement_basis(&self) -> Option<String> {
+        let mut basis = vec!['I'; self.num_qubits];
+        for (_, pauli) in &self.terms {
+            for (b, p) in basis.iter_mut().zip(pauli.chars()) {
+                match (*b, p) {
+                    (_, 'I') => {},
+                    ('I', p) => *b = p,
+                    (b, p) if b != p => return None,
+                    _ => {},
+                }
+            }
+        }
+        Some(basis.into_iter().collect())
+    }
+
+    /// Estimates the expectation value from measurement samples of a circuit
+    /// 
+    /// One measurement circuit is run per group of [`PauliSum::group_commuting`]: the
+    /// qubits are rotated into the group's basis, and every term of the group is
+    /// estimated from the parities of the same samples. Gates outside the light cone of
+    /// the terms are pruned first, see [`QuantumCircuit::light_cone`].
+    /// 
+    /// # Arguments
+    /// * `circuit` - The circuit preparing the state
+    /// * `simulator` - The simulator, and with it the noise model, sampling the circuits
+    /// * `shots` - The number of samples per measurement circuit
+    /// 
+    /// # Panics
+    /// Panics if the circuit does not act on the qubits of the sum or no shot is requested
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// use intrico::observables::PauliSum;
+    /// use intrico::simulator::Simulator;
+    /// 
+    /// let mut qc = QuantumCircuit::new(2);
+    /// qc.h(0);
+    /// qc.cnot(0, 1);
+    /// 
+    /// let hamiltonian: PauliSum = "ZZ + XX - 0.5*IZ".parse().unwrap();
+    /// let energy = hamiltonian.estimate(&qc, &Simulator::new().with_seed(1), 2000);
+    /// assert!((energy - 2.0).abs() < 0.1);
+    /// ```
+    pub fn estimate(&self, circuit: &QuantumCircuit, simulator: &Simulator, shots: usize) -> f64 {
+        if circuit.num_qubits() != self.num_qubits {
+            panic!("Circuit acts on {} qubits but the Pauli sum on {}", circuit.num_qubits(), self.num_qubits);
+        }
+        if shots == 0 {
+            panic!("Estimating an expectation value requires at least one shot");
+        }
+
+        let (circuit, observable) = self.reduce(circuit, simulator);
+        let (groups, circuits) = observable.measurement_circuits(&circuit);
+        simulator.run_batch(&circuits, shots)
+            .iter()
+            .zip(&groups)
+            .map(|(result, group)| {
+                group.terms.iter()
+                    .map(|(coefficient, pauli)| coefficient * result.pauli_expectation(pauli))
+                    .sum::<f64>()
+            })
+            .sum()
+    }
+
+    /// Returns the qubit-wise commuting groups of terms, each with a copy of the circuit
+    /// rotated into the measurement basis of the group
+    pub(crate) fn measurement_circuits(&self, circuit: &QuantumCircuit) -> (Vec<PauliSum>, Vec<QuantumCircuit>) {
+        let groups = self.group_commuting();
+        let circuits = groups.iter()
+            .map(|group| {
+                let mut rotated = circuit.clone();
+                let basis = group.measurement_basis().expect("groups commute qubit-wise");
+                for (qubit, pauli) in basis.chars().rev().enumerate() {
+                    if let Some(basis) = Basis::from_pauli(pauli) {
+                        rotated.rotate_to_basis(qubit, basis);
+                    }
+                }
+                rotated
+            })
+            .collect();
+        (groups, circuits)
+    }
+
+    /// Converts a validated Pauli string label to its symplectic form
+    fn string(pauli: &str) -> PauliString {
+        pauli.parse().expect("terms hold valid Pauli strings")
+    }
+
+    /// Merges equal Pauli strings, keeping first-appearance order, and drops vanishing terms
+    fn merged(num_qubits: usize, terms: impl IntoIterator<Item = (f64, String)>) -> PauliSum {
+        let mut merged: Vec<(f64, String)> = Vec::new();
+        let mut index: HashMap<String, usize> = HashMap::new();
+        for (coefficient, pauli) in terms {
+            match index.get(&pauli) {
+                Some(&position) => merged[position].0 += coefficient,
+                None => {
+                    index.insert(pauli.clone(), merged.len());
+                    merged.push((coefficient, pauli));
+                },
+            }
+        }
+        merged.retain(|(coefficient, _)| coefficient.abs() > 1e-12);
+        PauliSum { num_qubits, terms: merged }
+    }
+
+    /// Returns the dense `2^n × 2^n` matrix of the sum
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::observables::PauliSum;
+    /// 
+    /// let matrix = PauliSum::new(1).with_term(2.0, "X").to_matrix();
+    /// assert_eq!(matrix.get(0, 1).real, 2.0);
+    /// assert_eq!(matrix.get(0, 0).real, 0.0);
+    /// ```
+    pub fn to_matrix(&self) -> Matrix<Complex> {
+        let dim = 1 << self.num_qubits;
+        let mut matrix = Matrix::zeros(dim, dim);
+
+        for (coefficient, pauli) in &self.terms {
+            let (flip, phase, num_y) = Self::masks(pauli);
+            let global = match num_y % 4 {
+                0 => Complex::new(*coefficient, 0.0),
+                1 => Complex::new(0.0, *coefficient),
+                2 => Complex::new(-coefficient, 0.0),
+                _ => Complex::new(0.0, -coefficient),
+            };
+            for k in 0..dim {
+                let sign = if (k & phase).count_ones() % 2 == 0 { 1.0 } else { -1.0 };
+                let entry = *matrix.get(k ^ flip, k) + global * sign;
+                matrix.set(k ^ flip, k, entry);
+            }
+        }
+        matrix
+    }
+
+    /// Returns the bit flip mask, the phase mask and the number of Y operators of a Pauli string
+    fn masks(pauli: &str) -> (usize, usize, usize) {
+        let (mut flip, mut phase, mut num_y) = (0, 0, 0);
+        for (qubit, c) in pauli.chars().rev().enumerate() {
+            match c {
+                'X' => flip |= 1 << qubit,
+                'Y' => {
+                    flip |= 1 << qubit;
+                    phase |= 1 << qubit;
+                    num_y += 1;
+                },
+                'Z' => phase |= 1 << qubit,
+                _ => {},
+            }
+        }
+        (flip, phase, num_y)
+    }
+
+    /// Returns ⟨ψ|P|ψ⟩ for a single Pauli string without building its matrix
+    fn string_expectation(pauli: &str, state: &[Complex]) -> f64 {
+        let (flip, phase, num_y) = Self::masks(pauli);
+
+        // P|k⟩ = i^num_y · (-1)^popcount(k & phase) |k ^ flip⟩
+        let sum = state.iter()
+            .enumerate()
+            .fold(Complex::new(0.0, 0.0), |acc, (k, &amp)| {
+                let sign = if (k & phase).count_ones() % 2 == 0 { 1.0 } else { -1.0 };
+                let term = state[k ^ flip].conjugate() * amp;
+                acc + Complex::new(term.real * sign, term.imag * sign)
+            });
+
+        match num_y % 4 {
+            0 => sum.real,
+            1 => -sum.imag,
+            2 => -sum.real,
+            _ => sum.imag,
+        }
+    }
+
+    /// Parses a single term such as `0.5*ZZ`, `2 XI` or `YY`
+    fn parse_term(term: &str, negative: bool) -> Result<(f64, String), PauliSumError> {
+        let invalid = || PauliSumError::InvalidTerm { term: term.to_string() };
+        let (coefficient, pauli) = match term.split_once('*') {
+            Some((coefficient, pauli)) => (coefficient.trim(), pauli.trim()),
+            None => {
+                let start = term.rfind(|c: char| !matches!(c, 'I' | 'X' | 'Y' | 'Z')).map_or(0, |i| i + 1);
+                (term[..start].trim(), &term[start..])
+            },
+        };
+
+        if pauli.is_empty() || pauli.chars().any(|c| !matches!(c, 'I' | 'X' | 'Y' | 'Z')) {
+            return Err(invalid());
+        }
+        let value = match coefficient {
+            "" => 1.0,
+            _ => coefficient.parse::<f64>().map_err(|_| invalid())?,
+        };
+        Ok((if negative { -value } else { value }, pauli.to_string()))
+    }
+}
+
+impl FromStr for PauliSum {
+    type Err = PauliSumError;
+
+    /// Parses a sum of terms such as `"0.5*ZZ + 0.3*XI - YY"`
+    /// 
+    /// Each term is a Pauli string with an optional real coefficient, separated from it
+    /// by `*` or whitespace. Terms are separated by `+` or `-`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Split at signs that do not belong to the exponent of a coefficient
+        let mut terms: Vec<(bool, String)> = Vec::new();
+        let (mut negative, mut current) = (false, String::new());
+        for c in s.chars() {
+            let exponent = current.trim_end().ends_with(['e', 'E']);
+            if matches!(c, '+' | '-') && !exponent {
+                if !current.trim().is_empty() {
+                    terms.push((negative, current.trim().to_string()));
+                    negative = false;
+                }
+                negative ^= c == '-';
+                current.clear();
+            } else {
+                current.push(c);
+            }
+        }
+        if current.trim().is_empty() {
+            if terms.is_empty() && !negative {
+                return Err(PauliSumError::Empty);
+            }
+            return Err(PauliSumError::InvalidTerm { term: s.trim().to_string() });
+        }
+        terms.push((negative, current.trim().to_string()));
+
+        let mut sum: Option<PauliSum> = None;
+        for (negative, term) in terms {
+            let (coefficient, pauli) = Self::parse_term(&term, negative)?;
+            let num_qubits = pauli.chars().count();
+            let sum = sum.get_or_insert_with(|| PauliSum::new(num_qubits));
+            if num_qubits != sum.num_qubits {
+                return Err(PauliSumError::QubitMismatch { expected: sum.num_qubits, found: num_qubits });
+            }
+            sum.terms.push((coefficient, pauli));
+        }
+        sum.ok_or(PauliSumError::Empty)
+    }
+}
+
+impl fmt::Display for PauliSum {
+    /// Writes the sum in the format accepted by [`PauliSum::from_str`]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.terms.is_empty() {
+            return write!(f, "0");
+        }
+        for (index, (coefficient, pauli)) in self.terms.iter().enumerate() {
+            match (index, *coefficient < 0.0) {
+                (0, false) => write!(f, "{}*{}", coefficient, pauli)?,
+                (0, true) => write!(f, "-{}*{}", -coefficient, pauli)?,
+                (_, false) => write!(f, " + {}*{}", coefficient, pauli)?,
+                (_, true) => write!(f, " - {}*{}", -coefficient, pauli)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Add for PauliSum {
+    type Output = PauliSum;
+
+    /// Concatenates the terms of two sums; use [`PauliSum::simplify`] to merge them
+    /// 
+    /// # Panics
+    /// Panics if the sums act on different numbers of qubits
+    fn add(mut self, other: PauliSum) -> PauliSum {
+        if self.num_qubits != other.num_qubits {
+            panic!("Cannot add Pauli sums on {} and {} qubits", self.num_qubits, other.num_qubits);
+        }
+        self.terms.extend(other.terms);
+        self
+    }
+}
+
+impl Sub for PauliSum {
+    type Output = PauliSum;
+
+    /// # Panics
+    /// Panics if the sums act on different numbers of qubits
+    fn sub(self, other: PauliSum) -> PauliSum {
+        self + -other
+    }
+}
+
+impl Neg for PauliSum {
+    type Output = PauliSum;
+
+    fn neg(self) -> PauliSum {
+        self * -1.0
+    }
+}
+
+impl Mul<f64> for PauliSum {
+    type Output = PauliSum;
+
+    fn mul(mut self, scalar: f64) -> PauliSum {
+        for (coefficient, _) in &mut self.terms {
+            *coefficient *= scalar;
+        }
+        self
+    }
+}
+
+impl Mul<PauliSum> for f64 {
+    type Output = PauliSum;
+
+    fn mul(self, sum: PauliSum) -> PauliSum {
+        sum * self
+    }
+}
+
+impl Observable for PauliSum {
+    /// # Panics
+    /// Panics if the statevector does not describe the qubits of the sum
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// use intrico::observables::PauliSum;
+    /// use intrico::simulator::Observable;
+    /// 
+    /// let mut qc = QuantumCircuit::new(2);
+    /// qc.h(0);
+    /// qc.cnot(0, 1);
+    /// 
+    /// let hamiltonian = PauliSum::new(2).with_term(1.0, "ZZ").with_term(1.0, "XX");
+    /// assert!((hamiltonian.expectation(&qc.execute()) - 2.0).abs() < 1e-6);
+    /// ```
+    fn expectation(&self, state: &[Complex]) -> f64 {
+        if state.len() != 1 << self.num_qubits {
+            panic!("Statevector of length {} does not describe {} qubits", state.len(), self.num_qubits);
+        }
+
+        self.terms.iter()
+            .map(|(coefficient, pauli)| coefficient * Self::string_expectation(pauli, state))
+            .sum()
+    }
+}
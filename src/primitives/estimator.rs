@@ -0,0 +1,209 @@
+use std::collections::HashMap;
+
+use crate::circuit::ParameterizedCircuit;
+use crate::observables::PauliSum;
+use crate::simulator::{Observable, Simulator};
+
+/// Estimates expectation values of observables on parameterized circuits
+/// 
+/// Every estimate binds the parameters of the circuit, prepares the state on the
+/// simulator and evaluates a Pauli sum on it. Without shots the expectation value of the
+/// noiseless final statevector is returned exactly. With shots, one measurement circuit is run per
+/// qubit-wise commuting group of terms and the standard error of the estimate is derived
+/// from the spread of the samples. Gates outside the light cone of the observable are
+/// pruned before simulation, see [`QuantumCircuit::light_cone`](crate::QuantumCircuit::light_cone).
+/// 
+/// # Examples
+/// ```
+/// use intrico::circuit::ParameterizedCircuit;
+/// use intrico::observables::PauliSum;
+/// use intrico::primitives::Estimator;
+/// use intrico::simulator::Simulator;
+/// 
+/// let mut ansatz = ParameterizedCircuit::new(1);
+/// ansatz.ry(0, 0);
+/// let observable = PauliSum::new(1).with_term(1.0, "Z");
+/// 
+/// // ⟨Z⟩ = cos(θ) after Ry(θ)
+/// let exact = Estimator::new().run(&ansatz, &observable, &[0.5]);
+/// assert!((exact.value - 0.5_f64.cos()).abs() < 1e-6);
+/// assert_eq!(exact.std_error, 0.0);
+/// 
+/// let sampled = Estimator::new()
+///     .with_simulator(Simulator::new().with_seed(4))
+///     .with_shots(4000)
+///     .run(&ansatz, &observable, &[0.5]);
+/// assert!((sampled.value - exact.value).abs() < 4.0 * sampled.std_error);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Estimator {
+    simulator: Simulator,
+    shots: Option<usize>,
+}
+
+/// Outcome of an [`Estimator`] run
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EstimatorResult {
+    /// The estimated expectation value
+    pub value: f64,
+    /// The standard error of the estimate, 0.0 for exact expectation values
+    pub std_error: f64,
+}
+
+impl Estimator {
+    /// Creates an estimator returning exact expectation values
+    pub fn new() -> Self {
+        Estimator { simulator: Simulator::new(), shots: None }
+    }
+
+    /// Sets the simulator, and with it the noise model and seed, preparing the states
+    /// 
+    /// The noise model only applies once shots are set with [`Estimator::with_shots`].
+    /// Exact expectation values are computed from the noiseless final statevector, since
+    /// the simulator has no density-matrix backend, so a noisy simulator without shots
+    /// gives the same values as a noiseless one.
+    pub fn with_simulator(mut self, simulator: Simulator) -> Self {
+        self.simulator = simulator;
+        self
+    }
+
+    /// Estimates expectation values from the given number of shots per measurement circuit
+    /// 
+    /// Terms are measured together in their qubit-wise commuting groups, see
+    /// [`PauliSum::estimate`].
+    /// 
+    /// # Panics
+    /// Panics if no shot is requested
+    pub fn with_shots(mut self, shots: usize) -> Self {
+        if shots == 0 {
+            panic!("Estimating an expectation value requires at least one shot");
+        }
+        self.shots = Some(shots);
+        self
+    }
+
+    /// Estimates the expectation value of an observable for one set of parameters
+    /// 
+    /// # Arguments
+    /// * `circuit` - The parameterized circuit preparing the state
+    /// * `observable` - The observable to estimate
+    /// * `params` - The parameter values to bind
+    /// 
+    /// # Panics
+    /// Panics if the number of values does not match the number of parameters, or if
+    /// the circuit and the observable act on different numbers of qubits
+    pub fn run(&self, circuit: &ParameterizedCircuit, observable: &PauliSum, params: &[f64]) -> EstimatorResult {
+        self.run_batch(&[(circuit, observable, params)]).remove(0)
+    }
+
+    /// Estimates several expectation values, executing all circuits as one batch
+    /// 
+    /// The results are returned in the order of the inputs.
+    /// 
+    /// # Arguments
+    /// * `inputs` - Triples of circuit, observable and parameter values
+    /// 
+    /// # Panics
+    /// Panics if the number of values does not match the number of parameters of its
+    /// circuit, or if a circuit and its observable act on different numbers of qubits
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::circuit::ParameterizedCircuit;
+    /// use intrico::observables::PauliSum;
+    /// use intrico::primitives::Estimator;
+    /// 
+    /// let mut ansatz = ParameterizedCircuit::new(1);
+    /// ansatz.rx(0, 0);
+    /// let z = PauliSum::new(1).with_term(1.0, "Z");
+    /// let y = PauliSum::new(1).with_term(1.0, "Y");
+    /// 
+    /// let results = Estimator::new().run_batch(&[
+    ///     (&ansatz, &z, &[std::f64::consts::PI]),
+    ///     (&ansatz, &y, &[std::f64::consts::FRAC_PI_2]),
+    /// ]);
+    /// assert!((results[0].value + 1.0).abs() < 1e-6);
+    /// assert!((results[1].value + 1.0).abs() < 1e-6);
+    /// ```
+    pub fn run_batch(&self, inputs: &[(&ParameterizedCircuit, &PauliSum, &[f64])]) -> Vec<EstimatorResult> {
+        for (circuit, observable, _) in inputs {
+            if circuit.num_qubits() != observable.num_qubits() {
+                panic!("Circuit acts on {} qubits but the observable on {}", circuit.num_qubits(), observable.num_qubits());
+            }
+        }
+        let (circuits, observables): (Vec<_>, Vec<_>) = inputs.iter()
+            .map(|(circuit, observable, params)| observable.reduce(&circuit.bind(params), &self.simulator))
+            .unzip();
+
+        let Some(shots) = self.shots else {
+            return self.simulator.run_batch(&circuits, 0)
+                .iter()
+                .zip(&observables)
+                .map(|(result, observable)| EstimatorResult {
+                    value: observable.expectation(&result.final_state),
+                    std_error: 0.0,
+                })
+                .collect();
+        };
+
+        // Run the measurement circuits of every input in a single batch
+        let mut groups = Vec::new();
+        let mut measured = Vec::new();
+        let mut sizes = Vec::with_capacity(inputs.len());
+        for (observable, circuit) in observables.iter().zip(&circuits) {
+            let (input_groups, input_circuits) = observable.measurement_circuits(circuit);
+            sizes.push(input_groups.len());
+            groups.extend(input_groups);
+            measured.extend(input_circuits);
+        }
+        let mut results = self.simulator.run_batch(&measured, shots).into_iter().zip(groups);
+
+        sizes.into_iter()
+            .map(|size| {
+                // Groups are sampled independently, so their means and variances add up
+                let (value, variance) = results.by_ref()
+                    .take(size)
+                    .map(|(result, group)| group_statistics(&group, &result.counts, shots))
+                    .fold((0.0, 0.0), |(value, variance), (mean, var)| (value + mean, variance + var));
+                EstimatorResult { value, std_error: (variance / shots as f64).sqrt() }
+            })
+            .collect()
+    }
+}
+
+impl Default for Estimator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns the sample mean and variance of a group of terms measured in their common basis
+/// 
+/// Every shot contributes the sum of the coefficients, each signed by the parity of the
+/// measured bits on the support of its Pauli string.
+fn group_statistics(group: &PauliSum, counts: &HashMap<String, usize>, shots: usize) -> (f64, f64) {
+    let samples: Vec<(f64, usize)> = counts.iter()
+        .map(|(bits, &count)| {
+            let value = group.terms().iter()
+                .map(|(coefficient, pauli)| {
+                    let ones = pauli.chars()
+                        .zip(bits.chars())
+                        .filter(|&(p, bit)| p != 'I' && bit == '1')
+                        .count();
+                    if ones % 2 == 0 { *coefficient } else { -coefficient }
+                })
+                .sum::<f64>();
+            (value, count)
+        })
+        .collect();
+
+    let mean = samples.iter().map(|&(value, count)| value * count as f64).sum::<f64>() / shots as f64;
+    if shots < 2 {
+        return (mean, 0.0);
+    }
+    let variance = samples.iter()
+        .map(|&(value, count)| (value - mean).powi(2) * count as f64)
+        .sum::<f64>() / (shots - 1) as f64;
+    (mean, variance)
+}
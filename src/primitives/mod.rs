@@ -0,0 +1,13 @@
+//! Primitives module
+//! 
+//! This module provides stable entry points for the two basic workloads of near-term
+//! algorithms: estimating expectation values of observables and sampling measurement
+//! outcomes. Higher-level libraries can target these primitives instead of driving the
+//! simulator directly, and the primitives take care of binding parameters, grouping
+//! measurements and batching the circuits.
+
+mod estimator;
+mod sampler;
+
+pub use estimator::{Estimator, EstimatorResult};
+pub use sampler::{Sampler, SamplerResult};
@@ -0,0 +1,174 @@
+use std::collections::HashMap;
+
+use crate::QuantumCircuit;
+use crate::circuit::ParameterizedCircuit;
+use crate::simulator::Simulator;
+use crate::utility::{index_to_bitstring, Endianness};
+
+/// Samples the output distributions of parameterized circuits
+/// 
+/// Every run binds the parameters of the circuit and returns the distribution of the
+/// measured bitstrings as quasi-probabilities: relative frequencies once a number of
+/// shots is set, the exact probabilities of the noiseless final statevector otherwise. Bitstrings
+/// are written like simulation counts, with qubit 0 as the rightmost character.
+/// 
+/// # Examples
+/// ```
+/// use intrico::circuit::ParameterizedCircuit;
+/// use intrico::primitives::Sampler;
+/// 
+/// let mut ansatz = ParameterizedCircuit::new(2);
+/// ansatz.ry(0, 0);
+/// ansatz.circuit_mut().cnot(0, 1);
+/// 
+/// let exact = Sampler::new().run(&ansatz, &[std::f64::consts::FRAC_PI_2]);
+/// assert!((exact.quasi_dist["11"] - 0.5).abs() < 1e-6);
+/// 
+/// let sampled = Sampler::new().with_shots(1000).with_seed(3).run(&ansatz, &[std::f64::consts::FRAC_PI_2]);
+/// assert_eq!(sampled.quasi_dist.len(), 2);
+/// assert!((sampled.probability("00") + sampled.probability("11") - 1.0).abs() < 1e-9);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Sampler {
+    simulator: Simulator,
+    shots: Option<usize>,
+    seed: Option<u64>,
+}
+
+/// Outcome of a [`Sampler`] run
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SamplerResult {
+    /// Quasi-probability of every observed bitstring
+    pub quasi_dist: HashMap<String, f64>,
+    /// The number of shots the distribution was estimated from, 0 for exact probabilities
+    pub shots: usize,
+}
+
+impl SamplerResult {
+    /// Returns the quasi-probability of a bitstring, 0.0 if it was never observed
+    pub fn probability(&self, bitstring: &str) -> f64 {
+        self.quasi_dist.get(bitstring).copied().unwrap_or(0.0)
+    }
+}
+
+impl Sampler {
+    /// Creates a sampler returning exact probabilities
+    pub fn new() -> Self {
+        Sampler { simulator: Simulator::new(), shots: None, seed: None }
+    }
+
+    /// Sets the simulator, and with it the noise model, running the circuits
+    /// 
+    /// The noise model only applies once shots are set with [`Sampler::with_shots`].
+    /// Exact distributions are the probabilities of the noiseless final statevector,
+    /// since the simulator has no density-matrix backend, so a noisy simulator without
+    /// shots gives the same distributions as a noiseless one.
+    pub fn with_simulator(mut self, simulator: Simulator) -> Self {
+        self.simulator = simulator;
+        self
+    }
+
+    /// Estimates the distributions from the given number of shots per circuit
+    /// 
+    /// # Panics
+    /// Panics if no shot is requested
+    pub fn with_shots(mut self, shots: usize) -> Self {
+        if shots == 0 {
+            panic!("Sampling a distribution requires at least one shot");
+        }
+        self.shots = Some(shots);
+        self
+    }
+
+    /// Seeds the sampling for reproducible distributions
+    /// 
+    /// The `i`-th input of a batch is sampled with the seed `seed + i`, so every
+    /// distribution is reproducible on its own, independently of the other inputs and
+    /// of the order in which the batch is executed. The seed replaces the random number
+    /// generator of the simulator.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Samples the distribution of a circuit for one set of parameters
+    /// 
+    /// # Arguments
+    /// * `circuit` - The parameterized circuit to sample
+    /// * `params` - The parameter values to bind
+    /// 
+    /// # Panics
+    /// Panics if the number of values does not match the number of parameters
+    pub fn run(&self, circuit: &ParameterizedCircuit, params: &[f64]) -> SamplerResult {
+        self.run_batch(&[(circuit, params)]).remove(0)
+    }
+
+    /// Samples the distributions of several circuits, returned in the order of the inputs
+    /// 
+    /// Unseeded batches are executed in parallel with [`Simulator::run_batch`].
+    /// 
+    /// # Arguments
+    /// * `inputs` - Pairs of circuit and parameter values
+    /// 
+    /// # Panics
+    /// Panics if the number of values does not match the number of parameters of its circuit
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::circuit::ParameterizedCircuit;
+    /// use intrico::primitives::Sampler;
+    /// 
+    /// let mut ansatz = ParameterizedCircuit::new(1);
+    /// ansatz.rx(0, 0);
+    /// 
+    /// let results = Sampler::new().with_shots(100).run_batch(&[
+    ///     (&ansatz, &[0.0]),
+    ///     (&ansatz, &[std::f64::consts::PI]),
+    /// ]);
+    /// assert_eq!(results[0].quasi_dist["0"], 1.0);
+    /// assert_eq!(results[1].quasi_dist["1"], 1.0);
+    /// ```
+    pub fn run_batch(&self, inputs: &[(&ParameterizedCircuit, &[f64])]) -> Vec<SamplerResult> {
+        let circuits: Vec<QuantumCircuit> = inputs.iter()
+            .map(|(circuit, params)| circuit.bind(params))
+            .collect();
+        let shots = self.shots.unwrap_or(0);
+
+        let results = match self.seed {
+            Some(seed) => circuits.into_iter()
+                .enumerate()
+                .map(|(i, circuit)| self.simulator.clone()
+                    .with_seed(seed.wrapping_add(i as u64))
+                    .with_circuit(circuit)
+                    .run(shots))
+                .collect(),
+            None => self.simulator.run_batch(&circuits, shots),
+        };
+
+        results.into_iter()
+            .map(|result| {
+                let quasi_dist = if shots > 0 {
+                    result.counts.iter()
+                        .map(|(bits, &count)| (bits.clone(), count as f64 / shots as f64))
+                        .collect()
+                } else {
+                    let num_qubits = result.final_state.len().trailing_zeros() as usize;
+                    result.final_state.iter()
+                        .map(|amp| amp.norm_squared())
+                        .enumerate()
+                        .filter(|&(_, probability)| probability > 0.0)
+                        .map(|(idx, probability)| (index_to_bitstring(idx, num_qubits, Endianness::Little), probability))
+                        .collect()
+                };
+                SamplerResult { quasi_dist, shots }
+            })
+            .collect()
+    }
+}
+
+impl Default for Sampler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,279 @@
+//! Python module
+//! 
+//! This module exposes [`QuantumCircuit`], [`Simulator`] and [`SimulationResult`] to
+//! Python through `pyo3`, so the engine can be driven from notebooks and benchmarked
+//! against other frameworks. The extension is built with maturin, which enables the
+//! `python` feature through `pyproject.toml`, e.g. `maturin develop`, and imported as `intrico`.
+//! 
+//! Invalid arguments raise `ValueError` and failed simulations raise `RuntimeError`.
+
+use std::collections::HashMap;
+
+use pyo3::exceptions::{PyRuntimeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PyComplex;
+use rusticle::complex::Complex;
+
+use crate::QuantumCircuit;
+use crate::simulator::{SimulationResult, Simulator};
+
+/// A quantum circuit, available in Python as `intrico.QuantumCircuit`
+#[pyclass(name = "QuantumCircuit", module = "intrico")]
+pub struct PyQuantumCircuit {
+    circuit: QuantumCircuit,
+}
+
+#[pymethods]
+impl PyQuantumCircuit {
+    /// Creates an empty circuit on the given number of qubits
+    #[new]
+    fn new(num_qubits: usize) -> Self {
+        PyQuantumCircuit { circuit: QuantumCircuit::new(num_qubits) }
+    }
+
+    /// The number of qubits
+    #[getter]
+    fn num_qubits(&self) -> usize {
+        self.circuit.num_qubits()
+    }
+
+    /// The number of operations
+    #[getter]
+    fn num_operations(&self) -> usize {
+        self.circuit.num_operations()
+    }
+
+    /// Applies a Hadamard gate
+    fn h(&mut self, target: usize) -> PyResult<()> {
+        self.apply(&[target], |qc| qc.h(target))
+    }
+
+    /// Applies a Pauli-X gate
+    fn x(&mut self, target: usize) -> PyResult<()> {
+        self.apply(&[target], |qc| qc.x(target))
+    }
+
+    /// Applies a Pauli-Y gate
+    fn y(&mut self, target: usize) -> PyResult<()> {
+        self.apply(&[target], |qc| qc.y(target))
+    }
+
+    /// Applies a Pauli-Z gate
+    fn z(&mut self, target: usize) -> PyResult<()> {
+        self.apply(&[target], |qc| qc.z(target))
+    }
+
+    /// Applies an S gate
+    fn s(&mut self, target: usize) -> PyResult<()> {
+        self.apply(&[target], |qc| qc.s(target))
+    }
+
+    /// Applies a T gate
+    fn t(&mut self, target: usize) -> PyResult<()> {
+        self.apply(&[target], |qc| qc.t(target))
+    }
+
+    /// Applies an S† gate
+    fn sdg(&mut self, target: usize) -> PyResult<()> {
+        self.apply(&[target], |qc| qc.sdg(target))
+    }
+
+    /// Applies a T† gate
+    fn tdg(&mut self, target: usize) -> PyResult<()> {
+        self.apply(&[target], |qc| qc.tdg(target))
+    }
+
+    /// Applies a rotation around the X axis
+    fn rx(&mut self, target: usize, angle: f64) -> PyResult<()> {
+        self.apply(&[target], |qc| qc.rx(target, angle))
+    }
+
+    /// Applies a rotation around the Y axis
+    fn ry(&mut self, target: usize, angle: f64) -> PyResult<()> {
+        self.apply(&[target], |qc| qc.ry(target, angle))
+    }
+
+    /// Applies a rotation around the Z axis
+    fn rz(&mut self, target: usize, angle: f64) -> PyResult<()> {
+        self.apply(&[target], |qc| qc.rz(target, angle))
+    }
+
+    /// Applies a controlled-NOT gate
+    fn cx(&mut self, control: usize, target: usize) -> PyResult<()> {
+        self.apply(&[control, target], |qc| qc.cnot(control, target))
+    }
+
+    /// Applies a controlled-NOT gate
+    fn cnot(&mut self, control: usize, target: usize) -> PyResult<()> {
+        self.cx(control, target)
+    }
+
+    /// Applies a controlled-Z gate
+    fn cz(&mut self, control: usize, target: usize) -> PyResult<()> {
+        self.apply(&[control, target], |qc| qc.cz(control, target))
+    }
+
+    /// Swaps two qubits
+    fn swap(&mut self, a: usize, b: usize) -> PyResult<()> {
+        self.apply(&[a, b], |qc| qc.swap(a, b))
+    }
+
+    /// Measures a qubit into a classical bit
+    fn measure(&mut self, qubit: usize, classical_bit: usize) -> PyResult<()> {
+        self.apply(&[qubit], |qc| qc.measure(qubit, classical_bit))
+    }
+
+    /// Returns the final statevector as a list of complex numbers
+    fn statevector<'py>(&self, py: Python<'py>) -> Vec<Bound<'py, PyComplex>> {
+        complex_list(py, &self.circuit.execute())
+    }
+
+    /// Returns the ASCII diagram of the circuit
+    fn draw(&self) -> String {
+        self.circuit.draw()
+    }
+
+    fn __str__(&self) -> String {
+        self.circuit.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("QuantumCircuit(num_qubits={}, num_operations={})", self.circuit.num_qubits(), self.circuit.num_operations())
+    }
+}
+
+impl PyQuantumCircuit {
+    /// Checks the qubits of an operation before adding it to the circuit
+    fn apply<F: FnOnce(&mut QuantumCircuit)>(&mut self, qubits: &[usize], add: F) -> PyResult<()> {
+        let num_qubits = self.circuit.num_qubits();
+        if let Some(&qubit) = qubits.iter().find(|&&qubit| qubit >= num_qubits) {
+            return Err(PyValueError::new_err(format!("Qubit index {} out of range for {} qubits", qubit, num_qubits)));
+        }
+        if qubits.len() == 2 && qubits[0] == qubits[1] {
+            return Err(PyValueError::new_err(format!("Two-qubit gates need distinct qubits, got qubit {} twice", qubits[0])));
+        }
+        add(&mut self.circuit);
+        Ok(())
+    }
+}
+
+/// A statevector simulator, available in Python as `intrico.Simulator`
+#[pyclass(name = "Simulator", module = "intrico")]
+pub struct PySimulator {
+    simulator: Simulator,
+}
+
+#[pymethods]
+impl PySimulator {
+    /// Creates a simulator, seeded for reproducible sampling when a seed is given
+    #[new]
+    #[pyo3(signature = (seed=None))]
+    fn new(seed: Option<u64>) -> Self {
+        let simulator = match seed {
+            Some(seed) => Simulator::new().with_seed(seed),
+            None => Simulator::new(),
+        };
+        PySimulator { simulator }
+    }
+
+    /// Runs a circuit for the given number of shots, releasing the GIL meanwhile
+    #[pyo3(signature = (circuit, shots=1024))]
+    fn run(&self, py: Python<'_>, circuit: &PyQuantumCircuit, shots: usize) -> PyResult<PySimulationResult> {
+        let simulator = self.simulator.clone().with_circuit(circuit.circuit.clone());
+        py.detach(|| simulator.try_run(shots))
+            .map(|result| PySimulationResult { result })
+            .map_err(|error| PyRuntimeError::new_err(error.to_string()))
+    }
+
+    /// Returns the probability of every basis state of a circuit
+    fn probabilities(&self, py: Python<'_>, circuit: &PyQuantumCircuit) -> Vec<f64> {
+        let simulator = self.simulator.clone().with_circuit(circuit.circuit.clone());
+        py.detach(|| simulator.probabilities())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Simulator(backend={:?})", self.simulator.backend.name())
+    }
+}
+
+/// The outcome of a simulation, available in Python as `intrico.SimulationResult`
+#[pyclass(name = "SimulationResult", module = "intrico")]
+pub struct PySimulationResult {
+    result: SimulationResult,
+}
+
+#[pymethods]
+impl PySimulationResult {
+    /// The number of shots executed
+    #[getter]
+    fn shots(&self) -> usize {
+        self.result.shots
+    }
+
+    /// The measurement counts keyed by bitstring, qubit 0 rightmost
+    #[getter]
+    fn counts(&self) -> HashMap<String, usize> {
+        self.result.counts.clone()
+    }
+
+    /// The final statevector as a list of complex numbers
+    #[getter]
+    fn final_state<'py>(&self, py: Python<'py>) -> Vec<Bound<'py, PyComplex>> {
+        complex_list(py, &self.result.final_state)
+    }
+
+    /// The wall-clock time of the run in seconds
+    #[getter]
+    fn wall_time(&self) -> f64 {
+        self.result.metrics.wall_time.as_secs_f64()
+    }
+
+    /// Returns the most frequent outcome and its count, or `None` without counts
+    fn most_frequent(&self) -> Option<(String, usize)> {
+        self.result.most_frequent()
+    }
+
+    /// Returns the measured probability of a bitstring
+    fn probability(&self, bitstring: &str) -> f64 {
+        self.result.probability(bitstring)
+    }
+
+    /// Returns the counts restricted to a subset of qubits
+    fn marginal_counts(&self, qubits: Vec<usize>) -> PyResult<HashMap<String, usize>> {
+        let width = self.result.counts.keys().next().map_or(0, |key| key.len());
+        if let Some(&qubit) = qubits.iter().find(|&&qubit| qubit >= width) {
+            return Err(PyValueError::new_err(format!("Qubit index {} is out of bounds for {}-bit outcomes", qubit, width)));
+        }
+        Ok(self.result.marginal_counts(&qubits))
+    }
+
+    /// Returns the expectation value of a Pauli string such as `"XZ"` estimated from the counts
+    fn pauli_expectation(&self, pauli: &str) -> PyResult<f64> {
+        if pauli.chars().any(|c| !matches!(c, 'I' | 'X' | 'Y' | 'Z')) {
+            return Err(PyValueError::new_err(format!("Invalid Pauli string '{}'", pauli)));
+        }
+        Ok(self.result.pauli_expectation(pauli))
+    }
+
+    fn __str__(&self) -> String {
+        self.result.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("SimulationResult(shots={}, outcomes={})", self.result.shots, self.result.counts.len())
+    }
+}
+
+/// Converts amplitudes to Python complex numbers
+fn complex_list<'py>(py: Python<'py>, amplitudes: &[Complex]) -> Vec<Bound<'py, PyComplex>> {
+    amplitudes.iter().map(|amp| PyComplex::from_doubles(py, amp.real, amp.imag)).collect()
+}
+
+/// The `intrico` Python module
+#[pymodule]
+fn intrico(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyQuantumCircuit>()?;
+    module.add_class::<PySimulator>()?;
+    module.add_class::<PySimulationResult>()?;
+    Ok(())
+}
@@ -0,0 +1,125 @@
+use std::fmt;
+use rusticle::complex::Complex;
+
+use crate::QuantumCircuit;
+use crate::transpiler::TranspileOptions;
+use crate::utility::Tolerance;
+use super::{CancellationToken, NoiseModel, Progress, SamplingMode, SharedRng, SimulationError, SimulationResult};
+
+/// Default upper bound on the statevector size (16 GiB, i.e. 30 qubits)
+/// 
+/// On 32-bit targets such as `wasm32`, where 16 GiB does not fit into a `usize`, the
+/// bound is the whole address space instead.
+pub const DEFAULT_MEMORY_LIMIT: usize = if (usize::MAX as u64) < 16 << 30 { usize::MAX } else { (16u64 << 30) as usize };
+
+/// Configuration shared by every backend a [`Simulator`](super::Simulator) runs on
+#[derive(Debug, Clone)]
+pub struct SimulatorConfig {
+    /// Whether to compute entanglement diagnostics of the final state
+    pub entanglement_diagnostics: bool,
+    /// Token used to abort a running simulation
+    pub cancellation: Option<CancellationToken>,
+    /// Maximum number of qubits the simulator accepts
+    pub max_qubits: Option<usize>,
+    /// Maximum number of bytes the statevector may occupy
+    pub memory_limit: Option<usize>,
+    /// Statevector to start from instead of |0...0⟩
+    pub initial_state: Option<Vec<Complex>>,
+    /// Transpilation applied to circuits before they reach the backend
+    pub transpile: Option<TranspileOptions>,
+    /// Stochastic noise applied during the simulation
+    pub noise: Option<NoiseModel>,
+    /// Strategy used to draw measurement outcomes
+    pub sampling: SamplingMode,
+    /// Random number generator used for sampling and noise, or the thread-local one if unset
+    pub rng: Option<SharedRng>,
+    /// Rounding applied to the returned states, or the process-wide policy if unset
+    pub tolerance: Option<Tolerance>,
+}
+
+impl Default for SimulatorConfig {
+    fn default() -> Self {
+        SimulatorConfig {
+            entanglement_diagnostics: false,
+            cancellation: None,
+            max_qubits: None,
+            memory_limit: Some(DEFAULT_MEMORY_LIMIT),
+            initial_state: None,
+            transpile: None,
+            noise: None,
+            sampling: SamplingMode::default(),
+            rng: None,
+            tolerance: None,
+        }
+    }
+}
+
+impl SimulatorConfig {
+    /// Returns the rounding policy of the returned states
+    pub fn tolerance(&self) -> Tolerance {
+        self.tolerance.unwrap_or_else(Tolerance::global)
+    }
+
+    /// Returns an error if cancellation was requested through the attached token
+    pub fn check_cancelled(&self) -> Result<(), SimulationError> {
+        match &self.cancellation {
+            Some(token) if token.is_cancelled() => Err(SimulationError::Cancelled),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// An engine capable of executing quantum circuits
+/// 
+/// Implement this trait to plug a custom simulation engine into [`Simulator`](super::Simulator).
+/// The statevector engine, [`StateVectorBackend`](super::StateVectorBackend), is used by default.
+/// 
+/// # Examples
+/// ```
+/// use intrico::QuantumCircuit;
+/// use intrico::simulator::{Progress, SimulationError, SimulationResult, Simulator, SimulatorBackend, SimulatorConfig};
+/// 
+/// /// A backend that always reports the all-zero outcome
+/// #[derive(Debug)]
+/// struct ZeroBackend;
+/// 
+/// impl SimulatorBackend for ZeroBackend {
+///     fn name(&self) -> &str {
+///         "zero"
+///     }
+/// 
+///     fn run(&self, circuit: &QuantumCircuit, shots: usize, _config: &SimulatorConfig,
+///            _progress: &mut dyn FnMut(Progress)) -> Result<SimulationResult, SimulationError> {
+///         let zeros = "0".repeat(circuit.num_qubits());
+///         Ok(SimulationResult {
+///             shots,
+///             final_state: Vec::new(),
+///             counts: [(zeros, shots)].into_iter().collect(),
+///             snapshots: Default::default(),
+///             entanglement: None,
+///             metrics: Default::default(),
+///         })
+///     }
+/// }
+/// 
+/// let mut qc = QuantumCircuit::new(1);
+/// qc.x(0);
+/// let result = Simulator::with_backend(ZeroBackend).with_circuit(qc).run(10);
+/// assert_eq!(result.counts["0"], 10);
+/// ```
+pub trait SimulatorBackend: fmt::Debug + Send + Sync {
+    /// Returns a short name identifying the backend
+    fn name(&self) -> &str;
+
+    /// Executes a circuit and samples `shots` measurement outcomes
+    /// 
+    /// Backends should honour the options in `config` that apply to them and report
+    /// progress through `progress` where possible.
+    fn run(
+        &self,
+        circuit: &QuantumCircuit,
+        shots: usize,
+        config: &SimulatorConfig,
+        progress: &mut dyn FnMut(Progress),
+    ) -> Result<SimulationResult, SimulationError>;
+}
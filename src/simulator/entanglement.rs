@@ -0,0 +1,103 @@
+use rusticle::complex::Complex;
+use rusticle::linalg::Matrix;
+
+use crate::utility::hermitian_eigen;
+
+/// Entanglement diagnostics computed from a pure statevector
+///
+/// Bipartitions are the contiguous cuts of the register: entry `k - 1` of
+/// [`EntanglementReport::bipartition_entropies`] describes qubits `0..k` versus `k..n`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EntanglementReport {
+    /// Purity Tr(ρ²) of each qubit's reduced state, 1.0 for an unentangled qubit
+    pub qubit_purities: Vec<f64>,
+    /// Von Neumann entropy (in bits) of each contiguous bipartition
+    pub bipartition_entropies: Vec<f64>,
+}
+
+impl EntanglementReport {
+    /// Computes the diagnostics of a normalized statevector over `num_qubits` qubits
+    ///
+    /// # Examples
+    /// ```
+    /// use rusticle::complex::Complex;
+    /// use intrico::simulator::EntanglementReport;
+    ///
+    /// let h = 1.0 / 2.0_f64.sqrt();
+    /// let zero = Complex::new(0.0, 0.0);
+    /// let bell = [Complex::new(h, 0.0), zero, zero, Complex::new(h, 0.0)];
+    ///
+    /// let report = EntanglementReport::from_state(&bell, 2);
+    /// assert!((report.bipartition_entropies[0] - 1.0).abs() < 1e-10);
+    /// assert!(report.is_entangled());
+    /// ```
+    pub fn from_state(state: &[Complex], num_qubits: usize) -> Self {
+        if state.len() != 1 << num_qubits {
+            panic!("Statevector of length {} does not describe {} qubits", state.len(), num_qubits);
+        }
+
+        let qubit_purities = (0..num_qubits)
+            .map(|qubit| Self::qubit_purity(state, qubit))
+            .collect();
+        let bipartition_entropies = (1..num_qubits)
+            .map(|cut| Self::cut_entropy(state, num_qubits, cut))
+            .collect();
+
+        EntanglementReport { qubit_purities, bipartition_entropies }
+    }
+
+    /// Returns true if any bipartition carries entanglement above a small tolerance
+    pub fn is_entangled(&self) -> bool {
+        self.bipartition_entropies.iter().any(|&entropy| entropy > 1e-6)
+    }
+
+    fn qubit_purity(state: &[Complex], qubit: usize) -> f64 {
+        let mask = 1 << qubit;
+        let mut rho00 = 0.0;
+        let mut rho11 = 0.0;
+        let mut rho01 = Complex::new(0.0, 0.0);
+
+        for (i, amplitude) in state.iter().enumerate() {
+            if i & mask == 0 {
+                let partner = state[i | mask];
+                rho00 += amplitude.norm_squared();
+                rho11 += partner.norm_squared();
+                rho01 += *amplitude * partner.conjugate();
+            }
+        }
+
+        rho00 * rho00 + rho11 * rho11 + 2.0 * rho01.norm_squared()
+    }
+
+    fn cut_entropy(state: &[Complex], num_qubits: usize, cut: usize) -> f64 {
+        // Reduce onto the smaller side of the cut to keep the eigenproblem small
+        let low_dim = 1 << cut;
+        let high_dim = 1 << (num_qubits - cut);
+        let keep_low = low_dim <= high_dim;
+        let (dim, traced) = if keep_low { (low_dim, high_dim) } else { (high_dim, low_dim) };
+
+        let amplitude = |kept: usize, other: usize| {
+            if keep_low { state[kept + other * low_dim] } else { state[other + kept * low_dim] }
+        };
+
+        let mut rho = Matrix::zeros(dim, dim);
+        for r in 0..dim {
+            for c in r..dim {
+                let mut sum = Complex::new(0.0, 0.0);
+                for k in 0..traced {
+                    sum += amplitude(r, k) * amplitude(c, k).conjugate();
+                }
+                rho.set(r, c, sum);
+                rho.set(c, r, sum.conjugate());
+            }
+        }
+
+        let (eigenvalues, _) = hermitian_eigen(&rho);
+        eigenvalues.iter()
+            .filter(|&&lambda| lambda > 1e-12)
+            .map(|&lambda| -lambda * lambda.log2())
+            .sum::<f64>()
+            .max(0.0)
+    }
+}
@@ -0,0 +1,52 @@
+use std::fmt;
+
+/// Errors that can occur while running a simulation
+#[derive(Debug, Clone, PartialEq)]
+pub enum SimulationError {
+    /// The simulation was aborted through its cancellation token
+    Cancelled,
+    /// The statevector of the circuit exceeds the configured qubit or memory limit
+    StateTooLarge {
+        /// Number of qubits in the circuit
+        qubits: usize,
+        /// Bytes required to hold the statevector
+        bytes_needed: u128,
+    },
+    /// The initial statevector does not have `2^n` amplitudes for the circuit
+    InitialStateDimension {
+        /// Number of amplitudes required by the circuit
+        expected: usize,
+        /// Number of amplitudes in the provided state
+        found: usize,
+    },
+    /// The initial statevector is not normalized
+    InitialStateNotNormalized {
+        /// Squared norm of the provided state
+        norm: f64,
+    },
+}
+
+impl fmt::Display for SimulationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SimulationError::Cancelled => write!(f, "Simulation was cancelled"),
+            SimulationError::StateTooLarge { qubits, bytes_needed } => write!(
+                f,
+                "Statevector of {} qubits needs {} bytes, which exceeds the simulator limits",
+                qubits, bytes_needed
+            ),
+            SimulationError::InitialStateDimension { expected, found } => write!(
+                f,
+                "Initial state has {} amplitudes but the circuit requires {}",
+                found, expected
+            ),
+            SimulationError::InitialStateNotNormalized { norm } => write!(
+                f,
+                "Initial state must be normalized, but its squared norm is {}",
+                norm
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SimulationError {}
@@ -0,0 +1,81 @@
+use std::collections::HashMap;
+use std::time::Duration;
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+use std::time::Instant;
+
+/// Performance figures recorded while executing a circuit
+/// 
+/// Timings are zero on `wasm32-unknown-unknown`, which has no clock.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExecutionMetrics {
+    /// Total wall-clock time of the run, including sampling
+    pub wall_time: Duration,
+    /// Wall-clock time spent sampling measurement outcomes
+    pub sampling_time: Duration,
+    /// Number of applied operations per gate kind, e.g. `"Hadamard"` or `"Rx"`
+    pub gate_counts: HashMap<String, usize>,
+    /// Accumulated wall-clock time spent applying each gate kind
+    pub gate_timings: HashMap<String, Duration>,
+    /// Estimated peak memory held by statevectors and probability tables, in bytes
+    pub peak_memory_bytes: usize,
+}
+
+impl ExecutionMetrics {
+    /// Returns the total number of applied operations
+    pub fn total_gates(&self) -> usize {
+        self.gate_counts.values().sum()
+    }
+
+    /// Returns the gate kind that took the most time in total, if any gates were applied
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// use intrico::simulator::Simulator;
+    /// 
+    /// let mut qc = QuantumCircuit::new(2);
+    /// qc.h(0);
+    /// qc.h(1);
+    /// 
+    /// let result = Simulator::new().with_circuit(qc).run(10);
+    /// assert_eq!(result.metrics.gate_counts["Hadamard"], 2);
+    /// assert_eq!(result.metrics.hotspot().unwrap().0, "Hadamard");
+    /// ```
+    pub fn hotspot(&self) -> Option<(&str, Duration)> {
+        self.gate_timings.iter()
+            .max_by_key(|&(_, &time)| time)
+            .map(|(kind, &time)| (kind.as_str(), time))
+    }
+
+    /// Records a single applied operation of the given kind
+    pub(crate) fn record_gate(&mut self, kind: String, time: Duration) {
+        *self.gate_timings.entry(kind.clone()).or_default() += time;
+        *self.gate_counts.entry(kind).or_insert(0) += 1;
+    }
+}
+
+/// Measures wall-clock time for the execution metrics
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Stopwatch {
+    #[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+    started: Instant,
+}
+
+impl Stopwatch {
+    /// Starts measuring
+    pub(crate) fn start() -> Self {
+        Stopwatch {
+            #[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+            started: Instant::now(),
+        }
+    }
+
+    /// Returns the time since the stopwatch was started, zero on targets without a clock
+    pub(crate) fn elapsed(&self) -> Duration {
+        #[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+        return self.started.elapsed();
+        #[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+        return Duration::ZERO;
+    }
+}
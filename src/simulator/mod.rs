@@ -2,6 +2,39 @@
 //! 
 //! This module provides functionality for simulating quantum circuits using different backends.
 
+#[allow(clippy::module_inception)]
 mod simulator;
+mod result;
+mod entanglement;
+mod progress;
+mod error;
+mod task;
+mod state_io;
+mod backend;
+mod statevector;
+mod metrics;
+mod observable;
+mod noise;
+mod pauli_frame;
+mod sampling;
+mod rng;
+#[cfg(feature = "plotters")]
+mod plot;
 
-pub use simulator::{Simulator, Backend, SimulationResult};
\ No newline at end of file
+pub use simulator::Simulator;
+pub use backend::{SimulatorBackend, SimulatorConfig, DEFAULT_MEMORY_LIMIT};
+pub use statevector::StateVectorBackend;
+pub use metrics::ExecutionMetrics;
+pub use observable::Observable;
+pub use noise::NoiseModel;
+pub(crate) use noise::PauliChannel;
+pub use pauli_frame::PauliFrameSimulator;
+pub(crate) use pauli_frame::{apply_errors, propagate};
+pub use sampling::{SamplingMode, AUTO_MULTINOMIAL_SHOTS};
+pub use rng::SharedRng;
+pub(crate) use rng::fresh_seed;
+pub use result::SimulationResult;
+pub use entanglement::EntanglementReport;
+pub use progress::{Progress, ProgressStage, CancellationToken};
+pub use error::SimulationError;
+pub use state_io::{save_state, load_state, StateFormat};
\ No newline at end of file
@@ -0,0 +1,205 @@
+use rand::Rng;
+
+use crate::QuantumGate;
+use crate::core::{GateOp, NoiseChannel};
+use crate::device::DeviceModel;
+use super::sampling::multinomial;
+
+/// Stochastic noise applied by the simulator after every gate and at readout
+/// 
+/// Gate errors are modelled as depolarizing channels on the qubits a gate acts on.
+/// When a [`DeviceModel`] is attached, its calibrated gate and readout errors take
+/// precedence over the defaults, and the coherence times of the qubits add a
+/// Pauli-twirled thermal relaxation error for the duration of every gate. Durations set
+/// on individual operations override the calibrated ones.
+/// 
+/// # Examples
+/// ```
+/// use intrico::QuantumCircuit;
+/// use intrico::simulator::{NoiseModel, Simulator};
+/// 
+/// let mut qc = QuantumCircuit::new(1);
+/// qc.x(0);
+/// 
+/// let noise = NoiseModel::depolarizing(0.1, 0.0).with_readout_error(0.05);
+/// let result = Simulator::new().with_circuit(qc).with_noise(noise).run(1000);
+/// assert!(result.counts["1"] > 700);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NoiseModel {
+    /// Depolarizing probability after every single-qubit gate
+    pub single_qubit_error: f64,
+    /// Depolarizing probability after every gate acting on several qubits
+    pub multi_qubit_error: f64,
+    /// Probability of flipping each measured bit
+    pub readout_error: f64,
+    /// Device calibration overriding the default error rates
+    pub device: Option<DeviceModel>,
+}
+
+/// A stochastic error that applies one of several Pauli strings
+#[derive(Debug, Clone)]
+pub(crate) struct PauliChannel {
+    /// Pauli strings as (qubit, gate) pairs, with their probabilities
+    outcomes: Vec<(Vec<(usize, QuantumGate)>, f64)>,
+}
+
+impl NoiseModel {
+    /// Creates a noise model with depolarizing gate errors and no readout error
+    /// 
+    /// # Arguments
+    /// * `single_qubit_error` - The depolarizing probability after single-qubit gates
+    /// * `multi_qubit_error` - The depolarizing probability after multi-qubit gates
+    pub fn depolarizing(single_qubit_error: f64, multi_qubit_error: f64) -> Self {
+        NoiseModel {
+            single_qubit_error,
+            multi_qubit_error,
+            ..Self::default()
+        }
+    }
+
+    /// Creates a noise model from the calibration data of a device
+    pub fn from_device(device: DeviceModel) -> Self {
+        NoiseModel {
+            device: Some(device),
+            ..Self::default()
+        }
+    }
+
+    /// Sets the probability of flipping each measured bit
+    pub fn with_readout_error(mut self, probability: f64) -> Self {
+        self.readout_error = probability;
+        self
+    }
+
+    /// Returns the depolarizing probability after the given operation
+    pub fn gate_error(&self, op: &GateOp) -> f64 {
+        let calibrated = self.device.as_ref()
+            .and_then(|device| device.operation_properties(op))
+            .map(|props| props.error);
+
+        calibrated.unwrap_or(if op.qubit.len() == 1 { self.single_qubit_error } else { self.multi_qubit_error })
+    }
+
+    /// Returns the probability of flipping the measured bit of the given qubit
+    pub fn readout_error(&self, qubit: usize) -> f64 {
+        self.device.as_ref()
+            .and_then(|device| device.qubits.get(qubit))
+            .map_or(self.readout_error, |props| props.readout_error)
+    }
+
+    /// Returns the error channels to apply after an operation
+    pub(crate) fn channels(&self, op: &GateOp) -> Vec<PauliChannel> {
+        let mut channels = Vec::new();
+
+        let error = self.gate_error(op);
+        if error > 0.0 {
+            channels.push(PauliChannel::depolarizing(&op.qubit, error));
+        }
+
+        let duration = op.duration.or_else(|| self.device.as_ref()
+            .and_then(|device| device.operation_properties(op))
+            .map(|props| props.duration))
+            .unwrap_or(0.0);
+        if let (Some(device), true) = (&self.device, duration > 0.0) {
+            for &qubit in &op.qubit {
+                if let Some(props) = device.qubits.get(qubit) {
+                    channels.push(PauliChannel::thermal_relaxation(qubit, props.t1, props.t2, duration));
+                }
+            }
+        }
+
+        channels
+    }
+}
+
+impl PauliChannel {
+    /// Applies a uniformly random non-identity Pauli string with the given probability
+    pub(crate) fn depolarizing(qubits: &[usize], probability: f64) -> Self {
+        let paulis = [QuantumGate::X, QuantumGate::Y, QuantumGate::Z];
+        let count = (1usize << (2 * qubits.len())) - 1;
+
+        let outcomes = (1..=count)
+            .map(|code| {
+                // Base-4 digits select I, X, Y or Z on each qubit
+                let string = qubits.iter()
+                    .enumerate()
+                    .filter_map(|(j, &qubit)| match (code >> (2 * j)) & 3 {
+                        0 => None,
+                        digit => Some((qubit, paulis[digit - 1].clone())),
+                    })
+                    .collect();
+                (string, probability / count as f64)
+            })
+            .collect();
+
+        PauliChannel { outcomes }
+    }
+
+    /// Returns the Pauli channels realizing a noise channel attached to an operation
+    pub(crate) fn from_attached(channel: NoiseChannel, qubits: &[usize]) -> Vec<Self> {
+        let independent = |gate: QuantumGate, probability: f64| qubits.iter()
+            .map(|&qubit| PauliChannel { outcomes: vec![(vec![(qubit, gate.clone())], probability)] })
+            .collect();
+
+        match channel {
+            NoiseChannel::Depolarizing(probability) => vec![Self::depolarizing(qubits, probability)],
+            NoiseChannel::BitFlip(probability) => independent(QuantumGate::X, probability),
+            NoiseChannel::PhaseFlip(probability) => independent(QuantumGate::Z, probability),
+        }
+    }
+
+    /// Pauli-twirled approximation of amplitude and phase damping over `duration`
+    pub(crate) fn thermal_relaxation(qubit: usize, t1: f64, t2: f64, duration: f64) -> Self {
+        let p_reset = 1.0 - (-duration / t1).exp();
+        let p_phase = 1.0 - (-duration / t2).exp();
+        let px = p_reset / 4.0;
+        let pz = (p_phase / 2.0 - p_reset / 4.0).max(0.0);
+
+        PauliChannel {
+            outcomes: vec![
+                (vec![(qubit, QuantumGate::X)], px),
+                (vec![(qubit, QuantumGate::Y)], px),
+                (vec![(qubit, QuantumGate::Z)], pz),
+            ],
+        }
+    }
+
+    /// Distributes shots over the outcomes of the channel
+    /// 
+    /// Returns the number of shots hit by each outcome; the remaining shots are unaffected.
+    /// The counts come from a single multinomial draw, so the cost does not grow with
+    /// the number of shots.
+    pub(crate) fn split<R: Rng + ?Sized>(&self, shots: usize, rng: &mut R) -> Vec<usize> {
+        let mut probabilities: Vec<f64> = self.outcomes.iter().map(|(_, probability)| *probability).collect();
+        let total: f64 = probabilities.iter().sum();
+        probabilities.push((1.0 - total).max(0.0));
+
+        let mut hits = multinomial(rng, shots, &probabilities);
+        hits.pop();
+        hits
+    }
+
+    /// Draws the outcome hitting a single shot, if any
+    pub(crate) fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<usize> {
+        let mut draw = rng.random::<f64>();
+        for (outcome, (_, probability)) in self.outcomes.iter().enumerate() {
+            if draw < *probability {
+                return Some(outcome);
+            }
+            draw -= probability;
+        }
+        None
+    }
+
+    /// Returns the Pauli string of the given outcome as (qubit, gate) pairs
+    pub(crate) fn paulis(&self, outcome: usize) -> &[(usize, QuantumGate)] {
+        &self.outcomes[outcome].0
+    }
+
+    /// Returns the Pauli operations making up the given outcome
+    pub(crate) fn operations(&self, outcome: usize) -> impl Iterator<Item = GateOp> + '_ {
+        self.outcomes[outcome].0.iter()
+            .map(|(qubit, gate)| GateOp::new(gate.clone(), *qubit, 0))
+    }
+}
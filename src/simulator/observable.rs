@@ -0,0 +1,39 @@
+use rusticle::complex::Complex;
+use rusticle::linalg::Matrix;
+
+/// A Hermitian operator whose expectation value can be measured on a statevector
+pub trait Observable {
+    /// Returns the expectation value ⟨ψ|O|ψ⟩ for a normalized statevector
+    fn expectation(&self, state: &[Complex]) -> f64;
+}
+
+impl Observable for Matrix<Complex> {
+    /// # Panics
+    /// Panics if the matrix dimension does not match the statevector length
+    /// 
+    /// # Examples
+    /// ```
+    /// use rusticle::complex::Complex;
+    /// use intrico::QuantumGate;
+    /// use intrico::simulator::Observable;
+    /// 
+    /// let one = [Complex::new(0.0, 0.0), Complex::new(1.0, 0.0)];
+    /// assert_eq!(QuantumGate::Z.matrix().expectation(&one), -1.0);
+    /// ```
+    fn expectation(&self, state: &[Complex]) -> f64 {
+        if self.rows() != state.len() || self.cols() != state.len() {
+            panic!("Observable of dimension {}x{} does not match statevector of length {}",
+                   self.rows(), self.cols(), state.len());
+        }
+
+        state.iter()
+            .enumerate()
+            .map(|(r, amplitude)| {
+                let row: Complex = state.iter()
+                    .enumerate()
+                    .fold(Complex::new(0.0, 0.0), |acc, (c, other)| acc + *self.get(r, c) * *other);
+                (amplitude.conjugate() * row).real
+            })
+            .sum()
+    }
+}
@@ -0,0 +1,455 @@
+use std::collections::HashMap;
+
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+use crate::{QuantumCircuit, QuantumGate};
+use crate::core::GateOp;
+use super::{NoiseModel, PauliChannel, fresh_seed};
+
+/// Samples noisy Clifford circuits by propagating Pauli frames instead of a statevector
+///
+/// A single noiseless reference shot is simulated with a stabilizer tableau, which takes
+/// polynomial time in the number of qubits. Every other shot only tracks the Pauli frame,
+/// the X and Z components by which it differs from the reference: Clifford gates map the
+/// frame to another Pauli frame, sampled errors are multiplied into it, and a measurement
+/// returns the reference outcome flipped by the X component of the frame. Randomizing
+/// the Z component of the frame on the initial state and after every measurement, where
+/// it leaves the state unchanged, reproduces the randomness of the outcomes. A shot
+/// therefore costs a few bit operations per gate, so millions of noisy shots of circuits
+/// with hundreds of qubits remain cheap.
+///
+/// Supported operations are Pauli, H, S, S†, CNOT and CZ gates, measurements in any
+/// basis, snapshots, which are ignored, and classical operations. Only Pauli gates and
+/// classical operations can be conditioned on classical bits. Errors of the noise model
+/// are applied after every gate as in the statevector simulator, and readout errors flip
+/// every measured bit.
+///
+/// # Examples
+/// ```
+/// use intrico::QuantumCircuit;
+/// use intrico::simulator::{NoiseModel, PauliFrameSimulator};
+///
+/// // A GHZ state on 100 qubits, far beyond the reach of a statevector
+/// let mut qc = QuantumCircuit::new(100);
+/// qc.h(0);
+/// for qubit in 0..99 {
+///     qc.cnot(qubit, qubit + 1);
+/// }
+///
+/// let counts = PauliFrameSimulator::new().with_seed(3).run(&qc, 1000);
+/// assert_eq!(counts.len(), 2);
+/// assert_eq!(counts.values().sum::<usize>(), 1000);
+///
+/// let noisy = PauliFrameSimulator::new()
+///     .with_noise(NoiseModel::depolarizing(0.0, 0.01))
+///     .with_seed(3)
+///     .run(&qc, 1000);
+/// assert!(noisy.len() > 2);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct PauliFrameSimulator {
+    noise: Option<NoiseModel>,
+    seed: Option<u64>,
+}
+
+/// Noiseless outcomes of the reference shot
+struct Reference {
+    /// Outcome of every measurement, indexed by operation
+    measurements: Vec<bool>,
+    /// Whether the condition of every operation held
+    enabled: Vec<bool>,
+    /// Computational-basis readout of every qubit at the end of the circuit
+    readout: Vec<bool>,
+}
+
+impl PauliFrameSimulator {
+    /// Creates a noiseless Pauli-frame simulator
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the noise model applied after every gate and to every measured bit
+    pub fn with_noise(mut self, noise: NoiseModel) -> Self {
+        self.noise = Some(noise);
+        self
+    }
+
+    /// Seeds the random number generator for reproducible runs
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Samples the readout of every qubit at the end of the circuit
+    ///
+    /// Keys follow the convention of [`SimulationResult::counts`](super::SimulationResult::counts),
+    /// with qubit 0 as the rightmost character.
+    ///
+    /// # Panics
+    /// Panics if the circuit contains an operation that is not supported, see
+    /// [`PauliFrameSimulator`]
+    pub fn run(&self, circuit: &QuantumCircuit, shots: usize) -> HashMap<String, usize> {
+        let mut counts = HashMap::new();
+        self.simulate(circuit, shots, |_, readout| {
+            let bitstring: String = readout.iter().rev().map(|&bit| if bit { '1' } else { '0' }).collect();
+            *counts.entry(bitstring).or_insert(0) += 1;
+        });
+        counts
+    }
+
+    /// Samples the classical register at the end of every shot
+    ///
+    /// This is the measurement record error-correction experiments derive their
+    /// detectors from.
+    ///
+    /// # Panics
+    /// Panics if the circuit contains an operation that is not supported, see
+    /// [`PauliFrameSimulator`]
+    ///
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// use intrico::simulator::PauliFrameSimulator;
+    ///
+    /// // Measure the parity of a Bell pair onto an ancilla
+    /// let mut qc = QuantumCircuit::new(3);
+    /// qc.h(0);
+    /// qc.cnot(0, 1);
+    /// qc.cnot(0, 2);
+    /// qc.cnot(1, 2);
+    /// qc.measure(2, 0);
+    /// qc.measure(0, 1);
+    ///
+    /// for bits in PauliFrameSimulator::new().sample(&qc, 100) {
+    ///     assert!(!bits[0]);
+    /// }
+    /// ```
+    pub fn sample(&self, circuit: &QuantumCircuit, shots: usize) -> Vec<Vec<bool>> {
+        let mut records = Vec::with_capacity(shots);
+        self.simulate(circuit, shots, |classical_bits, _| records.push(classical_bits.to_vec()));
+        records
+    }
+
+    /// Propagates the frames of all shots, handing each shot's classical register and
+    /// final readout to `record`
+    fn simulate<F: FnMut(&[bool], &[bool])>(&self, circuit: &QuantumCircuit, shots: usize, mut record: F) {
+        let operations = circuit.operations();
+        for op in operations {
+            check_supported(op);
+        }
+        let reference = Self::reference(circuit);
+        let channels: Vec<Vec<PauliChannel>> = operations.iter()
+            .map(|op| {
+                let mut channels = match (&self.noise, op.gate.is_measurement() || matches!(op.gate, QuantumGate::Snapshot(_) | QuantumGate::Classical(_))) {
+                    (Some(noise), false) => noise.channels(op),
+                    _ => Vec::new(),
+                };
+                if let Some(channel) = op.noise {
+                    channels.extend(PauliChannel::from_attached(channel, &op.qubit));
+                }
+                channels
+            })
+            .collect();
+        let readout_errors: Vec<f64> = (0..circuit.num_qubits())
+            .map(|qubit| self.noise.as_ref().map_or(0.0, |noise| noise.readout_error(qubit)))
+            .collect();
+
+        let mut rng = StdRng::seed_from_u64(self.seed.unwrap_or_else(fresh_seed));
+        let num_qubits = circuit.num_qubits();
+        let mut x_frame = vec![false; num_qubits];
+        let mut z_frame = vec![false; num_qubits];
+        let mut classical_bits = vec![false; circuit.num_classical_bits()];
+        let mut readout = vec![false; num_qubits];
+
+        for _ in 0..shots {
+            x_frame.fill(false);
+            z_frame.iter_mut().for_each(|z| *z = rng.random());
+            classical_bits.fill(false);
+
+            for (i, (op, op_channels)) in operations.iter().zip(&channels).enumerate() {
+                match &op.gate {
+                    QuantumGate::Snapshot(_) => {},
+                    QuantumGate::Classical(classical) => {
+                        if op.condition.is_none_or(|(bit, value)| classical_bits[bit] == value) {
+                            classical_bits[op.classical_bit.unwrap_or_default()] = classical.evaluate(&classical_bits);
+                        }
+                    },
+                    gate if gate.is_measurement() => {
+                        let qubit = op.target();
+                        rotate_frame(gate, qubit, &mut x_frame, &mut z_frame);
+                        let flipped = rng.random::<f64>() < readout_errors[qubit];
+                        classical_bits[op.classical_bit.unwrap_or_default()] = reference.measurements[i] ^ x_frame[qubit] ^ flipped;
+                        z_frame[qubit] = rng.random();
+                    },
+                    _ => match op.condition {
+                        // A conditioned Pauli differs from the reference shot when the condition does
+                        Some((bit, value)) => {
+                            if (classical_bits[bit] == value) != reference.enabled[i] {
+                                toggle(&op.gate, op.target(), &mut x_frame, &mut z_frame);
+                            }
+                        },
+                        None => propagate(op, &mut x_frame, &mut z_frame),
+                    },
+                }
+
+                apply_errors(op_channels, &mut rng, &mut x_frame, &mut z_frame);
+            }
+
+            for (qubit, bit) in readout.iter_mut().enumerate() {
+                *bit = reference.readout[qubit] ^ x_frame[qubit] ^ (rng.random::<f64>() < readout_errors[qubit]);
+            }
+            record(&classical_bits, &readout);
+        }
+    }
+
+    /// Simulates the noiseless reference shot, choosing 0 for every random outcome
+    fn reference(circuit: &QuantumCircuit) -> Reference {
+        let operations = circuit.operations();
+        let mut tableau = Tableau::new(circuit.num_qubits());
+        let mut bits = vec![false; circuit.num_classical_bits()];
+        let mut reference = Reference {
+            measurements: vec![false; operations.len()],
+            enabled: Vec::with_capacity(operations.len()),
+            readout: Vec::with_capacity(circuit.num_qubits()),
+        };
+
+        for (i, op) in operations.iter().enumerate() {
+            let enabled = op.condition.is_none_or(|(bit, value)| bits[bit] == value);
+            reference.enabled.push(enabled);
+            match &op.gate {
+                QuantumGate::Classical(classical) if enabled => {
+                    bits[op.classical_bit.unwrap_or_default()] = classical.evaluate(&bits);
+                },
+                QuantumGate::Snapshot(_) | QuantumGate::Classical(_) => {},
+                gate if gate.is_measurement() => {
+                    let qubit = op.target();
+                    match gate {
+                        QuantumGate::MeasureX => tableau.h(qubit),
+                        QuantumGate::MeasureY => {
+                            tableau.sdg(qubit);
+                            tableau.h(qubit);
+                        },
+                        _ => {},
+                    }
+                    reference.measurements[i] = tableau.measure(qubit);
+                    bits[op.classical_bit.unwrap_or_default()] = reference.measurements[i];
+                },
+                gate if enabled => tableau.apply(gate, &op.qubit),
+                _ => {},
+            }
+        }
+
+        reference.readout = (0..circuit.num_qubits()).map(|qubit| tableau.measure(qubit)).collect();
+        reference
+    }
+}
+
+/// Panics unless the Pauli-frame simulator supports the operation
+fn check_supported(op: &GateOp) {
+    let supported = match op.gate {
+        QuantumGate::X | QuantumGate::Y | QuantumGate::Z | QuantumGate::Snapshot(_) | QuantumGate::Classical(_) => true,
+        QuantumGate::H | QuantumGate::S | QuantumGate::Sdg | QuantumGate::CNOT | QuantumGate::CZ
+            | QuantumGate::Measure | QuantumGate::MeasureX | QuantumGate::MeasureY => {
+            if op.condition.is_some() {
+                panic!("Only Pauli gates and classical operations can be conditioned in the Pauli-frame simulator, got a conditioned {}", op.gate.name());
+            }
+            true
+        },
+        _ => false,
+    };
+    if !supported {
+        panic!("The Pauli-frame simulator only supports Clifford circuits, got {}", op.gate.name());
+    }
+}
+
+/// Conjugates the frame by a Clifford gate
+pub(crate) fn propagate(op: &GateOp, x_frame: &mut [bool], z_frame: &mut [bool]) {
+    match (&op.gate, &op.qubit[..]) {
+        // Pauli gates commute with the frame up to a phase
+        (QuantumGate::X | QuantumGate::Y | QuantumGate::Z, _) => {},
+        (QuantumGate::H, &[target]) => std::mem::swap(&mut x_frame[target], &mut z_frame[target]),
+        (QuantumGate::S | QuantumGate::Sdg, &[target]) => z_frame[target] ^= x_frame[target],
+        (QuantumGate::CNOT, &[control, target]) => {
+            x_frame[target] ^= x_frame[control];
+            z_frame[control] ^= z_frame[target];
+        },
+        (QuantumGate::CZ, &[control, target]) => {
+            z_frame[control] ^= x_frame[target];
+            z_frame[target] ^= x_frame[control];
+        },
+        _ => unreachable!("Unsupported operations are rejected before simulation"),
+    }
+}
+
+/// Rotates the measured basis of the frame onto the computational basis
+fn rotate_frame(gate: &QuantumGate, qubit: usize, x_frame: &mut [bool], z_frame: &mut [bool]) {
+    if matches!(gate, QuantumGate::MeasureY) {
+        z_frame[qubit] ^= x_frame[qubit];
+    }
+    if matches!(gate, QuantumGate::MeasureX | QuantumGate::MeasureY) {
+        std::mem::swap(&mut x_frame[qubit], &mut z_frame[qubit]);
+    }
+}
+
+/// Samples every channel and multiplies the drawn Paulis into the frame
+pub(crate) fn apply_errors<R: Rng + ?Sized>(channels: &[PauliChannel], rng: &mut R, x_frame: &mut [bool], z_frame: &mut [bool]) {
+    for channel in channels {
+        if let Some(outcome) = channel.sample(rng) {
+            for (qubit, gate) in channel.paulis(outcome) {
+                toggle(gate, *qubit, x_frame, z_frame);
+            }
+        }
+    }
+}
+
+/// Multiplies a single-qubit Pauli into the frame
+fn toggle(gate: &QuantumGate, qubit: usize, x_frame: &mut [bool], z_frame: &mut [bool]) {
+    match gate {
+        QuantumGate::X => x_frame[qubit] ^= true,
+        QuantumGate::Z => z_frame[qubit] ^= true,
+        _ => {
+            x_frame[qubit] ^= true;
+            z_frame[qubit] ^= true;
+        },
+    }
+}
+
+/// Stabilizer tableau of Aaronson and Gottesman
+///
+/// Rows `0..n` hold the destabilizers, rows `n..2n` the stabilizers and row `2n` is
+/// scratch space for deterministic measurements.
+struct Tableau {
+    num_qubits: usize,
+    x: Vec<Vec<bool>>,
+    z: Vec<Vec<bool>>,
+    /// Sign bit of every row
+    r: Vec<bool>,
+}
+
+impl Tableau {
+    /// Creates the tableau of |0…0⟩
+    fn new(num_qubits: usize) -> Self {
+        let rows = 2 * num_qubits + 1;
+        let mut tableau = Tableau {
+            num_qubits,
+            x: vec![vec![false; num_qubits]; rows],
+            z: vec![vec![false; num_qubits]; rows],
+            r: vec![false; rows],
+        };
+        for qubit in 0..num_qubits {
+            tableau.x[qubit][qubit] = true;
+            tableau.z[num_qubits + qubit][qubit] = true;
+        }
+        tableau
+    }
+
+    /// Applies a supported unitary gate
+    fn apply(&mut self, gate: &QuantumGate, qubits: &[usize]) {
+        match (gate, qubits) {
+            (QuantumGate::X, &[target]) => self.pauli(target, false, true),
+            (QuantumGate::Y, &[target]) => self.pauli(target, true, true),
+            (QuantumGate::Z, &[target]) => self.pauli(target, true, false),
+            (QuantumGate::H, &[target]) => self.h(target),
+            (QuantumGate::S, &[target]) => self.s(target),
+            (QuantumGate::Sdg, &[target]) => self.sdg(target),
+            (QuantumGate::CNOT, &[control, target]) => self.cnot(control, target),
+            (QuantumGate::CZ, &[control, target]) => {
+                self.h(target);
+                self.cnot(control, target);
+                self.h(target);
+            },
+            _ => unreachable!("Unsupported operations are rejected before simulation"),
+        }
+    }
+
+    /// Applies a Pauli by flipping the sign of the rows anticommuting with it: those with
+    /// an X component on the qubit if `with_x`, and those with a Z component if `with_z`
+    fn pauli(&mut self, qubit: usize, with_x: bool, with_z: bool) {
+        for row in 0..2 * self.num_qubits {
+            self.r[row] ^= (with_x && self.x[row][qubit]) ^ (with_z && self.z[row][qubit]);
+        }
+    }
+
+    fn h(&mut self, qubit: usize) {
+        for row in 0..2 * self.num_qubits {
+            self.r[row] ^= self.x[row][qubit] && self.z[row][qubit];
+            std::mem::swap(&mut self.x[row][qubit], &mut self.z[row][qubit]);
+        }
+    }
+
+    fn s(&mut self, qubit: usize) {
+        for row in 0..2 * self.num_qubits {
+            self.r[row] ^= self.x[row][qubit] && self.z[row][qubit];
+            self.z[row][qubit] ^= self.x[row][qubit];
+        }
+    }
+
+    fn sdg(&mut self, qubit: usize) {
+        self.s(qubit);
+        self.pauli(qubit, true, false);
+    }
+
+    fn cnot(&mut self, control: usize, target: usize) {
+        for row in 0..2 * self.num_qubits {
+            let (xc, zc) = (self.x[row][control], self.z[row][control]);
+            let (xt, zt) = (self.x[row][target], self.z[row][target]);
+            self.r[row] ^= xc && zt && (xt == zc);
+            self.x[row][target] ^= xc;
+            self.z[row][control] ^= zt;
+        }
+    }
+
+    /// Measures a qubit in the computational basis, returning 0 for random outcomes
+    fn measure(&mut self, qubit: usize) -> bool {
+        let n = self.num_qubits;
+        if let Some(p) = (n..2 * n).find(|&row| self.x[row][qubit]) {
+            for row in 0..2 * n {
+                if row != p && self.x[row][qubit] {
+                    self.rowsum(row, p);
+                }
+            }
+            self.copy_row(p - n, p);
+            self.x[p].fill(false);
+            self.z[p].fill(false);
+            self.z[p][qubit] = true;
+            self.r[p] = false;
+            return false;
+        }
+
+        let scratch = 2 * n;
+        self.x[scratch].fill(false);
+        self.z[scratch].fill(false);
+        self.r[scratch] = false;
+        for row in 0..n {
+            if self.x[row][qubit] {
+                self.rowsum(scratch, row + n);
+            }
+        }
+        self.r[scratch]
+    }
+
+    fn copy_row(&mut self, to: usize, from: usize) {
+        self.x[to] = self.x[from].clone();
+        self.z[to] = self.z[from].clone();
+        self.r[to] = self.r[from];
+    }
+
+    /// Multiplies row `h` by row `i`, keeping track of the sign
+    fn rowsum(&mut self, h: usize, i: usize) {
+        let mut phase = 2 * (self.r[h] as i32) + 2 * (self.r[i] as i32);
+        for qubit in 0..self.num_qubits {
+            let (x1, z1) = (self.x[i][qubit] as i32, self.z[i][qubit] as i32);
+            let (x2, z2) = (self.x[h][qubit] as i32, self.z[h][qubit] as i32);
+            // Power of i picked up when multiplying the single-qubit Paulis
+            phase += match (x1, z1) {
+                (0, 0) => 0,
+                (1, 1) => z2 - x2,
+                (1, 0) => z2 * (2 * x2 - 1),
+                _ => x2 * (1 - 2 * z2),
+            };
+            self.x[h][qubit] ^= self.x[i][qubit];
+            self.z[h][qubit] ^= self.z[i][qubit];
+        }
+        self.r[h] = phase.rem_euclid(4) == 2;
+    }
+}
@@ -0,0 +1,104 @@
+use std::{io, path::Path};
+use plotters::prelude::*;
+
+use super::SimulationResult;
+
+/// Horizontal space taken by one bar and its gap, in pixels
+const BAR_WIDTH: u32 = 48;
+/// Smallest width of the chart, in pixels
+const MIN_WIDTH: u32 = 480;
+/// Height of the chart, in pixels
+const HEIGHT: u32 = 400;
+/// Space reserved for the axes and their labels, in pixels
+const AXIS_SPACE: u32 = 64;
+/// Gap left on each side of a bar, in pixels
+const BAR_MARGIN: u32 = 8;
+
+impl SimulationResult {
+    /// Plots the measured outcome probabilities as a bar chart and writes it to a PNG file
+    ///
+    /// Bars are sorted by bitstring and each carries an error bar of one standard error,
+    /// `sqrt(p (1 - p) / shots)`, the spread expected from sampling a finite number of shots.
+    ///
+    /// # Errors
+    /// Returns an error if no font is available or the file cannot be written
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use intrico::QuantumCircuit;
+    /// use intrico::simulator::Simulator;
+    ///
+    /// let mut qc = QuantumCircuit::new(2);
+    /// qc.h(0);
+    /// qc.cnot(0, 1);
+    ///
+    /// let result = Simulator::new().with_circuit(qc).run(1000);
+    /// result.plot_histogram("bell_counts.png").unwrap();
+    /// ```
+    pub fn plot_histogram<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let mut rows: Vec<(&String, &usize)> = self.counts.iter().collect();
+        rows.sort();
+
+        let bars: Vec<(String, f64, f64)> = rows.into_iter()
+            .map(|(bitstring, &count)| {
+                let probability = if self.shots == 0 { 0.0 } else { count as f64 / self.shots as f64 };
+                let error = if self.shots == 0 { 0.0 } else { (probability * (1.0 - probability) / self.shots as f64).sqrt() };
+                (bitstring.clone(), probability, error)
+            })
+            .collect();
+
+        let width = (AXIS_SPACE + bars.len() as u32 * BAR_WIDTH).max(MIN_WIDTH);
+        let area = BitMapBackend::new(path.as_ref(), (width, HEIGHT)).into_drawing_area();
+        draw_histogram(&area, &bars, self.shots).map_err(io::Error::other)?;
+        area.present().map_err(io::Error::other)
+    }
+}
+
+/// Draws `(bitstring, probability, standard error)` bars with their axes onto a drawing area
+fn draw_histogram<DB: DrawingBackend>(
+    area: &DrawingArea<DB, plotters::coord::Shift>,
+    bars: &[(String, f64, f64)],
+    shots: usize,
+) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+    let top = bars.iter().map(|&(_, probability, error)| probability + error).fold(0.0, f64::max);
+    let y_max = if top > 0.0 { (top * 1.1).min(1.05) } else { 1.0 };
+    let slots = bars.len().max(1);
+
+    area.fill(&WHITE)?;
+    let mut chart = ChartBuilder::on(area)
+        .caption(format!("Measurement outcomes ({} shots)", shots), ("sans-serif", 18))
+        .margin(12)
+        .x_label_area_size(AXIS_SPACE / 2)
+        .y_label_area_size(AXIS_SPACE - 8)
+        .build_cartesian_2d((0..slots - 1).into_segmented(), 0.0..y_max)?;
+
+    chart.configure_mesh()
+        .disable_x_mesh()
+        .x_labels(slots)
+        .x_label_formatter(&|value| match value {
+            SegmentValue::CenterOf(slot) => bars.get(*slot).map(|(bitstring, _, _)| bitstring.clone()).unwrap_or_default(),
+            _ => String::new(),
+        })
+        .y_desc("Probability")
+        .draw()?;
+
+    chart.draw_series(
+        Histogram::vertical(&chart)
+            .style(BLUE.mix(0.7).filled())
+            .margin(BAR_MARGIN)
+            .data(bars.iter().enumerate().map(|(slot, &(_, probability, _))| (slot, probability))),
+    )?;
+
+    chart.draw_series(bars.iter().enumerate().map(|(slot, &(_, probability, error))| {
+        ErrorBar::new_vertical(
+            SegmentValue::CenterOf(slot),
+            (probability - error).max(0.0),
+            probability,
+            (probability + error).min(1.0),
+            BLACK.stroke_width(2),
+            10,
+        )
+    }))?;
+
+    Ok(())
+}
@@ -0,0 +1,69 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// The phase of a simulation that a [`Progress`] report refers to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProgressStage {
+    /// Gates are being applied to the statevector
+    Gates,
+    /// Measurement outcomes are being sampled
+    Shots,
+}
+
+/// A progress report passed to the callback of [`Simulator::run_with_callback`](super::Simulator::run_with_callback)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Progress {
+    /// The current phase of the simulation
+    pub stage: ProgressStage,
+    /// Number of completed units (gates or shots) in the current phase
+    pub completed: usize,
+    /// Total number of units in the current phase
+    pub total: usize,
+}
+
+impl Progress {
+    /// Returns the completed fraction of the current phase, between 0.0 and 1.0
+    pub fn fraction(&self) -> f64 {
+        if self.total == 0 {
+            1.0
+        } else {
+            self.completed as f64 / self.total as f64
+        }
+    }
+}
+
+/// A shareable flag used to abort a running simulation
+/// 
+/// Clones share the same flag, so a token can be handed to the simulator and
+/// cancelled from another thread.
+/// 
+/// # Examples
+/// ```
+/// use intrico::simulator::CancellationToken;
+/// 
+/// let token = CancellationToken::new();
+/// let handle = token.clone();
+/// handle.cancel();
+/// assert!(token.is_cancelled());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    /// Creates a new token that is not cancelled
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requests cancellation of every simulation observing this token
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns true if cancellation has been requested
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
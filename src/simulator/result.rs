@@ -0,0 +1,560 @@
+use std::{collections::HashMap, fmt, io, ops::Index, path::Path, vec};
+use rusticle::complex::Complex;
+
+use crate::utility::{state_fidelity, ComplexFormat};
+use super::{EntanglementReport, ExecutionMetrics, Observable, StateFormat, save_state};
+
+/// Width in characters of the longest bar in the counts histogram
+const HISTOGRAM_WIDTH: usize = 40;
+
+/// Number of final-state terms written before the rest are elided
+const STATE_TERMS: usize = 16;
+
+/// Simulator result that stores all the necessary counts
+/// and states after running the simulation
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SimulationResult {
+    /// Number of shots executed
+    pub shots: usize,
+    /// Final state of the qubits after simulation
+    #[cfg_attr(feature = "serde", serde(with = "crate::utility::serialization::complex_vec"))]
+    pub final_state: Vec<Complex>,
+    /// Measurement counts for each basis state
+    pub counts: HashMap<String, usize>,
+    /// Statevectors recorded by snapshot instructions, keyed by label
+    #[cfg_attr(feature = "serde", serde(with = "crate::utility::serialization::complex_vec_map"))]
+    pub snapshots: HashMap<String, Vec<Complex>>,
+    /// Entanglement diagnostics of the final state, if enabled on the simulator
+    pub entanglement: Option<EntanglementReport>,
+    /// Timing and resource figures recorded during the run
+    pub metrics: ExecutionMetrics,
+}
+
+impl SimulationResult {
+    /// Returns the counts restricted to a subset of qubits
+    ///
+    /// Counts of all outcomes that agree on the selected qubits are summed together.
+    /// Keys follow the same convention as [`SimulationResult::counts`]: the first qubit
+    /// in `qubits` is the rightmost character of the key.
+    ///
+    /// # Arguments
+    /// * `qubits` - The indices of the qubits to keep
+    ///
+    /// # Panics
+    /// Panics if a qubit index is out of bounds for the measured bitstrings
+    ///
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// use intrico::simulator::Simulator;
+    ///
+    /// let mut qc = QuantumCircuit::new(2);
+    /// qc.x(1);
+    ///
+    /// let result = Simulator::new().with_circuit(qc).run(100);
+    /// let marginal = result.marginal_counts(&[1]);
+    /// assert_eq!(marginal["1"], 100);
+    /// ```
+    pub fn marginal_counts(&self, qubits: &[usize]) -> HashMap<String, usize> {
+        let mut marginal = HashMap::new();
+
+        for (bitstring, &count) in &self.counts {
+            let bits = bitstring.as_bytes();
+            let width = bits.len();
+
+            let key: String = qubits.iter()
+                .rev()
+                .map(|&qubit| {
+                    if qubit >= width {
+                        panic!("Qubit index {} is out of bounds for {}-bit outcomes", qubit, width);
+                    }
+                    bits[width - 1 - qubit] as char
+                })
+                .collect();
+
+            *marginal.entry(key).or_insert(0) += count;
+        }
+
+        marginal
+    }
+
+    /// Returns the fraction of shots whose outcome matches a bit pattern
+    ///
+    /// The pattern is written like the count keys (qubit 0 is the rightmost character)
+    /// and may contain `*` as a wildcard matching either `0` or `1`.
+    ///
+    /// # Arguments
+    /// * `pattern` - The bit pattern to match, e.g. `"1*0"`
+    ///
+    /// # Panics
+    /// Panics if the pattern contains characters other than `0`, `1` and `*`,
+    /// or if its length differs from the measured bitstrings
+    ///
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// use intrico::simulator::Simulator;
+    ///
+    /// let mut qc = QuantumCircuit::new(3);
+    /// qc.x(2);
+    /// qc.h(1);
+    ///
+    /// let result = Simulator::new().with_circuit(qc).run(100);
+    /// assert_eq!(result.probability_of("1*0"), 1.0);
+    /// assert_eq!(result.probability_of("0**"), 0.0);
+    /// ```
+    pub fn probability_of(&self, pattern: &str) -> f64 {
+        if let Some(c) = pattern.chars().find(|c| !matches!(c, '0' | '1' | '*')) {
+            panic!("Invalid character '{}' in pattern \"{}\"", c, pattern);
+        }
+
+        if self.shots == 0 {
+            return 0.0;
+        }
+
+        let matching: usize = self.counts.iter()
+            .filter(|(bitstring, _)| {
+                if bitstring.len() != pattern.len() {
+                    panic!("Pattern \"{}\" does not match the {}-bit outcomes",
+                           pattern, bitstring.len());
+                }
+                bitstring.chars()
+                    .zip(pattern.chars())
+                    .all(|(bit, p)| p == '*' || bit == p)
+            })
+            .map(|(_, &count)| count)
+            .sum();
+
+        matching as f64 / self.shots as f64
+    }
+
+    /// Returns the most frequently observed bitstring and its count
+    ///
+    /// Ties are broken in favour of the lexicographically smallest bitstring.
+    ///
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// use intrico::simulator::Simulator;
+    ///
+    /// let mut qc = QuantumCircuit::new(2);
+    /// qc.x(0);
+    ///
+    /// let result = Simulator::new().with_circuit(qc).run(100);
+    /// assert_eq!(result.most_frequent(), Some(("01".to_string(), 100)));
+    /// ```
+    pub fn most_frequent(&self) -> Option<(String, usize)> {
+        self.top_k(1).into_iter().next()
+    }
+
+    /// Returns the `k` most frequent bitstrings with their counts, most frequent first
+    ///
+    /// # Arguments
+    /// * `k` - The maximum number of outcomes to return
+    ///
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// use intrico::simulator::Simulator;
+    ///
+    /// let mut qc = QuantumCircuit::new(2);
+    /// qc.h(0);
+    ///
+    /// let result = Simulator::new().with_circuit(qc).run(100);
+    /// assert_eq!(result.top_k(5).len(), 2);
+    /// ```
+    pub fn top_k(&self, k: usize) -> Vec<(String, usize)> {
+        let mut outcomes: Vec<(String, usize)> = self.counts.iter()
+            .map(|(bitstring, &count)| (bitstring.clone(), count))
+            .collect();
+        outcomes.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        outcomes.truncate(k);
+        outcomes
+    }
+
+    /// Returns the fraction of shots that produced exactly the given bitstring
+    ///
+    /// Use [`SimulationResult::probability_of`] for patterns with wildcards.
+    ///
+    /// # Arguments
+    /// * `bitstring` - The outcome to look up, e.g. `"01"`
+    ///
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// use intrico::simulator::Simulator;
+    ///
+    /// let mut qc = QuantumCircuit::new(2);
+    /// qc.x(1);
+    ///
+    /// let result = Simulator::new().with_circuit(qc).run(100);
+    /// assert_eq!(result.probability("10"), 1.0);
+    /// assert_eq!(result.probability("01"), 0.0);
+    /// ```
+    pub fn probability(&self, bitstring: &str) -> f64 {
+        if self.shots == 0 {
+            return 0.0;
+        }
+        *self.counts.get(bitstring).unwrap_or(&0) as f64 / self.shots as f64
+    }
+
+    /// Returns the sampled expectation value of the product of Pauli-Z on the given qubits
+    ///
+    /// Each outcome contributes +1 if an even number of the selected qubits were measured
+    /// as `1`, and -1 otherwise.
+    ///
+    /// # Arguments
+    /// * `qubits` - The qubits the Z operators act on
+    ///
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// use intrico::simulator::Simulator;
+    ///
+    /// let mut qc = QuantumCircuit::new(2);
+    /// qc.h(0);
+    /// qc.cnot(0, 1);
+    ///
+    /// let result = Simulator::new().with_circuit(qc).run(100);
+    /// // Bell state outcomes are perfectly correlated
+    /// assert_eq!(result.expectation_z(&[0, 1]), 1.0);
+    /// ```
+    pub fn expectation_z(&self, qubits: &[usize]) -> f64 {
+        if self.shots == 0 {
+            return 0.0;
+        }
+
+        let total: i64 = self.marginal_counts(qubits)
+            .iter()
+            .map(|(bits, &count)| {
+                let ones = bits.chars().filter(|&c| c == '1').count();
+                if ones % 2 == 0 { count as i64 } else { -(count as i64) }
+            })
+            .sum();
+
+        total as f64 / self.shots as f64
+    }
+
+    /// Returns the sampled expectation value of a Pauli string
+    ///
+    /// The counts are read as if every qubit the string acts on was measured in the
+    /// basis of its Pauli operator, for instance with
+    /// [`QuantumCircuit::measure_in_basis`](crate::QuantumCircuit::measure_in_basis),
+    /// which reduces the estimate to the Z parity of those qubits.
+    ///
+    /// # Arguments
+    /// * `pauli` - The Pauli string, with the rightmost character acting on qubit 0
+    ///
+    /// # Panics
+    /// Panics if the string contains characters other than `I`, `X`, `Y` and `Z`
+    ///
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// use intrico::circuit::Basis;
+    /// use intrico::simulator::Simulator;
+    ///
+    /// let mut qc = QuantumCircuit::new(2);
+    /// qc.h(0);
+    /// qc.cnot(0, 1);
+    /// qc.measure_in_basis(0, Basis::X, 0);
+    /// qc.measure_in_basis(1, Basis::X, 1);
+    ///
+    /// let result = Simulator::new().with_circuit(qc).run(100);
+    /// assert_eq!(result.pauli_expectation("XX"), 1.0);
+    /// ```
+    pub fn pauli_expectation(&self, pauli: &str) -> f64 {
+        if let Some(c) = pauli.chars().find(|c| !matches!(c, 'I' | 'X' | 'Y' | 'Z')) {
+            panic!("Invalid Pauli operator '{}' in '{}'", c, pauli);
+        }
+
+        let qubits: Vec<usize> = pauli.chars()
+            .rev()
+            .enumerate()
+            .filter(|&(_, p)| p != 'I')
+            .map(|(qubit, _)| qubit)
+            .collect();
+        self.expectation_z(&qubits)
+    }
+
+    /// Returns the exact expectation value of an observable in the final state
+    ///
+    /// # Arguments
+    /// * `observable` - The observable, such as a matrix or a sum of Pauli strings
+    ///
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// use intrico::observables::PauliSum;
+    /// use intrico::simulator::Simulator;
+    ///
+    /// let mut qc = QuantumCircuit::new(2);
+    /// qc.h(0);
+    /// qc.cnot(0, 1);
+    ///
+    /// let result = Simulator::new().with_circuit(qc).run(0);
+    /// let hamiltonian: PauliSum = "0.5*ZZ + 0.3*XI".parse().unwrap();
+    /// assert!((result.expectation(&hamiltonian) - 0.5).abs() < 1e-6);
+    /// ```
+    pub fn expectation<O: Observable + ?Sized>(&self, observable: &O) -> f64 {
+        observable.expectation(&self.final_state)
+    }
+
+    /// Returns the total variation distance between the count distributions of two results
+    ///
+    /// The distance is half the L1 distance between the empirical probabilities, ranging
+    /// from 0.0 for identical distributions to 1.0 for disjoint ones.
+    ///
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// use intrico::simulator::Simulator;
+    ///
+    /// let mut zero = QuantumCircuit::new(1);
+    /// let mut one = QuantumCircuit::new(1);
+    /// one.x(0);
+    ///
+    /// let a = Simulator::new().with_circuit(zero).run(100);
+    /// let b = Simulator::new().with_circuit(one).run(100);
+    /// assert_eq!(a.total_variation_distance(&b), 1.0);
+    /// ```
+    pub fn total_variation_distance(&self, other: &SimulationResult) -> f64 {
+        let mut outcomes: Vec<&String> = self.counts.keys().chain(other.counts.keys()).collect();
+        outcomes.sort();
+        outcomes.dedup();
+
+        0.5 * outcomes.iter()
+            .map(|bitstring| (self.probability(bitstring) - other.probability(bitstring)).abs())
+            .sum::<f64>()
+    }
+
+    /// Returns the Hellinger fidelity between the count distributions of two results
+    ///
+    /// The fidelity is `(Σ √(pᵢ qᵢ))²` over all outcomes, ranging from 0.0 for disjoint
+    /// distributions to 1.0 for identical ones. It is commonly used to compare noisy
+    /// runs against ideal ones.
+    ///
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// use intrico::simulator::Simulator;
+    ///
+    /// let mut qc = QuantumCircuit::new(1);
+    /// qc.x(0);
+    ///
+    /// let sim = Simulator::new().with_circuit(qc);
+    /// let (a, b) = (sim.run(100), sim.run(100));
+    /// assert!((a.hellinger_fidelity(&b) - 1.0).abs() < 1e-12);
+    /// ```
+    pub fn hellinger_fidelity(&self, other: &SimulationResult) -> f64 {
+        let overlap: f64 = self.counts.keys()
+            .map(|bitstring| (self.probability(bitstring) * other.probability(bitstring)).sqrt())
+            .sum();
+        overlap * overlap
+    }
+
+    /// Returns the fidelity between the final state and a target statevector
+    ///
+    /// # Arguments
+    /// * `target` - The expected statevector, e.g. an analytically derived state
+    ///
+    /// # Panics
+    /// Panics if the target dimension differs from the final state
+    ///
+    /// # Examples
+    /// ```
+    /// use rusticle::complex::Complex;
+    /// use intrico::QuantumCircuit;
+    /// use intrico::simulator::Simulator;
+    ///
+    /// let mut qc = QuantumCircuit::new(2);
+    /// qc.h(0);
+    /// qc.cnot(0, 1);
+    ///
+    /// let h = 1.0 / 2.0_f64.sqrt();
+    /// let zero = Complex::new(0.0, 0.0);
+    /// let bell = [Complex::new(h, 0.0), zero, zero, Complex::new(h, 0.0)];
+    ///
+    /// let result = Simulator::new().with_circuit(qc).run(10);
+    /// assert!((result.state_fidelity(&bell) - 1.0).abs() < 1e-6);
+    /// ```
+    pub fn state_fidelity(&self, target: &[Complex]) -> f64 {
+        state_fidelity(&self.final_state, target)
+    }
+
+    /// Writes the final state to disk so it can be reused as an initial state later
+    ///
+    /// # Arguments
+    /// * `path` - The file to write
+    /// * `format` - The file format to use
+    ///
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// use intrico::simulator::{Simulator, StateFormat, load_state};
+    ///
+    /// let mut qc = QuantumCircuit::new(2);
+    /// qc.h(0);
+    /// qc.cnot(0, 1);
+    ///
+    /// let path = std::env::temp_dir().join("intrico_doc_bell.json");
+    /// let result = Simulator::new().with_circuit(qc).run(10);
+    /// result.save_state(&path, StateFormat::Json).unwrap();
+    ///
+    /// assert_eq!(load_state(&path, StateFormat::Json).unwrap(), result.final_state);
+    /// # std::fs::remove_file(&path).unwrap();
+    /// ```
+    pub fn save_state<P: AsRef<Path>>(&self, path: P, format: StateFormat) -> io::Result<()> {
+        save_state(path, &self.final_state, format)
+    }
+
+    /// Returns the counts as a CSV table with `bitstring,count,probability` columns
+    ///
+    /// Rows are sorted by bitstring so exports are stable across runs.
+    ///
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// use intrico::simulator::Simulator;
+    ///
+    /// let mut qc = QuantumCircuit::new(1);
+    /// qc.x(0);
+    ///
+    /// let result = Simulator::new().with_circuit(qc).run(10);
+    /// assert_eq!(result.to_csv(), "bitstring,count,probability\n1,10,1\n");
+    /// ```
+    pub fn to_csv(&self) -> String {
+        let mut rows: Vec<(&String, &usize)> = self.counts.iter().collect();
+        rows.sort();
+
+        let mut csv = String::from("bitstring,count,probability\n");
+        for (bitstring, &count) in rows {
+            let probability = if self.shots == 0 { 0.0 } else { count as f64 / self.shots as f64 };
+            csv.push_str(&format!("{},{},{}\n", bitstring, count, probability));
+        }
+        csv
+    }
+
+    /// Serializes the full result to a JSON string
+    ///
+    /// Complex amplitudes are written as `[real, imag]` pairs.
+    ///
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// use intrico::simulator::{Simulator, SimulationResult};
+    ///
+    /// let mut qc = QuantumCircuit::new(1);
+    /// qc.h(0);
+    ///
+    /// let result = Simulator::new().with_circuit(qc).run(10);
+    /// let json = result.to_json().unwrap();
+    /// let restored = SimulationResult::from_json(&json).unwrap();
+    /// assert_eq!(restored.counts, result.counts);
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(self)
+    }
+
+    /// Deserializes a result previously produced by [`SimulationResult::to_json`]
+    #[cfg(feature = "serde")]
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+}
+
+impl fmt::Display for SimulationResult {
+    /// Renders the counts as a histogram sorted by bitstring, followed by the final state
+    /// 
+    /// The formatter precision sets the number of decimals of the amplitudes, four by default.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Simulation Result ({} shots):", self.shots)?;
+
+        let mut rows: Vec<(&String, &usize)> = self.counts.iter().collect();
+        rows.sort();
+
+        let max_count = rows.iter().map(|&(_, &count)| count).max().unwrap_or(0);
+        for (bitstring, &count) in rows {
+            let bar_len = (count * HISTOGRAM_WIDTH).checked_div(max_count).unwrap_or(0);
+            let probability = if self.shots == 0 { 0.0 } else { count as f64 / self.shots as f64 };
+            writeln!(f, "  {} │{:<width$}│ {} ({:.3})",
+                     bitstring, "█".repeat(bar_len), count, probability, width = HISTOGRAM_WIDTH)?;
+        }
+
+        if !self.final_state.is_empty() {
+            let format = ComplexFormat::DEFAULT
+                .with_precision(f.precision().unwrap_or(ComplexFormat::DEFAULT.precision()))
+                .with_max_terms(Some(STATE_TERMS));
+            writeln!(f, "Final state: {}", format.format_state(&self.final_state))?;
+        }
+        Ok(())
+    }
+}
+
+impl Index<&str> for SimulationResult {
+    type Output = usize;
+
+    /// Returns the count of a bitstring, 0 if it was never observed
+    ///
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// use intrico::simulator::Simulator;
+    ///
+    /// let mut qc = QuantumCircuit::new(2);
+    /// qc.x(0);
+    ///
+    /// let result = Simulator::new().with_circuit(qc).run(100);
+    /// assert_eq!(result["01"], 100);
+    /// assert_eq!(result["10"], 0);
+    /// ```
+    fn index(&self, bitstring: &str) -> &usize {
+        self.counts.get(bitstring).unwrap_or(&0)
+    }
+}
+
+impl IntoIterator for SimulationResult {
+    type Item = (String, usize);
+    type IntoIter = vec::IntoIter<(String, usize)>;
+
+    /// Consumes the result into its counts, most frequent first
+    ///
+    /// Ties are broken in favour of the lexicographically smallest bitstring.
+    fn into_iter(self) -> Self::IntoIter {
+        let mut outcomes: Vec<(String, usize)> = self.counts.into_iter().collect();
+        outcomes.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        outcomes.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a SimulationResult {
+    type Item = (&'a str, usize);
+    type IntoIter = vec::IntoIter<(&'a str, usize)>;
+
+    /// Iterates over the counts, most frequent first
+    ///
+    /// Ties are broken in favour of the lexicographically smallest bitstring.
+    ///
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// use intrico::simulator::Simulator;
+    ///
+    /// let mut qc = QuantumCircuit::new(2);
+    /// qc.x(1);
+    ///
+    /// let result = Simulator::new().with_circuit(qc).run(100);
+    /// for (bitstring, count) in &result {
+    ///     assert_eq!((bitstring, count), ("10", 100));
+    /// }
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        let mut outcomes: Vec<(&str, usize)> = self.counts.iter()
+            .map(|(bitstring, &count)| (bitstring.as_str(), count))
+            .collect();
+        outcomes.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+        outcomes.into_iter()
+    }
+}
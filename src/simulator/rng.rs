@@ -0,0 +1,97 @@
+use std::fmt;
+#[cfg(not(feature = "os-rng"))]
+use std::sync::OnceLock;
+#[cfg(not(feature = "os-rng"))]
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use rand::{RngCore, SeedableRng, rngs::StdRng};
+
+/// A random number generator shared by all runs of a simulator
+/// 
+/// Runs using the same generator draw from it one after another, so a sequence of
+/// runs is reproducible when the generator is seeded. Runs executed concurrently,
+/// e.g. through [`Simulator::run_batch`](super::Simulator::run_batch), take turns in
+/// an unspecified order.
+/// 
+/// # Examples
+/// ```
+/// use rand::{SeedableRng, rngs::StdRng};
+/// use intrico::simulator::SharedRng;
+/// 
+/// let rng = SharedRng::new(StdRng::seed_from_u64(7));
+/// let same = SharedRng::seeded(7);
+/// ```
+#[derive(Clone)]
+pub struct SharedRng(Arc<Mutex<dyn RngCore + Send>>);
+
+impl SharedRng {
+    /// Wraps a user-provided generator, e.g. a counter-based or hardware RNG
+    pub fn new<R: RngCore + Send + 'static>(rng: R) -> Self {
+        SharedRng(Arc::new(Mutex::new(rng)))
+    }
+
+    /// Creates the standard generator seeded with the given value
+    pub fn seeded(seed: u64) -> Self {
+        Self::new(StdRng::seed_from_u64(seed))
+    }
+
+    /// Locks the generator for the duration of a run
+    pub fn lock(&self) -> MutexGuard<'_, dyn RngCore + Send + 'static> {
+        // A panic in another run cannot leave the generator in an invalid state
+        self.0.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl fmt::Debug for SharedRng {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SharedRng")
+    }
+}
+
+/// Returns a seed for runs that have no generator of their own
+/// 
+/// Drawn from the operating system's entropy through the `os-rng` feature. Without it,
+/// seeds follow a sequence of well-mixed values starting from an offset: with the
+/// `wasm` feature on `wasm32` the offset comes from the `Math.random` generator of
+/// the JavaScript runtime, so every page load draws different shots. On other targets
+/// without `os-rng` the offset is fixed, so unseeded runs differ from each other but
+/// repeat across processes; seed the simulator for reproducibility either way.
+#[cfg(feature = "os-rng")]
+pub(crate) fn fresh_seed() -> u64 {
+    use rand::Rng;
+    rand::rng().random()
+}
+
+/// Returns the next seed of a well-mixed sequence when the OS entropy source is not used
+#[cfg(not(feature = "os-rng"))]
+pub(crate) fn fresh_seed() -> u64 {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    static OFFSET: OnceLock<u64> = OnceLock::new();
+
+    // SplitMix64 finalizer over a Weyl sequence
+    let mut z = COUNTER.fetch_add(0x9E37_79B9_7F4A_7C15, Ordering::Relaxed)
+        .wrapping_add(*OFFSET.get_or_init(entropy));
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+#[cfg(all(not(feature = "os-rng"), feature = "wasm", target_arch = "wasm32"))]
+#[wasm_bindgen::prelude::wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = Math)]
+    fn random() -> f64;
+}
+
+/// Returns 64 bits drawn from the JavaScript runtime
+#[cfg(all(not(feature = "os-rng"), feature = "wasm", target_arch = "wasm32"))]
+fn entropy() -> u64 {
+    let word = || (random() * 4_294_967_296.0) as u64;
+    word() << 32 | word()
+}
+
+/// Returns the fixed offset of targets without an entropy source
+#[cfg(all(not(feature = "os-rng"), not(all(feature = "wasm", target_arch = "wasm32"))))]
+fn entropy() -> u64 {
+    0
+}
@@ -0,0 +1,97 @@
+use rand::Rng;
+
+/// Shot count from which [`SamplingMode::Auto`] switches to multinomial sampling
+pub const AUTO_MULTINOMIAL_SHOTS: usize = 100_000;
+
+/// Strategy used to draw measurement outcomes from a statevector
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SamplingMode {
+    /// Per-shot sampling for small shot counts, multinomial sampling from
+    /// [`AUTO_MULTINOMIAL_SHOTS`] shots on
+    #[default]
+    Auto,
+    /// Draws every shot individually, in time proportional to the number of shots
+    PerShot,
+    /// Draws the count of every basis state directly, in time proportional to the
+    /// dimension of the statevector regardless of the number of shots
+    Multinomial,
+}
+
+impl SamplingMode {
+    /// Returns true if `shots` shots should be drawn with multinomial sampling
+    pub(crate) fn use_multinomial(self, shots: usize) -> bool {
+        match self {
+            SamplingMode::Auto => shots >= AUTO_MULTINOMIAL_SHOTS,
+            SamplingMode::PerShot => false,
+            SamplingMode::Multinomial => true,
+        }
+    }
+}
+
+/// Distributes `shots` over the outcomes with the given probabilities
+/// 
+/// Uses a chain of conditional binomial draws, so the returned counts always sum to `shots`.
+pub(crate) fn multinomial<R: Rng + ?Sized>(rng: &mut R, shots: usize, probabilities: &[f64]) -> Vec<usize> {
+    let mut remaining_shots = shots;
+    let mut remaining_probability: f64 = probabilities.iter().sum();
+    let mut counts = vec![0; probabilities.len()];
+
+    for (count, &p) in counts.iter_mut().zip(probabilities) {
+        if remaining_shots == 0 || remaining_probability <= 0.0 {
+            break;
+        }
+        *count = binomial(rng, remaining_shots, (p / remaining_probability).min(1.0));
+        remaining_shots -= *count;
+        remaining_probability -= p;
+    }
+
+    // Floating point leftovers go to the last outcome with non-zero probability
+    if let Some(last) = probabilities.iter().rposition(|&p| p > 0.0) {
+        counts[last] += remaining_shots;
+    }
+    counts
+}
+
+/// Draws the number of successes out of `n` trials with success probability `p`
+/// 
+/// Small draws are exact; when both `np` and `n(1-p)` are large, the normal
+/// approximation with continuity correction is used.
+pub(crate) fn binomial<R: Rng + ?Sized>(rng: &mut R, n: usize, p: f64) -> usize {
+    if n == 0 || p <= 0.0 {
+        return 0;
+    }
+    if p >= 1.0 {
+        return n;
+    }
+    // Sample the rarer event and mirror the result
+    if p > 0.5 {
+        return n - binomial(rng, n, 1.0 - p);
+    }
+
+    let mean = n as f64 * p;
+    if n < 64 {
+        (0..n).filter(|_| rng.random::<f64>() < p).count()
+    } else if mean < 30.0 {
+        // Inversion: walk the probability mass function from zero
+        let ratio = p / (1.0 - p);
+        let mut pmf = (1.0 - p).powi(n as i32);
+        let mut draw = rng.random::<f64>();
+        let mut k = 0;
+        while draw > pmf && k < n {
+            draw -= pmf;
+            pmf *= ratio * (n - k) as f64 / (k + 1) as f64;
+            k += 1;
+        }
+        k
+    } else {
+        let std_dev = (mean * (1.0 - p)).sqrt();
+        (mean + std_dev * standard_normal(rng) + 0.5).floor().clamp(0.0, n as f64) as usize
+    }
+}
+
+/// Draws a standard normal variate with the Box-Muller transform
+fn standard_normal<R: Rng + ?Sized>(rng: &mut R) -> f64 {
+    let u1 = 1.0 - rng.random::<f64>();
+    let u2 = rng.random::<f64>();
+    (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+}
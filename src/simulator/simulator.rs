@@ -1,52 +1,36 @@
+use std::{borrow::Cow, collections::HashMap, sync::Arc, thread};
 use rusticle::complex::Complex;
-use rand::{distr::weighted::WeightedIndex, prelude::*, rng};
 
 use crate::QuantumCircuit;
-
-/// Represents the available simulation backends
-#[derive(Debug, Clone, PartialEq)]
-pub enum Backend {
-    /// Statevector simulation backend
-    StateVector,
-}
-
-impl Default for Backend {
-    fn default() -> Self {
-        Backend::StateVector
-    }
-}
-
-/// Simulator result that stores all the necessary counts
-/// and states after running the simulation
-#[derive(Debug)]
-pub struct SimulationResult {
-    /// Number of shots executed
-    pub shots: usize,
-    /// Final state of the qubits after simulation
-    pub final_state: Vec<Complex>,
-    /// Measurement counts for each basis state
-    pub counts: std::collections::HashMap<String, usize>,
-}
+use crate::circuit::ParameterizedCircuit;
+use crate::transpiler::{transpile, TranspileOptions};
+use crate::utility::Tolerance;
+use crate::utility::{index_to_bitstring, Endianness};
+use crate::trace::span;
+use super::task::BlockingTask;
+use super::{CancellationToken, NoiseModel, Observable, Progress, SamplingMode, SharedRng, SimulationError, SimulationResult, SimulatorBackend, SimulatorConfig, StateVectorBackend};
 
 /// A quantum circuit simulator that executes quantum circuits
 /// using various simulation backends
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Simulator {
     /// Name of the simulator
     pub name: String,
     /// The simulation backend to use
-    pub backend: Backend,
+    pub backend: Arc<dyn SimulatorBackend>,
     /// Quantum circuit
     pub circuit: Option<QuantumCircuit>,
-    
+    /// Options passed to the backend on every run
+    pub config: SimulatorConfig,
 }
 
 impl Default for Simulator {
     fn default() -> Self {
         Simulator {
             name: "Simulator".to_string(),
-            backend: Backend::default(),
+            backend: Arc::new(StateVectorBackend::new()),
             circuit: None,
+            config: SimulatorConfig::default(),
         }
     }
 }
@@ -68,15 +52,14 @@ impl Simulator {
     /// 
     /// # Examples
     /// ```
-    /// use intrico::simulator::{Simulator, Backend};
+    /// use intrico::simulator::{Simulator, StateVectorBackend};
     /// 
-    /// let sim = Simulator::with_backend(Backend::StateVector);
+    /// let sim = Simulator::with_backend(StateVectorBackend::new());
     /// ```
-    pub fn with_backend(backend: Backend) -> Self {
+    pub fn with_backend<B: SimulatorBackend + 'static>(backend: B) -> Self {
         Simulator {
-            name: "Simulator".to_string(),
-            backend,
-            circuit: None,
+            backend: Arc::new(backend),
+            ..Self::default()
         }
     }
 
@@ -111,6 +94,28 @@ impl Simulator {
         self
     }
 
+    /// Enables or disables entanglement diagnostics in the simulation result
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// use intrico::simulator::Simulator;
+    /// 
+    /// let mut qc = QuantumCircuit::new(2);
+    /// qc.h(0);
+    /// qc.cnot(0, 1);
+    /// 
+    /// let result = Simulator::new()
+    ///     .with_circuit(qc)
+    ///     .with_entanglement_diagnostics(true)
+    ///     .run(10);
+    /// assert!(result.entanglement.unwrap().is_entangled());
+    /// ```
+    pub fn with_entanglement_diagnostics(mut self, enabled: bool) -> Self {
+        self.config.entanglement_diagnostics = enabled;
+        self
+    }
+
     /// Run the simulator with the specified number of shots
     /// 
     /// # Examples
@@ -127,27 +132,512 @@ impl Simulator {
     /// let result = sim.run(1000);
     /// ```
     pub fn run(&self, shots: usize) -> SimulationResult {
-        let circuit = self.circuit.as_ref()
-            .expect("No circuit provided to simulator. Use with_circuit() or set_circuit() to add a circuit.");
-        
-        let final_state = circuit.execute();
+        self.run_circuit(self.circuit(), shots)
+    }
+
+    /// Run the simulator, returning an error instead of panicking on failure
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// use intrico::simulator::{Simulator, SimulationError};
+    /// 
+    /// let sim = Simulator::new()
+    ///     .with_circuit(QuantumCircuit::new(40));
+    /// 
+    /// match sim.try_run(10) {
+    ///     Err(SimulationError::StateTooLarge { qubits, .. }) => assert_eq!(qubits, 40),
+    ///     _ => unreachable!(),
+    /// }
+    /// ```
+    pub fn try_run(&self, shots: usize) -> Result<SimulationResult, SimulationError> {
+        self.execute(self.circuit(), shots, &mut |_| {})
+    }
+
+    /// Run the simulator while reporting progress to a callback
+    /// 
+    /// The callback is invoked after every applied gate and periodically while sampling
+    /// shots. If a cancellation token is attached to the simulator, it is checked between
+    /// gates and between shots, and the run stops with [`SimulationError::Cancelled`]
+    /// as soon as cancellation is requested.
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// use intrico::simulator::{Simulator, ProgressStage};
+    /// 
+    /// let mut qc = QuantumCircuit::new(2);
+    /// qc.h(0);
+    /// qc.cnot(0, 1);
+    /// 
+    /// let mut gates_done = 0;
+    /// let result = Simulator::new()
+    ///     .with_circuit(qc)
+    ///     .run_with_callback(100, |progress| {
+    ///         if progress.stage == ProgressStage::Gates {
+    ///             gates_done = progress.completed;
+    ///         }
+    ///     })
+    ///     .unwrap();
+    /// 
+    /// assert_eq!(gates_done, 2);
+    /// assert_eq!(result.shots, 100);
+    /// ```
+    pub fn run_with_callback<F: FnMut(Progress)>(&self, shots: usize, mut callback: F) -> Result<SimulationResult, SimulationError> {
+        self.execute(self.circuit(), shots, &mut callback)
+    }
+
+    /// Run the simulator on a background thread and return a future for the result
+    /// 
+    /// The future is runtime-agnostic: the simulation runs on a dedicated thread, so
+    /// awaiting it never blocks the executor of the calling task. The simulator is
+    /// cloned, so later changes to it do not affect the running simulation.
+    /// 
+    /// # Panics
+    /// The future panics if the simulation itself panics, e.g. when no circuit is set
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// use intrico::simulator::Simulator;
+    /// 
+    /// async fn bell_counts() -> usize {
+    ///     let mut qc = QuantumCircuit::new(2);
+    ///     qc.h(0);
+    ///     qc.cnot(0, 1);
+    /// 
+    ///     let result = Simulator::new().with_circuit(qc).run_async(1000).await;
+    ///     result.counts.len()
+    /// }
+    /// ```
+    pub fn run_async(&self, shots: usize) -> impl Future<Output = SimulationResult> + Send + 'static {
+        let simulator = self.clone();
+        BlockingTask::spawn(move || simulator.run(shots))
+    }
+
+    /// Limits the number of qubits the simulator accepts
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// use intrico::simulator::Simulator;
+    /// 
+    /// let sim = Simulator::new()
+    ///     .with_circuit(QuantumCircuit::new(3))
+    ///     .with_max_qubits(2);
+    /// assert!(sim.try_run(10).is_err());
+    /// ```
+    pub fn with_max_qubits(mut self, max_qubits: usize) -> Self {
+        self.config.max_qubits = Some(max_qubits);
+        self
+    }
+
+    /// Limits the number of bytes the statevector may occupy
+    /// 
+    /// Defaults to [`DEFAULT_MEMORY_LIMIT`](super::DEFAULT_MEMORY_LIMIT).
+    pub fn with_memory_limit(mut self, bytes: usize) -> Self {
+        self.config.memory_limit = Some(bytes);
+        self
+    }
+
+    /// Starts every simulation from the given statevector instead of |0...0⟩
+    /// 
+    /// This allows a long circuit to be simulated in segments, feeding the final state
+    /// of one run into the next. The state must have `2^n` amplitudes for an `n`-qubit
+    /// circuit and be normalized.
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// use intrico::simulator::Simulator;
+    /// 
+    /// let mut prepare = QuantumCircuit::new(2);
+    /// prepare.h(0);
+    /// let first = Simulator::new().with_circuit(prepare).run(10);
+    /// 
+    /// let mut entangle = QuantumCircuit::new(2);
+    /// entangle.cnot(0, 1);
+    /// let second = Simulator::new()
+    ///     .with_circuit(entangle)
+    ///     .with_initial_state(first.final_state)
+    ///     .run(100);
+    /// 
+    /// assert_eq!(second.probability("01") + second.probability("10"), 0.0);
+    /// ```
+    pub fn with_initial_state(mut self, state: Vec<Complex>) -> Self {
+        self.config.initial_state = Some(state);
+        self
+    }
+
+    /// Attaches a cancellation token that is checked between gates and shots
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// use intrico::simulator::{Simulator, SimulationError, CancellationToken};
+    /// 
+    /// let token = CancellationToken::new();
+    /// let sim = Simulator::new()
+    ///     .with_circuit(QuantumCircuit::new(1))
+    ///     .with_cancellation_token(token.clone());
+    /// 
+    /// token.cancel();
+    /// assert_eq!(sim.run_with_callback(10, |_| {}).unwrap_err(), SimulationError::Cancelled);
+    /// ```
+    pub fn with_cancellation_token(mut self, token: CancellationToken) -> Self {
+        self.config.cancellation = Some(token);
+        self
+    }
+
+    /// Simulates the circuit under the given stochastic noise model
+    /// 
+    /// Noisy runs use shot-branching: the evolution is shared between all shots and
+    /// only forks when an error actually occurs, so the cost grows with the number of
+    /// distinct error events rather than with the number of shots. The reported
+    /// `final_state` and snapshots are those of the noiseless evolution. Channels attached
+    /// to individual operations with [`QuantumCircuit::add_noise`] apply on top of the model.
+    pub fn with_noise(mut self, noise: NoiseModel) -> Self {
+        self.config.noise = Some(noise);
+        self
+    }
+
+    /// Selects how measurement outcomes are drawn from the final state
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// use intrico::simulator::{SamplingMode, Simulator};
+    /// 
+    /// let mut qc = QuantumCircuit::new(1);
+    /// qc.h(0);
+    /// 
+    /// // Ten million shots are drawn without sampling them one by one
+    /// let result = Simulator::new()
+    ///     .with_circuit(qc)
+    ///     .with_sampling(SamplingMode::Multinomial)
+    ///     .run(10_000_000);
+    /// assert!((result.probability("0") - 0.5).abs() < 0.01);
+    /// ```
+    pub fn with_sampling(mut self, mode: SamplingMode) -> Self {
+        self.config.sampling = mode;
+        self
+    }
+
+    /// Uses the given random number generator for sampling and noise
+    /// 
+    /// Accepts any [`rand::RngCore`] implementation, e.g. a counter-based generator for
+    /// reproducible distributed runs or a hardware random number generator.
+    /// 
+    /// # Examples
+    /// ```
+    /// use rand::{SeedableRng, rngs::StdRng};
+    /// use intrico::QuantumCircuit;
+    /// use intrico::simulator::Simulator;
+    /// 
+    /// let mut qc = QuantumCircuit::new(2);
+    /// qc.h(0);
+    /// qc.h(1);
+    /// 
+    /// let sim = Simulator::new().with_circuit(qc);
+    /// let first = sim.clone().with_rng(StdRng::seed_from_u64(42)).run(100);
+    /// let second = sim.with_rng(StdRng::seed_from_u64(42)).run(100);
+    /// assert_eq!(first.counts, second.counts);
+    /// ```
+    pub fn with_rng<R: rand::RngCore + Send + 'static>(mut self, rng: R) -> Self {
+        self.config.rng = Some(SharedRng::new(rng));
+        self
+    }
 
-        // Calculate probabilities
-        let probabilities: Vec<f64> = final_state.iter().map(|amp| amp.norm_squared()).collect();
+    /// Seeds the standard random number generator for reproducible runs
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.config.rng = Some(SharedRng::seeded(seed));
+        self
+    }
 
-        // Sample measurements
-        let dist = WeightedIndex::new(&probabilities).unwrap();
-        let mut rng = rng();
-        let mut counts = std::collections::HashMap::new();
+    /// Sets the rounding applied to the returned states, overriding the process-wide policy
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// use intrico::simulator::Simulator;
+    /// use intrico::utility::Tolerance;
+    /// 
+    /// let mut qc = QuantumCircuit::new(1);
+    /// qc.ry(0, 1e-6);
+    /// 
+    /// let result = Simulator::new().with_tolerance(Tolerance::EXACT).with_circuit(qc).run(0);
+    /// assert_eq!(result.final_state[1].real, (5e-7_f64).sin());
+    /// ```
+    pub fn with_tolerance(mut self, tolerance: Tolerance) -> Self {
+        self.config.tolerance = Some(tolerance);
+        self
+    }
 
-        let num_qubits = circuit.num_qubits();
-        for _ in 0..shots {
-            let idx = dist.sample(&mut rng);
-            let bitstring = format!("{:0width$b}", idx, width = num_qubits);
+    /// Shares a random number generator with other simulators
+    pub fn with_shared_rng(mut self, rng: SharedRng) -> Self {
+        self.config.rng = Some(rng);
+        self
+    }
 
-            *counts.entry(bitstring).or_insert(0) += 1;
+    /// Transpiles every circuit with the given options before it reaches the backend
+    /// 
+    /// This lets backends support only elementary gates: Toffoli gates are expanded and
+    /// single-qubit custom gates are synthesized from rotations, depending on the options.
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// use intrico::simulator::Simulator;
+    /// use intrico::transpiler::TranspileOptions;
+    /// 
+    /// let mut qc = QuantumCircuit::new(3);
+    /// qc.x(0);
+    /// qc.x(1);
+    /// qc.ccx(0, 1, 2);
+    /// 
+    /// let result = Simulator::new()
+    ///     .with_circuit(qc)
+    ///     .with_transpile(TranspileOptions::default())
+    ///     .run(100);
+    /// assert_eq!(result.counts["111"], 100);
+    /// ```
+    pub fn with_transpile(mut self, options: TranspileOptions) -> Self {
+        self.config.transpile = Some(options);
+        self
+    }
+
+    /// Runs several circuits with the same simulator configuration
+    /// 
+    /// The circuits are distributed over the available CPU cores and the results are
+    /// returned in the same order as the input circuits. The circuit attached to the
+    /// simulator, if any, is ignored.
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// use intrico::simulator::Simulator;
+    /// 
+    /// let circuits: Vec<QuantumCircuit> = (0..4)
+    ///     .map(|i| {
+    ///         let mut qc = QuantumCircuit::new(1);
+    ///         qc.ry(0, i as f64 * 0.5);
+    ///         qc
+    ///     })
+    ///     .collect();
+    /// 
+    /// let results = Simulator::new().run_batch(&circuits, 100);
+    /// assert_eq!(results.len(), 4);
+    /// assert_eq!(results[0].counts["0"], 100);
+    /// ```
+    pub fn run_batch(&self, circuits: &[QuantumCircuit], shots: usize) -> Vec<SimulationResult> {
+        if circuits.is_empty() {
+            return Vec::new();
+        }
+
+        let workers = thread::available_parallelism()
+            .map_or(1, |n| n.get())
+            .min(circuits.len());
+        // Without parallelism, e.g. on targets that cannot spawn threads, run in place
+        if workers == 1 {
+            return circuits.iter().map(|circuit| self.run_circuit(circuit, shots)).collect();
         }
+        let chunk_size = circuits.len().div_ceil(workers);
+
+        thread::scope(|scope| {
+            let handles: Vec<_> = circuits
+                .chunks(chunk_size)
+                .map(|chunk| scope.spawn(move || {
+                    chunk.iter()
+                        .map(|circuit| self.run_circuit(circuit, shots))
+                        .collect::<Vec<_>>()
+                }))
+                .collect();
+
+            handles.into_iter()
+                .flat_map(|handle| handle.join().expect("Simulation worker thread panicked"))
+                .collect()
+        })
+    }
+
+    /// Runs a parameterized circuit for every parameter set of a grid
+    /// 
+    /// The circuit structure is built once and only the rotation angles are rebound
+    /// for each point. The bound circuits are executed as one batch and the results
+    /// are returned together with their parameter values, in grid order.
+    /// 
+    /// # Arguments
+    /// * `circuit` - The parameterized circuit to run
+    /// * `parameter_grid` - The parameter values of every point of the sweep
+    /// * `shots` - The number of shots per point
+    /// 
+    /// # Panics
+    /// Panics if a parameter set does not match the number of parameters
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::circuit::ParameterizedCircuit;
+    /// use intrico::simulator::Simulator;
+    /// 
+    /// let mut ansatz = ParameterizedCircuit::new(1);
+    /// ansatz.rx(0, 0);
+    /// 
+    /// let grid: Vec<Vec<f64>> = vec![vec![0.0], vec![std::f64::consts::PI]];
+    /// let sweep = Simulator::new().sweep(&ansatz, &grid, 100);
+    /// 
+    /// assert_eq!(sweep[0].0, vec![0.0]);
+    /// assert_eq!(sweep[0].1.counts["0"], 100);
+    /// assert_eq!(sweep[1].1.counts["1"], 100);
+    /// ```
+    pub fn sweep(&self, circuit: &ParameterizedCircuit, parameter_grid: &[Vec<f64>], shots: usize) -> Vec<(Vec<f64>, SimulationResult)> {
+        let circuits: Vec<QuantumCircuit> = parameter_grid.iter()
+            .map(|params| circuit.bind(params))
+            .collect();
+
+        parameter_grid.iter()
+            .cloned()
+            .zip(self.run_batch(&circuits, shots))
+            .collect()
+    }
+
+    /// Computes the gradient of an expectation value with the parameter-shift rule
+    /// 
+    /// Every parameterized rotation is evaluated at `θ ± π/2`, giving two exact
+    /// simulations per rotation which are executed as one batch. Parameters shared by
+    /// several rotations accumulate the contributions of each of them.
+    /// 
+    /// # Arguments
+    /// * `circuit` - The parameterized circuit preparing the state
+    /// * `observable` - The observable whose expectation value is differentiated
+    /// * `params` - The parameter values at which the gradient is evaluated
+    /// 
+    /// # Panics
+    /// Panics if the number of values does not match the number of parameters
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumGate;
+    /// use intrico::circuit::ParameterizedCircuit;
+    /// use intrico::simulator::Simulator;
+    /// 
+    /// // ⟨Z⟩ = cos(θ) after Ry(θ), so the derivative is -sin(θ)
+    /// let mut ansatz = ParameterizedCircuit::new(1);
+    /// ansatz.ry(0, 0);
+    /// 
+    /// let gradient = Simulator::new().gradient(&ansatz, &QuantumGate::Z.matrix(), &[0.4]);
+    /// assert!((gradient[0] + 0.4_f64.sin()).abs() < 1e-6);
+    /// ```
+    pub fn gradient<O: Observable + ?Sized>(&self, circuit: &ParameterizedCircuit, observable: &O, params: &[f64]) -> Vec<f64> {
+        if params.len() != circuit.num_parameters() {
+            panic!("Expected {} parameter values, got {}", circuit.num_parameters(), params.len());
+        }
+
+        let shift = std::f64::consts::FRAC_PI_2;
+        let circuits: Vec<QuantumCircuit> = (0..circuit.num_rotations())
+            .flat_map(|rotation| [
+                circuit.bind_shifted(params, Some((rotation, shift))),
+                circuit.bind_shifted(params, Some((rotation, -shift))),
+            ])
+            .collect();
+
+        let expectations: Vec<f64> = self.run_batch(&circuits, 0)
+            .iter()
+            .map(|result| observable.expectation(&result.final_state))
+            .collect();
+
+        let mut gradient = vec![0.0; circuit.num_parameters()];
+        for (parameter, pair) in circuit.rotation_parameters().zip(expectations.chunks_exact(2)) {
+            gradient[parameter] += (pair[0] - pair[1]) / 2.0;
+        }
+        gradient
+    }
+
+    /// Runs the given circuit with the simulator configuration
+    fn run_circuit(&self, circuit: &QuantumCircuit, shots: usize) -> SimulationResult {
+        self.execute(circuit, shots, &mut |_| {})
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Transpiles the circuit if requested and hands it to the backend
+    fn execute(&self, circuit: &QuantumCircuit, shots: usize, progress: &mut dyn FnMut(Progress)) -> Result<SimulationResult, SimulationError> {
+        let _span = span!(INFO, "simulate", simulator = %self.name, backend = self.backend.name(), qubits = circuit.num_qubits(), operations = circuit.num_operations(), shots);
+        let circuit = match &self.config.transpile {
+            Some(options) => Cow::Owned(transpile(circuit, options)),
+            None => Cow::Borrowed(circuit),
+        };
+        self.backend.run(&circuit, shots, &self.config, progress)
+    }
+
+    /// Returns the exact probability of every basis state, without sampling
+    /// 
+    /// The probability of basis state `i` is stored at index `i`.
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// use intrico::simulator::Simulator;
+    /// 
+    /// let mut qc = QuantumCircuit::new(1);
+    /// qc.h(0);
+    /// 
+    /// let probabilities = Simulator::new().with_circuit(qc).probabilities();
+    /// assert!((probabilities[0] - 0.5).abs() < 1e-6);
+    /// assert!((probabilities[1] - 0.5).abs() < 1e-6);
+    /// ```
+    pub fn probabilities(&self) -> Vec<f64> {
+        self.run_circuit(self.circuit(), 0)
+            .final_state
+            .iter()
+            .map(|amp| amp.norm_squared())
+            .collect()
+    }
+
+    /// Returns counts allocated deterministically in proportion to the exact probabilities
+    /// 
+    /// Shots are distributed with the largest remainder method, so the counts always
+    /// sum to `shots` and no random sampling takes place. Basis states that receive
+    /// no shots are omitted.
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// use intrico::simulator::Simulator;
+    /// 
+    /// let mut qc = QuantumCircuit::new(2);
+    /// qc.h(0);
+    /// qc.cnot(0, 1);
+    /// 
+    /// let counts = Simulator::new().with_circuit(qc).quasi_counts(1000);
+    /// assert_eq!(counts["00"], 500);
+    /// assert_eq!(counts["11"], 500);
+    /// ```
+    pub fn quasi_counts(&self, shots: usize) -> HashMap<String, usize> {
+        let num_qubits = self.circuit().num_qubits();
+        let probabilities = self.probabilities();
+        let total: f64 = probabilities.iter().sum();
+
+        let ideal: Vec<f64> = probabilities.iter()
+            .map(|p| if total > 0.0 { p / total * shots as f64 } else { 0.0 })
+            .collect();
+        let mut allocated: Vec<usize> = ideal.iter().map(|x| x.floor() as usize).collect();
+
+        // Hand out the remaining shots to the largest fractional parts
+        let remaining = shots.saturating_sub(allocated.iter().sum());
+        let mut order: Vec<usize> = (0..ideal.len()).collect();
+        order.sort_by(|&a, &b| (ideal[b] - ideal[b].floor()).total_cmp(&(ideal[a] - ideal[a].floor())));
+        for &idx in order.iter().take(remaining) {
+            allocated[idx] += 1;
+        }
+
+        allocated.into_iter()
+            .enumerate()
+            .filter(|&(_, count)| count > 0)
+            .map(|(idx, count)| (index_to_bitstring(idx, num_qubits, Endianness::Little), count))
+            .collect()
+    }
 
-        SimulationResult { shots, final_state, counts }
+    /// Returns the circuit attached to the simulator
+    fn circuit(&self) -> &QuantumCircuit {
+        self.circuit.as_ref()
+            .expect("No circuit provided to simulator. Use with_circuit() or set_circuit() to add a circuit.")
     }
 }
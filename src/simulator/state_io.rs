@@ -0,0 +1,137 @@
+use std::fs;
+use std::io::{self, ErrorKind};
+use std::path::Path;
+use rusticle::complex::Complex;
+
+/// Magic bytes at the start of binary statevector files
+const MAGIC: &[u8; 4] = b"IQSV";
+/// Version of the binary statevector format
+const VERSION: u32 = 1;
+/// Size of the binary header: magic, version and amplitude count
+const HEADER_LEN: usize = 4 + 4 + 8;
+
+/// File formats supported for storing statevectors
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StateFormat {
+    /// Compact little-endian binary format with a small header
+    Binary,
+    /// Human readable JSON list of `[real, imag]` pairs
+    Json,
+}
+
+/// Writes a statevector to disk in the given format
+///
+/// # Arguments
+/// * `path` - The file to write
+/// * `state` - The statevector to store
+/// * `format` - The file format to use
+///
+/// # Examples
+/// ```
+/// use rusticle::complex::Complex;
+/// use intrico::simulator::{save_state, load_state, StateFormat};
+///
+/// let path = std::env::temp_dir().join("intrico_doc_state.bin");
+/// let state = vec![Complex::new(0.6, 0.0), Complex::new(0.0, 0.8)];
+///
+/// save_state(&path, &state, StateFormat::Binary).unwrap();
+/// assert_eq!(load_state(&path, StateFormat::Binary).unwrap(), state);
+/// # std::fs::remove_file(&path).unwrap();
+/// ```
+pub fn save_state<P: AsRef<Path>>(path: P, state: &[Complex], format: StateFormat) -> io::Result<()> {
+    let bytes = match format {
+        StateFormat::Binary => encode_binary(state),
+        StateFormat::Json => encode_json(state).into_bytes(),
+    };
+    fs::write(path, bytes)
+}
+
+/// Reads a statevector previously written by [`save_state`]
+///
+/// # Arguments
+/// * `path` - The file to read
+/// * `format` - The file format the statevector was stored in
+///
+/// # Errors
+/// Returns an [`ErrorKind::InvalidData`] error if the file is not a valid statevector
+pub fn load_state<P: AsRef<Path>>(path: P, format: StateFormat) -> io::Result<Vec<Complex>> {
+    let bytes = fs::read(path)?;
+    match format {
+        StateFormat::Binary => decode_binary(&bytes),
+        StateFormat::Json => {
+            let text = String::from_utf8(bytes).map_err(|e| invalid_data(e.to_string()))?;
+            decode_json(&text)
+        },
+    }
+}
+
+fn invalid_data<S: Into<String>>(message: S) -> io::Error {
+    io::Error::new(ErrorKind::InvalidData, message.into())
+}
+
+fn encode_binary(state: &[Complex]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(HEADER_LEN + state.len() * 16);
+    bytes.extend_from_slice(MAGIC);
+    bytes.extend_from_slice(&VERSION.to_le_bytes());
+    bytes.extend_from_slice(&(state.len() as u64).to_le_bytes());
+    for amplitude in state {
+        bytes.extend_from_slice(&amplitude.real.to_le_bytes());
+        bytes.extend_from_slice(&amplitude.imag.to_le_bytes());
+    }
+    bytes
+}
+
+fn decode_binary(bytes: &[u8]) -> io::Result<Vec<Complex>> {
+    if bytes.len() < HEADER_LEN || &bytes[..4] != MAGIC {
+        return Err(invalid_data("Not an intrico statevector file"));
+    }
+
+    let version = u32::from_le_bytes(bytes[4..8].try_into().unwrap());
+    if version != VERSION {
+        return Err(invalid_data(format!("Unsupported statevector format version {}", version)));
+    }
+
+    let len = u64::from_le_bytes(bytes[8..16].try_into().unwrap()) as usize;
+    let body = &bytes[HEADER_LEN..];
+    if len.checked_mul(16) != Some(body.len()) {
+        return Err(invalid_data(format!("Expected {} amplitudes, found {} bytes of data", len, body.len())));
+    }
+
+    let read_f64 = |chunk: &[u8]| f64::from_le_bytes(chunk.try_into().unwrap());
+    Ok(body.chunks_exact(16)
+        .map(|chunk| Complex::new(read_f64(&chunk[..8]), read_f64(&chunk[8..])))
+        .collect())
+}
+
+fn encode_json(state: &[Complex]) -> String {
+    let pairs: Vec<String> = state.iter()
+        .map(|c| format!("[{:?}, {:?}]", c.real, c.imag))
+        .collect();
+    format!("[{}]", pairs.join(", "))
+}
+
+fn decode_json(text: &str) -> io::Result<Vec<Complex>> {
+    let compact: String = text.chars().filter(|c| !c.is_whitespace()).collect();
+    let inner = compact.strip_prefix('[')
+        .and_then(|s| s.strip_suffix(']'))
+        .ok_or_else(|| invalid_data("Statevector JSON must be a list of [real, imag] pairs"))?;
+
+    if inner.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let inner = inner.strip_prefix('[')
+        .and_then(|s| s.strip_suffix(']'))
+        .ok_or_else(|| invalid_data("Statevector JSON must be a list of [real, imag] pairs"))?;
+
+    inner.split("],[")
+        .map(|pair| {
+            let parts: Vec<&str> = pair.split(',').collect();
+            if parts.len() != 2 {
+                return Err(invalid_data(format!("Invalid amplitude [{}]", pair)));
+            }
+            let parse = |s: &str| s.parse::<f64>().map_err(|_| invalid_data(format!("Invalid number '{}'", s)));
+            Ok(Complex::new(parse(parts[0])?, parse(parts[1])?))
+        })
+        .collect()
+}
@@ -0,0 +1,475 @@
+use std::{collections::{BTreeMap, HashMap}, marker::PhantomData, mem, time::Duration};
+use rusticle::complex::Complex;
+use rand::{distr::weighted::WeightedIndex, prelude::*};
+
+use crate::{QuantumCircuit, QuantumGate};
+use crate::core::{Amplitude, Float, GateOp};
+use crate::utility::{index_to_bitstring, Endianness};
+use super::noise::PauliChannel;
+use super::fresh_seed;
+use super::metrics::Stopwatch;
+use crate::trace::span;
+use super::sampling::{binomial, multinomial};
+use super::{EntanglementReport, ExecutionMetrics, NoiseModel, Progress, ProgressStage, SimulationError, SimulationResult, SimulatorBackend, SimulatorConfig};
+
+/// Dense statevector simulation backend
+/// 
+/// Evolves the full `2^n` amplitude vector of the circuit and samples measurement
+/// outcomes from the final state. Mid-circuit measurements collapse the state and
+/// split the shots between both outcomes; the returned final state and snapshots
+/// follow the most likely outcome of each of them.
+/// 
+/// Amplitudes are stored with the precision of `F`, double by default. Single
+/// precision halves the memory of every statevector, so larger circuits fit under the
+/// [memory limit](super::Simulator::with_memory_limit); returned states are converted
+/// back to double precision.
+/// 
+/// # Examples
+/// ```
+/// use intrico::QuantumCircuit;
+/// use intrico::simulator::{Simulator, StateVectorBackend};
+/// 
+/// let mut qc = QuantumCircuit::new(2);
+/// qc.h(0);
+/// qc.cnot(0, 1);
+/// 
+/// let result = Simulator::with_backend(StateVectorBackend::<f32>::default())
+///     .with_circuit(qc)
+///     .with_seed(7)
+///     .run(100);
+/// assert_eq!(result.counts.values().sum::<usize>(), 100);
+/// assert!((result.final_state[3].real - 0.5_f64.sqrt()).abs() < 1e-6);
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StateVectorBackend<F: Float = f64> {
+    precision: PhantomData<F>,
+}
+
+impl StateVectorBackend {
+    /// Creates a double precision statevector backend
+    pub const fn new() -> Self {
+        StateVectorBackend { precision: PhantomData }
+    }
+}
+
+impl<F: Float> PartialEq for StateVectorBackend<F> {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl<F: Float> Eq for StateVectorBackend<F> {}
+
+impl<F: Float> SimulatorBackend for StateVectorBackend<F> {
+    fn name(&self) -> &str {
+        "statevector"
+    }
+
+    fn run(
+        &self,
+        circuit: &QuantumCircuit,
+        shots: usize,
+        config: &SimulatorConfig,
+        progress: &mut dyn FnMut(Progress),
+    ) -> Result<SimulationResult, SimulationError> {
+        Self::check_capacity(config, circuit.num_qubits())?;
+
+        let started = Stopwatch::start();
+        let mut metrics = ExecutionMetrics::default();
+
+        let operations = circuit.operations();
+        let mut steps = Vec::with_capacity(operations.len());
+        let num_loops = Self::schedule(operations, None, config.noise.as_ref(), &mut steps, 0);
+        let num_qubits = circuit.num_qubits();
+        let num_classical_bits = circuit.num_classical_bits();
+        let tolerance = config.tolerance();
+        let readout_errors: Vec<f64> = (0..num_qubits)
+            .map(|qubit| config.noise.as_ref().map_or(0.0, |noise| noise.readout_error(qubit)))
+            .collect();
+
+        let mut local_rng;
+        let mut shared_rng;
+        let rng: &mut dyn RngCore = match &config.rng {
+            Some(shared) => {
+                shared_rng = shared.lock();
+                &mut *shared_rng
+            },
+            None => {
+                local_rng = StdRng::seed_from_u64(fresh_seed());
+                &mut local_rng
+            },
+        };
+        let mut counts = HashMap::new();
+        let mut snapshots = HashMap::new();
+        let mut final_state = Vec::new();
+        let mut sampled = 0;
+        let mut peak_branches = 1;
+        let mut sampling_time = Duration::ZERO;
+
+        // Report shot progress roughly every percent
+        let report_every = (shots / 100).max(1);
+
+        // Depth-first traversal of the shot-branching tree. The tracked branch is always
+        // followed to the end, even when no shots remain on it, to obtain the final state.
+        let mut pending = vec![Branch {
+            state: Self::starting_state(config, circuit)?,
+            position: 0,
+            shots,
+            classical_bits: vec![false; num_classical_bits],
+            iterations: vec![0; num_loops],
+            tracked: true,
+        }];
+
+        while let Some(mut branch) = pending.pop() {
+            {
+                let _span = span!(DEBUG, "gates", from = branch.position, shots = branch.shots, tracked = branch.tracked);
+                while branch.position < steps.len() {
+                    config.check_cancelled()?;
+
+                    match &steps[branch.position] {
+                        Step::Gate(i, op) => {
+                            let enabled = op.condition
+                                .is_none_or(|(bit, value)| branch.classical_bits[bit] == value);
+
+                            if let QuantumGate::Snapshot(label) = &op.gate {
+                                if branch.tracked {
+                                    snapshots.insert(label.clone(), tolerance.round_state(&Self::widen(&branch.state)));
+                                }
+                            } else if let (QuantumGate::Classical(classical), true) = (&op.gate, enabled) {
+                                let bit = op.classical_bit.unwrap_or_default();
+                                branch.classical_bits[bit] = classical.evaluate(&branch.classical_bits);
+                            } else if enabled {
+                                let gate_started = Stopwatch::start();
+                                circuit.apply_operation(&mut branch.state, op);
+                                metrics.record_gate(Self::gate_kind(&op.gate), gate_started.elapsed());
+                            }
+
+                            if branch.tracked {
+                                progress(Progress { stage: ProgressStage::Gates, completed: i + 1, total: operations.len() });
+                            }
+                        },
+                        Step::Measure(i, op) => {
+                            // Rotate X- and Y-basis measurements onto the computational basis
+                            circuit.apply_operation(&mut branch.state, op);
+                            let mask = 1 << op.target();
+                            let p1: f64 = branch.state.iter()
+                                .enumerate()
+                                .filter(|&(idx, _)| idx & mask != 0)
+                                .map(|(_, amp)| amp.norm_squared())
+                                .sum();
+                            let ones = binomial(rng, branch.shots, p1);
+                            let zeros = branch.shots - ones;
+
+                            // Stay on the most likely outcome and fork the other one if it got shots
+                            let outcome = if branch.tracked { p1 > 0.5 } else { ones > zeros };
+                            let (kept, forked) = if outcome { (ones, zeros) } else { (zeros, ones) };
+                            let bit = op.classical_bit.unwrap_or_default();
+
+                            if forked > 0 {
+                                let mut fork = Branch {
+                                    state: branch.state.clone(),
+                                    position: branch.position + 1,
+                                    shots: forked,
+                                    classical_bits: branch.classical_bits.clone(),
+                                    iterations: branch.iterations.clone(),
+                                    tracked: false,
+                                };
+                                Self::collapse(&mut fork.state, mask, !outcome, if outcome { 1.0 - p1 } else { p1 });
+                                fork.classical_bits[bit] = !outcome;
+                                pending.push(fork);
+                                peak_branches = peak_branches.max(pending.len() + 1);
+                            }
+
+                            Self::collapse(&mut branch.state, mask, outcome, if outcome { p1 } else { 1.0 - p1 });
+                            branch.classical_bits[bit] = outcome;
+                            branch.shots = kept;
+
+                            if branch.tracked {
+                                progress(Progress { stage: ProgressStage::Gates, completed: i + 1, total: operations.len() });
+                            }
+                        },
+                        Step::Noise(channel) => {
+                            // Fork a branch for every error that hits at least one shot
+                            let hits = channel.split(branch.shots, rng);
+                            for (outcome, &hit) in hits.iter().enumerate().filter(|&(_, &hit)| hit > 0) {
+                                let mut state = branch.state.clone();
+                                for op in channel.operations(outcome) {
+                                    circuit.apply_operation(&mut state, &op);
+                                }
+                                pending.push(Branch {
+                                    state,
+                                    position: branch.position + 1,
+                                    shots: hit,
+                                    classical_bits: branch.classical_bits.clone(),
+                                    iterations: branch.iterations.clone(),
+                                    tracked: false,
+                                });
+                            }
+                            branch.shots -= hits.iter().sum::<usize>();
+                            peak_branches = peak_branches.max(pending.len() + 1);
+                        },
+                        Step::Repeat { start, condition: (bit, value), max_iterations, slot } => {
+                            branch.iterations[*slot] += 1;
+                            if branch.classical_bits[*bit] != *value && branch.iterations[*slot] < *max_iterations {
+                                branch.position = *start;
+                                continue;
+                            }
+                            branch.iterations[*slot] = 0;
+                        },
+                    }
+
+                    if branch.shots == 0 && !branch.tracked {
+                        break;
+                    }
+                    branch.position += 1;
+                }
+            }
+
+            if branch.position < steps.len() {
+                continue;
+            }
+
+            let leaf_state = tolerance.round_state(&Self::widen(&branch.state));
+            if branch.shots > 0 {
+                let _span = span!(DEBUG, "sample", shots = branch.shots, multinomial = config.sampling.use_multinomial(branch.shots));
+                let sampling_started = Stopwatch::start();
+
+                // Sample measurements
+                let probabilities: Vec<f64> = leaf_state.iter().map(|amp| amp.norm_squared()).collect();
+
+                if config.sampling.use_multinomial(branch.shots) {
+                    let mut outcomes: BTreeMap<usize, usize> = multinomial(rng, branch.shots, &probabilities)
+                        .into_iter()
+                        .enumerate()
+                        .filter(|&(_, count)| count > 0)
+                        .collect();
+                    config.check_cancelled()?;
+
+                    for (qubit, &error) in readout_errors.iter().enumerate().filter(|&(_, &error)| error > 0.0) {
+                        let mut flipped = BTreeMap::new();
+                        for (idx, count) in outcomes {
+                            let flips = binomial(rng, count, error);
+                            *flipped.entry(idx).or_insert(0) += count - flips;
+                            *flipped.entry(idx ^ (1 << qubit)).or_insert(0) += flips;
+                        }
+                        outcomes = flipped;
+                    }
+
+                    for (idx, count) in outcomes.into_iter().filter(|&(_, count)| count > 0) {
+                        let bitstring = index_to_bitstring(idx, num_qubits, Endianness::Little);
+                        *counts.entry(bitstring).or_insert(0) += count;
+                    }
+
+                    sampled += branch.shots;
+                    progress(Progress { stage: ProgressStage::Shots, completed: sampled, total: shots });
+                } else {
+                    let dist = WeightedIndex::new(&probabilities).unwrap();
+
+                    for _ in 0..branch.shots {
+                        config.check_cancelled()?;
+
+                        let mut idx = dist.sample(rng);
+                        for (qubit, &error) in readout_errors.iter().enumerate() {
+                            if error > 0.0 && rng.random::<f64>() < error {
+                                idx ^= 1 << qubit;
+                            }
+                        }
+                        let bitstring = index_to_bitstring(idx, num_qubits, Endianness::Little);
+
+                        *counts.entry(bitstring).or_insert(0) += 1;
+
+                        sampled += 1;
+                        if sampled % report_every == 0 || sampled == shots {
+                            progress(Progress { stage: ProgressStage::Shots, completed: sampled, total: shots });
+                        }
+                    }
+                }
+
+                sampling_time += sampling_started.elapsed();
+            }
+
+            if branch.tracked {
+                final_state = leaf_state;
+            }
+        }
+
+        metrics.sampling_time = sampling_time;
+
+        let entanglement = config.entanglement_diagnostics
+            .then(|| EntanglementReport::from_state(&final_state, num_qubits));
+
+        // Live branch statevectors, the rounded copy, snapshots and the probability table
+        let branch_bytes = final_state.len() * mem::size_of::<F::Complex>();
+        let state_bytes = final_state.len() * mem::size_of::<Complex>();
+        metrics.peak_memory_bytes = branch_bytes * peak_branches + state_bytes * (1 + snapshots.len())
+            + final_state.len() * mem::size_of::<f64>();
+        metrics.wall_time = started.elapsed();
+
+        Ok(SimulationResult { shots, final_state, counts, snapshots, entanglement, metrics })
+    }
+}
+
+/// A step of the simulation: an operation of the circuit, a mid-circuit measurement,
+/// an error channel or the end of a loop body
+/// 
+/// Operations carry the index of the circuit operation they belong to, which is the
+/// enclosing loop for the operations of a loop body.
+enum Step<'a> {
+    /// Operation to apply
+    Gate(usize, &'a GateOp),
+    /// Measurement whose outcome affects later operations
+    Measure(usize, &'a GateOp),
+    /// Stochastic error to apply to the shots passing through
+    Noise(PauliChannel),
+    /// End of a loop body, jumping back to `start` until the condition holds
+    Repeat {
+        /// Position of the first step of the loop body
+        start: usize,
+        /// Classical bit and value that end the loop
+        condition: (usize, bool),
+        /// Maximum number of iterations
+        max_iterations: usize,
+        /// Index of the iteration counter of the loop in [`Branch::iterations`]
+        slot: usize,
+    },
+}
+
+/// A group of shots sharing the same evolution
+struct Branch<A> {
+    /// Statevector of the shots in this branch
+    state: Vec<A>,
+    /// Index of the next step to apply
+    position: usize,
+    /// Number of shots following this branch
+    shots: usize,
+    /// Classical register written by the mid-circuit measurements of this branch
+    classical_bits: Vec<bool>,
+    /// Number of iterations completed so far by every loop of the circuit
+    iterations: Vec<usize>,
+    /// Whether this branch has seen no error and the most likely measurement outcomes
+    tracked: bool,
+}
+
+impl<F: Float> StateVectorBackend<F> {
+    /// Interleaves the circuit operations with the error channels of the noise model and
+    /// the channels attached to individual operations, returning the number of loops
+    /// 
+    /// Measurements only become [`Step::Measure`] when a later operation acts on the
+    /// measured qubit, is conditioned on the written bit or reads it in a classical
+    /// operation or a loop condition. Terminal measurements are left to the final
+    /// sampling. Loop bodies are scheduled inline, followed by a [`Step::Repeat`], and
+    /// every measurement inside them is a [`Step::Measure`].
+    /// 
+    /// # Arguments
+    /// * `operations` - The operations to schedule
+    /// * `parent` - The index of the enclosing loop, or `None` at the top level
+    /// * `noise` - The noise model of the simulation
+    /// * `steps` - The schedule to append to
+    /// * `loops` - The number of loops scheduled so far
+    fn schedule<'a>(
+        operations: &'a [GateOp],
+        parent: Option<usize>,
+        noise: Option<&NoiseModel>,
+        steps: &mut Vec<Step<'a>>,
+        mut loops: usize,
+    ) -> usize {
+        for (i, op) in operations.iter().enumerate() {
+            let index = parent.unwrap_or(i);
+
+            if let QuantumGate::RepeatUntil { body, condition, max_iterations } = &op.gate {
+                let start = steps.len();
+                let slot = loops;
+                loops = Self::schedule(body, Some(index), noise, steps, loops + 1);
+                steps.push(Step::Repeat { start, condition: *condition, max_iterations: *max_iterations, slot });
+                continue;
+            }
+
+            let mid_circuit = op.gate.is_measurement()
+                && (parent.is_some() || operations[i + 1..].iter().any(|later| Self::depends_on(later, op)));
+            steps.push(if mid_circuit { Step::Measure(index, op) } else { Step::Gate(index, op) });
+
+            if let (Some(noise), false) = (noise, op.gate.is_measurement() || matches!(op.gate, QuantumGate::Snapshot(_) | QuantumGate::Classical(_))) {
+                steps.extend(noise.channels(op).into_iter().map(Step::Noise));
+            }
+            if let Some(channel) = op.noise {
+                steps.extend(PauliChannel::from_attached(channel, &op.qubit).into_iter().map(Step::Noise));
+            }
+        }
+        loops
+    }
+
+    /// Returns whether an operation acts on the qubit of a measurement or reads its bit
+    fn depends_on(later: &GateOp, measurement: &GateOp) -> bool {
+        let reads = |bit: usize| Some(bit) == measurement.classical_bit;
+        match &later.gate {
+            QuantumGate::Snapshot(_) => false,
+            QuantumGate::Classical(classical) => classical.inputs().into_iter().any(reads)
+                || later.condition.is_some_and(|(bit, _)| reads(bit)),
+            QuantumGate::RepeatUntil { body, condition: (bit, _), .. } => later.qubit.contains(&measurement.target())
+                || reads(*bit)
+                || body.iter().any(|inner| Self::depends_on(inner, measurement)),
+            _ => later.qubit.contains(&measurement.target())
+                || later.condition.is_some_and(|(bit, _)| reads(bit)),
+        }
+    }
+
+    /// Projects the state onto the given value of the masked qubit and renormalizes it
+    fn collapse(state: &mut [F::Complex], mask: usize, value: bool, probability: f64) {
+        let scale = 1.0 / probability.sqrt();
+        for (idx, amp) in state.iter_mut().enumerate() {
+            *amp = if (idx & mask != 0) == value {
+                amp.scale(scale)
+            } else {
+                F::Complex::ZERO
+            };
+        }
+    }
+
+    /// Converts a statevector to double precision
+    fn widen(state: &[F::Complex]) -> Vec<Complex> {
+        state.iter().map(|amp| amp.to_complex()).collect()
+    }
+
+    /// Returns the configured initial state, or |0...0⟩ if none is set
+    fn starting_state(config: &SimulatorConfig, circuit: &QuantumCircuit) -> Result<Vec<F::Complex>, SimulationError> {
+        let Some(state) = &config.initial_state else {
+            return Ok(circuit.initial_state());
+        };
+
+        let expected = 1 << circuit.num_qubits();
+        if state.len() != expected {
+            return Err(SimulationError::InitialStateDimension { expected, found: state.len() });
+        }
+
+        let norm: f64 = state.iter().map(|amp| amp.norm_squared()).sum();
+        if (norm - 1.0).abs() > 1e-6 {
+            return Err(SimulationError::InitialStateNotNormalized { norm });
+        }
+
+        Ok(state.iter().map(|&amp| F::Complex::from_complex(amp)).collect())
+    }
+
+    /// Returns the gate name without parameters, used to group metrics
+    fn gate_kind(gate: &QuantumGate) -> String {
+        let name = gate.name();
+        match name.find('(') {
+            Some(idx) => name[..idx].to_string(),
+            None => name,
+        }
+    }
+
+    /// Returns an error if a statevector over `qubits` qubits exceeds the configured limits
+    fn check_capacity(config: &SimulatorConfig, qubits: usize) -> Result<(), SimulationError> {
+        let bytes_needed = 1u128.checked_shl(qubits as u32)
+            .map_or(u128::MAX, |dim| dim.saturating_mul(mem::size_of::<F::Complex>() as u128));
+
+        let too_many_qubits = config.max_qubits.is_some_and(|max| qubits > max);
+        let too_much_memory = config.memory_limit.is_some_and(|limit| bytes_needed > limit as u128)
+            || qubits >= usize::BITS as usize;
+
+        if too_many_qubits || too_much_memory {
+            return Err(SimulationError::StateTooLarge { qubits, bytes_needed });
+        }
+        Ok(())
+    }
+}
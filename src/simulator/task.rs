@@ -0,0 +1,56 @@
+use std::future::Future;
+use std::panic;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::thread;
+
+/// State shared between a background simulation thread and its future
+struct Shared<T> {
+    output: Option<thread::Result<T>>,
+    waker: Option<Waker>,
+}
+
+/// A future resolving to the output of a job running on a dedicated thread
+/// 
+/// The job is started eagerly, so the future does not depend on any particular
+/// async runtime; it only needs to be polled to observe completion.
+pub(crate) struct BlockingTask<T> {
+    shared: Arc<Mutex<Shared<T>>>,
+}
+
+impl<T: Send + 'static> BlockingTask<T> {
+    /// Spawns the job on a new thread and returns a future for its output
+    pub(crate) fn spawn<F: FnOnce() -> T + Send + 'static>(job: F) -> Self {
+        let shared = Arc::new(Mutex::new(Shared { output: None, waker: None }));
+        let worker_shared = Arc::clone(&shared);
+
+        thread::spawn(move || {
+            let output = panic::catch_unwind(panic::AssertUnwindSafe(job));
+            let mut shared = worker_shared.lock().unwrap_or_else(|e| e.into_inner());
+            shared.output = Some(output);
+            if let Some(waker) = shared.waker.take() {
+                waker.wake();
+            }
+        });
+
+        BlockingTask { shared }
+    }
+}
+
+impl<T> Future for BlockingTask<T> {
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        let mut shared = self.shared.lock().unwrap_or_else(|e| e.into_inner());
+        match shared.output.take() {
+            Some(Ok(output)) => Poll::Ready(output),
+            // Re-raise panics from the worker thread in the awaiting task
+            Some(Err(payload)) => panic::resume_unwind(payload),
+            None => {
+                shared.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
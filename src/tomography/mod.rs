@@ -0,0 +1,11 @@
+//! Quantum tomography module
+//! 
+//! This module reconstructs quantum states from measurements in the Pauli bases, either
+//! by linear inversion or by maximum likelihood, and quantum processes as Choi and χ
+//! matrices from the reconstructed outputs of a complete set of input states.
+
+mod process;
+mod state;
+
+pub use process::{ProcessTomography, ProcessTomographyResult};
+pub use state::{Reconstruction, StateTomography};
@@ -0,0 +1,269 @@
+use std::collections::HashMap;
+
+use rusticle::complex::Complex;
+use rusticle::linalg::Matrix;
+
+use crate::QuantumCircuit;
+use crate::simulator::Simulator;
+use super::state::{pauli_bases, pauli_matrix, reconstruct_dense, rotate_to_basis, to_matrix, Reconstruction};
+
+/// Full process tomography of a circuit on all of its qubits
+/// 
+/// Every qubit is prepared in |0⟩, |1⟩, |+⟩ and |+i⟩, written `0`, `1`, `+` and `r`,
+/// and every output state is reconstructed by state tomography. As these inputs span
+/// all operators, the action of the process on every matrix unit `|a⟩⟨b|` follows by
+/// linearity, which yields the Choi matrix `Λ = Σ |a⟩⟨b| ⊗ E(|a⟩⟨b|)` and from it the
+/// χ matrix in the Pauli basis. Preparations are written like bitstrings: the
+/// rightmost character is the input of qubit 0.
+/// 
+/// # Examples
+/// ```
+/// use intrico::QuantumCircuit;
+/// use intrico::simulator::Simulator;
+/// use intrico::tomography::ProcessTomography;
+/// 
+/// let mut qc = QuantumCircuit::new(1);
+/// qc.h(0);
+/// 
+/// let result = ProcessTomography::new(qc.clone())
+///     .with_simulator(Simulator::new().with_seed(2))
+///     .run();
+/// assert!(result.average_gate_fidelity(&qc.unitary()) > 0.98);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ProcessTomography {
+    circuit: QuantumCircuit,
+    shots: usize,
+    simulator: Simulator,
+    method: Reconstruction,
+}
+
+/// Outcome of a [`ProcessTomography`] run
+#[derive(Debug, Clone)]
+pub struct ProcessTomographyResult {
+    /// The Choi matrix, indexed by `input · d + output` and of trace `d`
+    pub choi: Matrix<Complex>,
+    /// The χ matrix of `E(ρ) = Σ χₘₙ·Pₘ·ρ·Pₙ`, of trace 1
+    /// 
+    /// Rows and columns follow the Pauli strings `I…I`, `I…X`, `I…Y`, `I…Z`, `I…XI`, …,
+    /// counting in base 4 with the rightmost character acting on qubit 0.
+    pub chi: Matrix<Complex>,
+}
+
+impl ProcessTomography {
+    /// Creates a tomography experiment of the process implemented by a circuit
+    /// 
+    /// Defaults to 1000 shots per circuit on a noiseless simulator, with the output
+    /// states reconstructed by linear inversion.
+    pub fn new(circuit: QuantumCircuit) -> Self {
+        ProcessTomography {
+            circuit,
+            shots: 1000,
+            simulator: Simulator::new(),
+            method: Reconstruction::default(),
+        }
+    }
+
+    /// Sets the number of shots per circuit
+    pub fn with_shots(mut self, shots: usize) -> Self {
+        self.shots = shots;
+        self
+    }
+
+    /// Sets the simulator, and with it the noise model, used to run the circuits
+    pub fn with_simulator(mut self, simulator: Simulator) -> Self {
+        self.simulator = simulator;
+        self
+    }
+
+    /// Sets the method reconstructing the output states
+    pub fn with_method(mut self, method: Reconstruction) -> Self {
+        self.method = method;
+        self
+    }
+
+    /// Returns the `4^k` input preparations
+    pub fn preparations(&self) -> Vec<String> {
+        let num_qubits = self.circuit.num_qubits();
+        (0..4usize.pow(num_qubits as u32))
+            .map(|code| {
+                (0..num_qubits).rev()
+                    .map(|position| ['0', '1', '+', 'r'][code / 4usize.pow(position as u32) % 4])
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Returns the `3^k` measurement bases of every output state
+    pub fn bases(&self) -> Vec<String> {
+        pauli_bases(self.circuit.num_qubits())
+    }
+
+    /// Returns a circuit for every preparation and measurement basis, preparation-major
+    pub fn circuits(&self) -> Vec<QuantumCircuit> {
+        let num_qubits = self.circuit.num_qubits();
+        let qubits: Vec<usize> = (0..num_qubits).collect();
+        let bases = self.bases();
+
+        self.preparations().iter()
+            .flat_map(|preparation| {
+                let mut prepared = QuantumCircuit::new(num_qubits);
+                for (qubit, input) in preparation.chars().rev().enumerate() {
+                    match input {
+                        '1' => prepared.x(qubit),
+                        '+' => prepared.h(qubit),
+                        'r' => {
+                            prepared.h(qubit);
+                            prepared.s(qubit);
+                        },
+                        _ => {},
+                    }
+                }
+                prepared.compose(&self.circuit, &qubits);
+
+                bases.iter()
+                    .map(|basis| {
+                        let mut circuit = prepared.clone();
+                        rotate_to_basis(&mut circuit, &qubits, basis);
+                        circuit
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    /// Runs every circuit and reconstructs the process
+    pub fn run(&self) -> ProcessTomographyResult {
+        let qubits: Vec<usize> = (0..self.circuit.num_qubits()).collect();
+        let counts: Vec<HashMap<String, usize>> = self.simulator.run_batch(&self.circuits(), self.shots)
+            .iter()
+            .map(|result| result.marginal_counts(&qubits))
+            .collect();
+        self.reconstruct(&counts)
+    }
+
+    /// Reconstructs the process from the counts of every circuit of [`ProcessTomography::circuits`]
+    /// 
+    /// # Panics
+    /// Panics if the counts do not cover every circuit
+    pub fn reconstruct(&self, counts: &[HashMap<String, usize>]) -> ProcessTomographyResult {
+        let num_qubits = self.circuit.num_qubits();
+        let preparations = self.preparations();
+        let num_bases = self.bases().len();
+        if counts.len() != preparations.len() * num_bases {
+            panic!("Expected counts for {} circuits, got {}", preparations.len() * num_bases, counts.len());
+        }
+
+        let outputs: Vec<Vec<Complex>> = counts.chunks(num_bases)
+            .map(|chunk| reconstruct_dense(self.method, chunk, num_qubits))
+            .collect();
+
+        let dim = 1 << num_qubits;
+        let mut choi = vec![Complex::new(0.0, 0.0); dim * dim * dim * dim];
+        for a in 0..dim {
+            for b in 0..dim {
+                // E(|a⟩⟨b|) as a combination of the output states
+                for (preparation, output) in preparations.iter().zip(&outputs) {
+                    let weight = preparation.chars().rev()
+                        .enumerate()
+                        .fold(Complex::new(1.0, 0.0), |acc, (qubit, input)| {
+                            acc * unit_coefficient(a >> qubit & 1, b >> qubit & 1, input)
+                        });
+                    if weight.norm() == 0.0 {
+                        continue;
+                    }
+                    for r in 0..dim {
+                        for c in 0..dim {
+                            choi[(a * dim + r) * dim * dim + b * dim + c] += weight * output[r * dim + c];
+                        }
+                    }
+                }
+            }
+        }
+
+        let chi = choi_to_chi(&choi, num_qubits);
+        ProcessTomographyResult {
+            choi: to_matrix(&choi, dim * dim),
+            chi: to_matrix(&chi, dim * dim),
+        }
+    }
+}
+
+impl ProcessTomographyResult {
+    /// Returns the process fidelity `⟨⟨U|Λ|U⟩⟩ / d²` with the ideal unitary
+    /// 
+    /// # Panics
+    /// Panics if the unitary does not act on the qubits of the process
+    pub fn process_fidelity(&self, unitary: &Matrix<Complex>) -> f64 {
+        let dim = self.choi.rows().isqrt();
+        if unitary.rows() != dim || unitary.cols() != dim {
+            panic!("Expected a {}x{} unitary, got {}x{}", dim, dim, unitary.rows(), unitary.cols());
+        }
+
+        // |U⟩⟩ = Σᵢ |i⟩ ⊗ U|i⟩
+        let vector: Vec<Complex> = (0..dim * dim).map(|k| *unitary.get(k % dim, k / dim)).collect();
+        let overlap = (0..dim * dim)
+            .flat_map(|row| (0..dim * dim).map(move |col| (row, col)))
+            .fold(Complex::new(0.0, 0.0), |acc, (row, col)| {
+                acc + vector[row].conjugate() * *self.choi.get(row, col) * vector[col]
+            });
+        overlap.real / (dim * dim) as f64
+    }
+
+    /// Returns the average gate fidelity `(d·F + 1)/(d + 1)` with the ideal unitary
+    /// 
+    /// # Panics
+    /// Panics if the unitary does not act on the qubits of the process
+    pub fn average_gate_fidelity(&self, unitary: &Matrix<Complex>) -> f64 {
+        let dim = self.choi.rows().isqrt() as f64;
+        (dim * self.process_fidelity(unitary) + 1.0) / (dim + 1.0)
+    }
+}
+
+/// Returns the weight of the input `input` in the matrix unit `|a⟩⟨b|` of one qubit
+fn unit_coefficient(a: usize, b: usize, input: char) -> Complex {
+    let (zero, one, i) = (Complex::new(0.0, 0.0), Complex::new(1.0, 0.0), Complex::new(0.0, 1.0));
+    // |0⟩⟨1| = ρ₊ + i·ρᵣ - (1+i)/2·(ρ₀ + ρ₁) and |1⟩⟨0| is its adjoint
+    let row = match (a, b) {
+        (0, 0) => [one, zero, zero, zero],
+        (1, 1) => [zero, one, zero, zero],
+        (0, _) => [(one + i) * -0.5, (one + i) * -0.5, one, i],
+        _ => [(one - i) * -0.5, (one - i) * -0.5, one, -i],
+    };
+    match input {
+        '0' => row[0],
+        '1' => row[1],
+        '+' => row[2],
+        _ => row[3],
+    }
+}
+
+/// Converts a Choi matrix to the χ matrix: `χₘₙ = ⟨⟨Pₘ|Λ|Pₙ⟩⟩ / d²`
+fn choi_to_chi(choi: &[Complex], num_qubits: usize) -> Vec<Complex> {
+    let dim = 1 << num_qubits;
+    let size = dim * dim;
+
+    // |P⟩⟩ = Σᵢ |i⟩ ⊗ P|i⟩ for every Pauli string, in base-4 order
+    let vectors: Vec<Vec<Complex>> = (0..size)
+        .map(|code| {
+            let pauli: String = (0..num_qubits).rev()
+                .map(|position| ['I', 'X', 'Y', 'Z'][code / 4usize.pow(position as u32) % 4])
+                .collect();
+            let matrix = pauli_matrix(&pauli);
+            (0..size).map(|k| matrix[(k % dim) * dim + k / dim]).collect()
+        })
+        .collect();
+
+    let mut chi = vec![Complex::new(0.0, 0.0); size * size];
+    for (m, left) in vectors.iter().enumerate() {
+        for (n, right) in vectors.iter().enumerate() {
+            let mut sum = Complex::new(0.0, 0.0);
+            for row in 0..size {
+                let projected = (0..size).fold(Complex::new(0.0, 0.0), |acc, col| acc + choi[row * size + col] * right[col]);
+                sum += left[row].conjugate() * projected;
+            }
+            chi[m * size + n] = sum / size as f64;
+        }
+    }
+    chi
+}
@@ -0,0 +1,330 @@
+use std::collections::HashMap;
+
+use rusticle::complex::Complex;
+use rusticle::linalg::Matrix;
+
+use crate::QuantumCircuit;
+use crate::circuit::Basis;
+use crate::simulator::Simulator;
+
+/// Maximum number of iterations of the maximum-likelihood reconstruction
+const MAX_ITERATIONS: usize = 2000;
+
+/// How a density matrix is reconstructed from tomography counts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Reconstruction {
+    /// Sums the Pauli strings weighted by their measured expectation values
+    /// 
+    /// Fast and unbiased, but finite statistics can yield small negative eigenvalues.
+    #[default]
+    LinearInversion,
+    /// Maximizes the likelihood of the counts over physical density matrices
+    /// 
+    /// Uses the iterative `RρR` algorithm, which always returns a positive
+    /// semi-definite matrix of unit trace.
+    MaximumLikelihood,
+}
+
+/// Full state tomography of some qubits of a circuit
+/// 
+/// The measured qubits are rotated into every combination of the X, Y and Z bases, the
+/// `3^k` resulting circuits are sampled, and the density matrix is reconstructed from
+/// the counts. Bases are written like bitstrings: the rightmost character is the basis
+/// of `qubits[0]`, which is also the least significant bit of the density matrix index.
+/// 
+/// # Examples
+/// ```
+/// use intrico::QuantumCircuit;
+/// use intrico::simulator::Simulator;
+/// use intrico::tomography::{Reconstruction, StateTomography};
+/// 
+/// let mut bell = QuantumCircuit::new(2);
+/// bell.h(0);
+/// bell.cnot(0, 1);
+/// 
+/// let rho = StateTomography::new(bell, &[0, 1])
+///     .with_simulator(Simulator::new().with_seed(5))
+///     .with_method(Reconstruction::MaximumLikelihood)
+///     .run();
+/// 
+/// // ⟨Φ+|ρ|Φ+⟩ = (ρ₀₀ + ρ₃₃ + 2·Re ρ₀₃) / 2
+/// let fidelity = (rho.get(0, 0).real + rho.get(3, 3).real + 2.0 * rho.get(0, 3).real) / 2.0;
+/// assert!(fidelity > 0.95);
+/// ```
+#[derive(Debug, Clone)]
+pub struct StateTomography {
+    circuit: QuantumCircuit,
+    qubits: Vec<usize>,
+    shots: usize,
+    simulator: Simulator,
+    method: Reconstruction,
+}
+
+impl StateTomography {
+    /// Creates a tomography experiment of the given qubits after a circuit
+    /// 
+    /// Defaults to 1000 shots per basis on a noiseless simulator, reconstructed by
+    /// linear inversion.
+    /// 
+    /// # Panics
+    /// Panics if no qubit is given or a qubit is out of bounds
+    pub fn new(circuit: QuantumCircuit, qubits: &[usize]) -> Self {
+        if qubits.is_empty() {
+            panic!("State tomography requires at least one qubit");
+        }
+        if let Some(&qubit) = qubits.iter().find(|&&q| q >= circuit.num_qubits()) {
+            panic!("Qubit {} is out of bounds for a circuit of {} qubits", qubit, circuit.num_qubits());
+        }
+
+        StateTomography {
+            circuit,
+            qubits: qubits.to_vec(),
+            shots: 1000,
+            simulator: Simulator::new(),
+            method: Reconstruction::default(),
+        }
+    }
+
+    /// Sets the number of shots per measurement basis
+    pub fn with_shots(mut self, shots: usize) -> Self {
+        self.shots = shots;
+        self
+    }
+
+    /// Sets the simulator, and with it the noise model, used to run the circuits
+    pub fn with_simulator(mut self, simulator: Simulator) -> Self {
+        self.simulator = simulator;
+        self
+    }
+
+    /// Sets the reconstruction method
+    pub fn with_method(mut self, method: Reconstruction) -> Self {
+        self.method = method;
+        self
+    }
+
+    /// Returns the `3^k` measurement bases, in the order of [`StateTomography::circuits`]
+    pub fn bases(&self) -> Vec<String> {
+        pauli_bases(self.qubits.len())
+    }
+
+    /// Returns the circuit rotating the measured qubits into every basis
+    pub fn circuits(&self) -> Vec<QuantumCircuit> {
+        self.bases().iter()
+            .map(|basis| {
+                let mut circuit = self.circuit.clone();
+                rotate_to_basis(&mut circuit, &self.qubits, basis);
+                circuit
+            })
+            .collect()
+    }
+
+    /// Runs every basis circuit and reconstructs the density matrix of the qubits
+    pub fn run(&self) -> Matrix<Complex> {
+        let counts: Vec<HashMap<String, usize>> = self.simulator.run_batch(&self.circuits(), self.shots)
+            .iter()
+            .map(|result| result.marginal_counts(&self.qubits))
+            .collect();
+        self.reconstruct(&counts)
+    }
+
+    /// Reconstructs the density matrix from the counts of every basis
+    /// 
+    /// # Arguments
+    /// * `counts` - The counts of the measured qubits for every basis of
+    ///   [`StateTomography::bases`], with `qubits[0]` as the rightmost character
+    /// 
+    /// # Panics
+    /// Panics if the counts do not cover every basis
+    pub fn reconstruct(&self, counts: &[HashMap<String, usize>]) -> Matrix<Complex> {
+        let bases = self.bases();
+        if counts.len() != bases.len() {
+            panic!("Expected counts for {} bases, got {}", bases.len(), counts.len());
+        }
+        to_matrix(&reconstruct_dense(self.method, counts, self.qubits.len()), 1 << self.qubits.len())
+    }
+}
+
+/// Appends the rotations measuring `qubits[j]` in the basis of the `j`-th last character
+pub(super) fn rotate_to_basis(circuit: &mut QuantumCircuit, qubits: &[usize], basis: &str) {
+    for (&qubit, pauli) in qubits.iter().zip(basis.chars().rev()) {
+        if let Some(basis) = Basis::from_pauli(pauli) {
+            circuit.rotate_to_basis(qubit, basis);
+        }
+    }
+}
+
+/// Reconstructs a dense density matrix from the counts of every basis of [`pauli_bases`]
+pub(super) fn reconstruct_dense(method: Reconstruction, counts: &[HashMap<String, usize>], num_qubits: usize) -> Vec<Complex> {
+    let bases = pauli_bases(num_qubits);
+    let data: Vec<(&str, &HashMap<String, usize>)> = bases.iter().map(|b| b.as_str()).zip(counts).collect();
+    match method {
+        Reconstruction::LinearInversion => linear_inversion(&data, num_qubits),
+        Reconstruction::MaximumLikelihood => maximum_likelihood(&data, num_qubits),
+    }
+}
+
+/// Returns every string of X, Y and Z of the given length
+pub(super) fn pauli_bases(num_qubits: usize) -> Vec<String> {
+    (0..3usize.pow(num_qubits as u32))
+        .map(|code| {
+            (0..num_qubits).rev()
+                .map(|position| ['X', 'Y', 'Z'][code / 3usize.pow(position as u32) % 3])
+                .collect()
+        })
+        .collect()
+}
+
+/// Estimates `ρ = Σ_P ⟨P⟩·P / 2^k` over all Pauli strings `P`
+/// 
+/// Each expectation value averages every basis that measures the non-identity factors of `P`.
+fn linear_inversion(data: &[(&str, &HashMap<String, usize>)], num_qubits: usize) -> Vec<Complex> {
+    let dim = 1 << num_qubits;
+    let mut rho = vec![Complex::new(0.0, 0.0); dim * dim];
+
+    for code in 0..4usize.pow(num_qubits as u32) {
+        let pauli: String = (0..num_qubits).rev()
+            .map(|position| ['I', 'X', 'Y', 'Z'][code / 4usize.pow(position as u32) % 4])
+            .collect();
+
+        let (mut sum, mut total) = (0.0, 0usize);
+        for &(basis, counts) in data {
+            if !pauli.chars().zip(basis.chars()).all(|(p, b)| p == 'I' || p == b) {
+                continue;
+            }
+            for (bitstring, &count) in counts {
+                let odd = pauli.chars().zip(bitstring.chars())
+                    .filter(|&(p, bit)| p != 'I' && bit == '1')
+                    .count() % 2 == 1;
+                sum += if odd { -(count as f64) } else { count as f64 };
+                total += count;
+            }
+        }
+        if total == 0 {
+            continue;
+        }
+
+        let weight = sum / total as f64 / dim as f64;
+        let matrix = pauli_matrix(&pauli);
+        for (entry, p) in rho.iter_mut().zip(matrix) {
+            *entry += p * weight;
+        }
+    }
+    rho
+}
+
+/// Maximizes the likelihood of the counts with the iterative `RρR` algorithm
+fn maximum_likelihood(data: &[(&str, &HashMap<String, usize>)], num_qubits: usize) -> Vec<Complex> {
+    let dim = 1 << num_qubits;
+
+    // Projector and observed frequency of every outcome of every basis
+    let mut outcomes: Vec<(Vec<Complex>, f64)> = Vec::new();
+    for &(basis, counts) in data {
+        let total: usize = counts.values().sum();
+        if total == 0 {
+            continue;
+        }
+        for (bitstring, &count) in counts {
+            outcomes.push((projector(basis, bitstring), count as f64 / total as f64));
+        }
+    }
+
+    let mut rho = vec![Complex::new(0.0, 0.0); dim * dim];
+    for i in 0..dim {
+        rho[i * dim + i] = Complex::new(1.0 / dim as f64, 0.0);
+    }
+
+    for _ in 0..MAX_ITERATIONS {
+        let mut r = vec![Complex::new(0.0, 0.0); dim * dim];
+        for (projector, frequency) in &outcomes {
+            let probability = trace_product(projector, &rho, dim).max(1e-12);
+            for (entry, &p) in r.iter_mut().zip(projector) {
+                *entry += p * (frequency / probability);
+            }
+        }
+
+        let mut next = multiply(&multiply(&r, &rho, dim), &r, dim);
+        let trace: f64 = (0..dim).map(|i| next[i * dim + i].real).sum();
+        for entry in next.iter_mut() {
+            *entry /= trace;
+        }
+
+        let change: f64 = next.iter().zip(&rho).map(|(&a, &b)| (a - b).norm_squared()).sum();
+        rho = next;
+        if change < 1e-16 {
+            break;
+        }
+    }
+    rho
+}
+
+/// Returns the projector onto the outcome `bitstring` of measuring in `basis`
+pub(super) fn projector(basis: &str, bitstring: &str) -> Vec<Complex> {
+    let factors: Vec<Vec<Complex>> = basis.chars()
+        .zip(bitstring.chars())
+        .map(|(pauli, bit)| {
+            let sign = if bit == '1' { -0.5 } else { 0.5 };
+            let sigma = pauli_matrix(&pauli.to_string());
+            vec![
+                Complex::new(0.5, 0.0) + sigma[0] * sign, sigma[1] * sign,
+                sigma[2] * sign, Complex::new(0.5, 0.0) + sigma[3] * sign,
+            ]
+        })
+        .collect();
+    factors.iter().skip(1).fold(factors[0].clone(), |acc, factor| kron(&acc, factor))
+}
+
+/// Returns the dense matrix of a Pauli string, whose leftmost factor is the most significant
+pub(super) fn pauli_matrix(pauli: &str) -> Vec<Complex> {
+    let (zero, one, i) = (Complex::new(0.0, 0.0), Complex::new(1.0, 0.0), Complex::new(0.0, 1.0));
+    pauli.chars()
+        .map(|c| match c {
+            'I' => vec![one, zero, zero, one],
+            'X' => vec![zero, one, one, zero],
+            'Y' => vec![zero, -i, i, zero],
+            'Z' => vec![one, zero, zero, -one],
+            _ => panic!("Invalid Pauli operator '{}' in '{}'", c, pauli),
+        })
+        .reduce(|acc, factor| kron(&acc, &factor))
+        .unwrap_or_else(|| vec![one])
+}
+
+/// Returns the Kronecker product of two square dense matrices
+pub(super) fn kron(a: &[Complex], b: &[Complex]) -> Vec<Complex> {
+    let (n, m) = (a.len().isqrt(), b.len().isqrt());
+    let dim = n * m;
+    let mut result = vec![Complex::new(0.0, 0.0); dim * dim];
+    for row in 0..dim {
+        for col in 0..dim {
+            result[row * dim + col] = a[(row / m) * n + col / m] * b[(row % m) * m + col % m];
+        }
+    }
+    result
+}
+
+/// Returns the product of two square dense matrices
+pub(super) fn multiply(a: &[Complex], b: &[Complex], dim: usize) -> Vec<Complex> {
+    let mut result = vec![Complex::new(0.0, 0.0); dim * dim];
+    for row in 0..dim {
+        for k in 0..dim {
+            let factor = a[row * dim + k];
+            for col in 0..dim {
+                result[row * dim + col] += factor * b[k * dim + col];
+            }
+        }
+    }
+    result
+}
+
+/// Returns `Re tr(A·B)` of two square dense matrices
+pub(super) fn trace_product(a: &[Complex], b: &[Complex], dim: usize) -> f64 {
+    (0..dim)
+        .flat_map(|row| (0..dim).map(move |k| (row, k)))
+        .map(|(row, k)| (a[row * dim + k] * b[k * dim + row]).real)
+        .sum()
+}
+
+/// Converts a square dense matrix into a [`Matrix`]
+pub(super) fn to_matrix(entries: &[Complex], dim: usize) -> Matrix<Complex> {
+    Matrix::new(dim, dim, entries.to_vec())
+}
@@ -0,0 +1,26 @@
+//! Tracing instrumentation
+//! 
+//! With the `tracing` feature, simulations, circuit execution, transpiler passes and
+//! shot sampling are wrapped in [`tracing`](https://docs.rs/tracing) spans, so services
+//! embedding the crate can observe them with any subscriber. Without the feature the
+//! instrumentation compiles to nothing.
+
+/// Enters a span at the given level that lasts until the returned guard is dropped
+/// 
+/// The level is one of `INFO`, `DEBUG` or `TRACE`, followed by the span name and its
+/// fields in the syntax of [`tracing::span!`](https://docs.rs/tracing/latest/tracing/macro.span.html).
+macro_rules! span {
+    ($level:ident, $name:literal $(, $($fields:tt)*)?) => {{
+        #[cfg(feature = "tracing")]
+        let guard = tracing::span!(tracing::Level::$level, $name $(, $($fields)*)?).entered();
+        #[cfg(not(feature = "tracing"))]
+        let guard = $crate::trace::Disabled;
+        guard
+    }};
+}
+
+pub(crate) use span;
+
+/// Stand-in for an entered span when the `tracing` feature is disabled
+#[cfg(not(feature = "tracing"))]
+pub(crate) struct Disabled;
@@ -0,0 +1,100 @@
+use rusticle::complex::Complex;
+use rusticle::linalg::Matrix;
+
+use crate::core::{GateOp, QuantumGate};
+
+/// Angles below this threshold are treated as zero and their rotations dropped
+const ANGLE_TOLERANCE: f64 = 1e-10;
+
+/// Expands a Toffoli gate into CNOT, H, T and T† gates
+/// 
+/// Uses the standard 15-gate decomposition with six CNOTs. The steps of the
+/// returned operations are left at zero; they are assigned when the operations
+/// are added to a circuit.
+/// 
+/// # Arguments
+/// * `control1` - The index of the first control qubit
+/// * `control2` - The index of the second control qubit
+/// * `target` - The index of the target qubit
+pub fn toffoli_decomposition(control1: usize, control2: usize, target: usize) -> Vec<GateOp> {
+    let (a, b, c) = (control1, control2, target);
+    let single = |gate: QuantumGate, qubit: usize| GateOp::new(gate, qubit, 0);
+    let cnot = |control: usize, target: usize| GateOp::controlled(QuantumGate::CNOT, control, target, 0);
+
+    vec![
+        single(QuantumGate::H, c),
+        cnot(b, c),
+        single(QuantumGate::Tdg, c),
+        cnot(a, c),
+        single(QuantumGate::T, c),
+        cnot(b, c),
+        single(QuantumGate::Tdg, c),
+        cnot(a, c),
+        single(QuantumGate::T, b),
+        single(QuantumGate::T, c),
+        single(QuantumGate::H, c),
+        cnot(a, b),
+        single(QuantumGate::T, a),
+        single(QuantumGate::Tdg, b),
+        cnot(a, b),
+    ]
+}
+
+/// Decomposes a single-qubit unitary into Rz(β)·Ry(γ)·Rz(δ) up to a global phase
+/// 
+/// The gates are returned in application order, i.e. `Rz(δ)` first. Rotations with
+/// a negligible angle are omitted, so the identity yields an empty list.
+/// 
+/// # Arguments
+/// * `matrix` - A 2x2 unitary matrix
+/// 
+/// # Panics
+/// Panics if the matrix is not 2x2
+/// 
+/// # Examples
+/// ```
+/// use intrico::QuantumGate;
+/// use intrico::transpiler::zyz_decomposition;
+/// 
+/// // Up to a global phase, Z is a rotation by π around the Z axis
+/// let gates = zyz_decomposition(&QuantumGate::Z.matrix());
+/// assert_eq!(gates.len(), 1);
+/// ```
+pub fn zyz_decomposition(matrix: &Matrix<Complex>) -> Vec<QuantumGate> {
+    if matrix.rows() != 2 || matrix.cols() != 2 {
+        panic!("ZYZ decomposition requires a 2x2 matrix, got {}x{}", matrix.rows(), matrix.cols());
+    }
+
+    let (u00, u01, u10, u11) = (*matrix.get(0, 0), *matrix.get(0, 1), *matrix.get(1, 0), *matrix.get(1, 1));
+
+    // Remove the global phase so that the matrix lies in SU(2)
+    let phase = (u00 * u11 - u01 * u10).argument() / 2.0;
+    let unphase = Complex::new(0.0, -phase).exp();
+    let (v10, v11) = (u10 * unphase, u11 * unphase);
+
+    let gamma = 2.0 * v10.norm().atan2(v11.norm());
+    let sum = if v11.norm() > ANGLE_TOLERANCE { 2.0 * v11.argument() } else { 0.0 };
+    let difference = if v10.norm() > ANGLE_TOLERANCE { 2.0 * v10.argument() } else { 0.0 };
+    let beta = (sum + difference) / 2.0;
+    let delta = (sum - difference) / 2.0;
+
+    // Without the Y rotation both Z rotations collapse into one
+    let rotations = if is_negligible(gamma) {
+        vec![QuantumGate::Rz(sum)]
+    } else {
+        vec![QuantumGate::Rz(delta), QuantumGate::Ry(gamma), QuantumGate::Rz(beta)]
+    };
+
+    rotations.into_iter()
+        .filter(|gate| match gate {
+            QuantumGate::Ry(angle) | QuantumGate::Rz(angle) => !is_negligible(*angle),
+            _ => true,
+        })
+        .collect()
+}
+
+/// Returns true if a rotation by `angle` is the identity up to a global phase
+pub(crate) fn is_negligible(angle: f64) -> bool {
+    let wrapped = angle.rem_euclid(2.0 * std::f64::consts::PI);
+    wrapped < ANGLE_TOLERANCE || 2.0 * std::f64::consts::PI - wrapped < ANGLE_TOLERANCE
+}
@@ -0,0 +1,234 @@
+use rusticle::complex::Complex;
+use rusticle::linalg::Matrix;
+
+use crate::QuantumCircuit;
+use crate::core::{ClassicalOp, GateOp, QuantumGate};
+
+/// Rewrites feed-forward into controlled gates and terminal measurements
+///
+/// Following the principle of deferred measurement, every mid-circuit measurement, i.e.
+/// one whose qubit is acted on again or whose bit is read afterwards, is replaced by a
+/// CNOT copying the measured value onto a fresh ancilla. Operations conditioned on the
+/// bit become gates controlled by the ancilla, classical operations are computed
+/// reversibly onto further ancillas, and every ancilla holding the final value of a bit
+/// is measured into it at the end of the circuit. The outcome distribution of the
+/// original qubits is unchanged, so circuits with feed-forward can run on backends
+/// without dynamic circuit support.
+///
+/// Ancillas are appended after the qubits of the circuit, which keep their indices.
+/// X- and Y-basis measurements rotate their qubit onto the computational basis before
+/// the copy and leave it rotated, like the simulator does. Measurements without later
+/// dependencies are kept in place.
+///
+/// # Panics
+/// Panics if the circuit contains a loop, a conditioned measurement or a conditioned
+/// classical operation
+///
+/// # Examples
+/// ```
+/// use intrico::QuantumCircuit;
+/// use intrico::simulator::Simulator;
+/// use intrico::transpiler::defer_measurements;
+///
+/// // Flip qubit 1 whenever qubit 0 was measured as 1
+/// let mut qc = QuantumCircuit::new(2);
+/// qc.h(0);
+/// qc.measure(0, 0);
+/// qc.x(1);
+/// qc.c_if(0, true);
+///
+/// let deferred = defer_measurements(&qc);
+/// assert_eq!(deferred.num_qubits(), 3);
+/// assert!(deferred.operations().iter().all(|op| op.condition.is_none()));
+///
+/// let result = Simulator::new().with_circuit(deferred).run(100);
+/// assert!(result.marginal_counts(&[0, 1]).keys().all(|bits| bits == "00" || bits == "11"));
+/// ```
+pub fn defer_measurements(circuit: &QuantumCircuit) -> QuantumCircuit {
+    let operations = circuit.operations();
+    let num_qubits = circuit.num_qubits();
+    let mut ops = Vec::with_capacity(operations.len());
+    // Ancilla holding the current value of every classical bit, None while it is 0
+    let mut sources: Vec<Option<usize>> = vec![None; circuit.num_classical_bits()];
+    let mut num_ancillas = 0;
+    let mut allocate = || {
+        num_ancillas += 1;
+        num_qubits + num_ancillas - 1
+    };
+
+    for (i, op) in operations.iter().enumerate() {
+        match &op.gate {
+            QuantumGate::RepeatUntil { .. } => panic!("Loops cannot be deferred, as their number of iterations depends on the outcomes"),
+            QuantumGate::Classical(_) | QuantumGate::Measure | QuantumGate::MeasureX | QuantumGate::MeasureY if op.condition.is_some() => {
+                panic!("Conditioned {} operations cannot be deferred", op.gate.name());
+            },
+            QuantumGate::Classical(classical) => {
+                let bit = op.classical_bit.unwrap_or_default();
+                sources[bit] = compute(classical, &sources, &mut allocate, &mut ops);
+            },
+            gate if gate.is_measurement() => {
+                let qubit = op.target();
+                let bit = op.classical_bit.unwrap_or_default();
+                if !operations[i + 1..].iter().any(|later| depends_on(later, qubit, bit)) {
+                    ops.push(op.clone());
+                    sources[bit] = None;
+                    continue;
+                }
+                if matches!(gate, QuantumGate::MeasureY) {
+                    ops.push(GateOp::new(QuantumGate::Sdg, qubit, 0));
+                }
+                if matches!(gate, QuantumGate::MeasureX | QuantumGate::MeasureY) {
+                    ops.push(GateOp::new(QuantumGate::H, qubit, 0));
+                }
+                let ancilla = allocate();
+                ops.push(GateOp::controlled(QuantumGate::CNOT, qubit, ancilla, 0));
+                sources[bit] = Some(ancilla);
+            },
+            _ => match op.condition {
+                None => ops.push(op.clone()),
+                // A bit that was never written reads as 0
+                Some((bit, value)) => match sources[bit] {
+                    None if value => {},
+                    None => ops.push(GateOp { condition: None, ..op.clone() }),
+                    Some(control) => {
+                        if !value {
+                            ops.push(GateOp::new(QuantumGate::X, control, 0));
+                        }
+                        ops.push(GateOp {
+                            gate: controlled_gate(&op.gate),
+                            qubit: std::iter::once(control).chain(op.qubit.iter().copied()).collect(),
+                            condition: None,
+                            ..op.clone()
+                        });
+                        if !value {
+                            ops.push(GateOp::new(QuantumGate::X, control, 0));
+                        }
+                    },
+                },
+            },
+        }
+    }
+
+    let mut deferred = QuantumCircuit::new(num_qubits + num_ancillas);
+    for op in ops {
+        deferred.add_operation(op);
+    }
+    for (bit, source) in sources.into_iter().enumerate() {
+        if let Some(ancilla) = source {
+            deferred.measure(ancilla, bit);
+        }
+    }
+    deferred
+}
+
+/// Returns whether a later operation acts on the measured qubit or reads the measured bit
+fn depends_on(later: &GateOp, qubit: usize, bit: usize) -> bool {
+    let reads = match &later.gate {
+        QuantumGate::Snapshot(_) => return false,
+        QuantumGate::Classical(classical) => classical.inputs().contains(&bit),
+        _ => false,
+    };
+    reads || later.qubit.contains(&qubit) || later.condition.is_some_and(|(read, _)| read == bit)
+}
+
+/// Computes a classical operation reversibly onto a fresh ancilla and returns it, or
+/// `None` when the result is always 0
+fn compute(classical: &ClassicalOp, sources: &[Option<usize>], allocate: &mut impl FnMut() -> usize, ops: &mut Vec<GateOp>) -> Option<usize> {
+    match classical {
+        ClassicalOp::Xor(a, b) => {
+            let inputs: Vec<usize> = [sources[*a], sources[*b]].into_iter().flatten().collect();
+            if inputs.is_empty() {
+                return None;
+            }
+            let ancilla = allocate();
+            for input in inputs {
+                ops.push(GateOp::controlled(QuantumGate::CNOT, input, ancilla, 0));
+            }
+            Some(ancilla)
+        },
+        ClassicalOp::And(a, b) => {
+            let (Some(a), Some(b)) = (sources[*a], sources[*b]) else {
+                return None;
+            };
+            let ancilla = allocate();
+            // The AND of a bit with itself is a copy
+            if a == b {
+                ops.push(GateOp::controlled(QuantumGate::CNOT, a, ancilla, 0));
+            } else {
+                ops.push(GateOp::multi_qubit(QuantumGate::Toffoli, vec![a, b, ancilla], 0));
+            }
+            Some(ancilla)
+        },
+        ClassicalOp::Equals(bits, value) => {
+            if bits.len() < 64 && value >> bits.len() != 0 {
+                return None;
+            }
+            // Bits that are always 0 either never match or can be left out, and a bit listed
+            // twice either never matches or is tested once
+            let mut controls: Vec<(usize, bool)> = Vec::with_capacity(bits.len());
+            for (j, &bit) in bits.iter().enumerate() {
+                let expected = (value >> j) & 1 == 1;
+                match sources[bit] {
+                    None if expected => return None,
+                    None => {},
+                    Some(source) => match controls.iter().find(|&&(control, _)| control == source) {
+                        Some(&(_, tested)) if tested != expected => return None,
+                        Some(_) => {},
+                        None => controls.push((source, expected)),
+                    },
+                }
+            }
+
+            let ancilla = allocate();
+            let flips: Vec<GateOp> = controls.iter()
+                .filter(|&&(_, expected)| !expected)
+                .map(|&(source, _)| GateOp::new(QuantumGate::X, source, 0))
+                .collect();
+            ops.extend(flips.iter().cloned());
+            let mut qubits: Vec<usize> = controls.iter().map(|&(source, _)| source).collect();
+            qubits.push(ancilla);
+            ops.push(GateOp::multi_qubit(multi_controlled_x(controls.len()), qubits, 0));
+            ops.extend(flips);
+            Some(ancilla)
+        },
+    }
+}
+
+/// Returns the gate applying the given gate when an additional first qubit is |1⟩
+fn controlled_gate(gate: &QuantumGate) -> QuantumGate {
+    match gate {
+        QuantumGate::X => QuantumGate::CNOT,
+        QuantumGate::Z => QuantumGate::CZ,
+        QuantumGate::Rx(angle) => QuantumGate::CRx(*angle),
+        QuantumGate::Ry(angle) => QuantumGate::CRy(*angle),
+        QuantumGate::Rz(angle) => QuantumGate::CRz(*angle),
+        QuantumGate::CNOT => QuantumGate::Toffoli,
+        _ => QuantumGate::Custom(controlled(&gate.matrix()), format!("C-{}", gate.name()), gate.symbol()),
+    }
+}
+
+/// Returns the X gate controlled by the given number of qubits
+fn multi_controlled_x(num_controls: usize) -> QuantumGate {
+    match num_controls {
+        0 => QuantumGate::X,
+        1 => QuantumGate::CNOT,
+        2 => QuantumGate::Toffoli,
+        _ => {
+            let x = QuantumGate::X.matrix();
+            let matrix = (0..num_controls).fold(x, |matrix, _| controlled(&matrix));
+            QuantumGate::Custom(matrix, format!("MCX({})", num_controls), "X".to_string())
+        },
+    }
+}
+
+/// Returns the matrix of `U` controlled by an additional most significant qubit
+fn controlled(unitary: &Matrix<Complex>) -> Matrix<Complex> {
+    let dim = unitary.rows();
+    let mut matrix = Matrix::identity(2 * dim);
+    for row in 0..dim {
+        for col in 0..dim {
+            matrix.set(dim + row, dim + col, *unitary.get(row, col));
+        }
+    }
+    matrix
+}
@@ -0,0 +1,18 @@
+//! Circuit transpilation module
+//! 
+//! This module rewrites quantum circuits into simpler, equivalent ones before execution,
+//! e.g. by expanding Toffoli gates, synthesizing custom gates and cancelling redundant operations,
+//! turns feed-forward into controlled gates for backends without mid-circuit measurements, and
+//! routes circuits onto the connectivity of a device.
+
+#[allow(clippy::module_inception)]
+mod transpiler;
+mod decompose;
+mod optimize;
+mod defer;
+mod routing;
+
+pub use transpiler::{transpile, TranspileOptions};
+pub use decompose::{toffoli_decomposition, zyz_decomposition};
+pub use defer::defer_measurements;
+pub use routing::{route, RoutedCircuit, RoutingCost};
@@ -0,0 +1,70 @@
+use crate::core::{GateOp, QuantumGate};
+
+use super::decompose::is_negligible;
+
+/// Cancels adjacent inverse pairs and merges adjacent rotations around the same axis
+/// 
+/// Two operations are adjacent when no other operation touches any of their qubits
+/// in between. Measurements, snapshots, classically conditioned operations and
+/// operations with attached noise are never rewritten.
+pub(crate) fn optimize(ops: Vec<GateOp>, cancel_inverses: bool, merge_rotations: bool) -> Vec<GateOp> {
+    let mut output: Vec<GateOp> = Vec::with_capacity(ops.len());
+
+    for op in ops {
+        let previous = output.iter()
+            .rposition(|prev| touches(prev, &op))
+            .filter(|&index| output[index].qubit == op.qubit && is_rewritable(&output[index]) && is_rewritable(&op));
+
+        if let Some(index) = previous {
+            let prev = &output[index].gate;
+
+            if cancel_inverses && prev.inverse().as_ref() == Some(&op.gate) {
+                output.remove(index);
+                continue;
+            }
+
+            if merge_rotations {
+                let merged = match (prev, &op.gate) {
+                    (QuantumGate::Rx(a), QuantumGate::Rx(b)) => Some(QuantumGate::Rx(a + b)),
+                    (QuantumGate::Ry(a), QuantumGate::Ry(b)) => Some(QuantumGate::Ry(a + b)),
+                    (QuantumGate::Rz(a), QuantumGate::Rz(b)) => Some(QuantumGate::Rz(a + b)),
+                    (QuantumGate::CRx(a), QuantumGate::CRx(b)) => Some(QuantumGate::CRx(a + b)),
+                    (QuantumGate::CRy(a), QuantumGate::CRy(b)) => Some(QuantumGate::CRy(a + b)),
+                    (QuantumGate::CRz(a), QuantumGate::CRz(b)) => Some(QuantumGate::CRz(a + b)),
+                    (QuantumGate::Givens(a), QuantumGate::Givens(b)) => Some(QuantumGate::Givens(a + b)),
+                    (QuantumGate::Diagonal(a), QuantumGate::Diagonal(b)) => {
+                        Some(QuantumGate::Diagonal(a.iter().zip(b).map(|(a, b)| a + b).collect()))
+                    },
+                    _ => None,
+                };
+
+                if let Some(gate) = merged {
+                    match gate {
+                        QuantumGate::Rx(angle) | QuantumGate::Ry(angle) | QuantumGate::Rz(angle) | QuantumGate::Givens(angle)
+                            if is_negligible(angle) => {
+                            output.remove(index);
+                        },
+                        // A controlled rotation by 2π applies Z to the control, it only vanishes at 4π
+                        QuantumGate::CRx(angle) | QuantumGate::CRy(angle) | QuantumGate::CRz(angle) if is_negligible(angle / 2.0) => {
+                            output.remove(index);
+                        },
+                        _ => output[index].gate = gate,
+                    }
+                    continue;
+                }
+            }
+        }
+
+        output.push(op);
+    }
+
+    output
+}
+
+fn touches(a: &GateOp, b: &GateOp) -> bool {
+    a.qubit.iter().any(|q| b.qubit.contains(q))
+}
+
+fn is_rewritable(op: &GateOp) -> bool {
+    op.condition.is_none() && op.noise.is_none() && !op.gate.is_measurement() && !matches!(op.gate, QuantumGate::Snapshot(_))
+}
@@ -0,0 +1,274 @@
+use crate::QuantumCircuit;
+use crate::core::{GateOp, QuantumGate};
+use crate::device::DeviceModel;
+
+use super::transpiler::{transpile, TranspileOptions};
+
+/// Cost added to every hop of an error-weighted path, so that the shortest of equally
+/// reliable paths is chosen
+const HOP_PENALTY: f64 = 1e-9;
+
+/// Selects what the router minimizes when placing qubits and inserting SWAPs
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RoutingCost {
+    /// Minimize the number of SWAP gates, treating all couplings alike
+    SwapCount,
+    /// Minimize the estimated total error, weighting every coupling by the calibrated
+    /// CX error of the device and every qubit by its gate and readout errors
+    #[default]
+    Error,
+}
+
+/// A circuit mapped onto the physical qubits of a device
+#[derive(Debug, Clone)]
+pub struct RoutedCircuit {
+    /// The circuit acting on the physical qubits, whose two-qubit gates all act on
+    /// coupled qubits
+    pub circuit: QuantumCircuit,
+    /// Physical qubit holding every logical qubit at the start of the circuit
+    pub initial_layout: Vec<usize>,
+    /// Physical qubit holding every logical qubit at the end of the circuit
+    pub final_layout: Vec<usize>,
+    /// Number of SWAP gates inserted, each made of three CNOTs
+    pub num_swaps: usize,
+}
+
+/// Maps a circuit onto the connectivity of a device
+///
+/// Logical qubits are placed one after the other, each time choosing the one interacting
+/// most with those already placed, on the free physical qubit closest to its partners. Before every two-qubit
+/// gate on uncoupled qubits, the first qubit is swapped along a shortest path towards
+/// the second until they are coupled. With [`RoutingCost::Error`]
+/// the length of a coupling is `-ln(1 - e)` for its calibrated CX error `e`, so paths
+/// and placements avoid unreliable couplings, and placements also account for the
+/// single-qubit gate and readout errors of the qubits. With [`RoutingCost::SwapCount`]
+/// every coupling has length one. See [`DeviceModel::estimated_fidelity`] to compare
+/// the results.
+///
+/// Toffoli and composite gates are expanded first. Measurements, conditions and
+/// classical operations are carried over to the physical qubits, and snapshots span
+/// every physical qubit.
+///
+/// # Arguments
+/// * `circuit` - The circuit to route
+/// * `device` - The device providing the connectivity and calibrations
+/// * `cost` - The cost to minimize
+///
+/// # Panics
+/// Panics if the circuit has more qubits than the device, contains a loop or a gate
+/// acting on more than two qubits, or if two interacting qubits cannot be connected
+///
+/// # Examples
+/// ```
+/// use intrico::QuantumCircuit;
+/// use intrico::device::{DeviceModel, QubitProperties};
+/// use intrico::transpiler::{route, RoutingCost};
+///
+/// // A triangle whose coupling between qubits 0 and 1 is unreliable
+/// let qubit = QubitProperties { t1: 100_000.0, t2: 80_000.0, readout_error: 0.01 };
+/// let device = DeviceModel::new("triangle", vec![qubit; 3])
+///     .with_coupling(0, 1)
+///     .with_coupling(1, 2)
+///     .with_coupling(0, 2)
+///     .with_gate("CX", &[], 0.01, 300.0)
+///     .with_gate("CX", &[0, 1], 0.2, 300.0);
+///
+/// let mut qc = QuantumCircuit::new(2);
+/// qc.h(0);
+/// qc.cnot(0, 1);
+///
+/// // Counting SWAPs alone does not tell the couplings apart
+/// let routed = route(&qc, &device, RoutingCost::SwapCount);
+/// assert_eq!(routed.initial_layout, vec![0, 1]);
+///
+/// let routed = route(&qc, &device, RoutingCost::Error);
+/// assert_eq!(routed.initial_layout, vec![0, 2]);
+/// assert_eq!(routed.num_swaps, 0);
+/// ```
+pub fn route(circuit: &QuantumCircuit, device: &DeviceModel, cost: RoutingCost) -> RoutedCircuit {
+    let num_logical = circuit.num_qubits();
+    let num_physical = device.num_qubits();
+    if num_logical > num_physical {
+        panic!("Circuit of {} qubits does not fit on device {} of {} qubits", num_logical, device.name, num_physical);
+    }
+
+    let options = TranspileOptions {
+        expand_toffoli: true,
+        expand_composite: true,
+        synthesize_custom: false,
+        cancel_inverses: false,
+        merge_rotations: false,
+    };
+    let expanded = transpile(circuit, &options);
+    for op in expanded.operations() {
+        if matches!(op.gate, QuantumGate::Snapshot(_)) {
+            continue;
+        }
+        if matches!(op.gate, QuantumGate::RepeatUntil { .. }) {
+            panic!("Loops cannot be routed, as SWAPs inside them would move qubits between iterations");
+        }
+        if op.qubit.len() > 2 {
+            panic!("Only gates on one or two qubits can be routed, got {} on {} qubits", op.gate.name(), op.qubit.len());
+        }
+    }
+
+    let (distances, next_hops) = shortest_paths(device, cost);
+    let initial_layout = place(expanded.operations(), num_logical, device, &distances, cost);
+
+    let mut layout = initial_layout.clone();
+    let mut logical_at: Vec<Option<usize>> = vec![None; num_physical];
+    for (logical, &physical) in layout.iter().enumerate() {
+        logical_at[physical] = Some(logical);
+    }
+
+    let mut routed = QuantumCircuit::new(num_physical);
+    let mut num_swaps = 0;
+    for op in expanded.operations() {
+        // Snapshots span the whole physical register
+        if let QuantumGate::Snapshot(label) = &op.gate {
+            routed.snapshot(label.clone());
+            continue;
+        }
+        if let [a, b] = op.qubit[..] {
+            while !device.are_connected(layout[a], layout[b]) {
+                let (from, to) = (layout[a], layout[b]);
+                let hop = next_hops[from][to]
+                    .unwrap_or_else(|| panic!("Physical qubits {} and {} of device {} are not connected", from, to, device.name));
+                routed.swap(from, hop);
+                num_swaps += 1;
+
+                // Exchange whatever logical qubits sit on the two physical qubits
+                logical_at.swap(from, hop);
+                for physical in [from, hop] {
+                    if let Some(logical) = logical_at[physical] {
+                        layout[logical] = physical;
+                    }
+                }
+            }
+        }
+        routed.add_operation(GateOp {
+            qubit: op.qubit.iter().map(|&q| layout[q]).collect(),
+            ..op.clone()
+        });
+    }
+
+    RoutedCircuit { circuit: routed, initial_layout, final_layout: layout, num_swaps }
+}
+
+/// Returns the length of the coupling between two physical qubits
+fn coupling_length(device: &DeviceModel, a: usize, b: usize, cost: RoutingCost) -> f64 {
+    match cost {
+        RoutingCost::SwapCount => 1.0,
+        RoutingCost::Error => {
+            let error = device.gate_properties("CX", &[a, b]).map_or(0.0, |props| props.error);
+            -(1.0 - error).ln() + HOP_PENALTY
+        },
+    }
+}
+
+/// Returns the distances between all physical qubits and the first hop of a shortest
+/// path between them, using the Floyd–Warshall algorithm
+fn shortest_paths(device: &DeviceModel, cost: RoutingCost) -> (Vec<Vec<f64>>, Vec<Vec<Option<usize>>>) {
+    let n = device.num_qubits();
+    let mut distances = vec![vec![f64::INFINITY; n]; n];
+    let mut next_hops = vec![vec![None; n]; n];
+    for qubit in 0..n {
+        distances[qubit][qubit] = 0.0;
+        next_hops[qubit][qubit] = Some(qubit);
+    }
+    for &(a, b) in &device.connectivity {
+        let length = coupling_length(device, a, b, cost);
+        for (from, to) in [(a, b), (b, a)] {
+            if length < distances[from][to] {
+                distances[from][to] = length;
+                next_hops[from][to] = Some(to);
+            }
+        }
+    }
+
+    for via in 0..n {
+        for from in 0..n {
+            for to in 0..n {
+                let through = distances[from][via] + distances[via][to];
+                if through < distances[from][to] {
+                    distances[from][to] = through;
+                    next_hops[from][to] = next_hops[from][via];
+                }
+            }
+        }
+    }
+    (distances, next_hops)
+}
+
+/// Chooses the initial physical qubit of every logical qubit
+fn place(operations: &[GateOp], num_logical: usize, device: &DeviceModel, distances: &[Vec<f64>], cost: RoutingCost) -> Vec<usize> {
+    let operations: Vec<&GateOp> = operations.iter()
+        .filter(|op| !matches!(op.gate, QuantumGate::Snapshot(_)))
+        .collect();
+    let mut interactions = vec![vec![0usize; num_logical]; num_logical];
+    for op in &operations {
+        if let [a, b] = op.qubit[..] {
+            interactions[a][b] += 1;
+            interactions[b][a] += 1;
+        }
+    }
+
+    // Cost of running the single-qubit operations of a logical qubit on a physical one
+    let local_cost = |logical: usize, physical: usize| -> f64 {
+        if cost == RoutingCost::SwapCount {
+            return 0.0;
+        }
+        operations.iter()
+            .filter(|op| op.qubit[..] == [logical])
+            .map(|op| {
+                let error = if op.gate.is_measurement() {
+                    device.qubits[physical].readout_error
+                } else {
+                    device.operation_properties(&GateOp { qubit: vec![physical], ..(*op).clone() }).map_or(0.0, |props| props.error)
+                };
+                -(1.0 - error).ln()
+            })
+            .sum()
+    };
+
+    let mut layout = vec![usize::MAX; num_logical];
+    let mut used = vec![false; device.num_qubits()];
+    for _ in 0..num_logical {
+        // Grow the layout from the qubits interacting most with those already placed
+        let logical = (0..num_logical)
+            .filter(|&logical| layout[logical] == usize::MAX)
+            .max_by_key(|&logical| {
+                let placed: usize = (0..num_logical)
+                    .filter(|&partner| layout[partner] != usize::MAX)
+                    .map(|partner| interactions[logical][partner])
+                    .sum();
+                (placed, interactions[logical].iter().sum::<usize>(), std::cmp::Reverse(logical))
+            })
+            .expect("An unplaced qubit remains");
+
+        // Partners placed later are expected on the closest free qubit
+        let score = |physical: usize| -> f64 {
+            let interaction: f64 = (0..num_logical)
+                .filter(|&partner| interactions[logical][partner] > 0)
+                .map(|partner| {
+                    let distance = match layout[partner] {
+                        usize::MAX => (0..device.num_qubits())
+                            .filter(|&other| other != physical && !used[other])
+                            .map(|other| distances[physical][other])
+                            .fold(f64::INFINITY, f64::min),
+                        placed => distances[physical][placed],
+                    };
+                    interactions[logical][partner] as f64 * distance
+                })
+                .sum();
+            interaction + local_cost(logical, physical)
+        };
+        let physical = (0..device.num_qubits())
+            .filter(|&physical| !used[physical])
+            .min_by(|&a, &b| score(a).total_cmp(&score(b)))
+            .expect("The device has enough qubits");
+        layout[logical] = physical;
+        used[physical] = true;
+    }
+    layout
+}
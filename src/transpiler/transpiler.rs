@@ -0,0 +1,113 @@
+use crate::QuantumCircuit;
+use crate::core::{GateOp, QuantumGate};
+use crate::trace::span;
+
+use super::decompose::{toffoli_decomposition, zyz_decomposition};
+use super::optimize::optimize;
+
+/// Selects the passes applied by [`transpile`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TranspileOptions {
+    /// Expand Toffoli gates into CNOT, H, T and T† gates
+    pub expand_toffoli: bool,
+    /// Expand user-defined composite gates into the operations of their definition
+    pub expand_composite: bool,
+    /// Replace single-qubit custom gates by Rz·Ry·Rz rotations
+    pub synthesize_custom: bool,
+    /// Remove adjacent gates that cancel each other
+    pub cancel_inverses: bool,
+    /// Merge adjacent rotations around the same axis
+    pub merge_rotations: bool,
+}
+
+impl Default for TranspileOptions {
+    fn default() -> Self {
+        TranspileOptions {
+            expand_toffoli: true,
+            expand_composite: true,
+            synthesize_custom: true,
+            cancel_inverses: true,
+            merge_rotations: true,
+        }
+    }
+}
+
+/// Rewrites a circuit into an equivalent one according to the given options
+/// 
+/// Decompositions preserve the circuit up to a global phase and copy the classical
+/// condition of the original operation onto every replacement. Measurements,
+/// snapshots, conditioned operations and operations with attached noise are kept in
+/// place and act as barriers for the optimization passes; noisy operations are not
+/// decomposed either, so their channel keeps acting on the same qubits.
+/// 
+/// # Arguments
+/// * `circuit` - The circuit to transpile
+/// * `options` - The passes to apply
+/// 
+/// # Examples
+/// ```
+/// use intrico::QuantumCircuit;
+/// use intrico::transpiler::{transpile, TranspileOptions};
+/// 
+/// let mut qc = QuantumCircuit::new(3);
+/// qc.h(0);
+/// qc.h(0);
+/// qc.ccx(0, 1, 2);
+/// 
+/// let transpiled = transpile(&qc, &TranspileOptions::default());
+/// // The Hadamards cancel and the Toffoli is expanded into 15 elementary gates
+/// assert_eq!(transpiled.num_operations(), 15);
+/// ```
+pub fn transpile(circuit: &QuantumCircuit, options: &TranspileOptions) -> QuantumCircuit {
+    let _span = span!(INFO, "transpile", qubits = circuit.num_qubits(), operations = circuit.num_operations());
+    let mut ops = Vec::with_capacity(circuit.num_operations());
+
+    {
+        let _span = span!(DEBUG, "decompose");
+        for op in circuit.operations() {
+            decompose(op, options, &mut ops);
+        }
+    }
+
+    if options.cancel_inverses || options.merge_rotations {
+        let _span = span!(DEBUG, "optimize", operations = ops.len());
+        ops = optimize(ops, options.cancel_inverses, options.merge_rotations);
+    }
+
+    let mut transpiled = QuantumCircuit::new(circuit.num_qubits());
+    for op in ops {
+        transpiled.add_operation(op);
+    }
+    transpiled
+}
+
+/// Appends the decomposition of a single operation selected by the options
+/// 
+/// Composite gates are expanded recursively, so the gates of their definition are
+/// decomposed as well.
+fn decompose(op: &GateOp, options: &TranspileOptions, ops: &mut Vec<GateOp>) {
+    match &op.gate {
+        QuantumGate::Toffoli if options.expand_toffoli && op.noise.is_none() => {
+            ops.extend(toffoli_decomposition(op.qubit[0], op.qubit[1], op.qubit[2])
+                .into_iter()
+                .map(|expanded| GateOp { condition: op.condition, ..expanded }));
+        },
+        QuantumGate::Custom(matrix, _, _) if options.synthesize_custom && op.qubit.len() == 1 && op.noise.is_none() => {
+            let target = op.target();
+            ops.extend(zyz_decomposition(matrix)
+                .into_iter()
+                .map(|gate| GateOp { condition: op.condition, ..GateOp::new(gate, target, 0) }));
+        },
+        QuantumGate::Composite { body, .. } if options.expand_composite && op.noise.is_none() => {
+            for inner in body {
+                let mapped = GateOp {
+                    qubit: inner.qubit.iter().map(|&q| op.qubit[q]).collect(),
+                    condition: op.condition,
+                    ..inner.clone()
+                };
+                decompose(&mapped, options, ops);
+            }
+        },
+        _ => ops.push(op.clone()),
+    }
+}
@@ -0,0 +1,254 @@
+//! Terminal UI module
+//!
+//! This module provides an interactive terminal explorer for circuits, built on `ratatui`.
+//! It steps through a circuit one layer at a time, showing the diagram with the current
+//! column highlighted next to the statevector and its probabilities after that layer.
+//! Measurements are ignored, as in [`QuantumCircuit::execute`].
+//!
+//! | Key | Action |
+//! |-----|--------|
+//! | `→` / `l` / `Space` | Next step |
+//! | `←` / `h` | Previous step |
+//! | `Home` / `End` | First / last step |
+//! | `a` | Toggle between populated and all basis states |
+//! | `q` / `Esc` | Quit |
+
+use std::io;
+
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Paragraph, Row, Table};
+use ratatui::Frame;
+
+use crate::circuit::{circuit, QuantumCircuit, StateVector};
+use crate::utility::{index_to_bitstring, ComplexFormat, Endianness, Tolerance};
+
+/// Width in characters of a bar of probability one
+const BAR_WIDTH: usize = 30;
+
+/// Style of the diagram column of the current step
+const HIGHLIGHT: Style = Style::new().fg(Color::Black).bg(Color::Yellow);
+
+/// Step-by-step state of a circuit being explored
+///
+/// The explorer precomputes the statevector after every layer of the circuit, so moving
+/// between steps is instant. Step 0 is the initial |0…0⟩ state and step `n` the state
+/// after the `n`-th column of the diagram.
+///
+/// # Examples
+/// ```
+/// use intrico::QuantumCircuit;
+/// use intrico::tui::Explorer;
+///
+/// let mut qc = QuantumCircuit::new(2);
+/// qc.h(0);
+/// qc.cnot(0, 1);
+///
+/// let mut explorer = Explorer::new(&qc);
+/// assert_eq!(explorer.num_steps(), 2);
+/// explorer.next();
+/// assert!((explorer.state().probabilities()[1] - 0.5).abs() < 1e-6);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Explorer {
+    /// Diagram cells, one row per wire and gap and one column per step
+    grid: Vec<Vec<String>>,
+    /// Padded wire labels, one per qubit
+    labels: Vec<String>,
+    /// Statevector after every step, starting with the initial state
+    states: Vec<StateVector>,
+    /// Index of the current step
+    step: usize,
+    /// Whether basis states with zero amplitude are listed
+    show_all: bool,
+}
+
+impl Explorer {
+    /// Creates an explorer positioned at the initial state of a circuit
+    pub fn new(circuit: &QuantumCircuit) -> Self {
+        let grid = circuit.diagram_grid();
+        let num_steps = grid.first().map_or(0, |row| row.len().saturating_sub(1));
+
+        let mut state = circuit.initial_state();
+        let mut states = vec![StateVector::new(state.clone())];
+        for step in 1..=num_steps {
+            for op in circuit.unmeasured_operations().filter(|op| op.step == step) {
+                circuit.apply_operation(&mut state, op);
+            }
+            states.push(StateVector::new(Tolerance::global().round_state(&state)));
+        }
+
+        Explorer { grid, labels: circuit.wire_labels(), states, step: 0, show_all: false }
+    }
+
+    /// Returns the number of steps in the circuit
+    pub fn num_steps(&self) -> usize {
+        self.states.len() - 1
+    }
+
+    /// Returns the index of the current step, 0 for the initial state
+    pub fn step(&self) -> usize {
+        self.step
+    }
+
+    /// Returns the statevector after the current step
+    pub fn state(&self) -> &StateVector {
+        &self.states[self.step]
+    }
+
+    /// Moves to the next step, staying on the last one
+    pub fn next(&mut self) {
+        self.step = (self.step + 1).min(self.num_steps());
+    }
+
+    /// Moves to the previous step, staying on the initial state
+    pub fn previous(&mut self) {
+        self.step = self.step.saturating_sub(1);
+    }
+
+    /// Moves to the given step, clamped to the last one
+    pub fn go_to(&mut self, step: usize) {
+        self.step = step.min(self.num_steps());
+    }
+
+    /// Toggles between listing the populated basis states and all of them
+    pub fn toggle_all_states(&mut self) {
+        self.show_all = !self.show_all;
+    }
+
+    /// Updates the explorer for a key press and returns `false` if it asks to quit
+    pub fn handle_key(&mut self, key: KeyCode) -> bool {
+        match key {
+            KeyCode::Right | KeyCode::Char('l') | KeyCode::Char(' ') => self.next(),
+            KeyCode::Left | KeyCode::Char('h') => self.previous(),
+            KeyCode::Home => self.go_to(0),
+            KeyCode::End => self.go_to(self.num_steps()),
+            KeyCode::Char('a') => self.toggle_all_states(),
+            KeyCode::Char('q') | KeyCode::Esc => return false,
+            _ => {},
+        }
+        true
+    }
+
+    /// Draws the diagram, the statevector table and the key help onto a frame
+    pub fn render(&self, frame: &mut Frame) {
+        let diagram_height = self.grid.len() as u16 + 2;
+        let [diagram, state, help] = Layout::vertical([
+            Constraint::Length(diagram_height),
+            Constraint::Min(3),
+            Constraint::Length(1),
+        ]).areas(frame.area());
+
+        self.render_diagram(frame, diagram);
+        self.render_state(frame, state);
+        frame.render_widget(
+            Paragraph::new("←/→ step  Home/End first/last  a all states  q quit").style(Style::new().add_modifier(Modifier::DIM)),
+            help,
+        );
+    }
+
+    /// Draws the diagram with its columns aligned and the current one highlighted
+    fn render_diagram(&self, frame: &mut Frame, area: Rect) {
+        let columns = self.grid.first().map_or(0, Vec::len);
+        let widths: Vec<usize> = (0..columns)
+            .map(|col| self.grid.iter().map(|row| row[col].chars().count()).max().unwrap_or(0))
+            .collect();
+        let label_width = self.labels.first().map_or(0, |label| label.chars().count());
+
+        let lines: Vec<Line> = self.grid.iter().enumerate()
+            .map(|(i, row)| {
+                let label = if i % 2 == 0 { self.labels[i / 2].as_str() } else { "" };
+                let mut spans = vec![Span::raw(format!("{:<label_width$}", label))];
+                for (col, cell) in row.iter().enumerate().skip(1) {
+                    let text = pad_cell(cell, widths[col], i % 2 == 0);
+                    let style = if col == self.step { HIGHLIGHT } else { Style::new() };
+                    spans.push(Span::styled(text, style));
+                }
+                Line::from(spans)
+            })
+            .collect();
+
+        let title = format!(" Circuit · step {}/{} ", self.step, self.num_steps());
+        frame.render_widget(Paragraph::new(lines).block(Block::bordered().title(title)), area);
+    }
+
+    /// Draws the amplitudes and probabilities of the current state
+    fn render_state(&self, frame: &mut Frame, area: Rect) {
+        let state = self.state();
+        let num_qubits = state.num_qubits();
+        let rows: Vec<Row> = state.iter().enumerate()
+            .filter(|(_, amp)| self.show_all || amp.norm_squared() >= state.threshold())
+            .map(|(index, &amp)| {
+                let probability = amp.norm_squared();
+                let bar = "█".repeat((probability * BAR_WIDTH as f64).round() as usize);
+                Row::new([
+                    format!("|{}⟩", index_to_bitstring(index, num_qubits, Endianness::Little)),
+                    ComplexFormat::DEFAULT.format(amp),
+                    format!("{:.4}", probability),
+                    bar,
+                ])
+            })
+            .collect();
+
+        let widths = [
+            Constraint::Length(num_qubits as u16 + 2),
+            Constraint::Length(18),
+            Constraint::Length(11),
+            Constraint::Length(BAR_WIDTH as u16),
+        ];
+        let header = Row::new(["State", "Amplitude", "Probability", ""]).style(Style::new().add_modifier(Modifier::BOLD));
+        let table = Table::new(rows, widths)
+            .header(header)
+            .column_spacing(2)
+            .block(Block::bordered().title(" Statevector "));
+        frame.render_widget(table, area);
+    }
+}
+
+impl QuantumCircuit {
+    /// Opens the interactive explorer for the circuit in the terminal
+    ///
+    /// Blocks until the user quits and restores the terminal afterwards. See the
+    /// [module documentation](crate::tui) for the key bindings.
+    ///
+    /// # Errors
+    /// Returns an error if the terminal cannot be set up or read from
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use intrico::QuantumCircuit;
+    ///
+    /// let mut qc = QuantumCircuit::new(2);
+    /// qc.h(0);
+    /// qc.cnot(0, 1);
+    /// qc.explore().unwrap();
+    /// ```
+    pub fn explore(&self) -> io::Result<()> {
+        let mut explorer = Explorer::new(self);
+        let mut terminal = ratatui::init();
+        let result = (|| loop {
+            terminal.draw(|frame| explorer.render(frame))?;
+            if let Event::Key(key) = event::read()?
+                && key.kind == KeyEventKind::Press
+                && !explorer.handle_key(key.code)
+            {
+                return Ok(());
+            }
+        })();
+        ratatui::restore();
+        result
+    }
+}
+
+/// Pads a diagram cell to a column width, extending wires with `─` and gaps with spaces
+///
+/// Gap cells other than connectors and barriers are left blank, as in [`QuantumCircuit::draw`].
+fn pad_cell(cell: &str, width: usize, wire: bool) -> String {
+    match (wire, cell) {
+        (true, _) => circuit::pad_cell(cell, width, '─'),
+        (false, " │ " | " ░ ") => circuit::pad_cell(cell, width, ' '),
+        (false, _) => " ".repeat(width),
+    }
+}
@@ -0,0 +1,69 @@
+/// Order in which the bits of a basis-state index are written in a bitstring
+///
+/// Indices follow the statevector convention: bit `j` of the index is qubit `j`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Endianness {
+    /// Qubit 0 is the rightmost character, as in counts and printed states
+    #[default]
+    Little,
+    /// Qubit 0 is the leftmost character
+    Big,
+}
+
+/// Writes a basis-state index as a bitstring of `num_bits` characters
+///
+/// # Arguments
+/// * `index` - The basis-state index
+/// * `num_bits` - The number of characters, bits beyond it are dropped
+/// * `endianness` - The side qubit 0 is written on
+///
+/// # Examples
+/// ```
+/// use intrico::utility::{index_to_bitstring, Endianness};
+///
+/// // Index 1 has only qubit 0 set
+/// assert_eq!(index_to_bitstring(1, 4, Endianness::Little), "0001");
+/// assert_eq!(index_to_bitstring(1, 4, Endianness::Big), "1000");
+/// ```
+pub fn index_to_bitstring(index: usize, num_bits: usize, endianness: Endianness) -> String {
+    let bit = |position: usize| if position < usize::BITS as usize && index >> position & 1 == 1 { '1' } else { '0' };
+    match endianness {
+        Endianness::Little => (0..num_bits).rev().map(bit).collect(),
+        Endianness::Big => (0..num_bits).map(bit).collect(),
+    }
+}
+
+/// Reads a bitstring back into a basis-state index
+///
+/// Returns `None` if the bitstring contains characters other than `0` and `1` or
+/// does not fit into a `usize`.
+///
+/// # Arguments
+/// * `bitstring` - The bitstring, e.g. a key of the counts
+/// * `endianness` - The side qubit 0 is written on
+///
+/// # Examples
+/// ```
+/// use intrico::utility::{bitstring_to_index, Endianness};
+///
+/// assert_eq!(bitstring_to_index("0001", Endianness::Little), Some(1));
+/// assert_eq!(bitstring_to_index("1000", Endianness::Big), Some(1));
+/// assert_eq!(bitstring_to_index("0001", Endianness::Big), Some(8));
+/// assert_eq!(bitstring_to_index("01a", Endianness::Little), None);
+/// ```
+pub fn bitstring_to_index(bitstring: &str, endianness: Endianness) -> Option<usize> {
+    let len = bitstring.len();
+    bitstring.bytes()
+        .enumerate()
+        .try_fold(0usize, |index, (position, byte)| {
+            let qubit = match endianness {
+                Endianness::Little => len - 1 - position,
+                Endianness::Big => position,
+            };
+            match byte {
+                b'0' => Some(index),
+                b'1' if qubit < usize::BITS as usize => Some(index | 1 << qubit),
+                _ => None,
+            }
+        })
+}
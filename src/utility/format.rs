@@ -0,0 +1,205 @@
+use rusticle::complex::Complex;
+
+use super::{index_to_bitstring, Endianness};
+
+/// How a complex number is written
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Notation {
+    /// Real and imaginary parts, such as `0.5-0.5i`
+    #[default]
+    Cartesian,
+    /// Magnitude and phase in radians, such as `0.7071∠-0.7854`
+    Polar,
+}
+
+/// Policy for rendering complex numbers and statevectors as readable text
+///
+/// Numbers are written with a fixed number of decimals in either [`Notation`]. With
+/// suppression enabled, parts that round to zero are left out, trailing zeros are
+/// trimmed and zero amplitudes are skipped in statevectors, so `0.70710678+0i` reads
+/// as `0.7071`. Displaying a [`Qubit`](crate::Qubit) or a
+/// [`SimulationResult`](crate::simulator::SimulationResult) uses
+/// [`ComplexFormat::DEFAULT`], with the precision taken from the formatter when one is
+/// given, as in `{:.2}`.
+///
+/// # Examples
+/// ```
+/// use rusticle::complex::Complex;
+/// use intrico::utility::{ComplexFormat, Notation};
+///
+/// let c = Complex::new(0.5, -0.5);
+/// assert_eq!(ComplexFormat::DEFAULT.format(c), "0.5-0.5i");
+/// assert_eq!(ComplexFormat::DEFAULT.with_suppression(false).format(c), "0.5000-0.5000i");
+/// assert_eq!(ComplexFormat::DEFAULT.with_notation(Notation::Polar).format(c), "0.7071∠-0.7854");
+///
+/// let h = 1.0 / 2.0_f64.sqrt();
+/// let bell = vec![Complex::new(h, 0.0), Complex::new(0.0, 0.0), Complex::new(0.0, 0.0), Complex::new(-h, 0.0)];
+/// assert_eq!(ComplexFormat::DEFAULT.format_state(&bell), "0.7071|00⟩ - 0.7071|11⟩");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ComplexFormat {
+    precision: usize,
+    notation: Notation,
+    suppress: bool,
+    max_terms: Option<usize>,
+}
+
+impl ComplexFormat {
+    /// Four decimals in cartesian notation, with negligible parts suppressed
+    pub const DEFAULT: ComplexFormat = ComplexFormat {
+        precision: 4,
+        notation: Notation::Cartesian,
+        suppress: true,
+        max_terms: None,
+    };
+
+    /// Sets the number of decimals
+    pub const fn with_precision(mut self, precision: usize) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Sets the notation
+    pub const fn with_notation(mut self, notation: Notation) -> Self {
+        self.notation = notation;
+        self
+    }
+
+    /// Sets whether parts and amplitudes that round to zero are left out
+    pub const fn with_suppression(mut self, suppress: bool) -> Self {
+        self.suppress = suppress;
+        self
+    }
+
+    /// Sets the number of statevector terms written before eliding the rest, `None` for all
+    pub const fn with_max_terms(mut self, max_terms: Option<usize>) -> Self {
+        self.max_terms = max_terms;
+        self
+    }
+
+    /// Returns the number of decimals
+    pub fn precision(&self) -> usize {
+        self.precision
+    }
+
+    /// Returns the notation
+    pub fn notation(&self) -> Notation {
+        self.notation
+    }
+
+    /// Returns whether negligible parts are suppressed
+    pub fn suppress(&self) -> bool {
+        self.suppress
+    }
+
+    /// Returns the maximum number of statevector terms, if limited
+    pub fn max_terms(&self) -> Option<usize> {
+        self.max_terms
+    }
+
+    /// Formats a single complex number
+    pub fn format(&self, value: Complex) -> String {
+        match self.notation {
+            Notation::Cartesian => self.cartesian(value),
+            Notation::Polar => self.polar(value),
+        }
+    }
+
+    /// Formats a statevector in Dirac notation, with qubit 0 rightmost in the labels
+    ///
+    /// Coefficients of exactly 1 are left out, and coefficients with two parts are
+    /// parenthesized. A state without any written term renders as `0`.
+    pub fn format_state(&self, state: &[Complex]) -> String {
+        let num_bits = state.len().max(1).ilog2() as usize;
+        let terms: Vec<String> = state.iter()
+            .enumerate()
+            .map(|(index, &amplitude)| (index, self.format(amplitude)))
+            .filter(|(_, coefficient)| !self.suppress || coefficient != "0")
+            .map(|(index, coefficient)| {
+                let coefficient = match coefficient.as_str() {
+                    "1" => String::new(),
+                    "-1" => "-".to_string(),
+                    _ if coefficient[1..].contains(['+', '-', '∠']) => format!("({})", coefficient),
+                    _ => coefficient,
+                };
+                format!("{}|{}⟩", coefficient, index_to_bitstring(index, num_bits, Endianness::Little))
+            })
+            .collect();
+
+        if terms.is_empty() {
+            return "0".to_string();
+        }
+        let shown = self.max_terms.unwrap_or(terms.len()).min(terms.len());
+        let mut rendered = terms[0].clone();
+        for term in &terms[1..shown.max(1)] {
+            match term.strip_prefix('-') {
+                Some(rest) => rendered.push_str(&format!(" - {}", rest)),
+                None => rendered.push_str(&format!(" + {}", term)),
+            }
+        }
+        if shown.max(1) < terms.len() {
+            rendered.push_str(" + …");
+        }
+        rendered
+    }
+
+    /// Writes a cartesian number such as `0.5-0.5i`
+    fn cartesian(&self, value: Complex) -> String {
+        if !self.suppress {
+            let sign = if value.imag < 0.0 { '-' } else { '+' };
+            return format!("{}{}{}i", self.number(value.real), sign, self.number(value.imag.abs()));
+        }
+        match (self.negligible(value.real), self.negligible(value.imag)) {
+            (true, true) => "0".to_string(),
+            (false, true) => self.number(value.real),
+            (true, false) => self.imaginary(value.imag),
+            (false, false) => {
+                let sign = if value.imag < 0.0 { '-' } else { '+' };
+                format!("{}{}{}", self.number(value.real), sign, self.imaginary(value.imag.abs()))
+            },
+        }
+    }
+
+    /// Writes a polar number such as `0.7071∠0.7854`
+    fn polar(&self, value: Complex) -> String {
+        let (magnitude, phase) = (value.norm(), value.imag.atan2(value.real));
+        if self.suppress && self.negligible(magnitude) {
+            "0".to_string()
+        } else if self.suppress && self.negligible(phase) {
+            self.number(magnitude)
+        } else {
+            format!("{}∠{}", self.number(magnitude), self.number(phase))
+        }
+    }
+
+    /// Writes an imaginary part, as `i` or `-i` when it has unit size
+    fn imaginary(&self, value: f64) -> String {
+        match self.number(value).as_str() {
+            "1" => "i".to_string(),
+            "-1" => "-i".to_string(),
+            number => format!("{}i", number),
+        }
+    }
+
+    /// Writes a real number with the configured decimals
+    fn number(&self, value: f64) -> String {
+        let value = if self.negligible(value) { 0.0 } else { value };
+        let number = format!("{:.*}", self.precision, value);
+        if self.suppress && number.contains('.') {
+            number.trim_end_matches('0').trim_end_matches('.').to_string()
+        } else {
+            number
+        }
+    }
+
+    /// Returns whether a value rounds to zero at the configured precision
+    fn negligible(&self, value: f64) -> bool {
+        (value.abs() * 10f64.powi(self.precision as i32)).round() == 0.0
+    }
+}
+
+impl Default for ComplexFormat {
+    fn default() -> Self {
+        ComplexFormat::DEFAULT
+    }
+}
@@ -0,0 +1,328 @@
+use rusticle::complex::Complex;
+use rusticle::linalg::Matrix;
+
+/// Maximum number of Jacobi sweeps before giving up on convergence
+const MAX_SWEEPS: usize = 100;
+
+/// Computes the eigendecomposition of a Hermitian matrix using complex Jacobi rotations
+///
+/// Returns the eigenvalues in ascending order together with a unitary matrix whose
+/// columns are the corresponding eigenvectors.
+///
+/// # Arguments
+/// * `matrix` - A square Hermitian matrix
+///
+/// # Panics
+/// Panics if the matrix is not square
+///
+/// # Examples
+/// ```
+/// use rusticle::complex::Complex;
+/// use rusticle::linalg::Matrix;
+/// use intrico::utility::hermitian_eigen;
+///
+/// // Pauli-Y has eigenvalues -1 and +1
+/// let y = Matrix::new(2, 2, vec![
+///     Complex::new(0.0, 0.0), Complex::new(0.0, -1.0),
+///     Complex::new(0.0, 1.0), Complex::new(0.0, 0.0),
+/// ]);
+/// let (values, _vectors) = hermitian_eigen(&y);
+/// assert!((values[0] + 1.0).abs() < 1e-10);
+/// assert!((values[1] - 1.0).abs() < 1e-10);
+/// ```
+pub fn hermitian_eigen(matrix: &Matrix<Complex>) -> (Vec<f64>, Matrix<Complex>) {
+    if matrix.rows() != matrix.cols() {
+        panic!("Eigendecomposition requires a square matrix, got {}x{}", matrix.rows(), matrix.cols());
+    }
+    let n = matrix.rows();
+    let zero = Complex::new(0.0, 0.0);
+
+    let mut a: Vec<Complex> = (0..n * n).map(|k| *matrix.get(k / n, k % n)).collect();
+    let mut v = vec![zero; n * n];
+    for i in 0..n {
+        v[i * n + i] = Complex::new(1.0, 0.0);
+    }
+
+    let scale: f64 = a.iter().map(|x| x.norm_squared()).sum::<f64>().max(1e-300);
+
+    for _ in 0..MAX_SWEEPS {
+        let off: f64 = (0..n)
+            .flat_map(|p| (0..n).filter(move |&q| q != p).map(move |q| (p, q)))
+            .map(|(p, q)| a[p * n + q].norm_squared())
+            .sum();
+        if off <= 1e-28 * scale {
+            break;
+        }
+
+        for p in 0..n {
+            for q in (p + 1)..n {
+                let apq = a[p * n + q];
+                let magnitude = apq.norm();
+                if magnitude < 1e-300 {
+                    continue;
+                }
+
+                // Remove the phase of the pivot, then apply a real Jacobi rotation
+                let phase = apq / magnitude;
+                let theta = (a[q * n + q].real - a[p * n + p].real) / (2.0 * magnitude);
+                let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                let c = 1.0 / (t * t + 1.0).sqrt();
+                let s = t * c;
+
+                let g00 = Complex::new(c, 0.0);
+                let g01 = Complex::new(s, 0.0);
+                let g10 = -(phase.conjugate() * s);
+                let g11 = phase.conjugate() * c;
+
+                // A <- A G and V <- V G
+                for k in 0..n {
+                    let (akp, akq) = (a[k * n + p], a[k * n + q]);
+                    a[k * n + p] = akp * g00 + akq * g10;
+                    a[k * n + q] = akp * g01 + akq * g11;
+
+                    let (vkp, vkq) = (v[k * n + p], v[k * n + q]);
+                    v[k * n + p] = vkp * g00 + vkq * g10;
+                    v[k * n + q] = vkp * g01 + vkq * g11;
+                }
+                // A <- G† A
+                for k in 0..n {
+                    let (apk, aqk) = (a[p * n + k], a[q * n + k]);
+                    a[p * n + k] = g00.conjugate() * apk + g10.conjugate() * aqk;
+                    a[q * n + k] = g01.conjugate() * apk + g11.conjugate() * aqk;
+                }
+            }
+        }
+    }
+
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|&i, &j| a[i * n + i].real.total_cmp(&a[j * n + j].real));
+
+    let values = order.iter().map(|&i| a[i * n + i].real).collect();
+    let mut vectors = Matrix::zeros(n, n);
+    for (col, &i) in order.iter().enumerate() {
+        for row in 0..n {
+            vectors.set(row, col, v[row * n + i]);
+        }
+    }
+
+    (values, vectors)
+}
+
+/// Extends a set of orthonormal columns to a full unitary matrix
+///
+/// The given columns are kept in order as the first columns of the result, and the
+/// remaining ones are completed by Gram-Schmidt on the standard basis.
+///
+/// # Arguments
+/// * `columns` - Orthonormal vectors of a common dimension, at most that many
+///
+/// # Panics
+/// Panics if no columns are given, their lengths differ, there are more columns than
+/// their dimension, or they are not orthonormal
+///
+/// # Examples
+/// ```
+/// use rusticle::complex::Complex;
+/// use intrico::utility::complete_unitary;
+///
+/// // Any unitary whose first column is |+⟩ prepares |+⟩ from |0⟩
+/// let h = 1.0 / 2.0_f64.sqrt();
+/// let plus = vec![Complex::new(h, 0.0), Complex::new(h, 0.0)];
+/// let unitary = complete_unitary(&[plus]);
+/// assert!((unitary.get(0, 1).real - h).abs() < 1e-10);
+/// assert!((unitary.get(1, 1).real + h).abs() < 1e-10);
+/// ```
+pub fn complete_unitary(columns: &[Vec<Complex>]) -> Matrix<Complex> {
+    let Some(first) = columns.first() else {
+        panic!("Unitary completion requires at least one column");
+    };
+    let dim = first.len();
+    if let Some(column) = columns.iter().find(|column| column.len() != dim) {
+        panic!("Column lengths differ: {} and {}", dim, column.len());
+    }
+    if columns.len() > dim {
+        panic!("Cannot complete {} columns of dimension {}", columns.len(), dim);
+    }
+    for (i, a) in columns.iter().enumerate() {
+        for (j, b) in columns.iter().enumerate().skip(i) {
+            let overlap = a.iter().zip(b).fold(Complex::new(0.0, 0.0), |acc, (x, y)| acc + x.conjugate() * *y);
+            let expected = if i == j { 1.0 } else { 0.0 };
+            if (overlap - Complex::new(expected, 0.0)).norm() > 1e-8 {
+                panic!("Columns {} and {} are not orthonormal", i, j);
+            }
+        }
+    }
+
+    let mut columns: Vec<Vec<Complex>> = columns.to_vec();
+
+    for basis in 0..dim {
+        if columns.len() == dim {
+            break;
+        }
+        let mut column: Vec<Complex> = (0..dim)
+            .map(|i| Complex::new(if i == basis { 1.0 } else { 0.0 }, 0.0))
+            .collect();
+        // Two passes of modified Gram-Schmidt keep the new column orthogonal to working precision
+        for _ in 0..2 {
+            for other in &columns {
+                let overlap = other.iter().zip(&column).fold(Complex::new(0.0, 0.0), |acc, (o, x)| acc + o.conjugate() * *x);
+                for (entry, &o) in column.iter_mut().zip(other) {
+                    *entry -= o * overlap;
+                }
+            }
+        }
+        let norm = column.iter().map(|amp| amp.norm_squared()).sum::<f64>().sqrt();
+        if norm > 1e-6 {
+            columns.push(column.into_iter().map(|amp| amp / norm).collect());
+        }
+    }
+
+    let mut matrix = Matrix::zeros(dim, dim);
+    for (c, column) in columns.iter().enumerate() {
+        for (r, &entry) in column.iter().enumerate() {
+            matrix.set(r, c, entry);
+        }
+    }
+    matrix
+}
+
+/// Returns `e^(iAt)` using a scaled Taylor series followed by repeated squaring
+#[cfg(feature = "core")]
+pub(crate) fn exponentiate(matrix: &Matrix<Complex>, time: f64) -> Matrix<Complex> {
+    let dim = matrix.rows();
+    let norm = (0..dim)
+        .map(|r| (0..dim).map(|c| matrix.get(r, c).norm()).sum::<f64>())
+        .fold(0.0, f64::max) * time.abs();
+    let squarings = norm.max(1.0).log2().ceil() as u32 + 1;
+    let scale = time / (1u64 << squarings) as f64;
+
+    let mut generator = Matrix::zeros(dim, dim);
+    for r in 0..dim {
+        for c in 0..dim {
+            generator.set(r, c, *matrix.get(r, c) * Complex::new(0.0, scale));
+        }
+    }
+
+    let mut result = Matrix::identity(dim);
+    let mut term = Matrix::identity(dim);
+    for k in 1..=20 {
+        term = &term * &generator;
+        for r in 0..dim {
+            for c in 0..dim {
+                let value = *term.get(r, c) / k as f64;
+                term.set(r, c, value);
+                result.set(r, c, *result.get(r, c) + value);
+            }
+        }
+    }
+
+    for _ in 0..squarings {
+        result = &result * &result;
+    }
+    result
+}
+
+/// Computes the principal logarithm of a unitary matrix
+///
+/// The unitary is diagonalized as `U = V·diag(e^(iθ))·V†` with every phase `θ` in
+/// `(-π, π]`, and the logarithm is `V·diag(iθ)·V†`. Eigenvectors are found by
+/// diagonalizing the Hermitian part `(U + U†)/2`, then the anti-Hermitian part within
+/// each of its degenerate eigenspaces, so repeated eigenvalues are handled exactly.
+///
+/// # Arguments
+/// * `matrix` - A square unitary matrix
+///
+/// # Panics
+/// Panics if the matrix is not square or not unitary
+///
+/// # Examples
+/// ```
+/// use rusticle::complex::Complex;
+/// use intrico::QuantumGate;
+/// use intrico::utility::matrix_log;
+///
+/// // log(S) = diag(0, iπ/2)
+/// let log = matrix_log(&QuantumGate::S.matrix());
+/// assert!(log.get(0, 0).norm() < 1e-10);
+/// assert!((*log.get(1, 1) - Complex::new(0.0, std::f64::consts::FRAC_PI_2)).norm() < 1e-10);
+/// ```
+pub fn matrix_log(matrix: &Matrix<Complex>) -> Matrix<Complex> {
+    let n = matrix.rows();
+    if matrix.cols() != n {
+        panic!("Matrix logarithm requires a square matrix, got {}x{}", n, matrix.cols());
+    }
+    let adjoint = matrix.conjugate_transpose();
+    let product = &adjoint * matrix;
+    for r in 0..n {
+        for c in 0..n {
+            let expected = if r == c { 1.0 } else { 0.0 };
+            if (*product.get(r, c) - Complex::new(expected, 0.0)).norm() > 1e-8 {
+                panic!("Matrix logarithm requires a unitary matrix");
+            }
+        }
+    }
+
+    let mut hermitian = Matrix::zeros(n, n);
+    let mut anti_hermitian = Matrix::zeros(n, n);
+    for r in 0..n {
+        for c in 0..n {
+            let (u, u_dagger) = (*matrix.get(r, c), *adjoint.get(r, c));
+            hermitian.set(r, c, (u + u_dagger) / 2.0);
+            anti_hermitian.set(r, c, (u - u_dagger) * Complex::new(0.0, -0.5));
+        }
+    }
+
+    // Eigenvalues cos θ are sorted, so each degenerate eigenspace is a run of columns
+    let (values, mut vectors) = hermitian_eigen(&hermitian);
+    let mut start = 0;
+    while start < n {
+        let mut end = start + 1;
+        while end < n && values[end] - values[start] < 1e-8 {
+            end += 1;
+        }
+        if end - start > 1 {
+            let block = end - start;
+            let mut basis = Matrix::zeros(n, block);
+            for r in 0..n {
+                for c in 0..block {
+                    basis.set(r, c, *vectors.get(r, start + c));
+                }
+            }
+            let restricted = &(&basis.conjugate_transpose() * &anti_hermitian) * &basis;
+            let (_, rotation) = hermitian_eigen(&restricted);
+            let rotated = &basis * &rotation;
+            for r in 0..n {
+                for c in 0..block {
+                    vectors.set(r, start + c, *rotated.get(r, c));
+                }
+            }
+        }
+        start = end;
+    }
+
+    let phases: Vec<f64> = (0..n)
+        .map(|k| {
+            let eigenvalue = (0..n)
+                .flat_map(|r| (0..n).map(move |c| (r, c)))
+                .fold(Complex::new(0.0, 0.0), |acc, (r, c)| {
+                    acc + vectors.get(r, k).conjugate() * *matrix.get(r, c) * *vectors.get(c, k)
+                });
+            let phase = eigenvalue.imag.atan2(eigenvalue.real);
+            // Keep -1 on the upper side of the branch cut
+            if phase <= -std::f64::consts::PI + 1e-12 { std::f64::consts::PI } else { phase }
+        })
+        .collect();
+
+    let mut log = Matrix::zeros(n, n);
+    for r in 0..n {
+        for c in 0..n {
+            let entry = phases.iter()
+                .enumerate()
+                .fold(Complex::new(0.0, 0.0), |acc, (k, &phase)| {
+                    acc + *vectors.get(r, k) * vectors.get(c, k).conjugate() * Complex::new(0.0, phase)
+                });
+            log.set(r, c, entry);
+        }
+    }
+    log
+}
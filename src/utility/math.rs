@@ -1,11 +1,40 @@
+use rusticle::complex::Complex;
+
+use super::Tolerance;
+
 /// Round off to nearest relevant value or to 8 decimal places
+/// 
+/// Equivalent to [`Tolerance::round`] with a snapping distance of `tol`; use a
+/// [`Tolerance`] to configure or disable either step.
 pub fn round_if_close(val: f64, tol: f64) -> f64 {
-    let candidates = [0.0, 0.5, -0.5, 1.0, -1.0];
-    for &cand in &candidates {
-        if (val - cand).abs() < tol {
-            return cand;
-        }
+    Tolerance::DEFAULT.with_snap(Some(tol)).round(val)
+}
+
+/// Computes the fidelity |⟨a|b⟩|² between two normalized statevectors
+/// 
+/// # Arguments
+/// * `a` - The first statevector
+/// * `b` - The second statevector
+/// 
+/// # Panics
+/// Panics if the statevectors have different dimensions
+/// 
+/// # Examples
+/// ```
+/// use rusticle::complex::Complex;
+/// use intrico::utility::state_fidelity;
+/// 
+/// let h = 1.0 / 2.0_f64.sqrt();
+/// let zero = [Complex::new(1.0, 0.0), Complex::new(0.0, 0.0)];
+/// let plus = [Complex::new(h, 0.0), Complex::new(h, 0.0)];
+/// assert!((state_fidelity(&zero, &plus) - 0.5).abs() < 1e-10);
+/// ```
+pub fn state_fidelity(a: &[Complex], b: &[Complex]) -> f64 {
+    if a.len() != b.len() {
+        panic!("Statevector dimensions do not match: {} and {}", a.len(), b.len());
     }
-    // Round to 8 decimal places
-    (val * 1e8).round() / 1e8
-}
\ No newline at end of file
+    let overlap = a.iter()
+        .zip(b)
+        .fold(Complex::new(0.0, 0.0), |acc, (x, y)| acc + x.conjugate() * *y);
+    overlap.norm_squared()
+}
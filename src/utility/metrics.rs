@@ -0,0 +1,195 @@
+use rusticle::complex::Complex;
+use rusticle::linalg::Matrix;
+
+use super::hermitian_eigen;
+use super::state_fidelity;
+
+/// A quantum state given either as a statevector or as a density matrix
+///
+/// Implemented for statevectors (`[Complex]` and `Vec<Complex>`) and for density
+/// matrices (`Matrix<Complex>`), so the distance metrics of this module accept any
+/// combination of pure and mixed states.
+pub trait QuantumState {
+    /// Returns the statevector of a state given as one, `None` for density matrices
+    fn statevector(&self) -> Option<&[Complex]>;
+
+    /// Returns the density matrix of the state
+    fn density_matrix(&self) -> Matrix<Complex>;
+
+    /// Returns the dimension of the Hilbert space the state lives in
+    fn dimension(&self) -> usize;
+}
+
+impl QuantumState for [Complex] {
+    fn statevector(&self) -> Option<&[Complex]> {
+        Some(self)
+    }
+
+    fn density_matrix(&self) -> Matrix<Complex> {
+        let dim = self.len();
+        let mut rho = Matrix::zeros(dim, dim);
+        for r in 0..dim {
+            for c in 0..dim {
+                rho.set(r, c, self[r] * self[c].conjugate());
+            }
+        }
+        rho
+    }
+
+    fn dimension(&self) -> usize {
+        self.len()
+    }
+}
+
+impl QuantumState for Vec<Complex> {
+    fn statevector(&self) -> Option<&[Complex]> {
+        Some(self)
+    }
+
+    fn density_matrix(&self) -> Matrix<Complex> {
+        self.as_slice().density_matrix()
+    }
+
+    fn dimension(&self) -> usize {
+        self.len()
+    }
+}
+
+impl QuantumState for Matrix<Complex> {
+    fn statevector(&self) -> Option<&[Complex]> {
+        None
+    }
+
+    fn density_matrix(&self) -> Matrix<Complex> {
+        self.clone()
+    }
+
+    fn dimension(&self) -> usize {
+        self.rows()
+    }
+}
+
+/// Computes the Uhlmann fidelity `(tr √(√ρ σ √ρ))²` between two states
+///
+/// Reduces to `|⟨a|b⟩|²` for two statevectors and to `⟨ψ|σ|ψ⟩` when one state is pure.
+///
+/// # Panics
+/// Panics if the states have different dimensions
+///
+/// # Examples
+/// ```
+/// use rusticle::complex::Complex;
+/// use rusticle::linalg::Matrix;
+/// use intrico::utility::fidelity;
+///
+/// let zero = vec![Complex::new(1.0, 0.0), Complex::new(0.0, 0.0)];
+/// let mixed = Matrix::new(2, 2, vec![
+///     Complex::new(0.5, 0.0), Complex::new(0.0, 0.0),
+///     Complex::new(0.0, 0.0), Complex::new(0.5, 0.0),
+/// ]);
+/// assert!((fidelity(&zero, &mixed) - 0.5).abs() < 1e-10);
+/// ```
+pub fn fidelity<A: QuantumState + ?Sized, B: QuantumState + ?Sized>(a: &A, b: &B) -> f64 {
+    check_dimensions(a, b);
+
+    match (a.statevector(), b.statevector()) {
+        (Some(a), Some(b)) => state_fidelity(a, b),
+        (Some(psi), None) => expectation(psi, &b.density_matrix()),
+        (None, Some(psi)) => expectation(psi, &a.density_matrix()),
+        (None, None) => {
+            let root = square_root(&a.density_matrix());
+            let product = &(&root * &b.density_matrix()) * &root;
+            let (values, _) = hermitian_eigen(&product);
+            values.iter().map(|&value| value.max(0.0).sqrt()).sum::<f64>().powi(2)
+        },
+    }
+}
+
+/// Computes the trace distance `½·tr|ρ - σ|` between two states
+///
+/// The distance ranges from 0 for equal states to 1 for orthogonal ones.
+///
+/// # Panics
+/// Panics if the states have different dimensions
+///
+/// # Examples
+/// ```
+/// use rusticle::complex::Complex;
+/// use intrico::utility::trace_distance;
+///
+/// let h = 1.0 / 2.0_f64.sqrt();
+/// let zero = vec![Complex::new(1.0, 0.0), Complex::new(0.0, 0.0)];
+/// let plus = vec![Complex::new(h, 0.0), Complex::new(h, 0.0)];
+/// assert!((trace_distance(&zero, &plus) - h).abs() < 1e-10);
+/// ```
+pub fn trace_distance<A: QuantumState + ?Sized, B: QuantumState + ?Sized>(a: &A, b: &B) -> f64 {
+    check_dimensions(a, b);
+
+    if let (Some(a), Some(b)) = (a.statevector(), b.statevector()) {
+        return (1.0 - state_fidelity(a, b)).max(0.0).sqrt();
+    }
+
+    let (rho, sigma) = (a.density_matrix(), b.density_matrix());
+    let dim = rho.rows();
+    let mut difference = Matrix::zeros(dim, dim);
+    for r in 0..dim {
+        for c in 0..dim {
+            difference.set(r, c, *rho.get(r, c) - *sigma.get(r, c));
+        }
+    }
+    let (values, _) = hermitian_eigen(&difference);
+    values.iter().map(|value| value.abs()).sum::<f64>() / 2.0
+}
+
+/// Computes the Bures distance `√(2·(1 - √F))` between two states
+///
+/// # Panics
+/// Panics if the states have different dimensions
+///
+/// # Examples
+/// ```
+/// use rusticle::complex::Complex;
+/// use intrico::utility::bures_distance;
+///
+/// let zero = vec![Complex::new(1.0, 0.0), Complex::new(0.0, 0.0)];
+/// let one = vec![Complex::new(0.0, 0.0), Complex::new(1.0, 0.0)];
+/// assert!((bures_distance(&zero, &one) - 2.0_f64.sqrt()).abs() < 1e-10);
+/// ```
+pub fn bures_distance<A: QuantumState + ?Sized, B: QuantumState + ?Sized>(a: &A, b: &B) -> f64 {
+    let root = fidelity(a, b).clamp(0.0, 1.0).sqrt();
+    (2.0 * (1.0 - root)).sqrt()
+}
+
+/// Panics unless both states live in the same Hilbert space
+fn check_dimensions<A: QuantumState + ?Sized, B: QuantumState + ?Sized>(a: &A, b: &B) {
+    if a.dimension() != b.dimension() {
+        panic!("State dimensions do not match: {} and {}", a.dimension(), b.dimension());
+    }
+}
+
+/// Returns `⟨ψ|ρ|ψ⟩`
+fn expectation(psi: &[Complex], rho: &Matrix<Complex>) -> f64 {
+    let dim = psi.len();
+    (0..dim)
+        .flat_map(|r| (0..dim).map(move |c| (r, c)))
+        .fold(Complex::new(0.0, 0.0), |acc, (r, c)| acc + psi[r].conjugate() * *rho.get(r, c) * psi[c])
+        .real
+}
+
+/// Returns the square root of a positive semidefinite Hermitian matrix
+fn square_root(matrix: &Matrix<Complex>) -> Matrix<Complex> {
+    let (values, vectors) = hermitian_eigen(matrix);
+    let dim = matrix.rows();
+    let mut root = Matrix::zeros(dim, dim);
+    for r in 0..dim {
+        for c in 0..dim {
+            let entry = values.iter()
+                .enumerate()
+                .fold(Complex::new(0.0, 0.0), |acc, (k, &value)| {
+                    acc + *vectors.get(r, k) * vectors.get(c, k).conjugate() * value.max(0.0).sqrt()
+                });
+            root.set(r, c, entry);
+        }
+    }
+    root
+}
@@ -2,6 +2,22 @@
 //! 
 //! This module provides utility functions for the quantum computing library.
 
+pub mod bits;
+pub mod format;
 pub mod math;
+pub mod linalg;
+pub mod metrics;
+#[cfg(feature = "simulator")]
+pub mod random;
+pub mod tolerance;
+#[cfg(feature = "serde")]
+pub mod serialization;
 
-pub use math::round_if_close;
\ No newline at end of file
+pub use bits::{bitstring_to_index, index_to_bitstring, Endianness};
+pub use format::{ComplexFormat, Notation};
+pub use math::{round_if_close, state_fidelity};
+pub use linalg::{complete_unitary, hermitian_eigen, matrix_log};
+pub use metrics::{bures_distance, fidelity, trace_distance, QuantumState};
+#[cfg(feature = "simulator")]
+pub use random::{random_state, random_unitary};
+pub use tolerance::Tolerance;
\ No newline at end of file
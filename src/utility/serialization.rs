@@ -0,0 +1,47 @@
+//! Serde helpers for types from `rusticle` that do not implement serde traits
+//!
+//! Complex numbers are serialized as `[real, imag]` pairs.
+
+use std::collections::HashMap;
+use rusticle::complex::Complex;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Serializes a statevector as a list of `[real, imag]` pairs
+pub mod complex_vec {
+    use super::*;
+
+    /// Serializes the statevector
+    pub fn serialize<S: Serializer>(state: &[Complex], serializer: S) -> Result<S::Ok, S::Error> {
+        state.iter()
+            .map(|c| [c.real, c.imag])
+            .collect::<Vec<_>>()
+            .serialize(serializer)
+    }
+
+    /// Deserializes the statevector
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<Complex>, D::Error> {
+        let pairs = Vec::<[f64; 2]>::deserialize(deserializer)?;
+        Ok(pairs.into_iter().map(|[real, imag]| Complex::new(real, imag)).collect())
+    }
+}
+
+/// Serializes a map of labelled statevectors as lists of `[real, imag]` pairs
+pub mod complex_vec_map {
+    use super::*;
+
+    /// Serializes the labelled statevectors
+    pub fn serialize<S: Serializer>(states: &HashMap<String, Vec<Complex>>, serializer: S) -> Result<S::Ok, S::Error> {
+        states.iter()
+            .map(|(label, state)| (label, state.iter().map(|c| [c.real, c.imag]).collect::<Vec<_>>()))
+            .collect::<HashMap<_, _>>()
+            .serialize(serializer)
+    }
+
+    /// Deserializes the labelled statevectors
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<HashMap<String, Vec<Complex>>, D::Error> {
+        let pairs = HashMap::<String, Vec<[f64; 2]>>::deserialize(deserializer)?;
+        Ok(pairs.into_iter()
+            .map(|(label, state)| (label, state.into_iter().map(|[real, imag]| Complex::new(real, imag)).collect()))
+            .collect())
+    }
+}
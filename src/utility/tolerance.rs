@@ -0,0 +1,121 @@
+use std::sync::RwLock;
+
+use rusticle::complex::Complex;
+
+/// Values amplitude components snap to when they lie within the snapping distance
+const CANDIDATES: [f64; 5] = [0.0, 0.5, -0.5, 1.0, -1.0];
+
+/// Process-wide policy used where no other tolerance is configured
+static GLOBAL: RwLock<Tolerance> = RwLock::new(Tolerance::DEFAULT);
+
+/// Policy for cleaning up floating-point noise in amplitudes
+/// 
+/// Each real and imaginary component is first snapped to the nearest of 0, ±0.5 and
+/// ±1 when it lies within the snapping distance, and otherwise rounded to a fixed
+/// number of decimals. Either step can be disabled; [`Tolerance::EXACT`] disables both
+/// and leaves amplitudes untouched.
+/// 
+/// The policy applied by [`QuantumCircuit::execute`](crate::QuantumCircuit::execute) and
+/// by simulators without their own policy is the process-wide one, see
+/// [`Tolerance::set_global`].
+/// 
+/// # Examples
+/// ```
+/// use intrico::utility::Tolerance;
+/// 
+/// assert_eq!(Tolerance::DEFAULT.round(0.5 + 1e-12), 0.5);
+/// assert_eq!(Tolerance::DEFAULT.round(0.123456789), 0.12345679);
+/// assert_eq!(Tolerance::EXACT.round(0.5 + 1e-12), 0.5 + 1e-12);
+/// 
+/// let snap_only = Tolerance::DEFAULT.with_decimals(None);
+/// assert_eq!(snap_only.round(0.123456789), 0.123456789);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tolerance {
+    snap: Option<f64>,
+    decimals: Option<u32>,
+}
+
+impl Tolerance {
+    /// Snaps within 1e-10 and rounds to 8 decimals
+    pub const DEFAULT: Tolerance = Tolerance { snap: Some(1e-10), decimals: Some(8) };
+
+    /// Leaves every amplitude untouched
+    pub const EXACT: Tolerance = Tolerance { snap: None, decimals: None };
+
+    /// Sets the distance within which components snap to 0, ±0.5 or ±1, `None` to never snap
+    pub const fn with_snap(mut self, snap: Option<f64>) -> Self {
+        self.snap = snap;
+        self
+    }
+
+    /// Sets the number of decimals other components are rounded to, `None` to keep them
+    pub const fn with_decimals(mut self, decimals: Option<u32>) -> Self {
+        self.decimals = decimals;
+        self
+    }
+
+    /// Returns the snapping distance, if snapping is enabled
+    pub fn snap(&self) -> Option<f64> {
+        self.snap
+    }
+
+    /// Returns the number of decimals, if rounding is enabled
+    pub fn decimals(&self) -> Option<u32> {
+        self.decimals
+    }
+
+    /// Applies the policy to a single value
+    pub fn round(&self, value: f64) -> f64 {
+        if let Some(snap) = self.snap
+            && let Some(&candidate) = CANDIDATES.iter().find(|&&c| (value - c).abs() < snap) {
+            return candidate;
+        }
+        match self.decimals {
+            Some(decimals) => {
+                let scale = 10f64.powi(decimals as i32);
+                (value * scale).round() / scale
+            },
+            None => value,
+        }
+    }
+
+    /// Applies the policy to both components of every amplitude of a statevector
+    pub fn round_state(&self, state: &[Complex]) -> Vec<Complex> {
+        if *self == Tolerance::EXACT {
+            return state.to_vec();
+        }
+        state.iter()
+            .map(|c| Complex::new(self.round(c.real), self.round(c.imag)))
+            .collect()
+    }
+
+    /// Returns the process-wide policy, [`Tolerance::DEFAULT`] unless changed
+    pub fn global() -> Tolerance {
+        *GLOBAL.read().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Replaces the process-wide policy
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// use intrico::utility::Tolerance;
+    /// 
+    /// let mut qc = QuantumCircuit::new(1);
+    /// qc.ry(0, 1e-6);
+    /// assert_eq!(qc.execute()[1].real, 5e-7);
+    /// 
+    /// Tolerance::set_global(Tolerance::EXACT);
+    /// assert_eq!(qc.execute()[1].real, (5e-7_f64).sin());
+    /// ```
+    pub fn set_global(tolerance: Tolerance) {
+        *GLOBAL.write().unwrap_or_else(|poisoned| poisoned.into_inner()) = tolerance;
+    }
+}
+
+impl Default for Tolerance {
+    fn default() -> Self {
+        Tolerance::DEFAULT
+    }
+}
@@ -0,0 +1,110 @@
+use crate::circuit::ParameterizedCircuit;
+
+/// Builds a hardware-efficient ansatz in the style of EfficientSU2
+/// 
+/// Every rotation layer applies Ry then Rz to each qubit with fresh parameters, and
+/// the `reps` entangling layers in between apply a linear chain of CNOT gates. The
+/// circuit ends with a rotation layer, giving `2n·(reps + 1)` parameters.
+/// 
+/// # Arguments
+/// * `num_qubits` - The number of qubits
+/// * `reps` - The number of entangling layers
+/// 
+/// # Examples
+/// ```
+/// use intrico::variational::efficient_su2;
+/// 
+/// let ansatz = efficient_su2(3, 2);
+/// assert_eq!(ansatz.num_parameters(), 18);
+/// ```
+pub fn efficient_su2(num_qubits: usize, reps: usize) -> ParameterizedCircuit {
+    let mut ansatz = ParameterizedCircuit::new(num_qubits);
+    let mut parameter = 0;
+
+    for rep in 0..=reps {
+        if rep > 0 {
+            linear_entanglement(&mut ansatz);
+        }
+        for qubit in 0..num_qubits {
+            ansatz.ry(qubit, parameter);
+            ansatz.rz(qubit, parameter + 1);
+            parameter += 2;
+        }
+    }
+
+    ansatz
+}
+
+/// Builds a real-amplitudes ansatz: Ry rotation layers separated by CNOT chains
+/// 
+/// Only Ry rotations and CNOT gates are used, so every prepared state has real
+/// amplitudes. The circuit has `n·(reps + 1)` parameters.
+/// 
+/// # Arguments
+/// * `num_qubits` - The number of qubits
+/// * `reps` - The number of entangling layers
+/// 
+/// # Examples
+/// ```
+/// use intrico::variational::real_amplitudes;
+/// 
+/// let ansatz = real_amplitudes(4, 1);
+/// assert_eq!(ansatz.num_parameters(), 8);
+/// ```
+pub fn real_amplitudes(num_qubits: usize, reps: usize) -> ParameterizedCircuit {
+    let mut ansatz = ParameterizedCircuit::new(num_qubits);
+    let mut parameter = 0;
+
+    for rep in 0..=reps {
+        if rep > 0 {
+            linear_entanglement(&mut ansatz);
+        }
+        for qubit in 0..num_qubits {
+            ansatz.ry(qubit, parameter);
+            parameter += 1;
+        }
+    }
+
+    ansatz
+}
+
+/// Builds a brickwork ansatz of parameterized two-qubit blocks
+/// 
+/// Layers alternate between the pairs `(0, 1), (2, 3), ...` and `(1, 2), (3, 4), ...`,
+/// like the bricks of a wall. Each block applies Ry to both qubits followed by a CNOT,
+/// adding two parameters.
+/// 
+/// # Arguments
+/// * `num_qubits` - The number of qubits
+/// * `layers` - The number of brick layers
+/// 
+/// # Examples
+/// ```
+/// use intrico::variational::brickwork;
+/// 
+/// // Four qubits: two blocks in even layers and one in odd layers
+/// let ansatz = brickwork(4, 3);
+/// assert_eq!(ansatz.num_parameters(), 2 * (2 + 1 + 2));
+/// ```
+pub fn brickwork(num_qubits: usize, layers: usize) -> ParameterizedCircuit {
+    let mut ansatz = ParameterizedCircuit::new(num_qubits);
+    let mut parameter = 0;
+
+    for layer in 0..layers {
+        for a in (layer % 2..num_qubits.saturating_sub(1)).step_by(2) {
+            ansatz.ry(a, parameter);
+            ansatz.ry(a + 1, parameter + 1);
+            ansatz.circuit_mut().cnot(a, a + 1);
+            parameter += 2;
+        }
+    }
+
+    ansatz
+}
+
+/// Appends CNOT gates between neighbouring qubits
+fn linear_entanglement(ansatz: &mut ParameterizedCircuit) {
+    for qubit in 1..ansatz.num_qubits() {
+        ansatz.circuit_mut().cnot(qubit - 1, qubit);
+    }
+}
@@ -0,0 +1,18 @@
+//! Variational algorithms module
+//! 
+//! This module provides hybrid quantum-classical drivers that tune the parameters of a
+//! [`ParameterizedCircuit`](crate::circuit::ParameterizedCircuit) with a classical
+//! optimizer to minimize an expectation value measured on the simulator, such as
+//! VQE for ground-state energies and QAOA for MaxCut. Ready-made ansätze generate the
+//! parameterized circuits these drivers optimize. Gradient descent, Adam and SPSA
+//! optimizers work on any objective closure, with or without these drivers.
+
+mod ansatz;
+mod optimizer;
+mod vqe;
+mod qaoa;
+
+pub use ansatz::{brickwork, efficient_su2, real_amplitudes};
+pub use optimizer::{Adam, GradientDescent, Optimizer, SPSA};
+pub use vqe::{VQE, VQEResult};
+pub use qaoa::{cut_value, qaoa, qaoa_ansatz, QAOAResult};
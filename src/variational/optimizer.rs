@@ -0,0 +1,229 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::simulator::fresh_seed;
+
+/// A classical optimizer minimizing an objective function of real parameters
+pub trait Optimizer {
+    /// Updates the parameters in place by one iteration of the optimizer
+    /// 
+    /// # Arguments
+    /// * `objective` - The function to minimize
+    /// * `params` - The current parameters, overwritten with the next ones
+    fn step(&mut self, objective: &dyn Fn(&[f64]) -> f64, params: &mut [f64]);
+
+    /// Runs a fixed number of iterations from the initial parameters and returns the
+    /// final ones
+    /// 
+    /// # Arguments
+    /// * `objective` - The function to minimize
+    /// * `initial` - The parameters to start from
+    /// * `iterations` - The number of iterations to run
+    /// 
+    /// # Examples
+    /// ```
+    /// use intrico::variational::{GradientDescent, Optimizer};
+    /// 
+    /// let objective = |x: &[f64]| (x[0] - 3.0).powi(2) + (x[1] + 1.0).powi(2);
+    /// let params = GradientDescent::new(0.1).minimize(&objective, &[0.0, 0.0], 100);
+    /// assert!(objective(&params) < 1e-6);
+    /// ```
+    fn minimize(&mut self, objective: &dyn Fn(&[f64]) -> f64, initial: &[f64], iterations: usize) -> Vec<f64> {
+        let mut params = initial.to_vec();
+        for _ in 0..iterations {
+            self.step(objective, &mut params);
+        }
+        params
+    }
+}
+
+/// Gradient descent with central finite-difference gradients
+/// 
+/// # Examples
+/// ```
+/// use intrico::variational::{GradientDescent, Optimizer};
+/// 
+/// let mut optimizer = GradientDescent::new(0.1);
+/// let mut params = vec![2.0];
+/// for _ in 0..100 {
+///     optimizer.step(&|x| (x[0] - 1.0).powi(2), &mut params);
+/// }
+/// assert!((params[0] - 1.0).abs() < 1e-4);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GradientDescent {
+    /// Step size along the negative gradient
+    pub learning_rate: f64,
+    /// Offset used to estimate each partial derivative
+    pub finite_difference: f64,
+}
+
+impl GradientDescent {
+    /// Creates a gradient descent optimizer with the given learning rate
+    pub fn new(learning_rate: f64) -> Self {
+        GradientDescent { learning_rate, finite_difference: 1e-4 }
+    }
+}
+
+impl Optimizer for GradientDescent {
+    fn step(&mut self, objective: &dyn Fn(&[f64]) -> f64, params: &mut [f64]) {
+        let gradient = central_difference(objective, params, self.finite_difference);
+        for (param, derivative) in params.iter_mut().zip(gradient) {
+            *param -= self.learning_rate * derivative;
+        }
+    }
+}
+
+/// Adam: gradient descent with per-parameter step sizes adapted from running moments
+/// 
+/// Gradients are estimated with central finite differences as for [`GradientDescent`].
+/// The moment estimates persist across steps, so an optimizer should not be reused for
+/// a different objective.
+/// 
+/// # Examples
+/// ```
+/// use intrico::variational::{Adam, Optimizer};
+/// 
+/// // Badly scaled quadratic, where plain gradient descent would need a tiny step size
+/// let objective = |x: &[f64]| 100.0 * x[0] * x[0] + 0.01 * (x[1] - 2.0).powi(2);
+/// let params = Adam::new(0.1).minimize(&objective, &[1.0, 0.0], 500);
+/// assert!(params[0].abs() < 1e-2);
+/// assert!((params[1] - 2.0).abs() < 1e-2);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Adam {
+    /// Step size of every update
+    pub learning_rate: f64,
+    /// Decay rate of the running mean of the gradients
+    pub beta1: f64,
+    /// Decay rate of the running mean of the squared gradients
+    pub beta2: f64,
+    /// Constant added to the denominator of every update to avoid dividing by zero
+    pub epsilon: f64,
+    /// Offset used to estimate each partial derivative
+    pub finite_difference: f64,
+    /// Running mean of the gradients
+    first_moment: Vec<f64>,
+    /// Running mean of the squared gradients
+    second_moment: Vec<f64>,
+    /// Number of steps taken so far
+    iteration: i32,
+}
+
+impl Adam {
+    /// Creates an Adam optimizer with the given learning rate, the usual decay rates of
+    /// 0.9 and 0.999 and an `epsilon` of 1e-8
+    pub fn new(learning_rate: f64) -> Self {
+        Adam {
+            learning_rate,
+            beta1: 0.9,
+            beta2: 0.999,
+            epsilon: 1e-8,
+            finite_difference: 1e-4,
+            first_moment: Vec::new(),
+            second_moment: Vec::new(),
+            iteration: 0,
+        }
+    }
+}
+
+impl Optimizer for Adam {
+    fn step(&mut self, objective: &dyn Fn(&[f64]) -> f64, params: &mut [f64]) {
+        let gradient = central_difference(objective, params, self.finite_difference);
+        if self.first_moment.len() != params.len() {
+            self.first_moment = vec![0.0; params.len()];
+            self.second_moment = vec![0.0; params.len()];
+            self.iteration = 0;
+        }
+        self.iteration += 1;
+
+        // Bias corrections for the zero-initialized moments
+        let first_correction = 1.0 - self.beta1.powi(self.iteration);
+        let second_correction = 1.0 - self.beta2.powi(self.iteration);
+        for (i, derivative) in gradient.into_iter().enumerate() {
+            self.first_moment[i] = self.beta1 * self.first_moment[i] + (1.0 - self.beta1) * derivative;
+            self.second_moment[i] = self.beta2 * self.second_moment[i] + (1.0 - self.beta2) * derivative * derivative;
+
+            let mean = self.first_moment[i] / first_correction;
+            let variance = self.second_moment[i] / second_correction;
+            params[i] -= self.learning_rate * mean / (variance.sqrt() + self.epsilon);
+        }
+    }
+}
+
+/// Simultaneous perturbation stochastic approximation
+/// 
+/// Every step estimates the gradient from only two evaluations of the objective, at the
+/// parameters shifted forth and back along a random direction with ±1 entries, however
+/// many parameters there are. This makes SPSA well suited to objectives estimated from
+/// shots, whose noise would swamp finite differences. The step size `a / k^0.602` and
+/// the perturbation `c / k^0.101` decay with the iteration `k`, as recommended by Spall.
+/// 
+/// # Examples
+/// ```
+/// use intrico::variational::{Optimizer, SPSA};
+/// 
+/// let objective = |x: &[f64]| x.iter().map(|v| (v - 0.5).powi(2)).sum::<f64>();
+/// let params = SPSA::new(0.2, 0.1).with_seed(3).minimize(&objective, &[0.0; 6], 400);
+/// assert!(objective(&params) < 1e-3);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SPSA {
+    /// Initial step size `a`
+    pub learning_rate: f64,
+    /// Initial perturbation size `c`
+    pub perturbation: f64,
+    /// Number of steps taken so far
+    iteration: i32,
+    /// Source of the perturbation directions
+    rng: StdRng,
+}
+
+impl SPSA {
+    /// Creates an SPSA optimizer with the given initial step and perturbation sizes
+    pub fn new(learning_rate: f64, perturbation: f64) -> Self {
+        SPSA { learning_rate, perturbation, iteration: 0, rng: StdRng::seed_from_u64(fresh_seed()) }
+    }
+
+    /// Seeds the perturbation directions for reproducible optimizations
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng = StdRng::seed_from_u64(seed);
+        self
+    }
+}
+
+impl Optimizer for SPSA {
+    fn step(&mut self, objective: &dyn Fn(&[f64]) -> f64, params: &mut [f64]) {
+        self.iteration += 1;
+        let k = self.iteration as f64;
+        let step = self.learning_rate / k.powf(0.602);
+        let perturbation = self.perturbation / k.powf(0.101);
+
+        let direction: Vec<f64> = (0..params.len())
+            .map(|_| if self.rng.random::<bool>() { 1.0 } else { -1.0 })
+            .collect();
+        let shifted = |sign: f64| -> Vec<f64> {
+            params.iter().zip(&direction).map(|(param, d)| param + sign * perturbation * d).collect()
+        };
+        let difference = (objective(&shifted(1.0)) - objective(&shifted(-1.0))) / (2.0 * perturbation);
+
+        // The entries of the direction are ±1, so dividing by them is multiplying
+        for (param, d) in params.iter_mut().zip(direction) {
+            *param -= step * difference * d;
+        }
+    }
+}
+
+/// Estimates the gradient with central finite differences of the given offset
+fn central_difference(objective: &dyn Fn(&[f64]) -> f64, params: &[f64], offset: f64) -> Vec<f64> {
+    let mut shifted = params.to_vec();
+    (0..params.len())
+        .map(|i| {
+            shifted[i] = params[i] + offset;
+            let forward = objective(&shifted);
+            shifted[i] = params[i] - offset;
+            let backward = objective(&shifted);
+            shifted[i] = params[i];
+            (forward - backward) / (2.0 * offset)
+        })
+        .collect()
+}
@@ -0,0 +1,150 @@
+use crate::circuit::ParameterizedCircuit;
+use crate::observables::PauliSum;
+use crate::simulator::Simulator;
+use crate::utility::{index_to_bitstring, Endianness};
+
+use super::{GradientDescent, VQE};
+
+/// Outcome of a [`qaoa`] MaxCut run
+#[derive(Debug, Clone, PartialEq)]
+pub struct QAOAResult {
+    /// The optimized angles, as `[γ₁, β₁, γ₂, β₂, ...]`
+    pub parameters: Vec<f64>,
+    /// The expected cut size of the optimized state
+    pub expected_cut: f64,
+    /// The most likely bitstring of the optimized state, qubit 0 being the rightmost bit
+    pub best_bitstring: String,
+    /// The cut size of the most likely bitstring
+    pub best_cut: usize,
+    /// The maximum cut size of the graph, found by exhaustive search
+    pub max_cut: usize,
+    /// The expected cut divided by the maximum cut
+    pub approximation_ratio: f64,
+}
+
+/// Builds the QAOA ansatz for MaxCut on the given graph
+/// 
+/// Every layer applies the cost unitary, an `Rzz(γ)` on each edge built from two CNOTs
+/// and an Rz, followed by the mixer `Rx(β)` on every vertex. Layer `l` uses parameter
+/// `2l` for γ and `2l + 1` for β.
+/// 
+/// # Arguments
+/// * `edges` - The edges of the graph, as pairs of vertex indices
+/// * `p_layers` - The number of cost and mixer layers
+/// 
+/// # Panics
+/// Panics if an edge connects a vertex to itself
+pub fn qaoa_ansatz(edges: &[(usize, usize)], p_layers: usize) -> ParameterizedCircuit {
+    let num_vertices = num_vertices(edges);
+    let mut ansatz = ParameterizedCircuit::new(num_vertices);
+
+    for vertex in 0..num_vertices {
+        ansatz.circuit_mut().h(vertex);
+    }
+
+    for layer in 0..p_layers {
+        for &(a, b) in edges {
+            if a == b {
+                panic!("Edge ({}, {}) is a self-loop", a, b);
+            }
+            ansatz.circuit_mut().cnot(a, b);
+            ansatz.rz(b, 2 * layer);
+            ansatz.circuit_mut().cnot(a, b);
+        }
+        for vertex in 0..num_vertices {
+            ansatz.rx(vertex, 2 * layer + 1);
+        }
+    }
+
+    ansatz
+}
+
+/// Solves MaxCut on a small graph with the quantum approximate optimization algorithm
+/// 
+/// The angles are optimized on the simulator by minimizing `Σ ZᵢZⱼ` over the edges,
+/// which maximizes the expected cut. The maximum cut used for the approximation ratio
+/// is found by exhaustive search, so the graph should have few vertices.
+/// 
+/// # Arguments
+/// * `edges` - The edges of the graph, as pairs of vertex indices
+/// * `p_layers` - The number of cost and mixer layers
+/// 
+/// # Panics
+/// Panics if the graph has no edges or an edge is a self-loop
+/// 
+/// # Examples
+/// ```
+/// use intrico::variational::qaoa;
+/// 
+/// // A square is bipartite: the alternating colourings cut all four edges
+/// let result = qaoa(&[(0, 1), (1, 2), (2, 3), (3, 0)], 2);
+/// assert_eq!(result.max_cut, 4);
+/// assert_eq!(result.best_cut, 4);
+/// assert!(result.approximation_ratio > 0.7);
+/// ```
+pub fn qaoa(edges: &[(usize, usize)], p_layers: usize) -> QAOAResult {
+    if edges.is_empty() {
+        panic!("MaxCut requires a graph with at least one edge");
+    }
+
+    let num_vertices = num_vertices(edges);
+    let ansatz = qaoa_ansatz(edges, p_layers);
+    let hamiltonian = edges.iter().fold(PauliSum::new(num_vertices), |sum, &(a, b)| {
+        let pauli: String = (0..num_vertices)
+            .rev()
+            .map(|vertex| if vertex == a || vertex == b { 'Z' } else { 'I' })
+            .collect();
+        sum.with_term(1.0, &pauli)
+    });
+
+    let initial: Vec<f64> = (0..p_layers).flat_map(|_| [0.4, 0.4]).collect();
+    let mut vqe = VQE::new(ansatz.clone(), hamiltonian, GradientDescent::new(0.1))
+        .with_max_iterations(300);
+    let optimized = vqe.run(&initial);
+
+    let state = Simulator::new()
+        .run_batch(&[ansatz.bind(&optimized.parameters)], 0)
+        .remove(0)
+        .final_state;
+    let best = (0..state.len())
+        .max_by(|&a, &b| state[a].norm_squared().total_cmp(&state[b].norm_squared()))
+        .unwrap_or(0);
+
+    let max_cut = (0..1usize << num_vertices)
+        .map(|assignment| cut_value(edges, assignment))
+        .max()
+        .unwrap_or(0);
+    let expected_cut = (edges.len() as f64 - optimized.energy) / 2.0;
+
+    QAOAResult {
+        parameters: optimized.parameters,
+        expected_cut,
+        best_bitstring: index_to_bitstring(best, num_vertices, Endianness::Little),
+        best_cut: cut_value(edges, best),
+        max_cut,
+        approximation_ratio: expected_cut / max_cut as f64,
+    }
+}
+
+/// Returns the number of edges cut by a vertex assignment
+/// 
+/// # Arguments
+/// * `edges` - The edges of the graph, as pairs of vertex indices
+/// * `assignment` - The side of every vertex, bit `v` holding vertex `v`
+/// 
+/// # Examples
+/// ```
+/// use intrico::variational::cut_value;
+/// 
+/// assert_eq!(cut_value(&[(0, 1), (1, 2), (0, 2)], 0b001), 2);
+/// ```
+pub fn cut_value(edges: &[(usize, usize)], assignment: usize) -> usize {
+    edges.iter()
+        .filter(|&&(a, b)| (assignment >> a) & 1 != (assignment >> b) & 1)
+        .count()
+}
+
+/// Returns the number of vertices spanned by the edges
+fn num_vertices(edges: &[(usize, usize)]) -> usize {
+    edges.iter().map(|&(a, b)| a.max(b) + 1).max().unwrap_or(0)
+}
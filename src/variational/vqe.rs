@@ -0,0 +1,146 @@
+use crate::circuit::ParameterizedCircuit;
+use crate::observables::PauliSum;
+use crate::simulator::{Observable, Simulator};
+
+use super::Optimizer;
+
+/// Variational quantum eigensolver
+/// 
+/// Minimizes the energy ⟨ψ(θ)|H|ψ(θ)⟩ of a Hamiltonian over the states prepared by a
+/// parameterized ansatz. Energies are evaluated exactly on the final statevector, or
+/// estimated from one measurement circuit per qubit-wise commuting group of Hamiltonian
+/// terms once a number of shots is set.
+/// 
+/// # Examples
+/// ```
+/// use intrico::circuit::ParameterizedCircuit;
+/// use intrico::observables::PauliSum;
+/// use intrico::variational::{GradientDescent, VQE};
+/// 
+/// // The ground state of H = Z is |1⟩, with energy -1
+/// let mut ansatz = ParameterizedCircuit::new(1);
+/// ansatz.ry(0, 0);
+/// 
+/// let mut vqe = VQE::new(ansatz, PauliSum::new(1).with_term(1.0, "Z"), GradientDescent::new(0.4));
+/// let result = vqe.run(&[0.1]);
+/// assert!((result.energy + 1.0).abs() < 1e-4);
+/// ```
+#[derive(Debug, Clone)]
+pub struct VQE<O: Optimizer> {
+    ansatz: ParameterizedCircuit,
+    hamiltonian: PauliSum,
+    optimizer: O,
+    simulator: Simulator,
+    shots: Option<usize>,
+    max_iterations: usize,
+    tolerance: f64,
+}
+
+/// Outcome of a [`VQE`] run
+#[derive(Debug, Clone, PartialEq)]
+pub struct VQEResult {
+    /// The parameters reaching the lowest energy
+    pub parameters: Vec<f64>,
+    /// The lowest energy found
+    pub energy: f64,
+    /// The energy before the first iteration and after every iteration
+    pub history: Vec<f64>,
+}
+
+impl<O: Optimizer> VQE<O> {
+    /// Creates a solver for the given ansatz, Hamiltonian and optimizer
+    /// 
+    /// # Panics
+    /// Panics if the ansatz and the Hamiltonian act on different numbers of qubits
+    pub fn new(ansatz: ParameterizedCircuit, hamiltonian: PauliSum, optimizer: O) -> Self {
+        if ansatz.num_qubits() != hamiltonian.num_qubits() {
+            panic!("Ansatz acts on {} qubits but the Hamiltonian on {}",
+                   ansatz.num_qubits(), hamiltonian.num_qubits());
+        }
+
+        VQE {
+            ansatz,
+            hamiltonian,
+            optimizer,
+            simulator: Simulator::new(),
+            shots: None,
+            max_iterations: 200,
+            tolerance: 1e-8,
+        }
+    }
+
+    /// Sets the simulator used to prepare the ansatz states
+    pub fn with_simulator(mut self, simulator: Simulator) -> Self {
+        self.simulator = simulator;
+        self
+    }
+
+    /// Estimates energies from the given number of shots per measurement circuit
+    /// 
+    /// Terms are measured together in their qubit-wise commuting groups, see
+    /// [`PauliSum::estimate`].
+    pub fn with_shots(mut self, shots: usize) -> Self {
+        self.shots = Some(shots);
+        self
+    }
+
+    /// Sets the maximum number of optimizer iterations (200 by default)
+    pub fn with_max_iterations(mut self, max_iterations: usize) -> Self {
+        self.max_iterations = max_iterations;
+        self
+    }
+
+    /// Stops once an iteration lowers the energy by less than `tolerance` (1e-8 by default)
+    pub fn with_tolerance(mut self, tolerance: f64) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Returns the energy of the state prepared with the given parameters
+    /// 
+    /// # Panics
+    /// Panics if the number of values does not match the number of parameters
+    pub fn energy(&self, params: &[f64]) -> f64 {
+        evaluate(&self.ansatz, &self.hamiltonian, &self.simulator, self.shots, params)
+    }
+
+    /// Runs the minimization loop from the given initial parameters
+    /// 
+    /// # Panics
+    /// Panics if the number of values does not match the number of parameters
+    pub fn run(&mut self, initial: &[f64]) -> VQEResult {
+        let mut params = initial.to_vec();
+        let mut energy = self.energy(&params);
+        let mut history = vec![energy];
+        let mut best = (params.clone(), energy);
+
+        for _ in 0..self.max_iterations {
+            let (ansatz, hamiltonian, simulator, shots) = (&self.ansatz, &self.hamiltonian, &self.simulator, self.shots);
+            let objective = |values: &[f64]| evaluate(ansatz, hamiltonian, simulator, shots, values);
+            self.optimizer.step(&objective, &mut params);
+
+            let previous = energy;
+            energy = self.energy(&params);
+            history.push(energy);
+            if energy < best.1 {
+                best = (params.clone(), energy);
+            }
+
+            if (previous - energy).abs() < self.tolerance {
+                break;
+            }
+        }
+
+        VQEResult { parameters: best.0, energy: best.1, history }
+    }
+}
+
+/// Prepares the ansatz state for the given parameters and returns its energy
+fn evaluate(ansatz: &ParameterizedCircuit, hamiltonian: &PauliSum, simulator: &Simulator, shots: Option<usize>, params: &[f64]) -> f64 {
+    let circuit = ansatz.bind(params);
+    if let Some(shots) = shots {
+        return hamiltonian.estimate(&circuit, simulator, shots);
+    }
+    let result = simulator.run_batch(std::slice::from_ref(&circuit), 0);
+    hamiltonian.expectation(&result[0].final_state)
+}
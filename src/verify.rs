@@ -0,0 +1,220 @@
+//! Verification module
+//!
+//! This module provides numeric checks for testing quantum code: unitarity of gates,
+//! equivalence of circuits up to a global phase and closeness of statevectors. Each
+//! check comes as a function returning the measured deviation, an `assert_*` function
+//! that panics with a description of the first mismatch, and a macro of the same name
+//! that uses [`DEFAULT_TOLERANCE`] unless one is given.
+//!
+//! ```
+//! use intrico::{assert_circuits_equivalent, assert_state_close, assert_unitary, QuantumCircuit, QuantumGate};
+//! use rusticle::complex::Complex;
+//!
+//! assert_unitary!(QuantumGate::Ry(0.3));
+//!
+//! let mut a = QuantumCircuit::new(1);
+//! a.h(0);
+//! a.z(0);
+//! a.h(0);
+//! let mut b = QuantumCircuit::new(1);
+//! b.x(0);
+//! assert_circuits_equivalent!(a, b);
+//!
+//! assert_state_close!(a.execute(), vec![Complex::new(0.0, 0.0), Complex::new(1.0, 0.0)], 1e-6);
+//! ```
+
+use rusticle::complex::Complex;
+use rusticle::linalg::Matrix;
+
+use crate::utility::{index_to_bitstring, Endianness};
+use crate::{QuantumCircuit, QuantumGate};
+
+/// Tolerance used by the macros when none is given
+///
+/// Loose enough to absorb the rounding applied by
+/// [`QuantumCircuit::execute`](crate::QuantumCircuit::execute).
+pub const DEFAULT_TOLERANCE: f64 = 1e-6;
+
+/// Returns the largest deviation of `U·U†` from the identity, infinite for non-square matrices
+///
+/// # Examples
+/// ```
+/// use intrico::QuantumGate;
+/// use intrico::verify::unitary_deviation;
+///
+/// assert!(unitary_deviation(&QuantumGate::H.matrix()) < 1e-12);
+/// ```
+pub fn unitary_deviation(matrix: &Matrix<Complex>) -> f64 {
+    if matrix.rows() != matrix.cols() {
+        return f64::INFINITY;
+    }
+    let product = matrix * &matrix.conjugate_transpose();
+    let dim = matrix.rows();
+    (0..dim * dim)
+        .map(|idx| {
+            let (r, c) = (idx / dim, idx % dim);
+            let expected = if r == c { 1.0 } else { 0.0 };
+            (*product.get(r, c) - Complex::new(expected, 0.0)).norm()
+        })
+        .fold(0.0, f64::max)
+}
+
+/// Returns the largest entry-wise difference between two unitaries after removing their
+/// relative global phase, infinite if their dimensions differ
+///
+/// # Examples
+/// ```
+/// use intrico::QuantumGate;
+/// use intrico::verify::unitary_distance;
+///
+/// // Rz(π) is Z up to a global phase of -i
+/// let rz = QuantumGate::Rz(std::f64::consts::PI).matrix();
+/// assert!(unitary_distance(&rz, &QuantumGate::Z.matrix()) < 1e-12);
+/// ```
+pub fn unitary_distance(a: &Matrix<Complex>, b: &Matrix<Complex>) -> f64 {
+    if a.rows() != b.rows() || a.cols() != b.cols() {
+        return f64::INFINITY;
+    }
+    let (a, b) = (entries(a), entries(b));
+    let phase = global_phase(&a, &b);
+    a.iter()
+        .zip(&b)
+        .map(|(&x, &y)| (x - y * phase).norm())
+        .fold(0.0, f64::max)
+}
+
+/// Returns the largest amplitude-wise difference between two statevectors, infinite if
+/// their lengths differ
+///
+/// Unlike [`unitary_distance`], the global phase is compared too.
+pub fn state_distance(actual: &[Complex], expected: &[Complex]) -> f64 {
+    if actual.len() != expected.len() {
+        return f64::INFINITY;
+    }
+    actual.iter()
+        .zip(expected)
+        .map(|(&a, &e)| (a - e).norm())
+        .fold(0.0, f64::max)
+}
+
+/// Asserts that the matrix of a gate is unitary within `tol`
+///
+/// # Panics
+/// Panics with the measured deviation if `U·U†` differs from the identity by more
+/// than `tol`
+#[track_caller]
+pub fn assert_unitary(gate: &QuantumGate, tol: f64) {
+    let deviation = unitary_deviation(&gate.matrix());
+    if deviation > tol {
+        panic!("Gate {} is not unitary: U·U† deviates from the identity by {:e}", gate.name(), deviation);
+    }
+}
+
+/// Asserts that two circuits implement the same unitary up to a global phase
+///
+/// # Panics
+/// Panics if the circuits act on different numbers of qubits, or with the first
+/// mismatching matrix entry if their unitaries differ by more than `tol`
+#[track_caller]
+pub fn assert_circuits_equivalent(a: &QuantumCircuit, b: &QuantumCircuit, tol: f64) {
+    if a.num_qubits() != b.num_qubits() {
+        panic!("Circuits act on different numbers of qubits: {} and {}", a.num_qubits(), b.num_qubits());
+    }
+    let (ua, ub) = (a.unitary(), b.unitary());
+    if unitary_distance(&ua, &ub) <= tol {
+        return;
+    }
+
+    let dim = ua.rows();
+    let (ua, ub) = (entries(&ua), entries(&ub));
+    let phase = global_phase(&ua, &ub);
+    let (idx, x, y) = ua.iter()
+        .zip(&ub)
+        .enumerate()
+        .map(|(idx, (&x, &y))| (idx, x, y * phase))
+        .find(|&(_, x, y)| (x - y).norm() > tol)
+        .unwrap_or((0, ua[0], ub[0] * phase));
+    panic!(
+        "Circuits are not equivalent: entry ({}, {}) is {:?} and {:?} after aligning the global phase (tolerance {:e})",
+        idx / dim, idx % dim, x, y, tol
+    );
+}
+
+/// Asserts that two statevectors agree amplitude by amplitude within `tol`
+///
+/// # Panics
+/// Panics if the lengths differ, or with the first basis state whose amplitudes differ
+/// by more than `tol`
+#[track_caller]
+pub fn assert_state_close(actual: &[Complex], expected: &[Complex], tol: f64) {
+    if actual.len() != expected.len() {
+        panic!("Statevectors have different lengths: {} and {}", actual.len(), expected.len());
+    }
+    let num_qubits = actual.len().max(1).ilog2() as usize;
+    if let Some((idx, (a, e))) = actual.iter().zip(expected).enumerate().find(|(_, (a, e))| (**a - **e).norm() > tol) {
+        panic!(
+            "Statevectors differ at |{}⟩: {:?} and {:?} (tolerance {:e})",
+            index_to_bitstring(idx, num_qubits, Endianness::Little), a, e, tol
+        );
+    }
+}
+
+/// Returns the entries of a matrix in row-major order
+fn entries(matrix: &Matrix<Complex>) -> Vec<Complex> {
+    let cols = matrix.cols();
+    (0..matrix.rows() * cols).map(|idx| *matrix.get(idx / cols, idx % cols)).collect()
+}
+
+/// Returns the unit phase `φ` minimizing the distance between `a` and `φ·b`, taken from
+/// the largest entry of `b`
+fn global_phase(a: &[Complex], b: &[Complex]) -> Complex {
+    let Some(idx) = (0..b.len()).max_by(|&i, &j| b[i].norm().total_cmp(&b[j].norm())) else {
+        return Complex::new(1.0, 0.0);
+    };
+    let ratio = a[idx] * b[idx].conjugate();
+    let norm = ratio.norm();
+    if norm == 0.0 {
+        Complex::new(1.0, 0.0)
+    } else {
+        Complex::new(ratio.real / norm, ratio.imag / norm)
+    }
+}
+
+/// Asserts that the matrix of a gate is unitary, with an optional tolerance
+///
+/// See [`verify::assert_unitary`](crate::verify::assert_unitary).
+#[macro_export]
+macro_rules! assert_unitary {
+    ($gate:expr $(,)?) => {
+        $crate::verify::assert_unitary(&$gate, $crate::verify::DEFAULT_TOLERANCE)
+    };
+    ($gate:expr, $tol:expr $(,)?) => {
+        $crate::verify::assert_unitary(&$gate, $tol)
+    };
+}
+
+/// Asserts that two circuits are equivalent up to a global phase, with an optional tolerance
+///
+/// See [`verify::assert_circuits_equivalent`](crate::verify::assert_circuits_equivalent).
+#[macro_export]
+macro_rules! assert_circuits_equivalent {
+    ($a:expr, $b:expr $(,)?) => {
+        $crate::verify::assert_circuits_equivalent(&$a, &$b, $crate::verify::DEFAULT_TOLERANCE)
+    };
+    ($a:expr, $b:expr, $tol:expr $(,)?) => {
+        $crate::verify::assert_circuits_equivalent(&$a, &$b, $tol)
+    };
+}
+
+/// Asserts that two statevectors are close amplitude by amplitude, with an optional tolerance
+///
+/// See [`verify::assert_state_close`](crate::verify::assert_state_close).
+#[macro_export]
+macro_rules! assert_state_close {
+    ($actual:expr, $expected:expr $(,)?) => {
+        $crate::verify::assert_state_close(&$actual, &$expected, $crate::verify::DEFAULT_TOLERANCE)
+    };
+    ($actual:expr, $expected:expr, $tol:expr $(,)?) => {
+        $crate::verify::assert_state_close(&$actual, &$expected, $tol)
+    };
+}
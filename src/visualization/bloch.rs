@@ -0,0 +1,170 @@
+use std::f64::consts::PI;
+
+use crate::core::Float;
+use crate::{QuantumGate, Qubit};
+use super::svg::Svg;
+
+/// Width and height of the rendered sphere, in pixels
+pub(super) const SIZE: f64 = 320.0;
+/// Radius of the sphere, in pixels
+pub(super) const RADIUS: f64 = 120.0;
+/// Azimuth of the viewpoint, measured from the x axis towards the y axis
+const AZIMUTH: f64 = PI / 6.0;
+/// Elevation of the viewpoint above the equator
+const ELEVATION: f64 = PI / 9.0;
+/// Number of points per great circle and per gate of a trajectory
+const SEGMENTS: usize = 48;
+/// Color of the state arrow and trajectory
+const STATE_COLOR: &str = "#d62728";
+
+impl<F: Float> Qubit<F> {
+    /// Returns the Bloch vector `[x, y, z]` of the qubit, on the unit sphere for pure states
+    ///
+    /// # Examples
+    /// ```
+    /// use intrico::{Qubit, QuantumGate};
+    ///
+    /// let mut qubit = Qubit::zero();
+    /// assert_eq!(qubit.bloch_vector(), [0.0, 0.0, 1.0]);
+    ///
+    /// qubit.apply(QuantumGate::H);
+    /// let [x, y, z] = qubit.bloch_vector();
+    /// assert!((x - 1.0).abs() < 1e-12 && y.abs() < 1e-12 && z.abs() < 1e-12);
+    /// ```
+    pub fn bloch_vector(&self) -> [f64; 3] {
+        let components = self.state_vector().components;
+        let (alpha, beta) = (components[0], components[1]);
+        let coherence = alpha.conjugate() * beta;
+        [2.0 * coherence.real, 2.0 * coherence.imag, alpha.norm_squared() - beta.norm_squared()]
+    }
+
+    /// Renders the qubit on a Bloch sphere as an SVG document
+    ///
+    /// The sphere is drawn in orthographic projection with its equator and the xz
+    /// meridian, the poles labelled |0⟩ and |1⟩, and an arrow pointing at the state.
+    ///
+    /// # Examples
+    /// ```
+    /// use intrico::Qubit;
+    ///
+    /// let svg = Qubit::one().to_bloch_svg();
+    /// assert!(svg.starts_with("<svg"));
+    /// assert!(svg.contains("|1⟩"));
+    /// ```
+    pub fn to_bloch_svg(&self) -> String {
+        let mut svg = sphere();
+        arrow(&mut svg, self.bloch_vector());
+        svg.finish()
+    }
+
+    /// Renders the path of the qubit on the Bloch sphere while a sequence of gates is applied
+    ///
+    /// Every gate is followed continuously through its generator, so rotations trace arcs
+    /// on the sphere. The states between gates are marked with dots and the final state
+    /// gets an arrow.
+    ///
+    /// # Panics
+    /// Panics if a gate does not act on a single qubit or is not unitary
+    ///
+    /// # Examples
+    /// ```
+    /// use intrico::{Qubit, QuantumGate};
+    ///
+    /// let svg = Qubit::zero().bloch_trajectory_svg(&[QuantumGate::H, QuantumGate::T, QuantumGate::Rx(1.0)]);
+    /// assert_eq!(svg.matches("<circle").count(), 4);
+    /// ```
+    pub fn bloch_trajectory_svg(&self, gates: &[QuantumGate]) -> String {
+        let mut qubit = self.to_precision::<f64>();
+        let mut path = vec![qubit.bloch_vector()];
+        let mut stops = vec![qubit.bloch_vector()];
+
+        for gate in gates {
+            if gate.arity() != 1 {
+                panic!("Bloch trajectories need single-qubit gates, got {}", gate.name());
+            }
+            let Some(hamiltonian) = gate.hamiltonian() else {
+                panic!("Gate {} is not unitary and has no trajectory", gate.name());
+            };
+            for step in 1..=SEGMENTS {
+                let mut partial = qubit.clone();
+                partial.apply(QuantumGate::from_hamiltonian(&hamiltonian, step as f64 / SEGMENTS as f64));
+                path.push(partial.bloch_vector());
+            }
+            qubit.apply(gate.clone());
+            stops.push(qubit.bloch_vector());
+        }
+
+        let mut svg = sphere();
+        let points: Vec<(f64, f64)> = path.iter().map(|&point| project(point).0).collect();
+        svg.polyline(&points, STATE_COLOR, 2.0, false);
+        for &stop in &stops[..stops.len() - 1] {
+            svg.circle(project(stop).0, 3.5, STATE_COLOR, "none");
+        }
+        arrow(&mut svg, qubit.bloch_vector());
+        svg.finish()
+    }
+}
+
+/// Projects a point of the unit sphere onto the image, returning its position and
+/// whether it faces the viewer
+pub(super) fn project([x, y, z]: [f64; 3]) -> ((f64, f64), bool) {
+    let (sin_a, cos_a) = AZIMUTH.sin_cos();
+    let (sin_e, cos_e) = ELEVATION.sin_cos();
+    let right = -x * sin_a + y * cos_a;
+    let up = -x * sin_e * cos_a - y * sin_e * sin_a + z * cos_e;
+    let depth = x * cos_e * cos_a + y * cos_e * sin_a + z * sin_e;
+    ((SIZE / 2.0 + RADIUS * right, SIZE / 2.0 - RADIUS * up), depth >= 0.0)
+}
+
+/// Draws a great circle, solid on the front of the sphere and dashed behind it
+pub(super) fn great_circle(svg: &mut Svg, point: impl Fn(f64) -> [f64; 3]) {
+    let mut run: Vec<(f64, f64)> = Vec::new();
+    let mut front = None;
+    for step in 0..=SEGMENTS {
+        let (position, visible) = project(point(2.0 * PI * step as f64 / SEGMENTS as f64));
+        if front.is_some_and(|front| front != visible) {
+            let last = *run.last().unwrap_or(&position);
+            svg.polyline(&run, "#888888", 1.0, front == Some(false));
+            run = vec![last];
+        }
+        front = Some(visible);
+        run.push(position);
+    }
+    svg.polyline(&run, "#888888", 1.0, front == Some(false));
+}
+
+/// Draws the outline, great circles, axes and axis labels of the sphere
+fn sphere() -> Svg {
+    let mut svg = Svg::new(SIZE, SIZE);
+    let center = (SIZE / 2.0, SIZE / 2.0);
+    svg.circle(center, RADIUS, "#f4f6fb", "#444444");
+    great_circle(&mut svg, |t| [t.cos(), t.sin(), 0.0]);
+    great_circle(&mut svg, |t| [t.sin(), 0.0, t.cos()]);
+
+    let axes = [([1.0, 0.0, 0.0], "x"), ([0.0, 1.0, 0.0], "y"), ([0.0, 0.0, 1.0], "|0⟩"), ([0.0, 0.0, -1.0], "|1⟩")];
+    for (axis, label) in axes {
+        svg.line(center, project(axis).0, "#aaaaaa", 1.0);
+        let (x, y) = project(axis.map(|c| c * 1.15)).0;
+        svg.text((x, y), 14.0, label);
+    }
+    svg
+}
+
+/// Draws an arrow from the center of the sphere to a Bloch vector
+fn arrow(svg: &mut Svg, vector: [f64; 3]) {
+    let center = (SIZE / 2.0, SIZE / 2.0);
+    let (tip, _) = project(vector);
+    let (dx, dy) = (tip.0 - center.0, tip.1 - center.1);
+    let length = (dx * dx + dy * dy).sqrt();
+
+    // A vector along the line of sight projects onto the center and only gets a dot
+    if length < 1.0 {
+        svg.circle(tip, 4.0, STATE_COLOR, "none");
+        return;
+    }
+    let (ux, uy) = (dx / length, dy / length);
+    let head = 10.0_f64.min(length);
+    let base = (tip.0 - ux * head, tip.1 - uy * head);
+    svg.line(center, base, STATE_COLOR, 2.5);
+    svg.polygon(&[tip, (base.0 - uy * head / 2.5, base.1 + ux * head / 2.5), (base.0 + uy * head / 2.5, base.1 - ux * head / 2.5)], STATE_COLOR);
+}
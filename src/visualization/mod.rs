@@ -0,0 +1,11 @@
+//! Visualization module
+//!
+//! This module renders quantum states as standalone SVG documents, which can be
+//! written to a file, embedded in HTML or displayed in notebooks. Nothing beyond the
+//! standard library is needed to produce them. Single qubits are drawn on the Bloch
+//! sphere, full statevectors on a Q-sphere or as a grid of phase disks.
+
+mod bloch;
+#[cfg(feature = "circuit")]
+mod qsphere;
+mod svg;
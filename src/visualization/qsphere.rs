@@ -0,0 +1,161 @@
+use std::f64::consts::{PI, TAU};
+
+use crate::circuit::StateVector;
+use crate::utility::{index_to_bitstring, Endianness};
+use super::bloch::{great_circle, project, RADIUS, SIZE};
+use super::svg::Svg;
+
+/// Radius of the dot of a basis state with probability one, in pixels
+const MAX_DOT: f64 = 14.0;
+/// Largest number of qubits whose basis states are labelled on the Q-sphere
+const MAX_LABELLED_QUBITS: usize = 5;
+/// Side of one cell of the phase-disk grid, in pixels
+const CELL: f64 = 72.0;
+/// Radius of a phase disk, in pixels
+const DISK_RADIUS: f64 = 26.0;
+
+impl StateVector {
+    /// Renders the statevector on a Q-sphere as an SVG document
+    ///
+    /// Basis states sit on latitudes set by their Hamming weight, with |0…0⟩ at the north
+    /// pole and |1…1⟩ at the south pole. Each state above the
+    /// [threshold](StateVector::with_threshold) gets a dot whose area is proportional to
+    /// its probability and whose hue encodes the phase of its amplitude, as shown by the
+    /// phase wheel in the corner.
+    ///
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    ///
+    /// let mut qc = QuantumCircuit::new(2);
+    /// qc.h(0);
+    /// qc.cnot(0, 1);
+    ///
+    /// let svg = qc.execute().to_qsphere_svg();
+    /// assert!(svg.contains("|00⟩") && svg.contains("|11⟩"));
+    /// assert!(!svg.contains("|01⟩"));
+    /// ```
+    pub fn to_qsphere_svg(&self) -> String {
+        let num_qubits = self.num_qubits();
+        let mut svg = Svg::new(SIZE, SIZE);
+        let center = (SIZE / 2.0, SIZE / 2.0);
+        svg.circle(center, RADIUS, "#f4f6fb", "#444444");
+        great_circle(&mut svg, |t| [t.cos(), t.sin(), 0.0]);
+        svg.line(project([0.0, 0.0, 1.0]).0, project([0.0, 0.0, -1.0]).0, "#aaaaaa", 1.0);
+
+        // Rank of every basis state among the states of the same Hamming weight
+        let mut seen = vec![0; num_qubits + 1];
+        let mut states = Vec::new();
+        for (index, amp) in self.iter().enumerate() {
+            let weight = index.count_ones() as usize;
+            let rank = seen[weight];
+            seen[weight] += 1;
+            if amp.norm_squared() >= self.threshold() {
+                states.push((index, weight, rank));
+            }
+        }
+
+        let placed: Vec<(usize, [f64; 3])> = states.into_iter()
+            .map(|(index, weight, rank)| {
+                let theta = if num_qubits == 0 { 0.0 } else { PI * weight as f64 / num_qubits as f64 };
+                let phi = TAU * rank as f64 / binomial(num_qubits, weight) as f64;
+                (index, [theta.sin() * phi.cos(), theta.sin() * phi.sin(), theta.cos()])
+            })
+            .collect();
+
+        // States behind the sphere are drawn first so the front ones stay on top
+        for front in [false, true] {
+            for &(index, point) in placed.iter().filter(|&&(_, point)| project(point).1 == front) {
+                let amp = self[index];
+                let color = phase_color(amp.imag.atan2(amp.real));
+                let (position, _) = project(point);
+                svg.line(center, position, &color, 1.5);
+                svg.circle(position, (MAX_DOT * amp.norm()).max(2.0), &color, "#444444");
+                if num_qubits <= MAX_LABELLED_QUBITS {
+                    // Labels sit just outside the dot, away from the center of the image
+                    let (dx, dy) = (position.0 - center.0, position.1 - center.1);
+                    let length = (dx * dx + dy * dy).sqrt();
+                    let (ux, uy) = if length < 1.0 { (0.0, -1.0) } else { (dx / length, dy / length) };
+                    let label = (position.0 + ux * (MAX_DOT + 14.0), position.1 + uy * (MAX_DOT + 8.0));
+                    svg.text(label, 11.0, &format!("|{}⟩", index_to_bitstring(index, num_qubits, Endianness::Little)));
+                }
+            }
+        }
+
+        phase_wheel(&mut svg, (SIZE - 28.0, SIZE - 28.0));
+        svg.finish()
+    }
+
+    /// Renders the statevector as a grid of phase disks, one per basis state, as an SVG document
+    ///
+    /// Each disk is filled in proportion to the magnitude of the amplitude and carries a
+    /// hand pointing at its phase, measured counterclockwise from the right. States below
+    /// the [threshold](StateVector::with_threshold) are left empty. The grid has
+    /// `2^ceil(n/2)` columns, so the lower half of the qubits changes along each row.
+    ///
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    ///
+    /// let mut qc = QuantumCircuit::new(3);
+    /// qc.h(0);
+    ///
+    /// let svg = qc.execute().to_phase_disk_svg();
+    /// assert_eq!(svg.matches("<line").count(), 2);
+    /// ```
+    pub fn to_phase_disk_svg(&self) -> String {
+        let num_qubits = self.num_qubits();
+        let columns = (1usize << num_qubits.div_ceil(2)).min(self.len().max(1));
+        let rows = self.len().div_ceil(columns).max(1);
+        let mut svg = Svg::new(columns as f64 * CELL, rows as f64 * CELL + 8.0);
+
+        for (index, amp) in self.iter().enumerate() {
+            let center = (CELL * ((index % columns) as f64 + 0.5), CELL * ((index / columns) as f64 + 0.5) - 4.0);
+            svg.circle(center, DISK_RADIUS, "white", "#444444");
+            if amp.norm_squared() >= self.threshold() {
+                let phase = amp.imag.atan2(amp.real);
+                let color = phase_color(phase);
+                svg.circle(center, DISK_RADIUS * amp.norm().min(1.0), &color, "none");
+                let hand = (center.0 + DISK_RADIUS * phase.cos(), center.1 - DISK_RADIUS * phase.sin());
+                svg.line(center, hand, "#222222", 1.5);
+            }
+            let label = (center.0, center.1 + DISK_RADIUS + 10.0);
+            svg.text(label, 11.0, &format!("|{}⟩", index_to_bitstring(index, num_qubits, Endianness::Little)));
+        }
+        svg.finish()
+    }
+}
+
+/// Returns the hex color whose hue encodes a phase, red at zero and cyan at π
+fn phase_color(phase: f64) -> String {
+    let hue = phase.rem_euclid(TAU) / TAU * 6.0;
+    let fall = 1.0 - (hue % 2.0 - 1.0).abs();
+    let (red, green, blue) = match hue as usize {
+        0 => (1.0, fall, 0.0),
+        1 => (fall, 1.0, 0.0),
+        2 => (0.0, 1.0, fall),
+        3 => (0.0, fall, 1.0),
+        4 => (fall, 0.0, 1.0),
+        _ => (1.0, 0.0, fall),
+    };
+    let channel = |value: f64| (40.0 + 185.0 * value).round() as u8;
+    format!("#{:02x}{:02x}{:02x}", channel(red), channel(green), channel(blue))
+}
+
+/// Draws a ring of hues with the phases 0 and π marked, as a legend for the colors
+fn phase_wheel(svg: &mut Svg, center: (f64, f64)) {
+    const STEPS: usize = 24;
+    const WHEEL_RADIUS: f64 = 14.0;
+    for step in 0..STEPS {
+        let phase = TAU * step as f64 / STEPS as f64;
+        let position = (center.0 + WHEEL_RADIUS * phase.cos(), center.1 - WHEEL_RADIUS * phase.sin());
+        svg.circle(position, 3.0, &phase_color(phase), "none");
+    }
+    svg.text((center.0 + WHEEL_RADIUS + 10.0, center.1), 10.0, "0");
+    svg.text((center.0 - WHEEL_RADIUS - 10.0, center.1), 10.0, "π");
+}
+
+/// Returns the number of ways to choose `k` out of `n` items
+fn binomial(n: usize, k: usize) -> usize {
+    (0..k).fold(1, |acc, i| acc * (n - i) / (i + 1))
+}
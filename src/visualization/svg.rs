@@ -0,0 +1,79 @@
+use std::fmt::Write;
+
+/// Accumulates the elements of an SVG document
+pub(crate) struct Svg {
+    /// Markup written so far, without the closing tag
+    markup: String,
+}
+
+impl Svg {
+    /// Starts a document of the given size with a white background
+    pub(crate) fn new(width: f64, height: f64) -> Self {
+        let mut markup = String::new();
+        let _ = writeln!(
+            markup,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}" font-family="sans-serif">"#,
+            w = width, h = height
+        );
+        let _ = writeln!(markup, r#"<rect width="{}" height="{}" fill="white"/>"#, width, height);
+        Svg { markup }
+    }
+
+    /// Adds a line segment
+    pub(crate) fn line(&mut self, from: (f64, f64), to: (f64, f64), stroke: &str, width: f64) {
+        let _ = writeln!(
+            self.markup,
+            r#"<line x1="{:.2}" y1="{:.2}" x2="{:.2}" y2="{:.2}" stroke="{}" stroke-width="{}"/>"#,
+            from.0, from.1, to.0, to.1, stroke, width
+        );
+    }
+
+    /// Adds an open polyline, dashed if requested
+    pub(crate) fn polyline(&mut self, points: &[(f64, f64)], stroke: &str, width: f64, dashed: bool) {
+        if points.len() < 2 {
+            return;
+        }
+        let points: Vec<String> = points.iter().map(|(x, y)| format!("{:.2},{:.2}", x, y)).collect();
+        let dash = if dashed { r#" stroke-dasharray="4 4""# } else { "" };
+        let _ = writeln!(
+            self.markup,
+            r#"<polyline points="{}" fill="none" stroke="{}" stroke-width="{}"{}/>"#,
+            points.join(" "), stroke, width, dash
+        );
+    }
+
+    /// Adds a circle with optional fill and stroke colors
+    pub(crate) fn circle(&mut self, center: (f64, f64), radius: f64, fill: &str, stroke: &str) {
+        let _ = writeln!(
+            self.markup,
+            r#"<circle cx="{:.2}" cy="{:.2}" r="{:.2}" fill="{}" stroke="{}"/>"#,
+            center.0, center.1, radius, fill, stroke
+        );
+    }
+
+    /// Adds a closed polygon
+    pub(crate) fn polygon(&mut self, points: &[(f64, f64)], fill: &str) {
+        let points: Vec<String> = points.iter().map(|(x, y)| format!("{:.2},{:.2}", x, y)).collect();
+        let _ = writeln!(self.markup, r#"<polygon points="{}" fill="{}"/>"#, points.join(" "), fill);
+    }
+
+    /// Adds text centered on a point
+    pub(crate) fn text(&mut self, position: (f64, f64), size: f64, content: &str) {
+        let _ = writeln!(
+            self.markup,
+            r#"<text x="{:.2}" y="{:.2}" font-size="{}" text-anchor="middle" dominant-baseline="middle">{}</text>"#,
+            position.0, position.1, size, escape(content)
+        );
+    }
+
+    /// Closes the document and returns its markup
+    pub(crate) fn finish(mut self) -> String {
+        self.markup.push_str("</svg>\n");
+        self.markup
+    }
+}
+
+/// Escapes the characters with a meaning in XML
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
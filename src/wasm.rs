@@ -0,0 +1,157 @@
+//! WebAssembly module
+//! 
+//! This module exposes circuit building, execution and text rendering to JavaScript
+//! through `wasm-bindgen`. Without the `os-rng` feature, which needs an entropy source
+//! that `wasm32-unknown-unknown` lacks, the crate builds for the browser with
+//! 
+//! ```text
+//! cargo build --target wasm32-unknown-unknown --no-default-features --features wasm
+//! ```
+//! 
+//! Invalid qubit indices are reported as JavaScript errors instead of panics, which
+//! would abort the module.
+
+use wasm_bindgen::prelude::*;
+
+use crate::QuantumCircuit;
+use crate::simulator::Simulator;
+use crate::utility::ComplexFormat;
+
+/// A quantum circuit, available in JavaScript as `Circuit`
+#[wasm_bindgen(js_name = Circuit)]
+pub struct WasmCircuit {
+    circuit: QuantumCircuit,
+}
+
+#[wasm_bindgen(js_class = Circuit)]
+impl WasmCircuit {
+    /// Creates an empty circuit on the given number of qubits
+    #[wasm_bindgen(constructor)]
+    pub fn new(num_qubits: usize) -> WasmCircuit {
+        WasmCircuit { circuit: QuantumCircuit::new(num_qubits) }
+    }
+
+    /// Returns the number of qubits
+    #[wasm_bindgen(getter, js_name = numQubits)]
+    pub fn num_qubits(&self) -> usize {
+        self.circuit.num_qubits()
+    }
+
+    /// Applies a Hadamard gate
+    pub fn h(&mut self, target: usize) -> Result<(), JsError> {
+        self.apply(&[target], |qc| qc.h(target))
+    }
+
+    /// Applies a Pauli-X gate
+    pub fn x(&mut self, target: usize) -> Result<(), JsError> {
+        self.apply(&[target], |qc| qc.x(target))
+    }
+
+    /// Applies a Pauli-Y gate
+    pub fn y(&mut self, target: usize) -> Result<(), JsError> {
+        self.apply(&[target], |qc| qc.y(target))
+    }
+
+    /// Applies a Pauli-Z gate
+    pub fn z(&mut self, target: usize) -> Result<(), JsError> {
+        self.apply(&[target], |qc| qc.z(target))
+    }
+
+    /// Applies an S gate
+    pub fn s(&mut self, target: usize) -> Result<(), JsError> {
+        self.apply(&[target], |qc| qc.s(target))
+    }
+
+    /// Applies a T gate
+    pub fn t(&mut self, target: usize) -> Result<(), JsError> {
+        self.apply(&[target], |qc| qc.t(target))
+    }
+
+    /// Applies a rotation around the X axis
+    pub fn rx(&mut self, target: usize, angle: f64) -> Result<(), JsError> {
+        self.apply(&[target], |qc| qc.rx(target, angle))
+    }
+
+    /// Applies a rotation around the Y axis
+    pub fn ry(&mut self, target: usize, angle: f64) -> Result<(), JsError> {
+        self.apply(&[target], |qc| qc.ry(target, angle))
+    }
+
+    /// Applies a rotation around the Z axis
+    pub fn rz(&mut self, target: usize, angle: f64) -> Result<(), JsError> {
+        self.apply(&[target], |qc| qc.rz(target, angle))
+    }
+
+    /// Applies a controlled-NOT gate
+    pub fn cnot(&mut self, control: usize, target: usize) -> Result<(), JsError> {
+        self.apply(&[control, target], |qc| qc.cnot(control, target))
+    }
+
+    /// Applies a controlled-Z gate
+    pub fn cz(&mut self, control: usize, target: usize) -> Result<(), JsError> {
+        self.apply(&[control, target], |qc| qc.cz(control, target))
+    }
+
+    /// Measures a qubit into a classical bit
+    pub fn measure(&mut self, qubit: usize, classical_bit: usize) -> Result<(), JsError> {
+        self.apply(&[qubit], |qc| qc.measure(qubit, classical_bit))
+    }
+
+    /// Returns the ASCII diagram of the circuit
+    pub fn draw(&self) -> String {
+        self.circuit.draw()
+    }
+
+    /// Returns the list of operations of the circuit
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_text(&self) -> String {
+        self.circuit.to_string()
+    }
+
+    /// Returns the final statevector as interleaved real and imaginary parts
+    pub fn statevector(&self) -> Vec<f64> {
+        self.circuit.execute().iter().flat_map(|amp| [amp.real, amp.imag]).collect()
+    }
+
+    /// Returns the final state in Dirac notation with the given number of decimals
+    #[wasm_bindgen(js_name = formatState)]
+    pub fn format_state(&self, precision: usize) -> String {
+        ComplexFormat::DEFAULT.with_precision(precision).format_state(&self.circuit.execute())
+    }
+
+    /// Returns the probability of every basis state
+    pub fn probabilities(&self) -> Vec<f64> {
+        Simulator::new().with_circuit(self.circuit.clone()).probabilities()
+    }
+
+    /// Samples the circuit and returns the counts as a JSON object keyed by bitstring
+    pub fn run(&self, shots: usize, seed: Option<u64>) -> Result<String, JsError> {
+        let mut simulator = Simulator::new().with_circuit(self.circuit.clone());
+        if let Some(seed) = seed {
+            simulator = simulator.with_seed(seed);
+        }
+        let result = simulator.try_run(shots).map_err(|error| JsError::new(&error.to_string()))?;
+
+        let mut counts: Vec<(&String, &usize)> = result.counts.iter().collect();
+        counts.sort();
+        let entries: Vec<String> = counts.iter()
+            .map(|(bitstring, count)| format!("\"{}\":{}", bitstring, count))
+            .collect();
+        Ok(format!("{{{}}}", entries.join(",")))
+    }
+}
+
+impl WasmCircuit {
+    /// Checks the qubits of an operation before adding it to the circuit
+    fn apply<F: FnOnce(&mut QuantumCircuit)>(&mut self, qubits: &[usize], add: F) -> Result<(), JsError> {
+        let num_qubits = self.circuit.num_qubits();
+        if let Some(&qubit) = qubits.iter().find(|&&qubit| qubit >= num_qubits) {
+            return Err(JsError::new(&format!("Qubit index {} out of range for {} qubits", qubit, num_qubits)));
+        }
+        if qubits.len() == 2 && qubits[0] == qubits[1] {
+            return Err(JsError::new(&format!("Control and target must differ, got qubit {} twice", qubits[0])));
+        }
+        add(&mut self.circuit);
+        Ok(())
+    }
+}
@@ -0,0 +1,73 @@
+use intrico::QuantumCircuit;
+use intrico::algorithms::{amplitude_from_phase, AmplitudeEstimation};
+
+/// Test suite for amplitude estimation.
+/// 
+/// These tests verify the behaviour of:
+/// - Circuit inversion used by the Grover operator
+/// - Canonical amplitude estimation on exactly representable amplitudes
+/// - Maximum-likelihood amplitude estimation with default and custom oracles
+mod amplitude_estimation_tests {
+    use super::*;
+
+    /// Tests that a circuit followed by its inverse is the identity.
+    #[test]
+    fn test_circuit_inverse() {
+        let mut qc = QuantumCircuit::new(2);
+        qc.h(0);
+        qc.t(0);
+        qc.cnot(0, 1);
+        qc.ry(1, 0.3);
+        qc.cp(1, 0, 0.7);
+
+        let mut roundtrip = qc.clone();
+        roundtrip.compose(&qc.inverse(), &[0, 1]);
+        let unitary = roundtrip.unitary();
+        for row in 0..4 {
+            for col in 0..4 {
+                let expected = if row == col { 1.0 } else { 0.0 };
+                assert!((unitary.get(row, col).norm() - expected).abs() < 1e-10);
+            }
+        }
+    }
+
+    /// Tests that canonical estimation recovers amplitudes on its phase grid.
+    #[test]
+    fn test_canonical() {
+        // a = sin²(π/4) = 0.5 and a = sin²(π/8) lie on the grid of 3 and 4 precision qubits
+        for (theta, precision) in [(std::f64::consts::FRAC_PI_4, 3), (std::f64::consts::PI / 8.0, 4)] {
+            let mut preparation = QuantumCircuit::new(1);
+            preparation.ry(0, 2.0 * theta);
+
+            let estimate = AmplitudeEstimation::new(preparation, &[0]).canonical(precision, 200);
+            assert!((estimate - theta.sin().powi(2)).abs() < 1e-9, "θ = {}: {}", theta, estimate);
+        }
+        assert!((amplitude_from_phase(0.75) - 0.5).abs() < 1e-12);
+    }
+
+    /// Tests maximum-likelihood estimation on an amplitude off the canonical grid.
+    #[test]
+    fn test_maximum_likelihood() {
+        let mut preparation = QuantumCircuit::new(1);
+        preparation.ry(0, 2.0 * 0.3);
+
+        let estimate = AmplitudeEstimation::new(preparation, &[0]).maximum_likelihood(&[0, 1, 2, 4, 8, 16], 2000);
+        assert!((estimate - 0.3_f64.sin().powi(2)).abs() < 0.005, "{}", estimate);
+    }
+
+    /// Tests estimation with a user-supplied oracle marking |11⟩ in a uniform superposition.
+    #[test]
+    fn test_custom_oracle() {
+        let mut preparation = QuantumCircuit::new(2);
+        preparation.h(0);
+        preparation.h(1);
+        let mut oracle = QuantumCircuit::new(2);
+        oracle.cz(0, 1);
+
+        let problem = AmplitudeEstimation::new(preparation, &[0, 1]).with_oracle(oracle);
+        // θ = π/6 is off the grid, whose closest point with 5 qubits is sin²(5π/32)
+        assert!((problem.canonical(5, 200) - (5.0 * std::f64::consts::PI / 32.0).sin().powi(2)).abs() < 1e-9);
+        let estimate = problem.maximum_likelihood(&[0, 1, 2, 4], 2000);
+        assert!((estimate - 0.25).abs() < 0.01, "{}", estimate);
+    }
+}
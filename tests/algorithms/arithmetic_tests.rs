@@ -0,0 +1,105 @@
+use intrico::QuantumCircuit;
+use intrico::algorithms::arithmetic::{add_constant, comparator, modular_adder, qft_adder, ripple_carry_adder};
+
+/// Test suite for the reversible arithmetic circuits.
+/// 
+/// These tests verify the behaviour of:
+/// - Ripple-carry and QFT-based addition on every input
+/// - Constant addition and comparison
+/// - Modular addition
+mod arithmetic_tests {
+    use super::*;
+
+    /// Prepares the given value on a little-endian register
+    fn load(circuit: &mut QuantumCircuit, register: &[usize], value: usize) {
+        for (bit, &qubit) in register.iter().enumerate() {
+            if (value >> bit) & 1 == 1 {
+                circuit.x(qubit);
+            }
+        }
+    }
+
+    /// Returns the basis state the circuit ends in
+    fn output(circuit: &QuantumCircuit) -> usize {
+        let state = circuit.execute();
+        let index = (0..state.len())
+            .max_by(|&a, &b| state[a].norm_squared().total_cmp(&state[b].norm_squared()))
+            .unwrap();
+        assert!((state[index].norm_squared() - 1.0).abs() < 1e-6, "not a basis state");
+        index
+    }
+
+    /// Tests the ripple-carry adder with carry out on all 3-bit inputs.
+    #[test]
+    fn test_ripple_carry_adder() {
+        let (a, b, ancilla, carry) = ([0, 1, 2], [3, 4, 5], 6, 7);
+        for x in 0..8 {
+            for y in 0..8 {
+                let mut qc = QuantumCircuit::new(8);
+                load(&mut qc, &a, x);
+                load(&mut qc, &b, y);
+                ripple_carry_adder(&mut qc, &a, &b, ancilla, Some(carry));
+
+                let sum = x + y;
+                let expected = x | ((sum % 8) << 3) | ((sum / 8) << 7);
+                assert_eq!(output(&qc), expected, "{} + {}", x, y);
+            }
+        }
+    }
+
+    /// Tests the QFT adder, including an operand shorter than the target.
+    #[test]
+    fn test_qft_adder() {
+        for x in 0..4 {
+            for y in 0..8 {
+                let mut qc = QuantumCircuit::new(5);
+                load(&mut qc, &[0, 1], x);
+                load(&mut qc, &[2, 3, 4], y);
+                qft_adder(&mut qc, &[0, 1], &[2, 3, 4]);
+                assert_eq!(output(&qc), x | (((x + y) % 8) << 2), "{} + {}", x, y);
+            }
+        }
+    }
+
+    /// Tests constant addition modulo the register size.
+    #[test]
+    fn test_add_constant() {
+        for y in 0..8 {
+            let mut qc = QuantumCircuit::new(3);
+            load(&mut qc, &[0, 1, 2], y);
+            add_constant(&mut qc, &[0, 1, 2], 5);
+            assert_eq!(output(&qc), (y + 5) % 8);
+        }
+    }
+
+    /// Tests the comparator on all 2-bit inputs and that it restores its operands.
+    #[test]
+    fn test_comparator() {
+        for x in 0..4 {
+            for y in 0..4 {
+                let mut qc = QuantumCircuit::new(6);
+                load(&mut qc, &[0, 1], x);
+                load(&mut qc, &[2, 3], y);
+                comparator(&mut qc, &[0, 1], &[2, 3], 4, 5);
+
+                let expected = x | (y << 2) | (usize::from(x < y) << 5);
+                assert_eq!(output(&qc), expected, "{} < {}", x, y);
+            }
+        }
+    }
+
+    /// Tests modular addition for every pair of residues.
+    #[test]
+    fn test_modular_adder() {
+        let modulus = 5;
+        for x in 0..modulus {
+            for y in 0..modulus {
+                let mut qc = QuantumCircuit::new(8);
+                load(&mut qc, &[0, 1, 2], x);
+                load(&mut qc, &[3, 4, 5, 6], y);
+                modular_adder(&mut qc, &[0, 1, 2], &[3, 4, 5, 6], modulus as u64, 7);
+                assert_eq!(output(&qc), x | (((x + y) % modulus) << 3), "({} + {}) mod {}", x, y, modulus);
+            }
+        }
+    }
+}
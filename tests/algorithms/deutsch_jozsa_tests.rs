@@ -0,0 +1,69 @@
+use intrico::QuantumCircuit;
+use intrico::algorithms::{deutsch_jozsa, is_constant, BitOracle};
+use intrico::simulator::Simulator;
+
+/// Test suite for the Deutsch–Jozsa algorithm.
+/// 
+/// These tests verify the behaviour of:
+/// - Classification of constant and balanced oracles
+/// - The quantum oracles against their classical evaluation
+mod deutsch_jozsa_tests {
+    use super::*;
+
+    fn classify(oracle: &BitOracle) -> bool {
+        let result = Simulator::new().with_circuit(deutsch_jozsa(oracle)).run(50);
+        is_constant(&result, oracle.num_inputs())
+    }
+
+    /// Tests that constant oracles are recognised as constant.
+    #[test]
+    fn test_constant_oracles() {
+        assert!(classify(&BitOracle::constant(3, false)));
+        assert!(classify(&BitOracle::constant(3, true)));
+        assert!(classify(&BitOracle::truth_table(vec![true; 4])));
+    }
+
+    /// Tests that balanced oracles are recognised as balanced.
+    #[test]
+    fn test_balanced_oracles() {
+        for mask in 1..8 {
+            assert!(!classify(&BitOracle::balanced(3, mask)), "mask {:03b}", mask);
+        }
+
+        let table = vec![true, false, false, true, true, true, false, false];
+        assert!(!classify(&BitOracle::truth_table(table)));
+    }
+
+    /// Tests that every oracle writes f(x) into the output qubit for every basis input.
+    #[test]
+    fn test_oracle_evaluation() {
+        let oracles = [
+            BitOracle::constant(3, true),
+            BitOracle::balanced(3, 0b110),
+            BitOracle::truth_table(vec![false, true, true, false, true, false, false, false]),
+        ];
+
+        for oracle in &oracles {
+            for input in 0..8 {
+                let mut qc = QuantumCircuit::new(4);
+                for bit in 0..3 {
+                    if input >> bit & 1 == 1 {
+                        qc.x(bit);
+                    }
+                }
+                oracle.apply(&mut qc, &[0, 1, 2], 3);
+
+                let result = Simulator::new().with_circuit(qc).run(1);
+                let expected = format!("{}{:03b}", oracle.evaluate(input) as u8, input);
+                assert_eq!(result.counts[&expected], 1, "{:?} on input {:03b}", oracle, input);
+            }
+        }
+    }
+
+    /// Tests that masks outside the input register are rejected.
+    #[test]
+    #[should_panic]
+    fn test_invalid_mask() {
+        BitOracle::balanced(2, 0b100);
+    }
+}
@@ -0,0 +1,56 @@
+use intrico::QuantumCircuit;
+use intrico::algorithms::{grover, optimal_iterations, phase_oracle};
+use intrico::simulator::Simulator;
+
+/// Test suite for Grover's search.
+/// 
+/// These tests verify the behaviour of the Grover builder, including:
+/// - Amplification of single and multiple marked states
+/// - The optimal number of iterations
+/// - Custom oracles
+mod grover_tests {
+    use super::*;
+
+    /// Tests that a single marked state is found with high probability for several register sizes.
+    #[test]
+    fn test_single_marked_state() {
+        for num_qubits in 2..=5 {
+            let marked = (1 << num_qubits) - 2;
+            let circuit = grover(num_qubits, phase_oracle(&[marked]), optimal_iterations(num_qubits, 1));
+
+            let probabilities = Simulator::new().with_circuit(circuit).probabilities();
+            assert!(probabilities[marked] > 0.9, "{} qubits: {}", num_qubits, probabilities[marked]);
+        }
+    }
+
+    /// Tests that several marked states are amplified equally.
+    #[test]
+    fn test_multiple_marked_states() {
+        let marked = [0b0011, 0b1100];
+        let circuit = grover(4, phase_oracle(&marked), optimal_iterations(4, marked.len()));
+
+        let probabilities = Simulator::new().with_circuit(circuit).probabilities();
+        let success: f64 = marked.iter().map(|&m| probabilities[m]).sum();
+        assert!(success > 0.9);
+        assert!((probabilities[marked[0]] - probabilities[marked[1]]).abs() < 1e-6);
+    }
+
+    /// Tests the number of iterations for known search space sizes.
+    #[test]
+    fn test_optimal_iterations() {
+        assert_eq!(optimal_iterations(2, 1), 1);
+        assert_eq!(optimal_iterations(3, 1), 2);
+        assert_eq!(optimal_iterations(4, 1), 3);
+        assert_eq!(optimal_iterations(2, 4), 0);
+    }
+
+    /// Tests that any closure can serve as the oracle.
+    #[test]
+    fn test_custom_oracle() {
+        // Marks |11⟩ on the two qubits directly with a CZ
+        let circuit = grover(2, |qc: &mut QuantumCircuit| qc.cz(0, 1), 1);
+
+        let result = Simulator::new().with_circuit(circuit).run(100);
+        assert_eq!(result.counts["11"], 100);
+    }
+}
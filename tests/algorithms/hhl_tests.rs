@@ -0,0 +1,84 @@
+use std::f64::consts::PI;
+
+use rusticle::complex::Complex;
+use rusticle::linalg::Matrix;
+use intrico::algorithms::HHL;
+
+/// Test suite for the HHL linear-systems algorithm.
+/// 
+/// These tests verify the behaviour of:
+/// - Exact solutions when the eigenvalues lie on the phase grid
+/// - Negative eigenvalues and 4x4 systems
+/// - Approximate solutions with the default evolution time
+/// - Rejection of non-Hermitian matrices
+mod hhl_tests {
+    use super::*;
+
+    fn real_matrix(dim: usize, entries: &[f64]) -> Matrix<Complex> {
+        Matrix::new(dim, dim, entries.iter().map(|&x| Complex::new(x, 0.0)).collect())
+    }
+
+    fn real_vector(entries: &[f64]) -> Vec<Complex> {
+        entries.iter().map(|&x| Complex::new(x, 0.0)).collect()
+    }
+
+    /// Tests an exact solve of a 2x2 system and its register layout.
+    #[test]
+    fn test_exact_2x2() {
+        let a = real_matrix(2, &[1.0, -1.0 / 3.0, -1.0 / 3.0, 1.0]);
+        let hhl = HHL::new(a, &real_vector(&[0.0, 1.0])).with_precision(3).with_time(3.0 * PI / 8.0);
+        assert_eq!(hhl.system_qubits(), 1);
+        assert!((hhl.eigenvalue(1) - 2.0 / 3.0).abs() < 1e-12);
+
+        let result = hhl.run();
+        assert_eq!(result.circuit.num_qubits(), 5);
+        assert!((result.fidelity - 1.0).abs() < 1e-6, "fidelity {}", result.fidelity);
+        assert!(result.success_probability > 0.0 && result.success_probability <= 1.0);
+
+        // x ∝ A⁻¹·b = (3/8)·(1, 3)
+        let ratio = result.classical_solution[1].real / result.classical_solution[0].real;
+        assert!((ratio - 3.0).abs() < 1e-9);
+    }
+
+    /// Tests a matrix with a negative eigenvalue.
+    #[test]
+    fn test_negative_eigenvalue() {
+        // X has eigenvalues ±1, giving phases 1/4 and 3/4 for t = π/2
+        let a = real_matrix(2, &[0.0, 1.0, 1.0, 0.0]);
+        let result = HHL::new(a, &real_vector(&[0.6, 0.8])).with_precision(2).with_time(PI / 2.0).run();
+        assert!((result.fidelity - 1.0).abs() < 1e-6, "fidelity {}", result.fidelity);
+    }
+
+    /// Tests an exact solve of a 4x4 system.
+    #[test]
+    fn test_exact_4x4() {
+        // Eigenvalues 1 and 3 give the phases 1/8 and 3/8 for t = π/4
+        let a = real_matrix(4, &[
+            2.0, 1.0, 0.0, 0.0,
+            1.0, 2.0, 0.0, 0.0,
+            0.0, 0.0, 2.0, -1.0,
+            0.0, 0.0, -1.0, 2.0,
+        ]);
+        let hhl = HHL::new(a, &real_vector(&[1.0, 0.0, 0.5, 0.5])).with_precision(3).with_time(PI / 4.0);
+        assert_eq!(hhl.system_qubits(), 2);
+
+        let result = hhl.run();
+        assert!((result.fidelity - 1.0).abs() < 1e-6, "fidelity {}", result.fidelity);
+    }
+
+    /// Tests that the default evolution time gives a close approximation.
+    #[test]
+    fn test_default_time() {
+        // Eigenvalues 1 and 2.5 with t = π/5 give the phases 1/10 and 1/4
+        let a = real_matrix(2, &[1.75, 0.75, 0.75, 1.75]);
+        let result = HHL::new(a, &real_vector(&[1.0, 0.0])).with_precision(5).run();
+        assert!(result.fidelity > 0.95, "fidelity {}", result.fidelity);
+    }
+
+    /// Tests that non-Hermitian matrices are rejected.
+    #[test]
+    #[should_panic(expected = "Hermitian")]
+    fn test_non_hermitian() {
+        HHL::new(real_matrix(2, &[1.0, 2.0, 0.0, 1.0]), &real_vector(&[1.0, 0.0]));
+    }
+}
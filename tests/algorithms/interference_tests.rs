@@ -0,0 +1,85 @@
+use intrico::QuantumCircuit;
+use intrico::algorithms::{hadamard_test, hadamard_test_expectation, swap_test, swap_test_overlap, ExpectationPart};
+use intrico::simulator::Simulator;
+use intrico::utility::state_fidelity;
+
+/// Test suite for the SWAP test and the Hadamard test.
+/// 
+/// These tests verify the behaviour of:
+/// - Overlap estimates of identical, orthogonal and partially overlapping states
+/// - Real and imaginary parts of unitary expectation values
+mod interference_tests {
+    use super::*;
+
+    fn rotated(theta: f64, phi: f64) -> QuantumCircuit {
+        let mut qc = QuantumCircuit::new(2);
+        qc.ry(0, theta);
+        qc.cnot(0, 1);
+        qc.rz(1, phi);
+        qc
+    }
+
+    /// Returns the exact probability that qubit 0 reads 0 at the end of a circuit
+    fn ancilla_zero(circuit: &QuantumCircuit) -> f64 {
+        circuit.execute()
+            .iter()
+            .enumerate()
+            .filter(|&(idx, _)| idx & 1 == 0)
+            .map(|(_, amp)| amp.norm_squared())
+            .sum()
+    }
+
+    /// Tests the SWAP test on identical and orthogonal states.
+    #[test]
+    fn test_swap_test_extremes() {
+        let a = rotated(0.7, 0.2);
+        let result = Simulator::new().with_circuit(swap_test(&a, &a)).run(200);
+        assert_eq!(swap_test_overlap(&result), 1.0);
+
+        let zero = QuantumCircuit::new(2);
+        let mut one = QuantumCircuit::new(2);
+        one.x(1);
+        let result = Simulator::new().with_seed(1).with_circuit(swap_test(&zero, &one)).run(4000);
+        assert!(swap_test_overlap(&result) < 0.1);
+    }
+
+    /// Tests that the SWAP test probability matches the state fidelity.
+    #[test]
+    fn test_swap_test_overlap() {
+        let a = rotated(0.7, 0.2);
+        let b = rotated(1.9, -0.4);
+        let overlap = state_fidelity(&a.execute(), &b.execute());
+
+        let circuit = swap_test(&a, &b);
+        assert_eq!(circuit.num_qubits(), 5);
+        assert!((2.0 * ancilla_zero(&circuit) - 1.0 - overlap).abs() < 1e-6);
+
+        let result = Simulator::new().with_seed(2).with_circuit(circuit).run(20_000);
+        assert!((swap_test_overlap(&result) - overlap).abs() < 0.03);
+    }
+
+    /// Tests both parts of ⟨ψ|U|ψ⟩ against the exact value.
+    #[test]
+    fn test_hadamard_test() {
+        let theta = 0.9;
+        let mut u = QuantumCircuit::new(1);
+        u.rz(0, theta);
+        let mut plus = QuantumCircuit::new(1);
+        plus.h(0);
+
+        // Rz(θ) on |+⟩: ⟨+|Rz(θ)|+⟩ = cos(θ/2)
+        let real = 2.0 * ancilla_zero(&hadamard_test(&u, &plus, ExpectationPart::Real)) - 1.0;
+        let imaginary = 2.0 * ancilla_zero(&hadamard_test(&u, &plus, ExpectationPart::Imaginary)) - 1.0;
+        assert!((real - (theta / 2.0).cos()).abs() < 1e-6);
+        assert!(imaginary.abs() < 1e-6);
+
+        // Rz(θ) on |0⟩: e^(-iθ/2)
+        let zero = QuantumCircuit::new(1);
+        let imaginary = 2.0 * ancilla_zero(&hadamard_test(&u, &zero, ExpectationPart::Imaginary)) - 1.0;
+        assert!((imaginary + (theta / 2.0).sin()).abs() < 1e-6);
+
+        let circuit = hadamard_test(&u, &zero, ExpectationPart::Real);
+        let result = Simulator::new().with_seed(3).with_circuit(circuit).run(20_000);
+        assert!((hadamard_test_expectation(&result) - (theta / 2.0).cos()).abs() < 0.03);
+    }
+}
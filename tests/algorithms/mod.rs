@@ -0,0 +1,13 @@
+mod amplitude_estimation_tests;
+mod arithmetic_tests;
+mod deutsch_jozsa_tests;
+mod grover_tests;
+mod hhl_tests;
+mod interference_tests;
+mod phase_estimation_tests;
+mod quantum_walk_tests;
+mod shor_tests;
+mod simon_tests;
+mod superdense_tests;
+mod teleportation_tests;
+mod trotter_tests;
\ No newline at end of file
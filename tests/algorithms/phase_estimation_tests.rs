@@ -0,0 +1,82 @@
+use intrico::QuantumCircuit;
+use intrico::algorithms::{phase_estimation, qft};
+use intrico::simulator::Simulator;
+use std::f64::consts::PI;
+
+/// Test suite for the quantum Fourier transform and phase estimation.
+/// 
+/// These tests verify the behaviour of:
+/// - The QFT against the discrete Fourier transform
+/// - Exact phase estimation of single- and multi-qubit unitaries
+/// - The phase distribution of phases that are not exactly representable
+mod phase_estimation_tests {
+    use super::*;
+
+    /// Tests that the QFT circuit implements the discrete Fourier transform.
+    #[test]
+    fn test_qft_matrix() {
+        let n = 3;
+        let dim = 1 << n;
+        let mut qc = QuantumCircuit::new(n);
+        qft(&mut qc, &[0, 1, 2]);
+
+        let unitary = qc.unitary();
+        let scale = 1.0 / (dim as f64).sqrt();
+        for row in 0..dim {
+            for col in 0..dim {
+                let angle = 2.0 * PI * (row * col) as f64 / dim as f64;
+                let entry = unitary.get(row, col);
+                assert!((entry.real - scale * angle.cos()).abs() < 1e-9);
+                assert!((entry.imag - scale * angle.sin()).abs() < 1e-9);
+            }
+        }
+    }
+
+    /// Tests that exactly representable phases are recovered with certainty.
+    #[test]
+    fn test_exact_phase() {
+        let mut s = QuantumCircuit::new(1);
+        s.s(0);
+        let mut prepare = QuantumCircuit::new(1);
+        prepare.x(0);
+
+        let qpe = phase_estimation(&s, 4).with_preparation(&prepare);
+        let result = Simulator::new().with_circuit(qpe.circuit.clone()).run(200);
+
+        assert_eq!(qpe.estimate(&result), 0.25);
+        assert_eq!(qpe.phase_distribution(&result), vec![(0.25, 1.0)]);
+        assert_eq!(qpe.target_qubits(), 4..5);
+    }
+
+    /// Tests phase estimation of a two-qubit unitary on the |11⟩ eigenstate.
+    #[test]
+    fn test_multi_qubit_unitary() {
+        let phase = 5.0 / 16.0;
+        let mut unitary = QuantumCircuit::new(2);
+        unitary.cp(0, 1, 2.0 * PI * phase);
+        let mut prepare = QuantumCircuit::new(2);
+        prepare.x(0);
+        prepare.x(1);
+
+        let qpe = phase_estimation(&unitary, 4).with_preparation(&prepare);
+        let result = Simulator::new().with_circuit(qpe.circuit.clone()).run(100);
+
+        assert_eq!(qpe.estimate(&result), phase);
+        assert_eq!(qpe.decode_phase("110101"), phase);
+    }
+
+    /// Tests that an inexact phase is estimated to the nearest representable value.
+    #[test]
+    fn test_inexact_phase() {
+        let mut unitary = QuantumCircuit::new(1);
+        unitary.rz(0, 2.0 * PI / 3.0);
+
+        // Rz(θ)|0⟩ = e^(-iθ/2)|0⟩, i.e. φ = -1/6 ≡ 5/6
+        let qpe = phase_estimation(&unitary, 5);
+        let result = Simulator::new().with_circuit(qpe.circuit.clone()).run(2000);
+
+        assert!((qpe.estimate(&result) - 5.0 / 6.0).abs() <= 1.0 / 64.0);
+        let total: f64 = qpe.phase_distribution(&result).iter().map(|&(_, p)| p).sum();
+        assert!((total - 1.0).abs() < 1e-9);
+    }
+}
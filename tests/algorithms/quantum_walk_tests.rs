@@ -0,0 +1,52 @@
+use intrico::QuantumGate;
+use intrico::algorithms::{position_distribution, quantum_walk};
+
+/// Test suite for coined quantum walks.
+/// 
+/// These tests verify the behaviour of:
+/// - The distribution of the Hadamard walk after a few steps
+/// - Parity of the reachable positions
+/// - Wrap-around on the cycle
+mod quantum_walk_tests {
+    use super::*;
+
+    /// Tests the Hadamard walk distribution after two steps.
+    #[test]
+    fn test_two_steps() {
+        let distribution = position_distribution(&quantum_walk(3, 2, QuantumGate::H).execute());
+        // Positions -2, 0 and +2 with probabilities 1/4, 1/2 and 1/4
+        assert!((distribution[6] - 0.25).abs() < 1e-6);
+        assert!((distribution[0] - 0.5).abs() < 1e-6);
+        assert!((distribution[2] - 0.25).abs() < 1e-6);
+    }
+
+    /// Tests that an odd number of steps only reaches odd positions within range.
+    #[test]
+    fn test_parity_and_spread() {
+        let distribution = position_distribution(&quantum_walk(4, 5, QuantumGate::H).execute());
+        let total: f64 = distribution.iter().sum();
+        assert!((total - 1.0).abs() < 1e-6);
+
+        for (position, &p) in distribution.iter().enumerate() {
+            if position % 2 == 0 {
+                assert!(p < 1e-9, "even position {} has probability {}", position, p);
+            }
+        }
+        // Walkers never move further than the number of steps
+        assert!(distribution[6..=10].iter().all(|&p| p < 1e-9));
+    }
+
+    /// Tests that a Pauli-X coin alternates directions and that the cycle wraps around.
+    #[test]
+    fn test_cycle() {
+        // X coin: up, down, up, ... returns to 0 after two steps
+        let distribution = position_distribution(&quantum_walk(2, 2, QuantumGate::X).execute());
+        assert!((distribution[0] - 1.0).abs() < 1e-6);
+
+        // A Z coin leaves |0⟩ unchanged, so the walker keeps moving down around the 4-cycle
+        let distribution = position_distribution(&quantum_walk(2, 4, QuantumGate::Z).execute());
+        assert!((distribution[0] - 1.0).abs() < 1e-6);
+        let distribution = position_distribution(&quantum_walk(2, 1, QuantumGate::Z).execute());
+        assert!((distribution[3] - 1.0).abs() < 1e-6);
+    }
+}
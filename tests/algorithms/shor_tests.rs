@@ -0,0 +1,50 @@
+use intrico::algorithms::{order_finding, phase_to_order, shor, shor_with};
+use intrico::simulator::Simulator;
+
+/// Test suite for Shor's algorithm.
+/// 
+/// These tests verify the behaviour of:
+/// - The order finding circuit
+/// - The continued fraction step
+/// - Factoring of small composites, primes and even numbers
+mod shor_tests {
+    use super::*;
+
+    /// Tests that order finding for 7 mod 15 only measures multiples of 1/4.
+    #[test]
+    fn test_order_finding() {
+        let qpe = order_finding(7, 15);
+        assert_eq!(qpe.circuit.num_qubits(), 12);
+
+        let result = Simulator::new().with_circuit(qpe.circuit.clone()).run(200);
+        for (phase, _) in qpe.phase_distribution(&result) {
+            assert_eq!((phase * 4.0).fract(), 0.0, "unexpected phase {}", phase);
+        }
+    }
+
+    /// Tests that continued fractions recover the order from measured phases.
+    #[test]
+    fn test_phase_to_order() {
+        assert_eq!(phase_to_order(0.75, 7, 15), Some(4));
+        assert_eq!(phase_to_order(0.5, 7, 15), Some(2));
+        assert_eq!(phase_to_order(0.0, 7, 15), None);
+        // 2 has order 6 modulo 21, and 171/1024 ≈ 1/6
+        assert_eq!(phase_to_order(171.0 / 1024.0, 2, 21), Some(6));
+    }
+
+    /// Tests that small composites are factored from simulated runs.
+    #[test]
+    fn test_factoring() {
+        let sim = Simulator::new().with_seed(3);
+        assert_eq!(shor_with(15, &sim), Some((3, 5)));
+        assert_eq!(shor_with(21, &sim), Some((3, 7)));
+    }
+
+    /// Tests the classical shortcuts for primes and even numbers.
+    #[test]
+    fn test_classical_cases() {
+        assert_eq!(shor(13), None);
+        assert_eq!(shor(2), None);
+        assert_eq!(shor(22), Some((2, 11)));
+    }
+}
@@ -0,0 +1,57 @@
+use intrico::QuantumCircuit;
+use intrico::algorithms::{find_period, simon, simon_oracle, solve_simon};
+use intrico::simulator::Simulator;
+
+/// Test suite for Simon's algorithm.
+/// 
+/// These tests verify the behaviour of:
+/// - The two-to-one oracle
+/// - The GF(2) post-processing
+/// - End-to-end recovery of the hidden period
+mod simon_tests {
+    use super::*;
+
+    /// Tests that the oracle maps x and x ⊕ s to the same output.
+    #[test]
+    fn test_oracle_is_two_to_one() {
+        let (n, secret) = (3, 0b101);
+        let output = |input: usize| {
+            let mut qc = QuantumCircuit::new(2 * n);
+            for bit in 0..n {
+                if input >> bit & 1 == 1 {
+                    qc.x(bit);
+                }
+            }
+            simon_oracle(n, secret)(&mut qc);
+
+            let result = Simulator::new().with_circuit(qc).run(1);
+            let bits = result.counts.keys().next().unwrap().clone();
+            usize::from_str_radix(&bits[..n], 2).unwrap()
+        };
+
+        for input in 0..(1 << n) {
+            assert_eq!(output(input), output(input ^ secret));
+            assert_ne!(output(input), output(input ^ 0b001));
+        }
+    }
+
+    /// Tests the linear algebra step on hand-picked samples.
+    #[test]
+    fn test_solve_simon() {
+        assert_eq!(solve_simon(&[0b0110, 0b1001, 0b0011], 4), Some(0b1111));
+        assert_eq!(solve_simon(&[0b001, 0b010, 0b100], 3), Some(0));
+        assert_eq!(solve_simon(&[], 2), None);
+        assert_eq!(solve_simon(&[0b10, 0b10], 2), Some(0b01));
+    }
+
+    /// Tests that the period is recovered from simulated runs for every secret.
+    #[test]
+    fn test_find_period() {
+        let n = 4;
+        for secret in 0..(1 << n) {
+            let circuit = simon(n, simon_oracle(n, secret));
+            let result = Simulator::new().with_circuit(circuit).run(200);
+            assert_eq!(find_period(&result, n), Some(secret), "secret {:04b}", secret);
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use intrico::algorithms::{decoded_bits, superdense_coding};
+use intrico::simulator::Simulator;
+
+/// Test suite for superdense coding.
+/// 
+/// These tests verify the behaviour of:
+/// - Deterministic decoding of every pair of bits
+/// - Rejection of values that are not bits
+mod superdense_tests {
+    use super::*;
+
+    /// Tests that every pair of bits is decoded in every shot.
+    #[test]
+    fn test_all_messages() {
+        for bits in [(0, 0), (0, 1), (1, 0), (1, 1)] {
+            let result = Simulator::new().with_circuit(superdense_coding(bits)).run(200);
+            assert_eq!(decoded_bits(&result), bits);
+            assert_eq!(result.counts.len(), 1, "{:?}: {:?}", bits, result.counts);
+        }
+    }
+
+    /// Tests that values other than 0 and 1 are rejected.
+    #[test]
+    #[should_panic(expected = "Superdense coding sends two bits")]
+    fn test_invalid_bits() {
+        superdense_coding((2, 0));
+    }
+}
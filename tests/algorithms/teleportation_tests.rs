@@ -0,0 +1,52 @@
+use intrico::QuantumCircuit;
+use intrico::algorithms::{received_state, teleport, teleportation};
+use intrico::simulator::Simulator;
+use intrico::utility::state_fidelity;
+
+/// Test suite for quantum teleportation.
+/// 
+/// These tests verify the behaviour of:
+/// - Arrival of the prepared state on the receiver's qubit
+/// - Uniform Bell measurement outcomes on the sender's qubits
+/// - Classically conditioned corrections
+mod teleportation_tests {
+    use super::*;
+
+    /// Tests that a range of prepared states arrive intact.
+    #[test]
+    fn test_state_arrives_intact() {
+        for (theta, phi) in [(0.0, 0.0), (std::f64::consts::PI, 0.0), (0.7, 1.9), (2.3, -0.8)] {
+            let mut preparation = QuantumCircuit::new(1);
+            preparation.ry(0, theta);
+            preparation.rz(0, phi);
+
+            let result = Simulator::new().with_circuit(teleport(&preparation)).run(200);
+            let fidelity = state_fidelity(&received_state(&result.final_state), &preparation.execute());
+            assert!((fidelity - 1.0).abs() < 1e-6, "θ = {}, φ = {}: fidelity {}", theta, phi, fidelity);
+        }
+    }
+
+    /// Tests that the receiver's qubit always reads the teleported basis state.
+    #[test]
+    fn test_receiver_counts() {
+        let mut preparation = QuantumCircuit::new(1);
+        preparation.x(0);
+
+        let result = Simulator::new().with_seed(7).with_circuit(teleport(&preparation)).run(1000);
+        assert_eq!(result.marginal_counts(&[2]).get("1"), Some(&1000));
+
+        // Every Bell measurement outcome occurs
+        let sender = result.marginal_counts(&[0, 1]);
+        assert_eq!(sender.len(), 4);
+        assert!(sender.values().all(|&count| count > 150), "{:?}", sender);
+    }
+
+    /// Tests that the corrections are conditioned on the measured bits.
+    #[test]
+    fn test_conditioned_corrections() {
+        let qc = teleportation();
+        let conditions: Vec<_> = qc.operations().iter().filter_map(|op| op.condition).collect();
+        assert_eq!(conditions, vec![(1, true), (0, true)]);
+        assert!(qc.to_string().contains("if c1 == 1"));
+    }
+}
@@ -0,0 +1,116 @@
+use rusticle::complex::Complex;
+use rusticle::linalg::Matrix;
+use intrico::{QuantumCircuit, QuantumGate};
+use intrico::algorithms::trotter_evolution;
+use intrico::observables::PauliSum;
+use intrico::utility::state_fidelity;
+
+/// Test suite for Trotterized time evolution.
+/// 
+/// These tests verify the behaviour of:
+/// - Exact exponentials of single Pauli strings
+/// - Exact evolution under commuting terms
+/// - Convergence with the number of steps and the order
+mod trotter_tests {
+    use super::*;
+
+    /// Returns the matrix of a Pauli string, the rightmost character acting on qubit 0
+    fn pauli_matrix(pauli: &str) -> Matrix<Complex> {
+        pauli.chars().fold(Matrix::identity(1), |acc, c| {
+            let factor = match c {
+                'X' => QuantumGate::X.matrix(),
+                'Y' => QuantumGate::Y.matrix(),
+                'Z' => QuantumGate::Z.matrix(),
+                _ => Matrix::identity(2),
+            };
+            // The accumulated string holds the more significant qubits
+            let dim = acc.rows();
+            let mut product = Matrix::zeros(2 * dim, 2 * dim);
+            for r in 0..2 * dim {
+                for c in 0..2 * dim {
+                    product.set(r, c, *acc.get(r / 2, c / 2) * *factor.get(r % 2, c % 2));
+                }
+            }
+            product
+        })
+    }
+
+    /// Tests that a single Pauli string is exponentiated exactly, up to a global phase.
+    #[test]
+    fn test_single_pauli_strings() {
+        let theta = 0.37;
+        for pauli in ["X", "Y", "Z", "ZZ", "XX", "XY", "YZX", "ZIX"] {
+            let hamiltonian = PauliSum::new(pauli.len()).with_term(1.0, pauli);
+            let unitary = trotter_evolution(&hamiltonian, theta, 1, 1).unitary();
+
+            // e^(-iθP) = cos(θ)·I - i·sin(θ)·P
+            let p = pauli_matrix(pauli);
+            let dim = p.rows();
+            let mut phase = None;
+            for r in 0..dim {
+                for c in 0..dim {
+                    let identity = if r == c { theta.cos() } else { 0.0 };
+                    let expected = Complex::new(identity, 0.0) + *p.get(r, c) * Complex::new(0.0, -theta.sin());
+                    let actual = *unitary.get(r, c);
+                    if expected.norm() > 1e-6 {
+                        let ratio = actual / expected;
+                        let reference = *phase.get_or_insert(ratio);
+                        assert!((ratio - reference).norm() < 1e-6, "{}: entry ({}, {})", pauli, r, c);
+                    } else {
+                        assert!(actual.norm() < 1e-6, "{}: entry ({}, {})", pauli, r, c);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Tests that commuting terms are evolved exactly with a single step.
+    #[test]
+    fn test_commuting_terms() {
+        let time = 0.8;
+        let hamiltonian = PauliSum::new(2).with_term(1.0, "ZZ").with_term(0.5, "ZI");
+
+        let mut qc = QuantumCircuit::new(2);
+        qc.h(0);
+        qc.h(1);
+        qc.compose(&trotter_evolution(&hamiltonian, time, 1, 1), &[0, 1]);
+
+        let expected: Vec<Complex> = (0..4)
+            .map(|k| {
+                let z0 = if k & 1 == 0 { 1.0 } else { -1.0 };
+                let z1 = if k & 2 == 0 { 1.0 } else { -1.0 };
+                let energy = z0 * z1 + 0.5 * z1;
+                Complex::new(0.0, -energy * time).exp() * 0.5
+            })
+            .collect();
+        assert!((state_fidelity(&qc.execute(), &expected) - 1.0).abs() < 1e-6);
+    }
+
+    /// Tests convergence for non-commuting terms against the exact single-qubit evolution.
+    #[test]
+    fn test_convergence() {
+        // H = X + Z, so e^(-iHt)|0⟩ = cos(√2t)|0⟩ - i·sin(√2t)/√2·(|0⟩ + |1⟩)
+        let time = 1.3;
+        let hamiltonian = PauliSum::new(1).with_term(1.0, "X").with_term(1.0, "Z");
+        let (c, s) = ((2f64.sqrt() * time).cos(), (2f64.sqrt() * time).sin() / 2f64.sqrt());
+        let exact = [Complex::new(c, -s), Complex::new(0.0, -s)];
+
+        let infidelity = |steps, order| {
+            let state = trotter_evolution(&hamiltonian, time, steps, order).execute();
+            1.0 - state_fidelity(&state, &exact)
+        };
+
+        assert!(infidelity(1, 1) > 0.01);
+        assert!(infidelity(20, 1) < infidelity(5, 1));
+        assert!(infidelity(5, 2) < infidelity(5, 1));
+        assert!(infidelity(5, 4) < infidelity(5, 2));
+        assert!(infidelity(10, 4) < 1e-6);
+    }
+
+    /// Tests that odd orders above one are rejected.
+    #[test]
+    #[should_panic(expected = "Trotter order must be 1 or even")]
+    fn test_invalid_order() {
+        trotter_evolution(&PauliSum::new(1).with_term(1.0, "X"), 1.0, 1, 3);
+    }
+}
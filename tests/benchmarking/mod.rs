@@ -0,0 +1,2 @@
+mod quantum_volume_tests;
+mod randomized_benchmarking_tests;
\ No newline at end of file
@@ -0,0 +1,49 @@
+use intrico::benchmarking::quantum_volume;
+use intrico::simulator::{NoiseModel, Simulator};
+
+/// Test suite for the quantum volume experiment.
+/// 
+/// These tests verify the behaviour of:
+/// - Structure of the model circuits
+/// - Heavy output probabilities of noiseless and noisy runs
+/// - The reported quantum volume
+mod quantum_volume_tests {
+    use super::*;
+
+    /// Tests that model circuits have one SU(4) gate per pair and layer.
+    #[test]
+    fn test_model_circuit() {
+        let experiment = quantum_volume(4, 1);
+        let circuit = experiment.model_circuit(3, &mut rand::rng());
+
+        assert_eq!(circuit.num_qubits(), 3);
+        assert_eq!(circuit.num_operations(), 3);
+        assert!(circuit.operations().iter().all(|op| op.qubit.len() == 2));
+    }
+
+    /// Tests that a noiseless simulator reaches the full quantum volume.
+    #[test]
+    fn test_noiseless_volume() {
+        let result = quantum_volume(3, 100)
+            .with_simulator(Simulator::new().with_seed(2))
+            .with_seed(2)
+            .run();
+
+        assert_eq!(result.widths, vec![2, 3]);
+        assert!(result.heavy_output_probabilities.iter().all(|&h| h > 0.75));
+        assert_eq!(result.quantum_volume, 8);
+    }
+
+    /// Tests that strong noise pushes the heavy output probability towards 1/2.
+    #[test]
+    fn test_noisy_volume() {
+        let simulator = Simulator::new()
+            .with_noise(NoiseModel::depolarizing(0.0, 0.3))
+            .with_seed(4);
+        let result = quantum_volume(4, 40).with_simulator(simulator).with_seed(4).run();
+
+        assert!(result.heavy_output_probabilities[2] < 0.62);
+        assert!(!result.passed[0]);
+        assert_eq!(result.quantum_volume, 1);
+    }
+}
@@ -0,0 +1,86 @@
+use intrico::benchmarking::{clifford_group, fit_decay, try_fit_decay, RandomizedBenchmarking};
+use intrico::simulator::{NoiseModel, Simulator};
+
+/// Test suite for randomized benchmarking.
+/// 
+/// These tests verify the behaviour of:
+/// - Enumeration of the one- and two-qubit Clifford groups
+/// - Sequences returning to the initial state
+/// - Exponential-decay fitting of survival probabilities, and runs too noisy to fit
+/// - Error per Clifford growing with the simulated noise
+mod randomized_benchmarking_tests {
+    use super::*;
+
+    /// Tests the sizes of the Clifford groups.
+    #[test]
+    fn test_clifford_group_sizes() {
+        assert_eq!(clifford_group(1).len(), 24);
+        assert_eq!(RandomizedBenchmarking::new(2).num_cliffords(), 11520);
+    }
+
+    /// Tests that noiseless sequences always survive.
+    #[test]
+    fn test_noiseless_survival() {
+        let result = RandomizedBenchmarking::new(2)
+            .with_lengths(&[1, 5, 20])
+            .with_sequences(4)
+            .with_shots(20)
+            .with_seed(1)
+            .run();
+
+        assert_eq!(result.survival, vec![1.0, 1.0, 1.0]);
+        assert!(result.error_per_clifford.unwrap().abs() < 1e-12);
+    }
+
+    /// Tests that the fit recovers a known decay.
+    #[test]
+    fn test_fit_decay() {
+        let lengths = [2, 4, 8, 16, 32];
+        let survival: Vec<f64> = lengths.iter().map(|&m| 0.7 * 0.95_f64.powi(m as i32) + 0.25).collect();
+
+        let fit = fit_decay(&lengths, &survival, 0.25);
+        assert!((fit.decay - 0.95).abs() < 1e-9);
+        assert!((fit.value(8) - survival[2]).abs() < 1e-9);
+    }
+
+    /// Tests that stronger noise gives a larger error per Clifford.
+    #[test]
+    fn test_error_per_clifford() {
+        let run = |error: f64| {
+            let simulator = Simulator::new()
+                .with_noise(NoiseModel::depolarizing(error, error))
+                .with_seed(9);
+            RandomizedBenchmarking::new(1)
+                .with_lengths(&[1, 8, 16, 32, 64])
+                .with_simulator(simulator)
+                .with_seed(9)
+                .run()
+        };
+
+        let weak = run(0.002);
+        let strong = run(0.02);
+        assert!(weak.survival[0] > weak.survival[4]);
+        assert!(weak.error_per_clifford.unwrap() > 0.0);
+        assert!(strong.error_per_clifford.unwrap() > 3.0 * weak.error_per_clifford.unwrap());
+    }
+
+    /// Tests that fully decayed survival leaves the fit out instead of panicking.
+    #[test]
+    fn test_unavailable_fit() {
+        assert!(try_fit_decay(&[1, 10], &[0.9, 0.5], 0.5).is_none());
+        assert!(try_fit_decay(&[4, 4], &[0.9, 0.8], 0.5).is_none());
+
+        let simulator = Simulator::new()
+            .with_noise(NoiseModel::depolarizing(0.75, 0.75))
+            .with_seed(2);
+        let result = RandomizedBenchmarking::new(1)
+            .with_lengths(&[20, 40, 60])
+            .with_simulator(simulator)
+            .with_sequences(2)
+            .with_shots(20)
+            .with_seed(2)
+            .run();
+        assert_eq!(result.survival.len(), 3);
+        assert_eq!(result.fit.is_some(), result.error_per_clifford.is_some());
+    }
+}
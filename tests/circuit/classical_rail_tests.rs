@@ -0,0 +1,97 @@
+use intrico::QuantumCircuit;
+
+/// Test suite for classical rails in circuit diagrams.
+///
+/// These tests verify the behaviour of:
+/// - One labelled rail per classical bit below the qubits
+/// - Arrows from measurements to their bit
+/// - Markers where conditioned operations read
+/// - Classical operations written on their rail
+/// - Conditioned operations scheduled after the write of their bit
+mod classical_rail_tests {
+    use super::*;
+
+    /// Returns the lines of the diagram without trailing whitespace.
+    fn lines(qc: &QuantumCircuit) -> Vec<String> {
+        qc.draw().lines().map(|line| line.trim_end().to_string()).collect()
+    }
+
+    /// Tests that measurements are connected to the rail of their bit.
+    #[test]
+    fn test_measurement_arrows() {
+        let mut qc = QuantumCircuit::new(2);
+        qc.h(0);
+        qc.measure(0, 1);
+        qc.measure(1, 0);
+
+        assert_eq!(lines(&qc), [
+            "q0: ──H───[M]─",
+            "           ║",
+            "q1: ─[M]───╫──",
+            "      ║    ║",
+            "c0: ══▼════╬══",
+            "           ║",
+            "c1: ═══════▼══",
+        ]);
+    }
+
+    /// Tests that conditions are marked with the value they test.
+    #[test]
+    fn test_condition_markers() {
+        let mut qc = QuantumCircuit::new(1);
+        qc.measure(0, 0);
+        qc.x(0);
+        qc.c_if(0, false);
+        qc.z(0);
+        qc.c_if(0, true);
+
+        assert_eq!(lines(&qc), [
+            "q0: ─[M]──X──Z─",
+            "      ║   ║  ║",
+            "c0: ══▼═══○══●═",
+        ]);
+    }
+
+    /// Tests that classical bit labels are padded together with the qubit labels.
+    #[test]
+    fn test_clbit_labels() {
+        let mut qc = QuantumCircuit::new(1);
+        qc.label_clbit(0, "flag");
+        qc.measure(0, 0);
+
+        assert_eq!(lines(&qc), [
+            "q0  : ─[M]─",
+            "        ║",
+            "flag: ══▼══",
+        ]);
+    }
+
+    /// Tests that classical operations are written on the rail they set.
+    #[test]
+    fn test_classical_operations() {
+        let mut qc = QuantumCircuit::new(2);
+        qc.measure(0, 0);
+        qc.measure(1, 1);
+        qc.c_xor(0, 1, 2);
+
+        let diagram = qc.draw();
+        let rail = diagram.lines().last().unwrap();
+        assert!(rail.starts_with("c2: "));
+        assert!(rail.contains("c0 ^ c1"));
+    }
+
+    /// Tests that a conditioned operation is drawn after the measurement it reads.
+    #[test]
+    fn test_condition_after_measurement() {
+        let mut qc = QuantumCircuit::new(2);
+        qc.h(0);
+        qc.measure(0, 0);
+        qc.x(1);
+        qc.c_if(0, true);
+        qc.h(1);
+
+        assert_eq!(qc.operations()[1].step, 2);
+        assert_eq!(qc.operations()[2].step, 3);
+        assert_eq!(qc.operations()[3].step, 4);
+    }
+}
@@ -0,0 +1,115 @@
+use intrico::{QuantumCircuit, QuantumGate};
+use intrico::utility::state_fidelity;
+
+/// Test suite for user-defined composite gates.
+///
+/// These tests verify the behaviour of:
+/// - Calling a definition on different qubits
+/// - Drawing calls as a single element
+/// - Executing and inverting calls like their definition
+/// - Rejecting unknown, duplicate and non-unitary definitions
+mod composite_gate_tests {
+    use super::*;
+
+    fn toffoli_ish() -> QuantumCircuit {
+        let mut qc = QuantumCircuit::new(3);
+        qc.h(2);
+        qc.ccx(0, 1, 2);
+        qc.h(2);
+        qc
+    }
+
+    /// Tests that every call is a single operation on the mapped qubits.
+    #[test]
+    fn test_call_gate() {
+        let mut qc = QuantumCircuit::new(4);
+        qc.define_gate("toffoli_ish", toffoli_ish());
+        qc.call_gate("toffoli_ish", &[0, 1, 2]);
+        qc.call_gate("toffoli_ish", &[3, 2, 1]);
+
+        assert_eq!(qc.num_operations(), 2);
+        assert_eq!(qc[1].qubit, vec![3, 2, 1]);
+        assert_eq!(qc[1].gate.name(), "toffoli_ish");
+        assert_eq!(qc[1].gate.arity(), 3);
+        assert!(qc.draw().contains("[toffoli_ish]"));
+    }
+
+    /// Tests that executing a call matches composing the definition.
+    #[test]
+    fn test_execute_call() {
+        let mut called = QuantumCircuit::new(3);
+        called.h(0);
+        called.x(2);
+        called.define_gate("toffoli_ish", toffoli_ish());
+        called.call_gate("toffoli_ish", &[2, 0, 1]);
+
+        let mut composed = QuantumCircuit::new(3);
+        composed.h(0);
+        composed.x(2);
+        composed.compose(&toffoli_ish(), &[2, 0, 1]);
+
+        let fidelity = state_fidelity(&called.execute(), &composed.execute());
+        assert!((fidelity - 1.0).abs() < 1e-6);
+
+        let (called, composed) = (called.unitary(), composed.unitary());
+        for row in 0..8 {
+            for col in 0..8 {
+                assert!((*called.get(row, col) - *composed.get(row, col)).norm() < 1e-9);
+            }
+        }
+    }
+
+    /// Tests that the inverse of a call undoes it.
+    #[test]
+    fn test_inverse_call() {
+        let mut qc = QuantumCircuit::new(3);
+        qc.define_gate("toffoli_ish", toffoli_ish());
+        qc.call_gate("toffoli_ish", &[0, 1, 2]);
+        qc.t(1);
+
+        let inverse = qc.inverse();
+        assert!(matches!(inverse[1].gate, QuantumGate::Composite { .. }));
+        assert_eq!(inverse[1].gate.name(), "toffoli_ish†");
+
+        let mut round_trip = qc.clone();
+        round_trip.compose(&inverse, &[0, 1, 2]);
+        assert!((round_trip.execute().probabilities()[0] - 1.0).abs() < 1e-6);
+    }
+
+    /// Tests that calling an unknown gate panics.
+    #[test]
+    #[should_panic(expected = "No gate named")]
+    fn test_unknown_gate() {
+        let mut qc = QuantumCircuit::new(2);
+        qc.call_gate("missing", &[0, 1]);
+    }
+
+    /// Tests that a name cannot be defined twice.
+    #[test]
+    #[should_panic(expected = "already defined")]
+    fn test_duplicate_definition() {
+        let mut qc = QuantumCircuit::new(3);
+        qc.define_gate("toffoli_ish", toffoli_ish());
+        qc.define_gate("toffoli_ish", toffoli_ish());
+    }
+
+    /// Tests that definitions cannot measure.
+    #[test]
+    #[should_panic(expected = "unitary")]
+    fn test_measurement_in_definition() {
+        let mut definition = QuantumCircuit::new(1);
+        definition.measure(0, 0);
+
+        let mut qc = QuantumCircuit::new(1);
+        qc.define_gate("measure", definition);
+    }
+
+    /// Tests that a call must provide one qubit per qubit of the definition.
+    #[test]
+    #[should_panic(expected = "acts on 3 qubits")]
+    fn test_call_arity() {
+        let mut qc = QuantumCircuit::new(3);
+        qc.define_gate("toffoli_ish", toffoli_ish());
+        qc.call_gate("toffoli_ish", &[0, 1]);
+    }
+}
@@ -0,0 +1,54 @@
+use intrico::QuantumCircuit;
+use intrico::simulator::Simulator;
+use intrico::utility::{index_to_bitstring, Endianness};
+
+/// Test suite for circuits starting from a basis state.
+///
+/// These tests verify the behaviour of:
+/// - The number of qubits and the X gates inserted
+/// - The bit order of the initial state
+/// - Looping an oracle over all basis inputs
+/// - Rejection of invalid characters
+mod initial_bitstring_tests {
+    use super::*;
+
+    /// Tests that only the qubits set to 1 are flipped.
+    #[test]
+    fn test_inserted_gates() {
+        let qc = QuantumCircuit::with_initial_bitstring("0110");
+        assert_eq!(qc.num_qubits(), 4);
+        let flipped: Vec<usize> = qc.operations().iter().map(|op| op.target()).collect();
+        assert_eq!(flipped, vec![1, 2]);
+
+        assert_eq!(QuantumCircuit::with_initial_bitstring("000").num_operations(), 0);
+    }
+
+    /// Tests that the counts read back the initial bitstring.
+    #[test]
+    fn test_counts_match_bitstring() {
+        for bitstring in ["1", "10", "001", "1101"] {
+            let mut qc = QuantumCircuit::with_initial_bitstring(bitstring);
+            qc.measure_all();
+            let result = Simulator::new().with_circuit(qc).run(10);
+            assert_eq!(result.counts[bitstring], 10);
+        }
+    }
+
+    /// Tests a CNOT oracle on every two-qubit basis input.
+    #[test]
+    fn test_oracle_over_inputs() {
+        for index in 0..4 {
+            let mut qc = QuantumCircuit::with_initial_bitstring(&index_to_bitstring(index, 2, Endianness::Little));
+            qc.cnot(0, 1);
+            let expected = index ^ ((index & 1) << 1);
+            assert_eq!(qc.execute().probabilities()[expected], 1.0);
+        }
+    }
+
+    /// Tests that characters other than 0 and 1 are rejected.
+    #[test]
+    #[should_panic(expected = "can only contain 0 and 1")]
+    fn test_invalid_bitstring() {
+        QuantumCircuit::with_initial_bitstring("01a");
+    }
+}
@@ -0,0 +1,63 @@
+use intrico::{QuantumCircuit, QuantumGate};
+use intrico::utility::random_unitary;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use rusticle::complex::Complex;
+use rusticle::linalg::Matrix;
+
+/// Test suite for the simulator kernels behind circuit execution.
+///
+/// These tests verify the behaviour of:
+/// - The single-qubit kernel
+/// - The in-place CNOT kernel
+mod kernel_tests {
+    use super::*;
+
+    /// Tests that the single-qubit kernel agrees with applying the gate tensored with the identity.
+    #[test]
+    fn test_single_qubit_kernel() {
+        let mut rng = StdRng::seed_from_u64(12);
+        let state = QuantumGate::Custom(random_unitary(8, &mut rng), "U".to_string(), "U".to_string());
+        let gate = random_unitary(2, &mut rng);
+        let mut tensored = Matrix::new(4, 4, vec![Complex::new(0.0, 0.0); 16]);
+        for r in 0..4 {
+            for c in (0..4).filter(|c| c % 2 == r % 2) {
+                tensored.set(r, c, *gate.get(r / 2, c / 2));
+            }
+        }
+
+        for (target, other) in [(0, 1), (1, 2), (2, 0)] {
+            let mut kernel = QuantumCircuit::new(3);
+            kernel.add_multi_qubit_gate(state.clone(), &[0, 1, 2]);
+            kernel.add_gate(QuantumGate::Custom(gate.clone(), "G".to_string(), "G".to_string()), target);
+            let mut dense = QuantumCircuit::new(3);
+            dense.add_multi_qubit_gate(state.clone(), &[0, 1, 2]);
+            dense.add_multi_qubit_gate(QuantumGate::Custom(tensored.clone(), "G⊗I".to_string(), "G".to_string()), &[target, other]);
+
+            for (a, b) in kernel.execute().iter().zip(dense.execute().iter()) {
+                assert!((*a - *b).norm() < 1e-9);
+            }
+        }
+    }
+
+    /// Tests that the in-place CNOT kernel agrees with applying the CNOT matrix.
+    #[test]
+    fn test_cnot_kernel() {
+        let prepare = |qc: &mut QuantumCircuit| {
+            let unitary = random_unitary(8, &mut StdRng::seed_from_u64(11));
+            qc.add_multi_qubit_gate(QuantumGate::Custom(unitary, "U".to_string(), "U".to_string()), &[0, 1, 2]);
+        };
+        for (control, target) in [(0, 1), (1, 0), (0, 2), (2, 0), (1, 2), (2, 1)] {
+            let mut kernel = QuantumCircuit::new(3);
+            prepare(&mut kernel);
+            kernel.cnot(control, target);
+            let mut dense = QuantumCircuit::new(3);
+            prepare(&mut dense);
+            dense.add_multi_qubit_gate(QuantumGate::Custom(QuantumGate::CNOT.matrix(), "CX".to_string(), "CX".to_string()), &[control, target]);
+
+            for (a, b) in kernel.execute().iter().zip(dense.execute().iter()) {
+                assert!((*a - *b).norm() < 1e-9);
+            }
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use intrico::QuantumCircuit;
+use intrico::observables::PauliSum;
+use intrico::simulator::{Observable, Simulator};
+
+/// Test suite for causal light cones.
+///
+/// These tests verify the behaviour of:
+/// - The operations and qubits kept in the light cone
+/// - Expectation values of the cone matching the full circuit
+/// - Circuits with classical dependencies being kept whole
+mod light_cone_tests {
+    use super::*;
+
+    /// Builds a brickwork circuit of rotations and CNOTs over `n` qubits.
+    fn brickwork(n: usize, layers: usize) -> QuantumCircuit {
+        let mut qc = QuantumCircuit::new(n);
+        for layer in 0..layers {
+            for qubit in 0..n {
+                qc.ry(qubit, 0.3 + 0.1 * (qubit + layer) as f64);
+            }
+            for qubit in (layer % 2..n - 1).step_by(2) {
+                qc.cnot(qubit, qubit + 1);
+            }
+        }
+        qc
+    }
+
+    /// Tests that the cone grows by one qubit per side and layer of two-qubit gates.
+    #[test]
+    fn test_cone_width() {
+        let qc = brickwork(12, 2);
+
+        let (cone, qubits) = qc.light_cone(&[6]);
+        assert_eq!(qubits, vec![4, 5, 6, 7]);
+        assert_eq!(cone.num_operations(), 9);
+
+        let (cone, qubits) = qc.light_cone(&[]);
+        assert!(qubits.is_empty());
+        assert_eq!(cone.num_operations(), 0);
+    }
+
+    /// Tests that local expectation values of the cone match those of the full circuit.
+    #[test]
+    fn test_cone_expectation() {
+        let qc = brickwork(12, 3);
+        let observable: PauliSum = "IIIIIZXIIIII + 0.5*IIIIIIZIIIII".parse().unwrap();
+        let full = observable.expectation(&Simulator::new().with_circuit(qc.clone()).run(0).final_state);
+
+        let (cone, qubits) = qc.light_cone(&observable.support());
+        assert_eq!(qubits, (2..10).collect::<Vec<_>>());
+        let restrict = |pauli: &str| -> String {
+            let chars: Vec<char> = pauli.chars().rev().collect();
+            qubits.iter().rev().map(|&qubit| chars[qubit]).collect()
+        };
+        let reduced = PauliSum::new(qubits.len())
+            .with_term(1.0, &restrict("IIIIIZXIIIII"))
+            .with_term(0.5, &restrict("IIIIIIZIIIII"));
+        let value = reduced.expectation(&Simulator::new().with_circuit(cone).run(0).final_state);
+        assert!((value - full).abs() < 1e-6);
+    }
+
+    /// Tests that measurements keep the whole circuit and snapshots are dropped.
+    #[test]
+    fn test_classical_circuits_kept_whole() {
+        let mut qc = brickwork(4, 2);
+        qc.measure(3, 0);
+        let (cone, qubits) = qc.light_cone(&[0]);
+        assert_eq!(qubits, vec![0, 1, 2, 3]);
+        assert_eq!(cone.num_operations(), qc.num_operations());
+
+        let mut qc = QuantumCircuit::new(2);
+        qc.h(0);
+        qc.snapshot("middle");
+        qc.x(1);
+        let (cone, qubits) = qc.light_cone(&[0]);
+        assert_eq!(qubits, vec![0]);
+        assert_eq!(cone.num_operations(), 1);
+    }
+
+    /// Tests that out-of-bounds qubits are rejected.
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn test_out_of_bounds() {
+        QuantumCircuit::new(2).light_cone(&[2]);
+    }
+}
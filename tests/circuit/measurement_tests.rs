@@ -0,0 +1,97 @@
+use intrico::{QuantumCircuit, QuantumGate};
+use intrico::simulator::Simulator;
+
+/// Test suite for measuring and unmeasuring whole circuits.
+///
+/// These tests verify the behaviour of:
+/// - Measuring every qubit into the matching classical bit
+/// - Removing final measurements while keeping mid-circuit ones
+/// - Shrinking the classical register and the diagram afterwards
+mod measurement_tests {
+    use super::*;
+
+    /// Returns a Bell pair measured on both qubits.
+    fn measured_bell() -> QuantumCircuit {
+        let mut qc = QuantumCircuit::new(2);
+        qc.h(0);
+        qc.cnot(0, 1);
+        qc.measure_all();
+        qc
+    }
+
+    /// Tests that every qubit is measured into its own bit.
+    #[test]
+    fn test_measure_all() {
+        let qc = measured_bell();
+        assert_eq!(qc.num_classical_bits(), 2);
+        let measured: Vec<(usize, Option<usize>)> = qc.operations()[2..].iter()
+            .map(|op| (op.target(), op.classical_bit))
+            .collect();
+        assert_eq!(measured, vec![(0, Some(0)), (1, Some(1))]);
+
+        let result = Simulator::new().with_seed(7).with_circuit(qc).run(200);
+        assert!(result.counts.keys().all(|bits| bits == "00" || bits == "11"));
+    }
+
+    /// Tests that a register larger than the circuit is kept.
+    #[test]
+    fn test_measure_all_keeps_larger_register() {
+        let mut qc = QuantumCircuit::new(2);
+        qc.measure(0, 3);
+        qc.measure_all();
+        assert_eq!(qc.num_classical_bits(), 4);
+    }
+
+    /// Tests that removing final measurements restores the unmeasured circuit.
+    #[test]
+    fn test_remove_final_measurements() {
+        let mut qc = measured_bell();
+        qc.remove_final_measurements();
+
+        assert_eq!(qc.num_operations(), 2);
+        assert_eq!(qc.num_classical_bits(), 0);
+        assert!(qc.operations().iter().all(|op| !op.gate.is_measurement()));
+        assert!(!qc.draw().contains("c0"));
+
+        let state = qc.execute();
+        assert!((state.probabilities()[0b00] - 0.5).abs() < 1e-6);
+        assert!((state.probabilities()[0b11] - 0.5).abs() < 1e-6);
+    }
+
+    /// Tests that measurements read by conditions or followed by gates are kept.
+    #[test]
+    fn test_keep_mid_circuit_measurements() {
+        let mut qc = QuantumCircuit::new(3);
+        qc.h(0);
+        qc.measure(0, 0);
+        qc.x(1);
+        qc.c_if(0, true);
+        qc.measure(2, 1);
+        qc.h(2);
+        qc.measure(1, 2);
+        qc.snapshot("end");
+
+        qc.remove_final_measurements();
+        let gates: Vec<&QuantumGate> = qc.operations().iter().map(|op| &op.gate).collect();
+        assert_eq!(gates, vec![
+            &QuantumGate::H,
+            &QuantumGate::Measure,
+            &QuantumGate::X,
+            &QuantumGate::Measure,
+            &QuantumGate::H,
+            &QuantumGate::Snapshot("end".to_string()),
+        ]);
+        assert_eq!(qc.num_classical_bits(), 2);
+    }
+
+    /// Tests that gates added afterwards take the step freed by the measurement.
+    #[test]
+    fn test_steps_after_removal() {
+        let mut qc = QuantumCircuit::new(1);
+        qc.h(0);
+        qc.measure_all();
+        qc.remove_final_measurements();
+        qc.x(0);
+        assert_eq!(qc.operations()[1].step, 2);
+    }
+}
@@ -0,0 +1,12 @@
+mod classical_rail_tests;
+mod composite_gate_tests;
+mod initial_bitstring_tests;
+mod kernel_tests;
+mod light_cone_tests;
+mod measurement_tests;
+mod multiplexed_tests;
+mod state_preparation_tests;
+mod timeline_tests;
+mod wire_label_tests;
+#[cfg(feature = "plotters")]
+mod render_tests;
\ No newline at end of file
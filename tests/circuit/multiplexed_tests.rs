@@ -0,0 +1,75 @@
+use intrico::{QuantumCircuit, QuantumGate};
+use intrico::verify::unitary_distance;
+use rusticle::complex::Complex;
+use rusticle::linalg::Matrix;
+
+/// Test suite for uniformly controlled (multiplexed) rotations.
+///
+/// These tests verify the behaviour of:
+/// - The Ry and Rz multiplexers against their block-diagonal unitaries
+/// - The size of the CNOT and rotation decomposition
+/// - Validation of the angle count
+mod multiplexed_tests {
+    use super::*;
+
+    /// Builds the block-diagonal unitary applying `rotation(angles[j])` for control value `j`,
+    /// on a circuit whose qubits are `[controls..., target]` with qubit 0 as the least significant bit.
+    fn reference(rotation: fn(f64) -> QuantumGate, controls: &[usize], target: usize, angles: &[f64]) -> Matrix<Complex> {
+        let num_qubits = controls.len() + 1;
+        let mut blocks = Matrix::zeros(1 << num_qubits, 1 << num_qubits);
+        for (j, &angle) in angles.iter().enumerate() {
+            let matrix = rotation(angle).matrix();
+            for r in 0..2 {
+                for c in 0..2 {
+                    blocks.set(2 * j + r, 2 * j + c, *matrix.get(r, c));
+                }
+            }
+        }
+        let mut qubits = controls.to_vec();
+        qubits.push(target);
+
+        let mut qc = QuantumCircuit::new(num_qubits);
+        qc.add_multi_qubit_gate(QuantumGate::Custom(blocks, "UC".to_string(), "UC".to_string()), &qubits);
+        qc.unitary()
+    }
+
+    /// Tests multiplexers with up to three controls in arbitrary qubit order.
+    #[test]
+    fn test_multiplexed_rotations() {
+        let cases: [(&[usize], usize); 4] = [(&[], 0), (&[1], 0), (&[0, 2], 1), (&[3, 0, 2], 1)];
+        for (controls, target) in cases {
+            let angles: Vec<f64> = (0..1 << controls.len()).map(|j| 0.3 + 0.7 * j as f64).collect();
+
+            let mut ry = QuantumCircuit::new(controls.len() + 1);
+            ry.ucry(controls, target, &angles);
+            assert!(unitary_distance(&ry.unitary(), &reference(QuantumGate::Ry, controls, target, &angles)) < 1e-9);
+
+            // Rz multiplexers are exact, including the global phase
+            let mut rz = QuantumCircuit::new(controls.len() + 1);
+            rz.ucrz(controls, target, &angles);
+            let expected = reference(QuantumGate::Rz, controls, target, &angles);
+            let actual = rz.unitary();
+            for r in 0..expected.rows() {
+                for c in 0..expected.cols() {
+                    assert!((*actual.get(r, c) - *expected.get(r, c)).norm() < 1e-9);
+                }
+            }
+        }
+    }
+
+    /// Tests that a multiplexer with k controls uses 2^k rotations and 2^k CNOTs.
+    #[test]
+    fn test_multiplexed_gate_count() {
+        let mut qc = QuantumCircuit::new(4);
+        qc.ucry(&[0, 1, 2], 3, &[0.5; 8]);
+        let cnots = qc.operations().iter().filter(|op| op.gate == QuantumGate::CNOT).count();
+        assert_eq!((qc.num_operations(), cnots), (16, 8));
+    }
+
+    /// Tests that the number of angles must match the number of control values.
+    #[test]
+    #[should_panic(expected = "A multiplexed rotation with 2 controls needs 4 angles, got 2")]
+    fn test_multiplexed_angle_count() {
+        QuantumCircuit::new(3).ucrz(&[0, 1], 2, &[0.1, 0.2]);
+    }
+}
@@ -0,0 +1,57 @@
+use intrico::QuantumCircuit;
+
+/// Test suite for the circuit renderer.
+///
+/// These tests verify the behaviour of:
+/// - Image dimensions derived from the circuit layout
+/// - In-memory bitmap rendering
+/// - PNG export
+mod render_tests {
+    use super::*;
+
+    /// Builds a Bell circuit followed by a measurement.
+    fn bell() -> QuantumCircuit {
+        let mut qc = QuantumCircuit::new(2);
+        qc.h(0);
+        qc.cnot(0, 1);
+        qc.measure(0, 0);
+        qc
+    }
+
+    /// Tests that the image grows with the number of steps and qubits.
+    #[test]
+    fn test_render_size() {
+        let mut wider = bell();
+        wider.x(1);
+        wider.x(1);
+        assert!(wider.render_size().0 > bell().render_size().0);
+
+        let height = |qubits| QuantumCircuit::new(qubits).render_size().1;
+        assert!(height(2) > height(1));
+        assert_eq!(height(3) - height(2), height(2) - height(1));
+
+        let mut labelled = bell();
+        labelled.label_qubit(0, "a long ancilla label");
+        assert!(labelled.render_size().0 > bell().render_size().0);
+    }
+
+    /// Tests that the bitmap has the advertised size and contains dark pixels.
+    #[test]
+    fn test_render_bitmap() {
+        let bitmap = bell().render_bitmap().unwrap();
+        assert_eq!((bitmap.width, bitmap.height), bell().render_size());
+        assert_eq!(bitmap.pixels.len(), (bitmap.width * bitmap.height * 3) as usize);
+        assert!(bitmap.pixels.contains(&0));
+        assert!(bitmap.pixels.contains(&255));
+    }
+
+    /// Tests that a PNG file is written.
+    #[test]
+    fn test_render_png() {
+        let path = std::env::temp_dir().join(format!("intrico-render-{}.png", std::process::id()));
+        bell().render_png(&path).unwrap();
+        let bytes = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(&bytes[..8], b"\x89PNG\r\n\x1a\n");
+    }
+}
@@ -0,0 +1,60 @@
+use intrico::QuantumCircuit;
+use rusticle::complex::Complex;
+
+/// Test suite for single-qubit state preparation.
+///
+/// These tests verify the behaviour of:
+/// - Preparing arbitrary amplitudes on fresh wires
+/// - Rejecting wires that were already used
+/// - Ignoring earlier snapshots
+/// - Rejecting unnormalized amplitudes
+mod state_preparation_tests {
+    use super::*;
+
+    /// Tests that the prepared amplitudes appear in the executed state.
+    #[test]
+    fn test_set_qubit_state() {
+        let (alpha, beta) = (Complex::new(0.6, 0.0), Complex::new(0.0, -0.8));
+        let mut qc = QuantumCircuit::new(2);
+        qc.set_qubit_state(0, alpha, beta);
+        qc.set_qubit_state(1, beta, alpha);
+        assert_eq!(qc.operations()[0].gate.symbol(), "Init");
+
+        let state = qc.execute();
+        let expected = [alpha * beta, beta * beta, alpha * alpha, beta * alpha];
+        for (amplitude, expected) in state.iter().zip(expected) {
+            assert!((*amplitude - expected).norm() < 1e-6);
+        }
+    }
+
+    /// Tests that a wire cannot be prepared after an operation acted on it.
+    #[test]
+    #[should_panic(expected = "before any other operation")]
+    fn test_set_qubit_state_after_gate() {
+        let mut qc = QuantumCircuit::new(2);
+        qc.h(0);
+        qc.cnot(0, 1);
+        qc.set_qubit_state(1, Complex::new(0.0, 0.0), Complex::new(1.0, 0.0));
+    }
+
+    /// Tests that a snapshot does not count as an operation on the prepared wire.
+    #[test]
+    fn test_set_qubit_state_after_snapshot() {
+        let mut qc = QuantumCircuit::new(2);
+        qc.h(0);
+        qc.snapshot("start");
+        qc.set_qubit_state(1, Complex::new(0.0, 0.0), Complex::new(1.0, 0.0));
+
+        let probabilities = qc.execute().probabilities();
+        assert!((probabilities[2] - 0.5).abs() < 1e-6);
+        assert!((probabilities[3] - 0.5).abs() < 1e-6);
+    }
+
+    /// Tests that unnormalized amplitudes are rejected.
+    #[test]
+    #[should_panic(expected = "normalized")]
+    fn test_set_qubit_state_unnormalized() {
+        let mut qc = QuantumCircuit::new(1);
+        qc.set_qubit_state(0, Complex::new(1.0, 0.0), Complex::new(1.0, 0.0));
+    }
+}
@@ -0,0 +1,126 @@
+use intrico::QuantumCircuit;
+use intrico::device::{DeviceModel, QubitProperties};
+
+/// Test suite for circuit scheduling.
+///
+/// These tests verify the behaviour of:
+/// - As-soon-as-possible start times from per-operation durations
+/// - Barriers and classical dependencies
+/// - Calibrated durations of a device model
+/// - The time-axis diagram, including instantaneous operations
+/// - Rejection of invalid durations
+mod timeline_tests {
+    use super::*;
+
+    /// Tests that operations start once their qubits are free.
+    #[test]
+    fn test_start_times() {
+        let mut qc = QuantumCircuit::new(3);
+        qc.h(0);
+        qc.set_duration(30.0);
+        qc.h(1);
+        qc.set_duration(10.0);
+        qc.cnot(1, 2);
+        qc.set_duration(100.0);
+        qc.cnot(0, 1);
+        qc.set_duration(100.0);
+
+        let timeline = qc.timeline();
+        assert_eq!(timeline.start_times, vec![0.0, 0.0, 10.0, 110.0]);
+        assert_eq!(timeline.end_time(2), 110.0);
+        assert_eq!(qc.duration(), 210.0);
+        assert_eq!(QuantumCircuit::new(2).duration(), 0.0);
+    }
+
+    /// Tests that snapshots and classical conditions delay later operations.
+    #[test]
+    fn test_dependencies() {
+        let mut qc = QuantumCircuit::new(3);
+        qc.x(0);
+        qc.set_duration(50.0);
+        qc.snapshot("barrier");
+        qc.x(1);
+        qc.set_duration(10.0);
+        qc.measure(1, 0);
+        qc.set_duration(500.0);
+        qc.x(2);
+        qc.c_if(0, true);
+
+        let timeline = qc.timeline();
+        assert_eq!(timeline.start_times, vec![0.0, 50.0, 50.0, 60.0, 560.0]);
+    }
+
+    /// Tests that device calibrations fill in durations not set on the operations.
+    #[test]
+    fn test_device_timeline() {
+        let qubit = QubitProperties { t1: 100_000.0, t2: 80_000.0, readout_error: 0.0 };
+        let device = DeviceModel::new("pair", vec![qubit; 2])
+            .with_gate("H", &[], 0.0, 35.0)
+            .with_gate("CX", &[], 0.0, 300.0);
+
+        let mut qc = QuantumCircuit::new(2);
+        qc.h(0);
+        qc.set_duration(20.0);
+        qc.h(1);
+        qc.cnot(0, 1);
+
+        let timeline = device.timeline(&qc);
+        assert_eq!(timeline.durations, vec![20.0, 35.0, 300.0]);
+        assert_eq!(timeline.duration(), 335.0);
+    }
+
+    /// Tests that box widths follow the durations in the time-axis diagram.
+    #[test]
+    fn test_draw_timeline() {
+        let mut qc = QuantumCircuit::new(2);
+        qc.h(0);
+        qc.set_duration(100.0);
+        qc.x(1);
+        qc.set_duration(30.0);
+        qc.measure(1, 0);
+
+        let diagram = qc.timeline().draw(10.0);
+        let lines: Vec<&str> = diagram.lines().collect();
+        assert_eq!(lines[0], "q0: [H───────]");
+        assert_eq!(lines[1], "q1: [X]M──────");
+        assert_eq!(lines[2], "    0         100");
+    }
+
+    /// Tests that consecutive instantaneous operations get a column each.
+    #[test]
+    fn test_draw_instantaneous() {
+        let mut qc = QuantumCircuit::new(2);
+        qc.h(0);
+        qc.x(0);
+        qc.z(0);
+        qc.y(1);
+        qc.set_duration(20.0);
+
+        let diagram = qc.timeline().draw(10.0);
+        let lines: Vec<&str> = diagram.lines().collect();
+        assert_eq!(lines[0], "q0: HXZ");
+        assert_eq!(lines[1], "q1: Y──");
+    }
+
+    /// Tests that NaN and infinite durations are rejected.
+    #[test]
+    fn test_non_finite_duration() {
+        for duration in [f64::NAN, f64::INFINITY] {
+            let result = std::panic::catch_unwind(|| {
+                let mut qc = QuantumCircuit::new(1);
+                qc.h(0);
+                qc.set_duration(duration);
+            });
+            assert!(result.is_err());
+        }
+    }
+
+    /// Tests that negative durations are rejected.
+    #[test]
+    #[should_panic(expected = "negative")]
+    fn test_negative_duration() {
+        let mut qc = QuantumCircuit::new(1);
+        qc.h(0);
+        qc.set_duration(-1.0);
+    }
+}
@@ -0,0 +1,85 @@
+use intrico::QuantumCircuit;
+
+/// Test suite for custom wire labels.
+///
+/// These tests verify the behaviour of:
+/// - Default and custom qubit and classical bit labels
+/// - Label alignment in the ASCII diagram
+/// - Labels in the timeline diagram
+/// - Labels carried over to the inverse circuit
+mod wire_label_tests {
+    use super::*;
+
+    /// Tests that unlabelled wires fall back to their index.
+    #[test]
+    fn test_default_labels() {
+        let mut qc = QuantumCircuit::new(2);
+        qc.label_qubit(1, "anc");
+        qc.label_clbit(3, "m0");
+
+        assert_eq!(qc.qubit_label(0), "q0");
+        assert_eq!(qc.qubit_label(1), "anc");
+        assert_eq!(qc.clbit_label(0), "c0");
+        assert_eq!(qc.clbit_label(3), "m0");
+    }
+
+    /// Tests that labels are padded so that all wires line up.
+    #[test]
+    fn test_draw_labels() {
+        let mut qc = QuantumCircuit::new(2);
+        qc.label_qubit(0, "data");
+        qc.h(0);
+        qc.cnot(0, 1);
+
+        assert_eq!(qc.draw(), "data: ─H──●─\n          │ \nq1  : ────X─\n");
+
+        let mut empty = QuantumCircuit::new(2);
+        empty.label_qubit(1, "anc");
+        assert_eq!(empty.draw(), "q0 : ───\nanc: ───\n");
+    }
+
+    /// Tests that unlabelled circuits keep their usual diagram.
+    #[test]
+    fn test_draw_without_labels() {
+        let mut qc = QuantumCircuit::new(2);
+        qc.h(0);
+        qc.cnot(0, 1);
+
+        let diagram = qc.draw();
+        assert!(diagram.starts_with("q0: ─H─"));
+        assert!(diagram.lines().nth(1).unwrap().starts_with("    "));
+        assert!(diagram.lines().nth(2).unwrap().starts_with("q1: "));
+    }
+
+    /// Tests that the timeline diagram uses the labels.
+    #[test]
+    fn test_timeline_labels() {
+        let mut qc = QuantumCircuit::new(2);
+        qc.label_qubit(1, "anc");
+        qc.h(0);
+        qc.set_duration(10.0);
+
+        assert_eq!(qc.timeline().draw(10.0), "q0 : H\nanc: ─\n     0\n");
+    }
+
+    /// Tests that the inverse circuit keeps the labels.
+    #[test]
+    fn test_inverse_keeps_labels() {
+        let mut qc = QuantumCircuit::new(1);
+        qc.label_qubit(0, "a");
+        qc.label_clbit(0, "m");
+        qc.t(0);
+
+        let inverse = qc.inverse();
+        assert_eq!(inverse.qubit_label(0), "a");
+        assert_eq!(inverse.clbit_label(0), "m");
+    }
+
+    /// Tests that labelling a qubit outside the circuit panics.
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn test_label_out_of_bounds() {
+        let mut qc = QuantumCircuit::new(2);
+        qc.label_qubit(2, "anc");
+    }
+}
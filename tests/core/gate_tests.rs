@@ -0,0 +1,331 @@
+use intrico::{IntricoError, QuantumGate};
+use intrico::circuit::CircuitError;
+use intrico::core::GateError;
+use intrico::simulator::SimulationError;
+use intrico::utility::{matrix_log, random_unitary};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use rusticle::complex::Complex;
+use rusticle::linalg::Matrix;
+
+/// Test suite for the QuantumGate type.
+/// 
+/// These tests verify the core functionality of quantum gates, including:
+/// - Matrix representations
+/// - Parameterized two-qubit and controlled rotation gates
+/// - Diagonal phase gates and their pointwise application
+/// - Gate properties
+/// - Display formatting
+/// - Gates generated from Hamiltonians
+/// - Hamiltonians extracted from gates
+/// - Validation errors of fallible constructors
+mod gate_tests {
+    use super::*;
+
+    /// Tests the matrix representation of the Pauli-X gate.
+    #[test]
+    fn test_pauli_x_gate() {
+        let x = QuantumGate::X;
+        let expected = Matrix::new(2, 2, vec![
+            Complex::new(0.0, 0.0), Complex::new(1.0, 0.0),
+            Complex::new(1.0, 0.0), Complex::new(0.0, 0.0),
+        ]);
+        assert_eq!(x.matrix(), expected);
+    }
+
+    /// Tests the matrix representation of the Pauli-Y gate.
+    #[test]
+    fn test_pauli_y_gate() {
+        let y = QuantumGate::Y;
+        let expected = Matrix::new(2, 2, vec![
+            Complex::new(0.0, 0.0), Complex::new(0.0, -1.0),
+            Complex::new(0.0, 1.0), Complex::new(0.0, 0.0),
+        ]);
+        assert_eq!(y.matrix(), expected);
+    }
+
+    /// Tests the matrix representation of the Pauli-Z gate.
+    #[test]
+    fn test_pauli_z_gate() {
+        let z = QuantumGate::Z;
+        let expected = Matrix::new(2, 2, vec![
+            Complex::new(1.0, 0.0), Complex::new(0.0, 0.0),
+            Complex::new(0.0, 0.0), Complex::new(-1.0, 0.0),
+        ]);
+        assert_eq!(z.matrix(), expected);
+    }
+
+    /// Tests the matrix representation of the Hadamard gate.
+    #[test]
+    fn test_hadamard_gate() {
+        let h = QuantumGate::H;
+        let factor = Complex::new(1.0/2.0_f64.sqrt(), 0.0);
+        let expected = Matrix::new(2, 2, vec![
+            factor, factor,
+            factor, -factor,
+        ]);
+        assert_eq!(h.matrix(), expected);
+    }
+
+    /// Tests the matrix representation of the S gate.
+    #[test]
+    fn test_s_gate() {
+        let s = QuantumGate::S;
+        let expected = Matrix::new(2, 2, vec![
+            Complex::new(1.0, 0.0), Complex::new(0.0, 0.0),
+            Complex::new(0.0, 0.0), Complex::new(0.0, 1.0),
+        ]);
+        assert_eq!(s.matrix(), expected);
+    }
+
+    /// Tests the matrix representation of the T gate.
+    #[test]
+    fn test_t_gate() {
+        let t = QuantumGate::T;
+        let phase = Complex::new(0.0, std::f64::consts::PI/4.0).exp();
+        let expected = Matrix::new(2, 2, vec![
+            Complex::new(1.0, 0.0), Complex::new(0.0, 0.0),
+            Complex::new(0.0, 0.0), phase,
+        ]);
+        assert_eq!(t.matrix(), expected);
+    }
+
+    /// Tests the Givens rotation and that it preserves the number of excitations.
+    #[test]
+    fn test_givens_gate() {
+        let angle = 0.3_f64;
+        let givens = QuantumGate::Givens(angle);
+        let matrix = givens.matrix();
+        assert_eq!(givens.arity(), 2);
+        assert_eq!(givens.num_controls(), 0);
+        assert_eq!(*matrix.get(0, 0), Complex::new(1.0, 0.0));
+        assert_eq!(*matrix.get(3, 3), Complex::new(1.0, 0.0));
+        assert_eq!(*matrix.get(1, 2), Complex::new(-angle.sin(), 0.0));
+        assert_eq!(*matrix.get(2, 1), Complex::new(angle.sin(), 0.0));
+        assert!(matrix.is_unitary());
+        assert_eq!(givens.inverse(), Some(QuantumGate::Givens(-angle)));
+        assert_eq!(givens.to_string(), "G(0.30)");
+
+        let mut qc = intrico::QuantumCircuit::new(3);
+        qc.x(0);
+        qc.givens(0, 2, angle);
+        let probabilities = qc.execute().probabilities();
+        assert!((probabilities[1] - angle.cos().powi(2)).abs() < 1e-6);
+        assert!((probabilities[4] - angle.sin().powi(2)).abs() < 1e-6);
+    }
+
+    /// Tests that controlled rotations act as the rotation on the target when the control is |1⟩.
+    #[test]
+    fn test_controlled_rotations() {
+        let angle = 0.7;
+        for (controlled, rotation) in [
+            (QuantumGate::CRx(angle), QuantumGate::Rx(angle)),
+            (QuantumGate::CRy(angle), QuantumGate::Ry(angle)),
+            (QuantumGate::CRz(angle), QuantumGate::Rz(angle)),
+        ] {
+            let (matrix, block) = (controlled.matrix(), rotation.matrix());
+            assert_eq!((controlled.arity(), controlled.num_controls()), (2, 1));
+            for r in 0..4 {
+                for c in 0..4 {
+                    let expected = match (r, c) {
+                        (2..=3, 2..=3) => *block.get(r - 2, c - 2),
+                        _ if r == c => Complex::new(1.0, 0.0),
+                        _ => Complex::new(0.0, 0.0),
+                    };
+                    assert_eq!(*matrix.get(r, c), expected);
+                }
+            }
+        }
+        assert_eq!(QuantumGate::CRz(angle).inverse(), Some(QuantumGate::CRz(-angle)));
+        assert_eq!(QuantumGate::CRy(angle).to_string(), "CRy(0.70)");
+
+        // The angle is shown in the target box, the control as a dot
+        let mut qc = intrico::QuantumCircuit::new(2);
+        qc.crz(0, 1, 0.5);
+        assert_eq!(qc.draw(), "q0: ────●─────\n        │     \nq1: ─Rz(0.50)─\n");
+    }
+
+    /// Tests that the pointwise diagonal path agrees with applying the diagonal matrix.
+    #[test]
+    fn test_diagonal_gate() {
+        let phases = vec![0.1, -0.4, 1.3, 2.0, 0.0, 0.7, -2.5, 3.1];
+        let diagonal = QuantumGate::Diagonal(phases.clone());
+        assert_eq!(diagonal.arity(), 3);
+        assert!(diagonal.matrix().is_unitary());
+        assert_eq!(diagonal.inverse(), Some(QuantumGate::Diagonal(phases.iter().map(|p| -p).collect())));
+
+        let prepare = |qc: &mut intrico::QuantumCircuit| {
+            for qubit in 0..4 {
+                qc.h(qubit);
+                qc.ry(qubit, 0.3 * qubit as f64);
+            }
+        };
+        let mut pointwise = intrico::QuantumCircuit::new(4);
+        prepare(&mut pointwise);
+        pointwise.diagonal(&[3, 0, 2], &phases);
+        let mut dense = intrico::QuantumCircuit::new(4);
+        prepare(&mut dense);
+        dense.add_multi_qubit_gate(QuantumGate::Custom(diagonal.matrix(), "D".to_string(), "D".to_string()), &[3, 0, 2]);
+
+        for (a, b) in pointwise.execute().iter().zip(dense.execute().iter()) {
+            assert!((*a - *b).norm() < 1e-9);
+        }
+    }
+
+    /// Tests that a diagonal gate rejects a phase list of the wrong length.
+    #[test]
+    #[should_panic(expected = "A diagonal gate on 2 qubits needs 4 phases, got 3")]
+    fn test_diagonal_gate_phase_count() {
+        intrico::QuantumCircuit::new(2).diagonal(&[0, 1], &[0.0, 1.0, 2.0]);
+    }
+
+    /// Tests that hand-built diagonal gates are checked when added to a circuit.
+    #[test]
+    fn test_hand_built_diagonal_phase_count() {
+        let mut qc = intrico::QuantumCircuit::new(2);
+        assert_eq!(
+            qc.try_add_multi_qubit_gate(QuantumGate::Diagonal(vec![0.0, 1.0, 2.0]), &[0, 1]),
+            Err(IntricoError::Circuit(CircuitError::PhaseCountMismatch { num_qubits: 2, found: 3 }))
+        );
+        assert!(qc.try_add_multi_qubit_gate(QuantumGate::Diagonal(vec![0.0; 4]), &[0, 1]).is_ok());
+
+        let added = std::panic::catch_unwind(|| {
+            intrico::QuantumCircuit::new(1).add_gate(QuantumGate::Diagonal(vec![0.0; 3]), 0);
+        });
+        assert!(added.is_err());
+    }
+
+    /// Tests that evolving under Z/2 reproduces the Rz gate.
+    #[test]
+    fn test_from_hamiltonian() {
+        let half_z = Matrix::new(2, 2, vec![
+            Complex::new(0.5, 0.0), Complex::new(0.0, 0.0),
+            Complex::new(0.0, 0.0), Complex::new(-0.5, 0.0),
+        ]);
+        let gate = QuantumGate::from_hamiltonian(&half_z, 0.8);
+        let expected = QuantumGate::Rz(0.8).matrix();
+
+        assert_eq!(gate.arity(), 1);
+        for r in 0..2 {
+            for c in 0..2 {
+                assert!((*gate.matrix().get(r, c) - *expected.get(r, c)).norm() < 1e-10);
+            }
+        }
+    }
+
+    /// Tests that non-Hermitian generators are rejected.
+    #[test]
+    #[should_panic(expected = "Hamiltonian is not Hermitian at entry (0, 1)")]
+    fn test_from_hamiltonian_non_hermitian() {
+        let raising = Matrix::new(2, 2, vec![
+            Complex::new(0.0, 0.0), Complex::new(1.0, 0.0),
+            Complex::new(0.0, 0.0), Complex::new(0.0, 0.0),
+        ]);
+        QuantumGate::from_hamiltonian(&raising, 1.0);
+    }
+
+    /// Tests that exponentiating the extracted Hamiltonian reproduces the gate.
+    #[test]
+    fn test_hamiltonian_round_trip() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let gates = vec![
+            QuantumGate::X,
+            QuantumGate::H,
+            QuantumGate::Tdg,
+            QuantumGate::Ry(2.5),
+            QuantumGate::CNOT,
+            QuantumGate::CZ,
+            QuantumGate::Toffoli,
+            QuantumGate::Custom(random_unitary(4, &mut rng), "U".to_string(), "U".to_string()),
+        ];
+
+        for gate in gates {
+            let hamiltonian = gate.hamiltonian().unwrap();
+            let dim = hamiltonian.rows();
+            let (expected, actual) = (gate.matrix(), QuantumGate::from_hamiltonian(&hamiltonian, 1.0).matrix());
+            for r in 0..dim {
+                for c in 0..dim {
+                    assert!((*actual.get(r, c) - *expected.get(r, c)).norm() < 1e-8, "{}", gate.name());
+                }
+            }
+        }
+    }
+
+    /// Tests the Hamiltonians of rotations and non-unitary instructions.
+    #[test]
+    fn test_hamiltonian() {
+        // Rz(θ) = e^(-iθZ/2)
+        let hamiltonian = QuantumGate::Rz(0.6).hamiltonian().unwrap();
+        assert!((*hamiltonian.get(0, 0) - Complex::new(0.3, 0.0)).norm() < 1e-10);
+        assert!((*hamiltonian.get(1, 1) - Complex::new(-0.3, 0.0)).norm() < 1e-10);
+        assert!(hamiltonian.get(0, 1).norm() < 1e-10);
+
+        // Z has the eigenvalue -1 on the branch cut, which maps to phase π
+        let hamiltonian = QuantumGate::Z.hamiltonian().unwrap();
+        assert!(hamiltonian.get(0, 0).norm() < 1e-10);
+        assert!((*hamiltonian.get(1, 1) + Complex::new(std::f64::consts::PI, 0.0)).norm() < 1e-10);
+
+        assert!(QuantumGate::Measure.hamiltonian().is_none());
+        assert!(QuantumGate::Snapshot("s".to_string()).hamiltonian().is_none());
+
+        let scaled = Matrix::new(2, 2, vec![
+            Complex::new(2.0, 0.0), Complex::new(0.0, 0.0),
+            Complex::new(0.0, 0.0), Complex::new(1.0, 0.0),
+        ]);
+        assert!(QuantumGate::Custom(scaled, "S2".to_string(), "S2".to_string()).hamiltonian().is_none());
+    }
+
+    /// Tests that the logarithm of a non-unitary matrix is rejected.
+    #[test]
+    #[should_panic(expected = "Matrix logarithm requires a unitary matrix")]
+    fn test_matrix_log_non_unitary() {
+        let scaled = Matrix::new(2, 2, vec![
+            Complex::new(2.0, 0.0), Complex::new(0.0, 0.0),
+            Complex::new(0.0, 0.0), Complex::new(1.0, 0.0),
+        ]);
+        matrix_log(&scaled);
+    }
+
+    /// Tests the errors of validated gate constructors.
+    #[test]
+    fn test_try_custom() {
+        let h = QuantumGate::try_custom(QuantumGate::H.matrix(), "Hadamard", "H").unwrap();
+        assert_eq!(h.arity(), 1);
+
+        let not_square = Matrix::new(2, 1, vec![Complex::new(1.0, 0.0), Complex::new(0.0, 0.0)]);
+        assert_eq!(
+            QuantumGate::try_custom(not_square, "Column", "C").unwrap_err(),
+            IntricoError::Gate(GateError::InvalidDimension { rows: 2, cols: 1 })
+        );
+        assert_eq!(
+            QuantumGate::try_custom(Matrix::identity(3), "Qutrit", "I").unwrap_err(),
+            IntricoError::Gate(GateError::InvalidDimension { rows: 3, cols: 3 })
+        );
+
+        let raising = Matrix::new(2, 2, vec![
+            Complex::new(0.0, 0.0), Complex::new(1.0, 0.0),
+            Complex::new(0.0, 0.0), Complex::new(0.0, 0.0),
+        ]);
+        assert_eq!(
+            QuantumGate::try_custom(raising.clone(), "Raise", "R").unwrap_err(),
+            IntricoError::Gate(GateError::NotUnitary)
+        );
+        assert_eq!(
+            QuantumGate::try_from_hamiltonian(&raising, 1.0).unwrap_err(),
+            IntricoError::Gate(GateError::NotHermitian { row: 0, col: 1 })
+        );
+    }
+
+    /// Tests conversion, display and sources of the crate-wide error.
+    #[test]
+    fn test_intrico_error() {
+        use std::error::Error;
+
+        let error: IntricoError = SimulationError::Cancelled.into();
+        assert_eq!(error.to_string(), "Simulation error: Simulation was cancelled");
+        assert_eq!(error.source().unwrap().to_string(), "Simulation was cancelled");
+
+        let parse = "XQ".parse::<intrico::observables::PauliSum>().map_err(IntricoError::from).unwrap_err();
+        assert!(matches!(parse, IntricoError::Parse(_)));
+    }
+}
@@ -1,3 +1,4 @@
+use intrico::QuantumGate;
 use intrico::core::qubit::Qubit;
 use rusticle::complex::Complex;
 
@@ -8,6 +9,7 @@ use rusticle::complex::Complex;
 /// - Custom state creation and normalization
 /// - Probability calculations
 /// - State vector operations
+/// - Single precision storage
 mod qubit_tests {
     use super::*;
 
@@ -85,7 +87,30 @@ mod qubit_tests {
         let alpha = Complex::new(1.0/2.0_f64.sqrt(), 0.0);
         let beta = Complex::new(1.0/2.0_f64.sqrt(), 0.0);
         let superposition = Qubit::new(alpha, beta);
-        assert!(format!("{}", superposition).contains("|ψ⟩ = "));
-        assert!(format!("{:?}", superposition).contains("|ψ⟩ = "));
+        assert_eq!(format!("{}", superposition), "|ψ⟩ = 0.7071|0⟩ + 0.7071|1⟩");
+        assert_eq!(format!("{:?}", superposition), "|ψ⟩ = 0.7071|0⟩ + 0.7071|1⟩");
+        assert_eq!(format!("{:.2}", superposition), "|ψ⟩ = 0.71|0⟩ + 0.71|1⟩");
+
+        // Test phases
+        let minus_i = Qubit::new(alpha, Complex::new(0.0, -1.0/2.0_f64.sqrt()));
+        assert_eq!(format!("{}", minus_i), "|ψ⟩ = 0.7071|0⟩ - 0.7071i|1⟩");
+        let minus_one = Qubit::new(Complex::new(0.0, 0.0), Complex::new(-1.0, 0.0));
+        assert_eq!(format!("{}", minus_one), "|ψ⟩ = -|1⟩");
+    }
+
+    /// Tests gate application on a single precision qubit.
+    #[test]
+    fn test_single_precision_qubit() {
+        let mut qubit = Qubit::zero().to_precision::<f32>();
+        qubit.apply(QuantumGate::H);
+        qubit.apply(QuantumGate::S);
+        assert!((qubit.probability_zero() - 0.5).abs() < 1e-6);
+        assert_eq!(format!("{}", qubit), "|ψ⟩ = 0.7071|0⟩ + 0.7071i|1⟩");
+
+        qubit.apply(QuantumGate::Sdg);
+        qubit.apply(QuantumGate::H);
+        let back: Qubit = qubit.to_precision();
+        assert!((back.probability_zero() - 1.0).abs() < 1e-6);
+        assert!(Qubit::one().to_precision::<f32>().is_one());
     }
 }
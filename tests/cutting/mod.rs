@@ -0,0 +1 @@
+mod wire_cut_tests;
@@ -0,0 +1,106 @@
+use intrico::QuantumCircuit;
+use intrico::cutting::CutCircuit;
+use intrico::observables::PauliSum;
+use intrico::simulator::{Observable, Simulator};
+
+/// Test suite for wire cutting.
+///
+/// These tests verify the behaviour of:
+/// - Splitting circuits into fragments at cut wires
+/// - Knitting fragment results into exact expectation values
+/// - Evaluating circuits wider than the simulator allows
+/// - Rejecting invalid cuts and non-unitary circuits
+mod wire_cut_tests {
+    use super::*;
+
+    /// A 5-qubit circuit whose middle qubit links two halves
+    fn linked_circuit() -> QuantumCircuit {
+        let mut qc = QuantumCircuit::new(5);
+        qc.h(0);
+        qc.ry(1, 0.7);
+        qc.cnot(0, 1);
+        qc.rx(2, 0.3);
+        qc.cnot(1, 2);      // 4
+        qc.t(2);
+        qc.cnot(2, 3);      // 6
+        qc.ry(4, 1.1);
+        qc.cz(3, 4);
+        qc.rz(3, 0.4);
+        qc.cnot(4, 3);
+        qc
+    }
+
+    fn exact(qc: &QuantumCircuit, observable: &PauliSum) -> f64 {
+        observable.expectation(&qc.execute())
+    }
+
+    /// Tests that cuts split the circuit into narrower fragments.
+    #[test]
+    fn test_fragments() {
+        let mut cut = CutCircuit::new(&linked_circuit());
+        assert_eq!(cut.num_fragments(), 1);
+
+        cut.cut_wire(2, 4);
+        assert_eq!(cut.num_cuts(), 1);
+        assert_eq!(cut.num_fragments(), 2);
+
+        let widths: Vec<usize> = cut.fragment_circuits().iter().map(QuantumCircuit::num_qubits).collect();
+        assert_eq!(widths, vec![3, 3]);
+        let operations: usize = cut.fragment_circuits().iter().map(QuantumCircuit::num_operations).sum();
+        assert_eq!(operations, linked_circuit().num_operations());
+    }
+
+    /// Tests that knitted expectation values match the uncut circuit.
+    #[test]
+    fn test_knitted_expectation() {
+        let qc = linked_circuit();
+        let observable: PauliSum = "ZZIII + 0.5*XIXIZ - 0.3*IYZXI + 0.7*ZIIIX + 2*IIIII".parse().unwrap();
+
+        let mut cut = CutCircuit::new(&qc);
+        cut.cut_wire(2, 4);
+        let value = cut.expectation(&observable, &Simulator::new().with_max_qubits(3));
+        assert!((value - exact(&qc, &observable)).abs() < 1e-6, "{} vs {}", value, exact(&qc, &observable));
+
+        // A second cut on the same wire splits off the single T gate
+        cut.cut_wire(2, 5);
+        assert_eq!(cut.num_fragments(), 3);
+        let value = cut.expectation(&observable, &Simulator::new().with_max_qubits(3));
+        assert!((value - exact(&qc, &observable)).abs() < 1e-6);
+    }
+
+    /// Tests cuts whose two sides end up in the same fragment.
+    #[test]
+    fn test_cut_within_fragment() {
+        let mut qc = QuantumCircuit::new(2);
+        qc.h(0);
+        qc.cnot(0, 1);
+        qc.ry(0, 0.5);
+        qc.cnot(1, 0);
+
+        let mut cut = CutCircuit::new(&qc);
+        cut.cut_wire(0, 1);
+        assert_eq!(cut.num_fragments(), 1);
+        assert_eq!(cut.fragment_circuits()[0].num_qubits(), 3);
+
+        let observable: PauliSum = "ZX + 0.5*YY - XZ".parse().unwrap();
+        let value = cut.expectation(&observable, &Simulator::new());
+        assert!((value - exact(&qc, &observable)).abs() < 1e-6);
+    }
+
+    /// Tests that a wire can only be cut after an operation acting on it.
+    #[test]
+    #[should_panic(expected = "does not act on qubit 0")]
+    fn test_invalid_cut() {
+        let mut cut = CutCircuit::new(&linked_circuit());
+        cut.cut_wire(0, 3);
+    }
+
+    /// Tests that circuits with measurements cannot be cut.
+    #[test]
+    #[should_panic(expected = "unitary")]
+    fn test_measured_circuit() {
+        let mut qc = QuantumCircuit::new(1);
+        qc.measure(0, 0);
+        CutCircuit::new(&qc);
+    }
+}
@@ -0,0 +1,99 @@
+use intrico::QuantumCircuit;
+use intrico::device::{DeviceModel, QubitProperties};
+use std::io::ErrorKind;
+
+/// Test suite for the DeviceModel type.
+/// 
+/// These tests verify the behaviour of device models, including:
+/// - Connectivity queries
+/// - Gate calibration lookup
+/// - Validation of calibration data
+/// - Loading from and saving to JSON calibration files
+mod device_tests {
+    use super::*;
+
+    fn qubit() -> QubitProperties {
+        QubitProperties { t1: 100_000.0, t2: 120_000.0, readout_error: 0.01 }
+    }
+
+    fn line_device() -> DeviceModel {
+        DeviceModel::new("line", vec![qubit(); 3])
+            .with_coupling(0, 1)
+            .with_coupling(2, 1)
+            .with_gate("H", &[], 0.001, 35.0)
+            .with_gate("CX", &[], 0.01, 300.0)
+            .with_gate("CX", &[0, 1], 0.02, 250.0)
+    }
+
+    /// Tests that couplings are undirected and neighbours are reported in order.
+    #[test]
+    fn test_connectivity() {
+        let device = line_device();
+
+        assert!(device.are_connected(0, 1));
+        assert!(device.are_connected(1, 2));
+        assert!(!device.are_connected(0, 2));
+        assert_eq!(device.neighbours(1), vec![0, 2]);
+        assert_eq!(device.neighbours(0), vec![1]);
+    }
+
+    /// Tests that qubit-specific calibrations take precedence over generic ones.
+    #[test]
+    fn test_gate_properties() {
+        let device = line_device();
+
+        assert_eq!(device.gate_properties("CX", &[0, 1]).unwrap().error, 0.02);
+        assert_eq!(device.gate_properties("CX", &[1, 0]).unwrap().error, 0.02);
+        assert_eq!(device.gate_properties("CX", &[1, 2]).unwrap().error, 0.01);
+        assert!(device.gate_properties("T", &[0]).is_none());
+
+        let mut qc = QuantumCircuit::new(3);
+        qc.h(2);
+        qc.cnot(0, 1);
+        let durations: Vec<f64> = qc.operations().iter()
+            .map(|op| device.operation_properties(op).unwrap().duration)
+            .collect();
+        assert_eq!(durations, vec![35.0, 250.0]);
+    }
+
+    /// Tests that inconsistent calibration data is rejected.
+    #[test]
+    fn test_validate() {
+        assert!(line_device().validate().is_ok());
+
+        let bad_t2 = QubitProperties { t1: 10.0, t2: 30.0, readout_error: 0.0 };
+        let err = DeviceModel::new("bad", vec![bad_t2]).validate().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+
+        assert!(line_device().with_coupling(0, 3).validate().is_err());
+        assert!(line_device().with_gate("X", &[5], 0.0, 10.0).validate().is_err());
+        assert!(line_device().with_gate("X", &[], 1.5, 10.0).validate().is_err());
+    }
+
+    /// Tests that a device model survives a round trip through a calibration file.
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_save_and_load() {
+        let path = std::env::temp_dir().join("intrico_test_device.json");
+        let device = line_device();
+
+        device.save(&path).unwrap();
+        assert_eq!(DeviceModel::load(&path).unwrap(), device);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    /// Tests that malformed or inconsistent calibration documents are rejected.
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_from_json_errors() {
+        let malformed = DeviceModel::from_json("{\"name\": \"x\"}").unwrap_err();
+        assert_eq!(malformed.kind(), ErrorKind::InvalidData);
+
+        let inconsistent = DeviceModel::from_json(r#"{
+            "name": "x",
+            "qubits": [{"t1": 1000, "t2": 500}],
+            "connectivity": [[0, 1]]
+        }"#).unwrap_err();
+        assert_eq!(inconsistent.kind(), ErrorKind::InvalidData);
+    }
+}
@@ -0,0 +1 @@
+mod device_tests;
\ No newline at end of file
@@ -0,0 +1,3 @@
+mod repetition_tests;
+mod stabilizer_tests;
+mod surface_tests;
\ No newline at end of file
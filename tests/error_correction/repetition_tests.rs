@@ -0,0 +1,98 @@
+use intrico::QuantumCircuit;
+use intrico::error_correction::{logical_error_rate, RepetitionCode};
+use intrico::simulator::{NoiseModel, Simulator};
+
+/// Test suite for the three-qubit repetition codes.
+/// 
+/// These tests verify the behaviour of:
+/// - Correction of every single-qubit error the codes protect against
+/// - Syndromes identifying the flipped qubit
+/// - Logical error suppression under depolarizing noise
+mod repetition_tests {
+    use super::*;
+
+    /// Runs encode, an injected error, syndrome extraction, correction and decoding
+    fn run_with_error(code: RepetitionCode, logical_one: bool, error: Option<usize>) -> String {
+        let mut qc = QuantumCircuit::new(RepetitionCode::NUM_QUBITS);
+        if logical_one {
+            qc.x(0);
+        }
+        code.encode(&mut qc);
+        if let Some(qubit) = error {
+            match code {
+                RepetitionCode::BitFlip => qc.x(qubit),
+                RepetitionCode::PhaseFlip => qc.z(qubit),
+            }
+        }
+        code.extract_syndrome(&mut qc);
+        code.correct(&mut qc);
+        code.decode(&mut qc);
+
+        let result = Simulator::new().with_circuit(qc).run(20);
+        assert_eq!(result.counts.len(), 1, "{:?}", result.counts);
+        result.counts.keys().next().unwrap().clone()
+    }
+
+    /// Tests that every single error is corrected and flagged by the expected syndrome.
+    #[test]
+    fn test_single_errors() {
+        // Ancilla bits (q4, q3) for no error and errors on qubits 0, 1 and 2
+        let syndromes = [(None, "00"), (Some(0), "01"), (Some(1), "11"), (Some(2), "10")];
+
+        for code in [RepetitionCode::BitFlip, RepetitionCode::PhaseFlip] {
+            for logical_one in [false, true] {
+                for (error, syndrome) in syndromes {
+                    let bits = run_with_error(code, logical_one, error);
+                    let expected = format!("{}00{}", syndrome, u8::from(logical_one));
+                    assert_eq!(bits, expected, "{:?}, logical {}, error {:?}", code, logical_one, error);
+                }
+            }
+        }
+    }
+
+    /// Tests that the bit-flip code does not protect against phase errors.
+    #[test]
+    fn test_unprotected_error_type() {
+        let mut qc = QuantumCircuit::new(RepetitionCode::NUM_QUBITS);
+        qc.h(0);
+        RepetitionCode::BitFlip.encode(&mut qc);
+        qc.z(1);
+        RepetitionCode::BitFlip.extract_syndrome(&mut qc);
+        RepetitionCode::BitFlip.correct(&mut qc);
+        RepetitionCode::BitFlip.decode(&mut qc);
+        qc.h(0);
+
+        // The phase error passes through undetected and flips the |+⟩ logical state
+        let result = Simulator::new().with_circuit(qc).run(20);
+        assert_eq!(result.counts["00001"], 20);
+    }
+
+    /// Tests that both codes suppress logical errors under depolarizing noise.
+    #[test]
+    fn test_logical_error_suppression() {
+        let noise = NoiseModel::depolarizing(0.03, 0.0);
+
+        for code in [RepetitionCode::BitFlip, RepetitionCode::PhaseFlip] {
+            let run = |circuit| Simulator::new()
+                .with_noise(noise.clone())
+                .with_seed(11)
+                .with_circuit(circuit)
+                .run(50_000);
+
+            let protected = logical_error_rate(&run(code.memory_circuit(false, 3)), false);
+            let unprotected = logical_error_rate(&run(code.unprotected_circuit(false, 3)), false);
+            assert!(protected < 0.7 * unprotected, "{:?}: {} vs {}", code, protected, unprotected);
+        }
+    }
+
+    /// Tests that noiseless memory experiments never fail.
+    #[test]
+    fn test_noiseless_memory() {
+        for code in [RepetitionCode::BitFlip, RepetitionCode::PhaseFlip] {
+            for logical_one in [false, true] {
+                let result = Simulator::new().with_circuit(code.memory_circuit(logical_one, 2)).run(50);
+                assert_eq!(logical_error_rate(&result, logical_one), 0.0);
+            }
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use intrico::QuantumCircuit;
+use intrico::error_correction::StabilizerCode;
+use intrico::simulator::Simulator;
+
+/// Test suite for stabilizer codes.
+/// 
+/// These tests verify the behaviour of:
+/// - Code parameters of the Steane and five-qubit codes
+/// - Lookup-table decoding of every single-qubit error
+/// - Generated syndrome-extraction and correction circuits
+/// - Validation of stabilizer generators and logical operators
+mod stabilizer_tests {
+    use super::*;
+
+    /// Returns every single-qubit Pauli error on a block of the given size
+    fn single_errors(num_qubits: usize) -> Vec<String> {
+        let mut errors = Vec::new();
+        for qubit in 0..num_qubits {
+            for pauli in ['X', 'Y', 'Z'] {
+                let mut error = vec!['I'; num_qubits];
+                error[num_qubits - 1 - qubit] = pauli;
+                errors.push(error.into_iter().collect());
+            }
+        }
+        errors
+    }
+
+    /// Tests the sizes of the predefined codes.
+    #[test]
+    fn test_code_parameters() {
+        let steane = StabilizerCode::steane();
+        assert_eq!(steane.num_qubits(), 7);
+        assert_eq!(steane.num_stabilizers(), 6);
+        assert_eq!(steane.logical_x(), "XXXXXXX");
+
+        let five = StabilizerCode::five_qubit();
+        assert_eq!(five.num_qubits(), 5);
+        assert_eq!(five.num_stabilizers(), 4);
+        assert_eq!(five.stabilizers()[0], "XZZXI");
+    }
+
+    /// Tests that the decoder returns every single-qubit error as its own correction.
+    #[test]
+    fn test_lookup_decoder() {
+        for code in [StabilizerCode::steane(), StabilizerCode::five_qubit()] {
+            let trivial = vec![false; code.num_stabilizers()];
+            assert_eq!(code.correction(&trivial), "I".repeat(code.num_qubits()));
+
+            for error in single_errors(code.num_qubits()) {
+                let syndrome = code.syndrome(&error);
+                assert!(syndrome.iter().any(|&bit| bit), "{} is undetected", error);
+                assert_eq!(code.correction(&syndrome), error);
+            }
+        }
+    }
+
+    /// Tests that the generated circuits flag and correct every single-qubit error.
+    #[test]
+    fn test_correction_circuits() {
+        let code = StabilizerCode::five_qubit();
+        let n = code.num_qubits();
+
+        for logical_one in [false, true] {
+            for error in single_errors(n) {
+                let mut qc = QuantumCircuit::new(n + code.num_stabilizers());
+                if logical_one {
+                    qc.x(0);
+                }
+                code.encode(&mut qc);
+                for (qubit, pauli) in error.chars().rev().enumerate() {
+                    match pauli {
+                        'X' => qc.x(qubit),
+                        'Y' => qc.y(qubit),
+                        'Z' => qc.z(qubit),
+                        _ => {},
+                    }
+                }
+                code.extract_syndrome(&mut qc);
+                code.correct(&mut qc);
+                code.decode(&mut qc);
+
+                let syndrome: String = code.syndrome(&error).iter()
+                    .rev()
+                    .map(|&bit| if bit { '1' } else { '0' })
+                    .collect();
+                let expected = format!("{}{}{}", syndrome, "0".repeat(n - 1), logical_one as u8);
+
+                let result = Simulator::new().with_circuit(qc).run(10);
+                assert_eq!(result.counts.get(&expected), Some(&10), "error {}", error);
+            }
+        }
+    }
+
+    /// Tests that the Steane code preserves a logical superposition through a correction.
+    #[test]
+    fn test_steane_superposition() {
+        let code = StabilizerCode::steane();
+        let mut qc = QuantumCircuit::new(code.num_qubits() + code.num_stabilizers());
+        qc.h(0);
+        code.encode(&mut qc);
+        qc.y(4);
+        code.extract_syndrome(&mut qc);
+        code.correct(&mut qc);
+        code.decode(&mut qc);
+        qc.h(0);
+
+        let result = Simulator::new().with_circuit(qc).run(10);
+        assert_eq!(result.marginal_counts(&[0])["0"], 10);
+    }
+
+    /// Tests that non-commuting generators are rejected.
+    #[test]
+    #[should_panic(expected = "do not commute")]
+    fn test_non_commuting_stabilizers() {
+        StabilizerCode::new(&["ZZI", "XII"], "XXX", "ZZZ");
+    }
+
+    /// Tests that logical operators must anticommute.
+    #[test]
+    #[should_panic(expected = "must anticommute")]
+    fn test_commuting_logicals() {
+        StabilizerCode::new(&["ZZI", "IZZ"], "ZII", "ZZZ");
+    }
+}
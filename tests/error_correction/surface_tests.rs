@@ -0,0 +1,74 @@
+use intrico::error_correction::{StabilizerCode, SurfaceCode};
+use intrico::simulator::NoiseModel;
+
+/// Test suite for rotated surface-code patches.
+/// 
+/// These tests verify the behaviour of:
+/// - Patch layout, stabilizers and logical operators
+/// - Matching of data and measurement errors
+/// - Memory experiments under circuit-level noise
+mod surface_tests {
+    use super::*;
+
+    /// Tests that the plaquettes form a valid code with the stated logical operators.
+    #[test]
+    fn test_patch_layout() {
+        let code = SurfaceCode::new(3);
+        assert_eq!(code.num_data_qubits(), 9);
+        assert_eq!(code.num_qubits(), 17);
+
+        let stabilizers = code.stabilizers();
+        let strings: Vec<&str> = stabilizers.iter().map(|s| s.as_str()).collect();
+        let stabilizer_code = StabilizerCode::new(&strings, &code.logical_x(), &code.logical_z());
+        assert_eq!(stabilizer_code.num_stabilizers(), 8);
+        assert_eq!(code.logical_z(), "IIIIIIZZZ");
+    }
+
+    /// Tests that a single X error on any data qubit is matched correctly.
+    #[test]
+    fn test_data_errors() {
+        let code = SurfaceCode::new(5);
+        let rounds = 2;
+        let m = code.num_detectors(0);
+        let logical_z = code.logical_z();
+
+        for qubit in 0..code.num_data_qubits() {
+            // An error before the second round flips its plaquettes from then on
+            let mut detectors = vec![false; code.num_detectors(rounds)];
+            for (s, stabilizer) in code.stabilizers()[..m].iter().enumerate() {
+                detectors[m + s] = stabilizer.chars().rev().nth(qubit) == Some('Z');
+            }
+            let flips_logical = logical_z.chars().rev().nth(qubit) == Some('Z');
+            assert_eq!(code.decode(rounds, &detectors), flips_logical, "qubit {}", qubit);
+        }
+    }
+
+    /// Tests that a single measurement error is matched in time.
+    #[test]
+    fn test_measurement_error() {
+        let code = SurfaceCode::new(3);
+        let m = code.num_detectors(0);
+
+        let mut detectors = vec![false; code.num_detectors(3)];
+        detectors[m + 1] = true;
+        detectors[2 * m + 1] = true;
+        assert!(!code.decode(3, &detectors));
+    }
+
+    /// Tests that memory experiments without noise never fail.
+    #[test]
+    fn test_noiseless_memory() {
+        let code = SurfaceCode::new(3);
+        assert_eq!(code.logical_error_rate(3, &NoiseModel::default(), 100, 0), 0.0);
+    }
+
+    /// Tests that a larger distance suppresses logical errors below threshold.
+    #[test]
+    fn test_distance_suppression() {
+        let small = SurfaceCode::new(3).error_curve(&[0.003], 3000, 5);
+        let large = SurfaceCode::new(5).error_curve(&[0.003], 3000, 5);
+
+        assert!(small[0].1 > 0.0);
+        assert!(large[0].1 < small[0].1, "{:?} vs {:?}", large, small);
+    }
+}
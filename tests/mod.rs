@@ -1 +1,20 @@
-mod qsim_core;
+#![allow(clippy::module_inception)]
+
+mod algorithms;
+mod benchmarking;
+mod circuit;
+mod core;
+mod cutting;
+mod device;
+mod error_correction;
+mod observables;
+mod primitives;
+mod python;
+mod simulator;
+mod tomography;
+mod transpiler;
+mod tui;
+mod utility;
+mod variational;
+mod verify;
+mod visualization;
\ No newline at end of file
@@ -0,0 +1,2 @@
+mod pauli_string_tests;
+mod pauli_sum_tests;
\ No newline at end of file
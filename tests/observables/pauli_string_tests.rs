@@ -0,0 +1,71 @@
+use intrico::observables::{PauliString, PauliSumError};
+
+/// Test suite for Pauli strings.
+/// 
+/// These tests verify the behaviour of:
+/// - Parsing and printing of phases and labels
+/// - Products and their phases
+/// - Commutation checks across word boundaries
+mod pauli_string_tests {
+    use super::*;
+
+    fn pauli(s: &str) -> PauliString {
+        s.parse().unwrap()
+    }
+
+    /// Tests parsing, accessors and printing.
+    #[test]
+    fn test_parse() {
+        let p = pauli("-iXYZI");
+
+        assert_eq!(p.num_qubits(), 4);
+        assert_eq!(p.phase(), 3);
+        assert_eq!(p.get(0), 'I');
+        assert_eq!(p.get(3), 'X');
+        assert_eq!(p.weight(), 3);
+        assert_eq!(p.label(), "XYZI");
+        assert_eq!(p.to_string(), "-iXYZI");
+        assert_eq!("XA".parse::<PauliString>(), Err(PauliSumError::InvalidTerm { term: "XA".to_string() }));
+    }
+
+    /// Tests the single-qubit multiplication table.
+    #[test]
+    fn test_single_qubit_products() {
+        assert_eq!((&pauli("X") * &pauli("Y")).to_string(), "iZ");
+        assert_eq!((&pauli("Y") * &pauli("X")).to_string(), "-iZ");
+        assert_eq!((&pauli("Y") * &pauli("Z")).to_string(), "iX");
+        assert_eq!((&pauli("Z") * &pauli("X")).to_string(), "iY");
+        assert_eq!((&pauli("X") * &pauli("Z")).to_string(), "-iY");
+        assert_eq!((&pauli("Y") * &pauli("Y")).to_string(), "I");
+        assert_eq!((&pauli("iX") * &pauli("-iX")).to_string(), "I");
+    }
+
+    /// Tests products and commutation of multi-qubit strings.
+    #[test]
+    fn test_multi_qubit_products() {
+        // XX and ZZ anticommute on both qubits, so they commute
+        assert!(pauli("XX").commutes_with(&pauli("ZZ")));
+        assert_eq!((&pauli("XX") * &pauli("ZZ")).to_string(), "-YY");
+        assert!(!pauli("XI").commutes_with(&pauli("ZZ")));
+    }
+
+    /// Tests strings longer than a single 64-bit word.
+    #[test]
+    fn test_many_qubits() {
+        let mut x = PauliString::identity(130);
+        let mut z = PauliString::identity(130);
+        x.set(129, 'X');
+        x.set(3, 'X');
+        z.set(129, 'Z');
+
+        assert!(!x.commutes_with(&z));
+        let product = &x * &z;
+        assert_eq!(product.phase(), 3);
+        assert_eq!(product.get(129), 'Y');
+        assert_eq!(product.get(3), 'X');
+        assert_eq!(product.weight(), 2);
+
+        z.set(3, 'Z');
+        assert!(x.commutes_with(&z));
+    }
+}
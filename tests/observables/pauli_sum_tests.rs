@@ -0,0 +1,244 @@
+use rusticle::complex::Complex;
+use rusticle::linalg::Matrix;
+use intrico::QuantumCircuit;
+use intrico::algorithms::trotter_evolution;
+use intrico::observables::{PauliSum, PauliSumError};
+use intrico::simulator::{Observable, Simulator};
+use intrico::utility::state_fidelity;
+
+/// Test suite for Pauli sums.
+/// 
+/// These tests verify the behaviour of:
+/// - Parsing of sums from strings and the reported errors
+/// - Addition, subtraction and scalar multiplication
+/// - Merging of equal terms
+/// - Conversion to dense matrices
+/// - Products, commutators and anticommutators
+/// - Qubit-wise commuting groups and sampled estimation
+/// - Evolution gates
+/// - Expectation values through simulation results
+mod pauli_sum_tests {
+    use super::*;
+
+    /// Tests parsing of coefficients, signs and implicit weights.
+    #[test]
+    fn test_parse() {
+        let sum: PauliSum = "0.5*ZZ + 0.3*XI - YY + 2 IZ - 1e-3*XX".parse().unwrap();
+
+        assert_eq!(sum.num_qubits(), 2);
+        assert_eq!(sum.terms(), &[
+            (0.5, "ZZ".to_string()),
+            (0.3, "XI".to_string()),
+            (-1.0, "YY".to_string()),
+            (2.0, "IZ".to_string()),
+            (-1e-3, "XX".to_string()),
+        ]);
+        assert_eq!("-Z".parse::<PauliSum>().unwrap(), PauliSum::new(1).with_term(-1.0, "Z"));
+    }
+
+    /// Tests that printing and parsing round-trip.
+    #[test]
+    fn test_display_round_trip() {
+        let sum = PauliSum::new(3).with_term(-0.25, "XYZ").with_term(1.5, "IIZ");
+
+        assert_eq!(sum.to_string(), "-0.25*XYZ + 1.5*IIZ");
+        assert_eq!(sum.to_string().parse::<PauliSum>().unwrap(), sum);
+    }
+
+    /// Tests the errors of malformed strings.
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!("".parse::<PauliSum>(), Err(PauliSumError::Empty));
+        assert_eq!("0.5*ZA".parse::<PauliSum>(), Err(PauliSumError::InvalidTerm { term: "0.5*ZA".to_string() }));
+        assert_eq!("abc*ZZ".parse::<PauliSum>(), Err(PauliSumError::InvalidTerm { term: "abc*ZZ".to_string() }));
+        assert_eq!("ZZ + 0.5".parse::<PauliSum>(), Err(PauliSumError::InvalidTerm { term: "0.5".to_string() }));
+        assert_eq!("ZZ + X".parse::<PauliSum>(), Err(PauliSumError::QubitMismatch { expected: 2, found: 1 }));
+        assert!(matches!("ZZ +".parse::<PauliSum>(), Err(PauliSumError::InvalidTerm { .. })));
+    }
+
+    /// Tests addition, subtraction, negation and scaling.
+    #[test]
+    fn test_arithmetic() {
+        let a: PauliSum = "ZZ + XI".parse().unwrap();
+        let b: PauliSum = "0.5*ZZ".parse().unwrap();
+
+        let sum = (a.clone() + b.clone()).simplify();
+        assert_eq!(sum, "1.5*ZZ + XI".parse().unwrap());
+
+        let difference = (a.clone() - 2.0 * b).simplify();
+        assert_eq!(difference, PauliSum::new(2).with_term(1.0, "XI"));
+
+        assert_eq!(-a.clone() * 2.0, "-2*ZZ - 2*XI".parse().unwrap());
+    }
+
+    /// Tests that adding sums on different qubits panics.
+    #[test]
+    #[should_panic(expected = "Cannot add Pauli sums on 2 and 1 qubits")]
+    fn test_add_mismatch() {
+        let _ = PauliSum::new(2).with_term(1.0, "ZZ") + PauliSum::new(1).with_term(1.0, "Z");
+    }
+
+    /// Tests the dense matrix of a sum with Y operators.
+    #[test]
+    fn test_to_matrix() {
+        let matrix = "0.5*ZZ + 0.3*XY".parse::<PauliSum>().unwrap().to_matrix();
+
+        // X₁Y₀ maps |00⟩ to i|11⟩ and |01⟩ to -i|10⟩
+        assert_eq!(*matrix.get(0, 0), Complex::new(0.5, 0.0));
+        assert_eq!(*matrix.get(1, 1), Complex::new(-0.5, 0.0));
+        assert_eq!(*matrix.get(3, 0), Complex::new(0.0, 0.3));
+        assert_eq!(*matrix.get(2, 1), Complex::new(0.0, -0.3));
+        assert_eq!(*matrix.get(0, 3), Complex::new(0.0, -0.3));
+    }
+
+    /// Tests that matrix and string expectations agree on a random-looking state.
+    #[test]
+    fn test_matrix_expectation() {
+        let mut qc = QuantumCircuit::new(3);
+        qc.h(0);
+        qc.ry(1, 0.7);
+        qc.cnot(0, 2);
+        qc.s(2);
+        qc.rx(1, 1.1);
+
+        let sum: PauliSum = "0.4*XYZ - 1.2*ZIZ + 0.7*YXI + IIX".parse().unwrap();
+        let result = Simulator::new().with_circuit(qc).run(0);
+
+        let direct = result.expectation(&sum);
+        let dense = result.expectation(&sum.to_matrix());
+        assert!((direct - dense).abs() < 1e-9);
+        assert!((direct - sum.expectation(&result.final_state)).abs() < 1e-12);
+    }
+
+    /// Returns the largest entry-wise deviation between two matrices
+    fn distance(a: &Matrix<Complex>, b: &Matrix<Complex>) -> f64 {
+        let dim = a.rows();
+        (0..dim * dim).map(|k| (*a.get(k / dim, k % dim) - *b.get(k / dim, k % dim)).norm()).fold(0.0, f64::max)
+    }
+
+    /// Returns `a + scalar·b` for dense matrices
+    fn combine(a: &Matrix<Complex>, scalar: Complex, b: &Matrix<Complex>) -> Matrix<Complex> {
+        let dim = a.rows();
+        let mut sum = Matrix::zeros(dim, dim);
+        for k in 0..dim * dim {
+            sum.set(k / dim, k % dim, *a.get(k / dim, k % dim) + scalar * *b.get(k / dim, k % dim));
+        }
+        sum
+    }
+
+    /// Tests that products, commutators and anticommutators match their dense matrices.
+    #[test]
+    fn test_algebra_matches_matrices() {
+        let a: PauliSum = "0.5*XYZ - 1.2*ZIZ + 0.7*YXI + 0.3*IIX".parse().unwrap();
+        let b: PauliSum = "0.8*ZZI + 0.4*XXY - 0.6*IYZ + 1.1*ZII".parse().unwrap();
+        let (ma, mb) = (a.to_matrix(), b.to_matrix());
+        let (ab, ba) = (&ma * &mb, &mb * &ma);
+        let i = Complex::new(0.0, 1.0);
+
+        let (real, imaginary) = a.multiply(&b);
+        assert!(distance(&combine(&real.to_matrix(), i, &imaginary.to_matrix()), &ab) < 1e-9);
+
+        let commutator = combine(&ab, Complex::new(-1.0, 0.0), &ba);
+        let zero = Matrix::zeros(8, 8);
+        assert!(distance(&combine(&zero, i, &a.commutator(&b).to_matrix()), &commutator) < 1e-9);
+
+        let anticommutator = combine(&ab, Complex::new(1.0, 0.0), &ba);
+        assert!(distance(&a.anticommutator(&b).to_matrix(), &anticommutator) < 1e-9);
+    }
+
+    /// Tests commutation of sums.
+    #[test]
+    fn test_commutes_with() {
+        let zz: PauliSum = "ZZ + 0.5*ZI".parse().unwrap();
+        let xx: PauliSum = "XX".parse().unwrap();
+        let yy: PauliSum = "YY - IZ".parse().unwrap();
+
+        assert!(!zz.commutes_with(&xx));
+        assert!(xx.commutes_with(&"YY + 2*ZZ".parse().unwrap()));
+        assert!(!xx.commutes_with(&yy));
+        assert_eq!(zz.commutator(&zz).terms().len(), 0);
+    }
+
+    /// Tests that every group commutes qubit-wise and that the groups cover all terms.
+    #[test]
+    fn test_group_commuting() {
+        let hamiltonian: PauliSum = "ZZI + 0.5*IZZ + XXI - YIY + 0.3*IIZ + IXX + 0.2*ZIZ + YYI".parse().unwrap();
+        let groups = hamiltonian.group_commuting();
+
+        assert_eq!(groups.iter().map(|g| g.terms().len()).sum::<usize>(), 8);
+        assert!(groups.len() < 8);
+        for group in &groups {
+            assert!(group.measurement_basis().is_some());
+        }
+
+        // ZZI, IZZ, IIZ and ZIZ share the Z basis and keep their relative order
+        assert_eq!(groups[0], "ZZI + 0.5*IZZ + 0.3*IIZ + 0.2*ZIZ".parse().unwrap());
+        assert_eq!(groups[0].measurement_basis(), Some("ZZZ".to_string()));
+    }
+
+    /// Tests the measurement basis of compatible and incompatible sums.
+    #[test]
+    fn test_measurement_basis() {
+        assert_eq!("XIZ + IIZ".parse::<PauliSum>().unwrap().measurement_basis(), Some("XIZ".to_string()));
+        assert_eq!("XI + ZI".parse::<PauliSum>().unwrap().measurement_basis(), None);
+        assert_eq!(PauliSum::new(2).measurement_basis(), Some("II".to_string()));
+    }
+
+    /// Tests that sampled estimates approach the exact expectation value.
+    #[test]
+    fn test_estimate() {
+        let mut qc = QuantumCircuit::new(3);
+        qc.h(0);
+        qc.ry(1, 0.7);
+        qc.cnot(0, 2);
+        qc.s(2);
+        qc.rx(1, 1.1);
+
+        let sum: PauliSum = "0.4*XYZ - 1.2*ZIZ + 0.7*YXI + IIX + 0.5*III".parse().unwrap();
+        let simulator = Simulator::new().with_seed(17);
+        let exact = sum.expectation(&qc.execute());
+
+        assert!((sum.estimate(&qc, &simulator, 20000) - exact).abs() < 0.05);
+    }
+
+    /// Tests that estimation requires matching qubit counts.
+    #[test]
+    #[should_panic(expected = "Circuit acts on 1 qubits but the Pauli sum on 2")]
+    fn test_estimate_mismatch() {
+        PauliSum::new(2).with_term(1.0, "ZZ").estimate(&QuantumCircuit::new(1), &Simulator::new(), 10);
+    }
+
+    /// Tests that the evolution gate of commuting terms matches exact Trotterization.
+    #[test]
+    fn test_evolution_gate() {
+        let hamiltonian: PauliSum = "0.7*ZZI - 0.4*IZZ + 1.3*XXX".parse().unwrap();
+        let gate = hamiltonian.evolution_gate(0.9);
+        assert_eq!(gate.arity(), 3);
+
+        let mut prepare = QuantumCircuit::new(3);
+        prepare.h(0);
+        prepare.ry(1, 0.4);
+        prepare.cnot(0, 2);
+
+        let mut evolved = prepare.clone();
+        evolved.add_multi_qubit_gate(gate, &[2, 1, 0]);
+        let mut trotterized = prepare.clone();
+        trotterized.compose(&trotter_evolution(&hamiltonian, 0.9, 1, 1), &[0, 1, 2]);
+
+        assert!((state_fidelity(&evolved.execute(), &trotterized.execute()) - 1.0).abs() < 1e-6);
+    }
+
+    /// Tests that the evolution conserves the energy.
+    #[test]
+    fn test_evolution_conserves_energy() {
+        let hamiltonian: PauliSum = "ZZ + 0.5*XI + 0.5*IX".parse().unwrap();
+
+        let mut qc = QuantumCircuit::new(2);
+        qc.h(1);
+        qc.ry(0, 0.3);
+        let before = hamiltonian.expectation(&qc.execute());
+        qc.add_multi_qubit_gate(hamiltonian.evolution_gate(2.5), &[1, 0]);
+
+        assert!((hamiltonian.expectation(&qc.execute()) - before).abs() < 1e-5);
+    }
+}
@@ -0,0 +1,128 @@
+use intrico::circuit::ParameterizedCircuit;
+use intrico::observables::PauliSum;
+use intrico::primitives::Estimator;
+use intrico::simulator::{NoiseModel, Simulator};
+
+/// Test suite for the Estimator primitive.
+///
+/// These tests verify the behaviour of:
+/// - Exact expectation values without shots
+/// - Sampled estimates and their standard errors
+/// - Batches mixing circuits, observables and parameters
+/// - Noisy simulators
+/// - Light-cone reduction of wide circuits
+mod estimator_tests {
+    use super::*;
+
+    fn bell_ansatz() -> ParameterizedCircuit {
+        let mut ansatz = ParameterizedCircuit::new(2);
+        ansatz.ry(0, 0);
+        ansatz.circuit_mut().cnot(0, 1);
+        ansatz
+    }
+
+    /// Tests that exact estimates match the analytic expectation value.
+    #[test]
+    fn test_exact_estimate() {
+        let observable: PauliSum = "ZZ + 0.5*XX - 0.25*IZ + 2*II".parse().unwrap();
+        let theta = 0.8_f64;
+
+        let result = Estimator::new().run(&bell_ansatz(), &observable, &[theta]);
+        let expected = 1.0 + 0.5 * theta.sin() - 0.25 * theta.cos() + 2.0;
+        assert!((result.value - expected).abs() < 1e-6);
+        assert_eq!(result.std_error, 0.0);
+    }
+
+    /// Tests that sampled estimates stay within a few standard errors and shrink with shots.
+    #[test]
+    fn test_sampled_estimate() {
+        let observable: PauliSum = "ZZ + 0.5*XX - 0.25*IZ".parse().unwrap();
+        let exact = Estimator::new().run(&bell_ansatz(), &observable, &[0.8]);
+
+        let estimator = |shots| Estimator::new()
+            .with_simulator(Simulator::new().with_seed(21))
+            .with_shots(shots);
+        let coarse = estimator(500).run(&bell_ansatz(), &observable, &[0.8]);
+        let fine = estimator(8000).run(&bell_ansatz(), &observable, &[0.8]);
+
+        assert!((fine.value - exact.value).abs() < 4.0 * fine.std_error);
+        assert!(fine.std_error > 0.0);
+        assert!(fine.std_error < coarse.std_error / 2.0);
+    }
+
+    /// Tests that an eigenstate has no sampling error.
+    #[test]
+    fn test_eigenstate_estimate() {
+        let observable: PauliSum = "ZZ + XX".parse().unwrap();
+        let result = Estimator::new()
+            .with_shots(100)
+            .run(&bell_ansatz(), &observable, &[std::f64::consts::FRAC_PI_2]);
+        assert!((result.value - 2.0).abs() < 1e-6);
+        assert!(result.std_error < 1e-6);
+    }
+
+    /// Tests that batches return one result per input, in order.
+    #[test]
+    fn test_batch() {
+        let ansatz = bell_ansatz();
+        let zz: PauliSum = "ZZ".parse().unwrap();
+        let xi: PauliSum = "XI".parse().unwrap();
+        let thetas = [0.0, 1.0, 2.0];
+
+        let inputs: Vec<(&ParameterizedCircuit, &PauliSum, &[f64])> = thetas.iter()
+            .flat_map(|theta| [(&ansatz, &zz, std::slice::from_ref(theta)), (&ansatz, &xi, std::slice::from_ref(theta))])
+            .collect();
+        let results = Estimator::new().run_batch(&inputs);
+
+        assert_eq!(results.len(), 6);
+        for (pair, theta) in results.chunks(2).zip(thetas) {
+            assert!((pair[0].value - 1.0).abs() < 1e-6);
+            assert!(pair[1].value.abs() < 1e-6, "theta {}", theta);
+        }
+    }
+
+    /// Tests that the noise model of the simulator affects sampled estimates.
+    #[test]
+    fn test_noisy_estimate() {
+        let observable: PauliSum = "ZZ".parse().unwrap();
+        let result = Estimator::new()
+            .with_simulator(Simulator::new().with_seed(2).with_noise(NoiseModel::depolarizing(0.1, 0.2)))
+            .with_shots(4000)
+            .run(&bell_ansatz(), &observable, &[0.0]);
+        assert!(result.value < 0.95);
+    }
+
+    /// Tests that the observable must act on the qubits of the circuit.
+    #[test]
+    #[should_panic(expected = "observable on 1")]
+    fn test_qubit_mismatch() {
+        let observable: PauliSum = "Z".parse().unwrap();
+        Estimator::new().run(&bell_ansatz(), &observable, &[0.0]);
+    }
+
+    /// Tests that local observables of wide circuits are estimated on their light cone.
+    #[test]
+    fn test_light_cone_reduction() {
+        let mut ansatz = ParameterizedCircuit::new(40);
+        for qubit in 0..40 {
+            ansatz.ry(qubit, 0);
+        }
+        for start in [0, 1] {
+            for qubit in (start..39).step_by(2) {
+                ansatz.circuit_mut().cz(qubit, qubit + 1);
+            }
+        }
+        let mut pauli = ['I'; 40];
+        pauli[40 - 1 - 20] = 'X';
+        let observable = PauliSum::new(40).with_term(1.0, &pauli.iter().collect::<String>());
+
+        // X on qubit 20 picks up a Z from each neighbour: ⟨X⟩ = sin(θ)·cos²(θ)
+        let theta = 0.7_f64;
+        let estimator = Estimator::new().with_simulator(Simulator::new().with_max_qubits(4));
+        let exact = estimator.run(&ansatz, &observable, &[theta]);
+        assert!((exact.value - theta.sin() * theta.cos().powi(2)).abs() < 1e-6);
+
+        let sampled = estimator.with_shots(2000).run(&ansatz, &observable, &[theta]);
+        assert!((sampled.value - exact.value).abs() < 5.0 * sampled.std_error);
+    }
+}
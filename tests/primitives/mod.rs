@@ -0,0 +1,2 @@
+mod estimator_tests;
+mod sampler_tests;
@@ -0,0 +1,71 @@
+use intrico::circuit::ParameterizedCircuit;
+use intrico::primitives::Sampler;
+use intrico::simulator::{NoiseModel, Simulator};
+
+/// Test suite for the Sampler primitive.
+///
+/// These tests verify the behaviour of:
+/// - Exact distributions without shots
+/// - Sampled distributions summing to one
+/// - Reproducible seeded batches
+/// - Noisy simulators
+mod sampler_tests {
+    use super::*;
+
+    fn ghz_ansatz() -> ParameterizedCircuit {
+        let mut ansatz = ParameterizedCircuit::new(3);
+        ansatz.ry(0, 0);
+        ansatz.circuit_mut().cnot(0, 1);
+        ansatz.circuit_mut().cnot(1, 2);
+        ansatz
+    }
+
+    /// Tests that exact distributions hold the probabilities of the final state.
+    #[test]
+    fn test_exact_distribution() {
+        let theta = 1.2_f64;
+        let result = Sampler::new().run(&ghz_ansatz(), &[theta]);
+
+        assert_eq!(result.shots, 0);
+        assert_eq!(result.quasi_dist.len(), 2);
+        assert!((result.probability("000") - (theta / 2.0).cos().powi(2)).abs() < 1e-6);
+        assert!((result.probability("111") - (theta / 2.0).sin().powi(2)).abs() < 1e-6);
+        assert_eq!(result.probability("010"), 0.0);
+    }
+
+    /// Tests that sampled distributions are relative frequencies of the shots.
+    #[test]
+    fn test_sampled_distribution() {
+        let result = Sampler::new().with_shots(2000).with_seed(8).run(&ghz_ansatz(), &[1.2]);
+
+        assert_eq!(result.shots, 2000);
+        assert!((result.quasi_dist.values().sum::<f64>() - 1.0).abs() < 1e-9);
+        assert!(result.quasi_dist.values().all(|&p| (p * 2000.0).fract() < 1e-9));
+        assert!((result.probability("000") - 0.6_f64.cos().powi(2)).abs() < 0.05);
+    }
+
+    /// Tests that every input of a seeded batch is reproducible on its own.
+    #[test]
+    fn test_seeded_batch() {
+        let ansatz = ghz_ansatz();
+        let sampler = Sampler::new().with_shots(500).with_seed(30);
+
+        let batch = sampler.run_batch(&[(&ansatz, &[0.4]), (&ansatz, &[2.0])]);
+        let again = sampler.run_batch(&[(&ansatz, &[0.4]), (&ansatz, &[2.0])]);
+        assert_eq!(batch, again);
+        assert_eq!(batch[0], sampler.run(&ansatz, &[0.4]));
+    }
+
+    /// Tests that the noise model of the simulator shows in the distribution.
+    #[test]
+    fn test_noisy_distribution() {
+        let simulator = Simulator::new().with_noise(NoiseModel::depolarizing(0.0, 0.0).with_readout_error(0.1));
+        let result = Sampler::new()
+            .with_simulator(simulator)
+            .with_shots(2000)
+            .with_seed(1)
+            .run(&ghz_ansatz(), &[0.0]);
+        assert!(result.quasi_dist.len() > 2);
+        assert!(result.probability("000") < 0.9);
+    }
+}
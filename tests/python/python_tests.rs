@@ -0,0 +1,87 @@
+use intrico::python::{PyQuantumCircuit, PySimulationResult, PySimulator};
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+/// Test suite for the Python bindings.
+///
+/// These tests verify the behaviour of:
+/// - Building circuits and reading their statevector from Python
+/// - Running seeded simulations and reading their results
+/// - Errors raised for invalid arguments
+mod python_tests {
+    use super::*;
+
+    /// Runs a Python snippet with the wrapped classes in scope.
+    fn run(code: &std::ffi::CStr) {
+        Python::initialize();
+        Python::attach(|py| {
+            let globals = PyDict::new(py);
+            globals.set_item("QuantumCircuit", py.get_type::<PyQuantumCircuit>()).unwrap();
+            globals.set_item("Simulator", py.get_type::<PySimulator>()).unwrap();
+            globals.set_item("SimulationResult", py.get_type::<PySimulationResult>()).unwrap();
+            if let Err(error) = py.run(code, Some(&globals), None) {
+                panic!("{}", error);
+            }
+        });
+    }
+
+    /// Tests that a Bell circuit built from Python has the expected statevector.
+    #[test]
+    fn test_circuit() {
+        run(cr#"
+qc = QuantumCircuit(2)
+qc.h(0)
+qc.cx(0, 1)
+assert qc.num_qubits == 2
+assert qc.num_operations == 2
+state = qc.statevector()
+assert abs(state[0] - 2 ** -0.5) < 1e-6
+assert abs(state[3] - 2 ** -0.5) < 1e-6
+assert abs(state[1]) < 1e-6 and abs(state[2]) < 1e-6
+assert "q0" in qc.draw()
+"#);
+    }
+
+    /// Tests that a seeded simulation returns correlated counts and probabilities.
+    #[test]
+    fn test_simulator() {
+        run(cr#"
+qc = QuantumCircuit(2)
+qc.h(0)
+qc.cx(0, 1)
+qc.measure(0, 0)
+qc.measure(1, 1)
+result = Simulator(seed=7).run(qc, shots=200)
+assert isinstance(result, SimulationResult)
+assert result.shots == 200
+assert set(result.counts) <= {"00", "11"}
+assert sum(result.counts.values()) == 200
+assert abs(result.pauli_expectation("ZZ") - 1.0) < 1e-6
+assert result.most_frequent()[0] in ("00", "11")
+
+probabilities = Simulator().probabilities(qc)
+assert abs(probabilities[0] - 0.5) < 1e-6 and abs(probabilities[3] - 0.5) < 1e-6
+"#);
+    }
+
+    /// Tests that invalid arguments raise ValueError.
+    #[test]
+    fn test_errors() {
+        run(cr#"
+qc = QuantumCircuit(1)
+try:
+    qc.x(3)
+    raise AssertionError("out-of-range qubit accepted")
+except ValueError:
+    pass
+
+qc.measure(0, 0)
+result = Simulator(seed=1).run(qc, shots=10)
+try:
+    result.pauli_expectation("XQ")
+    raise AssertionError("invalid Pauli string accepted")
+except ValueError:
+    pass
+"#);
+    }
+}
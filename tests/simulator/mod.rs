@@ -0,0 +1,6 @@
+mod pauli_frame_tests;
+mod result_tests;
+mod simulator_tests;
+mod state_io_tests;
+#[cfg(feature = "plotters")]
+mod plot_tests;
\ No newline at end of file
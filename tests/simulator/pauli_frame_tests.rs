@@ -0,0 +1,129 @@
+use std::collections::HashMap;
+use intrico::QuantumCircuit;
+use intrico::simulator::{NoiseModel, PauliFrameSimulator, Simulator};
+
+/// Test suite for the Pauli-frame simulator.
+///
+/// These tests verify the behaviour of:
+/// - Agreement with the statevector simulator on noiseless and noisy Clifford circuits
+/// - Deterministic and correlated measurement outcomes
+/// - Feed-forward through conditioned Pauli gates and classical operations
+/// - Readout errors
+/// - Rejection of non-Clifford operations
+mod pauli_frame_tests {
+    use super::*;
+
+    /// Builds a Clifford circuit using every supported gate and measurement basis.
+    fn clifford_circuit() -> QuantumCircuit {
+        let mut qc = QuantumCircuit::new(4);
+        qc.h(0);
+        qc.s(0);
+        qc.cnot(0, 1);
+        qc.h(2);
+        qc.cz(2, 1);
+        qc.sdg(1);
+        qc.y(3);
+        qc.cnot(1, 3);
+        qc.h(1);
+        qc.measure_x(2, 0);
+        qc.measure_y(0, 1);
+        qc.z(2);
+        qc.cnot(2, 0);
+        qc
+    }
+
+    /// Returns the largest difference between the frequencies of two sets of counts.
+    fn distance(a: &HashMap<String, usize>, b: &HashMap<String, usize>, shots: usize) -> f64 {
+        a.keys()
+            .chain(b.keys())
+            .map(|key| {
+                let (x, y) = (a.get(key).copied().unwrap_or(0), b.get(key).copied().unwrap_or(0));
+                (x as f64 - y as f64).abs() / shots as f64
+            })
+            .fold(0.0, f64::max)
+    }
+
+    /// Tests that noiseless samples follow the statevector distribution.
+    #[test]
+    fn test_matches_statevector() {
+        let shots = 20000;
+        let frames = PauliFrameSimulator::new().with_seed(1).run(&clifford_circuit(), shots);
+        let exact = Simulator::new().with_circuit(clifford_circuit()).with_seed(1).run(shots);
+        assert!(distance(&frames, &exact.counts, shots) < 0.02);
+    }
+
+    /// Tests that noisy samples follow the statevector distribution.
+    #[test]
+    fn test_noisy_matches_statevector() {
+        let shots = 20000;
+        let noise = NoiseModel::depolarizing(0.05, 0.1);
+        let frames = PauliFrameSimulator::new()
+            .with_noise(noise.clone())
+            .with_seed(2)
+            .run(&clifford_circuit(), shots);
+        let exact = Simulator::new().with_circuit(clifford_circuit()).with_noise(noise).with_seed(2).run(shots);
+        assert!(distance(&frames, &exact.counts, shots) < 0.02);
+    }
+
+    /// Tests deterministic outcomes and the correlations of a GHZ state.
+    #[test]
+    fn test_deterministic_and_correlated() {
+        let mut qc = QuantumCircuit::new(3);
+        qc.x(1);
+        qc.measure(1, 0);
+        assert_eq!(PauliFrameSimulator::new().run(&qc, 100)["010"], 100);
+        assert!(PauliFrameSimulator::new().sample(&qc, 100).iter().all(|bits| bits[0]));
+
+        let mut ghz = QuantumCircuit::new(60);
+        ghz.h(0);
+        for qubit in 0..59 {
+            ghz.cnot(qubit, qubit + 1);
+        }
+        let counts = PauliFrameSimulator::new().with_seed(5).run(&ghz, 2000);
+        assert_eq!(counts.len(), 2);
+        assert!(counts["0".repeat(60).as_str()] > 900);
+        assert!(counts["1".repeat(60).as_str()] > 900);
+    }
+
+    /// Tests that conditioned Pauli gates and classical operations follow the measured bits.
+    #[test]
+    fn test_feed_forward() {
+        let mut qc = QuantumCircuit::new(3);
+        qc.h(0);
+        qc.h(1);
+        qc.measure(0, 0);
+        qc.measure(1, 1);
+        qc.c_xor(0, 1, 2);
+        qc.x(2);
+        qc.c_if(2, true);
+
+        let records = PauliFrameSimulator::new().with_seed(6).sample(&qc, 1000);
+        assert!(records.iter().any(|bits| bits[0]) && records.iter().any(|bits| !bits[0]));
+        let counts = PauliFrameSimulator::new().with_seed(6).run(&qc, 1000);
+        for (bitstring, _) in counts {
+            let bits: Vec<bool> = bitstring.chars().rev().map(|c| c == '1').collect();
+            assert_eq!(bits[2], bits[0] ^ bits[1]);
+        }
+    }
+
+    /// Tests that readout errors flip measured bits at the given rate.
+    #[test]
+    fn test_readout_error() {
+        let mut qc = QuantumCircuit::new(1);
+        qc.measure(0, 0);
+        let noise = NoiseModel::depolarizing(0.0, 0.0).with_readout_error(0.2);
+
+        let records = PauliFrameSimulator::new().with_noise(noise).with_seed(7).sample(&qc, 10000);
+        let rate = records.iter().filter(|bits| bits[0]).count() as f64 / 10000.0;
+        assert!((rate - 0.2).abs() < 0.02);
+    }
+
+    /// Tests that non-Clifford gates are rejected.
+    #[test]
+    #[should_panic(expected = "only supports Clifford circuits")]
+    fn test_non_clifford() {
+        let mut qc = QuantumCircuit::new(1);
+        qc.t(0);
+        PauliFrameSimulator::new().run(&qc, 1);
+    }
+}
@@ -0,0 +1,45 @@
+use intrico::QuantumCircuit;
+use intrico::simulator::{Simulator, SimulationResult};
+
+/// Test suite for the histogram plots of simulation results.
+///
+/// These tests verify the behaviour of:
+/// - PNG export of the counts
+/// - Chart widths that grow with the number of outcomes
+/// - Results without any shots
+mod plot_tests {
+    use super::*;
+
+    /// Plots a result to a temporary file and returns the image width read from the PNG header.
+    fn plot_width(result: &SimulationResult, name: &str) -> u32 {
+        let path = std::env::temp_dir().join(format!("intrico-plot-{}-{}.png", name, std::process::id()));
+        result.plot_histogram(&path).unwrap();
+        let bytes = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(&bytes[..8], b"\x89PNG\r\n\x1a\n");
+        u32::from_be_bytes([bytes[16], bytes[17], bytes[18], bytes[19]])
+    }
+
+    /// Tests that a histogram is written and widens for many outcomes.
+    #[test]
+    fn test_plot_histogram() {
+        let mut bell = QuantumCircuit::new(2);
+        bell.h(0);
+        bell.cnot(0, 1);
+        let narrow = plot_width(&Simulator::new().with_circuit(bell).with_seed(1).run(200), "bell");
+
+        let mut uniform = QuantumCircuit::new(5);
+        for qubit in 0..5 {
+            uniform.h(qubit);
+        }
+        let wide = plot_width(&Simulator::new().with_circuit(uniform).with_seed(1).run(2000), "uniform");
+        assert!(wide > narrow);
+    }
+
+    /// Tests that a result without shots still produces an image.
+    #[test]
+    fn test_plot_histogram_no_shots() {
+        let result = Simulator::new().with_circuit(QuantumCircuit::new(1)).run(0);
+        plot_width(&result, "empty");
+    }
+}
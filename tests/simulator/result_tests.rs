@@ -0,0 +1,180 @@
+use std::collections::HashMap;
+use intrico::simulator::{ExecutionMetrics, SimulationResult};
+use rusticle::complex::Complex;
+
+/// Test suite for the SimulationResult type.
+/// 
+/// These tests verify the post-processing helpers on simulation results, including:
+/// - Marginal counts over subsets of qubits
+/// - Pattern based probability queries
+/// - State fidelity against target states
+/// - Histogram display
+/// - Counts analysis helpers
+/// - Indexing and iteration over counts
+mod result_tests {
+    use super::*;
+
+    /// Builds a result with fixed counts over 3 qubits.
+    fn sample_result() -> SimulationResult {
+        let counts = HashMap::from([
+            ("000".to_string(), 10),
+            ("011".to_string(), 20),
+            ("101".to_string(), 30),
+            ("110".to_string(), 40),
+        ]);
+        SimulationResult { shots: 100, final_state: Vec::new(), counts, snapshots: HashMap::new(), entanglement: None, metrics: ExecutionMetrics::default() }
+    }
+
+    /// Tests marginalising over a single qubit and over a pair of qubits.
+    #[test]
+    fn test_marginal_counts() {
+        let result = sample_result();
+
+        let q0 = result.marginal_counts(&[0]);
+        assert_eq!(q0["0"], 50);
+        assert_eq!(q0["1"], 50);
+
+        // First listed qubit is the rightmost character
+        let q02 = result.marginal_counts(&[0, 2]);
+        assert_eq!(q02["00"], 10);
+        assert_eq!(q02["01"], 20);
+        assert_eq!(q02["11"], 30);
+        assert_eq!(q02["10"], 40);
+    }
+
+    /// Tests that out of range qubits are rejected.
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn test_marginal_counts_out_of_bounds() {
+        sample_result().marginal_counts(&[3]);
+    }
+
+    /// Tests exact and wildcard pattern probabilities.
+    #[test]
+    fn test_probability_of() {
+        let result = sample_result();
+        assert!((result.probability_of("011") - 0.2).abs() < 1e-12);
+        assert!((result.probability_of("1**") - 0.7).abs() < 1e-12);
+        assert!((result.probability_of("**1") - 0.5).abs() < 1e-12);
+        assert!((result.probability_of("***") - 1.0).abs() < 1e-12);
+        assert_eq!(result.probability_of("111"), 0.0);
+    }
+
+    /// Tests that malformed patterns are rejected.
+    #[test]
+    #[should_panic(expected = "Invalid character")]
+    fn test_probability_of_invalid_pattern() {
+        sample_result().probability_of("1x0");
+    }
+
+    /// Tests fidelity against identical, orthogonal and phase shifted targets.
+    #[test]
+    fn test_state_fidelity() {
+        let h = 1.0 / 2.0_f64.sqrt();
+        let mut result = sample_result();
+        result.final_state = vec![Complex::new(h, 0.0), Complex::new(0.0, h)];
+
+        assert!((result.state_fidelity(&[Complex::new(h, 0.0), Complex::new(0.0, h)]) - 1.0).abs() < 1e-10);
+        assert!(result.state_fidelity(&[Complex::new(h, 0.0), Complex::new(0.0, -h)]).abs() < 1e-10);
+        // Global phase does not change the fidelity
+        assert!((result.state_fidelity(&[Complex::new(0.0, h), Complex::new(-h, 0.0)]) - 1.0).abs() < 1e-10);
+    }
+
+    /// Tests the histogram rendering of counts.
+    #[test]
+    fn test_result_display() {
+        let rendered = format!("{}", sample_result());
+        let lines: Vec<&str> = rendered.lines().collect();
+
+        assert_eq!(lines[0], "Simulation Result (100 shots):");
+        assert_eq!(lines.len(), 5);
+        // Rows are sorted by bitstring and the largest count fills the bar
+        assert!(lines[1].starts_with("  000 │██████████ "));
+        assert!(lines[1].ends_with("│ 10 (0.100)"));
+        assert!(lines[4].contains(&"█".repeat(40)));
+        assert!(lines[4].ends_with("│ 40 (0.400)"));
+    }
+
+    /// Tests that the final state is rendered after the histogram.
+    #[test]
+    fn test_result_display_state() {
+        let h = 1.0 / 2.0_f64.sqrt();
+        let mut result = sample_result();
+        result.final_state = vec![Complex::new(h, 0.0), Complex::new(0.0, 0.0), Complex::new(0.0, 0.0), Complex::new(0.0, h)];
+
+        let rendered = format!("{}", result);
+        assert_eq!(rendered.lines().last(), Some("Final state: 0.7071|00⟩ + 0.7071i|11⟩"));
+        let rendered = format!("{:.1}", result);
+        assert_eq!(rendered.lines().last(), Some("Final state: 0.7|00⟩ + 0.7i|11⟩"));
+    }
+
+    /// Tests ranking helpers on counts.
+    #[test]
+    fn test_most_frequent_and_top_k() {
+        let result = sample_result();
+        assert_eq!(result.most_frequent(), Some(("110".to_string(), 40)));
+        assert_eq!(result.top_k(2), vec![("110".to_string(), 40), ("101".to_string(), 30)]);
+        assert_eq!(result.top_k(10).len(), 4);
+        assert!((result.probability("101") - 0.3).abs() < 1e-12);
+    }
+
+    /// Tests Z expectation values over single qubits and qubit pairs.
+    #[test]
+    fn test_expectation_z() {
+        let result = sample_result();
+        // qubit 0 is 1 in "011" and "101": 0.5 * (+1) + 0.5 * (-1)
+        assert!(result.expectation_z(&[0]).abs() < 1e-12);
+        // qubit 2 is 1 in "101" and "110"
+        assert!((result.expectation_z(&[2]) + 0.4).abs() < 1e-12);
+        // parity of qubits 0 and 1 is odd for "101" and "110"
+        assert!((result.expectation_z(&[0, 1]) + 0.4).abs() < 1e-12);
+        assert!((result.expectation_z(&[]) - 1.0).abs() < 1e-12);
+    }
+
+    /// Tests Pauli expectations read from the counts.
+    #[test]
+    fn test_pauli_expectation() {
+        let result = sample_result();
+        // Only the non-identity qubits enter the parity
+        assert!((result.pauli_expectation("IIX") - result.expectation_z(&[0])).abs() < 1e-12);
+        assert!((result.pauli_expectation("YIZ") - result.expectation_z(&[0, 2])).abs() < 1e-12);
+        assert!((result.pauli_expectation("III") - 1.0).abs() < 1e-12);
+    }
+
+    /// Tests the total variation distance and Hellinger fidelity between count distributions.
+    #[test]
+    fn test_distribution_distances() {
+        let a = sample_result();
+        assert_eq!(a.total_variation_distance(&sample_result()), 0.0);
+
+        let b = SimulationResult {
+            shots: 50,
+            final_state: Vec::new(),
+            counts: HashMap::from([("000".to_string(), 25), ("111".to_string(), 25)]),
+            snapshots: HashMap::new(),
+            entanglement: None,
+            metrics: ExecutionMetrics::default(),
+        };
+        // |0.1 - 0.5| + 0.2 + 0.3 + 0.4 + 0.5 = 1.8
+        assert!((a.total_variation_distance(&b) - 0.9).abs() < 1e-12);
+
+        // Only "000" is shared: (sqrt(0.1 * 0.5))^2
+        assert!((a.hellinger_fidelity(&b) - 0.05).abs() < 1e-12);
+        assert!((a.hellinger_fidelity(&b) - b.hellinger_fidelity(&a)).abs() < 1e-12);
+        assert!((a.hellinger_fidelity(&sample_result()) - 1.0).abs() < 1e-12);
+    }
+
+    /// Tests count lookup by bitstring and iteration in order of frequency.
+    #[test]
+    fn test_index_and_iteration() {
+        let result = sample_result();
+        assert_eq!(result["101"], 30);
+        assert_eq!(result["111"], 0);
+
+        let borrowed: Vec<(&str, usize)> = (&result).into_iter().collect();
+        assert_eq!(borrowed, vec![("110", 40), ("101", 30), ("011", 20), ("000", 10)]);
+
+        let owned: Vec<(String, usize)> = result.into_iter().take(2).collect();
+        assert_eq!(owned, vec![("110".to_string(), 40), ("101".to_string(), 30)]);
+    }
+}
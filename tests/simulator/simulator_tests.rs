@@ -0,0 +1,829 @@
+use intrico::{QuantumCircuit, QuantumGate};
+use intrico::circuit::{Basis, ParameterizedCircuit};
+use intrico::core::NoiseChannel;
+use intrico::device::{DeviceModel, QubitProperties};
+use intrico::simulator::{CancellationToken, DEFAULT_MEMORY_LIMIT, NoiseModel, Observable, ProgressStage, SamplingMode, SimulationError, Simulator, StateVectorBackend};
+use intrico::transpiler::{transpile, TranspileOptions};
+use rusticle::linalg::Matrix;
+use rusticle::complex::Complex;
+use std::future::Future;
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake};
+use std::thread::{self, Thread};
+
+/// Test suite for the Simulator type.
+/// 
+/// These tests verify the behaviour of circuit simulation, including:
+/// - Snapshots of intermediate states
+/// - Entanglement diagnostics
+/// - Exact probabilities and deterministic counts
+/// - Batch execution
+/// - Measurements in Pauli bases and X/Y measurement instructions
+/// - Classical feed-forward between measurements
+/// - Repeat-until-success loops
+/// - Progress reporting and cancellation
+/// - Asynchronous execution
+/// - Qubit and memory limits
+/// - Warm-starting from a previous state
+/// - Execution metrics
+/// - Parameter-shift gradients and parameter sweeps
+/// - Noisy simulation with shot-branching
+/// - Noise channels attached to individual operations
+/// - Multinomial sampling for large shot counts
+/// - Custom random number generators
+/// - Single precision simulation
+/// - Tracing spans
+mod simulator_tests {
+    use super::*;
+
+    /// Tests that snapshots record the state at their position in the circuit.
+    #[test]
+    fn test_snapshots() {
+        let mut qc = QuantumCircuit::new(2);
+        qc.snapshot("initial");
+        qc.x(0);
+        qc.snapshot("flipped");
+        qc.cnot(0, 1);
+
+        let result = Simulator::new().with_circuit(qc).run(10);
+
+        let zero = Complex::new(0.0, 0.0);
+        let one = Complex::new(1.0, 0.0);
+        assert_eq!(result.snapshots["initial"], vec![one, zero, zero, zero]);
+        assert_eq!(result.snapshots["flipped"], vec![zero, one, zero, zero]);
+        assert_eq!(result.final_state, vec![zero, zero, zero, one]);
+    }
+
+    /// Tests entanglement diagnostics on a GHZ state and a product state.
+    #[test]
+    fn test_entanglement_diagnostics() {
+        let mut ghz = QuantumCircuit::new(3);
+        ghz.h(0);
+        ghz.cnot(0, 1);
+        ghz.cnot(1, 2);
+
+        let result = Simulator::new().with_circuit(ghz).with_entanglement_diagnostics(true).run(10);
+        let report = result.entanglement.unwrap();
+        for purity in &report.qubit_purities {
+            assert!((purity - 0.5).abs() < 1e-6);
+        }
+        for entropy in &report.bipartition_entropies {
+            assert!((entropy - 1.0).abs() < 1e-6);
+        }
+
+        let mut product = QuantumCircuit::new(3);
+        product.h(0);
+        product.ry(2, 0.3);
+
+        let result = Simulator::new().with_circuit(product).with_entanglement_diagnostics(true).run(10);
+        let report = result.entanglement.unwrap();
+        assert!(!report.is_entangled());
+        for purity in &report.qubit_purities {
+            assert!((purity - 1.0).abs() < 1e-6);
+        }
+
+        // Diagnostics are opt-in
+        let result = Simulator::new().with_circuit(QuantumCircuit::new(1)).run(10);
+        assert!(result.entanglement.is_none());
+    }
+
+    /// Tests that quasi counts always sum to the requested shots.
+    #[test]
+    fn test_quasi_counts() {
+        let mut qc = QuantumCircuit::new(3);
+        qc.h(0);
+        qc.h(1);
+        qc.h(2);
+        let sim = Simulator::new().with_circuit(qc);
+
+        let probabilities = sim.probabilities();
+        assert_eq!(probabilities.len(), 8);
+        assert!((probabilities.iter().sum::<f64>() - 1.0).abs() < 1e-6);
+
+        // 10 shots over 8 equally likely outcomes
+        let counts = sim.quasi_counts(10);
+        assert_eq!(counts.values().sum::<usize>(), 10);
+        assert!(counts.values().all(|&count| count == 1 || count == 2));
+
+        // Deterministic across calls
+        assert_eq!(counts, sim.quasi_counts(10));
+    }
+
+    /// Tests that batch results keep the order of the input circuits.
+    #[test]
+    fn test_run_batch_order() {
+        let circuits: Vec<QuantumCircuit> = (0..16)
+            .map(|i| {
+                let mut qc = QuantumCircuit::new(4);
+                for qubit in 0..4 {
+                    if (i >> qubit) & 1 == 1 {
+                        qc.x(qubit);
+                    }
+                }
+                qc
+            })
+            .collect();
+
+        let results = Simulator::new().run_batch(&circuits, 5);
+        assert_eq!(results.len(), 16);
+        for (i, result) in results.iter().enumerate() {
+            assert_eq!(result.counts[&format!("{:04b}", i)], 5);
+        }
+
+        assert!(Simulator::new().run_batch(&[], 5).is_empty());
+    }
+
+    /// Tests that progress reports cover every gate and every shot.
+    #[test]
+    fn test_progress_reports() {
+        let mut qc = QuantumCircuit::new(2);
+        qc.h(0);
+        qc.cnot(0, 1);
+        qc.x(1);
+
+        let mut reports = Vec::new();
+        Simulator::new()
+            .with_circuit(qc)
+            .run_with_callback(250, |progress| reports.push(progress))
+            .unwrap();
+
+        let gates: Vec<_> = reports.iter().filter(|p| p.stage == ProgressStage::Gates).collect();
+        assert_eq!(gates.len(), 3);
+        assert_eq!(gates[2].completed, 3);
+
+        let last = reports.last().unwrap();
+        assert_eq!(last.stage, ProgressStage::Shots);
+        assert_eq!(last.fraction(), 1.0);
+    }
+
+    /// Tests cancelling a simulation from inside the progress callback.
+    #[test]
+    fn test_cancellation_between_gates() {
+        let mut qc = QuantumCircuit::new(1);
+        for _ in 0..10 {
+            qc.h(0);
+        }
+
+        let token = CancellationToken::new();
+        let sim = Simulator::new()
+            .with_circuit(qc)
+            .with_cancellation_token(token.clone());
+
+        let mut gates_seen = 0;
+        let result = sim.run_with_callback(10, |progress| {
+            gates_seen = progress.completed;
+            if progress.completed == 3 {
+                token.cancel();
+            }
+        });
+
+        assert_eq!(result.unwrap_err(), SimulationError::Cancelled);
+        assert_eq!(gates_seen, 3);
+    }
+
+    /// Wakes a parked thread, used by the minimal executor below.
+    struct ThreadWaker(Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    /// Drives a future to completion on the current thread.
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = Box::pin(future);
+        let waker = Arc::new(ThreadWaker(thread::current())).into();
+        let mut cx = Context::from_waker(&waker);
+        loop {
+            match future.as_mut().poll(&mut cx) {
+                Poll::Ready(output) => return output,
+                Poll::Pending => thread::park(),
+            }
+        }
+    }
+
+    /// Tests that the async API resolves to the simulation result.
+    #[test]
+    fn test_run_async() {
+        let mut qc = QuantumCircuit::new(2);
+        qc.x(0);
+        qc.cnot(0, 1);
+
+        let sim = Simulator::new().with_circuit(qc);
+        let future = sim.run_async(50);
+        drop(sim);
+
+        let result = block_on(future);
+        assert_eq!(result.counts["11"], 50);
+    }
+
+    /// Tests that panics inside the simulation surface when awaiting.
+    #[test]
+    #[should_panic(expected = "No circuit provided")]
+    fn test_run_async_propagates_panics() {
+        block_on(Simulator::new().run_async(10));
+    }
+
+    /// Tests that oversized circuits are rejected before allocation.
+    #[test]
+    fn test_state_too_large() {
+        let sim = Simulator::new().with_circuit(QuantumCircuit::new(70));
+        match sim.try_run(1) {
+            Err(SimulationError::StateTooLarge { qubits, bytes_needed }) => {
+                assert_eq!(qubits, 70);
+                assert_eq!(bytes_needed, 16u128 << 70);
+            },
+            other => panic!("Unexpected result: {:?}", other.map(|r| r.shots)),
+        }
+
+        let sim = Simulator::new().with_circuit(QuantumCircuit::new(4)).with_memory_limit(128);
+        assert_eq!(
+            sim.try_run(1).unwrap_err(),
+            SimulationError::StateTooLarge { qubits: 4, bytes_needed: 256 }
+        );
+
+        let sim = Simulator::new().with_circuit(QuantumCircuit::new(3)).with_memory_limit(128);
+        assert!(sim.try_run(1).is_ok());
+    }
+
+    /// Tests that the default memory limit is 16 GiB on 64-bit targets.
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn test_default_memory_limit() {
+        assert_eq!(DEFAULT_MEMORY_LIMIT, 16 << 30);
+        assert!(Simulator::new().with_circuit(QuantumCircuit::new(3)).try_run(1).is_ok());
+    }
+
+    /// Tests that the default memory limit does not wrap to zero on 32-bit targets.
+    #[test]
+    #[cfg(target_pointer_width = "32")]
+    fn test_default_memory_limit_wasm32() {
+        assert_eq!(DEFAULT_MEMORY_LIMIT, usize::MAX);
+        assert!(Simulator::new().with_circuit(QuantumCircuit::new(3)).try_run(1).is_ok());
+    }
+
+    /// Tests that segmented simulation matches simulating the whole circuit.
+    #[test]
+    fn test_initial_state_segments() {
+        let mut whole = QuantumCircuit::new(3);
+        let mut first = QuantumCircuit::new(3);
+        let mut second = QuantumCircuit::new(3);
+        whole.h(0);
+        first.h(0);
+        whole.cnot(0, 1);
+        first.cnot(0, 1);
+        whole.ry(2, 0.7);
+        second.ry(2, 0.7);
+        whole.cz(1, 2);
+        second.cz(1, 2);
+
+        let expected = Simulator::new().with_circuit(whole).run(1).final_state;
+        let intermediate = Simulator::new().with_circuit(first).run(1).final_state;
+        let result = Simulator::new()
+            .with_circuit(second)
+            .with_initial_state(intermediate)
+            .run(1);
+
+        assert!((result.state_fidelity(&expected) - 1.0).abs() < 1e-6);
+    }
+
+    /// Tests validation of the provided initial state.
+    #[test]
+    fn test_invalid_initial_state() {
+        let one = Complex::new(1.0, 0.0);
+        let zero = Complex::new(0.0, 0.0);
+
+        let sim = Simulator::new()
+            .with_circuit(QuantumCircuit::new(2))
+            .with_initial_state(vec![one, zero]);
+        assert_eq!(
+            sim.try_run(1).unwrap_err(),
+            SimulationError::InitialStateDimension { expected: 4, found: 2 }
+        );
+
+        let sim = Simulator::new()
+            .with_circuit(QuantumCircuit::new(1))
+            .with_initial_state(vec![one, one]);
+        assert_eq!(
+            sim.try_run(1).unwrap_err(),
+            SimulationError::InitialStateNotNormalized { norm: 2.0 }
+        );
+    }
+
+    /// Tests that execution metrics account for every applied gate.
+    #[test]
+    fn test_execution_metrics() {
+        let mut qc = QuantumCircuit::new(3);
+        qc.h(0);
+        qc.rx(1, 0.1);
+        qc.rx(2, 0.2);
+        qc.cnot(0, 1);
+        qc.snapshot("mid");
+
+        let result = Simulator::new().with_circuit(qc).run(100);
+        let metrics = &result.metrics;
+
+        assert_eq!(metrics.total_gates(), 4);
+        assert_eq!(metrics.gate_counts["Rx"], 2);
+        assert_eq!(metrics.gate_counts["CNOT"], 1);
+        assert!(!metrics.gate_counts.contains_key("Snapshot"));
+        assert_eq!(metrics.gate_timings.len(), 3);
+        assert!(metrics.wall_time >= metrics.sampling_time);
+        // 8 amplitudes of 16 bytes: working state, rounded copy and one snapshot, plus probabilities
+        assert_eq!(metrics.peak_memory_bytes, 3 * 8 * 16 + 8 * 8);
+    }
+
+    /// Tests that parameter-shift gradients match finite differences, including shared parameters.
+    #[test]
+    fn test_gradient() {
+        let mut ansatz = ParameterizedCircuit::new(2);
+        ansatz.ry(0, 0);
+        ansatz.rx(1, 1);
+        ansatz.circuit_mut().cnot(0, 1);
+        ansatz.rz(1, 0);
+        ansatz.ry(1, 1);
+
+        // Z ⊗ X on qubits 1 and 0
+        let z = QuantumGate::Z.matrix();
+        let x = QuantumGate::X.matrix();
+        let observable = Matrix::new(4, 4, (0..16)
+            .map(|k| *z.get(k / 8, (k % 4) / 2) * *x.get((k / 4) % 2, k % 2))
+            .collect());
+
+        let sim = Simulator::new();
+        let params = [0.3, -0.8];
+        let gradient = sim.gradient(&ansatz, &observable, &params);
+
+        let energy = |values: &[f64]| {
+            let state = sim.clone().with_circuit(ansatz.bind(values)).run(0).final_state;
+            observable.expectation(&state)
+        };
+        let h = 1e-4;
+        for (i, &derivative) in gradient.iter().enumerate() {
+            let mut plus = params;
+            let mut minus = params;
+            plus[i] += h;
+            minus[i] -= h;
+            let numeric = (energy(&plus) - energy(&minus)) / (2.0 * h);
+            assert!((derivative - numeric).abs() < 1e-3, "parameter {}: {} vs {}", i, derivative, numeric);
+        }
+    }
+
+    /// Tests that a sweep binds every parameter set and keeps the grid order.
+    #[test]
+    fn test_sweep() {
+        let mut ansatz = ParameterizedCircuit::new(2);
+        ansatz.ry(0, 0);
+        ansatz.circuit_mut().cnot(0, 1);
+        ansatz.rz(1, 1);
+
+        let grid: Vec<Vec<f64>> = (0..5)
+            .flat_map(|i| (0..3).map(move |j| vec![i as f64 * 0.4, j as f64]))
+            .collect();
+        let sweep = Simulator::new().sweep(&ansatz, &grid, 0);
+
+        assert_eq!(sweep.len(), grid.len());
+        for ((params, result), expected) in sweep.iter().zip(&grid) {
+            assert_eq!(params, expected);
+            let p11 = result.final_state[3].norm_squared();
+            assert!((p11 - (params[0] / 2.0).sin().powi(2)).abs() < 1e-6);
+        }
+    }
+
+    /// Tests that depolarizing noise flips outcomes at the expected rate.
+    #[test]
+    fn test_depolarizing_noise() {
+        let mut qc = QuantumCircuit::new(1);
+        qc.x(0);
+
+        let shots = 20_000;
+        let result = Simulator::new()
+            .with_circuit(qc)
+            .with_noise(NoiseModel::depolarizing(0.3, 0.0))
+            .run(shots);
+
+        // X and Y errors flip the outcome, Z errors do not
+        let flipped = result.probability("0");
+        assert!((flipped - 0.2).abs() < 0.02, "flip rate {}", flipped);
+        assert_eq!(result.counts.values().sum::<usize>(), shots);
+        assert_eq!(result.final_state, vec![Complex::new(0.0, 0.0), Complex::new(1.0, 0.0)]);
+    }
+
+    /// Tests that readout errors flip measured bits independently.
+    #[test]
+    fn test_readout_noise() {
+        let qc = QuantumCircuit::new(2);
+        let result = Simulator::new()
+            .with_circuit(qc)
+            .with_noise(NoiseModel::default().with_readout_error(0.1))
+            .run(20_000);
+
+        assert!((result.probability("00") - 0.81).abs() < 0.02);
+        assert!((result.probability("11") - 0.01).abs() < 0.01);
+    }
+
+    /// Tests that shots share their evolution until an error occurs.
+    #[test]
+    fn test_shot_branching() {
+        let mut qc = QuantumCircuit::new(3);
+        for _ in 0..10 {
+            qc.h(0);
+            qc.cnot(0, 1);
+            qc.cnot(1, 2);
+        }
+
+        let shots = 10_000;
+        let result = Simulator::new()
+            .with_circuit(qc)
+            .with_noise(NoiseModel::depolarizing(0.0001, 0.0005))
+            .run(shots);
+
+        // Naive per-shot execution would apply 30 gates for every shot
+        assert!(result.metrics.total_gates() < 30 * shots / 20, "{} gates applied", result.metrics.total_gates());
+        assert_eq!(result.counts.values().sum::<usize>(), shots);
+    }
+
+    /// Tests that noise attached to one operation only affects its qubits.
+    #[test]
+    fn test_attached_noise() {
+        let mut qc = QuantumCircuit::new(3);
+        qc.h(0);
+        qc.add_noise(NoiseChannel::PhaseFlip(1.0));
+        qc.h(0);
+        qc.x(1);
+        qc.add_noise(NoiseChannel::BitFlip(0.5));
+        qc.x(2);
+
+        // The phase flip turns H·H into X, the bit flip randomizes qubit 1 only
+        let result = Simulator::new().with_seed(11).with_circuit(qc).run(1000);
+        assert!(result.counts.keys().all(|bits| bits == "101" || bits == "111"));
+        let ones = result.counts.get("111").copied().unwrap_or(0);
+        assert!((400..600).contains(&ones), "{}", ones);
+
+        // Attached channels combine with a global model and survive transpilation
+        let mut qc = QuantumCircuit::new(1);
+        qc.rx(0, 0.3);
+        qc.add_noise(NoiseChannel::Depolarizing(0.75));
+        qc.rx(0, -0.3);
+        let transpiled = transpile(&qc, &TranspileOptions::default());
+        assert_eq!(transpiled.num_operations(), 2);
+
+        let result = Simulator::new().with_seed(11).with_circuit(qc).with_noise(NoiseModel::depolarizing(0.0, 0.0)).run(1000);
+        assert!(result.counts["0"] < 800, "{:?}", result.counts);
+    }
+
+    /// Tests that device calibrations drive gate errors and thermal relaxation.
+    #[test]
+    fn test_device_noise() {
+        let qubit = QubitProperties { t1: 1_000.0, t2: 1_000.0, readout_error: 0.0 };
+        let device = DeviceModel::new("slow", vec![qubit])
+            .with_gate("X", &[], 0.0, 500.0);
+
+        let mut qc = QuantumCircuit::new(1);
+        qc.x(0);
+
+        let noise = NoiseModel::from_device(device);
+        assert_eq!(noise.gate_error(&qc.operations()[0]), 0.0);
+
+        let result = Simulator::new().with_circuit(qc).with_noise(noise).run(20_000);
+
+        // X and Y relaxation errors each occur with probability (1 - e^(-1/2)) / 4
+        let expected = (1.0 - (-0.5_f64).exp()) / 2.0;
+        assert!((result.probability("0") - expected).abs() < 0.02);
+    }
+
+    /// Tests that multinomial sampling matches the exact probabilities for huge shot counts.
+    #[test]
+    fn test_multinomial_sampling() {
+        let mut qc = QuantumCircuit::new(3);
+        qc.ry(0, 1.0);
+        qc.h(1);
+        qc.cnot(1, 2);
+
+        let shots = 5_000_000;
+        let sim = Simulator::new()
+            .with_circuit(qc)
+            .with_sampling(SamplingMode::Multinomial);
+        let result = sim.run(shots);
+        let probabilities = sim.probabilities();
+
+        assert_eq!(result.counts.values().sum::<usize>(), shots);
+        for (idx, &p) in probabilities.iter().enumerate() {
+            let bits = format!("{:03b}", idx);
+            assert!((result.probability(&bits) - p).abs() < 1e-3, "{}: {} vs {}", bits, result.probability(&bits), p);
+        }
+    }
+
+    /// Tests that readout errors are applied to multinomially sampled counts.
+    #[test]
+    fn test_multinomial_readout_noise() {
+        let result = Simulator::new()
+            .with_circuit(QuantumCircuit::new(2))
+            .with_noise(NoiseModel::default().with_readout_error(0.1))
+            .with_sampling(SamplingMode::Multinomial)
+            .run(1_000_000);
+
+        assert_eq!(result.counts.values().sum::<usize>(), 1_000_000);
+        assert!((result.probability("00") - 0.81).abs() < 2e-3);
+        assert!((result.probability("01") - 0.09).abs() < 2e-3);
+        assert!((result.probability("11") - 0.01).abs() < 2e-3);
+    }
+
+    /// A counter-based generator hashing an incrementing counter with SplitMix64.
+    struct CounterRng(u64);
+
+    impl rand::RngCore for CounterRng {
+        fn next_u32(&mut self) -> u32 {
+            self.next_u64() as u32
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+            let mut z = self.0;
+            z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+            z ^ (z >> 31)
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            for chunk in dest.chunks_mut(8) {
+                let bytes = self.next_u64().to_le_bytes();
+                chunk.copy_from_slice(&bytes[..chunk.len()]);
+            }
+        }
+    }
+
+    /// Tests that seeded and user-provided generators make noisy runs reproducible.
+    #[test]
+    fn test_custom_rng() {
+        let mut qc = QuantumCircuit::new(3);
+        qc.h(0);
+        qc.cnot(0, 1);
+        qc.ry(2, 0.7);
+
+        let sim = Simulator::new()
+            .with_circuit(qc)
+            .with_noise(NoiseModel::depolarizing(0.05, 0.1).with_readout_error(0.02));
+
+        let seeded = |seed| sim.clone().with_seed(seed).run(2_000).counts;
+        assert_eq!(seeded(1), seeded(1));
+        assert_ne!(seeded(1), seeded(2));
+
+        let counter = |start| sim.clone()
+            .with_rng(CounterRng(start))
+            .with_sampling(SamplingMode::Multinomial)
+            .run(1_000_000)
+            .counts;
+        assert_eq!(counter(5), counter(5));
+    }
+
+    /// Tests that mid-circuit measurements collapse the state and drive conditioned gates.
+    #[test]
+    fn test_mid_circuit_measurement() {
+        // Copy a random bit onto qubit 1 through a classical condition
+        let mut qc = QuantumCircuit::new(2);
+        qc.h(0);
+        qc.measure(0, 0);
+        qc.x(1);
+        qc.c_if(0, true);
+
+        let result = Simulator::new().with_seed(3).with_circuit(qc).run(1000);
+        assert_eq!(result.counts.len(), 2);
+        assert!(result.counts.keys().all(|bits| bits == "00" || bits == "11"));
+
+        // The collapse is visible to later gates: H·measure·H is no longer the identity
+        let mut qc = QuantumCircuit::new(1);
+        qc.h(0);
+        qc.measure(0, 0);
+        qc.h(0);
+
+        let result = Simulator::new().with_seed(3).with_circuit(qc).run(1000);
+        let ones = result.counts.get("1").copied().unwrap_or(0);
+        assert!((400..600).contains(&ones), "{}", ones);
+    }
+
+    /// Tests that classical operations on measured bits drive conditioned gates.
+    #[test]
+    fn test_classical_feed_forward() {
+        // Qubit 2 receives the AND of two random bits
+        let mut qc = QuantumCircuit::new(3);
+        qc.h(0);
+        qc.h(1);
+        qc.measure(0, 0);
+        qc.measure(1, 1);
+        qc.c_and(0, 1, 2);
+        qc.x(2);
+        qc.c_if(2, true);
+
+        let result = Simulator::new().with_seed(5).with_circuit(qc).run(1000);
+        assert_eq!(result.counts.len(), 4);
+        for bits in result.counts.keys() {
+            let b: Vec<bool> = bits.chars().rev().map(|c| c == '1').collect();
+            assert_eq!(b[2], b[0] && b[1], "{}", bits);
+        }
+
+        // Comparing a register against a constant
+        let mut qc = QuantumCircuit::new(3);
+        qc.x(1);
+        qc.measure(0, 0);
+        qc.measure(1, 1);
+        qc.c_eq(&[0, 1], 0b10, 2);
+        qc.x(2);
+        qc.c_if(2, true);
+
+        let result = Simulator::new().with_circuit(qc).run(100);
+        assert_eq!(result.counts["110"], 100);
+    }
+
+    /// Tests that loops repeat their body per shot until the condition holds or the cap is hit.
+    #[test]
+    fn test_repeat_until() {
+        let mut coin = QuantumCircuit::new(1);
+        coin.h(0);
+        coin.measure(0, 0);
+
+        // Every shot eventually flips a 1
+        let mut qc = QuantumCircuit::new(2);
+        qc.repeat_until(&coin, &[1], 0, true, 40);
+        let result = Simulator::new().with_seed(11).with_circuit(qc).run(500);
+        assert_eq!(result.counts["10"], 500);
+
+        // A single iteration leaves the coin as it fell
+        let mut qc = QuantumCircuit::new(1);
+        qc.repeat_until(&coin, &[0], 0, true, 1);
+        let result = Simulator::new().with_seed(11).with_circuit(qc).run(1000);
+        assert_eq!(result.counts.len(), 2);
+
+        // The body toggles the qubit, so the loop ends after the second iteration
+        let mut toggle = QuantumCircuit::new(1);
+        toggle.x(0);
+        toggle.measure(0, 0);
+        let mut qc = QuantumCircuit::new(2);
+        qc.repeat_until(&toggle, &[0], 0, false, 5);
+        qc.x(1);
+        qc.c_if(0, false);
+        let result = Simulator::new().with_circuit(qc.clone()).run(10);
+        assert_eq!(result.counts["10"], 10);
+
+        // Without measurements the body is applied once
+        let state = qc.execute();
+        assert!((state.probabilities()[0b11] - 1.0).abs() < 1e-6);
+
+        // Loops follow their qubits when composed into a larger circuit
+        let mut outer = QuantumCircuit::new(3);
+        outer.compose(&qc, &[2, 0]);
+        assert_eq!(outer.operations()[0].qubit, vec![2]);
+        let result = Simulator::new().with_circuit(outer).run(10);
+        assert_eq!(result.counts["001"], 10);
+    }
+
+    /// Tests that terminal measurements leave the final state untouched.
+    #[test]
+    fn test_terminal_measurement() {
+        let mut qc = QuantumCircuit::new(1);
+        qc.h(0);
+        qc.measure(0, 0);
+
+        let result = Simulator::new().with_circuit(qc).run(10);
+        let h = 1.0 / 2.0_f64.sqrt();
+        assert!((result.final_state[0].real - h).abs() < 1e-6);
+        assert!((result.final_state[1].real - h).abs() < 1e-6);
+    }
+
+    /// Tests deterministic outcomes of measurements in the eigenbases of prepared states.
+    #[test]
+    fn test_measure_in_basis() {
+        let mut qc = QuantumCircuit::new(3);
+        qc.x(0);
+        qc.h(0);
+        qc.h(1);
+        qc.s(1);
+        qc.x(2);
+        qc.measure_in_basis(0, Basis::X, 0);
+        qc.measure_in_basis(1, Basis::Y, 1);
+        qc.measure_in_basis(2, Basis::Z, 2);
+
+        // |-⟩, |+i⟩ and |1⟩ give -1, +1 and -1
+        let result = Simulator::new().with_circuit(qc).run(200);
+        assert_eq!(result.counts["101"], 200);
+        assert_eq!(result.pauli_expectation("ZYX"), 1.0);
+        assert_eq!(result.pauli_expectation("IIX"), -1.0);
+    }
+
+    /// Tests that X- and Y-basis measurement instructions match explicit basis changes.
+    #[test]
+    fn test_measure_x_y() {
+        let mut qc = QuantumCircuit::new(2);
+        qc.x(0);
+        qc.h(0);
+        qc.h(1);
+        qc.sdg(1);
+        qc.measure_x(0, 0);
+        qc.measure_y(1, 1);
+
+        // |-⟩ and |-i⟩ both give -1, each measurement is a single operation
+        assert_eq!(qc.num_operations(), 6);
+        assert!(qc.draw().contains("[MX]") && qc.draw().contains("[MY]"));
+        let result = Simulator::new().with_circuit(qc).run(100);
+        assert_eq!(result.counts["11"], 100);
+
+        // Mid-circuit X measurements collapse onto |±⟩ and drive conditioned gates
+        let mut qc = QuantumCircuit::new(2);
+        qc.measure_x(0, 0);
+        qc.x(1);
+        qc.c_if(0, true);
+
+        let result = Simulator::new().with_seed(3).with_circuit(qc).run(1000);
+        assert_eq!(result.counts.len(), 2);
+        assert!(result.counts.keys().all(|bits| bits == "00" || bits == "11"));
+    }
+
+    /// Tests that measuring a Bell pair in matching bases reproduces its correlations.
+    #[test]
+    fn test_basis_correlations() {
+        for (basis, expected) in [(Basis::X, 1.0), (Basis::Y, -1.0), (Basis::Z, 1.0)] {
+            let mut qc = QuantumCircuit::new(2);
+            qc.h(0);
+            qc.cnot(0, 1);
+            qc.measure_in_basis(0, basis, 0);
+            qc.measure_in_basis(1, basis, 1);
+
+            let result = Simulator::new().with_seed(3).with_circuit(qc).run(500);
+            let pauli: String = [basis.pauli(); 2].iter().collect();
+            assert_eq!(result.pauli_expectation(&pauli), expected);
+            assert!(result.pauli_expectation(&format!("I{}", basis.pauli())).abs() < 0.2);
+        }
+    }
+
+    /// Tests that single precision matches double precision and halves the memory.
+    #[test]
+    fn test_single_precision() {
+        let mut qc = QuantumCircuit::new(3);
+        qc.h(0);
+        qc.ry(1, 0.3);
+        qc.cnot(0, 2);
+        qc.rzz(1, 2, 0.7);
+        qc.ccx(0, 1, 2);
+
+        let double = Simulator::new().with_circuit(qc.clone()).with_seed(5).run(1000);
+        let single = Simulator::with_backend(StateVectorBackend::<f32>::default())
+            .with_circuit(qc)
+            .with_seed(5)
+            .run(1000);
+
+        for (a, b) in double.final_state.iter().zip(&single.final_state) {
+            assert!((a.real - b.real).abs() < 1e-6 && (a.imag - b.imag).abs() < 1e-6);
+        }
+        assert_eq!(single.counts.values().sum::<usize>(), 1000);
+
+        // 16 amplitudes of 8 bytes fit under a limit too small for 16 bytes each
+        let sim = Simulator::new().with_circuit(QuantumCircuit::new(4)).with_memory_limit(128);
+        assert!(sim.try_run(1).is_err());
+        let sim = Simulator::with_backend(StateVectorBackend::<f32>::default())
+            .with_circuit(QuantumCircuit::new(4))
+            .with_memory_limit(128);
+        assert!(sim.try_run(1).is_ok());
+    }
+
+    /// Tests that simulations, transpilation and sampling emit nested spans.
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn test_tracing_spans() {
+        use std::sync::Mutex;
+        use intrico::transpiler::TranspileOptions;
+        use tracing::span::{Attributes, Id, Record};
+        use tracing::{Event, Metadata, Subscriber};
+
+        /// Records the names of all created spans
+        struct Recorder(Arc<Mutex<Vec<&'static str>>>);
+
+        impl Subscriber for Recorder {
+            fn enabled(&self, _: &Metadata<'_>) -> bool { true }
+            fn new_span(&self, span: &Attributes<'_>) -> Id {
+                let mut names = self.0.lock().unwrap();
+                names.push(span.metadata().name());
+                Id::from_u64(names.len() as u64)
+            }
+            fn record(&self, _: &Id, _: &Record<'_>) {}
+            fn record_follows_from(&self, _: &Id, _: &Id) {}
+            fn event(&self, _: &Event<'_>) {}
+            fn enter(&self, _: &Id) {}
+            fn exit(&self, _: &Id) {}
+        }
+
+        let names = Arc::new(Mutex::new(Vec::new()));
+        let mut qc = QuantumCircuit::new(2);
+        qc.h(0);
+        qc.cnot(0, 1);
+
+        tracing::subscriber::with_default(Recorder(names.clone()), || {
+            Simulator::new().with_circuit(qc).with_transpile(TranspileOptions::default()).run(10);
+        });
+
+        let names = names.lock().unwrap();
+        for expected in ["simulate", "transpile", "decompose", "optimize", "gates", "sample"] {
+            assert!(names.contains(&expected), "missing span {}", expected);
+        }
+    }
+}
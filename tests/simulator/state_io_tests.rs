@@ -0,0 +1,58 @@
+use intrico::simulator::{load_state, save_state, StateFormat};
+use rusticle::complex::Complex;
+use std::fs;
+use std::path::PathBuf;
+
+/// Test suite for storing statevectors on disk.
+/// 
+/// These tests verify:
+/// - Lossless round trips through the binary and JSON formats
+/// - Rejection of corrupted files
+mod state_io_tests {
+    use super::*;
+
+    /// Returns a unique temporary path for a test file.
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("intrico_{}_{}", std::process::id(), name))
+    }
+
+    /// Returns a statevector with awkward floating point values.
+    fn sample_state() -> Vec<Complex> {
+        vec![
+            Complex::new(1.0 / 3.0, -1e-17),
+            Complex::new(-0.25, 2.0_f64.sqrt() / 7.0),
+            Complex::new(0.0, 0.0),
+            Complex::new(-0.0, 0.123456789012345),
+        ]
+    }
+
+    /// Tests round trips through both formats.
+    #[test]
+    fn test_round_trip() {
+        for (format, name) in [(StateFormat::Binary, "round_trip.bin"), (StateFormat::Json, "round_trip.json")] {
+            let path = temp_path(name);
+            save_state(&path, &sample_state(), format).unwrap();
+            assert_eq!(load_state(&path, format).unwrap(), sample_state());
+            fs::remove_file(&path).unwrap();
+        }
+    }
+
+    /// Tests that malformed files are rejected with an error.
+    #[test]
+    fn test_invalid_files() {
+        let path = temp_path("invalid.bin");
+
+        fs::write(&path, b"not a state").unwrap();
+        assert!(load_state(&path, StateFormat::Binary).is_err());
+        assert!(load_state(&path, StateFormat::Json).is_err());
+
+        // Truncated amplitude data
+        save_state(&path, &sample_state(), StateFormat::Binary).unwrap();
+        let mut bytes = fs::read(&path).unwrap();
+        bytes.truncate(bytes.len() - 8);
+        fs::write(&path, bytes).unwrap();
+        assert!(load_state(&path, StateFormat::Binary).is_err());
+
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,2 @@
+mod process_tomography_tests;
+mod state_tomography_tests;
\ No newline at end of file
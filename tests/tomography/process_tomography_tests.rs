@@ -0,0 +1,118 @@
+use rusticle::complex::Complex;
+use rusticle::linalg::Matrix;
+
+use intrico::QuantumCircuit;
+use intrico::simulator::{NoiseModel, Simulator};
+use intrico::tomography::{ProcessTomography, Reconstruction};
+
+/// Test suite for process tomography.
+/// 
+/// These tests verify the behaviour of:
+/// - Generation of the preparations and circuits
+/// - Reconstruction of the Choi and χ matrices of single-qubit gates
+/// - Process and average gate fidelities of ideal and noisy gates
+/// - Tomography of two-qubit gates
+mod process_tomography_tests {
+    use super::*;
+
+    /// Returns the trace of a matrix
+    fn trace(matrix: &Matrix<Complex>) -> Complex {
+        (0..matrix.rows()).fold(Complex::new(0.0, 0.0), |acc, k| acc + *matrix.get(k, k))
+    }
+
+    /// Tests the preparations and circuits of a two-qubit experiment.
+    #[test]
+    fn test_preparations() {
+        let mut qc = QuantumCircuit::new(2);
+        qc.cnot(0, 1);
+        let tomography = ProcessTomography::new(qc);
+        let preparations = tomography.preparations();
+
+        assert_eq!(preparations.len(), 16);
+        assert_eq!(preparations[0], "00");
+        assert_eq!(preparations[6], "1+");
+        assert_eq!(preparations[15], "rr");
+        assert_eq!(tomography.circuits().len(), 16 * 9);
+    }
+
+    /// Tests the χ matrix of the X gate.
+    #[test]
+    fn test_chi_of_x() {
+        let mut qc = QuantumCircuit::new(1);
+        qc.x(0);
+
+        let result = ProcessTomography::new(qc)
+            .with_shots(4000)
+            .with_simulator(Simulator::new().with_seed(3))
+            .run();
+
+        assert!((trace(&result.chi).real - 1.0).abs() < 1e-6);
+        assert!((trace(&result.choi).real - 2.0).abs() < 1e-6);
+        assert!((result.chi.get(1, 1).real - 1.0).abs() < 0.05);
+        assert!(result.chi.get(0, 0).norm() < 0.05);
+        assert!(result.chi.get(3, 3).norm() < 0.05);
+    }
+
+    /// Tests the fidelities of an ideal Hadamard gate.
+    #[test]
+    fn test_ideal_fidelity() {
+        let mut qc = QuantumCircuit::new(1);
+        qc.h(0);
+
+        let result = ProcessTomography::new(qc.clone())
+            .with_shots(4000)
+            .with_simulator(Simulator::new().with_seed(5))
+            .with_method(Reconstruction::MaximumLikelihood)
+            .run();
+
+        assert!(result.process_fidelity(&qc.unitary()) > 0.97);
+        assert!(result.average_gate_fidelity(&qc.unitary()) > 0.98);
+
+        let mut other = QuantumCircuit::new(1);
+        other.x(0);
+        assert!(result.process_fidelity(&other.unitary()) < 0.6);
+    }
+
+    /// Tests that depolarizing noise lowers the fidelity.
+    #[test]
+    fn test_noisy_fidelity() {
+        let mut qc = QuantumCircuit::new(1);
+        qc.s(0);
+
+        let ideal = ProcessTomography::new(qc.clone())
+            .with_shots(4000)
+            .with_simulator(Simulator::new().with_seed(7))
+            .run();
+        let noisy = ProcessTomography::new(qc.clone())
+            .with_shots(4000)
+            .with_simulator(Simulator::new().with_noise(NoiseModel::depolarizing(0.2, 0.0)).with_seed(7))
+            .run();
+
+        let ideal_fidelity = ideal.average_gate_fidelity(&qc.unitary());
+        let noisy_fidelity = noisy.average_gate_fidelity(&qc.unitary());
+        assert!(ideal_fidelity > 0.97);
+        assert!(noisy_fidelity < ideal_fidelity - 0.05);
+    }
+
+    /// Tests the fidelity of a CNOT gate.
+    #[test]
+    fn test_cnot() {
+        let mut qc = QuantumCircuit::new(2);
+        qc.cnot(0, 1);
+
+        let result = ProcessTomography::new(qc.clone())
+            .with_shots(2000)
+            .with_simulator(Simulator::new().with_seed(11))
+            .run();
+
+        assert!((trace(&result.chi).real - 1.0).abs() < 1e-6);
+        assert!(result.average_gate_fidelity(&qc.unitary()) > 0.95);
+    }
+
+    /// Tests that reconstruction rejects incomplete counts.
+    #[test]
+    #[should_panic(expected = "Expected counts for 12 circuits")]
+    fn test_missing_counts() {
+        ProcessTomography::new(QuantumCircuit::new(1)).reconstruct(&[]);
+    }
+}
@@ -0,0 +1,112 @@
+use rusticle::complex::Complex;
+use rusticle::linalg::Matrix;
+
+use intrico::QuantumCircuit;
+use intrico::simulator::{NoiseModel, Simulator};
+use intrico::tomography::{Reconstruction, StateTomography};
+use intrico::utility::hermitian_eigen;
+
+/// Test suite for state tomography.
+/// 
+/// These tests verify the behaviour of:
+/// - Generation of the measurement bases and circuits
+/// - Linear-inversion reconstruction of single-qubit states
+/// - Maximum-likelihood reconstruction of entangled and mixed states
+/// - Ordering of the reconstructed qubits
+mod state_tomography_tests {
+    use super::*;
+
+    /// Returns the largest entry-wise deviation between two matrices
+    fn distance(a: &Matrix<Complex>, b: &[Complex]) -> f64 {
+        let dim = a.rows();
+        (0..dim * dim).map(|k| (*a.get(k / dim, k % dim) - b[k]).norm()).fold(0.0, f64::max)
+    }
+
+    /// Tests the bases and circuits of a two-qubit experiment.
+    #[test]
+    fn test_bases() {
+        let tomography = StateTomography::new(QuantumCircuit::new(3), &[0, 2]);
+        let bases = tomography.bases();
+
+        assert_eq!(bases.len(), 9);
+        assert_eq!(bases[0], "XX");
+        assert_eq!(bases[5], "YZ");
+        assert_eq!(tomography.circuits()[5].num_operations(), 2);
+    }
+
+    /// Tests linear inversion of the |+i⟩ state.
+    #[test]
+    fn test_linear_inversion() {
+        let mut qc = QuantumCircuit::new(1);
+        qc.h(0);
+        qc.s(0);
+
+        let rho = StateTomography::new(qc, &[0])
+            .with_shots(4000)
+            .with_simulator(Simulator::new().with_seed(3))
+            .run();
+
+        let half = Complex::new(0.5, 0.0);
+        let expected = [half, Complex::new(0.0, -0.5), Complex::new(0.0, 0.5), half];
+        assert!(distance(&rho, &expected) < 0.05);
+    }
+
+    /// Tests that maximum likelihood returns a physical Bell state.
+    #[test]
+    fn test_maximum_likelihood_bell_state() {
+        let mut qc = QuantumCircuit::new(2);
+        qc.h(0);
+        qc.cnot(0, 1);
+
+        let rho = StateTomography::new(qc, &[0, 1])
+            .with_simulator(Simulator::new().with_seed(8))
+            .with_method(Reconstruction::MaximumLikelihood)
+            .run();
+
+        let (values, _) = hermitian_eigen(&rho);
+        assert!(values.iter().all(|&v| v > -1e-9));
+        assert!((values.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+
+        let (zero, half) = (Complex::new(0.0, 0.0), Complex::new(0.5, 0.0));
+        let expected = [
+            half, zero, zero, half,
+            zero, zero, zero, zero,
+            zero, zero, zero, zero,
+            half, zero, zero, half,
+        ];
+        assert!(distance(&rho, &expected) < 0.05);
+    }
+
+    /// Tests that depolarizing noise lowers the purity of the reconstruction.
+    #[test]
+    fn test_mixed_state() {
+        let mut qc = QuantumCircuit::new(1);
+        qc.h(0);
+        for _ in 0..10 {
+            qc.x(0);
+        }
+
+        let rho = StateTomography::new(qc, &[0])
+            .with_simulator(Simulator::new().with_noise(NoiseModel::depolarizing(0.1, 0.0)).with_seed(1))
+            .with_method(Reconstruction::MaximumLikelihood)
+            .run();
+
+        let (values, _) = hermitian_eigen(&rho);
+        let purity: f64 = values.iter().map(|v| v * v).sum();
+        assert!(purity < 0.9);
+    }
+
+    /// Tests that `qubits[0]` is the least significant bit of the density matrix.
+    #[test]
+    fn test_qubit_order() {
+        let mut qc = QuantumCircuit::new(3);
+        qc.x(1);
+
+        let rho = StateTomography::new(qc, &[2, 1])
+            .with_shots(200)
+            .with_simulator(Simulator::new().with_seed(6))
+            .with_method(Reconstruction::MaximumLikelihood)
+            .run();
+        assert!((rho.get(2, 2).real - 1.0).abs() < 1e-2);
+    }
+}
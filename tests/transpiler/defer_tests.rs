@@ -0,0 +1,136 @@
+use std::collections::HashMap;
+use intrico::{QuantumCircuit, QuantumGate};
+use intrico::simulator::Simulator;
+use intrico::transpiler::defer_measurements;
+use rusticle::complex::Complex;
+
+/// Test suite for the deferred-measurement pass.
+///
+/// These tests verify the behaviour of:
+/// - Teleportation with its corrections turned into controlled gates
+/// - Classical operations computed onto ancillas, including repeated inputs
+/// - Measurements without dependencies being kept in place
+/// - Rejection of loops
+mod defer_tests {
+    use super::*;
+
+    /// Returns the probability of qubit `qubit` being |1⟩ in a statevector.
+    fn probability_one(state: &[Complex], qubit: usize) -> f64 {
+        state.iter()
+            .enumerate()
+            .filter(|&(idx, _)| idx & (1 << qubit) != 0)
+            .map(|(_, amp)| amp.norm_squared())
+            .sum()
+    }
+
+    /// Returns the largest difference between the frequencies of two sets of counts.
+    fn distance(a: &HashMap<String, usize>, b: &HashMap<String, usize>, shots: usize) -> f64 {
+        a.keys()
+            .chain(b.keys())
+            .map(|key| {
+                let (x, y) = (a.get(key).copied().unwrap_or(0), b.get(key).copied().unwrap_or(0));
+                (x as f64 - y as f64).abs() / shots as f64
+            })
+            .fold(0.0, f64::max)
+    }
+
+    /// Tests that deferred teleportation transfers the state without any condition.
+    #[test]
+    fn test_teleportation() {
+        let mut qc = QuantumCircuit::new(3);
+        qc.ry(0, 0.7);
+        qc.h(1);
+        qc.cnot(1, 2);
+        qc.cnot(0, 1);
+        qc.h(0);
+        qc.measure(0, 0);
+        qc.measure(1, 1);
+        qc.x(2);
+        qc.c_if(1, true);
+        qc.z(2);
+        qc.c_if(0, true);
+
+        let deferred = defer_measurements(&qc);
+        assert_eq!(deferred.num_qubits(), 5);
+        assert!(deferred.operations().iter().all(|op| op.condition.is_none()));
+
+        let state = Simulator::new().with_circuit(deferred).run(0).final_state;
+        assert!((probability_one(&state, 2) - 0.35_f64.sin().powi(2)).abs() < 1e-6);
+    }
+
+    /// Tests that classical operations and inverted conditions keep the outcome distribution.
+    #[test]
+    fn test_classical_operations() {
+        let mut qc = QuantumCircuit::new(4);
+        qc.h(0);
+        qc.h(1);
+        qc.measure(0, 0);
+        qc.measure_x(1, 1);
+        qc.c_xor(0, 1, 2);
+        qc.c_eq(&[0, 1], 0b01, 3);
+        qc.x(2);
+        qc.c_if(2, false);
+        qc.ry(3, 1.1);
+        qc.c_if(3, true);
+        qc.h(0);
+
+        let deferred = defer_measurements(&qc);
+        assert!(deferred.operations().iter().all(|op| op.condition.is_none() && !matches!(op.gate, QuantumGate::Classical(_))));
+
+        let shots = 20000;
+        let original = Simulator::new().with_circuit(qc).with_seed(3).run(shots);
+        let result = Simulator::new().with_circuit(deferred).with_seed(3).run(shots);
+        assert!(distance(&original.counts, &result.marginal_counts(&[0, 1, 2, 3]), shots) < 0.02);
+    }
+
+    /// Tests that classical operations reading the same bit twice are deferred.
+    #[test]
+    fn test_repeated_classical_inputs() {
+        let mut qc = QuantumCircuit::new(4);
+        qc.h(0);
+        qc.measure(0, 0);
+        qc.c_and(0, 0, 1);
+        qc.c_eq(&[0, 0], 0b11, 2);
+        qc.c_eq(&[0, 0], 0b01, 3);
+        qc.x(1);
+        qc.c_if(1, true);
+        qc.x(2);
+        qc.c_if(2, true);
+        qc.x(3);
+        qc.c_if(3, true);
+
+        let deferred = defer_measurements(&qc);
+        let shots = 2000;
+        let original = Simulator::new().with_circuit(qc).with_seed(5).run(shots);
+        let result = Simulator::new().with_circuit(deferred).with_seed(5).run(shots);
+        let counts = result.marginal_counts(&[0, 1, 2, 3]);
+        assert!(counts.keys().all(|bits| bits == "0000" || bits == "0111"));
+        assert!(distance(&original.counts, &counts, shots) < 0.05);
+    }
+
+    /// Tests that circuits without feed-forward are left unchanged.
+    #[test]
+    fn test_terminal_measurements_kept() {
+        let mut qc = QuantumCircuit::new(2);
+        qc.h(0);
+        qc.cnot(0, 1);
+        qc.measure(0, 0);
+        qc.measure(1, 1);
+
+        let deferred = defer_measurements(&qc);
+        assert_eq!(deferred.num_qubits(), 2);
+        assert_eq!(deferred.operations(), qc.operations());
+    }
+
+    /// Tests that loops are rejected.
+    #[test]
+    #[should_panic(expected = "Loops cannot be deferred")]
+    fn test_loop() {
+        let mut body = QuantumCircuit::new(1);
+        body.h(0);
+        body.measure(0, 0);
+        let mut qc = QuantumCircuit::new(1);
+        qc.repeat_until(&body, &[0], 0, true, 5);
+        defer_measurements(&qc);
+    }
+}
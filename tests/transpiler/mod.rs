@@ -0,0 +1,3 @@
+mod defer_tests;
+mod routing_tests;
+mod transpiler_tests;
@@ -0,0 +1,140 @@
+use intrico::{QuantumCircuit, QuantumGate};
+use intrico::device::{DeviceModel, QubitProperties};
+use intrico::simulator::Simulator;
+use intrico::transpiler::{route, RoutingCost};
+
+/// Test suite for routing circuits onto devices.
+///
+/// These tests verify the behaviour of:
+/// - Two-qubit gates acting on coupled qubits only
+/// - Equivalence of the routed circuit up to the final layout
+/// - Placements and SWAP paths avoiding unreliable couplings
+/// - Snapshots spanning the physical register without SWAPs
+/// - Rejection of circuits larger than the device
+mod routing_tests {
+    use super::*;
+
+    fn qubit() -> QubitProperties {
+        QubitProperties { t1: 100_000.0, t2: 80_000.0, readout_error: 0.01 }
+    }
+
+    /// Builds a ring of `n` qubits with the given CX error on every coupling, except
+    /// for the listed unreliable ones.
+    fn ring(n: usize, error: f64, unreliable: &[(usize, usize, f64)]) -> DeviceModel {
+        let mut device = DeviceModel::new("ring", vec![qubit(); n]).with_gate("CX", &[], error, 300.0);
+        for qubit in 0..n {
+            device = device.with_coupling(qubit, (qubit + 1) % n);
+        }
+        for &(a, b, error) in unreliable {
+            device = device.with_gate("CX", &[a, b], error, 300.0);
+        }
+        device
+    }
+
+    /// Tests that snapshots are carried over to every physical qubit without SWAPs.
+    #[test]
+    fn test_snapshots() {
+        let device = ring(4, 0.01, &[]);
+        let mut qc = QuantumCircuit::new(3);
+        qc.h(0);
+        qc.snapshot("middle");
+        qc.cnot(0, 1);
+
+        let routed = route(&qc, &device, RoutingCost::SwapCount);
+        let snapshot = routed.circuit.operations().iter()
+            .find(|op| matches!(op.gate, QuantumGate::Snapshot(_)))
+            .unwrap();
+        assert_eq!(snapshot.qubit, vec![0, 1, 2, 3]);
+        assert_eq!(routed.num_swaps, 0);
+
+        let mut pair = QuantumCircuit::new(2);
+        pair.h(0);
+        pair.snapshot("start");
+        pair.h(1);
+        let routed = route(&pair, &DeviceModel::new("line", vec![qubit(); 3]).with_coupling(0, 1).with_coupling(1, 2), RoutingCost::SwapCount);
+        assert_eq!(routed.num_swaps, 0);
+    }
+
+    /// Tests that the routed circuit respects the connectivity and prepares the same state.
+    #[test]
+    fn test_routed_equivalence() {
+        let device = DeviceModel::new("line", vec![qubit(); 5])
+            .with_coupling(0, 1)
+            .with_coupling(1, 2)
+            .with_coupling(2, 3)
+            .with_coupling(3, 4);
+        let mut qc = QuantumCircuit::new(5);
+        qc.h(0);
+        qc.ry(3, 0.4);
+        qc.cnot(0, 4);
+        qc.cnot(3, 1);
+        qc.cz(4, 2);
+        qc.ccx(0, 2, 3);
+        qc.rx(1, 1.3);
+
+        for cost in [RoutingCost::SwapCount, RoutingCost::Error] {
+            let routed = route(&qc, &device, cost);
+            assert!(routed.num_swaps > 0);
+            for op in routed.circuit.operations().iter().filter(|op| op.qubit.len() == 2) {
+                assert!(device.are_connected(op.qubit[0], op.qubit[1]));
+            }
+
+            let expected = Simulator::new().with_circuit(qc.clone()).run(0).final_state;
+            let actual = Simulator::new().with_circuit(routed.circuit).run(0).final_state;
+            for (index, amplitude) in expected.iter().enumerate() {
+                let physical: usize = routed.final_layout.iter()
+                    .enumerate()
+                    .filter(|&(logical, _)| index & (1 << logical) != 0)
+                    .map(|(_, &physical)| 1 << physical)
+                    .sum();
+                assert!((*amplitude - actual[physical]).norm() < 1e-6);
+            }
+        }
+    }
+
+    /// Tests that error-weighted routing avoids unreliable couplings that SWAP counting uses.
+    #[test]
+    fn test_avoids_unreliable_couplings() {
+        let device = ring(6, 0.01, &[(0, 1, 0.3), (1, 2, 0.3)]);
+        let mut qc = QuantumCircuit::new(3);
+        qc.h(0);
+        qc.cnot(0, 1);
+        qc.cnot(1, 2);
+        qc.cnot(0, 2);
+        qc.measure(2, 0);
+
+        let by_swaps = route(&qc, &device, RoutingCost::SwapCount);
+        let by_error = route(&qc, &device, RoutingCost::Error);
+        for op in by_error.circuit.operations().iter().filter(|op| op.qubit.len() == 2) {
+            assert!(!op.qubit.contains(&1));
+        }
+        assert!(device.estimated_fidelity(&by_error.circuit) > device.estimated_fidelity(&by_swaps.circuit));
+    }
+
+    /// Tests that inserted SWAPs stay off an unreliable coupling.
+    #[test]
+    fn test_reliable_swap_path() {
+        // A chain filling the ring, closed into a cycle of four qubits that the ring cannot
+        // hold, so SWAPs are needed, and there is a reliable way around
+        let device = ring(6, 0.01, &[(1, 2, 0.3)]);
+        let mut qc = QuantumCircuit::new(6);
+        for qubit in 0..5 {
+            qc.cnot(qubit, qubit + 1);
+        }
+        qc.cnot(0, 3);
+
+        let routed = route(&qc, &device, RoutingCost::Error);
+        assert!(routed.num_swaps > 0);
+        let unreliable = routed.circuit.operations().iter()
+            .filter(|op| op.qubit == [1, 2] || op.qubit == [2, 1])
+            .count();
+        assert_eq!(unreliable, 0);
+    }
+
+    /// Tests that circuits larger than the device are rejected.
+    #[test]
+    #[should_panic(expected = "does not fit on device")]
+    fn test_too_many_qubits() {
+        route(&QuantumCircuit::new(7), &ring(6, 0.01, &[]), RoutingCost::Error);
+    }
+}
@@ -0,0 +1,207 @@
+use intrico::{QuantumCircuit, QuantumGate};
+use intrico::simulator::Simulator;
+use intrico::transpiler::{transpile, zyz_decomposition, TranspileOptions};
+use intrico::utility::state_fidelity;
+use rusticle::complex::Complex;
+use rusticle::linalg::Matrix;
+
+/// Test suite for circuit transpilation.
+/// 
+/// These tests verify the behaviour of the transpiler, including:
+/// - Toffoli expansion
+/// - Synthesis of single-qubit custom gates
+/// - Expansion of composite gates
+/// - Cancellation of inverse pairs and merging of rotations
+/// - The pre-run transpilation hook of the simulator
+mod transpiler_tests {
+    use super::*;
+
+    fn final_state(qc: QuantumCircuit) -> Vec<Complex> {
+        Simulator::new().with_circuit(qc).run(0).final_state
+    }
+
+    /// Tests that the Toffoli expansion matches the Toffoli gate on every basis input.
+    #[test]
+    fn test_toffoli_expansion() {
+        let options = TranspileOptions { cancel_inverses: false, merge_rotations: false, ..Default::default() };
+
+        for input in 0..8 {
+            let mut qc = QuantumCircuit::new(3);
+            for qubit in 0..3 {
+                if input >> qubit & 1 == 1 {
+                    qc.x(qubit);
+                }
+            }
+            qc.ccx(0, 1, 2);
+
+            let transpiled = transpile(&qc, &options);
+            assert!(transpiled.operations().iter().all(|op| op.gate != QuantumGate::Toffoli));
+
+            let fidelity = state_fidelity(&final_state(qc), &final_state(transpiled));
+            assert!((fidelity - 1.0).abs() < 1e-6, "input {:03b} has fidelity {}", input, fidelity);
+        }
+    }
+
+    /// Tests that single-qubit custom gates are replaced by equivalent rotations.
+    #[test]
+    fn test_custom_gate_synthesis() {
+        let h = 1.0 / 2.0_f64.sqrt();
+        let matrix = Matrix::new(2, 2, vec![
+            Complex::new(h, 0.0), Complex::new(0.0, h),
+            Complex::new(0.0, h), Complex::new(h, 0.0),
+        ]);
+
+        let mut qc = QuantumCircuit::new(1);
+        qc.ry(0, 0.3);
+        qc.add_gate(QuantumGate::Custom(matrix, "SqrtX".to_string(), "√X".to_string()), 0);
+
+        let transpiled = transpile(&qc, &TranspileOptions::default());
+        assert!(transpiled.operations().iter()
+            .all(|op| matches!(op.gate, QuantumGate::Ry(_) | QuantumGate::Rz(_))));
+
+        let fidelity = state_fidelity(&final_state(qc), &final_state(transpiled));
+        assert!((fidelity - 1.0).abs() < 1e-6);
+    }
+
+    /// Tests that composite gates are expanded, including the gates of their definition.
+    #[test]
+    fn test_composite_expansion() {
+        let mut definition = QuantumCircuit::new(3);
+        definition.h(0);
+        definition.ccx(0, 1, 2);
+
+        let mut qc = QuantumCircuit::new(3);
+        qc.x(1);
+        qc.define_gate("block", definition);
+        qc.call_gate("block", &[2, 1, 0]);
+
+        let kept = transpile(&qc, &TranspileOptions { expand_composite: false, ..Default::default() });
+        assert!(matches!(kept[1].gate, QuantumGate::Composite { .. }));
+
+        let transpiled = transpile(&qc, &TranspileOptions::default());
+        assert!(transpiled.operations().iter()
+            .all(|op| !matches!(op.gate, QuantumGate::Composite { .. } | QuantumGate::Toffoli)));
+
+        let fidelity = state_fidelity(&final_state(qc), &final_state(transpiled));
+        assert!((fidelity - 1.0).abs() < 1e-6);
+    }
+
+    /// Tests that the ZYZ decomposition reproduces standard gates up to a global phase.
+    #[test]
+    fn test_zyz_decomposition() {
+        for gate in [QuantumGate::H, QuantumGate::X, QuantumGate::Y, QuantumGate::S, QuantumGate::T] {
+            let mut original = QuantumCircuit::new(1);
+            original.ry(0, 0.7);
+            original.add_gate(gate.clone(), 0);
+
+            let mut synthesized = QuantumCircuit::new(1);
+            synthesized.ry(0, 0.7);
+            for rotation in zyz_decomposition(&gate.matrix()) {
+                synthesized.add_gate(rotation, 0);
+            }
+
+            let fidelity = state_fidelity(&final_state(original), &final_state(synthesized));
+            assert!((fidelity - 1.0).abs() < 1e-6, "{} has fidelity {}", gate.name(), fidelity);
+        }
+    }
+
+    /// Tests that adjacent inverse gates cancel while separated ones are kept.
+    #[test]
+    fn test_cancel_inverses() {
+        let mut qc = QuantumCircuit::new(2);
+        qc.h(0);
+        qc.s(1);
+        qc.h(0);
+        qc.sdg(1);
+        qc.cnot(0, 1);
+        qc.x(1);
+        qc.cnot(0, 1);
+
+        let transpiled = transpile(&qc, &TranspileOptions::default());
+        let gates: Vec<QuantumGate> = transpiled.operations().iter().map(|op| op.gate.clone()).collect();
+        assert_eq!(gates, vec![QuantumGate::CNOT, QuantumGate::X, QuantumGate::CNOT]);
+    }
+
+    /// Tests that adjacent rotations around the same axis are merged.
+    #[test]
+    fn test_merge_rotations() {
+        let mut qc = QuantumCircuit::new(1);
+        qc.rz(0, 0.25);
+        qc.rz(0, 0.5);
+        qc.rx(0, 1.0);
+        qc.rx(0, -1.0);
+
+        let transpiled = transpile(&qc, &TranspileOptions::default());
+        assert_eq!(transpiled.num_operations(), 1);
+        assert_eq!(transpiled.operations()[0].gate, QuantumGate::Rz(0.75));
+
+        let mut givens = QuantumCircuit::new(2);
+        givens.givens(0, 1, 0.25);
+        givens.givens(0, 1, 0.5);
+        givens.givens(1, 0, 0.5);
+        let transpiled = transpile(&givens, &TranspileOptions::default());
+        assert_eq!(transpiled.num_operations(), 2);
+        assert_eq!(transpiled.operations()[0].gate, QuantumGate::Givens(0.75));
+
+        // Controlled rotations only cancel once the angles add up to 4π
+        let mut controlled = QuantumCircuit::new(2);
+        controlled.crz(0, 1, std::f64::consts::PI);
+        controlled.crz(0, 1, std::f64::consts::PI);
+        assert_eq!(transpile(&controlled, &TranspileOptions::default()).num_operations(), 1);
+        controlled.crz(0, 1, 2.0 * std::f64::consts::PI);
+        assert_eq!(transpile(&controlled, &TranspileOptions::default()).num_operations(), 0);
+
+        let mut diagonals = QuantumCircuit::new(2);
+        diagonals.diagonal(&[0, 1], &[0.0, 0.25, 0.5, 1.0]);
+        diagonals.diagonal(&[0, 1], &[1.0, 0.25, 0.0, 1.0]);
+        let transpiled = transpile(&diagonals, &TranspileOptions::default());
+        assert_eq!(transpiled.num_operations(), 1);
+        assert_eq!(transpiled.operations()[0].gate, QuantumGate::Diagonal(vec![1.0, 0.5, 0.5, 2.0]));
+    }
+
+    /// Tests that measurements act as barriers for the optimization passes.
+    #[test]
+    fn test_measurement_barrier() {
+        let mut qc = QuantumCircuit::new(1);
+        qc.h(0);
+        qc.measure(0, 0);
+        qc.h(0);
+
+        let transpiled = transpile(&qc, &TranspileOptions::default());
+        assert_eq!(transpiled.num_operations(), 3);
+    }
+
+    /// Tests that conditioned operations keep their condition and are never merged.
+    #[test]
+    fn test_conditioned_operations() {
+        let mut qc = QuantumCircuit::new(3);
+        qc.measure(0, 0);
+        qc.x(1);
+        qc.c_if(0, true);
+        qc.x(1);
+        qc.ccx(0, 1, 2);
+        qc.c_if(0, true);
+
+        let transpiled = transpile(&qc, &TranspileOptions::default());
+        assert_eq!(transpiled.num_operations(), 18);
+        assert_eq!(transpiled.operations().iter().filter(|op| op.condition == Some((0, true))).count(), 16);
+    }
+
+    /// Tests that the simulator transpiles circuits before running them.
+    #[test]
+    fn test_simulator_hook() {
+        let mut qc = QuantumCircuit::new(3);
+        qc.x(0);
+        qc.x(1);
+        qc.ccx(0, 1, 2);
+
+        let result = Simulator::new()
+            .with_circuit(qc)
+            .with_transpile(TranspileOptions::default())
+            .run(50);
+
+        assert_eq!(result.counts["111"], 50);
+        assert_eq!(result.metrics.gate_counts.get("Toffoli"), None);
+        assert_eq!(result.metrics.gate_counts["CNOT"], 6);
+    }
+}
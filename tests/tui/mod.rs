@@ -0,0 +1,2 @@
+#[cfg(feature = "tui")]
+mod tui_tests;
\ No newline at end of file
@@ -0,0 +1,97 @@
+use intrico::QuantumCircuit;
+use intrico::tui::Explorer;
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+use ratatui::buffer::Buffer;
+use ratatui::crossterm::event::KeyCode;
+use ratatui::style::Color;
+
+/// Test suite for the terminal circuit explorer.
+///
+/// These tests verify the behaviour of:
+/// - Statevectors recorded after every layer
+/// - Navigation between steps with the keyboard
+/// - Rendering of the diagram, the highlighted column and the state table
+mod tui_tests {
+    use super::*;
+
+    /// Builds a Bell circuit followed by a measurement.
+    fn bell() -> QuantumCircuit {
+        let mut qc = QuantumCircuit::new(2);
+        qc.h(0);
+        qc.cnot(0, 1);
+        qc.measure(0, 0);
+        qc
+    }
+
+    /// Renders the explorer on an in-memory terminal and returns the buffer.
+    fn render(explorer: &Explorer) -> Buffer {
+        let mut terminal = Terminal::new(TestBackend::new(60, 16)).unwrap();
+        terminal.draw(|frame| explorer.render(frame)).unwrap();
+        terminal.backend().buffer().clone()
+    }
+
+    /// Returns the text of one line of a buffer.
+    fn line(buffer: &Buffer, y: u16) -> String {
+        (0..buffer.area.width).map(|x| buffer[(x, y)].symbol()).collect()
+    }
+
+    /// Tests the statevector after each step.
+    #[test]
+    fn test_explorer_states() {
+        let mut explorer = Explorer::new(&bell());
+        assert_eq!(explorer.num_steps(), 3);
+        assert_eq!(explorer.state().probabilities(), vec![1.0, 0.0, 0.0, 0.0]);
+
+        explorer.next();
+        let probabilities = explorer.state().probabilities();
+        assert!((probabilities[0] - 0.5).abs() < 1e-6 && (probabilities[1] - 0.5).abs() < 1e-6);
+
+        explorer.go_to(10);
+        assert_eq!(explorer.step(), 3);
+        assert_eq!(explorer.state(), &bell().execute());
+    }
+
+    /// Tests the key bindings.
+    #[test]
+    fn test_explorer_keys() {
+        let mut explorer = Explorer::new(&bell());
+        assert!(explorer.handle_key(KeyCode::Right));
+        assert!(explorer.handle_key(KeyCode::Char('l')));
+        assert_eq!(explorer.step(), 2);
+        assert!(explorer.handle_key(KeyCode::Left));
+        assert_eq!(explorer.step(), 1);
+        assert!(explorer.handle_key(KeyCode::End));
+        assert_eq!(explorer.step(), 3);
+        assert!(explorer.handle_key(KeyCode::Home));
+        assert_eq!(explorer.step(), 0);
+        assert!(explorer.handle_key(KeyCode::Left));
+        assert_eq!(explorer.step(), 0);
+        assert!(!explorer.handle_key(KeyCode::Char('q')));
+        assert!(!explorer.handle_key(KeyCode::Esc));
+    }
+
+    /// Tests that the diagram and the state table are drawn with the current column highlighted.
+    #[test]
+    fn test_explorer_render() {
+        let mut explorer = Explorer::new(&bell());
+        explorer.next();
+        let buffer = render(&explorer);
+
+        assert!(line(&buffer, 0).contains("step 1/3"));
+        let wire = line(&buffer, 1);
+        assert!(wire.contains("q0: ─H──●──[M]─"), "{}", wire);
+        let h = wire.find('H').unwrap();
+        let column = wire[..h].chars().count() as u16;
+        assert_eq!(buffer[(column, 1)].bg, Color::Yellow);
+
+        let table: Vec<String> = (5..10).map(|y| line(&buffer, y)).collect();
+        assert!(table.iter().any(|row| row.contains("|00⟩")));
+        assert!(table.iter().any(|row| row.contains("|01⟩")));
+        assert!(!table.iter().any(|row| row.contains("|10⟩")));
+
+        explorer.toggle_all_states();
+        let all = render(&explorer);
+        assert!((5..12).any(|y| line(&all, y).contains("|10⟩")));
+    }
+}
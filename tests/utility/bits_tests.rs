@@ -0,0 +1,58 @@
+use intrico::QuantumCircuit;
+use intrico::simulator::Simulator;
+use intrico::utility::{bitstring_to_index, index_to_bitstring, Endianness};
+
+/// Test suite for bitstring conversions.
+/// 
+/// These tests verify the behaviour of:
+/// - Formatting of indices in both bit orders
+/// - Parsing of bitstrings and rejection of invalid ones
+/// - Agreement with the keys of simulated counts
+mod bits_tests {
+    use super::*;
+
+    /// Tests that formatting and parsing round-trip in both orders.
+    #[test]
+    fn test_round_trip() {
+        for endianness in [Endianness::Little, Endianness::Big] {
+            for index in 0..32 {
+                let bitstring = index_to_bitstring(index, 5, endianness);
+                assert_eq!(bitstring.len(), 5);
+                assert_eq!(bitstring_to_index(&bitstring, endianness), Some(index));
+            }
+        }
+        assert_eq!(Endianness::default(), Endianness::Little);
+    }
+
+    /// Tests the position of qubit 0 and truncation to the requested width.
+    #[test]
+    fn test_bit_order() {
+        assert_eq!(index_to_bitstring(1, 3, Endianness::Little), "001");
+        assert_eq!(index_to_bitstring(1, 3, Endianness::Big), "100");
+        assert_eq!(index_to_bitstring(9, 3, Endianness::Little), "001");
+        assert_eq!(index_to_bitstring(0, 0, Endianness::Little), "");
+    }
+
+    /// Tests parsing of edge cases.
+    #[test]
+    fn test_parse() {
+        assert_eq!(bitstring_to_index("", Endianness::Little), Some(0));
+        assert_eq!(bitstring_to_index("10", Endianness::Little), Some(2));
+        assert_eq!(bitstring_to_index("10", Endianness::Big), Some(1));
+        assert_eq!(bitstring_to_index("1x", Endianness::Little), None);
+        assert_eq!(bitstring_to_index(&"1".repeat(65), Endianness::Little), None);
+        assert_eq!(bitstring_to_index(&format!("0{}", "1".repeat(64)), Endianness::Little), Some(usize::MAX));
+    }
+
+    /// Tests that count keys follow the little-endian order.
+    #[test]
+    fn test_counts_keys() {
+        let mut qc = QuantumCircuit::new(3);
+        qc.x(0);
+        qc.x(2);
+
+        let result = Simulator::new().with_circuit(qc).run(10);
+        let key = index_to_bitstring(0b101, 3, Endianness::Little);
+        assert_eq!(result.counts[&key], 10);
+    }
+}
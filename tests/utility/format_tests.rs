@@ -0,0 +1,58 @@
+use rusticle::complex::Complex;
+use intrico::utility::{ComplexFormat, Notation};
+
+/// Test suite for complex-number formatting.
+/// 
+/// These tests verify the behaviour of:
+/// - Cartesian and polar notation
+/// - Suppression of negligible parts and trailing zeros
+/// - Statevector rendering in Dirac notation
+mod format_tests {
+    use super::*;
+
+    /// Tests cartesian notation with suppression.
+    #[test]
+    fn test_cartesian() {
+        let format = ComplexFormat::default();
+        assert_eq!(format.format(Complex::new(0.0, 0.0)), "0");
+        assert_eq!(format.format(Complex::new(1.0, 1e-12)), "1");
+        assert_eq!(format.format(Complex::new(-1e-12, -0.25)), "-0.25i");
+        assert_eq!(format.format(Complex::new(0.0, 1.0)), "i");
+        assert_eq!(format.format(Complex::new(0.0, -1.0)), "-i");
+        assert_eq!(format.format(Complex::new(-0.123456, 1.0)), "-0.1235+i");
+        assert_eq!(format.with_precision(2).format(Complex::new(0.001, 0.5)), "0.5i");
+    }
+
+    /// Tests cartesian notation without suppression.
+    #[test]
+    fn test_unsuppressed() {
+        let format = ComplexFormat::DEFAULT.with_suppression(false).with_precision(2);
+        assert_eq!(format.format(Complex::new(1.0, 0.0)), "1.00+0.00i");
+        assert_eq!(format.format(Complex::new(-1e-12, -0.5)), "0.00-0.50i");
+        assert_eq!(format.format_state(&[Complex::new(1.0, 0.0), Complex::new(0.0, 0.0)]), "(1.00+0.00i)|0⟩ + (0.00+0.00i)|1⟩");
+    }
+
+    /// Tests polar notation.
+    #[test]
+    fn test_polar() {
+        let format = ComplexFormat::DEFAULT.with_notation(Notation::Polar).with_precision(3);
+        assert_eq!(format.notation(), Notation::Polar);
+        assert_eq!(format.format(Complex::new(2.0, 0.0)), "2");
+        assert_eq!(format.format(Complex::new(0.0, 1.0)), "1∠1.571");
+        assert_eq!(format.format(Complex::new(1e-9, 1e-9)), "0");
+        assert_eq!(format.format_state(&[Complex::new(0.0, 0.0), Complex::new(0.0, -1.0)]), "(1∠-1.571)|1⟩");
+    }
+
+    /// Tests statevector rendering and elision of terms.
+    #[test]
+    fn test_format_state() {
+        let format = ComplexFormat::DEFAULT;
+        let half = Complex::new(0.5, 0.0);
+        let state = vec![half, Complex::new(0.0, -0.5), Complex::new(-0.5, 0.0), Complex::new(0.25, 0.25)];
+
+        assert_eq!(format.format_state(&state), "0.5|00⟩ - 0.5i|01⟩ - 0.5|10⟩ + (0.25+0.25i)|11⟩");
+        assert_eq!(format.with_max_terms(Some(2)).format_state(&state), "0.5|00⟩ - 0.5i|01⟩ + …");
+        assert_eq!(format.format_state(&[Complex::new(0.0, 0.0); 2]), "0");
+        assert_eq!(format.format_state(&[Complex::new(1.0, 0.0)]), "|⟩");
+    }
+}
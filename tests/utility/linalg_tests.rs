@@ -0,0 +1,75 @@
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use rusticle::complex::Complex;
+use rusticle::linalg::Matrix;
+use intrico::utility::{complete_unitary, random_state, random_unitary};
+
+/// Test suite for unitary completion.
+/// 
+/// These tests verify the behaviour of:
+/// - Preservation of the given columns
+/// - Unitarity of the completed matrix
+/// - Rejection of invalid columns
+mod linalg_tests {
+    use super::*;
+
+    fn assert_unitary(matrix: &Matrix<Complex>) {
+        let product = &matrix.conjugate_transpose() * matrix;
+        for r in 0..matrix.rows() {
+            for c in 0..matrix.cols() {
+                let expected = if r == c { 1.0 } else { 0.0 };
+                assert!((*product.get(r, c) - Complex::new(expected, 0.0)).norm() < 1e-10);
+            }
+        }
+    }
+
+    /// Tests that a random state is completed to a unitary with it as first column.
+    #[test]
+    fn test_single_column() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let state = random_state(3, &mut rng);
+        let unitary = complete_unitary(std::slice::from_ref(&state));
+
+        assert_eq!((unitary.rows(), unitary.cols()), (8, 8));
+        assert_unitary(&unitary);
+        for (r, &amp) in state.iter().enumerate() {
+            assert!((*unitary.get(r, 0) - amp).norm() < 1e-12);
+        }
+    }
+
+    /// Tests completion from several columns, including a full set.
+    #[test]
+    fn test_several_columns() {
+        let mut rng = StdRng::seed_from_u64(5);
+        let source = random_unitary(4, &mut rng);
+        let column = |c: usize| -> Vec<Complex> { (0..4).map(|r| *source.get(r, c)).collect() };
+
+        for count in 1..=4 {
+            let columns: Vec<Vec<Complex>> = (0..count).map(column).collect();
+            let unitary = complete_unitary(&columns);
+            assert_unitary(&unitary);
+            for (c, expected) in columns.iter().enumerate() {
+                for (r, &amp) in expected.iter().enumerate() {
+                    assert!((*unitary.get(r, c) - amp).norm() < 1e-12);
+                }
+            }
+        }
+    }
+
+    /// Tests that columns which are not orthonormal are rejected.
+    #[test]
+    #[should_panic(expected = "Columns 0 and 1 are not orthonormal")]
+    fn test_non_orthogonal_columns() {
+        let h = 1.0 / 2.0_f64.sqrt();
+        let zero = vec![Complex::new(1.0, 0.0), Complex::new(0.0, 0.0)];
+        let plus = vec![Complex::new(h, 0.0), Complex::new(h, 0.0)];
+        complete_unitary(&[zero, plus]);
+    }
+
+    /// Tests that an empty set of columns is rejected.
+    #[test]
+    #[should_panic(expected = "Unitary completion requires at least one column")]
+    fn test_no_columns() {
+        complete_unitary(&[]);
+    }
+}
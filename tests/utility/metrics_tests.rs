@@ -0,0 +1,94 @@
+use rusticle::complex::Complex;
+use rusticle::linalg::Matrix;
+use intrico::QuantumCircuit;
+use intrico::utility::{bures_distance, fidelity, trace_distance, QuantumState};
+
+/// Test suite for state distance metrics.
+/// 
+/// These tests verify the behaviour of:
+/// - Fidelity between pure, mixed and mixed-with-pure states
+/// - Trace and Bures distances
+/// - Consistency between statevector and density-matrix inputs
+mod metrics_tests {
+    use super::*;
+
+    /// Returns `p·|ψ⟩⟨ψ| + (1 - p)·I/d`
+    fn depolarized(psi: &[Complex], p: f64) -> Matrix<Complex> {
+        let dim = psi.len();
+        let pure = psi.density_matrix();
+        let mut rho = Matrix::zeros(dim, dim);
+        for r in 0..dim {
+            for c in 0..dim {
+                let noise = if r == c { (1.0 - p) / dim as f64 } else { 0.0 };
+                rho.set(r, c, *pure.get(r, c) * p + Complex::new(noise, 0.0));
+            }
+        }
+        rho
+    }
+
+    fn bell() -> Vec<Complex> {
+        let h = Complex::new(1.0 / 2.0_f64.sqrt(), 0.0);
+        let zero = Complex::new(0.0, 0.0);
+        vec![h, zero, zero, h]
+    }
+
+    fn product() -> Vec<Complex> {
+        let mut qc = QuantumCircuit::new(2);
+        qc.ry(0, 0.6);
+        qc.rx(1, 1.3);
+        qc.execute().into_vec()
+    }
+
+    /// Tests that statevector and density-matrix inputs agree.
+    #[test]
+    fn test_pure_states() {
+        let (a, b) = (bell(), product());
+        let (rho, sigma) = (a.density_matrix(), b.density_matrix());
+
+        let expected = fidelity(&a, &b);
+        assert!((fidelity(&rho, &sigma) - expected).abs() < 1e-6);
+        assert!((fidelity(&a, &sigma) - expected).abs() < 1e-9);
+        assert!((fidelity(&rho, &b) - expected).abs() < 1e-9);
+
+        let distance = trace_distance(&a, &b);
+        assert!((trace_distance(&rho, &sigma) - distance).abs() < 1e-6);
+        assert!((distance - (1.0 - expected).sqrt()).abs() < 1e-9);
+        assert!((fidelity(a.as_slice(), &a) - 1.0).abs() < 1e-6);
+    }
+
+    /// Tests the metrics of depolarized Bell states.
+    #[test]
+    fn test_mixed_states() {
+        let psi = bell();
+        let rho = depolarized(&psi, 0.6);
+
+        // ⟨Φ|ρ|Φ⟩ = 0.6 + 0.4/4
+        assert!((fidelity(&psi, &rho) - 0.7).abs() < 1e-6);
+        assert!((fidelity(&rho, &psi.density_matrix()) - 0.7).abs() < 1e-6);
+        // ρ - |Φ⟩⟨Φ| has eigenvalues -0.3 and 0.1 three times
+        assert!((trace_distance(&psi, &rho) - 0.3).abs() < 1e-6);
+        assert!((bures_distance(&psi, &rho) - (2.0 * (1.0 - 0.7_f64.sqrt())).sqrt()).abs() < 1e-6);
+        assert!(bures_distance(&rho, &rho) < 1e-6);
+    }
+
+    /// Tests symmetry and the Fuchs-van de Graaf inequalities on mixed states.
+    #[test]
+    fn test_inequalities() {
+        let rho = depolarized(&bell(), 0.8);
+        let sigma = depolarized(&product(), 0.5);
+
+        let f = fidelity(&rho, &sigma);
+        let d = trace_distance(&rho, &sigma);
+        assert!((f - fidelity(&sigma, &rho)).abs() < 1e-6);
+        assert!((d - trace_distance(&sigma, &rho)).abs() < 1e-9);
+        assert!(1.0 - f.sqrt() <= d + 1e-9);
+        assert!(d <= (1.0 - f).sqrt() + 1e-9);
+    }
+
+    /// Tests that states of different dimensions are rejected.
+    #[test]
+    #[should_panic(expected = "State dimensions do not match: 4 and 2")]
+    fn test_dimension_mismatch() {
+        fidelity(&bell(), &vec![Complex::new(1.0, 0.0), Complex::new(0.0, 0.0)]);
+    }
+}
@@ -0,0 +1,6 @@
+mod bits_tests;
+mod format_tests;
+mod linalg_tests;
+mod metrics_tests;
+mod random_tests;
+mod tolerance_tests;
\ No newline at end of file
@@ -0,0 +1,82 @@
+use rand::{SeedableRng, rngs::StdRng};
+use rusticle::complex::Complex;
+use intrico::utility::{fidelity, random_state, random_unitary};
+
+/// Test suite for random state and unitary generation.
+/// 
+/// These tests verify the behaviour of:
+/// - Unitarity and reproducibility of random unitaries
+/// - Haar statistics of random unitaries
+/// - Normalization and Haar statistics of random states
+mod random_tests {
+    use super::*;
+
+    /// Tests that the generated matrices are unitary and reproducible from a seed.
+    #[test]
+    fn test_unitarity() {
+        let mut rng = StdRng::seed_from_u64(1);
+        for dim in [1, 2, 3, 8] {
+            let u = random_unitary(dim, &mut rng);
+            let product = &u.conjugate_transpose() * &u;
+            for r in 0..dim {
+                for c in 0..dim {
+                    let expected = if r == c { 1.0 } else { 0.0 };
+                    assert!((*product.get(r, c) - Complex::new(expected, 0.0)).norm() < 1e-10);
+                }
+            }
+        }
+
+        let a = random_unitary(4, &mut StdRng::seed_from_u64(9));
+        let b = random_unitary(4, &mut StdRng::seed_from_u64(9));
+        assert_eq!(a, b);
+    }
+
+    /// Tests Haar moments: E|U₀₀|² = 1/d and E|tr U|² = 1.
+    #[test]
+    fn test_haar_moments() {
+        let mut rng = StdRng::seed_from_u64(5);
+        let (dim, samples) = (4, 4000);
+        let (mut entry, mut trace) = (0.0, 0.0);
+        for _ in 0..samples {
+            let u = random_unitary(dim, &mut rng);
+            entry += u.get(0, 0).norm_squared();
+            trace += (0..dim).fold(Complex::new(0.0, 0.0), |acc, k| acc + *u.get(k, k)).norm_squared();
+        }
+
+        assert!((entry / samples as f64 - 0.25).abs() < 0.02);
+        assert!((trace / samples as f64 - 1.0).abs() < 0.1);
+    }
+
+    /// Tests that random states are normalized and reproducible from a seed.
+    #[test]
+    fn test_random_state() {
+        let mut rng = StdRng::seed_from_u64(2);
+        for num_qubits in 0..5 {
+            let state = random_state(num_qubits, &mut rng);
+            assert_eq!(state.len(), 1 << num_qubits);
+            let norm: f64 = state.iter().map(|amp| amp.norm_squared()).sum();
+            assert!((norm - 1.0).abs() < 1e-12);
+        }
+
+        assert_eq!(random_state(3, &mut StdRng::seed_from_u64(4)), random_state(3, &mut StdRng::seed_from_u64(4)));
+    }
+
+    /// Tests that the overlap of independent states averages 1/d.
+    #[test]
+    fn test_state_overlaps() {
+        let mut rng = StdRng::seed_from_u64(8);
+        let samples = 4000;
+        let mean = (0..samples)
+            .map(|_| fidelity(&random_state(3, &mut rng), &random_state(3, &mut rng)))
+            .sum::<f64>() / samples as f64;
+
+        assert!((mean - 0.125).abs() < 0.01);
+    }
+
+    /// Tests that a zero dimension is rejected.
+    #[test]
+    #[should_panic(expected = "Unitary dimension must be positive")]
+    fn test_zero_dimension() {
+        random_unitary(0, &mut StdRng::seed_from_u64(0));
+    }
+}
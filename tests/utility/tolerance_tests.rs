@@ -0,0 +1,72 @@
+use rusticle::complex::Complex;
+use intrico::QuantumCircuit;
+use intrico::simulator::Simulator;
+use intrico::utility::{round_if_close, Tolerance};
+
+/// Test suite for the numeric tolerance policy.
+/// 
+/// These tests verify the behaviour of:
+/// - Snapping to 0, ±0.5 and ±1 and decimal rounding
+/// - Disabling either step or both
+/// - The legacy `round_if_close` helper
+/// - Per-simulator tolerance overrides
+mod tolerance_tests {
+    use super::*;
+
+    /// Tests the default policy.
+    #[test]
+    fn test_default() {
+        let tolerance = Tolerance::default();
+
+        assert_eq!(tolerance, Tolerance::DEFAULT);
+        assert_eq!(tolerance.round(-1.0 + 5e-11), -1.0);
+        assert_eq!(tolerance.round(-0.5 - 5e-11), -0.5);
+        assert_eq!(tolerance.round(3e-11), 0.0);
+        assert_eq!(tolerance.round(0.123456789), 0.12345679);
+    }
+
+    /// Tests that snapping and rounding can be configured and disabled.
+    #[test]
+    fn test_configuration() {
+        let coarse = Tolerance::DEFAULT.with_snap(Some(1e-3)).with_decimals(Some(2));
+        assert_eq!(coarse.snap(), Some(1e-3));
+        assert_eq!(coarse.decimals(), Some(2));
+        assert_eq!(coarse.round(0.4995), 0.5);
+        assert_eq!(coarse.round(0.123), 0.12);
+
+        let snap_only = Tolerance::DEFAULT.with_decimals(None);
+        assert_eq!(snap_only.round(0.4999999999), 0.4999999999);
+        assert_eq!(snap_only.round(0.49999999999), 0.5);
+
+        let digits_only = Tolerance::DEFAULT.with_snap(None);
+        assert_eq!(digits_only.round(1e-12), 0.0);
+        assert_eq!(digits_only.round(0.500000004), 0.5);
+
+        assert_eq!(Tolerance::EXACT.round(1e-12), 1e-12);
+        let state = [Complex::new(1e-12, 0.5 + 1e-12)];
+        assert_eq!(Tolerance::EXACT.round_state(&state), state.to_vec());
+    }
+
+    /// Tests that the legacy helper keeps its behaviour.
+    #[test]
+    fn test_round_if_close() {
+        assert_eq!(round_if_close(0.5 + 1e-11, 1e-10), 0.5);
+        assert_eq!(round_if_close(0.5 + 1e-9, 1e-10), 0.5);
+        assert_eq!(round_if_close(0.5 + 1e-6, 1e-10), 0.500001);
+    }
+
+    /// Tests that a simulator can return exact amplitudes.
+    #[test]
+    fn test_simulator_override() {
+        let mut qc = QuantumCircuit::new(1);
+        qc.ry(0, 2e-5);
+
+        let exact = Simulator::new().with_tolerance(Tolerance::EXACT).with_circuit(qc.clone()).run(0);
+        assert_eq!(exact.final_state[1].real, 1e-5_f64.sin());
+
+        let coarse = Tolerance::DEFAULT.with_decimals(Some(3));
+        let rounded = Simulator::new().with_tolerance(coarse).with_circuit(qc).run(0);
+        assert_eq!(rounded.final_state[1].real, 0.0);
+        assert_eq!(rounded.final_state[0].real, 1.0);
+    }
+}
@@ -0,0 +1,57 @@
+use intrico::observables::PauliSum;
+use intrico::variational::{brickwork, efficient_su2, real_amplitudes, GradientDescent, VQE};
+
+/// Test suite for the ready-made ansätze.
+/// 
+/// These tests verify the behaviour of:
+/// - Parameter and gate counts of every ansatz
+/// - Real amplitudes of the real-amplitudes ansatz
+/// - Use of the ansätze with the VQE driver
+mod ansatz_tests {
+    use super::*;
+
+    /// Tests the parameter and rotation counts of every ansatz.
+    #[test]
+    fn test_parameter_counts() {
+        let su2 = efficient_su2(3, 2);
+        assert_eq!(su2.num_parameters(), 18);
+        assert_eq!(su2.num_rotations(), 18);
+        assert_eq!(su2.bind(&[0.1; 18]).num_operations(), 18 + 2 * 2);
+
+        let real = real_amplitudes(3, 2);
+        assert_eq!(real.num_parameters(), 9);
+        assert_eq!(real.bind(&[0.1; 9]).num_operations(), 9 + 2 * 2);
+
+        let bricks = brickwork(5, 2);
+        assert_eq!(bricks.num_parameters(), 2 * (2 + 2));
+        assert_eq!(bricks.bind(&[0.1; 8]).num_operations(), 3 * 4);
+
+        assert_eq!(brickwork(1, 3).num_parameters(), 0);
+    }
+
+    /// Tests that the real-amplitudes ansatz only prepares real states.
+    #[test]
+    fn test_real_amplitudes() {
+        let params: Vec<f64> = (0..8).map(|i| 0.3 * i as f64 - 1.0).collect();
+        let state = real_amplitudes(4, 1).bind(&params).execute();
+        assert!(state.iter().all(|amp| amp.imag.abs() < 1e-12));
+
+        let norm: f64 = state.iter().map(|amp| amp.norm_squared()).sum();
+        assert!((norm - 1.0).abs() < 1e-6);
+    }
+
+    /// Tests that every ansatz reaches the ground state of a simple Hamiltonian with VQE.
+    #[test]
+    fn test_with_vqe() {
+        // Ground state |11⟩ with energy -2
+        let hamiltonian = PauliSum::new(2).with_term(1.0, "ZI").with_term(1.0, "IZ");
+
+        for ansatz in [efficient_su2(2, 1), real_amplitudes(2, 1), brickwork(2, 1)] {
+            let initial = vec![0.2; ansatz.num_parameters()];
+            let mut vqe = VQE::new(ansatz, hamiltonian.clone(), GradientDescent::new(0.2))
+                .with_max_iterations(400);
+            let result = vqe.run(&initial);
+            assert!((result.energy + 2.0).abs() < 1e-3, "energy {}", result.energy);
+        }
+    }
+}
@@ -0,0 +1,4 @@
+mod ansatz_tests;
+mod optimizer_tests;
+mod qaoa_tests;
+mod vqe_tests;
\ No newline at end of file
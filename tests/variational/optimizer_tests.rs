@@ -0,0 +1,96 @@
+use intrico::circuit::ParameterizedCircuit;
+use intrico::observables::PauliSum;
+use intrico::variational::{Adam, GradientDescent, Optimizer, SPSA, VQE};
+
+/// Test suite for the classical optimizers.
+/// 
+/// These tests verify the behaviour of:
+/// - Minimizing closures with gradient descent, Adam and SPSA
+/// - Reproducible SPSA runs from a seed
+/// - SPSA on noisy objectives
+/// - Driving VQE with every optimizer
+mod optimizer_tests {
+    use super::*;
+
+    fn quadratic(x: &[f64]) -> f64 {
+        x.iter().enumerate().map(|(i, v)| (i as f64 + 1.0) * (v - 1.0).powi(2)).sum()
+    }
+
+    /// Tests that every optimizer minimizes a convex quadratic.
+    #[test]
+    fn test_minimize_quadratic() {
+        let initial = [0.0, 2.0, -1.0];
+        let results = [
+            GradientDescent::new(0.1).minimize(&quadratic, &initial, 200),
+            Adam::new(0.1).minimize(&quadratic, &initial, 500),
+            SPSA::new(0.2, 0.1).with_seed(5).minimize(&quadratic, &initial, 500),
+        ];
+        for params in results {
+            assert!(quadratic(&params) < 1e-3, "{:?}", params);
+        }
+    }
+
+    /// Tests that Adam restarts its moments when the number of parameters changes.
+    #[test]
+    fn test_adam_restart() {
+        let mut adam = Adam::new(0.1);
+        adam.minimize(&quadratic, &[0.0, 0.0], 10);
+        let params = adam.minimize(&quadratic, &[0.0], 300);
+        assert!((params[0] - 1.0).abs() < 1e-2);
+    }
+
+    /// Tests that Adam's epsilon damps the update rather than the gradient estimate.
+    #[test]
+    fn test_adam_epsilon() {
+        // The bias-corrected first step has the size of the learning rate
+        let mut params = vec![0.0];
+        Adam::new(0.1).step(&quadratic, &mut params);
+        assert!((params[0] - 0.1).abs() < 1e-6);
+
+        let mut damped = Adam::new(0.1);
+        damped.epsilon = 1e3;
+        let mut params = vec![0.0];
+        damped.step(&quadratic, &mut params);
+        assert!(params[0] > 0.0 && params[0] < 1e-3);
+    }
+
+    /// Tests that seeded SPSA runs are reproducible.
+    #[test]
+    fn test_spsa_seed() {
+        let first = SPSA::new(0.2, 0.1).with_seed(9).minimize(&quadratic, &[0.0; 4], 20);
+        let second = SPSA::new(0.2, 0.1).with_seed(9).minimize(&quadratic, &[0.0; 4], 20);
+        assert_eq!(first, second);
+    }
+
+    /// Tests that SPSA converges on an objective with deterministic pseudo-noise.
+    #[test]
+    fn test_spsa_noisy_objective() {
+        let noisy = |x: &[f64]| {
+            let noise = ((x[0] * 12_345.678).sin() * 43_758.545).fract() * 1e-3;
+            quadratic(x) + noise
+        };
+        let params = SPSA::new(0.2, 0.2).with_seed(1).minimize(&noisy, &[3.0, -2.0], 1000);
+        assert!(quadratic(&params) < 1e-2, "{:?}", params);
+    }
+
+    /// Tests that VQE reaches the ground-state energy with Adam and SPSA.
+    #[test]
+    fn test_vqe_optimizers() {
+        let mut ansatz = ParameterizedCircuit::new(1);
+        ansatz.ry(0, 0);
+        ansatz.rz(0, 1);
+        let hamiltonian = PauliSum::new(1).with_term(1.0, "Z").with_term(1.0, "X");
+
+        let adam = VQE::new(ansatz.clone(), hamiltonian.clone(), Adam::new(0.1))
+            .with_max_iterations(500)
+            .with_tolerance(0.0)
+            .run(&[0.1, 0.1]);
+        assert!((adam.energy + 2.0_f64.sqrt()).abs() < 1e-3, "energy {}", adam.energy);
+
+        let spsa = VQE::new(ansatz, hamiltonian, SPSA::new(0.3, 0.1).with_seed(2))
+            .with_max_iterations(500)
+            .with_tolerance(0.0)
+            .run(&[0.1, 0.1]);
+        assert!((spsa.energy + 2.0_f64.sqrt()).abs() < 1e-2, "energy {}", spsa.energy);
+    }
+}
@@ -0,0 +1,58 @@
+use intrico::variational::{cut_value, qaoa, qaoa_ansatz};
+
+/// Test suite for QAOA on MaxCut.
+/// 
+/// These tests verify the behaviour of:
+/// - Cut values of vertex assignments
+/// - The structure of the cost and mixer layers
+/// - Optimized solutions on small graphs
+mod qaoa_tests {
+    use super::*;
+
+    /// Tests cut values on a triangle with a pendant vertex.
+    #[test]
+    fn test_cut_value() {
+        let edges = [(0, 1), (1, 2), (0, 2), (2, 3)];
+        assert_eq!(cut_value(&edges, 0b0000), 0);
+        assert_eq!(cut_value(&edges, 0b0100), 3);
+        assert_eq!(cut_value(&edges, 0b1010), 3);
+        assert_eq!(cut_value(&edges, 0b0001), 2);
+    }
+
+    /// Tests that every layer adds one γ and one β parameter.
+    #[test]
+    fn test_ansatz_layers() {
+        let edges = [(0, 1), (1, 2)];
+        let ansatz = qaoa_ansatz(&edges, 3);
+        assert_eq!(ansatz.num_qubits(), 3);
+        assert_eq!(ansatz.num_parameters(), 6);
+        // One Rz per edge and one Rx per vertex in every layer
+        assert_eq!(ansatz.num_rotations(), 3 * (2 + 3));
+    }
+
+    /// Tests that QAOA finds the maximum cut of small graphs.
+    #[test]
+    fn test_max_cut() {
+        for (edges, max_cut) in [
+            (vec![(0, 1)], 1),
+            (vec![(0, 1), (1, 2), (0, 2)], 2),
+            (vec![(0, 1), (1, 2), (2, 3), (3, 0)], 4),
+        ] {
+            let result = qaoa(&edges, 2);
+            assert_eq!(result.max_cut, max_cut);
+            assert_eq!(result.best_cut, max_cut, "{:?}: {:?}", edges, result);
+            assert_eq!(cut_value(&edges, usize::from_str_radix(&result.best_bitstring, 2).unwrap()), max_cut);
+            assert!(result.approximation_ratio > 0.7 && result.approximation_ratio <= 1.0 + 1e-9);
+        }
+    }
+
+    /// Tests that more layers do not lower the approximation ratio.
+    #[test]
+    fn test_more_layers() {
+        let edges = [(0, 1), (1, 2), (2, 3), (3, 4), (4, 0)];
+        let shallow = qaoa(&edges, 1);
+        let deep = qaoa(&edges, 2);
+        assert!(deep.approximation_ratio >= shallow.approximation_ratio - 1e-3,
+                "p = 1: {}, p = 2: {}", shallow.approximation_ratio, deep.approximation_ratio);
+    }
+}
@@ -0,0 +1,114 @@
+use rusticle::complex::Complex;
+use intrico::QuantumCircuit;
+use intrico::circuit::ParameterizedCircuit;
+use intrico::observables::PauliSum;
+use intrico::simulator::{Observable, Simulator};
+use intrico::variational::{GradientDescent, Optimizer, VQE};
+
+/// Test suite for the variational quantum eigensolver.
+/// 
+/// These tests verify the behaviour of:
+/// - Pauli sum expectation values
+/// - Convergence to the ground-state energy
+/// - Energies estimated from grouped measurements
+/// - The energy history and iteration limits
+mod vqe_tests {
+    use super::*;
+
+    fn two_qubit_ansatz() -> ParameterizedCircuit {
+        let mut ansatz = ParameterizedCircuit::new(2);
+        ansatz.ry(0, 0);
+        ansatz.ry(1, 1);
+        ansatz.circuit_mut().cnot(0, 1);
+        ansatz.ry(0, 2);
+        ansatz.ry(1, 3);
+        ansatz
+    }
+
+    /// Tests Pauli sum expectations on a few reference states.
+    #[test]
+    fn test_pauli_sum_expectation() {
+        let h = 1.0 / 2.0_f64.sqrt();
+        let plus_i = [Complex::new(h, 0.0), Complex::new(0.0, h)];
+        let y = PauliSum::new(1).with_term(2.0, "Y");
+        assert!((y.expectation(&plus_i) - 2.0).abs() < 1e-10);
+
+        let mut qc = QuantumCircuit::new(2);
+        qc.x(0);
+        let hamiltonian = PauliSum::new(2)
+            .with_term(0.5, "ZI")
+            .with_term(0.25, "IZ")
+            .with_term(1.0, "XX");
+        assert!((hamiltonian.expectation(&qc.execute()) - 0.25).abs() < 1e-10);
+    }
+
+    /// Tests that VQE reaches the ground-state energy of a two-qubit Hamiltonian.
+    #[test]
+    fn test_ground_state_energy() {
+        // Eigenvalues of ZZ + 0.5·XI + 0.5·IX are ±√2 and 0 (twice)
+        let hamiltonian = PauliSum::new(2)
+            .with_term(1.0, "ZZ")
+            .with_term(0.5, "XI")
+            .with_term(0.5, "IX");
+
+        let mut vqe = VQE::new(two_qubit_ansatz(), hamiltonian, GradientDescent::new(0.2))
+            .with_max_iterations(500);
+        let result = vqe.run(&[0.1, -0.2, 0.3, 0.4]);
+
+        assert!((result.energy + 2.0_f64.sqrt()).abs() < 1e-3, "energy {}", result.energy);
+        assert!((vqe.energy(&result.parameters) - result.energy).abs() < 1e-10);
+    }
+
+    /// Tests that VQE converges with energies estimated from grouped measurements.
+    #[test]
+    fn test_sampled_energy() {
+        let hamiltonian: PauliSum = "ZZ + 0.5*XI + 0.5*IX".parse().unwrap();
+
+        let mut vqe = VQE::new(two_qubit_ansatz(), hamiltonian, GradientDescent::new(0.2))
+            .with_simulator(Simulator::new().with_seed(4))
+            .with_shots(4000)
+            .with_max_iterations(60)
+            .with_tolerance(0.0);
+        let result = vqe.run(&[0.1, -0.2, 0.3, 0.4]);
+
+        assert!((result.energy + 2.0_f64.sqrt()).abs() < 0.1, "energy {}", result.energy);
+    }
+
+    /// Tests that the history starts at the initial energy and respects the iteration limit.
+    #[test]
+    fn test_history() {
+        let hamiltonian = PauliSum::new(2).with_term(1.0, "ZZ");
+        let initial = [0.3, 0.2, 0.1, 0.0];
+
+        let mut vqe = VQE::new(two_qubit_ansatz(), hamiltonian, GradientDescent::new(0.1))
+            .with_max_iterations(5);
+        let result = vqe.run(&initial);
+
+        assert_eq!(result.history.len(), 6);
+        assert!((result.history[0] - vqe.energy(&initial)).abs() < 1e-10);
+        assert!(result.history.last().unwrap() < &result.history[0]);
+    }
+
+    /// Tests that VQE drives user-defined optimizers.
+    #[test]
+    fn test_custom_optimizer() {
+        /// Lowers the first parameter by a fixed amount every iteration
+        struct FixedStep;
+
+        impl Optimizer for FixedStep {
+            fn step(&mut self, _objective: &dyn Fn(&[f64]) -> f64, params: &mut [f64]) {
+                params[0] -= 0.5;
+            }
+        }
+
+        let mut ansatz = ParameterizedCircuit::new(1);
+        ansatz.ry(0, 0);
+
+        let mut vqe = VQE::new(ansatz, PauliSum::new(1).with_term(1.0, "Z"), FixedStep)
+            .with_max_iterations(4);
+        let result = vqe.run(&[0.0]);
+
+        assert_eq!(result.parameters, vec![-2.0]);
+        assert_eq!(result.history.len(), 5);
+    }
+}
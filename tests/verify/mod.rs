@@ -0,0 +1 @@
+mod verify_tests;
\ No newline at end of file
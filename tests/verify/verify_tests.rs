@@ -0,0 +1,109 @@
+use intrico::{assert_circuits_equivalent, assert_state_close, assert_unitary, QuantumCircuit, QuantumGate};
+use intrico::verify::{state_distance, unitary_deviation, unitary_distance};
+use rusticle::complex::Complex;
+use rusticle::linalg::Matrix;
+
+/// Test suite for the verification helpers.
+///
+/// These tests verify the behaviour of:
+/// - Unitarity checks of gates
+/// - Circuit equivalence up to a global phase
+/// - Statevector comparisons
+/// - The assertion macros and their panic messages
+mod verify_tests {
+    use super::*;
+
+    /// Tests the unitarity check on built-in and custom gates.
+    #[test]
+    fn test_unitary() {
+        for gate in [QuantumGate::H, QuantumGate::Toffoli, QuantumGate::Rx(0.4), QuantumGate::Tdg] {
+            assert_unitary!(gate);
+        }
+
+        let scaled = Matrix::new(2, 2, vec![
+            Complex::new(2.0, 0.0), Complex::new(0.0, 0.0),
+            Complex::new(0.0, 0.0), Complex::new(1.0, 0.0),
+        ]);
+        assert!((unitary_deviation(&scaled) - 3.0).abs() < 1e-12);
+        assert_eq!(unitary_deviation(&Matrix::zeros(2, 3)), f64::INFINITY);
+
+        // A slightly scaled gate passes once the tolerance is loosened
+        let nearly = Matrix::new(2, 2, vec![
+            Complex::new(1.0001, 0.0), Complex::new(0.0, 0.0),
+            Complex::new(0.0, 0.0), Complex::new(1.0, 0.0),
+        ]);
+        assert_unitary!(QuantumGate::Custom(nearly, "Nearly".to_string(), "N".to_string()), 1e-3);
+    }
+
+    /// Tests that a non-unitary custom gate fails the assertion.
+    #[test]
+    #[should_panic(expected = "Gate Half is not unitary")]
+    fn test_unitary_panics() {
+        let half = Matrix::new(2, 2, vec![
+            Complex::new(0.5, 0.0), Complex::new(0.0, 0.0),
+            Complex::new(0.0, 0.0), Complex::new(0.5, 0.0),
+        ]);
+        assert_unitary!(QuantumGate::Custom(half, "Half".to_string(), "½".to_string()));
+    }
+
+    /// Tests equivalence of circuits that differ by a global phase.
+    #[test]
+    fn test_circuits_equivalent() {
+        // CNOT conjugated by Hadamards on the target is CZ
+        let mut a = QuantumCircuit::new(2);
+        a.h(1);
+        a.cnot(0, 1);
+        a.h(1);
+        let mut b = QuantumCircuit::new(2);
+        b.cz(0, 1);
+        assert_circuits_equivalent!(a, b);
+
+        // Rz(θ) equals P(θ) up to the phase e^(-iθ/2)
+        let mut rz = QuantumCircuit::new(1);
+        rz.rz(0, 0.8);
+        let mut p = QuantumCircuit::new(1);
+        p.p(0, 0.8);
+        assert_circuits_equivalent!(rz, p, 1e-12);
+        assert!(unitary_distance(&rz.unitary(), &p.unitary()) < 1e-12);
+    }
+
+    /// Tests that circuits implementing different unitaries fail the assertion.
+    #[test]
+    #[should_panic(expected = "Circuits are not equivalent: entry (0, 0)")]
+    fn test_circuits_not_equivalent() {
+        let mut a = QuantumCircuit::new(1);
+        a.h(0);
+        let mut b = QuantumCircuit::new(1);
+        b.x(0);
+        assert_circuits_equivalent!(a, b);
+    }
+
+    /// Tests that circuits on different numbers of qubits fail the assertion.
+    #[test]
+    #[should_panic(expected = "different numbers of qubits: 1 and 2")]
+    fn test_circuits_different_widths() {
+        assert_circuits_equivalent!(QuantumCircuit::new(1), QuantumCircuit::new(2));
+    }
+
+    /// Tests statevector comparisons, which are sensitive to the global phase.
+    #[test]
+    fn test_state_close() {
+        let mut qc = QuantumCircuit::new(2);
+        qc.x(0);
+        let expected = vec![Complex::new(0.0, 0.0), Complex::new(1.0, 0.0), Complex::new(0.0, 0.0), Complex::new(0.0, 0.0)];
+        assert_state_close!(qc.execute(), expected);
+
+        let flipped: Vec<Complex> = expected.iter().map(|&amp| amp * Complex::new(-1.0, 0.0)).collect();
+        assert!((state_distance(&expected, &flipped) - 2.0).abs() < 1e-12);
+        assert_eq!(state_distance(&expected, &expected[..2]), f64::INFINITY);
+    }
+
+    /// Tests that the failing basis state is reported.
+    #[test]
+    #[should_panic(expected = "Statevectors differ at |00⟩")]
+    fn test_state_close_panics() {
+        let mut qc = QuantumCircuit::new(2);
+        qc.h(0);
+        assert_state_close!(qc.execute(), QuantumCircuit::new(2).execute(), 1e-3);
+    }
+}
@@ -0,0 +1,73 @@
+use intrico::{QuantumGate, Qubit};
+
+/// Test suite for the Bloch sphere rendering.
+///
+/// These tests verify the behaviour of:
+/// - Bloch vectors of basis and superposition states
+/// - The structure of the rendered SVG documents
+/// - Trajectories through sequences of gates
+/// - Rejection of gates that have no single-qubit trajectory
+mod bloch_tests {
+    use super::*;
+
+    /// Asserts that two Bloch vectors agree up to rounding errors.
+    fn assert_close(actual: [f64; 3], expected: [f64; 3]) {
+        for (a, e) in actual.iter().zip(expected) {
+            assert!((a - e).abs() < 1e-9, "expected {:?}, got {:?}", expected, actual);
+        }
+    }
+
+    /// Tests the Bloch vectors of the six cardinal states.
+    #[test]
+    fn test_bloch_vector() {
+        assert_close(Qubit::zero().bloch_vector(), [0.0, 0.0, 1.0]);
+        assert_close(Qubit::one().bloch_vector(), [0.0, 0.0, -1.0]);
+
+        let mut plus = Qubit::zero();
+        plus.apply(QuantumGate::H);
+        assert_close(plus.bloch_vector(), [1.0, 0.0, 0.0]);
+
+        let mut minus = Qubit::one();
+        minus.apply(QuantumGate::H);
+        assert_close(minus.bloch_vector(), [-1.0, 0.0, 0.0]);
+
+        let mut plus_i = plus.clone();
+        plus_i.apply(QuantumGate::S);
+        assert_close(plus_i.bloch_vector(), [0.0, 1.0, 0.0]);
+
+        let mut single = Qubit::zero().to_precision::<f32>();
+        single.apply(QuantumGate::Ry(std::f64::consts::FRAC_PI_2));
+        let [x, _, z] = single.bloch_vector();
+        assert!((x - 1.0).abs() < 1e-6 && z.abs() < 1e-6);
+    }
+
+    /// Tests that a single state renders as a complete SVG document.
+    #[test]
+    fn test_bloch_svg() {
+        let svg = Qubit::zero().to_bloch_svg();
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.trim_end().ends_with("</svg>"));
+        assert!(svg.contains("|0⟩") && svg.contains("|1⟩"));
+        assert_eq!(svg.matches("<polygon").count(), 1);
+    }
+
+    /// Tests that a trajectory marks every intermediate state and ends with an arrow.
+    #[test]
+    fn test_bloch_trajectory() {
+        let gates = [QuantumGate::H, QuantumGate::S, QuantumGate::Rx(0.5)];
+        let svg = Qubit::zero().bloch_trajectory_svg(&gates);
+        // The sphere outline plus one dot per state before the last gate
+        assert_eq!(svg.matches("<circle").count(), 1 + gates.len());
+        assert_eq!(svg.matches("<polygon").count(), 1);
+
+        let empty = Qubit::one().bloch_trajectory_svg(&[]);
+        assert_eq!(empty.matches("<circle").count(), 1);
+    }
+
+    /// Tests that multi-qubit gates are rejected.
+    #[test]
+    #[should_panic(expected = "Bloch trajectories need single-qubit gates, got CNOT")]
+    fn test_bloch_trajectory_multi_qubit() {
+        Qubit::zero().bloch_trajectory_svg(&[QuantumGate::H, QuantumGate::CNOT]);
+    }
+}
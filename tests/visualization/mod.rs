@@ -0,0 +1,2 @@
+mod bloch_tests;
+mod qsphere_tests;
\ No newline at end of file
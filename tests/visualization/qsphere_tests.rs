@@ -0,0 +1,59 @@
+use intrico::QuantumCircuit;
+
+/// Test suite for the statevector visualizations.
+///
+/// These tests verify the behaviour of:
+/// - Q-sphere rendering of the basis states above the threshold
+/// - Phase colors distinguishing amplitudes of opposite sign
+/// - Phase-disk grids and their layout
+mod qsphere_tests {
+    use super::*;
+
+    /// Builds the GHZ state on three qubits with a relative phase of -1.
+    fn ghz_minus() -> QuantumCircuit {
+        let mut qc = QuantumCircuit::new(3);
+        qc.h(0);
+        qc.cnot(0, 1);
+        qc.cnot(1, 2);
+        qc.z(0);
+        qc
+    }
+
+    /// Tests that only the populated basis states are drawn and labelled.
+    #[test]
+    fn test_qsphere_states() {
+        let svg = ghz_minus().execute().to_qsphere_svg();
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.contains("|000⟩") && svg.contains("|111⟩"));
+        assert!(!svg.contains("|010⟩"));
+
+        let all = ghz_minus().execute().with_threshold(0.0).to_qsphere_svg();
+        for index in 0..8 {
+            assert!(all.contains(&format!("|{:03b}⟩", index)));
+        }
+    }
+
+    /// Tests that amplitudes with opposite phases get different colors.
+    #[test]
+    fn test_qsphere_phase_colors() {
+        let svg = ghz_minus().execute().to_qsphere_svg();
+        let strokes: Vec<&str> = svg.lines()
+            .filter(|line| line.starts_with("<line") && !line.contains("#aaaaaa"))
+            .collect();
+        assert_eq!(strokes.len(), 2);
+        assert_ne!(strokes[0].split("stroke=").nth(1), strokes[1].split("stroke=").nth(1));
+    }
+
+    /// Tests the phase-disk grid layout and hands.
+    #[test]
+    fn test_phase_disks() {
+        let svg = ghz_minus().execute().to_phase_disk_svg();
+        // Four columns for three qubits, one hand per populated state
+        assert!(svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" width="288""#));
+        assert_eq!(svg.matches("<line").count(), 2);
+        assert_eq!(svg.matches("⟩</text>").count(), 8);
+
+        let single = QuantumCircuit::new(1).execute().to_phase_disk_svg();
+        assert_eq!(single.matches("⟩</text>").count(), 2);
+    }
+}